@@ -95,3 +95,12 @@ pub fn emit_task_error(id: &str, message: &str) {
         }),
     });
 }
+
+/// Emit a task_parse_error event carrying the file, line and parser message of a task file
+/// that no longer parses (e.g. after a merge left conflict markers in it).
+pub fn emit_task_parse_error(warning: &crate::storage::TaskParseWarning) {
+    emit(&ApiEvent {
+        kind: "task_parse_error".into(),
+        data: serde_json::to_value(warning).unwrap_or(JsonValue::Null),
+    });
+}
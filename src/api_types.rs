@@ -31,6 +31,8 @@ pub struct TaskDTO {
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub acceptance_criteria: Vec<String>,
     #[serde(
         skip_serializing_if = "crate::types::TaskRelationships::is_empty",
         default
@@ -138,6 +140,9 @@ pub struct AgentJob {
     pub worktree_path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub worktree_branch: Option<String>,
+    /// Current attempt number (1-based); greater than 1 after automatic retries.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub attempt: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -170,6 +175,13 @@ pub struct AgentJobListResponse {
     /// Queue statistics
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub queue_stats: Option<AgentQueueStats>,
+    /// Jobs matching the filters, including those outside the returned page
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub total: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub offset: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -194,6 +206,166 @@ pub struct AgentJobCancelAllResponse {
     pub jobs: Vec<AgentJob>,
 }
 
+/// Progress of one stage within an agent pipeline run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentPipelineStageRun {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub agent: Option<String>,
+    /// pending, running, awaiting_approval, succeeded, failed, or cancelled
+    pub status: String,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub needs: Vec<String>,
+    #[serde(default)]
+    pub approval: bool,
+    /// Agent job running (or last run) for this stage
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub job_id: Option<String>,
+    /// Number of times the stage has been started (greater than 1 after a rewind)
+    #[serde(default)]
+    pub runs: u32,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub started_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub finished_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub message: Option<String>,
+}
+
+/// State of a declarative agent pipeline executing against one ticket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentPipelineRun {
+    pub id: String,
+    pub pipeline: String,
+    pub ticket_id: String,
+    /// running, awaiting_approval, completed, failed, or cancelled
+    pub status: String,
+    pub created_at: String,
+    pub updated_at: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub finished_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub prompt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub message: Option<String>,
+    /// Number of failure transitions that re-ran earlier stages
+    #[serde(default)]
+    pub rewinds: u32,
+    pub stages: Vec<AgentPipelineStageRun>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentPipelineRunRequest {
+    pub ticket_id: String,
+    pub pipeline: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub prompt: Option<String>,
+}
+
+/// Approve or reject the approval stage a pipeline run is waiting on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentPipelineDecisionRequest {
+    pub ticket_id: String,
+    /// Required when several approval stages are waiting at once
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub stage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentPipelineCancelRequest {
+    pub ticket_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentPipelineRunResponse {
+    pub run: AgentPipelineRun,
+}
+
+/// Summary of a pipeline definition from config
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentPipelineInfo {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+    pub stages: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentPipelineListResponse {
+    pub pipelines: Vec<AgentPipelineInfo>,
+    pub runs: Vec<AgentPipelineRun>,
+}
+
+/// Ticket operation requested by an agent and parked until a human decides on it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentApproval {
+    pub id: u32,
+    pub ticket_id: String,
+    /// update, comment, or delete
+    pub operation: String,
+    /// Human-readable description, e.g. "status -> Merging"
+    pub summary: String,
+    /// pending, approved, rejected, or failed
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub job_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub agent: Option<String>,
+    pub requested_at: String,
+    /// Field changes to apply (update operations)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub patch: Option<TaskUpdate>,
+    /// Comment text to add (comment operations)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub decided_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub decided_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub note: Option<String>,
+    /// Why applying an approved operation failed
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentApprovalListResponse {
+    pub approvals: Vec<AgentApproval>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentApprovalDecisionRequest {
+    pub id: u32,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentApprovalDecisionResponse {
+    pub approval: AgentApproval,
+    /// Ticket after an approved update or comment was applied
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub task: Option<TaskDTO>,
+    /// Whether the requesting agent job was sent a message about the rejection
+    #[serde(default)]
+    pub notified: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct AutomationInspectResponse {
@@ -460,6 +632,9 @@ pub struct TaskListResponse {
     pub offset: usize,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub tasks: Vec<TaskDTO>,
+    /// Task files that could not be parsed and are missing from `tasks`
+    #[serde(default)]
+    pub warnings: Vec<crate::storage::TaskParseWarning>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -471,6 +646,40 @@ pub struct TaskSelection {
     pub r#where: Vec<(String, String)>,
 }
 
+/// Parameters for a completion forecast (`GET /api/forecast`, MCP `forecast`).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct ForecastRequest {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub project: Option<String>,
+    /// Free-text query narrowing the tasks.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub q: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub tags: Vec<String>,
+    /// Forecast the descendants of this epic.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub epic: Option<String>,
+    /// Sprint reference (numeric id, next, previous, active).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub sprint: Option<String>,
+    /// `sprint` (default) or `week`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub by: Option<String>,
+    /// `tasks` (default), `points` or `hours`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub metric: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub history: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub iterations: Option<usize>,
+    /// Target date (YYYY-MM-DD or a relative expression such as `+6w`).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub target: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub seed: Option<u64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
@@ -616,6 +825,8 @@ pub struct SprintAssignmentResponse {
     pub replaced: Vec<SprintReassignment>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub messages: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub capacity_warnings: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub integrity: Option<SprintIntegrityDiagnostics>,
 }
@@ -861,6 +1072,9 @@ pub struct ScanRequest {
     pub modified_only: bool,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub targets: Vec<ScanTarget>,
+    /// Also check tasks whose only code reference may have disappeared.
+    #[serde(default)]
+    pub reconcile: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -907,6 +1121,27 @@ pub struct ScanResponse {
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub info: Vec<String>,
     pub entries: Vec<ScanEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub reconciled: Vec<ScanReconcileEntry>,
+}
+
+/// A task whose only code reference no longer points at its TODO.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct ScanReconcileEntry {
+    pub task_id: String,
+    pub code_reference: String,
+    /// `moved` when the key was found on another line, `removed` when it is gone,
+    /// `skipped` when the anchor could not be checked (see `message`).
+    pub outcome: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub new_reference: Option<String>,
+    /// Changes applied (or planned in dry-run mode): `reanchor`, `unlink`, `comment`,
+    /// `tag:<tag>`, `status:<status>`.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub actions: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub message: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
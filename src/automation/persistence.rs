@@ -8,6 +8,23 @@ pub fn load_global_automation(tasks_dir: &Path) -> LoTaRResult<Option<Automation
     load_automation_from_path(&path)
 }
 
+/// Automation rules shipped next to a config inherited through `extends:`; the closest layer wins.
+pub fn load_inherited_automation(tasks_dir: &Path) -> LoTaRResult<Option<AutomationFile>> {
+    let Ok(global) = crate::config::persistence::load_global_config(Some(tasks_dir)) else {
+        return Ok(None);
+    };
+    let layers = crate::config::inheritance::load_inherited_layers(tasks_dir, &global)
+        .map_err(|e| LoTaRError::ValidationError(e.to_string()))?;
+    for layer in layers.iter().rev() {
+        if let Some(content) = layer.read_sibling("automation.yml") {
+            let parsed =
+                serde_yaml::from_str::<AutomationFile>(&content).map_err(LoTaRError::from)?;
+            return Ok(Some(parsed));
+        }
+    }
+    Ok(None)
+}
+
 pub fn load_project_automation(
     tasks_dir: &Path,
     project: &str,
@@ -88,7 +105,7 @@ fn write_automation_file(path: &Path, file: &AutomationFile) -> LoTaRResult<()>
         fs::create_dir_all(parent)?;
     }
     let payload = to_canonical_yaml(file)?;
-    fs::write(path, payload)?;
+    crate::utils::filesystem::write_yaml_preserving_modeline(path, &payload)?;
     Ok(())
 }
 
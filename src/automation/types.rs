@@ -1,13 +1,18 @@
+use crate::config::types::SandboxConfig;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct AutomationFile {
     #[serde(default)]
     pub automation: AutomationRuleSet,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 pub enum AutomationRuleSet {
     List(Vec<AutomationRule>),
@@ -51,6 +56,7 @@ impl AutomationRuleSet {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct AutomationRule {
     #[serde(default)]
     pub name: Option<String>,
@@ -62,9 +68,13 @@ pub struct AutomationRule {
     pub when: Option<AutomationConditionGroup>,
     #[serde(default)]
     pub on: AutomationRuleActions,
+    /// Limits applied to this rule's `run` commands.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<SandboxConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct AutomationRuleActions {
     // Legacy catch-all: fires on any matching task change (create or update).
     // New configs should use specific event hooks below instead.
@@ -95,6 +105,7 @@ pub struct AutomationRuleActions {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct AutomationConditionGroup {
     #[serde(default)]
     pub all: Option<Vec<AutomationConditionGroup>>,
@@ -111,6 +122,7 @@ pub struct AutomationConditionGroup {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct AutomationChangeCondition {
     #[serde(default)]
     pub from: Option<AutomationFieldCondition>,
@@ -119,6 +131,7 @@ pub struct AutomationChangeCondition {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 pub enum AutomationFieldCondition {
     Scalar(String),
@@ -143,6 +156,7 @@ impl AutomationFieldCondition {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct AutomationFieldConditionMap {
     #[serde(default)]
     pub equals: Option<String>,
@@ -174,6 +188,7 @@ pub struct AutomationFieldConditionMap {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct AutomationAction {
     #[serde(default)]
     pub set: Option<AutomationActionSet>,
@@ -188,6 +203,7 @@ pub struct AutomationAction {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct AutomationRunCommand {
     pub command: String,
     #[serde(default)]
@@ -207,6 +223,7 @@ fn default_wait() -> bool {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 pub enum AutomationRunAction {
     Shell(String),
@@ -214,6 +231,7 @@ pub enum AutomationRunAction {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct AutomationActionSet {
     #[serde(default)]
     pub status: Option<String>,
@@ -238,10 +256,15 @@ pub struct AutomationActionSet {
     #[serde(default, alias = "label", alias = "labels")]
     pub labels: Option<StringOrVec>,
     #[serde(default)]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(with = "Option<HashMap<String, serde_json::Value>>")
+    )]
     pub custom_fields: Option<HashMap<String, serde_yaml::Value>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct AutomationTagAction {
     #[serde(default)]
     pub tags: Option<StringOrVec>,
@@ -258,6 +281,7 @@ pub struct AutomationTagAction {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 pub enum StringOrVec {
     Scalar(String),
@@ -28,6 +28,10 @@ pub enum AgentAction {
     Queue(AgentQueueArgs),
     /// Manage agent worktrees
     Worktree(WorktreeArgs),
+    /// Run and control multi-stage agent pipelines
+    Pipeline(AgentPipelineArgs),
+    /// Review ticket operations that agents parked for approval
+    Approvals(AgentApprovalsArgs),
     /// Internal worker for agent queueing
     #[command(name = "worker", hide = true)]
     Worker(AgentWorkerArgs),
@@ -60,6 +64,89 @@ pub struct WorktreeCleanupArgs {
     pub dry_run: bool,
 }
 
+#[derive(Args)]
+pub struct AgentPipelineArgs {
+    #[command(subcommand)]
+    pub action: AgentPipelineAction,
+}
+
+#[derive(Subcommand)]
+pub enum AgentPipelineAction {
+    /// List configured pipelines and runs in this process
+    List,
+    /// Run a pipeline for a ticket in the foreground until it finishes
+    Run(AgentPipelineRunArgs),
+    /// Show the latest pipeline run for a ticket
+    Status {
+        /// Ticket id (e.g. TEST-1)
+        ticket: String,
+    },
+    /// Approve a stage that is waiting for approval
+    Approve(AgentPipelineDecisionArgs),
+    /// Reject a stage that is waiting for approval
+    Reject(AgentPipelineDecisionArgs),
+    /// Cancel a pipeline run and its running jobs
+    Cancel {
+        /// Ticket id (e.g. TEST-1)
+        ticket: String,
+    },
+}
+
+#[derive(Args)]
+pub struct AgentPipelineRunArgs {
+    /// Ticket id (e.g. TEST-1)
+    pub ticket: String,
+    /// Pipeline name from config
+    pub pipeline: String,
+    /// Prompt used for stages without their own prompt
+    #[arg(long)]
+    pub prompt: Option<String>,
+    /// Cancel the run if it has not finished after this many seconds
+    #[arg(long, value_parser = clap::value_parser!(u64))]
+    pub timeout_seconds: Option<u64>,
+}
+
+#[derive(Args)]
+pub struct AgentPipelineDecisionArgs {
+    /// Ticket id (e.g. TEST-1)
+    pub ticket: String,
+    /// Stage to decide (required when several stages are waiting)
+    #[arg(long)]
+    pub stage: Option<String>,
+    /// Note recorded with the decision
+    #[arg(long, alias = "reason")]
+    pub note: Option<String>,
+}
+
+#[derive(Args)]
+pub struct AgentApprovalsArgs {
+    #[command(subcommand)]
+    pub action: Option<AgentApprovalsAction>,
+    /// Include decided approvals when listing
+    #[arg(long)]
+    pub all: bool,
+    /// Only list approvals for this ticket
+    #[arg(long)]
+    pub ticket: Option<String>,
+}
+
+#[derive(Subcommand)]
+pub enum AgentApprovalsAction {
+    /// Apply a parked operation
+    Approve(AgentApprovalDecisionArgs),
+    /// Discard a parked operation and notify the agent
+    Reject(AgentApprovalDecisionArgs),
+}
+
+#[derive(Args)]
+pub struct AgentApprovalDecisionArgs {
+    /// Approval id (as shown by `lotar agent approvals`)
+    pub id: u32,
+    /// Note recorded with the decision
+    #[arg(long, alias = "reason")]
+    pub note: Option<String>,
+}
+
 #[derive(Args)]
 pub struct AgentRunArgs {
     /// Ticket id (e.g. TEST-1)
@@ -98,6 +185,18 @@ pub struct AgentListJobsArgs {
     /// Limit number of jobs to list
     #[arg(long, short = 'n', default_value = "20")]
     pub limit: usize,
+    /// Skip this many jobs (for paging)
+    #[arg(long, default_value = "0")]
+    pub offset: usize,
+    /// Only list jobs for this ticket
+    #[arg(long)]
+    pub ticket: Option<String>,
+    /// Only list jobs with this status (queued, running, completed, failed, cancelled)
+    #[arg(long)]
+    pub status: Option<String>,
+    /// Only list jobs using this runner
+    #[arg(long)]
+    pub runner: Option<String>,
 }
 
 #[derive(Args)]
@@ -0,0 +1,59 @@
+use clap::{Args, ValueEnum};
+
+#[derive(Args, Debug)]
+pub struct CheckArgs {
+    /// One or more paths to check; if omitted, defaults to current project or '.'
+    pub paths: Vec<String>,
+
+    /// Include specific file extensions
+    #[arg(long)]
+    pub include: Vec<String>,
+
+    /// Exclude specific file extensions
+    #[arg(long)]
+    pub exclude: Vec<String>,
+
+    /// Only check lines added since the merge base with this git ref (e.g. origin/main)
+    #[arg(long, value_name = "REF", conflicts_with = "staged")]
+    pub base: Option<String>,
+
+    /// Only check lines added in the git index (for pre-commit hooks)
+    #[arg(long)]
+    pub staged: bool,
+
+    /// Check only git-modified/renamed files (based on `git status --porcelain`)
+    #[arg(long)]
+    pub modified_only: bool,
+
+    /// Report format for findings (use the global --format json for JSON)
+    #[arg(long, value_enum, default_value_t = CheckReport::Text)]
+    pub report: CheckReport,
+
+    /// Rules that make the command exit non-zero (comma-separated, or `none`)
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_values_t = [CheckFailOn::Unlinked, CheckFailOn::Unknown, CheckFailOn::Done]
+    )]
+    pub fail_on: Vec<CheckFailOn>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum CheckReport {
+    Text,
+    Sarif,
+    Github,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum CheckFailOn {
+    /// TODO comments without a ticket key
+    Unlinked,
+    /// Ticket keys that do not resolve to a task
+    Unknown,
+    /// Ticket keys whose task is already done
+    Done,
+    /// Never fail; report only
+    None,
+}
@@ -0,0 +1,10 @@
+use clap::Args;
+
+/// Options for `lotar doctor`.
+#[derive(Args, Debug, Default)]
+pub struct DoctorArgs {
+    /// Apply safe repairs: drop dangling relationship and sprint references, delete orphan
+    /// attachments, rename non-canonical task files and remove stale agent worktrees
+    #[arg(long)]
+    pub fix: bool,
+}
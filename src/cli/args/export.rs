@@ -0,0 +1,48 @@
+use clap::{Args, Subcommand};
+
+#[derive(Args, Debug)]
+pub struct ExportArgs {
+    #[command(subcommand)]
+    pub action: ExportAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ExportAction {
+    /// Export task due dates and sprint windows as an iCalendar (.ics) file
+    Ical(ExportIcalArgs),
+}
+
+#[derive(Args, Debug, Default)]
+pub struct ExportIcalArgs {
+    /// Write the calendar to this file instead of stdout
+    #[arg(long, short = 'o', value_name = "PATH")]
+    pub output: Option<std::path::PathBuf>,
+
+    /// Only include tasks assigned to this user (@me for yourself)
+    #[arg(long)]
+    pub assignee: Option<String>,
+
+    /// Only include tasks with this tag (repeatable)
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
+
+    /// Include tasks from every project instead of the current one
+    #[arg(long)]
+    pub all_projects: bool,
+
+    /// Leave sprint windows out of the calendar
+    #[arg(long)]
+    pub no_sprints: bool,
+
+    /// Leave task due dates out of the calendar
+    #[arg(long)]
+    pub no_tasks: bool,
+
+    /// Keep tasks that are already done
+    #[arg(long)]
+    pub include_done: bool,
+
+    /// Emit due dates as VTODO entries instead of all-day events
+    #[arg(long)]
+    pub todo: bool,
+}
@@ -0,0 +1,43 @@
+use crate::cli::args::sprint::TaskSelectionArgs;
+use crate::services::forecast_service::ForecastBasis;
+use crate::services::sprint_metrics::SprintBurndownMetric;
+use clap::Args;
+
+/// Options for `lotar forecast`.
+#[derive(Args, Debug, Default)]
+pub struct ForecastArgs {
+    /// Forecast the tasks under this epic (children and their descendants)
+    #[arg(long, value_name = "ID")]
+    pub epic: Option<String>,
+
+    /// Only forecast tasks in this sprint (numeric id, next, previous, active)
+    #[arg(long)]
+    pub sprint: Option<String>,
+
+    /// Sample historical throughput per closed sprint or per week
+    #[arg(long = "by", value_enum, default_value_t)]
+    pub basis: ForecastBasis,
+
+    /// Measure remaining work and throughput in tasks, points, or hours
+    #[arg(long, value_enum, default_value_t)]
+    pub metric: SprintBurndownMetric,
+
+    /// Number of past sprints (default 6) or weeks (default 12) to sample
+    #[arg(long)]
+    pub history: Option<usize>,
+
+    /// Number of simulated runs (default 10000, at most 100000)
+    #[arg(long)]
+    pub iterations: Option<usize>,
+
+    /// Report the probability of finishing by this date (e.g. 2026-03-31, +6w)
+    #[arg(long)]
+    pub target: Option<String>,
+
+    /// Seed for reproducible results
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    #[command(flatten)]
+    pub select: TaskSelectionArgs,
+}
@@ -8,6 +8,12 @@ pub enum GitAction {
         #[command(subcommand)]
         action: Option<GitHooksAction>,
     },
+    /// Link commits to the tickets they mention and apply keyword transitions.
+    Ingest(GitIngestArgs),
+    /// Validate the ticket references in a commit message file (used by the `commit-msg` hook).
+    CommitMsg(GitCommitMsgArgs),
+    /// Register `lotar merge-driver` for task and sprint files (git config + .gitattributes).
+    InstallMergeDriver(GitInstallMergeDriverArgs),
 }
 
 /// Subcommands available under `lotar git hooks`.
@@ -28,3 +34,42 @@ pub struct GitHooksInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 }
+
+/// Options for `lotar git ingest`.
+#[derive(Debug, Clone, Args, Default)]
+pub struct GitIngestArgs {
+    /// Only read commits reachable from HEAD but not from this ref (e.g. `origin/main`).
+    #[arg(long, conflicts_with = "commit")]
+    pub since: Option<String>,
+
+    /// Read specific commits instead of a range (repeatable, e.g. `--commit HEAD`).
+    #[arg(long = "commit", value_name = "REV")]
+    pub commit: Vec<String>,
+
+    /// Report what would be linked and transitioned without writing tasks.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// Options for `lotar git commit-msg`.
+#[derive(Debug, Clone, Args)]
+pub struct GitCommitMsgArgs {
+    /// Path to the commit message file git passes to the hook.
+    pub file: String,
+
+    /// Reject the commit when it mentions a ticket that does not exist.
+    #[arg(long)]
+    pub strict: bool,
+}
+
+/// Options for `lotar git install-merge-driver`.
+#[derive(Debug, Clone, Args, Default)]
+pub struct GitInstallMergeDriverArgs {
+    /// Overwrite an existing `merge.lotar.driver` value.
+    #[arg(long)]
+    pub force: bool,
+
+    /// Preview the changes without modifying git configuration or `.gitattributes`.
+    #[arg(long)]
+    pub dry_run: bool,
+}
@@ -0,0 +1,14 @@
+use clap::Args;
+
+/// Arguments git passes to `lotar merge-driver` (`%O %A %B %P`).
+#[derive(Args, Debug)]
+pub struct MergeDriverArgs {
+    /// Common ancestor version of the file (`%O`)
+    pub base: String,
+    /// Our version (`%A`); the merged result is written back to this file
+    pub ours: String,
+    /// Their version (`%B`)
+    pub theirs: String,
+    /// Path of the file in the repository (`%P`), used to tell task and sprint files apart
+    pub path: Option<String>,
+}
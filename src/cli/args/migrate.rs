@@ -0,0 +1,13 @@
+use clap::Args;
+
+/// Options for `lotar migrate`.
+#[derive(Args, Debug, Default)]
+pub struct MigrateArgs {
+    /// Show which files would change, with a diff, without writing anything
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Do not copy the original files to .tasks/.backups/ before rewriting them
+    #[arg(long)]
+    pub no_backup: bool,
+}
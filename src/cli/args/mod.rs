@@ -1,42 +1,65 @@
 pub mod agent;
 pub mod automation;
+pub mod check;
 pub mod common;
 pub mod completions;
 pub mod config;
+pub mod doctor;
+pub mod export;
+pub mod forecast;
 pub mod git;
 pub mod index;
+pub mod merge_driver;
+pub mod migrate;
+pub mod renumber;
 pub mod scan;
+pub mod schema;
 pub mod serve;
 pub mod sprint;
+pub mod start;
 pub mod stats;
 pub mod sync;
 pub mod task;
 
 // Re-exports for ergonomic imports from crate::cli::args
 pub use agent::{
-    AgentAction, AgentArgs, AgentCheckArgs, AgentListJobsArgs, AgentQueueAction, AgentQueueArgs,
+    AgentAction, AgentApprovalDecisionArgs, AgentApprovalsAction, AgentApprovalsArgs, AgentArgs,
+    AgentCheckArgs, AgentListJobsArgs, AgentPipelineAction, AgentPipelineArgs,
+    AgentPipelineDecisionArgs, AgentPipelineRunArgs, AgentQueueAction, AgentQueueArgs,
     AgentRunArgs, AgentWorkerArgs, WorktreeAction, WorktreeArgs, WorktreeCleanupArgs,
 };
 pub use automation::{AutomationAction, AutomationArgs, AutomationSimulateArgs};
+pub use check::{CheckArgs, CheckFailOn, CheckReport};
 pub use common::parse_key_value;
 pub use completions::{CompletionShell, CompletionsAction, CompletionsArgs};
 pub use config::{
     ConfigAction, ConfigInitArgs, ConfigNormalizeArgs, ConfigSetArgs, ConfigShowArgs,
     ConfigValidateArgs,
 };
-pub use git::{GitAction, GitHooksAction, GitHooksInstallArgs};
+pub use doctor::DoctorArgs;
+pub use export::{ExportAction, ExportArgs, ExportIcalArgs};
+pub use forecast::ForecastArgs;
+pub use git::{
+    GitAction, GitCommitMsgArgs, GitHooksAction, GitHooksInstallArgs, GitIngestArgs,
+    GitInstallMergeDriverArgs,
+};
 pub use index::{IndexAction, IndexArgs};
+pub use merge_driver::MergeDriverArgs;
+pub use migrate::MigrateArgs;
+pub use renumber::RenumberArgs;
 pub use scan::ScanArgs;
+pub use schema::{SchemaAction, SchemaArgs, SchemaExportArgs, SchemaShowArgs};
 pub use serve::ServeArgs;
 pub use sprint::{
     SprintAction, SprintArgs, SprintCloseArgs, SprintCreateArgs, SprintListArgs, SprintShowArgs,
     SprintStartArgs, SprintUpdateArgs,
 };
+pub use start::StartArgs;
 pub use stats::{StatsAction, StatsArgs};
 pub use sync::{SyncArgs, SyncCheckArgs, SyncCommandAction, SyncCommandArgs};
 pub use task::{
     AddArgs, RelationshipKind, SortField, TaskAction, TaskAddArgs, TaskDeleteArgs, TaskEditArgs,
     TaskReferenceAction, TaskReferenceAddArgs, TaskReferenceArgs, TaskReferenceKindAdd,
     TaskReferenceKindRemove, TaskReferenceRemoveArgs, TaskRelationshipsArgs, TaskSearchArgs,
-    TaskStatusArgs,
+    TaskStatusArgs, TaskTransferArgs,
 };
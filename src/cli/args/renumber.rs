@@ -0,0 +1,16 @@
+use clap::Args;
+
+/// Options for `lotar renumber`.
+#[derive(Args, Debug, Default)]
+pub struct RenumberArgs {
+    /// Task to move to a new number; without it, split merge collisions and duplicate files
+    pub id: Option<String>,
+
+    /// Number to move the task to (default: the next free number in its project)
+    #[arg(long, requires = "id")]
+    pub to: Option<u64>,
+
+    /// Show what would be renumbered and rewritten without changing any file
+    #[arg(long)]
+    pub dry_run: bool,
+}
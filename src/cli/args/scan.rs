@@ -38,4 +38,10 @@ pub struct ScanArgs {
     /// Falls back to full scan when not in a git repository.
     #[arg(long)]
     pub modified_only: bool,
+
+    /// Reconcile tasks whose only code reference is stale: follow moved TODOs and apply
+    /// `scan.reconcile` (comment/tag/status) to tasks whose TODO was removed.
+    /// Combine with --dry-run for a report.
+    #[arg(long)]
+    pub reconcile: bool,
 }
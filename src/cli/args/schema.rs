@@ -0,0 +1,42 @@
+use crate::services::schema_service::SchemaKind;
+use clap::{Args, Subcommand};
+
+#[derive(Args, Debug)]
+pub struct SchemaArgs {
+    #[command(subcommand)]
+    pub action: SchemaAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SchemaAction {
+    /// Print the JSON Schema for one kind of file
+    Show(SchemaShowArgs),
+    /// Write JSON Schemas for lotar's YAML files and point editors at them
+    Export(SchemaExportArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct SchemaShowArgs {
+    /// Kind of file
+    #[arg(value_enum)]
+    pub kind: SchemaKind,
+}
+
+#[derive(Args, Debug, Default)]
+pub struct SchemaExportArgs {
+    /// Only export this kind of file (repeatable; default: all)
+    #[arg(long = "kind", value_enum)]
+    pub kinds: Vec<SchemaKind>,
+
+    /// Directory for the schema files (default: .tasks/.schemas)
+    #[arg(long, short = 'o', value_name = "DIR")]
+    pub out: Option<std::path::PathBuf>,
+
+    /// Add a `# yaml-language-server: $schema=...` first line to every existing file
+    #[arg(long)]
+    pub modelines: bool,
+
+    /// Map the schemas to their files in .vscode/settings.json (yaml.schemas)
+    #[arg(long)]
+    pub vscode: bool,
+}
@@ -1,4 +1,5 @@
 use crate::cli::args::common::parse_key_value;
+use crate::config::types::SprintRolloverMode;
 use crate::services::sprint_metrics::SprintBurndownMetric;
 use clap::{Args, Subcommand};
 
@@ -176,6 +177,15 @@ pub struct SprintUpdateArgs {
     /// Planned capacity in hours
     #[arg(long)]
     pub capacity_hours: Option<u32>,
+    /// Weekly hours a member has for this sprint (MEMBER=HOURS, repeatable; empty HOURS clears)
+    #[arg(long = "member-hours", value_name = "MEMBER=HOURS", value_parser = parse_key_value)]
+    pub member_hours: Vec<(String, String)>,
+    /// Days off for a member (MEMBER=YYYY-MM-DD or MEMBER=YYYY-MM-DD..YYYY-MM-DD, repeatable)
+    #[arg(long = "time-off", value_name = "MEMBER=DATES", value_parser = parse_key_value)]
+    pub time_off: Vec<(String, String)>,
+    /// Remove all recorded time off for a member (repeatable)
+    #[arg(long = "clear-time-off", value_name = "MEMBER")]
+    pub clear_time_off: Vec<String>,
     /// Grace period before overdue warnings (e.g. 12h, 1d)
     #[arg(long)]
     pub overdue_after: Option<String>,
@@ -199,6 +209,9 @@ impl SprintUpdateArgs {
             || self.starts_at.is_some()
             || self.capacity_points.is_some()
             || self.capacity_hours.is_some()
+            || !self.member_hours.is_empty()
+            || !self.time_off.is_empty()
+            || !self.clear_time_off.is_empty()
             || self.overdue_after.is_some()
             || self.notes.is_some()
             || self.actual_started_at.is_some()
@@ -243,6 +256,12 @@ pub struct SprintCloseArgs {
     /// Run a review immediately after closing
     #[arg(long)]
     pub review: bool,
+    /// Move unfinished tasks to the next pending sprint, a new sprint, or the backlog
+    #[arg(long, value_enum)]
+    pub rollover: Option<SprintRolloverMode>,
+    /// Leave unfinished tasks on the closed sprint even if sprint defaults configure a rollover
+    #[arg(long = "no-rollover", conflicts_with = "rollover")]
+    pub no_rollover: bool,
 }
 
 #[derive(Args, Debug, Default)]
@@ -0,0 +1,24 @@
+use clap::Args;
+
+/// Options for `lotar start`.
+#[derive(Debug, Clone, Args)]
+pub struct StartArgs {
+    /// Task ID (with or without project prefix)
+    pub id: String,
+
+    /// Create the branch from this ref instead of the current HEAD
+    #[arg(long)]
+    pub base: Option<String>,
+
+    /// Status to move the task to (defaults to the project's in-progress state)
+    #[arg(long)]
+    pub status: Option<String>,
+
+    /// Do not assign the task to @me (auto.assign_on_status still applies)
+    #[arg(long)]
+    pub no_assign: bool,
+
+    /// Preview the branch and task changes without applying them
+    #[arg(long, short = 'n')]
+    pub dry_run: bool,
+}
@@ -233,6 +233,32 @@ pub enum StatsAction {
         global: bool,
     },
 
+    /// Daily task counts per status for a cumulative flow diagram
+    Flow {
+        /// Since (e.g., 14d, 2025-01-01, "2025-01-01T10:00Z")
+        #[arg(long)]
+        since: Option<String>,
+        /// Until (defaults to now)
+        #[arg(long)]
+        until: Option<String>,
+        /// Span all projects (default: current project only)
+        #[arg(long)]
+        global: bool,
+    },
+
+    /// Lead/cycle time percentiles per type and weekly throughput for tasks finished in a window
+    CycleTime {
+        /// Since (e.g., 14d, 2025-01-01, "2025-01-01T10:00Z")
+        #[arg(long)]
+        since: Option<String>,
+        /// Until (defaults to now)
+        #[arg(long)]
+        until: Option<String>,
+        /// Span all projects (default: current project only)
+        #[arg(long)]
+        global: bool,
+    },
+
     /// Per-ticket statistics
     Status {
         /// Task ID (full like PROJ-123 or numeric like 123 with project context)
@@ -249,6 +249,10 @@ pub enum TaskAction {
     Relationships(TaskRelationshipsArgs),
     /// Delete a task
     Delete(TaskDeleteArgs),
+    /// Move a task to another project, rewriting references to its old ID
+    Move(TaskTransferArgs),
+    /// Copy a task into another project
+    Copy(TaskTransferArgs),
 
     /// Show git history for a task file (read-only)
     History {
@@ -612,6 +616,11 @@ pub struct TaskSearchArgs {
     #[arg(long)]
     #[serde(default)]
     pub details: bool,
+
+    /// Fail when a task file cannot be parsed instead of skipping it with a warning
+    #[arg(long)]
+    #[serde(default)]
+    pub strict: bool,
 }
 
 #[derive(Args, Deserialize, Debug)]
@@ -629,3 +638,18 @@ pub struct TaskDeleteArgs {
     #[serde(default)]
     pub dry_run: bool,
 }
+
+#[derive(Args, Deserialize, Debug)]
+pub struct TaskTransferArgs {
+    /// Task ID to move or copy
+    pub id: String,
+
+    /// Target project (name or prefix)
+    #[arg(long)]
+    pub to: String,
+
+    /// Preview the new ID and the references that would change
+    #[arg(long)]
+    #[serde(default)]
+    pub dry_run: bool,
+}
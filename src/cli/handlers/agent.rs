@@ -1,14 +1,21 @@
-use crate::api_types::AgentJobCreateRequest;
+use crate::api_types::{
+    AgentJobCreateRequest, AgentPipelineDecisionRequest, AgentPipelineRun, AgentPipelineRunRequest,
+};
 use crate::automation::types::AutomationFile;
 use crate::cli::args::{
-    AgentAction, AgentCheckArgs, AgentListJobsArgs, AgentQueueAction, AgentQueueArgs, AgentRunArgs,
-    AgentWorkerArgs, WorktreeAction, WorktreeCleanupArgs,
+    AgentAction, AgentApprovalDecisionArgs, AgentApprovalsAction, AgentApprovalsArgs,
+    AgentCheckArgs, AgentListJobsArgs, AgentPipelineAction, AgentPipelineDecisionArgs,
+    AgentPipelineRunArgs, AgentQueueAction, AgentQueueArgs, AgentRunArgs, AgentWorkerArgs,
+    WorktreeAction, WorktreeCleanupArgs,
 };
 use crate::config::manager::ConfigManager;
 use crate::output::{OutputFormat, OutputRenderer};
-use crate::services::agent_job_service::AgentJobService;
+use crate::services::agent_approval_service::AgentApprovalService;
+use crate::services::agent_job_service::{AgentJobListFilter, AgentJobService};
 use crate::services::agent_log_service::AgentLogService;
+use crate::services::agent_pipeline_service::AgentPipelineService;
 use crate::services::agent_queue_service::AgentQueueService;
+use crate::services::agent_worktree_service::list_agent_worktrees;
 use crate::services::automation_service::AutomationService;
 use crate::services::task_service::TaskService;
 use crate::workspace::TasksDirectoryResolver;
@@ -58,6 +65,25 @@ impl AgentHandler {
                     Self::worktree_cleanup(&cleanup_args, resolver, renderer)
                 }
             },
+            AgentAction::Pipeline(args) => match args.action {
+                AgentPipelineAction::List => Self::pipeline_list(project, resolver, renderer),
+                AgentPipelineAction::Run(run_args) => {
+                    Self::pipeline_run(run_args, resolver, renderer)
+                }
+                AgentPipelineAction::Status { ticket } => {
+                    Self::pipeline_status(&ticket, resolver, renderer)
+                }
+                AgentPipelineAction::Approve(decision) => {
+                    Self::pipeline_decide(&decision, true, resolver, renderer)
+                }
+                AgentPipelineAction::Reject(decision) => {
+                    Self::pipeline_decide(&decision, false, resolver, renderer)
+                }
+                AgentPipelineAction::Cancel { ticket } => {
+                    Self::pipeline_cancel(&ticket, resolver, renderer)
+                }
+            },
+            AgentAction::Approvals(args) => Self::approvals(args, resolver, renderer),
             AgentAction::Worker(args) => Self::worker(args, resolver),
         }
     }
@@ -104,6 +130,9 @@ impl AgentHandler {
                 return Ok(());
             }
             renderer.emit_success(format!("Job {}: {}", job.id, job.status));
+            if let Some(attempt) = job.attempt.filter(|attempt| *attempt > 1) {
+                renderer.emit_info(format!("Attempt: {}", attempt));
+            }
             if let Some(msg) = job.last_message.as_ref() {
                 renderer.emit_info(msg);
             }
@@ -253,62 +282,45 @@ impl AgentHandler {
         resolver: &TasksDirectoryResolver,
         renderer: &OutputRenderer,
     ) -> Result<(), String> {
-        let (workspace_root, logs_dir) = get_logs_config(resolver);
-        let Some(logs_dir) = logs_dir else {
-            if matches!(renderer.format, OutputFormat::Json) {
-                renderer.emit_json(&serde_json::json!({ "jobs": [] }));
-                return Ok(());
-            }
-            renderer.emit_notice("No job logs found (agent logging not enabled).");
-            return Ok(());
+        let filter = AgentJobListFilter {
+            ticket_id: args.ticket.clone(),
+            status: args.status.clone(),
+            runner: args.runner.clone(),
+            limit: args.limit,
+            offset: args.offset,
         };
+        let list = AgentJobService::list_job_history(&resolver.path, &filter)
+            .map_err(|e| e.to_string())?;
 
-        let job_ids =
-            AgentLogService::list_logs(&workspace_root, &logs_dir).map_err(|e| e.to_string())?;
-
-        if job_ids.is_empty() {
-            if matches!(renderer.format, OutputFormat::Json) {
-                renderer.emit_json(&serde_json::json!({ "jobs": [] }));
-                return Ok(());
-            }
-            renderer.emit_notice("No job logs found.");
+        if matches!(renderer.format, OutputFormat::Json) {
+            renderer.emit_json(&list);
             return Ok(());
         }
 
-        let mut jobs = Vec::new();
-        for job_id in job_ids.iter().take(args.limit) {
-            let header = match AgentLogService::load_header(&workspace_root, &logs_dir, job_id) {
-                Ok(Some(h)) => h,
-                _ => continue,
-            };
-            let status = AgentLogService::load_status(&workspace_root, &logs_dir, job_id)
-                .ok()
-                .flatten();
-
-            jobs.push(serde_json::json!({
-                "id": header.job_id,
-                "ticket_id": header.ticket_id,
-                "runner": header.runner,
-                "created_at": header.created_at,
-                "status": status.as_ref().map(|s| s.status.as_str()).unwrap_or("unknown"),
-                "exit_code": status.as_ref().and_then(|s| s.exit_code),
-                "summary": status.as_ref().and_then(|s| s.summary.clone()),
-            }));
-        }
-
-        if matches!(renderer.format, OutputFormat::Json) {
-            renderer.emit_json(&serde_json::json!({ "jobs": jobs }));
+        if list.jobs.is_empty() {
+            let (_, logs_dir) = get_logs_config(resolver);
+            if logs_dir.is_none() {
+                renderer.emit_notice("No job logs found (agent logging not enabled).");
+            } else {
+                renderer.emit_notice("No job logs found.");
+            }
             return Ok(());
         }
 
         renderer.emit_raw_stdout(format_args!("job_id\tticket\tstatus\trunner"));
-        for job in &jobs {
+        for job in &list.jobs {
             renderer.emit_raw_stdout(format_args!(
                 "{}\t{}\t{}\t{}",
-                job["id"].as_str().unwrap_or("-"),
-                job["ticket_id"].as_str().unwrap_or("-"),
-                job["status"].as_str().unwrap_or("-"),
-                job["runner"].as_str().unwrap_or("-")
+                job.id, job.ticket_id, job.status, job.runner
+            ));
+        }
+        let total = list.total.unwrap_or(list.jobs.len());
+        if args.offset + list.jobs.len() < total {
+            renderer.emit_info(format!(
+                "Showing {}-{} of {} jobs (use --offset to page)",
+                args.offset + 1,
+                args.offset + list.jobs.len(),
+                total
             ));
         }
         Ok(())
@@ -399,6 +411,293 @@ impl AgentHandler {
         }
     }
 
+    fn pipeline_list(
+        project: Option<&str>,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Result<(), String> {
+        let cfg_mgr = ConfigManager::new_manager_with_tasks_dir_readonly(&resolver.path)
+            .map_err(|e| e.to_string())?;
+        let config = match project {
+            Some(prefix) => cfg_mgr
+                .get_project_config(prefix)
+                .map_err(|e| e.to_string())?,
+            None => cfg_mgr.get_resolved_config().clone(),
+        };
+        let pipelines = AgentPipelineService::list_definitions(&config);
+        let runs = AgentPipelineService::list_runs();
+
+        if matches!(renderer.format, OutputFormat::Json) {
+            renderer.emit_json(&serde_json::json!({ "pipelines": pipelines, "runs": runs }));
+            return Ok(());
+        }
+
+        if pipelines.is_empty() {
+            renderer.emit_notice("No pipelines configured.");
+            return Ok(());
+        }
+        renderer.emit_raw_stdout(format_args!("name\tstages\tdescription"));
+        for pipeline in &pipelines {
+            renderer.emit_raw_stdout(format_args!(
+                "{}\t{}\t{}",
+                pipeline.name,
+                pipeline.stages.join(" -> "),
+                pipeline.description.as_deref().unwrap_or("-")
+            ));
+        }
+        Ok(())
+    }
+
+    fn pipeline_run(
+        args: AgentPipelineRunArgs,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Result<(), String> {
+        let req = AgentPipelineRunRequest {
+            ticket_id: args.ticket,
+            pipeline: args.pipeline,
+            prompt: args.prompt,
+        };
+        let run = AgentPipelineService::start(req, &resolver.path).map_err(|e| e.to_string())?;
+        let json = matches!(renderer.format, OutputFormat::Json);
+        if !json {
+            renderer.emit_success(format!(
+                "Pipeline '{}' started for {} ({})",
+                run.pipeline, run.ticket_id, run.id
+            ));
+        }
+
+        // Stages run as jobs inside this process, so stay in the foreground until the run ends.
+        let start = Instant::now();
+        let mut reported: Vec<(String, String)> = Vec::new();
+        loop {
+            let run = AgentPipelineService::status(&resolver.path, &run.ticket_id)
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| format!("Pipeline run for '{}' disappeared", run.ticket_id))?;
+            if !json {
+                for stage in &run.stages {
+                    let key = (
+                        stage.name.clone(),
+                        format!("{}#{}", stage.status, stage.runs),
+                    );
+                    if stage.runs > 0 && !reported.contains(&key) {
+                        renderer.emit_info(format!("Stage {}: {}", stage.name, stage.status));
+                        if stage.status == "awaiting_approval" {
+                            renderer.emit_notice(format!(
+                                "Approve with: lotar agent pipeline approve {} --stage {}",
+                                run.ticket_id, stage.name
+                            ));
+                        }
+                        reported.push(key);
+                    }
+                }
+            }
+
+            if matches!(run.status.as_str(), "completed" | "failed" | "cancelled") {
+                if json {
+                    renderer.emit_json(&serde_json::json!({ "run": run }));
+                } else if run.status == "completed" {
+                    renderer.emit_success(format!("Pipeline '{}' completed", run.pipeline));
+                } else {
+                    renderer.emit_error(format!("Pipeline '{}' {}", run.pipeline, run.status));
+                    if let Some(message) = run.message.as_ref() {
+                        renderer.emit_info(message);
+                    }
+                }
+                return if run.status == "completed" {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "Pipeline '{}' ended with status {}",
+                        run.pipeline, run.status
+                    ))
+                };
+            }
+
+            if let Some(timeout) = args.timeout_seconds
+                && start.elapsed() > Duration::from_secs(timeout)
+            {
+                let _ = AgentPipelineService::cancel(&run.ticket_id, &resolver.path, None);
+                return Err(format!(
+                    "Timed out waiting for pipeline '{}'; run cancelled",
+                    run.pipeline
+                ));
+            }
+
+            thread::sleep(Duration::from_millis(500));
+        }
+    }
+
+    fn pipeline_status(
+        ticket: &str,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Result<(), String> {
+        let run = AgentPipelineService::status(&resolver.path, ticket)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("No pipeline run found for '{}'", ticket))?;
+        if matches!(renderer.format, OutputFormat::Json) {
+            renderer.emit_json(&serde_json::json!({ "run": run }));
+            return Ok(());
+        }
+        render_pipeline_run(&run, renderer);
+        Ok(())
+    }
+
+    fn pipeline_decide(
+        args: &AgentPipelineDecisionArgs,
+        approve: bool,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Result<(), String> {
+        let req = AgentPipelineDecisionRequest {
+            ticket_id: args.ticket.clone(),
+            stage: args.stage.clone(),
+            note: args.note.clone(),
+        };
+        let actor = crate::utils::identity::resolve_current_user(Some(resolver.path.as_path()));
+        let outcome = if approve {
+            AgentPipelineService::approve(&req, &resolver.path, actor.as_deref())
+        } else {
+            AgentPipelineService::reject(&req, &resolver.path, actor.as_deref())
+        }
+        .map_err(|e| e.to_string())?;
+
+        if matches!(renderer.format, OutputFormat::Json) {
+            renderer.emit_json(&serde_json::json!({
+                "run": outcome.run,
+                "forwarded": outcome.forwarded,
+            }));
+            return Ok(());
+        }
+        let verb = if approve { "Approved" } else { "Rejected" };
+        if outcome.forwarded {
+            renderer.emit_success(format!(
+                "{} pending stage for {}; the running pipeline will pick it up",
+                verb, args.ticket
+            ));
+        } else {
+            renderer.emit_success(format!("{} pending stage for {}", verb, args.ticket));
+            render_pipeline_run(&outcome.run, renderer);
+        }
+        Ok(())
+    }
+
+    fn pipeline_cancel(
+        ticket: &str,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Result<(), String> {
+        let actor = crate::utils::identity::resolve_current_user(Some(resolver.path.as_path()));
+        let outcome = AgentPipelineService::cancel(ticket, &resolver.path, actor.as_deref())
+            .map_err(|e| e.to_string())?;
+        if matches!(renderer.format, OutputFormat::Json) {
+            renderer.emit_json(&serde_json::json!({
+                "run": outcome.run,
+                "forwarded": outcome.forwarded,
+            }));
+            return Ok(());
+        }
+        if outcome.forwarded {
+            renderer.emit_success(format!(
+                "Cancellation requested for {}; the running pipeline will stop shortly",
+                ticket
+            ));
+        } else {
+            renderer.emit_success(format!("Pipeline run for {} cancelled", ticket));
+        }
+        Ok(())
+    }
+
+    fn approvals(
+        args: AgentApprovalsArgs,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Result<(), String> {
+        match args.action {
+            Some(AgentApprovalsAction::Approve(decision)) => {
+                Self::approval_decide(&decision, true, resolver, renderer)
+            }
+            Some(AgentApprovalsAction::Reject(decision)) => {
+                Self::approval_decide(&decision, false, resolver, renderer)
+            }
+            None => {
+                let status = if args.all { None } else { Some("pending") };
+                let approvals =
+                    AgentApprovalService::list(&resolver.path, status, args.ticket.as_deref())
+                        .map_err(|e| e.to_string())?;
+                if matches!(renderer.format, OutputFormat::Json) {
+                    renderer.emit_json(&serde_json::json!({ "approvals": approvals }));
+                    return Ok(());
+                }
+                if approvals.is_empty() {
+                    renderer.emit_notice("No approvals waiting.");
+                    return Ok(());
+                }
+                renderer.emit_raw_stdout(format_args!("id\tticket\tstatus\tagent\trequest"));
+                for approval in &approvals {
+                    renderer.emit_raw_stdout(format_args!(
+                        "{}\t{}\t{}\t{}\t{}",
+                        approval.id,
+                        approval.ticket_id,
+                        approval.status,
+                        approval.agent.as_deref().unwrap_or("-"),
+                        approval.summary
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn approval_decide(
+        args: &AgentApprovalDecisionArgs,
+        approve: bool,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Result<(), String> {
+        let actor = crate::utils::identity::resolve_current_user(Some(resolver.path.as_path()));
+        let outcome = if approve {
+            AgentApprovalService::approve(
+                &resolver.path,
+                args.id,
+                actor.as_deref(),
+                args.note.as_deref(),
+            )
+        } else {
+            AgentApprovalService::reject(
+                &resolver.path,
+                args.id,
+                actor.as_deref(),
+                args.note.as_deref(),
+            )
+        }
+        .map_err(|e| e.to_string())?;
+
+        if matches!(renderer.format, OutputFormat::Json) {
+            renderer.emit_json(&outcome);
+            return Ok(());
+        }
+        let approval = &outcome.approval;
+        if approve {
+            renderer.emit_success(format!(
+                "Approved #{}: {} on {}",
+                approval.id, approval.summary, approval.ticket_id
+            ));
+        } else {
+            renderer.emit_success(format!(
+                "Rejected #{}: {} on {}",
+                approval.id, approval.summary, approval.ticket_id
+            ));
+            if !outcome.notified {
+                renderer.emit_notice(
+                    "The requesting agent job is no longer running; it was not notified.",
+                );
+            }
+        }
+        Ok(())
+    }
+
     fn worker(_args: AgentWorkerArgs, resolver: &TasksDirectoryResolver) -> Result<(), String> {
         AgentJobService::set_orchestrator_mode(
             crate::services::agent_job_service::AgentOrchestratorMode::Worker,
@@ -529,18 +828,6 @@ fn derive_default_statuses(
     Ok(statuses)
 }
 
-/// Information about an agent worktree
-#[derive(Debug, Clone, Serialize)]
-struct WorktreeInfo {
-    path: String,
-    branch: String,
-    ticket_id: String,
-    /// Whether the ticket is in a "done" state
-    is_done: bool,
-    /// Whether there's an active job for this ticket
-    has_active_job: bool,
-}
-
 impl AgentHandler {
     fn worktree_list(
         resolver: &TasksDirectoryResolver,
@@ -550,7 +837,7 @@ impl AgentHandler {
             .map_err(|e| e.to_string())?;
         let config = config_manager.get_resolved_config();
 
-        let worktrees = list_agent_worktrees(resolver, config)?;
+        let worktrees = list_agent_worktrees(&resolver.path, config)?;
 
         if matches!(renderer.format, OutputFormat::Json) {
             renderer.emit_json(&serde_json::json!({ "worktrees": worktrees }));
@@ -588,7 +875,7 @@ impl AgentHandler {
             .map_err(|e| e.to_string())?;
         let config = config_manager.get_resolved_config();
 
-        let worktrees = list_agent_worktrees(resolver, config)?;
+        let worktrees = list_agent_worktrees(&resolver.path, config)?;
 
         // Filter to worktrees that should be removed
         let to_remove: Vec<_> = worktrees
@@ -701,116 +988,6 @@ impl AgentHandler {
     }
 }
 
-/// List all agent worktrees
-fn list_agent_worktrees(
-    resolver: &TasksDirectoryResolver,
-    config: &crate::config::types::ResolvedConfig,
-) -> Result<Vec<WorktreeInfo>, String> {
-    let repo_root = match crate::utils::git::find_repo_root(&resolver.path) {
-        Some(root) => root,
-        None => return Ok(Vec::new()),
-    };
-
-    let branch_prefix = &config.agent_worktree.branch_prefix;
-
-    // Get list of worktrees from git
-    let output = std::process::Command::new("git")
-        .args(["worktree", "list", "--porcelain"])
-        .current_dir(&repo_root)
-        .output()
-        .map_err(|e| format!("Failed to run git worktree list: {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("git worktree list failed: {}", stderr.trim()));
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let worktrees = parse_git_worktree_list(&stdout, branch_prefix);
-
-    // Enrich with task status information
-    let storage = crate::storage::manager::Storage::new(&resolver.path.clone());
-
-    let mut results = Vec::new();
-    for (path, branch) in worktrees {
-        let ticket_id = extract_ticket_from_branch(&branch, branch_prefix);
-        let is_done = check_ticket_done(&storage, &ticket_id);
-        let has_active_job = AgentJobService::has_active_job(&ticket_id);
-
-        results.push(WorktreeInfo {
-            path,
-            branch,
-            ticket_id,
-            is_done,
-            has_active_job,
-        });
-    }
-
-    Ok(results)
-}
-
-/// Parse git worktree list --porcelain output
-fn parse_git_worktree_list(output: &str, branch_prefix: &str) -> Vec<(String, String)> {
-    let mut results = Vec::new();
-    let mut current_path: Option<String> = None;
-    let mut current_branch: Option<String> = None;
-
-    for line in output.lines() {
-        if let Some(path) = line.strip_prefix("worktree ") {
-            // Flush previous worktree if any
-            if let (Some(path), Some(branch)) = (current_path.take(), current_branch.take())
-                && branch.starts_with(branch_prefix)
-            {
-                results.push((path, branch));
-            }
-            current_path = Some(path.to_string());
-        } else if let Some(branch) = line.strip_prefix("branch refs/heads/") {
-            current_branch = Some(branch.to_string());
-        } else if line.is_empty() {
-            // End of worktree block - flush
-            if let (Some(path), Some(branch)) = (current_path.take(), current_branch.take())
-                && branch.starts_with(branch_prefix)
-            {
-                results.push((path, branch));
-            }
-        }
-    }
-
-    // Flush last entry
-    if let (Some(path), Some(branch)) = (current_path, current_branch)
-        && branch.starts_with(branch_prefix)
-    {
-        results.push((path, branch));
-    }
-
-    results
-}
-
-/// Extract ticket ID from branch name
-fn extract_ticket_from_branch(branch: &str, prefix: &str) -> String {
-    branch
-        .strip_prefix(prefix)
-        .unwrap_or(branch)
-        .trim_start_matches('/')
-        .to_string()
-}
-
-/// Check if a ticket is in a "done" state
-fn check_ticket_done(storage: &crate::storage::manager::Storage, ticket_id: &str) -> bool {
-    // Derive project prefix from ID (e.g., ABCD-1 -> ABCD)
-    let derived = ticket_id.split('-').next().unwrap_or("");
-    match storage.get(ticket_id, derived) {
-        Some(task) => {
-            let status_lower = task.status.as_str().to_lowercase();
-            status_lower == "done" || status_lower == "closed" || status_lower == "completed"
-        }
-        None => {
-            // Task not found - consider it "done" for cleanup purposes
-            true
-        }
-    }
-}
-
 #[derive(Debug, Clone, Serialize)]
 pub(crate) struct RunningAgentJob {
     pub pid: u32,
@@ -917,6 +1094,26 @@ fn wait_for_job_completion(
     }
 }
 
+fn render_pipeline_run(run: &AgentPipelineRun, renderer: &OutputRenderer) {
+    renderer.emit_info(format!(
+        "Pipeline '{}' for {}: {}",
+        run.pipeline, run.ticket_id, run.status
+    ));
+    if let Some(message) = run.message.as_ref() {
+        renderer.emit_info(message);
+    }
+    renderer.emit_raw_stdout(format_args!("stage\tstatus\truns\tjob"));
+    for stage in &run.stages {
+        renderer.emit_raw_stdout(format_args!(
+            "{}\t{}\t{}\t{}",
+            stage.name,
+            stage.status,
+            stage.runs,
+            stage.job_id.as_deref().unwrap_or("-")
+        ));
+    }
+}
+
 fn is_terminal_status(status: &str) -> bool {
     status.eq_ignore_ascii_case("completed")
         || status.eq_ignore_ascii_case("failed")
@@ -991,7 +1188,7 @@ fn parse_wrapper_metadata(cmd: &[String]) -> (Option<String>, Option<String>, Op
 
 #[cfg(test)]
 mod tests {
-    use super::{extract_ticket_from_branch, parse_git_worktree_list, parse_wrapper_metadata};
+    use super::parse_wrapper_metadata;
 
     #[test]
     fn parses_wrapper_metadata() {
@@ -1012,61 +1209,4 @@ mod tests {
         assert_eq!(ticket_id.as_deref(), Some("TEST-1"));
         assert_eq!(runner.as_deref(), Some("codex"));
     }
-
-    #[test]
-    fn parses_git_worktree_list_output() {
-        let output = r#"worktree /Users/test/repo
-HEAD abc123
-branch refs/heads/main
-
-worktree /Users/test/.lotar-worktrees/repo/TEST-1
-HEAD def456
-branch refs/heads/agent/TEST-1
-
-worktree /Users/test/.lotar-worktrees/repo/TEST-2
-HEAD ghi789
-branch refs/heads/agent/TEST-2
-
-"#;
-        let results = parse_git_worktree_list(output, "agent/");
-        assert_eq!(results.len(), 2);
-        assert_eq!(results[0].0, "/Users/test/.lotar-worktrees/repo/TEST-1");
-        assert_eq!(results[0].1, "agent/TEST-1");
-        assert_eq!(results[1].0, "/Users/test/.lotar-worktrees/repo/TEST-2");
-        assert_eq!(results[1].1, "agent/TEST-2");
-    }
-
-    #[test]
-    fn parses_git_worktree_list_with_custom_prefix() {
-        let output = r#"worktree /Users/test/repo
-HEAD abc123
-branch refs/heads/main
-
-worktree /Users/test/.lotar-worktrees/repo/TEST-1
-HEAD def456
-branch refs/heads/my-agent/TEST-1
-
-"#;
-        let results = parse_git_worktree_list(output, "my-agent/");
-        assert_eq!(results.len(), 1);
-        assert_eq!(results[0].1, "my-agent/TEST-1");
-    }
-
-    #[test]
-    fn extracts_ticket_from_branch() {
-        assert_eq!(
-            extract_ticket_from_branch("agent/TEST-1", "agent/"),
-            "TEST-1"
-        );
-        assert_eq!(
-            extract_ticket_from_branch("agent/PROJ-42", "agent/"),
-            "PROJ-42"
-        );
-        assert_eq!(
-            extract_ticket_from_branch("custom-prefix/ABC-1", "custom-prefix/"),
-            "ABC-1"
-        );
-        // Edge case: no match falls back to full branch name
-        assert_eq!(extract_ticket_from_branch("main", "agent/"), "main");
-    }
 }
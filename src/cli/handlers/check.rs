@@ -0,0 +1,183 @@
+use crate::cli::CheckArgs;
+use crate::cli::args::{CheckFailOn, CheckReport};
+use crate::cli::handlers::CommandHandler;
+use crate::output::{OutputFormat, OutputRenderer};
+use crate::services::check_service::{
+    CheckDiff, CheckFinding, CheckRequest, CheckRule, CheckService,
+};
+use crate::workspace::TasksDirectoryResolver;
+use serde_json::json;
+use std::collections::HashSet;
+
+/// Handler for `lotar check`: fail CI on TODOs that are not tracked by a live ticket.
+pub struct CheckHandler;
+
+impl CommandHandler for CheckHandler {
+    type Args = CheckArgs;
+    type Result = Result<(), String>;
+
+    fn execute(
+        args: Self::Args,
+        project: Option<&str>,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Self::Result {
+        let effective_project = crate::cli::project::ProjectResolver::new(resolver)
+            .ok()
+            .and_then(|mut r| r.resolve_project("", project).ok())
+            .filter(|p| !p.is_empty());
+
+        let diff = match (args.base, args.staged) {
+            (Some(base), _) => Some(CheckDiff::Base(base)),
+            (None, true) => Some(CheckDiff::Staged),
+            (None, false) => None,
+        };
+        let request = CheckRequest {
+            paths: args.paths,
+            include: args.include,
+            exclude: args.exclude,
+            modified_only: args.modified_only,
+            diff,
+            project: effective_project,
+        };
+        let findings = CheckService::run(resolver, &request).map_err(|e| e.to_string())?;
+
+        let failing: HashSet<CheckRule> = args
+            .fail_on
+            .iter()
+            .filter_map(|rule| match rule {
+                CheckFailOn::Unlinked => Some(CheckRule::Unlinked),
+                CheckFailOn::Unknown => Some(CheckRule::Unknown),
+                CheckFailOn::Done => Some(CheckRule::Done),
+                CheckFailOn::None => None,
+            })
+            .collect();
+        let failing = if args.fail_on.contains(&CheckFailOn::None) {
+            HashSet::new()
+        } else {
+            failing
+        };
+        let violations = findings
+            .iter()
+            .filter(|f| failing.contains(&f.rule))
+            .count();
+
+        match args.report {
+            CheckReport::Sarif => {
+                renderer.emit_json(&sarif_report(&findings, &failing));
+            }
+            CheckReport::Github => {
+                for finding in &findings {
+                    renderer.emit_raw_stdout(github_annotation(
+                        finding,
+                        failing.contains(&finding.rule),
+                    ));
+                }
+            }
+            CheckReport::Text if matches!(renderer.format, OutputFormat::Json) => {
+                renderer.emit_json(&json!({
+                    "status": if violations == 0 { "ok" } else { "failed" },
+                    "violations": violations,
+                    "findings": findings,
+                }));
+            }
+            CheckReport::Text => {
+                for finding in &findings {
+                    let marker = if failing.contains(&finding.rule) {
+                        "❌"
+                    } else {
+                        "⚠️ "
+                    };
+                    renderer.emit_raw_stdout(format_args!(
+                        "{} {}:{} [{}] {}",
+                        marker,
+                        finding.file,
+                        finding.line,
+                        finding.rule.id(),
+                        finding.message
+                    ));
+                }
+                if findings.is_empty() {
+                    renderer.emit_success("No unlinked TODOs or stale ticket references found");
+                } else if violations == 0 {
+                    renderer.emit_info(format_args!(
+                        "{} finding(s); none are enforced by --fail-on",
+                        findings.len()
+                    ));
+                }
+            }
+        }
+
+        if violations > 0 {
+            return Err(format!(
+                "Check failed: {} TODO finding(s) violate the ticket policy",
+                violations
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn sarif_report(findings: &[CheckFinding], failing: &HashSet<CheckRule>) -> serde_json::Value {
+    let rules: Vec<_> = CheckRule::ALL
+        .iter()
+        .map(|rule| {
+            json!({
+                "id": rule.id(),
+                "shortDescription": { "text": rule.description() },
+            })
+        })
+        .collect();
+    let results: Vec<_> = findings
+        .iter()
+        .map(|finding| {
+            json!({
+                "ruleId": finding.rule.id(),
+                "level": if failing.contains(&finding.rule) { "error" } else { "warning" },
+                "message": { "text": finding.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": finding.file },
+                        "region": { "startLine": finding.line },
+                    }
+                }],
+            })
+        })
+        .collect();
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "lotar",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }],
+    })
+}
+
+/// Render a GitHub Actions workflow command (`::error file=..,line=..::message`).
+fn github_annotation(finding: &CheckFinding, failing: bool) -> String {
+    fn escape_data(value: &str) -> String {
+        value
+            .replace('%', "%25")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+    }
+    fn escape_property(value: &str) -> String {
+        escape_data(value).replace(':', "%3A").replace(',', "%2C")
+    }
+
+    format!(
+        "::{} file={},line={},title={}::{}",
+        if failing { "error" } else { "warning" },
+        escape_property(&finding.file),
+        finding.line,
+        escape_property(finding.rule.id()),
+        escape_data(&finding.message)
+    )
+}
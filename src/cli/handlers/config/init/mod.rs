@@ -489,6 +489,10 @@ fn copy_from_project(
         .issue_priorities
         .clone()
         .or(target.issue_priorities.take());
+    target.issue_templates = parsed
+        .issue_templates
+        .clone()
+        .or(target.issue_templates.take());
     target.tags = parsed.tags.clone().or(target.tags.take());
     target.default_assignee = parsed
         .default_assignee
@@ -508,6 +512,10 @@ fn copy_from_project(
         .or(target.default_status.take());
     target.default_tags = parsed.default_tags.clone().or(target.default_tags.take());
     target.members = parsed.members.clone().or(target.members.take());
+    target.member_profiles = parsed
+        .member_profiles
+        .clone()
+        .or(target.member_profiles.take());
     target.custom_fields = parsed.custom_fields.clone().or(target.custom_fields.take());
     Ok(())
 }
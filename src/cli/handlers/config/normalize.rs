@@ -64,7 +64,7 @@ impl ConfigHandler {
             };
 
             if write {
-                std::fs::write(&path, canonical.as_bytes())
+                crate::utils::filesystem::write_yaml_preserving_modeline(&path, &canonical)
                     .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
                 renderer.emit_success(format_args!("Normalized {} -> {}", label, path.display()));
                 changed += 1;
@@ -13,7 +13,10 @@ pub(super) struct YamlRenderOptions<'a> {
 
 impl<'a> YamlRenderOptions<'a> {
     pub(super) fn allows(&self, source: Option<&String>) -> bool {
-        let label = source.map(|s| s.as_str()).unwrap_or("default");
+        let label = match source.map(|s| s.as_str()).unwrap_or("default") {
+            inherited if inherited.starts_with("inherited") => "inherited",
+            label => label,
+        };
         if !self.include_defaults && label == "default" {
             return false;
         }
@@ -177,11 +180,10 @@ fn render_resolved_config_yaml(
     }
 
     let mut members_body = String::new();
-    if write_sequence(
+    if write_members(
         &mut members_body,
-        0,
-        "members",
         &resolved.members,
+        &resolved.member_profiles,
         sources.get("members"),
         options,
     ) {
@@ -222,6 +224,25 @@ fn render_resolved_config_yaml(
         sources.get("issue.tags"),
         options,
     );
+    // Templates render one inline mapping per task type.
+    let templates: Vec<(String, String)> = resolved
+        .issue_templates
+        .iter()
+        .map(|(task_type, template)| {
+            (
+                task_type.clone(),
+                serde_json::to_string(template).unwrap_or_else(|_| "{}".to_string()),
+            )
+        })
+        .collect();
+    issue_written |= write_mapping_entries(
+        &mut issue_body,
+        2,
+        "templates",
+        &templates,
+        sources.get("issue.templates"),
+        options,
+    );
     if issue_written {
         sections.push(format!("issue:\n{}", issue_body));
     }
@@ -409,6 +430,9 @@ fn render_resolved_config_yaml(
         || resolved.sprint_defaults.capacity_hours.is_some()
         || resolved.sprint_defaults.length.is_some()
         || resolved.sprint_defaults.overdue_after.is_some()
+        || resolved.sprint_defaults.weekly_hours.is_some()
+        || !resolved.sprint_defaults.member_weekly_hours.is_empty()
+        || resolved.sprint_defaults.rollover.is_some()
     {
         let sprint_defaults_source = resolve_section_source(
             sources,
@@ -417,6 +441,9 @@ fn render_resolved_config_yaml(
                 "sprints.defaults.capacity_hours",
                 "sprints.defaults.length",
                 "sprints.defaults.overdue_after",
+                "sprints.defaults.weekly_hours",
+                "sprints.defaults.member_weekly_hours",
+                "sprints.defaults.rollover",
             ],
         );
         sprint_written |= write_scalar_line(
@@ -561,6 +588,33 @@ fn write_sequence<T: Serialize>(
     true
 }
 
+/// Members render as a plain sequence; entries with a profile use an inline mapping.
+fn write_members(
+    buf: &mut String,
+    members: &[String],
+    profiles: &[crate::config::types::MemberProfile],
+    source: Option<&String>,
+    options: &YamlRenderOptions,
+) -> bool {
+    if profiles.is_empty() {
+        return write_sequence(buf, 0, "members", members, source, options);
+    }
+    if !options.allows(source) {
+        return false;
+    }
+    let comment = yaml_comment(source, options);
+    let _ = writeln!(buf, "members:{comment}");
+    for member in members {
+        let formatted = profiles
+            .iter()
+            .find(|profile| profile.handle.eq_ignore_ascii_case(member))
+            .and_then(|profile| serde_json::to_string(profile).ok())
+            .unwrap_or_else(|| yaml_scalar(member));
+        let _ = writeln!(buf, "  - {formatted}");
+    }
+    true
+}
+
 fn write_mapping_entries(
     buf: &mut String,
     indent: usize,
@@ -622,8 +676,9 @@ fn resolve_section_source<'a>(
 
 fn scope_rank(label: &str) -> i32 {
     match label {
-        "project" => 3,
-        "global" | "home" | "env" => 2,
+        "project" => 4,
+        "global" | "home" | "env" => 3,
+        _ if label.starts_with("inherited") => 2,
         "default" | "built_in" => 1,
         _ => 0,
     }
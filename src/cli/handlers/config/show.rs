@@ -71,6 +71,13 @@ impl ConfigHandler {
             let global_cfg =
                 crate::config::persistence::load_global_config(Some(&effective_read_root)).ok();
             let home_cfg = crate::config::persistence::load_home_config().ok();
+            let inherited = global_cfg
+                .as_ref()
+                .and_then(|cfg| {
+                    crate::config::inheritance::load_inherited_layers(&effective_read_root, cfg)
+                        .ok()
+                })
+                .unwrap_or_default();
             let base_config = config_manager.get_resolved_config().clone();
             let project_sources = build_project_source_labels(
                 &resolved_project,
@@ -78,6 +85,7 @@ impl ConfigHandler {
                 project_cfg_raw.as_ref(),
                 &global_cfg,
                 &home_cfg,
+                &inherited,
             );
 
             const PROJECT_ONLY_SOURCES: &[&str] = &["project"];
@@ -144,9 +152,17 @@ impl ConfigHandler {
             let global_cfg =
                 crate::config::persistence::load_global_config(Some(&effective_read_root)).ok();
             let home_cfg = crate::config::persistence::load_home_config().ok();
-            let sources = build_global_source_labels(resolved_config, &global_cfg, &home_cfg);
-
-            const GLOBAL_SOURCES: &[&str] = &["env", "home", "global"];
+            let inherited = global_cfg
+                .as_ref()
+                .and_then(|cfg| {
+                    crate::config::inheritance::load_inherited_layers(&effective_read_root, cfg)
+                        .ok()
+                })
+                .unwrap_or_default();
+            let sources =
+                build_global_source_labels(resolved_config, &global_cfg, &home_cfg, &inherited);
+
+            const GLOBAL_SOURCES: &[&str] = &["env", "home", "global", "inherited"];
             let options = YamlRenderOptions {
                 include_defaults: full,
                 include_comments: explain,
@@ -164,6 +180,13 @@ impl ConfigHandler {
                     renderer.emit_info(format!("Attachments root: {}", root.display()));
                 }
                 renderer.emit_info(format!("Attachment uploads: {uploads_mode}"));
+                if !inherited.is_empty() {
+                    let chain: Vec<&str> = inherited
+                        .iter()
+                        .map(|layer| layer.source.as_str())
+                        .collect();
+                    renderer.emit_info(format!("Inherits: {}", chain.join(", ")));
+                }
             }
 
             let mut json_meta = serde_json::json!({
@@ -0,0 +1,78 @@
+use crate::cli::args::DoctorArgs;
+use crate::cli::handlers::CommandHandler;
+use crate::output::{OutputFormat, OutputRenderer};
+use crate::services::doctor_service::{DoctorOptions, DoctorReport, DoctorService};
+use crate::workspace::TasksDirectoryResolver;
+
+/// Handler for `lotar doctor`: report (and optionally repair) integrity problems in the store.
+pub struct DoctorHandler;
+
+impl CommandHandler for DoctorHandler {
+    type Args = DoctorArgs;
+    type Result = Result<(), String>;
+
+    fn execute(
+        args: Self::Args,
+        _project: Option<&str>,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Self::Result {
+        let options = DoctorOptions { fix: args.fix };
+        let report = DoctorService::run(&resolver.path, &options).map_err(|e| e.to_string())?;
+
+        match renderer.format {
+            OutputFormat::Json => {
+                let mut value = serde_json::to_value(&report).unwrap_or_default();
+                if let Some(map) = value.as_object_mut() {
+                    let status = if report.unresolved() == 0 {
+                        "ok"
+                    } else {
+                        "failed"
+                    };
+                    map.insert("status".into(), status.into());
+                    map.insert("action".into(), "doctor".into());
+                    map.insert("fixed".into(), report.fixed().into());
+                    map.insert("unresolved".into(), report.unresolved().into());
+                }
+                renderer.emit_json(&value);
+            }
+            _ => render_text(&report, args.fix, renderer),
+        }
+
+        match report.unresolved() {
+            0 => Ok(()),
+            n => Err(format!("Doctor found {} unresolved problem(s)", n)),
+        }
+    }
+}
+
+fn render_text(report: &DoctorReport, fix: bool, renderer: &OutputRenderer) {
+    for finding in &report.findings {
+        let marker = if finding.fixed { "✅" } else { "❌" };
+        let subject = finding.task_id.as_deref().unwrap_or(&finding.path);
+        renderer.emit_raw_stdout(format_args!(
+            "{} [{}] {}: {}",
+            marker,
+            finding.check.as_str(),
+            subject,
+            finding.message
+        ));
+    }
+
+    if report.findings.is_empty() {
+        renderer.emit_success(format_args!(
+            "No integrity problems found ({} task(s) scanned)",
+            report.tasks_scanned
+        ));
+        return;
+    }
+    if report.fixed() > 0 {
+        renderer.emit_success(format_args!("Repaired {} problem(s)", report.fixed()));
+    }
+    if !fix && report.fixable() > 0 {
+        renderer.emit_info(format_args!(
+            "{} problem(s) can be repaired with `lotar doctor --fix`",
+            report.fixable()
+        ));
+    }
+}
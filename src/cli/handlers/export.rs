@@ -0,0 +1,103 @@
+use crate::cli::args::{ExportAction, ExportArgs, ExportIcalArgs};
+use crate::cli::handlers::CommandHandler;
+use crate::config::manager::ConfigManager;
+use crate::output::{OutputFormat, OutputRenderer};
+use crate::services::ical_service::{self, CalendarOptions};
+use crate::storage::manager::Storage;
+use crate::workspace::TasksDirectoryResolver;
+use serde_json::json;
+use std::io::Write;
+
+/// Handler for `lotar export`.
+pub struct ExportHandler;
+
+impl CommandHandler for ExportHandler {
+    type Args = ExportArgs;
+    type Result = Result<(), String>;
+
+    fn execute(
+        args: Self::Args,
+        project: Option<&str>,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Self::Result {
+        match args.action {
+            ExportAction::Ical(ical) => export_ical(ical, project, resolver, renderer),
+        }
+    }
+}
+
+fn export_ical(
+    args: ExportIcalArgs,
+    project: Option<&str>,
+    resolver: &TasksDirectoryResolver,
+    renderer: &OutputRenderer,
+) -> Result<(), String> {
+    let manager = ConfigManager::new_manager_with_tasks_dir_readonly(&resolver.path)
+        .map_err(|e| format!("Failed to load config: {}", e))?;
+    let project = if args.all_projects {
+        None
+    } else {
+        Some(match project {
+            Some(raw) => crate::utils::resolve_project_input(raw, resolver.path.as_path()),
+            None if !manager.get_resolved_config().default_project.is_empty() => {
+                manager.get_resolved_config().default_project.clone()
+            }
+            None => crate::project::get_effective_project_name(resolver),
+        })
+        .filter(|name| !name.is_empty())
+    };
+    let config = match project.as_deref() {
+        Some(name) => manager
+            .get_project_config(name)
+            .map_err(|e| format!("Failed to load config: {}", e))?,
+        None => manager.get_resolved_config().clone(),
+    };
+    let assignee = match args.assignee.as_deref() {
+        Some(raw) => Some(
+            crate::utils::identity::resolve_me_alias(raw, Some(resolver.path.as_path()))
+                .ok_or_else(|| {
+                    "Could not resolve @me; set default.reporter or git user.name".to_string()
+                })?,
+        ),
+        None => None,
+    };
+
+    let options = CalendarOptions {
+        project,
+        assignee,
+        tags: args.tags,
+        include_tasks: !args.no_tasks,
+        include_sprints: !args.no_sprints,
+        include_done: args.include_done,
+        todos: args.todo,
+    };
+    let storage = Storage::new(&resolver.path);
+    let calendar = ical_service::export_calendar(&storage, &config, &options, chrono::Utc::now());
+
+    let Some(path) = args.output else {
+        let mut stdout = std::io::stdout();
+        return stdout
+            .write_all(calendar.body.as_bytes())
+            .and_then(|_| stdout.flush())
+            .map_err(|e| format!("Failed to write calendar: {}", e));
+    };
+    std::fs::write(&path, calendar.body.as_bytes())
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    match renderer.format {
+        OutputFormat::Json => renderer.emit_json(&json!({
+            "status": "ok",
+            "action": "export.ical",
+            "path": path.display().to_string(),
+            "tasks": calendar.tasks,
+            "sprints": calendar.sprints,
+        })),
+        _ => renderer.emit_success(format_args!(
+            "Wrote {} task(s) and {} sprint(s) to {}",
+            calendar.tasks,
+            calendar.sprints,
+            path.display()
+        )),
+    }
+    Ok(())
+}
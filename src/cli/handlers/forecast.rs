@@ -0,0 +1,126 @@
+use crate::cli::args::ForecastArgs;
+use crate::cli::handlers::CommandHandler;
+use crate::cli::handlers::sprint::{build_selection_from_args, load_resolved_config};
+use crate::output::{OutputFormat, OutputRenderer};
+use crate::services::forecast_service::{self, ForecastOptions, ForecastReport, ForecastScope};
+use crate::workspace::TasksDirectoryResolver;
+
+/// Handler for `lotar forecast`: Monte Carlo completion dates for a set of tasks.
+pub struct ForecastHandler;
+
+impl CommandHandler for ForecastHandler {
+    type Args = ForecastArgs;
+    type Result = Result<(), String>;
+
+    fn execute(
+        args: Self::Args,
+        project: Option<&str>,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Self::Result {
+        let config = load_resolved_config(resolver)?;
+        let mut selection =
+            build_selection_from_args(&args.select, resolver, &config)?.unwrap_or_default();
+        // An epic may span projects; otherwise default to the current project.
+        if selection.filter.project.is_none() && (args.epic.is_none() || project.is_some()) {
+            selection.filter.project = Some(match project {
+                Some(raw) => crate::utils::resolve_project_input(raw, resolver.path.as_path()),
+                None if !config.default_project.is_empty() => config.default_project.clone(),
+                None => crate::project::get_effective_project_name(resolver),
+            });
+        }
+
+        let options = ForecastOptions {
+            basis: args.basis,
+            metric: args.metric,
+            history: args.history,
+            iterations: args.iterations,
+            target: args
+                .target
+                .as_deref()
+                .map(forecast_service::parse_target)
+                .transpose()?,
+            seed: args.seed,
+        };
+        let scope = ForecastScope {
+            selection,
+            epic: args.epic,
+            sprint: args.sprint,
+        };
+        let repo_root = std::env::current_dir()
+            .ok()
+            .and_then(|cwd| crate::utils::git::find_repo_root(&cwd));
+        let report = forecast_service::run(
+            resolver,
+            repo_root.as_deref(),
+            &scope,
+            &options,
+            chrono::Utc::now(),
+        )?;
+
+        match renderer.format {
+            OutputFormat::Json => {
+                let mut value = serde_json::to_value(&report).unwrap_or_default();
+                if let Some(map) = value.as_object_mut() {
+                    map.insert("status".into(), "ok".into());
+                    map.insert("action".into(), "forecast".into());
+                }
+                renderer.emit_json(&value);
+            }
+            _ => render_text(&report, renderer),
+        }
+        Ok(())
+    }
+}
+
+fn render_text(report: &ForecastReport, renderer: &OutputRenderer) {
+    let unit = report.basis.as_str();
+    renderer.emit_raw_stdout(format_args!(
+        "Forecast for {} open task(s) ({} {} remaining) from {} {}(s) of history, {} runs",
+        report.remaining_tasks,
+        format_amount(report.remaining),
+        report.metric,
+        report.history.len(),
+        unit,
+        report.iterations
+    ));
+    if report.remaining_tasks == 0 {
+        renderer.emit_success("Nothing left to do.");
+        return;
+    }
+    renderer.emit_raw_stdout(format_args!(
+        "  history: {} {} per {}",
+        report
+            .history
+            .iter()
+            .map(|value| format_amount(*value))
+            .collect::<Vec<_>>()
+            .join(", "),
+        report.metric,
+        unit
+    ));
+    for entry in &report.percentiles {
+        renderer.emit_raw_stdout(format_args!(
+            "  {:>3}%  {}  ({} {}(s))",
+            entry.percentile, entry.date, entry.periods, unit
+        ));
+    }
+    if let Some(target) = report.target.as_ref() {
+        renderer.emit_raw_stdout(format_args!(
+            "  Target {}: {:.0}% likely",
+            target.date,
+            target.probability * 100.0
+        ));
+    }
+    for warning in &report.warnings {
+        renderer.emit_warning(warning);
+    }
+}
+
+fn format_amount(value: f64) -> String {
+    if value.fract().abs() < f64::EPSILON {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.1}", value)
+    }
+}
@@ -1,6 +1,8 @@
+use crate::cli::args::{GitCommitMsgArgs, GitIngestArgs, GitInstallMergeDriverArgs};
 use crate::cli::handlers::{CommandHandler, emit_subcommand_overview};
 use crate::cli::{GitAction, GitHooksAction, GitHooksInstallArgs};
 use crate::output::{OutputFormat, OutputRenderer};
+use crate::services::commit_ingest_service::{CommitIngestEntry, CommitIngestService, CommitRange};
 use crate::utils::git::find_repo_root;
 use crate::workspace::TasksDirectoryResolver;
 use serde_json::json;
@@ -18,7 +20,7 @@ impl CommandHandler for GitHandler {
     fn execute(
         args: Self::Args,
         _project: Option<&str>,
-        _resolver: &TasksDirectoryResolver,
+        resolver: &TasksDirectoryResolver,
         renderer: &OutputRenderer,
     ) -> Self::Result {
         let Some(action) = args else {
@@ -39,11 +41,121 @@ impl CommandHandler for GitHandler {
                     }
                 }
             }
+            GitAction::Ingest(ingest_args) => Self::handle_ingest(ingest_args, resolver, renderer),
+            GitAction::CommitMsg(msg_args) => {
+                Self::handle_commit_msg(&msg_args, resolver, renderer)
+            }
+            GitAction::InstallMergeDriver(install_args) => {
+                Self::handle_install_merge_driver(&install_args, resolver, renderer)
+            }
         }
     }
 }
 
 impl GitHandler {
+    fn handle_ingest(
+        args: GitIngestArgs,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Result<(), String> {
+        let cwd = std::env::current_dir()
+            .map_err(|err| format!("Failed to determine current directory: {err}"))?;
+        let repo_root = find_repo_root(&cwd).ok_or_else(|| {
+            "Git repository not found. Run this command inside the repository to ingest."
+                .to_string()
+        })?;
+
+        let range = match (args.since, args.commit.is_empty()) {
+            (Some(since), _) => CommitRange::Since(since),
+            (None, false) => CommitRange::Revisions(args.commit),
+            (None, true) => CommitRange::All,
+        };
+        let commits =
+            CommitIngestService::list_commits(&repo_root, &range).map_err(|e| e.to_string())?;
+        let entries = CommitIngestService::ingest(resolver, &commits, args.dry_run)
+            .map_err(|e| e.to_string())?;
+
+        let linked = entries.iter().filter(|e| e.outcome == "linked").count();
+        if matches!(renderer.format, OutputFormat::Json) {
+            renderer.emit_json(&json!({
+                "status": "success",
+                "action": "git_ingest",
+                "dry_run": args.dry_run,
+                "commits": commits.len(),
+                "linked": linked,
+                "entries": entries,
+            }));
+            return Ok(());
+        }
+
+        for entry in &entries {
+            renderer.emit_raw_stdout(format_args!(
+                "{} {} {}",
+                short_sha(&entry.commit),
+                entry.task_id,
+                describe_entry(entry, args.dry_run)
+            ));
+        }
+        let summary = format!(
+            "{} {} commit reference(s) from {} commit(s)",
+            if args.dry_run { "Would link" } else { "Linked" },
+            linked,
+            commits.len()
+        );
+        if args.dry_run {
+            renderer.emit_notice(&summary);
+        } else {
+            renderer.emit_success(&summary);
+        }
+        Ok(())
+    }
+
+    fn handle_commit_msg(
+        args: &GitCommitMsgArgs,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Result<(), String> {
+        let message = fs::read_to_string(&args.file)
+            .map_err(|err| format!("Failed to read commit message '{}': {err}", args.file))?;
+        let entries = CommitIngestService::preview_message(resolver, &message);
+        let unknown: Vec<&str> = entries
+            .iter()
+            .filter(|e| e.outcome == "unknown_task")
+            .map(|e| e.task_id.as_str())
+            .collect();
+
+        if matches!(renderer.format, OutputFormat::Json) {
+            renderer.emit_json(&json!({
+                "status": if args.strict && !unknown.is_empty() { "failed" } else { "ok" },
+                "action": "git_commit_msg",
+                "entries": entries,
+            }));
+        } else {
+            for entry in entries.iter().filter(|e| e.status.is_some()) {
+                renderer.emit_info(format_args!(
+                    "{} will move to {} ('{}')",
+                    entry.task_id,
+                    entry.status.as_deref().unwrap_or_default(),
+                    entry.keyword.as_deref().unwrap_or_default()
+                ));
+            }
+            for key in &unknown {
+                renderer.emit_warning(format_args!(
+                    "Commit message references unknown ticket {}",
+                    key
+                ));
+            }
+        }
+
+        if args.strict && !unknown.is_empty() {
+            return Err(format!(
+                "Commit message references unknown ticket(s): {}",
+                unknown.join(", ")
+            ));
+        }
+        Ok(())
+    }
+
     fn handle_hooks_install(
         args: &GitHooksInstallArgs,
         renderer: &OutputRenderer,
@@ -71,7 +183,7 @@ impl GitHandler {
             ));
         }
 
-        let current_path = Self::read_hooks_path(&repo_root)?;
+        let current_path = Self::read_local_config(&repo_root, "core.hooksPath")?;
         let desired_path = ".githooks";
         let script_count = scripts.len();
 
@@ -109,7 +221,7 @@ impl GitHandler {
         }
 
         Self::ensure_executable(&scripts)?;
-        Self::write_hooks_path(&repo_root, desired_path)?;
+        Self::write_local_config(&repo_root, "core.hooksPath", desired_path)?;
         Self::emit_summary(
             renderer,
             desired_path,
@@ -120,6 +232,82 @@ impl GitHandler {
         Ok(())
     }
 
+    fn handle_install_merge_driver(
+        args: &GitInstallMergeDriverArgs,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Result<(), String> {
+        let cwd = std::env::current_dir()
+            .map_err(|err| format!("Failed to determine current directory: {err}"))?;
+        let repo_root = find_repo_root(&cwd).ok_or_else(|| {
+            "Git repository not found. Run this command inside the repository you want to configure."
+                .to_string()
+        })?;
+
+        let pattern = merge_attributes_pattern(&repo_root, &resolver.path)?;
+        let attributes_path = repo_root.join(".gitattributes");
+        let attributes = fs::read_to_string(&attributes_path).unwrap_or_default();
+        let attributes_present = attributes.lines().any(|line| line.trim() == pattern);
+        let current_driver = Self::read_local_config(&repo_root, "merge.lotar.driver")?;
+        let driver_configured = current_driver.as_deref() == Some(MERGE_DRIVER_COMMAND);
+
+        let state = if args.dry_run {
+            InstallState::DryRun
+        } else if driver_configured && attributes_present {
+            InstallState::AlreadyConfigured
+        } else {
+            if let Some(current) = current_driver.as_deref()
+                && !driver_configured
+                && !args.force
+            {
+                return Err(format!(
+                    "merge.lotar.driver is currently set to '{}'. Re-run with --force to overwrite.",
+                    current
+                ));
+            }
+            Self::write_local_config(&repo_root, "merge.lotar.name", MERGE_DRIVER_NAME)?;
+            Self::write_local_config(&repo_root, "merge.lotar.driver", MERGE_DRIVER_COMMAND)?;
+            if !attributes_present {
+                let mut updated = attributes;
+                if !updated.is_empty() && !updated.ends_with('\n') {
+                    updated.push('\n');
+                }
+                updated.push_str(&pattern);
+                updated.push('\n');
+                fs::write(&attributes_path, updated).map_err(|err| {
+                    format!("Failed to write '{}': {err}", attributes_path.display())
+                })?;
+            }
+            InstallState::Updated
+        };
+
+        match renderer.format {
+            OutputFormat::Json => renderer.emit_json(&json!({
+                "status": "success",
+                "action": "git_install_merge_driver",
+                "driver": MERGE_DRIVER_COMMAND,
+                "attributes": pattern,
+                "previous": current_driver,
+                "state": state.label(),
+            })),
+            _ => match state {
+                InstallState::DryRun => renderer.emit_notice(format_args!(
+                    "Would set git merge.lotar.driver to '{}' and add '{}' to .gitattributes.",
+                    MERGE_DRIVER_COMMAND, pattern
+                )),
+                InstallState::AlreadyConfigured => renderer.emit_notice(format_args!(
+                    "Merge driver already configured ('{}' in .gitattributes).",
+                    pattern
+                )),
+                InstallState::Updated => renderer.emit_success(format_args!(
+                    "Configured git merge.lotar.driver and added '{}' to .gitattributes (commit it to share with your team).",
+                    pattern
+                )),
+            },
+        }
+        Ok(())
+    }
+
     fn collect_hook_scripts(dir: &Path) -> Result<Vec<PathBuf>, String> {
         let mut scripts = Vec::new();
         let entries = fs::read_dir(dir)
@@ -148,11 +336,11 @@ impl GitHandler {
         Ok(scripts)
     }
 
-    fn read_hooks_path(repo_root: &Path) -> Result<Option<String>, String> {
+    fn read_local_config(repo_root: &Path, key: &str) -> Result<Option<String>, String> {
         let output = Command::new("git")
             .arg("-C")
             .arg(repo_root)
-            .args(["config", "--local", "--get", "core.hooksPath"])
+            .args(["config", "--local", "--get", key])
             .output()
             .map_err(|err| format!("Failed to execute git: {err}"))?;
 
@@ -178,11 +366,11 @@ impl GitHandler {
         }
     }
 
-    fn write_hooks_path(repo_root: &Path, value: &str) -> Result<(), String> {
+    fn write_local_config(repo_root: &Path, key: &str, value: &str) -> Result<(), String> {
         let status = Command::new("git")
             .arg("-C")
             .arg(repo_root)
-            .args(["config", "--local", "core.hooksPath", value])
+            .args(["config", "--local", key, value])
             .status()
             .map_err(|err| format!("Failed to execute git: {err}"))?;
 
@@ -301,3 +489,60 @@ impl InstallState {
         }
     }
 }
+
+const MERGE_DRIVER_NAME: &str = "lotar structured merge for task and sprint YAML";
+const MERGE_DRIVER_COMMAND: &str = "lotar merge-driver %O %A %B %P";
+
+/// `.gitattributes` line routing task and sprint files (numeric names) to the lotar driver.
+fn merge_attributes_pattern(repo_root: &Path, tasks_dir: &Path) -> Result<String, String> {
+    let repo_root = repo_root
+        .canonicalize()
+        .unwrap_or_else(|_| repo_root.to_path_buf());
+    let tasks_dir = tasks_dir
+        .canonicalize()
+        .unwrap_or_else(|_| tasks_dir.to_path_buf());
+    let relative = tasks_dir.strip_prefix(&repo_root).map_err(|_| {
+        format!(
+            "Tasks directory '{}' is outside the git repository '{}'.",
+            tasks_dir.display(),
+            repo_root.display()
+        )
+    })?;
+    let relative = relative.to_string_lossy().replace('\\', "/");
+    let prefix = if relative.is_empty() {
+        String::new()
+    } else {
+        format!("{relative}/")
+    };
+    Ok(format!("{prefix}*/[0-9]*.yml merge=lotar"))
+}
+
+fn short_sha(sha: &str) -> &str {
+    sha.get(..10).unwrap_or(sha)
+}
+
+fn describe_entry(entry: &CommitIngestEntry, dry_run: bool) -> String {
+    match entry.outcome.as_str() {
+        "already_linked" => "already linked".to_string(),
+        "unknown_task" => "unknown ticket (skipped)".to_string(),
+        _ => {
+            let mut text = if dry_run {
+                "would link".to_string()
+            } else {
+                "linked".to_string()
+            };
+            if let Some(status) = &entry.status {
+                text.push_str(&format!(
+                    ", {} {} ('{}')",
+                    if dry_run { "would move to" } else { "moved to" },
+                    status,
+                    entry.keyword.as_deref().unwrap_or_default()
+                ));
+            }
+            if let Some(message) = &entry.message {
+                text.push_str(&format!(" ({})", message));
+            }
+            text
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::cli::args::MergeDriverArgs;
+use crate::cli::handlers::CommandHandler;
+use crate::output::{OutputFormat, OutputRenderer};
+use crate::services::merge_service::MergeService;
+use crate::workspace::TasksDirectoryResolver;
+use std::path::Path;
+
+/// Handler for `lotar merge-driver`: git's custom merge driver for task and sprint files.
+///
+/// Exits non-zero when conflict markers were written, which tells git the file still
+/// needs manual resolution.
+pub struct MergeDriverHandler;
+
+impl CommandHandler for MergeDriverHandler {
+    type Args = MergeDriverArgs;
+    type Result = Result<(), String>;
+
+    fn execute(
+        args: Self::Args,
+        _project: Option<&str>,
+        _resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Self::Result {
+        let outcome = MergeService::merge_files(
+            Path::new(&args.base),
+            Path::new(&args.ours),
+            Path::new(&args.theirs),
+            args.path.as_deref(),
+        )
+        .map_err(|e| e.to_string())?;
+        let display = args.path.as_deref().unwrap_or(&args.ours);
+
+        if matches!(renderer.format, OutputFormat::Json) {
+            renderer.emit_json(&serde_json::json!({
+                "status": if outcome.is_clean() { "ok" } else { "conflict" },
+                "action": "merge_driver",
+                "path": display,
+                "kind": outcome.kind,
+                "textual": outcome.textual,
+                "id_collision": outcome.id_collision,
+                "conflicts": outcome.conflicts,
+            }));
+        } else if outcome.is_clean() {
+            renderer.log_info(format_args!("Merged {} without conflicts", display));
+        }
+
+        if outcome.is_clean() {
+            Ok(())
+        } else if outcome.id_collision {
+            Err(format!(
+                "Both branches created a different task as {}; finish the merge with `lotar renumber` to give the incoming one a new ID",
+                display
+            ))
+        } else if outcome.textual {
+            Err(format!(
+                "Merge conflict in {} ({} hunk(s)); the file is not valid YAML so a line-based merge was used",
+                display,
+                outcome.conflicts.len()
+            ))
+        } else {
+            Err(format!(
+                "Merge conflict in {}: both sides changed {}",
+                display,
+                outcome.conflicts.join(", ")
+            ))
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::cli::args::MigrateArgs;
+use crate::cli::handlers::CommandHandler;
+use crate::output::{OutputFormat, OutputRenderer};
+use crate::services::migration_service::{self, MigrationOptions, MigrationReport};
+use crate::workspace::TasksDirectoryResolver;
+
+/// Handler for `lotar migrate`: upgrade config and task files to the current schema versions.
+pub struct MigrateHandler;
+
+impl CommandHandler for MigrateHandler {
+    type Args = MigrateArgs;
+    type Result = Result<(), String>;
+
+    fn execute(
+        args: Self::Args,
+        project: Option<&str>,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Self::Result {
+        let options = MigrationOptions {
+            dry_run: args.dry_run,
+            backup: !args.no_backup,
+            project: project
+                .map(|raw| crate::utils::resolve_project_input(raw, resolver.path.as_path())),
+        };
+        let report = migration_service::run(&resolver.path, &options, chrono::Utc::now())
+            .map_err(|e| e.to_string())?;
+
+        match renderer.format {
+            OutputFormat::Json => {
+                let mut value = serde_json::to_value(&report).unwrap_or_default();
+                if let Some(map) = value.as_object_mut() {
+                    let status = if report.failures.is_empty() {
+                        "ok"
+                    } else {
+                        "partial"
+                    };
+                    map.insert("status".into(), status.into());
+                    map.insert("action".into(), "migrate".into());
+                }
+                renderer.emit_json(&value);
+            }
+            _ => render_text(&report, renderer),
+        }
+
+        if report.failures.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} file(s) could not be migrated",
+                report.failures.len()
+            ))
+        }
+    }
+}
+
+fn render_text(report: &MigrationReport, renderer: &OutputRenderer) {
+    for file in &report.files {
+        let steps = if file.steps.is_empty() {
+            "version stamp only".to_string()
+        } else {
+            file.steps.join(", ")
+        };
+        let verb = if report.dry_run {
+            "Would migrate"
+        } else {
+            "Migrated"
+        };
+        renderer.emit_raw_stdout(format_args!(
+            "{} {} (v{} -> v{}): {}",
+            verb, file.path, file.from_version, file.to_version, steps
+        ));
+        if let Some(diff) = file.diff.as_deref() {
+            renderer.emit_raw_stdout(diff.trim_end());
+        }
+    }
+    for failure in &report.failures {
+        renderer.emit_warning(format_args!("{}: {}", failure.path, failure.error));
+    }
+
+    if report.files.is_empty() {
+        renderer.emit_success(format_args!(
+            "All {} file(s) are already at the current schema version",
+            report.scanned
+        ));
+    } else if report.dry_run {
+        renderer.emit_info(format_args!(
+            "{} of {} file(s) need migration; rerun without --dry-run to apply",
+            report.files.len(),
+            report.scanned
+        ));
+    } else {
+        renderer.emit_success(format_args!(
+            "Migrated {} of {} file(s)",
+            report.files.len(),
+            report.scanned
+        ));
+        if let Some(backup) = report.backup_dir.as_deref() {
+            renderer.emit_info(format_args!("Originals saved to {}", backup));
+        }
+    }
+}
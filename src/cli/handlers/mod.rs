@@ -15,17 +15,26 @@ use std::io::Write;
 pub mod agent;
 pub mod assignee;
 pub mod automation;
+pub mod check;
 pub mod comment;
 pub mod completions;
 pub mod config;
+pub mod doctor;
 pub mod duedate;
 pub mod effort;
+pub mod export;
+pub mod forecast;
 pub mod git;
+pub mod merge_driver;
+pub mod migrate;
 pub mod priority;
 pub mod relationships;
+pub mod renumber;
 pub mod scan_handler;
+pub mod schema;
 pub mod serve_handler;
 pub mod sprint;
+pub mod start;
 pub mod stats;
 pub mod status;
 pub mod sync;
@@ -34,12 +43,21 @@ pub mod task;
 // Re-export handlers for easy access
 pub use agent::AgentHandler;
 pub use automation::AutomationHandler;
+pub use check::CheckHandler;
 pub use completions::CompletionsHandler;
 pub use config::ConfigHandler;
+pub use doctor::DoctorHandler;
+pub use export::ExportHandler;
+pub use forecast::ForecastHandler;
 pub use git::GitHandler;
+pub use merge_driver::MergeDriverHandler;
+pub use migrate::MigrateHandler;
+pub use renumber::RenumberHandler;
 pub use scan_handler::ScanHandler;
+pub use schema::SchemaHandler;
 pub use serve_handler::ServeHandler;
 pub use sprint::SprintHandler;
+pub use start::StartHandler;
 pub use stats::StatsHandler;
 pub use sync::SyncHandler;
 pub use task::TaskHandler;
@@ -356,6 +374,15 @@ impl CommandHandler for AddHandler {
             );
         }
 
+        // Per-type template defaults, then its required fields.
+        crate::utils::issue_templates::apply_issue_template(
+            &mut task,
+            &config,
+            !args.tags.is_empty(),
+        );
+        crate::utils::issue_templates::enforce_required_fields(&task, &config)
+            .map_err(|e| e.to_string())?;
+
         #[allow(clippy::drop_non_drop)]
         drop(validator);
 
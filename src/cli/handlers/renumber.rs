@@ -0,0 +1,116 @@
+use crate::cli::args::RenumberArgs;
+use crate::cli::handlers::CommandHandler;
+use crate::cli::project::ProjectResolver;
+use crate::output::{OutputFormat, OutputRenderer};
+use crate::services::task_relocation_service::{
+    Relocation, RelocationReason, RenumberReport, TaskRelocationService,
+};
+use crate::workspace::TasksDirectoryResolver;
+
+/// Handler for `lotar renumber`: move a task to a new ID, or resolve ID collisions a branch
+/// merge left behind.
+pub struct RenumberHandler;
+
+impl CommandHandler for RenumberHandler {
+    type Args = RenumberArgs;
+    type Result = Result<(), String>;
+
+    fn execute(
+        args: Self::Args,
+        project: Option<&str>,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Self::Result {
+        let report = match args.id.as_deref() {
+            Some(id) => {
+                let mut project_resolver = ProjectResolver::new(resolver)?;
+                project_resolver.validate_task_id_format(id)?;
+                let effective_project = project
+                    .map(|p| crate::utils::resolve_project_input(p, resolver.path.as_path()));
+                let full_id =
+                    project_resolver.get_full_task_id(id, effective_project.as_deref())?;
+                let relocation =
+                    TaskRelocationService::renumber(resolver, &full_id, args.to, args.dry_run)
+                        .map_err(|e| e.to_string())?;
+                RenumberReport {
+                    dry_run: args.dry_run,
+                    relocations: vec![relocation],
+                    skipped: Vec::new(),
+                }
+            }
+            None => TaskRelocationService::resolve_collisions(resolver, args.dry_run)
+                .map_err(|e| e.to_string())?,
+        };
+
+        match renderer.format {
+            OutputFormat::Json => {
+                let mut value = serde_json::to_value(&report).unwrap_or_default();
+                if let Some(map) = value.as_object_mut() {
+                    let status = if report.skipped.is_empty() {
+                        "ok"
+                    } else {
+                        "failed"
+                    };
+                    map.insert("status".into(), status.into());
+                    map.insert("action".into(), "renumber".into());
+                }
+                renderer.emit_json(&value);
+            }
+            _ => render_text(&report, renderer),
+        }
+
+        match report.skipped.len() {
+            0 => Ok(()),
+            n => Err(format!("{} file(s) need manual resolution", n)),
+        }
+    }
+}
+
+fn render_text(report: &RenumberReport, renderer: &OutputRenderer) {
+    let verb = if report.dry_run {
+        "Would renumber"
+    } else {
+        "Renumbered"
+    };
+    for relocation in &report.relocations {
+        renderer.emit_success(format_args!(
+            "{} {} → {} ({})",
+            verb,
+            relocation.from,
+            relocation.to,
+            describe(relocation)
+        ));
+        for rewrite in &relocation.rewrites {
+            renderer.emit_raw_stdout(format_args!(
+                "  {} reference(s) in {}",
+                rewrite.count, rewrite.path
+            ));
+        }
+        if relocation.reason != RelocationReason::Requested {
+            renderer.emit_info(format_args!(
+                "Review references to {} added on the other branch; they may mean {}",
+                relocation.from, relocation.to
+            ));
+        }
+    }
+    for skipped in &report.skipped {
+        renderer.emit_warning(format_args!("Skipped {}", skipped));
+    }
+    if report.relocations.is_empty() && report.skipped.is_empty() {
+        renderer.emit_success("No ID collisions found");
+    }
+}
+
+fn describe(relocation: &Relocation) -> String {
+    match relocation.reason {
+        RelocationReason::Requested | RelocationReason::Moved => match relocation.rewrites.len() {
+            0 => "no references".to_string(),
+            n => format!("references rewritten in {} file(s)", n),
+        },
+        RelocationReason::MergeCollision => {
+            format!("both branches created {}", relocation.file)
+        }
+        RelocationReason::DuplicateFile => format!("duplicate file {}", relocation.file),
+        RelocationReason::Copied => format!("copy of {}", relocation.from),
+    }
+}
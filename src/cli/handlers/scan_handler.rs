@@ -1,14 +1,20 @@
+use crate::api_types::ScanReconcileEntry;
 use crate::cli::ScanArgs;
 use crate::cli::handlers::AddHandler;
 use crate::cli::handlers::CommandHandler;
 use crate::cli::handlers::task::context::TaskCommandContext;
 use crate::cli::handlers::task::errors::TaskStorageAction;
 use crate::cli::handlers::task::mutation::{LoadedTask, load_task};
+use crate::config::types::ScanCommentSyntax;
 use crate::output::OutputRenderer;
 use crate::project;
 use crate::scanner;
-use crate::utils::scan::{parse_inline_attributes, strip_bracket_attributes};
+use crate::services::scan_service::ScanService;
+use crate::utils::scan::{
+    AnchorLocation, AnchorLocator, parse_inline_attributes, strip_bracket_attributes,
+};
 use crate::workspace::TasksDirectoryResolver;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 // feature-aware custom fields manipulation helpers are implemented below
 
@@ -93,14 +99,24 @@ impl CommandHandler for ScanHandler {
             Err(_) => None,
         };
 
-        // Load config (global or project-specific) to obtain scan settings
+        // Load config (global or project-specific) to obtain scan settings:
+        // (signal words, ticket patterns, ticket words, mentions, issue-type words, comment syntax)
+        type ScanSettings = (
+            Vec<String>,
+            Option<Vec<String>>,
+            bool,
+            bool,
+            Vec<String>,
+            BTreeMap<String, ScanCommentSyntax>,
+        );
         let (
             cfg_words,
             cfg_ticket_patterns,
             cfg_enable_ticket_words,
             cfg_enable_mentions,
             issue_type_words,
-        ): (Vec<String>, Option<Vec<String>>, bool, bool, Vec<String>) = {
+            cfg_comment_syntax,
+        ): ScanSettings = {
             let mgr = crate::config::manager::ConfigManager::new_manager_with_tasks_dir_readonly(
                 _resolver.path.as_path(),
             )
@@ -123,6 +139,7 @@ impl CommandHandler for ScanHandler {
                             resolved.scan_enable_ticket_words,
                             resolved.scan_enable_mentions,
                             type_words,
+                            resolved.scan_comment_syntax,
                         )
                     } else {
                         let r = mgr.get_resolved_config();
@@ -137,6 +154,7 @@ impl CommandHandler for ScanHandler {
                             r.scan_enable_ticket_words,
                             r.scan_enable_mentions,
                             type_words,
+                            r.scan_comment_syntax.clone(),
                         )
                     }
                 } else {
@@ -151,11 +169,12 @@ impl CommandHandler for ScanHandler {
                         r.scan_enable_ticket_words,
                         r.scan_enable_mentions,
                         type_words,
+                        r.scan_comment_syntax.clone(),
                     )
                 }
             } else {
                 // Fallback: use scanner defaults by returning empty to skip override
-                (Vec::new(), None, false, true, Vec::new())
+                (Vec::new(), None, false, true, Vec::new(), BTreeMap::new())
             }
         };
 
@@ -165,7 +184,8 @@ impl CommandHandler for ScanHandler {
             let mut scanner = scanner::Scanner::new(root)
                 .with_include_ext(&args.include)
                 .with_exclude_ext(&args.exclude)
-                .with_modified_only(args.modified_only);
+                .with_modified_only(args.modified_only)
+                .with_comment_syntax(&cfg_comment_syntax);
             // Merge regular signal words with issue-type words if enabled
             let mut final_words = cfg_words.clone();
             for w in &issue_type_words {
@@ -189,6 +209,9 @@ impl CommandHandler for ScanHandler {
                 .then(a.line_number.cmp(&b.line_number))
         });
 
+        // Only an explicit --project narrows reconciliation; otherwise every project is checked
+        let reconcile_project = _project.and(effective_project_for_config.as_deref());
+
         if matches!(renderer.format, crate::output::OutputFormat::Json) {
             let items: Vec<serde_json::Value> = all_results
                 .iter()
@@ -202,7 +225,17 @@ impl CommandHandler for ScanHandler {
                     })
                 })
                 .collect();
-            match serde_json::to_string(&items) {
+            let payload = if args.reconcile {
+                let reconciled = ScanService::reconcile(_resolver, reconcile_project, args.dry_run)
+                    .map_err(|e| e.to_string())?;
+                serde_json::to_string(&serde_json::json!({
+                    "results": items,
+                    "reconciled": reconciled,
+                }))
+            } else {
+                serde_json::to_string(&items)
+            };
+            match payload {
                 Ok(s) => renderer.emit_raw_stdout(s),
                 Err(e) => renderer.emit_raw_stdout(
                     serde_json::json!({"status":"error","message":format!("scan serialization failed: {}", e)}).to_string(),
@@ -496,6 +529,12 @@ impl CommandHandler for ScanHandler {
             }
         }
 
+        if args.reconcile && !matches!(renderer.format, crate::output::OutputFormat::Json) {
+            let reconciled = ScanService::reconcile(_resolver, reconcile_project, args.dry_run)
+                .map_err(|e| e.to_string())?;
+            Self::render_reconcile(renderer, &reconciled, args.dry_run);
+        }
+
         Ok(())
     }
 }
@@ -520,6 +559,44 @@ fn replace_line(contents: &str, line_number_1based: usize, new_line: &str) -> Op
 // custom_fields-based source_refs helper removed; we now use Task.references with code anchors
 
 impl ScanHandler {
+    fn render_reconcile(renderer: &OutputRenderer, entries: &[ScanReconcileEntry], dry_run: bool) {
+        if entries.is_empty() {
+            renderer.emit_success("Reconcile: all code references are current.");
+            return;
+        }
+        let (skipped, changed): (Vec<_>, Vec<_>) =
+            entries.iter().partition(|entry| entry.outcome == "skipped");
+        if dry_run {
+            renderer.emit_info(format_args!(
+                "Reconcile (dry run): {} task(s) would change:",
+                changed.len()
+            ));
+        } else {
+            renderer.emit_info(format_args!("Reconciled {} task(s):", changed.len()));
+        }
+        for entry in changed {
+            let detail = match entry.new_reference.as_deref() {
+                Some(next) => format!("moved to {}", next),
+                None => format!("TODO removed ({})", entry.actions.join(", ")),
+            };
+            renderer.emit_raw_stdout(format_args!(
+                "  {} {}: {}",
+                entry.task_id, entry.code_reference, detail
+            ));
+            if !dry_run && let Some(message) = &entry.message {
+                renderer.emit_warning(format_args!("{}: {}", entry.task_id, message));
+            }
+        }
+        for entry in skipped {
+            renderer.emit_warning(format_args!(
+                "{} {}: skipped: {}",
+                entry.task_id,
+                entry.code_reference,
+                entry.message.as_deref().unwrap_or("")
+            ));
+        }
+    }
+
     /// Re-anchor existing task references by searching for their key occurrences
     /// near the previous anchor line, with a fallback to a full-file search.
     /// If `window_hint` is None, a default small window will be used.
@@ -542,16 +619,10 @@ impl ScanHandler {
         }
 
         let mut updates = 0usize;
-        // Compute repo root once for this pass
-        let repo_root = std::env::current_dir()
-            .ok()
-            .and_then(|cwd| crate::utils::git::find_repo_root(&cwd));
-        // Best-effort git rename map if inside a repo
-        let rename_map = if let Some(root) = &repo_root {
-            Self::git_rename_map(root)
-        } else {
-            std::collections::HashMap::new()
+        let Ok(cwd) = std::env::current_dir() else {
+            return Ok(());
         };
+        let locator = AnchorLocator::new(&cwd, window);
 
         for (task_id, mut task) in tasks.drain(..) {
             // Skip tasks without references
@@ -563,114 +634,21 @@ impl ScanHandler {
             let mut changed = false;
 
             for r in task.references.iter_mut() {
-                // Avoid borrowing r.code across mutations by cloning it first
-                let code_ref_opt = r.code.clone();
-                let Some(code_ref) = code_ref_opt else {
-                    continue;
-                };
-                let (path_str, orig_line_opt) = Self::parse_code_ref(&code_ref);
-                if path_str.is_empty() {
+                let Some(code_ref) = r.code.clone() else {
                     continue;
-                }
-
-                // Resolve absolute file path if possible
-                let abs_path = if let Some(root) = &repo_root {
-                    root.join(&path_str)
-                } else {
-                    std::path::PathBuf::from(&path_str)
                 };
-
-                let abs_path = if abs_path.exists() {
-                    abs_path
-                } else {
-                    // File may have been renamed; try git rename map using repo-relative key
-                    let rel_key = &path_str;
-                    if let Some(new_rel) = rename_map.get(rel_key) {
-                        if let Some(root) = &repo_root {
-                            let candidate = root.join(new_rel);
-                            if candidate.exists() {
-                                candidate
-                            } else {
-                                // Fall back to skipping if new file also doesn't exist
-                                continue;
-                            }
-                        } else {
-                            continue;
-                        }
-                    } else {
-                        continue;
-                    }
-                };
-
-                // Determine the search key token for this task
-                let key = task_id.clone();
-
-                // Load file and gather lines
-                let Ok(content) = std::fs::read_to_string(&abs_path) else {
+                // Files that vanished or no longer mention the key are left for --reconcile
+                let Some(AnchorLocation::Found { path, line }) =
+                    locator.locate(&task_id, &code_ref)
+                else {
                     continue;
                 };
-                let lines: Vec<&str> = content.lines().collect();
-
-                // If the original line still contains the key marker, nothing to do
-                if let Some(orig_line) = orig_line_opt
-                    && let Some(existing) = lines.get(orig_line.saturating_sub(1))
-                    && Self::line_contains_key(existing, &key)
-                {
-                    // Ensure canonical formatting of code ref
-                    let rel = crate::utils::paths::repo_relative_display(&abs_path);
-                    let new_code = format!("{}#{}", rel, orig_line);
-                    if r.code.as_deref() != Some(&new_code) {
-                        r.code = Some(new_code.clone());
-                        changed = true;
-                    }
-                    continue;
-                }
-
-                // Proximity search window around original line (if known)
-                let mut best_line: Option<usize> = None;
-                if let Some(orig_line) = orig_line_opt {
-                    let start = orig_line.saturating_sub(window);
-                    let end = (orig_line + window).min(lines.len());
-                    for ln in start..=end {
-                        if ln == 0 || ln > lines.len() {
-                            continue;
-                        }
-                        let text = lines[ln - 1];
-                        if Self::line_contains_key(text, &key) {
-                            best_line = Some(ln);
-                            break;
-                        }
-                    }
-                }
-
-                // Fallback: full-file search for the exact key token markers
-                if best_line.is_none() {
-                    // Try to find all candidate lines and pick the closest to original, else the first
-                    let mut candidates: Vec<usize> = Vec::new();
-                    for (idx, text) in lines.iter().enumerate() {
-                        if Self::line_contains_key(text, &key) {
-                            candidates.push(idx + 1);
-                        }
-                    }
-                    if !candidates.is_empty() {
-                        if let Some(orig_line) = orig_line_opt {
-                            // Choose nearest to original
-                            candidates.sort_by_key(|&ln| ln.abs_diff(orig_line));
-                            best_line = candidates.first().copied();
-                        } else {
-                            best_line = candidates.first().copied();
-                        }
-                    }
-                }
-
-                if let Some(new_line) = best_line {
-                    let rel = crate::utils::paths::repo_relative_display(&abs_path);
-                    let new_code = format!("{}#{}", rel, new_line);
-                    if r.code.as_deref() != Some(&new_code) {
-                        r.code = Some(new_code.clone());
-                        changed = true;
-                        renderer.log_debug(format_args!("reanchor: {} -> {}", code_ref, new_code));
-                    }
+                let rel = crate::utils::paths::repo_relative_display(&path);
+                let new_code = format!("{}#{}", rel, line);
+                if r.code.as_deref() != Some(&new_code) {
+                    r.code = Some(new_code.clone());
+                    changed = true;
+                    renderer.log_debug(format_args!("reanchor: {} -> {}", code_ref, new_code));
                 }
             }
 
@@ -688,72 +666,4 @@ impl ScanHandler {
         }
         Ok(())
     }
-
-    fn parse_code_ref(code: &str) -> (String, Option<usize>) {
-        if let Some((path, anchor)) = code.split_once('#') {
-            let mut buffer = String::new();
-            let mut found = false;
-            for ch in anchor.chars() {
-                if ch.is_ascii_digit() {
-                    buffer.push(ch);
-                    found = true;
-                    continue;
-                }
-                if found {
-                    break;
-                }
-            }
-            let line = buffer.parse::<usize>().ok();
-            (path.to_string(), line)
-        } else {
-            (code.to_string(), None)
-        }
-    }
-
-    fn line_contains_key(line: &str, key: &str) -> bool {
-        if line.contains(&format!("({})", key)) {
-            return true;
-        }
-        // Accept [ticket=KEY] with optional spaces and case-insensitive ticket
-        let pattern = format!(r"(?i)\[\s*ticket\s*=\s*{}\s*\]", regex::escape(key));
-        if regex::Regex::new(&pattern)
-            .map(|re| re.is_match(line))
-            .unwrap_or(false)
-        {
-            return true;
-        }
-        false
-    }
-
-    fn git_rename_map(repo_root: &std::path::Path) -> std::collections::HashMap<String, String> {
-        use std::process::Command;
-        let mut map = std::collections::HashMap::new();
-        let out = Command::new("git")
-            .arg("-C")
-            .arg(repo_root)
-            .arg("status")
-            .arg("--porcelain")
-            .output();
-        let Ok(o) = out else {
-            return map;
-        };
-        if !o.status.success() {
-            return map;
-        }
-        let s = String::from_utf8_lossy(&o.stdout);
-        for line in s.lines() {
-            if line.len() < 4 {
-                continue;
-            }
-            let status = &line[..2];
-            if status.contains('R')
-                && let Some(pos) = line.find(" -> ")
-            {
-                let old = line[3..pos].trim().to_string();
-                let newp = line[pos + 4..].trim().to_string();
-                map.insert(old, newp);
-            }
-        }
-        map
-    }
 }
@@ -0,0 +1,76 @@
+use crate::cli::args::{SchemaAction, SchemaArgs, SchemaExportArgs};
+use crate::cli::handlers::CommandHandler;
+use crate::output::{OutputFormat, OutputRenderer};
+use crate::services::schema_service::{self, SchemaExportOptions, SchemaExportReport};
+use crate::workspace::TasksDirectoryResolver;
+
+/// Handler for `lotar schema`.
+pub struct SchemaHandler;
+
+impl CommandHandler for SchemaHandler {
+    type Args = SchemaArgs;
+    type Result = Result<(), String>;
+
+    fn execute(
+        args: Self::Args,
+        _project: Option<&str>,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Self::Result {
+        match args.action {
+            SchemaAction::Show(show) => {
+                let schema = schema_service::generate(show.kind).map_err(|e| e.to_string())?;
+                let body = serde_json::to_string_pretty(&schema).map_err(|e| e.to_string())?;
+                renderer.emit_raw_stdout(body);
+                Ok(())
+            }
+            SchemaAction::Export(export) => export_schemas(export, resolver, renderer),
+        }
+    }
+}
+
+fn export_schemas(
+    args: SchemaExportArgs,
+    resolver: &TasksDirectoryResolver,
+    renderer: &OutputRenderer,
+) -> Result<(), String> {
+    let options = SchemaExportOptions {
+        kinds: args.kinds,
+        out_dir: args.out,
+        modelines: args.modelines,
+        vscode: args.vscode,
+    };
+    let report = schema_service::export(&resolver.path, &options).map_err(|e| e.to_string())?;
+
+    match renderer.format {
+        OutputFormat::Json => {
+            let mut value = serde_json::to_value(&report).unwrap_or_default();
+            if let Some(map) = value.as_object_mut() {
+                map.insert("status".into(), "ok".into());
+                map.insert("action".into(), "schema_export".into());
+            }
+            renderer.emit_json(&value);
+        }
+        _ => render_text(&report, renderer),
+    }
+    Ok(())
+}
+
+fn render_text(report: &SchemaExportReport, renderer: &OutputRenderer) {
+    for schema in &report.schemas {
+        renderer.emit_success(format_args!(
+            "Wrote {} schema to {}",
+            schema.kind.as_str(),
+            schema.path
+        ));
+    }
+    if !report.modelines.is_empty() {
+        renderer.emit_info(format_args!(
+            "Added schema modelines to {} file(s)",
+            report.modelines.len()
+        ));
+    }
+    if let Some(settings) = report.vscode_settings.as_deref() {
+        renderer.emit_info(format_args!("Mapped yaml.schemas in {}", settings));
+    }
+}
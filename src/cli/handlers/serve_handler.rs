@@ -4,6 +4,7 @@ use crate::cli::handlers::CommandHandler;
 use crate::config::persistence;
 use crate::output::OutputRenderer;
 use crate::routes;
+use crate::services::agent_job_service::AgentJobService;
 use crate::web_server::{self, WebServerConfig};
 use crate::workspace::TasksDirectoryResolver;
 use std::path::PathBuf;
@@ -79,6 +80,7 @@ impl CommandHandler for ServeHandler {
         renderer.emit_warning("Press Ctrl+C to stop the server");
 
         pin_process_tasks_dir(resolver)?;
+        restore_agent_jobs(resolver, renderer);
 
         let mut api_server = api_server::ApiServer::new();
         routes::initialize(&mut api_server);
@@ -98,6 +100,34 @@ fn pin_process_tasks_dir(resolver: &TasksDirectoryResolver) -> Result<(), String
     Ok(())
 }
 
+/// Reattach to agent jobs left behind by a previous server process.
+fn restore_agent_jobs(resolver: &TasksDirectoryResolver, renderer: &OutputRenderer) {
+    match AgentJobService::restore_jobs(&resolver.path) {
+        Ok(restored) => {
+            if !restored.reattached.is_empty() {
+                renderer.emit_info(format_args!(
+                    "Reattached to {} running agent job(s): {}",
+                    restored.reattached.len(),
+                    restored.reattached.join(", ")
+                ));
+            }
+            for (old_id, new_id) in &restored.requeued {
+                renderer.emit_info(format_args!("Requeued agent job {} as {}", old_id, new_id));
+            }
+            if !restored.failed.is_empty() {
+                renderer.emit_warning(format_args!(
+                    "Marked {} orphaned agent job(s) failed: {}",
+                    restored.failed.len(),
+                    restored.failed.join(", ")
+                ));
+            }
+        }
+        Err(err) => {
+            renderer.emit_warning(format_args!("Could not restore agent jobs: {}", err));
+        }
+    }
+}
+
 /// Helper function to open browser (cross-platform)
 fn open_browser(url: &str) -> Result<(), String> {
     #[cfg(target_os = "macos")]
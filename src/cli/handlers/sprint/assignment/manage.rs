@@ -97,6 +97,7 @@ pub(crate) fn handle_add(
                     })
                     .collect(),
                 messages: reassignment_messages.clone(),
+                capacity_warnings: outcome.capacity_warnings.clone(),
                 integrity: build_assignment_integrity(
                     context.baseline_integrity(),
                     context.integrity(),
@@ -128,6 +129,9 @@ pub(crate) fn handle_add(
                     renderer.emit_info(message);
                 }
             }
+            for warning in &outcome.capacity_warnings {
+                renderer.emit_warning(warning);
+            }
         }
     }
 
@@ -209,6 +213,7 @@ pub(crate) fn handle_move(
                     })
                     .collect(),
                 messages: reassignment_messages.clone(),
+                capacity_warnings: outcome.capacity_warnings.clone(),
                 integrity: build_assignment_integrity(
                     context.baseline_integrity(),
                     context.integrity(),
@@ -240,6 +245,9 @@ pub(crate) fn handle_move(
                     "No tasks changed sprint membership; all provided tasks already belonged to the target sprint.",
                 );
             }
+            for warning in &outcome.capacity_warnings {
+                renderer.emit_warning(warning);
+            }
         }
     }
 
@@ -306,6 +314,7 @@ pub(crate) fn handle_remove(
                 unchanged: outcome.unchanged.clone(),
                 replaced: Vec::new(),
                 messages: Vec::new(),
+                capacity_warnings: Vec::new(),
                 integrity: build_assignment_integrity(
                     context.baseline_integrity(),
                     context.integrity(),
@@ -376,13 +385,15 @@ fn local_resolver(tasks_root: &Path) -> TasksDirectoryResolver {
     }
 }
 
-fn load_resolved_config(resolver: &TasksDirectoryResolver) -> Result<ResolvedConfig, String> {
+pub(crate) fn load_resolved_config(
+    resolver: &TasksDirectoryResolver,
+) -> Result<ResolvedConfig, String> {
     let manager = ConfigManager::new_manager_with_tasks_dir_readonly(&resolver.path)
         .map_err(|e| format!("Failed to load config: {}", e))?;
     Ok(manager.get_resolved_config().clone())
 }
 
-fn build_selection_from_args(
+pub(crate) fn build_selection_from_args(
     args: &TaskSelectionArgs,
     resolver: &TasksDirectoryResolver,
     config: &ResolvedConfig,
@@ -460,6 +471,8 @@ struct SprintAssignmentResponse {
     replaced: Vec<SprintReassignment>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     messages: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    capacity_warnings: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     integrity: Option<SprintAssignmentIntegrityPayload>,
 }
@@ -3,4 +3,6 @@ mod context;
 mod manage;
 
 pub(crate) use backlog::handle_backlog;
-pub(crate) use manage::{handle_add, handle_move, handle_remove};
+pub(crate) use manage::{
+    build_selection_from_args, handle_add, handle_move, handle_remove, load_resolved_config,
+};
@@ -3,6 +3,8 @@ mod operations;
 mod reporting;
 mod shared;
 
+pub(crate) use assignment::{build_selection_from_args, load_resolved_config};
+
 use crate::cli::args::sprint::{SprintAction, SprintArgs};
 use crate::cli::handlers::{CommandHandler, emit_subcommand_overview};
 use crate::output::OutputRenderer;
@@ -4,7 +4,7 @@ use crate::cli::args::sprint::{SprintCreateArgs, SprintUpdateArgs};
 use crate::output::OutputRenderer;
 use crate::services::sprint_service::SprintService;
 use crate::storage::manager::Storage;
-use crate::storage::sprint::{Sprint, SprintActual, SprintCapacity, SprintPlan};
+use crate::storage::sprint::{Sprint, SprintActual, SprintCapacity, SprintPlan, SprintTimeOff};
 
 use super::support::{SprintOperationKind, render_operation_response};
 
@@ -62,7 +62,7 @@ pub(crate) fn handle_update(
     let existing = SprintService::get(&storage, sprint_id).map_err(|err| err.to_string())?;
 
     let mut sprint = existing.sprint.clone();
-    apply_update_to_sprint(&mut sprint, update_args);
+    apply_update_to_sprint(&mut sprint, update_args)?;
 
     let warnings_enabled = resolved_config.sprint_notifications.enabled;
 
@@ -150,7 +150,7 @@ fn clean_opt_string(input: Option<String>) -> Option<String> {
     })
 }
 
-fn apply_update_to_sprint(sprint: &mut Sprint, update: &SprintUpdateArgs) {
+fn apply_update_to_sprint(sprint: &mut Sprint, update: &SprintUpdateArgs) -> Result<(), String> {
     if update.label.is_some()
         || update.goal.is_some()
         || update.plan_length.is_some()
@@ -195,6 +195,39 @@ fn apply_update_to_sprint(sprint: &mut Sprint, update: &SprintUpdateArgs) {
         }
     }
 
+    if !update.member_hours.is_empty()
+        || !update.time_off.is_empty()
+        || !update.clear_time_off.is_empty()
+    {
+        let plan = sprint.plan.get_or_insert_with(SprintPlan::default);
+        let capacity = plan.capacity.get_or_insert_with(SprintCapacity::default);
+        for (member, hours) in &update.member_hours {
+            let (member, hours) = (member.trim(), hours.trim());
+            let entry = capacity.members.entry(member.to_string()).or_default();
+            entry.weekly_hours = if hours.is_empty() {
+                None
+            } else {
+                Some(hours.parse::<u32>().map_err(|_| {
+                    format!("Invalid --member-hours value '{}' for {}", hours, member)
+                })?)
+            };
+        }
+        for member in &update.clear_time_off {
+            if let Some(entry) = capacity.members.get_mut(member.trim()) {
+                entry.time_off.clear();
+            }
+        }
+        for (member, dates) in &update.time_off {
+            let time_off = parse_time_off(dates)?;
+            capacity
+                .members
+                .entry(member.trim().to_string())
+                .or_default()
+                .time_off
+                .push(time_off);
+        }
+    }
+
     if update.actual_started_at.is_some() || update.actual_closed_at.is_some() {
         let actual = sprint.actual.get_or_insert_with(SprintActual::default);
         if update.actual_started_at.is_some() {
@@ -204,4 +237,32 @@ fn apply_update_to_sprint(sprint: &mut Sprint, update: &SprintUpdateArgs) {
             actual.closed_at = clean_opt_string(update.actual_closed_at.clone());
         }
     }
+
+    Ok(())
+}
+
+/// Parse `YYYY-MM-DD` or `YYYY-MM-DD..YYYY-MM-DD` into a time-off entry.
+fn parse_time_off(raw: &str) -> Result<SprintTimeOff, String> {
+    let (start, end) = match raw.split_once("..") {
+        Some((start, end)) => (start.trim(), Some(end.trim())),
+        None => (raw.trim(), None),
+    };
+    let parse = |value: &str| {
+        chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid --time-off date '{}'; expected YYYY-MM-DD", value))
+    };
+    let start_date = parse(start)?;
+    if let Some(end) = end
+        && parse(end)? < start_date
+    {
+        return Err(format!(
+            "Invalid --time-off range '{}': end is before start",
+            raw
+        ));
+    }
+    Ok(SprintTimeOff {
+        start: start.to_string(),
+        end: end.map(str::to_string),
+        note: None,
+    })
 }
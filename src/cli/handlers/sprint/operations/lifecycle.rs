@@ -5,6 +5,7 @@ use chrono::{DateTime, Duration, Utc};
 use crate::cli::args::sprint::{SprintCloseArgs, SprintStartArgs};
 use crate::output::{OutputFormat, OutputRenderer};
 use crate::services::sprint_assignment;
+use crate::services::sprint_rollover;
 use crate::services::sprint_service::{SprintRecord, SprintService};
 use crate::services::sprint_status::{self, SprintLifecycleState, SprintLifecycleStatus};
 use crate::storage::sprint::{Sprint, SprintActual};
@@ -143,6 +144,29 @@ pub(crate) fn handle_close(
 
     apply_close_to_sprint(&mut sprint, close_instant, close_args.force);
 
+    let rollover_mode = if close_args.no_rollover {
+        None
+    } else {
+        close_args
+            .rollover
+            .or(context.resolved_config.sprint_defaults.rollover)
+    };
+    let rollover = match rollover_mode {
+        Some(mode) => Some(
+            sprint_rollover::rollover_incomplete_tasks(
+                &mut context.storage,
+                target_id,
+                &mut sprint,
+                &context.records,
+                mode,
+                &context.resolved_config,
+                close_instant,
+            )
+            .map_err(|err| format!("Failed to roll over unfinished tasks: {}", err))?,
+        ),
+        None => None,
+    };
+
     warn_about_parallel_active_sprints(
         renderer,
         warnings_enabled,
@@ -162,7 +186,7 @@ pub(crate) fn handle_close(
     };
 
     render_operation_response(
-        SprintOperationKind::Close,
+        SprintOperationKind::Close(rollover.as_ref()),
         outcome.record,
         outcome.warnings,
         outcome.applied_defaults,
@@ -6,6 +6,7 @@ use serde::Serialize;
 use crate::config::types::ResolvedConfig;
 use crate::output::{OutputFormat, OutputRenderer};
 use crate::services::sprint_analytics::{SprintDetail, SprintSummary};
+use crate::services::sprint_rollover::SprintRolloverOutcome;
 use crate::services::sprint_service::{SprintRecord, SprintService};
 use crate::services::sprint_status;
 use crate::storage::manager::Storage;
@@ -41,16 +42,16 @@ pub(crate) fn resolve_sprint_records_context(
 }
 
 #[derive(Copy, Clone, Debug)]
-pub(super) enum SprintOperationKind {
+pub(super) enum SprintOperationKind<'a> {
     Create,
     Update,
     Start,
-    Close,
+    Close(Option<&'a SprintRolloverOutcome>),
 }
 
 #[allow(clippy::needless_pass_by_value)]
 pub(super) fn render_operation_response(
-    kind: SprintOperationKind,
+    kind: SprintOperationKind<'_>,
     record: SprintRecord,
     warnings: Vec<SprintCanonicalizationWarning>,
     applied_defaults: Vec<String>,
@@ -80,9 +81,13 @@ pub(super) fn render_operation_response(
                     SprintOperationKind::Create => "created",
                     SprintOperationKind::Update => "updated",
                     SprintOperationKind::Start => "started",
-                    SprintOperationKind::Close => "closed",
+                    SprintOperationKind::Close(_) => "closed",
                 },
                 sprint: detail,
+                rollover: match kind {
+                    SprintOperationKind::Close(rollover) => rollover.cloned(),
+                    _ => None,
+                },
                 applied_defaults: applied_defaults.clone(),
                 warnings: canonical_warnings
                     .iter()
@@ -99,7 +104,7 @@ pub(super) fn render_operation_response(
                 SprintOperationKind::Create => "Created",
                 SprintOperationKind::Update => "Updated",
                 SprintOperationKind::Start => "Started",
-                SprintOperationKind::Close => "Closed",
+                SprintOperationKind::Close(_) => "Closed",
             };
 
             renderer.emit_success(format_args!(
@@ -139,7 +144,7 @@ pub(super) fn render_operation_response(
             {
                 renderer.emit_info(format_args!("Started at: {}", started_at));
             }
-            if matches!(kind, SprintOperationKind::Close)
+            if matches!(kind, SprintOperationKind::Close(_))
                 && let Some(closed_at) = record
                     .sprint
                     .actual
@@ -148,15 +153,38 @@ pub(super) fn render_operation_response(
             {
                 renderer.emit_info(format_args!("Closed at: {}", closed_at));
             }
+            if let SprintOperationKind::Close(Some(rollover)) = kind {
+                render_rollover(rollover, renderer);
+            }
         }
     }
 }
 
+fn render_rollover(rollover: &SprintRolloverOutcome, renderer: &OutputRenderer) {
+    if rollover.tasks.is_empty() {
+        renderer.emit_info("No unfinished tasks to roll over.");
+        return;
+    }
+    let destination = match rollover.target_sprint {
+        Some(id) if rollover.created_sprint => format!("new sprint #{}", id),
+        Some(id) => format!("sprint #{}", id),
+        None => "the backlog".to_string(),
+    };
+    renderer.emit_success(format_args!(
+        "Rolled {} unfinished task(s) over to {}: {}",
+        rollover.tasks.len(),
+        destination,
+        rollover.tasks.join(", ")
+    ));
+}
+
 #[derive(Debug, Serialize)]
 struct SprintOperationResponse {
     status: &'static str,
     action: &'static str,
     sprint: SprintDetail,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rollover: Option<SprintRolloverOutcome>,
     applied_defaults: Vec<String>,
     warnings: Vec<SprintWarningPayload>,
 }
@@ -1,4 +1,5 @@
 use crate::output::OutputRenderer;
+use crate::services::sprint_capacity::SprintMemberCapacityPayload;
 use crate::services::sprint_reports::{
     SprintReviewContext, SprintStatsContext, SprintSummaryContext,
 };
@@ -217,6 +218,8 @@ pub(super) fn render_summary_text(renderer: &OutputRenderer, context: &SprintSum
         }
     }
 
+    render_member_capacity(renderer, &payload.metrics.members);
+
     if let Some(start) = lifecycle.actual_start.as_ref() {
         renderer.emit_raw_stdout(format_args!("Started: {}", start.to_rfc3339()));
     } else if let Some(start) = lifecycle.planned_start.as_ref() {
@@ -350,6 +353,8 @@ pub(super) fn render_stats_text(renderer: &OutputRenderer, context: &SprintStats
         }
     }
 
+    render_member_capacity(renderer, &payload.metrics.members);
+
     renderer.emit_raw_stdout("Timeline:");
     if let Some(start) = lifecycle.planned_start.as_ref() {
         renderer.emit_raw_stdout(format_args!("  Planned start: {}", start.to_rfc3339()));
@@ -460,3 +465,39 @@ pub(super) fn emit_actual(renderer: &OutputRenderer, actual: &SprintActual) {
         renderer.emit_raw_stdout(format_args!("  closed_at: {}", closed_at));
     }
 }
+
+fn render_member_capacity(renderer: &OutputRenderer, members: &[SprintMemberCapacityPayload]) {
+    if members.is_empty() {
+        return;
+    }
+    renderer.emit_raw_stdout("Capacity by member:");
+    for member in members {
+        let available = member
+            .available_hours
+            .map(|hours| format!("{}h available", format_float(hours)))
+            .unwrap_or_else(|| "availability unknown".to_string());
+        let points = if member.planned_points > 0.0 {
+            format!(" • {} pts", format_float(member.planned_points))
+        } else {
+            String::new()
+        };
+        let time_off = if member.time_off_days > 0 {
+            format!(" • {} day(s) off", member.time_off_days)
+        } else {
+            String::new()
+        };
+        renderer.emit_raw_stdout(format_args!(
+            "  - {}: {}h planned / {}{}{}",
+            member.member,
+            format_float(member.planned_hours),
+            available,
+            points,
+            time_off
+        ));
+    }
+    for member in members {
+        if let Some(message) = member.describe_overload() {
+            renderer.emit_warning(message);
+        }
+    }
+}
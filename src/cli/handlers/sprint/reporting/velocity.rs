@@ -118,6 +118,12 @@ fn render_velocity_text(
             capacity_display,
             entry.relative
         ));
+        if entry.spillover > 0.0 {
+            renderer.emit_raw_stdout(format_args!(
+                "     spillover: {} rolled over on close",
+                format_velocity_value(computation.metric, entry.spillover)
+            ));
+        }
     }
 
     if let Some(avg_velocity) = computation.average_velocity {
@@ -0,0 +1,241 @@
+use crate::api_types::TaskUpdate;
+use crate::cli::StartArgs;
+use crate::cli::handlers::CommandHandler;
+use crate::cli::handlers::task::context::TaskCommandContext;
+use crate::cli::handlers::task::errors::TaskStorageAction;
+use crate::cli::handlers::task::mutation::{LoadedTask, load_task};
+use crate::cli::validation::CliValidator;
+use crate::output::{OutputFormat, OutputRenderer};
+use crate::services::sprint_metrics::determine_in_progress_status_from_config;
+use crate::services::task_service::TaskService;
+use crate::utils::git::{find_repo_root, read_current_branch};
+use crate::utils::identity::resolve_me_alias;
+use crate::utils::task_intel::render_branch_name;
+use crate::workspace::TasksDirectoryResolver;
+use serde_json::json;
+use std::path::Path;
+use std::process::Command;
+
+/// Handler for `lotar start <ID>`: branch from the ticket, then mark it as started.
+pub struct StartHandler;
+
+impl CommandHandler for StartHandler {
+    type Args = StartArgs;
+    type Result = Result<(), String>;
+
+    fn execute(
+        args: Self::Args,
+        project: Option<&str>,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Self::Result {
+        let mut ctx = TaskCommandContext::new(resolver, project, Some(args.id.as_str()))?;
+        let LoadedTask { full_id, task, .. } = load_task(&mut ctx, &args.id, project)?;
+
+        let branch = render_branch_name(
+            &ctx.config.branch_template,
+            &full_id,
+            task.task_type.as_str(),
+            &task.title,
+        );
+
+        let target_status = match args.status.as_deref() {
+            Some(raw) => Some(
+                CliValidator::new(&ctx.config)
+                    .validate_status(raw)
+                    .map_err(|e| format!("Status validation failed: {}", e))?,
+            ),
+            None => determine_in_progress_status_from_config(&ctx.config),
+        };
+        if target_status.is_none() {
+            renderer.emit_warning(
+                "No in-progress status found in issue.states; pass --status to choose one",
+            );
+        }
+        let status_change = target_status.filter(|status| *status != task.status);
+
+        let assignee_change = if args.no_assign {
+            None
+        } else {
+            match resolve_me_alias("@me", Some(ctx.storage_root())) {
+                Some(me) => Some(me),
+                None => {
+                    renderer.emit_warning("Could not resolve @me; leaving the assignee unchanged");
+                    None
+                }
+            }
+        }
+        .filter(|me| task.assignee.as_deref() != Some(me.as_str()));
+
+        let cwd = std::env::current_dir()
+            .map_err(|err| format!("Failed to determine current directory: {err}"))?;
+        let repo_root = find_repo_root(&cwd).ok_or_else(|| {
+            "Git repository not found. Run this command inside the repository to branch from."
+                .to_string()
+        })?;
+        let checkout = plan_checkout(&repo_root, &branch)?;
+
+        if args.dry_run {
+            emit_result(
+                renderer,
+                &full_id,
+                &branch,
+                checkout,
+                status_change.as_ref().map(|s| s.as_str()),
+                assignee_change.as_deref(),
+                true,
+            );
+            return Ok(());
+        }
+
+        // Switch branches first so a dirty tree or bad base leaves the task untouched.
+        run_checkout(&repo_root, &branch, checkout, args.base.as_deref())?;
+
+        if status_change.is_some() || assignee_change.is_some() {
+            let patch = TaskUpdate {
+                status: status_change.clone(),
+                assignee: assignee_change.clone(),
+                ..TaskUpdate::default()
+            };
+            TaskService::update(&mut ctx.storage, &full_id, patch)
+                .map_err(TaskStorageAction::Update.map_err(&full_id))?;
+        }
+
+        emit_result(
+            renderer,
+            &full_id,
+            &branch,
+            checkout,
+            status_change.as_ref().map(|s| s.as_str()),
+            assignee_change.as_deref(),
+            false,
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Checkout {
+    /// Already on the branch
+    Current,
+    /// Branch exists and gets checked out
+    Existing,
+    /// Branch is created from HEAD (or --base)
+    Create,
+}
+
+impl Checkout {
+    fn label(self) -> &'static str {
+        match self {
+            Checkout::Current => "current",
+            Checkout::Existing => "existing",
+            Checkout::Create => "created",
+        }
+    }
+}
+
+fn plan_checkout(repo_root: &Path, branch: &str) -> Result<Checkout, String> {
+    if read_current_branch(repo_root).as_deref() == Some(branch) {
+        return Ok(Checkout::Current);
+    }
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(["rev-parse", "--verify", "--quiet"])
+        .arg(format!("refs/heads/{}", branch))
+        .output()
+        .map_err(|err| format!("Failed to execute git: {err}"))?
+        .status;
+    Ok(if status.success() {
+        Checkout::Existing
+    } else {
+        Checkout::Create
+    })
+}
+
+fn run_checkout(
+    repo_root: &Path,
+    branch: &str,
+    checkout: Checkout,
+    base: Option<&str>,
+) -> Result<(), String> {
+    let mut cmd = Command::new("git");
+    cmd.arg("-C").arg(repo_root).arg("checkout").arg("--quiet");
+    match checkout {
+        Checkout::Current => return Ok(()),
+        Checkout::Existing => {
+            cmd.arg(branch);
+        }
+        Checkout::Create => {
+            cmd.arg("-b").arg(branch);
+            if let Some(base) = base {
+                cmd.arg(base);
+            }
+        }
+    }
+    let output = cmd
+        .output()
+        .map_err(|err| format!("Failed to execute git: {err}"))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "git checkout '{}' failed: {}",
+            branch,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+fn emit_result(
+    renderer: &OutputRenderer,
+    task_id: &str,
+    branch: &str,
+    checkout: Checkout,
+    status: Option<&str>,
+    assignee: Option<&str>,
+    dry_run: bool,
+) {
+    if matches!(renderer.format, OutputFormat::Json) {
+        renderer.emit_json(&json!({
+            "status": if dry_run { "preview" } else { "success" },
+            "action": "start",
+            "task_id": task_id,
+            "branch": branch,
+            "checkout": checkout.label(),
+            "new_status": status,
+            "assignee": assignee,
+        }));
+        return;
+    }
+
+    let mut changes = Vec::new();
+    if let Some(status) = status {
+        changes.push(format!("status → {}", status));
+    }
+    if let Some(assignee) = assignee {
+        changes.push(format!("assignee → {}", assignee));
+    }
+    let suffix = if changes.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", changes.join(", "))
+    };
+
+    if dry_run {
+        let action = match checkout {
+            Checkout::Current => "Would stay on",
+            Checkout::Existing => "Would check out",
+            Checkout::Create => "Would create",
+        };
+        renderer.emit_notice(format_args!(
+            "DRY RUN: {} branch '{}' for {}{}",
+            action, branch, task_id, suffix
+        ));
+    } else {
+        renderer.emit_success(format_args!(
+            "Started {} on branch '{}'{}",
+            task_id, branch, suffix
+        ));
+    }
+}
@@ -68,6 +68,7 @@ pub(crate) fn run(
         until_dt,
         gb,
         scope_project.as_deref(),
+        &crate::utils::member::MemberDirectory::load(Some(resolver.path.as_path())),
     )?;
     let limited: Vec<_> = items.into_iter().take(limit).collect();
 
@@ -57,6 +57,7 @@ pub(crate) fn run(
         since_dt,
         until_dt,
         scope_project.as_deref(),
+        &crate::utils::member::MemberDirectory::load(Some(resolver.path.as_path())),
     )?;
     let limited: Vec<_> = items.into_iter().take(limit).collect();
 
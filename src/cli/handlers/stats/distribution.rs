@@ -25,6 +25,8 @@ pub(crate) fn run(
     };
     let tasks = crate::services::task_service::TaskService::list(&storage, &filter);
 
+    let members = crate::utils::member::MemberDirectory::load(Some(resolver.path.as_path()));
+
     use std::collections::HashMap;
     let mut freq: HashMap<String, usize> = HashMap::new();
     for (_id, t) in tasks.into_iter() {
@@ -39,11 +41,11 @@ pub(crate) fn run(
                 *freq.entry(t.task_type.to_string()).or_insert(0) += 1;
             }
             crate::cli::args::stats::StatsDistributionField::Assignee => {
-                let key = t.assignee.unwrap_or_default();
+                let key = members.canonical(&t.assignee.unwrap_or_default());
                 *freq.entry(key).or_insert(0) += 1;
             }
             crate::cli::args::stats::StatsDistributionField::Reporter => {
-                let key = t.reporter.unwrap_or_default();
+                let key = members.canonical(&t.reporter.unwrap_or_default());
                 *freq.entry(key).or_insert(0) += 1;
             }
             crate::cli::args::stats::StatsDistributionField::Project => {
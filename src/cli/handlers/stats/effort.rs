@@ -357,6 +357,7 @@ pub(crate) fn run(
                                         subtitle: task.subtitle,
                                         description: task.description,
                                         tags: task.tags,
+                                        acceptance_criteria: task.acceptance_criteria,
                                         relationships: task.relationships,
                                         comments: task.comments,
                                         references: task.references,
@@ -482,6 +483,7 @@ pub(crate) fn run(
                                 subtitle: None,
                                 description: None,
                                 tags,
+                                acceptance_criteria: Vec::new(),
                                 relationships,
                                 comments,
                                 references,
@@ -510,6 +512,11 @@ pub(crate) fn run(
         }
     }
 
+    // Member aliases collapse onto their handle so one person is one group
+    let members = crate::utils::member::MemberDirectory::load(Some(resolver.path.as_path()));
+    let canonical_member =
+        |value: &Option<String>| members.canonical(value.as_deref().unwrap_or_default());
+
     // Unified field resolver: returns optional grouping key for a task given a key pattern
     let resolve_group_key = |id: &str,
                              t: &crate::api_types::TaskDTO,
@@ -522,10 +529,10 @@ pub(crate) fn run(
         if let Some(canon) = crate::utils::fields::is_reserved_field(raw) {
             match canon {
                 "assignee" => {
-                    return Some(vec![t.assignee.clone().unwrap_or_default()]);
+                    return Some(vec![canonical_member(&t.assignee)]);
                 }
                 "reporter" => {
-                    return Some(vec![t.reporter.clone().unwrap_or_default()]);
+                    return Some(vec![canonical_member(&t.reporter)]);
                 }
                 "type" => return Some(vec![t.task_type.to_string()]),
                 "status" => return Some(vec![t.status.to_string()]),
@@ -603,6 +610,10 @@ pub(crate) fn run(
         } else {
             v
         };
+        let filter_value = match crate::utils::fields::is_reserved_field(&k) {
+            Some("assignee") | Some("reporter") => members.canonical(&filter_value),
+            _ => filter_value,
+        };
         filters.entry(k).or_default().insert(filter_value);
     }
 
@@ -675,10 +686,8 @@ pub(crate) fn run(
         .map_err(|e| format!("Failed to load config: {}", e))?;
         let cfg = cfg_mgr.get_resolved_config();
         let mut keys = resolve_group_key(&id, &t, by, cfg).unwrap_or_else(|| vec![String::new()]);
-        if by.trim().to_lowercase() == "assignee"
-            && let Some(a) = &t.assignee
-        {
-            keys = vec![a.clone()];
+        if by.trim().to_lowercase() == "assignee" && t.assignee.is_some() {
+            keys = vec![canonical_member(&t.assignee)];
         }
         let keys = if keys.is_empty() {
             vec![String::new()]
@@ -0,0 +1,156 @@
+use crate::output::{OutputFormat, OutputRenderer};
+use crate::services::flow_metrics::{self, DurationPercentiles};
+use crate::storage::manager::Storage;
+use crate::workspace::TasksDirectoryResolver;
+
+fn resolve_scope(
+    global: bool,
+    project: Option<&str>,
+    resolver: &TasksDirectoryResolver,
+) -> Option<String> {
+    if global {
+        None
+    } else {
+        project
+            .map(|p| crate::utils::resolve_project_input(p, resolver.path.as_path()))
+            .or_else(|| Some(crate::project::get_effective_project_name(resolver)))
+    }
+}
+
+fn repo_root() -> Option<std::path::PathBuf> {
+    let cwd = std::env::current_dir().ok()?;
+    crate::utils::git::find_repo_root(&cwd)
+}
+
+fn with_envelope(action: &str, global: bool, payload: impl serde::Serialize) -> serde_json::Value {
+    let mut value = serde_json::to_value(payload).unwrap_or_default();
+    if let Some(map) = value.as_object_mut() {
+        map.insert("status".into(), "ok".into());
+        map.insert("action".into(), action.into());
+        map.insert("global".into(), global.into());
+    }
+    value
+}
+
+pub(crate) fn run_flow(
+    since: Option<&str>,
+    until: Option<&str>,
+    global: bool,
+    project: Option<&str>,
+    resolver: &TasksDirectoryResolver,
+    renderer: &OutputRenderer,
+) -> Result<(), String> {
+    let (since_dt, until_dt) = crate::utils::time::parse_since_until(since, until)?;
+    let scope_project = resolve_scope(global, project, resolver);
+    let storage = Storage::new(&resolver.path.clone());
+    let report = flow_metrics::flow_report(
+        &storage,
+        repo_root().as_deref(),
+        scope_project.as_deref(),
+        since_dt,
+        until_dt,
+    );
+
+    match renderer.format {
+        OutputFormat::Json => renderer.emit_json(&with_envelope("stats.flow", global, &report)),
+        _ => {
+            if report.days.iter().all(|day| day.total == 0) {
+                renderer.emit_success("No tasks in the selected window.");
+                return Ok(());
+            }
+            let width = report
+                .statuses
+                .iter()
+                .map(|status| status.len().max(5))
+                .collect::<Vec<_>>();
+            let header = report
+                .statuses
+                .iter()
+                .zip(&width)
+                .map(|(status, w)| format!("{:>w$}", status, w = *w))
+                .collect::<Vec<_>>()
+                .join("  ");
+            renderer.emit_raw_stdout(format_args!("{:<10}  {}  {:>5}", "Date", header, "Total"));
+            for day in &report.days {
+                let cells = report
+                    .statuses
+                    .iter()
+                    .zip(&width)
+                    .map(|(status, w)| {
+                        format!(
+                            "{:>w$}",
+                            day.counts.get(status).copied().unwrap_or(0),
+                            w = *w
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("  ");
+                renderer.emit_raw_stdout(format_args!(
+                    "{:<10}  {}  {:>5}",
+                    day.date, cells, day.total
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn run_cycle_time(
+    since: Option<&str>,
+    until: Option<&str>,
+    global: bool,
+    project: Option<&str>,
+    resolver: &TasksDirectoryResolver,
+    renderer: &OutputRenderer,
+) -> Result<(), String> {
+    let (since_dt, until_dt) = crate::utils::time::parse_since_until(since, until)?;
+    let scope_project = resolve_scope(global, project, resolver);
+    let storage = Storage::new(&resolver.path.clone());
+    let report = flow_metrics::cycle_time_report(
+        &storage,
+        repo_root().as_deref(),
+        scope_project.as_deref(),
+        since_dt,
+        until_dt,
+    );
+
+    match renderer.format {
+        OutputFormat::Json => {
+            renderer.emit_json(&with_envelope("stats.cycle_time", global, &report))
+        }
+        _ => {
+            if report.overall.completed == 0 {
+                renderer.emit_success("No tasks completed in the selected window.");
+                return Ok(());
+            }
+            let fmt = |value: Option<&DurationPercentiles>| match value {
+                Some(p) => format!("{:>6.1} {:>6.1} {:>6.1}", p.p50, p.p85, p.p95),
+                None => format!("{:>6} {:>6} {:>6}", "-", "-", "-"),
+            };
+            renderer.emit_raw_stdout(format_args!(
+                "{:<12} {:>5}  {:<20}  {:<20}",
+                "Type", "Done", "Lead p50/p85/p95 (d)", "Cycle p50/p85/p95 (d)"
+            ));
+            for group in report
+                .by_type
+                .iter()
+                .chain(std::iter::once(&report.overall))
+            {
+                renderer.emit_raw_stdout(format_args!(
+                    "{:<12} {:>5}  {}  {}",
+                    group.task_type,
+                    group.completed,
+                    fmt(group.lead_time_days.as_ref()),
+                    fmt(group.cycle_time_days.as_ref())
+                ));
+            }
+            renderer.emit_raw_stdout("");
+            renderer.emit_raw_stdout("Throughput per week:");
+            for week in &report.throughput {
+                renderer
+                    .emit_raw_stdout(format_args!("  {}  {:>4}", week.week_start, week.completed));
+            }
+        }
+    }
+    Ok(())
+}
@@ -7,6 +7,7 @@ mod custom;
 mod distribution;
 mod due;
 mod effort;
+mod flow;
 mod history;
 mod stale;
 mod status;
@@ -63,6 +64,30 @@ impl CommandHandler for StatsHandler {
                 resolver,
                 renderer,
             ),
+            StatsAction::Flow {
+                since,
+                until,
+                global,
+            } => flow::run_flow(
+                since.as_deref(),
+                until.as_deref(),
+                global,
+                project,
+                resolver,
+                renderer,
+            ),
+            StatsAction::CycleTime {
+                since,
+                until,
+                global,
+            } => flow::run_cycle_time(
+                since.as_deref(),
+                until.as_deref(),
+                global,
+                project,
+                resolver,
+                renderer,
+            ),
             StatsAction::Effort {
                 by,
                 r#where,
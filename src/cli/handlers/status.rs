@@ -326,6 +326,11 @@ fn resolve_auto_assign_candidate(
         && let Some(codeowners) = crate::utils::codeowners::CodeOwners::load_from_repo(&repo_root)
         && let Some(owner) = codeowners.default_owner()
     {
+        // CODEOWNERS lists GitHub logins; map them onto member handles when known.
+        let members = crate::utils::member::MemberDirectory::from_config(config);
+        if members.find(&owner).is_some() {
+            return Some(members.canonical(&owner));
+        }
         return Some(owner);
     }
 
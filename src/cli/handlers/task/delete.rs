@@ -3,6 +3,7 @@ use crate::cli::handlers::CommandHandler;
 use crate::cli::handlers::task::context::TaskCommandContext;
 use crate::cli::handlers::task::errors::TaskStorageAction;
 use crate::cli::handlers::task::mutation::{LoadedTask, load_task};
+use crate::services::task_service::TaskService;
 use crate::workspace::TasksDirectoryResolver;
 
 /// Handler for deleting tasks
@@ -68,10 +69,9 @@ impl CommandHandler for DeleteHandler {
         }
 
         // Delete the task
-        let deleted = ctx
-            .storage
-            .delete(&full_id, &project_prefix)
-            .map_err(TaskStorageAction::Delete.map_err(&full_id))?;
+        let deleted =
+            TaskService::delete(&mut ctx.storage, &full_id, Some(project_prefix.as_str()))
+                .map_err(TaskStorageAction::Delete.map_err(&full_id))?;
         if deleted {
             match renderer.format {
                 crate::output::OutputFormat::Json => {
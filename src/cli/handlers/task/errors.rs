@@ -20,6 +20,13 @@ impl TaskStorageAction {
     /// Format a user-facing error message for a storage failure.
     #[allow(clippy::needless_pass_by_value)]
     pub fn format_error(self, subject: &str, err: LoTaRError) -> String {
+        // Approval gates are not storage failures; their message stands on its own.
+        if matches!(
+            err,
+            LoTaRError::ApprovalPending(_) | LoTaRError::PermissionDenied(_)
+        ) {
+            return err.to_string();
+        }
         let permission_hint = match &err {
             LoTaRError::IoError(io_err)
                 if io_err.kind() == std::io::ErrorKind::PermissionDenied =>
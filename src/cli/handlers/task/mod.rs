@@ -21,12 +21,14 @@ pub(crate) mod mutation;
 mod reference;
 pub(crate) mod render;
 mod search;
+mod transfer;
 
 use delete::DeleteHandler;
 use edit::EditHandler;
 use history::{handle_at, handle_diff, handle_history, handle_history_by_field};
 use reference::handle_reference;
 use search::SearchHandler;
+use transfer::{CopyHandler, MoveHandler};
 
 /// Handler for all task subcommands
 pub struct TaskHandler;
@@ -136,6 +138,12 @@ impl CommandHandler for TaskHandler {
             TaskAction::Delete(delete_args) => {
                 DeleteHandler::execute(delete_args, project, resolver, renderer)
             }
+            TaskAction::Move(move_args) => {
+                MoveHandler::execute(move_args, project, resolver, renderer)
+            }
+            TaskAction::Copy(copy_args) => {
+                CopyHandler::execute(copy_args, project, resolver, renderer)
+            }
             TaskAction::Comment {
                 id,
                 text,
@@ -3,7 +3,7 @@ use crate::cli::handlers::CommandHandler;
 use crate::cli::handlers::task::context::TaskCommandContext;
 use crate::cli::validation::CliValidator;
 use crate::config::types::ResolvedConfig;
-use crate::storage::{TaskFilter, task::Task};
+use crate::storage::{TaskFilter, TaskParseWarning, task::Task};
 use crate::workspace::TasksDirectoryResolver;
 
 /// Handler for searching tasks
@@ -28,8 +28,14 @@ impl CommandHandler for SearchHandler {
         drop(validator);
 
         renderer.log_debug("list: executing search");
-        let mut tasks: Vec<(String, Task)> =
-            ctx.storage.search(&build.task_filter).into_iter().collect();
+        let (mut tasks, parse_warnings) = ctx.storage.search_with_warnings(&build.task_filter);
+        Self::report_parse_warnings(renderer, &parse_warnings);
+        if args.strict && !parse_warnings.is_empty() {
+            return Err(format!(
+                "{} task file(s) could not be parsed (--strict); fix them or run `lotar doctor`",
+                parse_warnings.len()
+            ));
+        }
 
         TaskPostFilters::new(&args, &ctx.config, resolver, build.where_filters)
             .apply(&mut tasks)?;
@@ -40,13 +46,38 @@ impl CommandHandler for SearchHandler {
         let (offset, limit) = Self::resolve_pagination(&args)?;
         Self::apply_offset_and_limit(&mut tasks, offset, limit);
 
-        Self::render_results(renderer, tasks, total_matching, offset, limit, args.details);
+        Self::render_results(
+            renderer,
+            tasks,
+            &parse_warnings,
+            total_matching,
+            offset,
+            limit,
+            args.details,
+        );
 
         Ok(())
     }
 }
 
 impl SearchHandler {
+    /// Tell the user about task files that were skipped because they do not parse.
+    /// JSON output carries them in the `warnings` array instead.
+    fn report_parse_warnings(
+        renderer: &crate::output::OutputRenderer,
+        warnings: &[TaskParseWarning],
+    ) {
+        if warnings.is_empty() || matches!(renderer.format, crate::output::OutputFormat::Json) {
+            return;
+        }
+        for warning in warnings {
+            renderer.emit_warning(format_args!(
+                "Skipped {} (could not parse {})",
+                warning.id, warning
+            ));
+        }
+    }
+
     fn build_task_filter(
         args: &TaskSearchArgs,
         validator: &CliValidator,
@@ -237,6 +268,7 @@ impl SearchHandler {
     fn render_results(
         renderer: &crate::output::OutputRenderer,
         tasks: Vec<(String, Task)>,
+        warnings: &[TaskParseWarning],
         total_matching: usize,
         offset: usize,
         limit: usize,
@@ -273,6 +305,7 @@ impl SearchHandler {
                             "has_previous": offset > 0,
                             "next_offset": serde_json::Value::Null,
                             "next_page": serde_json::Value::Null,
+                            "warnings": warnings,
                         })
                         .to_string(),
                     );
@@ -357,6 +390,7 @@ impl SearchHandler {
                         "has_previous": has_previous,
                         "next_offset": next_offset,
                         "next_page": next_page,
+                        "warnings": warnings,
                     })
                     .to_string(),
                 );
@@ -457,7 +491,12 @@ impl<'a> TaskPostFilters<'a> {
             };
 
             if let Some(user) = target {
-                tasks.retain(|(_, task)| task.assignee.as_ref() == Some(&user));
+                let directory = self.member_directory();
+                tasks.retain(|(_, task)| {
+                    task.assignee
+                        .as_deref()
+                        .is_some_and(|a| directory.same_member(a, &user))
+                });
             } else {
                 tasks.clear();
             }
@@ -469,13 +508,22 @@ impl<'a> TaskPostFilters<'a> {
             if let Some(me) =
                 crate::utils::identity::resolve_current_user(Some(self.resolver.path.as_path()))
             {
-                tasks.retain(|(_, task)| task.assignee.as_ref() == Some(&me));
+                let directory = self.member_directory();
+                tasks.retain(|(_, task)| {
+                    task.assignee
+                        .as_deref()
+                        .is_some_and(|a| directory.same_member(a, &me))
+                });
             } else {
                 tasks.clear();
             }
         }
     }
 
+    fn member_directory(&self) -> crate::utils::member::MemberDirectory {
+        crate::utils::member::MemberDirectory::load(Some(self.resolver.path.as_path()))
+    }
+
     fn apply_priority_flags(&self, tasks: &mut Vec<(String, Task)>) {
         if self.args.high {
             tasks.retain(|(_, task)| task.priority.eq_ignore_case("high"));
@@ -0,0 +1,122 @@
+use crate::cli::TaskTransferArgs;
+use crate::cli::handlers::CommandHandler;
+use crate::cli::handlers::task::context::TaskCommandContext;
+use crate::cli::handlers::task::mutation::{LoadedTask, load_task};
+use crate::output::{OutputFormat, OutputRenderer};
+use crate::services::task_relocation_service::{Relocation, RewriteTarget, TaskRelocationService};
+use crate::workspace::TasksDirectoryResolver;
+
+/// Handler for `lotar task move`
+pub struct MoveHandler;
+
+/// Handler for `lotar task copy`
+pub struct CopyHandler;
+
+#[derive(Clone, Copy)]
+enum Transfer {
+    Move,
+    Copy,
+}
+
+impl CommandHandler for MoveHandler {
+    type Args = TaskTransferArgs;
+    type Result = Result<(), String>;
+
+    fn execute(
+        args: Self::Args,
+        project: Option<&str>,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Self::Result {
+        transfer(Transfer::Move, args, project, resolver, renderer)
+    }
+}
+
+impl CommandHandler for CopyHandler {
+    type Args = TaskTransferArgs;
+    type Result = Result<(), String>;
+
+    fn execute(
+        args: Self::Args,
+        project: Option<&str>,
+        resolver: &TasksDirectoryResolver,
+        renderer: &OutputRenderer,
+    ) -> Self::Result {
+        transfer(Transfer::Copy, args, project, resolver, renderer)
+    }
+}
+
+fn transfer(
+    kind: Transfer,
+    args: TaskTransferArgs,
+    project: Option<&str>,
+    resolver: &TasksDirectoryResolver,
+    renderer: &OutputRenderer,
+) -> Result<(), String> {
+    let TaskTransferArgs { id, to, dry_run } = args;
+    let mut ctx = TaskCommandContext::new(resolver, project, Some(id.as_str()))?;
+    let LoadedTask { full_id, .. } = load_task(&mut ctx, &id, project)?;
+
+    let relocation = match kind {
+        Transfer::Move => TaskRelocationService::move_task(resolver, &full_id, &to, dry_run),
+        Transfer::Copy => TaskRelocationService::copy_task(resolver, &full_id, &to, dry_run),
+    }
+    .map_err(|e| e.to_string())?;
+
+    match renderer.format {
+        OutputFormat::Json => {
+            let mut value = serde_json::to_value(&relocation).unwrap_or_default();
+            if let Some(map) = value.as_object_mut() {
+                let status = if dry_run { "preview" } else { "success" };
+                let action = match kind {
+                    Transfer::Move => "move",
+                    Transfer::Copy => "copy",
+                };
+                map.insert("status".into(), status.into());
+                map.insert("action".into(), action.into());
+                map.insert("dry_run".into(), dry_run.into());
+            }
+            renderer.emit_json(&value);
+        }
+        _ => render_text(kind, &relocation, dry_run, renderer),
+    }
+    Ok(())
+}
+
+fn render_text(kind: Transfer, relocation: &Relocation, dry_run: bool, renderer: &OutputRenderer) {
+    let verb = match (kind, dry_run) {
+        (Transfer::Move, true) => "Would move",
+        (Transfer::Move, false) => "Moved",
+        (Transfer::Copy, true) => "Would copy",
+        (Transfer::Copy, false) => "Copied",
+    };
+    renderer.emit_success(format_args!(
+        "{} {} → {}",
+        verb, relocation.from, relocation.to
+    ));
+    for rewrite in &relocation.rewrites {
+        match rewrite.target {
+            RewriteTarget::Attachment => {
+                renderer.emit_raw_stdout(format_args!("  attachment {}", rewrite.path))
+            }
+            _ => renderer.emit_raw_stdout(format_args!(
+                "  {} reference(s) in {}",
+                rewrite.count, rewrite.path
+            )),
+        }
+    }
+    for change in &relocation.mapped {
+        renderer.emit_warning(format_args!(
+            "{} '{}' is not used in the target project; set to '{}'",
+            change.field,
+            change.old.as_deref().unwrap_or_default(),
+            change.new.as_deref().unwrap_or_default()
+        ));
+    }
+    if matches!(kind, Transfer::Move) && !dry_run {
+        renderer.emit_info(format_args!(
+            "{} now redirects to {}",
+            relocation.from, relocation.to
+        ));
+    }
+}
@@ -6,13 +6,14 @@ use std::sync::LazyLock;
 pub mod args;
 pub use args::{
     AddArgs, AgentAction, AgentArgs, AgentCheckArgs, AgentRunArgs, AgentWorkerArgs,
-    AutomationAction, AutomationArgs, AutomationSimulateArgs, CompletionShell, CompletionsAction,
-    CompletionsArgs, ConfigAction, ConfigInitArgs, ConfigNormalizeArgs, ConfigSetArgs,
-    ConfigShowArgs, ConfigValidateArgs, GitAction, GitHooksAction, GitHooksInstallArgs,
-    IndexAction, IndexArgs, ScanArgs, ServeArgs, SortField, SprintAction, SprintArgs,
-    SprintCreateArgs, SprintListArgs, SprintShowArgs, StatsArgs, SyncArgs, SyncCheckArgs,
-    SyncCommandAction, SyncCommandArgs, TaskAction, TaskAddArgs, TaskDeleteArgs, TaskEditArgs,
-    TaskSearchArgs, TaskStatusArgs, parse_key_value,
+    AutomationAction, AutomationArgs, AutomationSimulateArgs, CheckArgs, CompletionShell,
+    CompletionsAction, CompletionsArgs, ConfigAction, ConfigInitArgs, ConfigNormalizeArgs,
+    ConfigSetArgs, ConfigShowArgs, ConfigValidateArgs, DoctorArgs, ExportArgs, ForecastArgs,
+    GitAction, GitHooksAction, GitHooksInstallArgs, IndexAction, IndexArgs, MergeDriverArgs,
+    MigrateArgs, RenumberArgs, ScanArgs, SchemaArgs, ServeArgs, SortField, SprintAction,
+    SprintArgs, SprintCreateArgs, SprintListArgs, SprintShowArgs, StartArgs, StatsArgs, SyncArgs,
+    SyncCheckArgs, SyncCommandAction, SyncCommandArgs, TaskAction, TaskAddArgs, TaskDeleteArgs,
+    TaskEditArgs, TaskSearchArgs, TaskStatusArgs, TaskTransferArgs, parse_key_value,
 };
 pub mod preprocess;
 
@@ -151,6 +152,9 @@ pub enum Commands {
         explain: bool,
     },
 
+    /// Start work on a task: check out its branch, mark it in progress and assign to @me
+    Start(StartArgs),
+
     /// Full task management (existing functionality)
     #[command(alias = "tasks")]
     Task {
@@ -170,6 +174,9 @@ pub enum Commands {
     /// Scan source files for TODO comments (existing)
     Scan(ScanArgs),
 
+    /// Check TODO comments for missing, unknown or done ticket references
+    Check(CheckArgs),
+
     /// Pull tasks from a configured sync remote
     Pull(SyncArgs),
 
@@ -194,6 +201,27 @@ pub enum Commands {
     /// Sprint storage management (create/list/show commands)
     Sprint(SprintArgs),
 
+    /// Forecast completion dates from historical throughput (Monte Carlo)
+    Forecast(ForecastArgs),
+
+    /// Export tasks and sprints to other formats (iCalendar)
+    Export(ExportArgs),
+
+    /// Upgrade config and task files to the current schema version
+    Migrate(MigrateArgs),
+
+    /// JSON Schemas for editor completion and validation of .tasks YAML files
+    Schema(SchemaArgs),
+
+    /// Check the whole task store for integrity problems (optionally repair them)
+    Doctor(DoctorArgs),
+
+    /// Three-way merge of task and sprint YAML, invoked by git (see `lotar git install-merge-driver`)
+    MergeDriver(MergeDriverArgs),
+
+    /// Move a task to a new ID, or split the ID collisions left by a branch merge
+    Renumber(RenumberArgs),
+
     /// Show task changes (default: vs HEAD working tree; optionally vs a ref)
     Changelog {
         /// Compare since this git ref (e.g., HEAD~1, a tag, or a commit); if omitted, compares working tree vs HEAD
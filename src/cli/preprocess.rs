@@ -348,9 +348,124 @@ fn find_serve_index(raw_args: &[String]) -> Option<usize> {
     None
 }
 
+/// Quick commands whose first positional is a task ID, with the options that take a value.
+const TASK_ID_COMMANDS: &[(&str, &[&str])] = &[
+    ("status", &[]),
+    ("priority", &[]),
+    ("assignee", &[]),
+    ("due-date", &[]),
+    ("effort", &[]),
+    ("comment", &["-m", "--message", "-F", "--file"]),
+    ("c", &["-m", "--message", "-F", "--file"]),
+];
+
+/// Insert the task ID for quick commands that were run without one.
+///
+/// `lotar status Done` on a branch created by `lotar start PROJ-12` becomes
+/// `lotar status PROJ-12 Done`. The first positional counts as an ID when it is a
+/// `PROJ-12` key, or a bare number followed by a value (`effort 5 3h`); otherwise
+/// `branch_task_id` is consulted, and only then, so git is never touched for fully
+/// specified commands.
+pub fn default_task_id(
+    args: &[String],
+    branch_task_id: impl FnOnce() -> Option<String>,
+) -> Vec<String> {
+    let Some(cmd_idx) = find_subcommand_index(args) else {
+        return args.to_vec();
+    };
+    let Some((_, value_flags)) = TASK_ID_COMMANDS
+        .iter()
+        .find(|(name, _)| *name == args[cmd_idx])
+    else {
+        return args.to_vec();
+    };
+
+    let mut idx = cmd_idx + 1;
+    let mut positionals: Vec<&String> = Vec::new();
+    while idx < args.len() {
+        let token = &args[idx];
+        if token == "--" {
+            positionals.extend(&args[idx + 1..]);
+            break;
+        }
+        if value_flags.contains(&token.as_str()) || is_global_value_flag(token) {
+            idx += 2;
+            continue;
+        }
+        if !token.starts_with('-') || token == "-" {
+            positionals.push(token);
+        }
+        idx += 1;
+    }
+
+    // A bare number is a value (`effort 5`, `priority 2`) unless a value follows it.
+    let explicit = match positionals.first() {
+        Some(first) if is_numeric(first) => positionals.len() > 1,
+        Some(first) => looks_like_task_id(first),
+        None => false,
+    };
+    if explicit {
+        return args.to_vec();
+    }
+    let Some(task_id) = branch_task_id() else {
+        return args.to_vec();
+    };
+
+    let mut rewritten = Vec::with_capacity(args.len() + 1);
+    rewritten.extend_from_slice(&args[..=cmd_idx]);
+    rewritten.push(task_id);
+    rewritten.extend_from_slice(&args[cmd_idx + 1..]);
+    rewritten
+}
+
+fn is_numeric(token: &str) -> bool {
+    !token.is_empty() && token.chars().all(|c| c.is_ascii_digit())
+}
+
+/// An explicit `PREFIX-N` task ID.
+fn looks_like_task_id(token: &str) -> bool {
+    let Some((prefix, number)) = token.rsplit_once('-') else {
+        return false;
+    };
+    prefix.starts_with(|c: char| c.is_ascii_alphabetic())
+        && prefix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_global_value_flag(token: &str) -> bool {
+    matches!(
+        token,
+        "--format" | "--tasks-dir" | "--log-level" | "--project" | "--config" | "-f" | "-l" | "-p"
+    )
+}
+
+/// Index of the subcommand, skipping leading global options.
+fn find_subcommand_index(raw_args: &[String]) -> Option<usize> {
+    let mut idx = 1; // skip binary name
+    while idx < raw_args.len() {
+        let token = &raw_args[idx];
+        if is_global_value_flag(token) {
+            idx += 2;
+            continue;
+        }
+        if token == "--" {
+            return None;
+        }
+        if token.starts_with('-') {
+            idx += 1;
+            continue;
+        }
+        return Some(idx);
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
-    use super::normalize_args;
+    use super::{default_task_id, normalize_args};
 
     fn to_vec(slice: &[&str]) -> Vec<String> {
         slice.iter().map(|s| s.to_string()).collect()
@@ -541,4 +656,46 @@ mod tests {
         let normalized = normalize_args(&args).unwrap();
         assert_eq!(normalized, args);
     }
+
+    #[test]
+    fn inserts_branch_task_id_when_missing() {
+        let args = to_vec(&["lotar", "--format", "json", "status", "Done"]);
+        let rewritten = default_task_id(&args, || Some("PROJ-12".to_string()));
+        assert_eq!(
+            rewritten,
+            to_vec(&["lotar", "--format", "json", "status", "PROJ-12", "Done"])
+        );
+
+        let args = to_vec(&["lotar", "comment", "-m", "PROJ-3", "--dry-run"]);
+        let rewritten = default_task_id(&args, || Some("PROJ-12".to_string()));
+        assert_eq!(
+            rewritten,
+            to_vec(&["lotar", "comment", "PROJ-12", "-m", "PROJ-3", "--dry-run"])
+        );
+    }
+
+    #[test]
+    fn bare_numbers_are_values_for_the_branch_ticket() {
+        let args = to_vec(&["lotar", "effort", "5"]);
+        let rewritten = default_task_id(&args, || Some("PROJ-12".to_string()));
+        assert_eq!(rewritten, to_vec(&["lotar", "effort", "PROJ-12", "5"]));
+
+        let args = to_vec(&["lotar", "priority", "2"]);
+        let rewritten = default_task_id(&args, || Some("PROJ-12".to_string()));
+        assert_eq!(rewritten, to_vec(&["lotar", "priority", "PROJ-12", "2"]));
+    }
+
+    #[test]
+    fn keeps_explicit_task_ids_and_other_commands() {
+        let explicit = to_vec(&["lotar", "status", "PROJ-7", "Done"]);
+        let numeric = to_vec(&["lotar", "effort", "7", "3h"]);
+        let list = to_vec(&["lotar", "list"]);
+        for args in [explicit, numeric, list] {
+            let rewritten = default_task_id(&args, || panic!("branch must not be read"));
+            assert_eq!(rewritten, args);
+        }
+
+        let args = to_vec(&["lotar", "status"]);
+        assert_eq!(default_task_id(&args, || None), args);
+    }
 }
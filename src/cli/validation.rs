@@ -322,10 +322,11 @@ impl<'a> CliValidator<'a> {
             return Ok(());
         }
 
-        let norm_val = crate::utils::member::member_for_comparison(trimmed);
+        let directory = crate::utils::member::MemberDirectory::from_config(self.config);
+        let norm_val = directory.comparison_key(trimmed);
         let permitted = allowed
             .iter()
-            .any(|candidate| crate::utils::member::member_for_comparison(candidate) == norm_val);
+            .any(|candidate| directory.comparison_key(candidate) == norm_val);
 
         if permitted {
             return Ok(());
@@ -199,6 +199,16 @@ pub const ENV_OVERRIDE_DEFINITIONS: &[EnvOverrideDefinition] = &[
         "sprints_defaults_capacity_hours",
         &["LOTAR_SPRINTS_DEFAULT_CAPACITY_HOURS"],
     ),
+    def(
+        "sprints_defaults_weekly_hours",
+        "sprints_defaults_weekly_hours",
+        &["LOTAR_SPRINTS_DEFAULT_WEEKLY_HOURS"],
+    ),
+    def(
+        "sprints_defaults_rollover",
+        "sprints_defaults_rollover",
+        &["LOTAR_SPRINTS_DEFAULT_ROLLOVER"],
+    ),
     def(
         "sprints_defaults_length",
         "sprints_defaults_length",
@@ -0,0 +1,317 @@
+//! Shared configuration layers declared with `extends:` in `.tasks/config.yml`.
+//!
+//! Each reference is either a file path or a git ref of a config repository:
+//! - `../../org-config/lotar.yml` — a file, absolute or relative to the directory of the
+//!   config that declares it (this also covers files inside another checked-out repo);
+//! - `../../org-config#main:lotar.yml` — `<repo>#<ref>:<path>`, read with `git show` from a
+//!   local clone. `<repo>` resolves like a file path and may be empty to mean the
+//!   repository holding the declaring config; `<path>` is relative to the repo root.
+//!
+//! Inherited configs may declare `extends:` themselves. Layers merge parents-first and in
+//! list order, so later entries win; the declaring config always wins over what it inherits.
+
+use crate::config::types::{ConfigError, GlobalConfig};
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+
+/// One config file pulled in through `extends:`.
+#[derive(Debug, Clone)]
+pub struct InheritedLayer {
+    /// The reference as written in the declaring config.
+    pub source: String,
+    pub config: GlobalConfig,
+    location: Location,
+}
+
+impl InheritedLayer {
+    /// Provenance label used by `config show --explain` and `config inspect`.
+    pub fn label(&self) -> String {
+        format!("inherited from {}", self.source)
+    }
+
+    /// Read a file that sits next to this layer's config (e.g. `automation.yml`).
+    pub fn read_sibling(&self, name: &str) -> Option<String> {
+        let sibling = match self.location.origin() {
+            Origin::Dir(dir) => Location::File(dir.join(name)),
+            Origin::Git { repo, rev, dir } => Location::Git {
+                repo,
+                rev,
+                path: normalize_repo_path(&dir, name),
+            },
+        };
+        read_location(name, &sibling).ok()
+    }
+}
+
+/// Where a config was read from; relative references resolve against it.
+#[derive(Debug, Clone)]
+enum Origin {
+    Dir(PathBuf),
+    Git {
+        repo: PathBuf,
+        rev: String,
+        dir: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+enum Location {
+    File(PathBuf),
+    Git {
+        repo: PathBuf,
+        rev: String,
+        path: String,
+    },
+}
+
+impl Location {
+    fn key(&self) -> String {
+        match self {
+            Location::File(path) => canonical(path).display().to_string(),
+            Location::Git { repo, rev, path } => {
+                format!("{}#{}:{}", canonical(repo).display(), rev, path)
+            }
+        }
+    }
+
+    fn origin(&self) -> Origin {
+        match self {
+            Location::File(path) => Origin::Dir(
+                path.parent()
+                    .map(Path::to_path_buf)
+                    .unwrap_or_else(|| PathBuf::from(".")),
+            ),
+            Location::Git { repo, rev, path } => Origin::Git {
+                repo: repo.clone(),
+                rev: rev.clone(),
+                dir: path
+                    .rsplit_once('/')
+                    .map(|(dir, _)| dir.to_string())
+                    .unwrap_or_default(),
+            },
+        }
+    }
+}
+
+/// Load every layer inherited by the global config in `tasks_dir`, lowest precedence first.
+pub fn load_inherited_layers(
+    tasks_dir: &Path,
+    global: &GlobalConfig,
+) -> Result<Vec<InheritedLayer>, ConfigError> {
+    if global.extends.is_empty() {
+        return Ok(Vec::new());
+    }
+    let declaring = crate::utils::paths::global_config_path(tasks_dir);
+    let mut stack = vec![(
+        Location::File(declaring.clone()).key(),
+        declaring.display().to_string(),
+    )];
+    let mut layers = Vec::new();
+    collect_layers(
+        &global.extends,
+        &Origin::Dir(tasks_dir.to_path_buf()),
+        &mut stack,
+        &mut layers,
+    )?;
+    Ok(layers)
+}
+
+fn collect_layers(
+    references: &[String],
+    origin: &Origin,
+    stack: &mut Vec<(String, String)>,
+    layers: &mut Vec<InheritedLayer>,
+) -> Result<(), ConfigError> {
+    for reference in references {
+        let reference = reference.trim();
+        if reference.is_empty() {
+            continue;
+        }
+        let location = resolve_reference(reference, origin)?;
+        let key = location.key();
+        if stack.iter().any(|(visited, _)| *visited == key) {
+            let chain = stack
+                .iter()
+                .map(|(_, shown)| shown.as_str())
+                .chain(std::iter::once(reference))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            return Err(ConfigError::ParseError(format!(
+                "Config extends cycle: {}",
+                chain
+            )));
+        }
+
+        let content = read_location(reference, &location)?;
+        let config =
+            crate::config::normalization::parse_global_from_yaml_str(&content).map_err(|e| {
+                ConfigError::ParseError(format!("Failed to parse extends '{}': {}", reference, e))
+            })?;
+
+        stack.push((key, reference.to_string()));
+        collect_layers(&config.extends, &location.origin(), stack, layers)?;
+        stack.pop();
+
+        layers.push(InheritedLayer {
+            source: reference.to_string(),
+            config,
+            location,
+        });
+    }
+    Ok(())
+}
+
+fn resolve_reference(reference: &str, origin: &Origin) -> Result<Location, ConfigError> {
+    if let Some((repo, rest)) = reference.split_once('#') {
+        let Some((rev, path)) = rest.split_once(':') else {
+            return Err(ConfigError::ParseError(format!(
+                "Invalid extends '{}': expected <repo>#<ref>:<path>",
+                reference
+            )));
+        };
+        let (rev, path) = (rev.trim(), normalize_repo_path("", path.trim()));
+        if rev.is_empty() || path.is_empty() {
+            return Err(ConfigError::ParseError(format!(
+                "Invalid extends '{}': expected <repo>#<ref>:<path>",
+                reference
+            )));
+        }
+        // A leading `-` would be read as a git option rather than a revision.
+        if rev.starts_with('-') {
+            return Err(ConfigError::ParseError(format!(
+                "Invalid extends '{}': ref '{}' must not start with '-'",
+                reference, rev
+            )));
+        }
+        let base = match origin {
+            Origin::Dir(dir) => dir.clone(),
+            Origin::Git { repo, .. } => repo.clone(),
+        };
+        let repo = repo.trim();
+        let repo = if repo.is_empty() {
+            base
+        } else {
+            base.join(expand_home(repo))
+        };
+        return Ok(Location::Git {
+            repo,
+            rev: rev.to_string(),
+            path,
+        });
+    }
+
+    let path = expand_home(reference);
+    if path.is_absolute() {
+        return Ok(Location::File(path));
+    }
+    Ok(match origin {
+        Origin::Dir(dir) => Location::File(dir.join(path)),
+        Origin::Git { repo, rev, dir } => Location::Git {
+            repo: repo.clone(),
+            rev: rev.clone(),
+            path: normalize_repo_path(dir, reference),
+        },
+    })
+}
+
+fn read_location(reference: &str, location: &Location) -> Result<String, ConfigError> {
+    match location {
+        Location::File(path) => {
+            if !path.exists() {
+                return Err(ConfigError::FileNotFound(format!(
+                    "extends '{}' ({})",
+                    reference,
+                    path.display()
+                )));
+            }
+            std::fs::read_to_string(path).map_err(|e| {
+                ConfigError::IoError(format!("Failed to read extends '{}': {}", reference, e))
+            })
+        }
+        Location::Git { repo, rev, path } => {
+            let output = Command::new("git")
+                .arg("-C")
+                .arg(repo)
+                .args(["show", "--end-of-options", &format!("{}:{}", rev, path)])
+                .output()
+                .map_err(|e| ConfigError::IoError(format!("Failed to run git: {}", e)))?;
+            if !output.status.success() {
+                return Err(ConfigError::IoError(format!(
+                    "Failed to read extends '{}': {}",
+                    reference,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                )));
+            }
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        }
+    }
+}
+
+fn expand_home(raw: &str) -> PathBuf {
+    if let Some(rest) = raw.strip_prefix("~/")
+        && let Some(home) = dirs::home_dir()
+    {
+        return home.join(rest);
+    }
+    PathBuf::from(raw)
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Join `path` onto the repo-relative directory `dir`, resolving `.` and `..`.
+fn normalize_repo_path(dir: &str, path: &str) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    let joined = Path::new(dir).join(path);
+    for component in joined.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_string_lossy().into_owned()),
+            Component::ParentDir => {
+                parts.pop();
+            }
+            _ => {}
+        }
+    }
+    parts.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_git_references_relative_to_their_repo() {
+        let origin = Origin::Dir(PathBuf::from("/work/app/.tasks"));
+        let Location::Git { repo, rev, path } =
+            resolve_reference("../../org#v2:config/lotar.yml", &origin).unwrap()
+        else {
+            panic!("expected git location");
+        };
+        assert_eq!(repo, PathBuf::from("/work/app/.tasks/../../org"));
+        assert_eq!(rev, "v2");
+        assert_eq!(path, "config/lotar.yml");
+
+        let nested = Origin::Git {
+            repo,
+            rev,
+            dir: "config".to_string(),
+        };
+        let Location::Git { path, rev, .. } = resolve_reference("../base.yml", &nested).unwrap()
+        else {
+            panic!("expected git location");
+        };
+        assert_eq!(path, "base.yml");
+        assert_eq!(rev, "v2");
+
+        assert!(resolve_reference("org#main", &origin).is_err());
+    }
+
+    #[test]
+    fn rejects_git_refs_that_look_like_options() {
+        let origin = Origin::Dir(PathBuf::from("/work/app/.tasks"));
+        let err = resolve_reference("../org#--output=/tmp/x:lotar.yml", &origin).unwrap_err();
+        assert!(err.to_string().contains("must not start with '-'"), "{err}");
+        assert!(resolve_reference("#-p:lotar.yml", &origin).is_err());
+    }
+}
@@ -87,9 +87,8 @@ impl ConfigManager {
             let content = fs::read_to_string(&config_path).map_err(|e| {
                 ConfigError::IoError(format!("Failed to read global config: {}", e))
             })?;
-            let mut global_config: GlobalConfig = serde_yaml::from_str(&content).map_err(|e| {
-                ConfigError::ParseError(format!("Failed to parse global config: {}", e))
-            })?;
+            let mut global_config =
+                crate::config::normalization::parse_global_from_yaml_str(&content)?;
 
             // Update the default_project
             global_config.default_project = detected_prefix.clone();
@@ -97,7 +96,8 @@ impl ConfigManager {
             // Save updated config using canonical writer
             let updated_yaml =
                 crate::config::normalization::to_canonical_global_yaml(&global_config);
-            fs::write(&config_path, updated_yaml).map_err(|e| {
+            crate::utils::filesystem::write_yaml_preserving_modeline(&config_path, &updated_yaml)
+                .map_err(|e| {
                 ConfigError::IoError(format!("Failed to write updated global config: {}", e))
             })?;
 
@@ -183,7 +183,11 @@ impl ConfigManager {
         if let Ok(global_config) =
             crate::config::persistence::load_global_config(Some(tasks_dir_buf.as_path()))
         {
-            crate::config::resolution::merge_global_config(&mut config, global_config);
+            crate::config::resolution::merge_global_with_inherited(
+                &mut config,
+                global_config,
+                Some(tasks_dir),
+            )?;
         }
 
         // 3. Project config (.tasks/{project}/config.yml) - will be handled per-project
@@ -11,11 +11,13 @@
 //! - `manager`: Main configuration management coordination
 //! - `operations`: CRUD operations for configuration data
 //! - `persistence`: File I/O operations for configuration files
+//! - `inheritance`: Shared config layers pulled in with `extends:`
 //! - `resolution`: Configuration merging and resolution logic
 //! - `validation`: Configuration validation system
 
 pub mod bootstrap;
 pub mod env_overrides;
+pub mod inheritance;
 pub mod manager;
 pub mod normalization;
 pub mod operations;
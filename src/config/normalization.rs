@@ -1,13 +1,33 @@
 use serde::de::DeserializeOwned;
 use serde_yaml::Value;
+use std::collections::BTreeMap;
 
 use crate::config::types::{
-    AgentAutomationConfig, AgentAutomationConfigOverride, AgentInstructionsConfig,
-    AgentProfileConfig, AgentWorktreeConfig, AgentWorktreeConfigOverride, ConfigError,
-    GlobalConfig, ProjectConfig, StringConfigField, SyncAuthProfile, SyncConfig, SyncRemoteConfig,
+    AgentApprovalRule, AgentAutomationConfig, AgentAutomationConfigOverride,
+    AgentInstructionsConfig, AgentPipelineConfig, AgentProfileConfig, AgentWorktreeConfig,
+    AgentWorktreeConfigOverride, CONFIG_SCHEMA_VERSION, ConfigError, GlobalConfig, IssueTemplate,
+    MemberProfile, ProjectConfig, ScanReconcileConfig, StringConfigField, SyncAuthProfile,
+    SyncConfig, SyncRemoteConfig,
 };
 use crate::types::{Priority, TaskStatus, TaskType};
 
+/// `issue.templates` is keyed by task type; unparseable entries are dropped.
+fn parse_issue_templates(value: Value) -> Option<BTreeMap<String, IssueTemplate>> {
+    let Value::Mapping(map) = value else {
+        return None;
+    };
+    let mut templates = BTreeMap::new();
+    for (key, entry) in map {
+        let Some(task_type) = key.as_str().map(str::trim).filter(|k| !k.is_empty()) else {
+            continue;
+        };
+        if let Ok(template) = serde_yaml::from_value::<IssueTemplate>(entry) {
+            templates.insert(task_type.to_string(), template);
+        }
+    }
+    Some(templates)
+}
+
 fn expand_dotted_keys(value: Value) -> Value {
     match value {
         Value::Mapping(map) => {
@@ -238,6 +258,17 @@ pub fn parse_global_from_yaml_str(content: &str) -> Result<GlobalConfig, ConfigE
 
     let mut cfg = GlobalConfig::default();
 
+    // extends: a single reference or a list of them
+    match get_path(&data, &["extends"]) {
+        Some(Value::String(reference)) => cfg.extends = vec![reference.clone()],
+        Some(v @ Value::Sequence(_)) => {
+            if let Ok(list) = serde_yaml::from_value(v.clone()) {
+                cfg.extends = list;
+            }
+        }
+        _ => {}
+    }
+
     // server.port
     if let Some(v) = get_path(&data, &["server", "port"]).and_then(cast::<u16>) {
         cfg.server_port = v;
@@ -261,14 +292,10 @@ pub fn parse_global_from_yaml_str(content: &str) -> Result<GlobalConfig, ConfigE
     if let Some(v) = get_path(&data, &["members"])
         .cloned()
         .or_else(|| get_path(&data, &["default", "members"]).cloned())
-        && let Ok(list) = serde_yaml::from_value::<Vec<String>>(v)
+        && let Some((members, profiles)) = parse_member_entries(v)
     {
-        let members: Vec<String> = list
-            .into_iter()
-            .map(|entry| entry.trim().to_string())
-            .filter(|entry| !entry.is_empty())
-            .collect();
         cfg.members = members;
+        cfg.member_profiles = profiles;
     }
     if let Some(v) = get_path(&data, &["default", "strict_members"]).and_then(cast::<bool>) {
         cfg.strict_members = v;
@@ -299,6 +326,11 @@ pub fn parse_global_from_yaml_str(content: &str) -> Result<GlobalConfig, ConfigE
     {
         cfg.issue_priorities.values = list;
     }
+    if let Some(v) = get_path(&data, &["issue", "templates"]).cloned()
+        && let Some(templates) = parse_issue_templates(v)
+    {
+        cfg.issue_templates = templates;
+    }
 
     // taxonomy.* (legacy) — will be overridden by issue.* if present
     if let Some(v) = get_path(&data, &["taxonomy", "tags"]).cloned()
@@ -344,6 +376,16 @@ pub fn parse_global_from_yaml_str(content: &str) -> Result<GlobalConfig, ConfigE
     if let Some(v) = get_path(&data, &["scan", "strip_attributes"]).and_then(cast::<bool>) {
         cfg.scan_strip_attributes = v;
     }
+    // scan.comment_syntax
+    if let Some(v) = get_path(&data, &["scan", "comment_syntax"]).cloned()
+        && let Ok(entries) = serde_yaml::from_value(v)
+    {
+        cfg.scan_comment_syntax = entries;
+    }
+    // scan.reconcile
+    if let Some(v) = get_path(&data, &["scan", "reconcile"]).and_then(cast::<ScanReconcileConfig>) {
+        cfg.scan_reconcile = v;
+    }
 
     // attachments.dir
     if let Some(v) = get_path(&data, &["attachments", "dir"]).and_then(cast::<String>) {
@@ -405,6 +447,21 @@ pub fn parse_global_from_yaml_str(content: &str) -> Result<GlobalConfig, ConfigE
         cfg.agents = profiles;
     }
 
+    // pipelines (declarative agent pipelines)
+    if let Some(v) = get_path(&data, &["pipelines"]).cloned()
+        && let Ok(pipelines) =
+            serde_yaml::from_value::<std::collections::HashMap<String, AgentPipelineConfig>>(v)
+    {
+        cfg.pipelines = pipelines;
+    }
+
+    // agent.approvals
+    if let Some(v) = get_path(&data, &["agent", "approvals"]).cloned()
+        && let Ok(rules) = serde_yaml::from_value::<Vec<AgentApprovalRule>>(v)
+    {
+        cfg.agent_approvals = rules;
+    }
+
     // agent.automation
     if let Some(v) = get_path(&data, &["agent", "automation"]).cloned()
         && let Ok(automation) = serde_yaml::from_value::<AgentAutomationConfig>(v)
@@ -446,6 +503,26 @@ pub fn parse_global_from_yaml_str(content: &str) -> Result<GlobalConfig, ConfigE
             cfg.sprints.defaults.overdue_after = Some(trimmed);
         }
     }
+    if let Some(value) =
+        get_path(&data, &["sprints", "defaults", "weekly_hours"]).and_then(cast::<u32>)
+    {
+        cfg.sprints.defaults.weekly_hours = Some(value);
+    }
+    if let Some(value) = get_path(&data, &["sprints", "defaults", "member_weekly_hours"])
+        .and_then(cast::<std::collections::BTreeMap<String, u32>>)
+    {
+        cfg.sprints.defaults.member_weekly_hours = value
+            .into_iter()
+            .map(|(member, hours)| (member.trim().to_string(), hours))
+            .filter(|(member, _)| !member.is_empty())
+            .collect();
+    }
+    if let Some(mode) = get_path(&data, &["sprints", "defaults", "rollover"])
+        .and_then(cast::<String>)
+        .and_then(|raw| serde_yaml::from_value(Value::String(raw.trim().to_ascii_lowercase())).ok())
+    {
+        cfg.sprints.defaults.rollover = Some(mode);
+    }
     if let Some(enabled) =
         get_path(&data, &["sprints", "notifications", "enabled"]).and_then(cast::<bool>)
     {
@@ -509,6 +586,20 @@ pub fn parse_global_from_yaml_str(content: &str) -> Result<GlobalConfig, ConfigE
         cfg.branch_priority_aliases = map;
     }
 
+    if let Some(v) = get_path(&data, &["branch", "template"]).and_then(cast::<String>) {
+        let trimmed = v.trim();
+        if !trimmed.is_empty() {
+            cfg.branch_template = trimmed.to_string();
+        }
+    }
+
+    // commits.keywords (global)
+    if let Some(v) = get_path(&data, &["commits", "keywords"]).cloned()
+        && let Some(map) = parse_alias_map_tolerant::<TaskStatus>(v, parse_task_status_tolerant)
+    {
+        cfg.commit_keywords = map.into_iter().collect();
+    }
+
     if let Some(v) = get_path(&data, &["sync"]).cloned()
         && let Ok(sync) = serde_yaml::from_value::<SyncConfig>(v)
     {
@@ -567,14 +658,12 @@ pub fn parse_project_from_yaml_str(
     if let Some(v) = get_path(&data, &["members"])
         .cloned()
         .or_else(|| get_path(&data, &["default", "members"]).cloned())
-        && let Ok(list) = serde_yaml::from_value::<Vec<String>>(v)
+        && let Some((members, profiles)) = parse_member_entries(v)
     {
-        let members: Vec<String> = list
-            .into_iter()
-            .map(|entry| entry.trim().to_string())
-            .filter(|entry| !entry.is_empty())
-            .collect();
         cfg.members = Some(members);
+        if !profiles.is_empty() {
+            cfg.member_profiles = Some(profiles);
+        }
     }
     if let Some(v) = get_path(&data, &["default", "strict_members"]).and_then(cast::<bool>) {
         cfg.strict_members = Some(v);
@@ -632,6 +721,9 @@ pub fn parse_project_from_yaml_str(
     if let Some(v) = get_path(&data, &["issue", "priorities"]).cloned() {
         cfg.issue_priorities = serde_yaml::from_value(v).ok();
     }
+    if let Some(v) = get_path(&data, &["issue", "templates"]).cloned() {
+        cfg.issue_templates = parse_issue_templates(v);
+    }
     // taxonomy.* (legacy)
     if let Some(v) = get_path(&data, &["taxonomy", "tags"]).cloned()
         && let Ok(list) = serde_yaml::from_value(v)
@@ -669,6 +761,14 @@ pub fn parse_project_from_yaml_str(
     if let Some(v) = get_path(&data, &["scan", "strip_attributes"]).and_then(cast::<bool>) {
         cfg.scan_strip_attributes = Some(v);
     }
+    // scan.comment_syntax (project)
+    if let Some(v) = get_path(&data, &["scan", "comment_syntax"]).cloned() {
+        cfg.scan_comment_syntax = serde_yaml::from_value(v).ok();
+    }
+    // scan.reconcile (project)
+    if let Some(v) = get_path(&data, &["scan", "reconcile"]).and_then(cast::<ScanReconcileConfig>) {
+        cfg.scan_reconcile = Some(v);
+    }
 
     // Back-compat: support flat keys for project toggles
     if let Some(v) = get_path(&data, &["scan_enable_ticket_words"]).and_then(cast::<bool>) {
@@ -737,6 +837,21 @@ pub fn parse_project_from_yaml_str(
         cfg.agents = Some(profiles);
     }
 
+    // pipelines (project override)
+    if let Some(v) = get_path(&data, &["pipelines"]).cloned()
+        && let Ok(pipelines) =
+            serde_yaml::from_value::<std::collections::HashMap<String, AgentPipelineConfig>>(v)
+    {
+        cfg.pipelines = Some(pipelines);
+    }
+
+    // agent.approvals (project override)
+    if let Some(v) = get_path(&data, &["agent", "approvals"]).cloned()
+        && let Ok(rules) = serde_yaml::from_value::<Vec<AgentApprovalRule>>(v)
+    {
+        cfg.agent_approvals = Some(rules);
+    }
+
     // agent.automation (project override)
     if let Some(v) = get_path(&data, &["agent", "automation"]).cloned()
         && let Ok(automation) = serde_yaml::from_value::<AgentAutomationConfigOverride>(v)
@@ -763,6 +878,17 @@ pub fn parse_project_from_yaml_str(
         cfg.branch_priority_aliases =
             parse_alias_map_tolerant::<Priority>(v, parse_priority_tolerant);
     }
+    if let Some(v) = get_path(&data, &["branch", "template"]).and_then(cast::<String>) {
+        let trimmed = v.trim();
+        if !trimmed.is_empty() {
+            cfg.branch_template = Some(trimmed.to_string());
+        }
+    }
+    // commits.keywords (project)
+    if let Some(v) = get_path(&data, &["commits", "keywords"]).cloned() {
+        cfg.commit_keywords = parse_alias_map_tolerant::<TaskStatus>(v, parse_task_status_tolerant)
+            .map(|map| map.into_iter().collect());
+    }
 
     if let Some(v) = get_path(&data, &["sync"]).cloned()
         && let Ok(sync) = serde_yaml::from_value::<SyncConfig>(v)
@@ -792,6 +918,13 @@ pub fn to_canonical_global_yaml(cfg: &GlobalConfig) -> String {
     let defaults = GlobalConfig::default();
     let mut root = serde_yaml::Mapping::new();
 
+    if !cfg.extends.is_empty() {
+        root.insert(
+            Y::String("extends".into()),
+            serde_yaml::to_value(&cfg.extends).unwrap_or(Y::Null),
+        );
+    }
+
     // server
     if cfg.server_port != defaults.server_port {
         let mut server = serde_yaml::Mapping::new();
@@ -838,10 +971,10 @@ pub fn to_canonical_global_yaml(cfg: &GlobalConfig) -> String {
         root.insert(Y::String("default".into()), Y::Mapping(default));
     }
 
-    if !cfg.members.is_empty() {
+    if !cfg.members.is_empty() || !cfg.member_profiles.is_empty() {
         root.insert(
             Y::String("members".into()),
-            serde_yaml::to_value(&cfg.members).unwrap_or(Y::Null),
+            member_entries_to_yaml(&cfg.members, &cfg.member_profiles),
         );
     }
 
@@ -871,6 +1004,12 @@ pub fn to_canonical_global_yaml(cfg: &GlobalConfig) -> String {
             serde_yaml::to_value(&cfg.tags.values).unwrap_or(Y::Null),
         );
     }
+    if !cfg.issue_templates.is_empty() {
+        issue.insert(
+            Y::String("templates".into()),
+            serde_yaml::to_value(&cfg.issue_templates).unwrap_or(Y::Null),
+        );
+    }
     if !issue.is_empty() {
         root.insert(Y::String("issue".into()), Y::Mapping(issue));
     }
@@ -920,6 +1059,18 @@ pub fn to_canonical_global_yaml(cfg: &GlobalConfig) -> String {
             Y::Bool(cfg.scan_strip_attributes),
         );
     }
+    if !cfg.scan_comment_syntax.is_empty() {
+        scan.insert(
+            Y::String("comment_syntax".into()),
+            serde_yaml::to_value(&cfg.scan_comment_syntax).unwrap_or(Y::Null),
+        );
+    }
+    if cfg.scan_reconcile != ScanReconcileConfig::default() {
+        scan.insert(
+            Y::String("reconcile".into()),
+            serde_yaml::to_value(&cfg.scan_reconcile).unwrap_or(Y::Null),
+        );
+    }
     if !scan.is_empty() {
         root.insert(Y::String("scan".into()), Y::Mapping(scan));
     }
@@ -978,6 +1129,12 @@ pub fn to_canonical_global_yaml(cfg: &GlobalConfig) -> String {
             serde_yaml::to_value(instructions).unwrap_or(Y::Null),
         );
     }
+    if !cfg.agent_approvals.is_empty() {
+        agent.insert(
+            Y::String("approvals".into()),
+            serde_yaml::to_value(&cfg.agent_approvals).unwrap_or(Y::Null),
+        );
+    }
     if cfg.agent_automation != defaults.agent_automation {
         agent.insert(
             Y::String("automation".into()),
@@ -999,6 +1156,12 @@ pub fn to_canonical_global_yaml(cfg: &GlobalConfig) -> String {
             serde_yaml::to_value(&cfg.agents).unwrap_or(Y::Null),
         );
     }
+    if !cfg.pipelines.is_empty() {
+        root.insert(
+            Y::String("pipelines".into()),
+            serde_yaml::to_value(&cfg.pipelines).unwrap_or(Y::Null),
+        );
+    }
 
     // sprints
     let mut sprints = serde_yaml::Mapping::new();
@@ -1021,6 +1184,21 @@ pub fn to_canonical_global_yaml(cfg: &GlobalConfig) -> String {
             Y::String(overdue.clone()),
         );
     }
+    if let Some(hours) = cfg.sprints.defaults.weekly_hours {
+        sprint_defaults.insert(Y::String("weekly_hours".into()), Y::Number(hours.into()));
+    }
+    if !cfg.sprints.defaults.member_weekly_hours.is_empty() {
+        sprint_defaults.insert(
+            Y::String("member_weekly_hours".into()),
+            serde_yaml::to_value(&cfg.sprints.defaults.member_weekly_hours).unwrap_or(Y::Null),
+        );
+    }
+    if let Some(mode) = cfg.sprints.defaults.rollover {
+        sprint_defaults.insert(
+            Y::String("rollover".into()),
+            Y::String(mode.as_str().to_string()),
+        );
+    }
     if !sprint_defaults.is_empty() {
         sprints.insert(Y::String("defaults".into()), Y::Mapping(sprint_defaults));
     }
@@ -1105,6 +1283,7 @@ pub fn to_canonical_global_yaml(cfg: &GlobalConfig) -> String {
     if !cfg.branch_type_aliases.is_empty()
         || !cfg.branch_status_aliases.is_empty()
         || !cfg.branch_priority_aliases.is_empty()
+        || cfg.branch_template != defaults.branch_template
     {
         let mut branch = serde_yaml::Mapping::new();
         if !cfg.branch_type_aliases.is_empty() {
@@ -1125,9 +1304,24 @@ pub fn to_canonical_global_yaml(cfg: &GlobalConfig) -> String {
                 serde_yaml::to_value(&cfg.branch_priority_aliases).unwrap_or(Y::Null),
             );
         }
+        if cfg.branch_template != defaults.branch_template {
+            branch.insert(
+                Y::String("template".into()),
+                Y::String(cfg.branch_template.clone()),
+            );
+        }
         root.insert(Y::String("branch".into()), Y::Mapping(branch));
     }
 
+    if cfg.commit_keywords != defaults.commit_keywords {
+        let mut commits = serde_yaml::Mapping::new();
+        commits.insert(
+            Y::String("keywords".into()),
+            serde_yaml::to_value(&cfg.commit_keywords).unwrap_or(Y::Null),
+        );
+        root.insert(Y::String("commits".into()), Y::Mapping(commits));
+    }
+
     if !cfg.remotes.is_empty() {
         root.insert(
             Y::String("remotes".into()),
@@ -1146,7 +1340,18 @@ pub fn to_canonical_global_yaml(cfg: &GlobalConfig) -> String {
             .to_string();
     }
 
-    serde_yaml::to_string(&Y::Mapping(root)).unwrap_or_default()
+    serde_yaml::to_string(&Y::Mapping(with_schema_version(root))).unwrap_or_default()
+}
+
+/// Prefix a canonical document with the current config `schema_version`.
+fn with_schema_version(root: serde_yaml::Mapping) -> serde_yaml::Mapping {
+    let mut stamped = serde_yaml::Mapping::new();
+    stamped.insert(
+        Value::String("schema_version".into()),
+        Value::from(CONFIG_SCHEMA_VERSION),
+    );
+    stamped.extend(root);
+    stamped
 }
 
 /// Render ProjectConfig into canonical nested YAML form
@@ -1199,10 +1404,13 @@ pub fn to_canonical_project_yaml(cfg: &ProjectConfig) -> String {
         root.insert(Y::String("default".into()), Y::Mapping(default));
     }
 
-    if let Some(members) = &cfg.members {
+    if cfg.members.is_some() || cfg.member_profiles.is_some() {
         root.insert(
             Y::String("members".into()),
-            serde_yaml::to_value(members).unwrap_or(Y::Null),
+            member_entries_to_yaml(
+                cfg.members.as_deref().unwrap_or_default(),
+                cfg.member_profiles.as_deref().unwrap_or_default(),
+            ),
         );
     }
 
@@ -1265,6 +1473,12 @@ pub fn to_canonical_project_yaml(cfg: &ProjectConfig) -> String {
             serde_yaml::to_value(&v.values).unwrap_or(Y::Null),
         );
     }
+    if let Some(templates) = &cfg.issue_templates {
+        issue.insert(
+            Y::String("templates".into()),
+            serde_yaml::to_value(templates).unwrap_or(Y::Null),
+        );
+    }
     if !issue.is_empty() {
         root.insert(Y::String("issue".into()), Y::Mapping(issue));
     }
@@ -1321,6 +1535,18 @@ pub fn to_canonical_project_yaml(cfg: &ProjectConfig) -> String {
     if let Some(b) = &cfg.scan_strip_attributes {
         scan.insert(Y::String("strip_attributes".into()), Y::Bool(*b));
     }
+    if let Some(entries) = &cfg.scan_comment_syntax {
+        scan.insert(
+            Y::String("comment_syntax".into()),
+            serde_yaml::to_value(entries).unwrap_or(Y::Null),
+        );
+    }
+    if let Some(reconcile) = &cfg.scan_reconcile {
+        scan.insert(
+            Y::String("reconcile".into()),
+            serde_yaml::to_value(reconcile).unwrap_or(Y::Null),
+        );
+    }
     if !scan.is_empty() {
         root.insert(Y::String("scan".into()), Y::Mapping(scan));
     }
@@ -1386,6 +1612,17 @@ pub fn to_canonical_project_yaml(cfg: &ProjectConfig) -> String {
             );
         }
     }
+    if let Some(rules) = &cfg.agent_approvals {
+        let entry = root
+            .entry(Y::String("agent".into()))
+            .or_insert_with(|| Y::Mapping(serde_yaml::Mapping::new()));
+        if let Y::Mapping(map) = entry {
+            map.insert(
+                Y::String("approvals".into()),
+                serde_yaml::to_value(rules).unwrap_or(Y::Null),
+            );
+        }
+    }
     if let Some(automation) = &cfg.agent_automation {
         let entry = root
             .entry(Y::String("agent".into()))
@@ -1416,6 +1653,14 @@ pub fn to_canonical_project_yaml(cfg: &ProjectConfig) -> String {
             serde_yaml::to_value(profiles).unwrap_or(Y::Null),
         );
     }
+    if let Some(pipelines) = &cfg.pipelines
+        && !pipelines.is_empty()
+    {
+        root.insert(
+            Y::String("pipelines".into()),
+            serde_yaml::to_value(pipelines).unwrap_or(Y::Null),
+        );
+    }
 
     // branch alias maps in project canonical YAML
     let has_branch = cfg
@@ -1432,7 +1677,8 @@ pub fn to_canonical_project_yaml(cfg: &ProjectConfig) -> String {
             .branch_priority_aliases
             .as_ref()
             .map(|m| !m.is_empty())
-            .unwrap_or(false);
+            .unwrap_or(false)
+        || cfg.branch_template.is_some();
     if has_branch {
         let mut branch = serde_yaml::Mapping::new();
         if let Some(m) = &cfg.branch_type_aliases
@@ -1459,9 +1705,21 @@ pub fn to_canonical_project_yaml(cfg: &ProjectConfig) -> String {
                 serde_yaml::to_value(m).unwrap_or(Y::Null),
             );
         }
+        if let Some(template) = &cfg.branch_template {
+            branch.insert(Y::String("template".into()), Y::String(template.clone()));
+        }
         root.insert(Y::String("branch".into()), Y::Mapping(branch));
     }
 
+    if let Some(keywords) = &cfg.commit_keywords {
+        let mut commits = serde_yaml::Mapping::new();
+        commits.insert(
+            Y::String("keywords".into()),
+            serde_yaml::to_value(keywords).unwrap_or(Y::Null),
+        );
+        root.insert(Y::String("commits".into()), Y::Mapping(commits));
+    }
+
     if !cfg.remotes.is_empty() {
         root.insert(
             Y::String("remotes".into()),
@@ -1475,7 +1733,69 @@ pub fn to_canonical_project_yaml(cfg: &ProjectConfig) -> String {
         );
     }
 
-    serde_yaml::to_string(&Y::Mapping(root)).unwrap_or_default()
+    serde_yaml::to_string(&Y::Mapping(with_schema_version(root))).unwrap_or_default()
+}
+
+/// Split a `members` list into handles and structured profiles.
+///
+/// Entries are either bare handles (`- alice`) or mappings with a `handle` key; every
+/// profile handle is also listed as a member.
+fn parse_member_entries(value: Value) -> Option<(Vec<String>, Vec<MemberProfile>)> {
+    let Value::Sequence(items) = value else {
+        return None;
+    };
+    let mut members: Vec<String> = Vec::new();
+    let mut profiles: Vec<MemberProfile> = Vec::new();
+    for item in items {
+        let handle = match item {
+            Value::String(raw) => raw.trim().to_string(),
+            Value::Mapping(_) => {
+                let Ok(mut profile) = serde_yaml::from_value::<MemberProfile>(item) else {
+                    continue;
+                };
+                profile.handle = profile.handle.trim().to_string();
+                let handle = profile.handle.clone();
+                if !handle.is_empty() {
+                    profiles.push(profile);
+                }
+                handle
+            }
+            _ => continue,
+        };
+        if !handle.is_empty()
+            && !members
+                .iter()
+                .any(|existing| existing.eq_ignore_ascii_case(&handle))
+        {
+            members.push(handle);
+        }
+    }
+    Some((members, profiles))
+}
+
+/// Inverse of `parse_member_entries`: handles with a profile are written as mappings.
+fn member_entries_to_yaml(members: &[String], profiles: &[MemberProfile]) -> Value {
+    let profile_for = |handle: &str| {
+        profiles
+            .iter()
+            .find(|profile| profile.handle.eq_ignore_ascii_case(handle))
+    };
+    let mut out: Vec<Value> = members
+        .iter()
+        .map(|member| match profile_for(member) {
+            Some(profile) => serde_yaml::to_value(profile).unwrap_or(Value::Null),
+            None => Value::String(member.clone()),
+        })
+        .collect();
+    for profile in profiles {
+        if !members
+            .iter()
+            .any(|member| member.eq_ignore_ascii_case(&profile.handle))
+        {
+            out.push(serde_yaml::to_value(profile).unwrap_or(Value::Null));
+        }
+    }
+    Value::Sequence(out)
 }
 
 // Helper: tolerant parser for issue.types accepting mixed-case strings mapping to TaskType
@@ -86,6 +86,18 @@ fn parse_optional_status(value: &str, field: &str) -> Result<Option<TaskStatus>,
     }
 }
 
+fn parse_rollover_mode(value: &str) -> Result<SprintRolloverMode, ConfigError> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "next" => Ok(SprintRolloverMode::Next),
+        "new" => Ok(SprintRolloverMode::New),
+        "backlog" => Ok(SprintRolloverMode::Backlog),
+        other => Err(ConfigError::ParseError(format!(
+            "sprints.defaults.rollover must be one of next, new or backlog, got '{}'",
+            other
+        ))),
+    }
+}
+
 fn parse_optional_string(value: &str) -> Option<String> {
     let trimmed = value.trim();
     if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("inherit") {
@@ -215,7 +227,7 @@ pub fn save_global_config(tasks_dir: &Path, config: &GlobalConfig) -> Result<(),
     // Serialize in canonical nested format
     let config_yaml = crate::config::normalization::to_canonical_global_yaml(config);
 
-    fs::write(&config_path, config_yaml)
+    crate::utils::filesystem::write_yaml_preserving_modeline(&config_path, &config_yaml)
         .map_err(|e| ConfigError::IoError(format!("Failed to write global config: {}", e)))?;
 
     // Invalidate cache for this tasks_dir
@@ -241,7 +253,7 @@ pub fn save_project_config(
     // Serialize in canonical nested format
     let config_yaml = crate::config::normalization::to_canonical_project_yaml(config);
 
-    fs::write(&config_path, config_yaml)
+    crate::utils::filesystem::write_yaml_preserving_modeline(&config_path, &config_yaml)
         .map_err(|e| ConfigError::IoError(format!("Failed to write project config: {}", e)))?;
 
     // Invalidate cache for this tasks_dir
@@ -652,6 +664,15 @@ pub fn apply_field_to_global_config(
             let map = parse_alias_map::<Priority>(value, "branch priority alias")?;
             config.branch_priority_aliases = map;
         }
+        "branch_template" => {
+            let trimmed = value.trim();
+            if trimmed.is_empty() {
+                return Err(ConfigError::ParseError(
+                    "branch_template cannot be empty".to_string(),
+                ));
+            }
+            config.branch_template = trimmed.to_string();
+        }
         "sprints_defaults_capacity_points" => {
             let trimmed = value.trim();
             if trimmed.is_empty() {
@@ -680,6 +701,28 @@ pub fn apply_field_to_global_config(
                 config.sprints.defaults.capacity_hours = Some(parsed);
             }
         }
+        "sprints_defaults_weekly_hours" => {
+            let trimmed = value.trim();
+            if trimmed.is_empty() {
+                config.sprints.defaults.weekly_hours = None;
+            } else {
+                let parsed = trimmed.parse::<u32>().map_err(|err| {
+                    ConfigError::ParseError(format!(
+                        "Invalid sprint weekly hours '{}': {}",
+                        value, err
+                    ))
+                })?;
+                config.sprints.defaults.weekly_hours = Some(parsed);
+            }
+        }
+        "sprints_defaults_rollover" => {
+            let trimmed = value.trim();
+            if trimmed.is_empty() {
+                config.sprints.defaults.rollover = None;
+            } else {
+                config.sprints.defaults.rollover = Some(parse_rollover_mode(trimmed)?);
+            }
+        }
         "sprints_defaults_length" => {
             let trimmed = value.trim();
             if trimmed.is_empty() {
@@ -1020,6 +1063,9 @@ fn apply_field_to_project_config(
                 config.branch_priority_aliases = Some(map);
             }
         }
+        "branch_template" => {
+            config.branch_template = parse_optional_string(value);
+        }
         _ => {
             return Err(ConfigError::ParseError(format!(
                 "Unknown project config field: {}",
@@ -1065,6 +1111,7 @@ pub fn validate_field_name(field: &str, is_global: bool) -> Result<(), ConfigErr
         "branch_type_aliases",
         "branch_status_aliases",
         "branch_priority_aliases",
+        "branch_template",
         "attachments_dir",
         "attachments_max_upload_mb",
         "sync_reports_dir",
@@ -1088,6 +1135,8 @@ pub fn validate_field_name(field: &str, is_global: bool) -> Result<(), ConfigErr
         "remotes",
         "sprints_defaults_capacity_points",
         "sprints_defaults_capacity_hours",
+        "sprints_defaults_weekly_hours",
+        "sprints_defaults_rollover",
         "sprints_defaults_length",
         "sprints_defaults_overdue_after",
         "sprints_notifications_enabled",
@@ -1124,6 +1173,7 @@ pub fn validate_field_name(field: &str, is_global: bool) -> Result<(), ConfigErr
         "branch_type_aliases",
         "branch_status_aliases",
         "branch_priority_aliases",
+        "branch_template",
         "attachments_dir",
         "attachments_max_upload_mb",
         "sync_reports_dir",
@@ -1381,7 +1431,9 @@ pub fn validate_field_value(field: &str, value: &str) -> Result<(), ConfigError>
         "issue_priorities" => {
             parse_token_list::<Priority>(value, "priority")?;
         }
-        "sprints_defaults_capacity_points" | "sprints_defaults_capacity_hours" => {
+        "sprints_defaults_capacity_points"
+        | "sprints_defaults_capacity_hours"
+        | "sprints_defaults_weekly_hours" => {
             let trimmed = value.trim();
             if !trimmed.is_empty() {
                 trimmed.parse::<u32>().map_err(|err| {
@@ -1392,6 +1444,12 @@ pub fn validate_field_value(field: &str, value: &str) -> Result<(), ConfigError>
                 })?;
             }
         }
+        "sprints_defaults_rollover" => {
+            let trimmed = value.trim();
+            if !trimmed.is_empty() {
+                parse_rollover_mode(trimmed)?;
+            }
+        }
         "sprints_defaults_length" | "sprints_defaults_overdue_after" => {
             // No additional validation beyond trimming; empty clears the override
         }
@@ -1442,6 +1500,7 @@ pub fn clear_project_field(
         "branch_type_aliases" => project_config.branch_type_aliases = None,
         "branch_status_aliases" => project_config.branch_status_aliases = None,
         "branch_priority_aliases" => project_config.branch_priority_aliases = None,
+        "branch_template" => project_config.branch_template = None,
         "agent_context_enabled" => project_config.agent_context_enabled = None,
         "agent_context_extension" => project_config.agent_context_extension = None,
         "agent_logs_dir" => project_config.agent_logs_dir = None,
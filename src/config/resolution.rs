@@ -140,7 +140,7 @@ pub fn load_and_merge_configs(tasks_dir: Option<&Path>) -> Result<ResolvedConfig
     if let Ok(global_config) =
         crate::config::persistence::load_global_config(tasks_dir_buf.as_deref())
     {
-        merge_global_config(&mut config, global_config);
+        merge_global_with_inherited(&mut config, global_config, tasks_dir)?;
     }
 
     // 3. Project config (.tasks/{project}/config.yml) - will be handled per-project
@@ -163,6 +163,23 @@ pub fn load_and_merge_configs(tasks_dir: Option<&Path>) -> Result<ResolvedConfig
     Ok(resolved)
 }
 
+/// Merge the global config preceded by the shared layers it declares in `extends:`.
+pub fn merge_global_with_inherited(
+    base: &mut GlobalConfig,
+    global: GlobalConfig,
+    tasks_dir: Option<&Path>,
+) -> Result<(), ConfigError> {
+    let root = match tasks_dir {
+        Some(dir) => dir.to_path_buf(),
+        None => crate::utils::paths::tasks_root_from(Path::new(".")),
+    };
+    for layer in crate::config::inheritance::load_inherited_layers(&root, &global)? {
+        merge_global_config(base, layer.config);
+    }
+    merge_global_config(base, global);
+    Ok(())
+}
+
 /// Improved merging that only overrides non-default values
 pub fn merge_global_config(base: &mut GlobalConfig, override_config: GlobalConfig) {
     // Only override fields that are different from defaults
@@ -185,6 +202,7 @@ pub fn merge_global_config(base: &mut GlobalConfig, override_config: GlobalConfi
     if override_config.issue_priorities.values != defaults.issue_priorities.values {
         base.issue_priorities = override_config.issue_priorities;
     }
+    merge_issue_templates(&mut base.issue_templates, override_config.issue_templates);
     if override_config.tags.values != defaults.tags.values {
         base.tags = override_config.tags;
     }
@@ -202,6 +220,7 @@ pub fn merge_global_config(base: &mut GlobalConfig, override_config: GlobalConfi
     if !override_config.members.is_empty() {
         base.members = override_config.members;
     }
+    merge_member_profiles(&mut base.member_profiles, override_config.member_profiles);
     if override_config.strict_members != defaults.strict_members {
         base.strict_members = override_config.strict_members;
     }
@@ -266,6 +285,12 @@ pub fn merge_global_config(base: &mut GlobalConfig, override_config: GlobalConfi
     if !override_config.agents.is_empty() {
         base.agents.extend(override_config.agents);
     }
+    if !override_config.pipelines.is_empty() {
+        base.pipelines.extend(override_config.pipelines);
+    }
+    if !override_config.agent_approvals.is_empty() {
+        base.agent_approvals = override_config.agent_approvals;
+    }
     if override_config.agent_automation != defaults.agent_automation {
         base.agent_automation = override_config.agent_automation;
     }
@@ -278,6 +303,11 @@ pub fn merge_global_config(base: &mut GlobalConfig, override_config: GlobalConfi
     if override_config.scan_strip_attributes != defaults.scan_strip_attributes {
         base.scan_strip_attributes = override_config.scan_strip_attributes;
     }
+    base.scan_comment_syntax
+        .extend(override_config.scan_comment_syntax);
+    if override_config.scan_reconcile != defaults.scan_reconcile {
+        base.scan_reconcile = override_config.scan_reconcile;
+    }
     if override_config.scan_ticket_patterns.is_some() {
         base.scan_ticket_patterns = override_config.scan_ticket_patterns;
     }
@@ -299,6 +329,12 @@ pub fn merge_global_config(base: &mut GlobalConfig, override_config: GlobalConfi
     if !override_config.branch_priority_aliases.is_empty() {
         base.branch_priority_aliases = override_config.branch_priority_aliases;
     }
+    if override_config.branch_template != defaults.branch_template {
+        base.branch_template = override_config.branch_template;
+    }
+    if override_config.commit_keywords != defaults.commit_keywords {
+        base.commit_keywords = override_config.commit_keywords;
+    }
     if override_config.auto_identity != defaults.auto_identity {
         base.auto_identity = override_config.auto_identity;
     }
@@ -336,6 +372,10 @@ pub fn overlay_global_into_resolved(resolved: &mut ResolvedConfig, override_conf
     if override_config.issue_priorities.values != defaults.issue_priorities.values {
         resolved.issue_priorities = override_config.issue_priorities;
     }
+    merge_issue_templates(
+        &mut resolved.issue_templates,
+        override_config.issue_templates,
+    );
     if override_config.tags.values != defaults.tags.values {
         resolved.tags = override_config.tags;
     }
@@ -352,6 +392,10 @@ pub fn overlay_global_into_resolved(resolved: &mut ResolvedConfig, override_conf
     if !override_config.members.is_empty() {
         resolved.members = override_config.members;
     }
+    merge_member_profiles(
+        &mut resolved.member_profiles,
+        override_config.member_profiles,
+    );
     if override_config.strict_members != defaults.strict_members {
         resolved.strict_members = override_config.strict_members;
     }
@@ -421,6 +465,12 @@ pub fn overlay_global_into_resolved(resolved: &mut ResolvedConfig, override_conf
                 .map(|(k, v)| (k, v.to_detail())),
         );
     }
+    if !override_config.pipelines.is_empty() {
+        resolved.agent_pipelines.extend(override_config.pipelines);
+    }
+    if !override_config.agent_approvals.is_empty() {
+        resolved.agent_approvals = override_config.agent_approvals;
+    }
     if override_config.agent_automation != defaults.agent_automation {
         resolved.agent_automation = override_config.agent_automation;
     }
@@ -433,6 +483,12 @@ pub fn overlay_global_into_resolved(resolved: &mut ResolvedConfig, override_conf
     if override_config.scan_strip_attributes != defaults.scan_strip_attributes {
         resolved.scan_strip_attributes = override_config.scan_strip_attributes;
     }
+    resolved
+        .scan_comment_syntax
+        .extend(override_config.scan_comment_syntax);
+    if override_config.scan_reconcile != defaults.scan_reconcile {
+        resolved.scan_reconcile = override_config.scan_reconcile;
+    }
     if override_config.scan_ticket_patterns.is_some() {
         resolved.scan_ticket_patterns = override_config.scan_ticket_patterns;
     }
@@ -455,6 +511,12 @@ pub fn overlay_global_into_resolved(resolved: &mut ResolvedConfig, override_conf
     if !override_config.branch_priority_aliases.is_empty() {
         resolved.branch_priority_aliases = override_config.branch_priority_aliases;
     }
+    if override_config.branch_template != defaults.branch_template {
+        resolved.branch_template = override_config.branch_template;
+    }
+    if override_config.commit_keywords != defaults.commit_keywords {
+        resolved.commit_keywords = override_config.commit_keywords;
+    }
     if override_config.auto_identity != defaults.auto_identity {
         resolved.auto_identity = override_config.auto_identity;
     }
@@ -514,6 +576,9 @@ fn apply_project_config_overrides(resolved: &mut ResolvedConfig, project_config:
     if let Some(priorities) = project_config.issue_priorities {
         resolved.issue_priorities = priorities;
     }
+    if let Some(templates) = project_config.issue_templates {
+        merge_issue_templates(&mut resolved.issue_templates, templates);
+    }
     if let Some(tags) = project_config.tags {
         resolved.tags = tags;
     }
@@ -529,6 +594,9 @@ fn apply_project_config_overrides(resolved: &mut ResolvedConfig, project_config:
     if let Some(members) = project_config.members {
         resolved.members = members;
     }
+    if let Some(profiles) = project_config.member_profiles {
+        merge_member_profiles(&mut resolved.member_profiles, profiles);
+    }
     if let Some(strict_members) = project_config.strict_members {
         resolved.strict_members = strict_members;
     }
@@ -593,6 +661,13 @@ fn apply_project_config_overrides(resolved: &mut ResolvedConfig, project_config:
     if let Some(strip) = project_config.scan_strip_attributes {
         resolved.scan_strip_attributes = strip;
     }
+    // Project entries replace global entries for the same extension.
+    if let Some(entries) = project_config.scan_comment_syntax {
+        resolved.scan_comment_syntax.extend(entries);
+    }
+    if let Some(reconcile) = project_config.scan_reconcile {
+        resolved.scan_reconcile = reconcile;
+    }
     if let Some(dir) = project_config.sync_reports_dir
         && !dir.trim().is_empty()
     {
@@ -622,6 +697,13 @@ fn apply_project_config_overrides(resolved: &mut ResolvedConfig, project_config:
             resolved.agent_profiles.insert(name, profile.to_detail());
         }
     }
+    if let Some(pipelines) = project_config.pipelines {
+        resolved.agent_pipelines.extend(pipelines);
+    }
+    // Project approval rules replace the global list (an empty list disables the gates).
+    if let Some(rules) = project_config.agent_approvals {
+        resolved.agent_approvals = rules;
+    }
     if let Some(automation) = project_config.agent_automation {
         apply_agent_automation_override(&mut resolved.agent_automation, automation);
     }
@@ -646,12 +728,43 @@ fn apply_project_config_overrides(resolved: &mut ResolvedConfig, project_config:
         resolved.branch_priority_aliases =
             m.into_iter().map(|(k, v)| (k.to_lowercase(), v)).collect();
     }
+    if let Some(template) = project_config.branch_template {
+        resolved.branch_template = template;
+    }
+    if let Some(keywords) = project_config.commit_keywords {
+        resolved.commit_keywords = keywords;
+    }
     if !project_config.remotes.is_empty() {
         resolved.remotes.extend(project_config.remotes);
     }
     // Smart toggles for branch/codeowners remain global/home/env scoped for now.
 }
 
+/// Later layers replace profiles with the same handle and add new ones; a person's
+/// identities are shared across projects, so lower layers are never dropped.
+fn merge_member_profiles(base: &mut Vec<MemberProfile>, overrides: Vec<MemberProfile>) {
+    for profile in overrides {
+        match base
+            .iter_mut()
+            .find(|existing| existing.handle.eq_ignore_ascii_case(&profile.handle))
+        {
+            Some(existing) => *existing = profile,
+            None => base.push(profile),
+        }
+    }
+}
+
+/// A later layer replaces the whole template for a type (type names match case-insensitively).
+fn merge_issue_templates(
+    base: &mut BTreeMap<String, IssueTemplate>,
+    overrides: BTreeMap<String, IssueTemplate>,
+) {
+    for (task_type, template) in overrides {
+        base.retain(|existing, _| !existing.eq_ignore_ascii_case(&task_type));
+        base.insert(task_type, template);
+    }
+}
+
 fn apply_agent_automation_override(
     target: &mut crate::config::types::AgentAutomationConfig,
     override_config: crate::config::types::AgentAutomationConfigOverride,
@@ -736,11 +849,13 @@ impl ResolvedConfig {
             issue_states: global.issue_states,
             issue_types: global.issue_types,
             issue_priorities: global.issue_priorities,
+            issue_templates: global.issue_templates,
             tags: global.tags,
             default_assignee: global.default_assignee,
             default_reporter: global.default_reporter,
             default_tags: global.default_tags,
             members: global.members,
+            member_profiles: global.member_profiles,
             strict_members: global.strict_members,
             auto_populate_members: global.auto_populate_members,
             auto_set_reporter: global.auto_set_reporter,
@@ -755,6 +870,8 @@ impl ResolvedConfig {
             custom_fields: global.custom_fields,
             scan_signal_words: global.scan_signal_words,
             scan_strip_attributes: global.scan_strip_attributes,
+            scan_comment_syntax: global.scan_comment_syntax,
+            scan_reconcile: global.scan_reconcile,
             scan_ticket_patterns: global.scan_ticket_patterns,
             scan_enable_ticket_words: global.scan_enable_ticket_words,
             scan_enable_mentions: global.scan_enable_mentions,
@@ -765,6 +882,8 @@ impl ResolvedConfig {
             branch_type_aliases: global.branch_type_aliases,
             branch_status_aliases: global.branch_status_aliases,
             branch_priority_aliases: global.branch_priority_aliases,
+            branch_template: global.branch_template,
+            commit_keywords: global.commit_keywords,
             attachments_dir: global.attachments_dir,
             attachments_max_upload_mb: global.attachments_max_upload_mb,
             sync_reports_dir: global.sync_reports_dir,
@@ -778,6 +897,8 @@ impl ResolvedConfig {
                 .into_iter()
                 .map(|(k, v)| (k, v.to_detail()))
                 .collect(),
+            agent_pipelines: global.pipelines,
+            agent_approvals: global.agent_approvals,
             agent_automation: global.agent_automation,
             agent_worktree: global.agent_worktree,
             remotes: global.remotes,
@@ -1,4 +1,5 @@
 use crate::config::env_overrides::capture_env_override_snapshot;
+use crate::config::inheritance::InheritedLayer;
 use crate::config::types::{GlobalConfig, ProjectConfig, ResolvedConfig};
 use std::collections::{HashMap, HashSet};
 
@@ -34,6 +35,7 @@ pub const CONFIG_SOURCE_ENTRIES: &[ConfigSourceEntry] = &[
     entry("issue.states", "issue_states", "issue_states"),
     entry("issue.types", "issue_types", "issue_types"),
     entry("issue.priorities", "issue_priorities", "issue_priorities"),
+    entry("issue.templates", "issue_templates", "issue_templates"),
     entry("issue.tags", "issue_tags", "tags"),
     entry("custom.fields", "custom_fields", "custom_fields"),
     entry(
@@ -123,6 +125,16 @@ pub const CONFIG_SOURCE_ENTRIES: &[ConfigSourceEntry] = &[
         "sprints_defaults_capacity_hours",
         "sprints_defaults_capacity_hours",
     ),
+    entry(
+        "sprints.defaults.weekly_hours",
+        "sprints_defaults_weekly_hours",
+        "sprints_defaults_weekly_hours",
+    ),
+    entry(
+        "sprints.defaults.rollover",
+        "sprints_defaults_rollover",
+        "sprints_defaults_rollover",
+    ),
     entry(
         "sprints.defaults.length",
         "sprints_defaults_length",
@@ -209,30 +221,39 @@ where
     }
 }
 
-fn scope_for_global_value<T>(
+fn scope_for_global_value<'a, T, I>(
     resolved_value: &T,
     home_value: Option<&T>,
     global_value: Option<&T>,
+    inherited_values: I,
     default_value: &T,
-) -> &'static str
+) -> String
 where
-    T: PartialEq,
+    T: PartialEq + 'a,
+    I: DoubleEndedIterator<Item = (&'a InheritedLayer, &'a T)>,
 {
     if home_value.is_some_and(|value| value == resolved_value) {
-        return "home";
+        return "home".to_string();
     }
 
     if global_value.is_some_and(|value| value == resolved_value && value != default_value) {
-        return "global";
+        return "global".to_string();
+    }
+
+    for (layer, value) in inherited_values.rev() {
+        if value == resolved_value && value != default_value {
+            return layer.label();
+        }
     }
 
-    "default"
+    "default".to_string()
 }
 
 struct GlobalScopeContext<'a> {
     base_config: &'a ResolvedConfig,
     global_cfg: &'a Option<GlobalConfig>,
     home_cfg: &'a Option<GlobalConfig>,
+    inherited: &'a [InheritedLayer],
     env_resolved: &'a ResolvedConfig,
     env_applied: &'a HashSet<&'static str>,
 }
@@ -242,6 +263,7 @@ impl<'a> GlobalScopeContext<'a> {
         base_config: &'a ResolvedConfig,
         global_cfg: &'a Option<GlobalConfig>,
         home_cfg: &'a Option<GlobalConfig>,
+        inherited: &'a [InheritedLayer],
         env_resolved: &'a ResolvedConfig,
         env_applied: &'a HashSet<&'static str>,
     ) -> Self {
@@ -249,16 +271,18 @@ impl<'a> GlobalScopeContext<'a> {
             base_config,
             global_cfg,
             home_cfg,
+            inherited,
             env_resolved,
             env_applied,
         }
     }
 
-    fn global_label(&self, key: &str) -> &'static str {
+    fn global_label(&self, key: &str) -> String {
         source_label_for_global(
             self.base_config,
             self.global_cfg,
             self.home_cfg,
+            self.inherited,
             self.env_resolved,
             self.env_applied,
             key,
@@ -275,7 +299,7 @@ fn project_scope_with_global(
     if has_project_override || values_differ {
         "project".to_string()
     } else {
-        context.global_label(key).to_string()
+        context.global_label(key)
     }
 }
 
@@ -283,15 +307,22 @@ pub fn build_global_source_labels(
     resolved: &ResolvedConfig,
     global_cfg: &Option<GlobalConfig>,
     home_cfg: &Option<GlobalConfig>,
+    inherited: &[InheritedLayer],
 ) -> HashMap<String, String> {
     let snapshot = capture_env_override_snapshot();
     let env_resolved = &snapshot.resolved;
     let env_applied = snapshot.applied_keys();
     let mut labels = HashMap::new();
     populate_source_labels(&mut labels, |entry| {
-        GlobalScopeContext::new(resolved, global_cfg, home_cfg, env_resolved, env_applied)
-            .global_label(entry.label_key)
-            .to_string()
+        GlobalScopeContext::new(
+            resolved,
+            global_cfg,
+            home_cfg,
+            inherited,
+            env_resolved,
+            env_applied,
+        )
+        .global_label(entry.label_key)
     });
     labels
 }
@@ -303,12 +334,19 @@ pub fn build_project_source_labels(
     project_cfg: Option<&ProjectConfig>,
     global_cfg: &Option<GlobalConfig>,
     home_cfg: &Option<GlobalConfig>,
+    inherited: &[InheritedLayer],
 ) -> HashMap<String, String> {
     let snapshot = capture_env_override_snapshot();
     let env_resolved = &snapshot.resolved;
     let env_applied = snapshot.applied_keys();
-    let context =
-        GlobalScopeContext::new(base_config, global_cfg, home_cfg, env_resolved, env_applied);
+    let context = GlobalScopeContext::new(
+        base_config,
+        global_cfg,
+        home_cfg,
+        inherited,
+        env_resolved,
+        env_applied,
+    );
     let mut labels = HashMap::new();
     populate_source_labels(&mut labels, |entry| {
         source_label_for_project(entry.label_key, resolved_project, project_cfg, &context)
@@ -320,6 +358,7 @@ pub fn collapse_label_to_scope(label: &str) -> &'static str {
     match label {
         "project" => "project",
         "global" | "home" | "env" => "global",
+        _ if label.starts_with("inherited") => "global",
         _ => "built_in",
     }
 }
@@ -328,13 +367,14 @@ fn source_label_for_global(
     resolved: &ResolvedConfig,
     global_cfg: &Option<GlobalConfig>,
     home_cfg: &Option<GlobalConfig>,
+    inherited: &[InheritedLayer],
     env_resolved: &ResolvedConfig,
     env_applied: &HashSet<&'static str>,
     key: &str,
-) -> &'static str {
+) -> String {
     let env_matches_key = env_applied.iter().any(|candidate| *candidate == key);
     if env_matches_key && env_value_matches(resolved, env_resolved, key) {
-        return "env";
+        return "env".to_string();
     }
 
     let defaults = GlobalConfig::default();
@@ -347,6 +387,7 @@ fn source_label_for_global(
                 &resolved.$field,
                 home.map(|cfg| &cfg.$field),
                 global.map(|cfg| &cfg.$field),
+                inherited.iter().map(|layer| (layer, &layer.config.$field)),
                 &defaults.$field,
             )
         };
@@ -355,6 +396,9 @@ fn source_label_for_global(
                 &resolved.$field $(.$rest)+,
                 home.map(|cfg| &cfg.$field $(.$rest)+),
                 global.map(|cfg| &cfg.$field $(.$rest)+),
+                inherited
+                    .iter()
+                    .map(|layer| (layer, &layer.config.$field $(.$rest)+)),
                 &defaults.$field $(.$rest)+,
             )
         };
@@ -374,6 +418,7 @@ fn source_label_for_global(
         "issue_states" => scope_field!(issue_states.values),
         "issue_types" => scope_field!(issue_types.values),
         "issue_priorities" => scope_field!(issue_priorities.values),
+        "issue_templates" => scope_field!(issue_templates),
         "issue_tags" => scope_field!(tags.values),
         "custom_fields" => scope_field!(custom_fields.values),
         "scan_signal_words" => scope_field!(scan_signal_words),
@@ -405,36 +450,69 @@ fn source_label_for_global(
             &resolved.sprint_defaults.capacity_points,
             home.map(|cfg| &cfg.sprints.defaults.capacity_points),
             global.map(|cfg| &cfg.sprints.defaults.capacity_points),
+            inherited
+                .iter()
+                .map(|layer| (layer, &layer.config.sprints.defaults.capacity_points)),
             &defaults.sprints.defaults.capacity_points,
         ),
         "sprints_defaults_capacity_hours" => scope_for_global_value(
             &resolved.sprint_defaults.capacity_hours,
             home.map(|cfg| &cfg.sprints.defaults.capacity_hours),
             global.map(|cfg| &cfg.sprints.defaults.capacity_hours),
+            inherited
+                .iter()
+                .map(|layer| (layer, &layer.config.sprints.defaults.capacity_hours)),
             &defaults.sprints.defaults.capacity_hours,
         ),
+        "sprints_defaults_weekly_hours" => scope_for_global_value(
+            &resolved.sprint_defaults.weekly_hours,
+            home.map(|cfg| &cfg.sprints.defaults.weekly_hours),
+            global.map(|cfg| &cfg.sprints.defaults.weekly_hours),
+            inherited
+                .iter()
+                .map(|layer| (layer, &layer.config.sprints.defaults.weekly_hours)),
+            &defaults.sprints.defaults.weekly_hours,
+        ),
+        "sprints_defaults_rollover" => scope_for_global_value(
+            &resolved.sprint_defaults.rollover,
+            home.map(|cfg| &cfg.sprints.defaults.rollover),
+            global.map(|cfg| &cfg.sprints.defaults.rollover),
+            inherited
+                .iter()
+                .map(|layer| (layer, &layer.config.sprints.defaults.rollover)),
+            &defaults.sprints.defaults.rollover,
+        ),
         "sprints_defaults_length" => scope_for_global_value(
             &resolved.sprint_defaults.length,
             home.map(|cfg| &cfg.sprints.defaults.length),
             global.map(|cfg| &cfg.sprints.defaults.length),
+            inherited
+                .iter()
+                .map(|layer| (layer, &layer.config.sprints.defaults.length)),
             &defaults.sprints.defaults.length,
         ),
         "sprints_defaults_overdue_after" => scope_for_global_value(
             &resolved.sprint_defaults.overdue_after,
             home.map(|cfg| &cfg.sprints.defaults.overdue_after),
             global.map(|cfg| &cfg.sprints.defaults.overdue_after),
+            inherited
+                .iter()
+                .map(|layer| (layer, &layer.config.sprints.defaults.overdue_after)),
             &defaults.sprints.defaults.overdue_after,
         ),
         "sprints_notifications_enabled" => scope_for_global_value(
             &resolved.sprint_notifications.enabled,
             home.map(|cfg| &cfg.sprints.notifications.enabled),
             global.map(|cfg| &cfg.sprints.notifications.enabled),
+            inherited
+                .iter()
+                .map(|layer| (layer, &layer.config.sprints.notifications.enabled)),
             &defaults.sprints.notifications.enabled,
         ),
         "branch_type_aliases" => scope_field!(branch_type_aliases),
         "branch_status_aliases" => scope_field!(branch_status_aliases),
         "branch_priority_aliases" => scope_field!(branch_priority_aliases),
-        _ => "default",
+        _ => "default".to_string(),
     }
 }
 
@@ -462,6 +540,7 @@ fn env_value_matches(resolved: &ResolvedConfig, env_resolved: &ResolvedConfig, k
         "issue_states" => env_equal!(issue_states.values),
         "issue_types" => env_equal!(issue_types.values),
         "issue_priorities" => env_equal!(issue_priorities.values),
+        "issue_templates" => env_equal!(issue_templates),
         "issue_tags" => env_equal!(tags.values),
         "custom_fields" => env_equal!(custom_fields.values),
         "scan_signal_words" => env_equal!(scan_signal_words),
@@ -498,6 +577,8 @@ fn env_value_matches(resolved: &ResolvedConfig, env_resolved: &ResolvedConfig, k
         "sprints_defaults_capacity_hours" => {
             env_equal!(sprint_defaults.capacity_hours)
         }
+        "sprints_defaults_weekly_hours" => env_equal!(sprint_defaults.weekly_hours),
+        "sprints_defaults_rollover" => env_equal!(sprint_defaults.rollover),
         "sprints_defaults_length" => env_equal!(sprint_defaults.length),
         "sprints_defaults_overdue_after" => env_equal!(sprint_defaults.overdue_after),
         "sprints_notifications_enabled" => env_equal!(sprint_notifications.enabled),
@@ -512,10 +593,6 @@ fn source_label_for_project(
     context: &GlobalScopeContext<'_>,
 ) -> String {
     let base_config = context.base_config;
-    let global_cfg = context.global_cfg;
-    let home_cfg = context.home_cfg;
-    let env_resolved = context.env_resolved;
-    let env_applied = context.env_applied;
 
     macro_rules! project_scope {
         ($key_literal:literal, $has_override:expr, $diff:expr) => {
@@ -561,8 +638,10 @@ fn source_label_for_project(
             project_scope!("default_tags", has_override, diff)
         }
         "members" => {
-            let has_override = project_cfg.and_then(|pc| pc.members.as_ref()).is_some();
-            let diff = resolved_project.members != base_config.members;
+            let has_override =
+                project_cfg.is_some_and(|pc| pc.members.is_some() || pc.member_profiles.is_some());
+            let diff = resolved_project.members != base_config.members
+                || resolved_project.member_profiles != base_config.member_profiles;
             project_scope!("members", has_override, diff)
         }
         "strict_members" => {
@@ -605,6 +684,13 @@ fn source_label_for_project(
                 resolved_project.issue_priorities.values != base_config.issue_priorities.values;
             project_scope!("issue_priorities", has_override, diff)
         }
+        "issue_templates" => {
+            let has_override = project_cfg
+                .and_then(|pc| pc.issue_templates.as_ref())
+                .is_some();
+            let diff = resolved_project.issue_templates != base_config.issue_templates;
+            project_scope!("issue_templates", has_override, diff)
+        }
         "issue_tags" => {
             let has_override = project_cfg
                 .and_then(|pc| pc.tags.as_ref())
@@ -668,17 +754,11 @@ fn source_label_for_project(
         | "auto_identity_git"
         | "sprints_defaults_capacity_points"
         | "sprints_defaults_capacity_hours"
+        | "sprints_defaults_weekly_hours"
+        | "sprints_defaults_rollover"
         | "sprints_defaults_length"
         | "sprints_defaults_overdue_after"
-        | "sprints_notifications_enabled" => source_label_for_global(
-            base_config,
-            global_cfg,
-            home_cfg,
-            env_resolved,
-            env_applied,
-            key,
-        )
-        .to_string(),
+        | "sprints_notifications_enabled" => context.global_label(key),
         "branch_type_aliases" => {
             let has_override = project_cfg
                 .and_then(|pc| pc.branch_type_aliases.as_ref())
@@ -743,24 +823,8 @@ fn source_label_for_project(
             let diff = resolved_project.agent_worktree != base_config.agent_worktree;
             project_scope!("agent_worktree", has_override, diff)
         }
-        "server_port" => source_label_for_global(
-            base_config,
-            global_cfg,
-            home_cfg,
-            env_resolved,
-            env_applied,
-            key,
-        )
-        .to_string(),
-        _ => source_label_for_global(
-            base_config,
-            global_cfg,
-            home_cfg,
-            env_resolved,
-            env_applied,
-            key,
-        )
-        .to_string(),
+        "server_port" => context.global_label(key),
+        _ => context.global_label(key),
     }
 }
 
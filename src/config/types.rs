@@ -2,7 +2,7 @@ use crate::types::{Priority, TaskStatus, TaskType};
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectTemplate {
@@ -21,6 +21,38 @@ pub struct SprintDefaultsConfig {
     pub length: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub overdue_after: Option<String>,
+    /// Weekly hours each entry in `members` is available for sprint work.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub weekly_hours: Option<u32>,
+    /// Per-member weekly hours, overriding `weekly_hours`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub member_weekly_hours: BTreeMap<String, u32>,
+    /// Where `sprint close` moves unfinished tasks when `--rollover` is not given.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub rollover: Option<SprintRolloverMode>,
+}
+
+/// Destination for unfinished tasks when a sprint closes.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, clap::ValueEnum)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum SprintRolloverMode {
+    /// Next pending sprint, created from defaults when none exists
+    Next,
+    /// A freshly created sprint
+    New,
+    /// No sprint
+    Backlog,
+}
+
+impl SprintRolloverMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SprintRolloverMode::Next => "next",
+            SprintRolloverMode::New => "new",
+            SprintRolloverMode::Backlog => "backlog",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -143,6 +175,168 @@ pub struct AgentMcpConfig {
     pub allow: Vec<String>,
 }
 
+/// Defaults and required fields for new tasks of one type (`issue.templates.<Type>`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct IssueTemplate {
+    /// Markdown description used when the task is created without one.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+    /// Tags added when the task is created without explicit tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Custom field values filled in when the field is not set.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom_fields: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub acceptance_criteria: Vec<String>,
+    /// Fields that must have a value on create (built-ins such as `assignee` or custom field names).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required: Vec<String>,
+}
+
+/// Structured `members` entry: one person and every identity they show up under.
+///
+/// Plain string entries in `members` are bare handles; mapping entries carry a profile.
+/// Any alias below (emails, git author names, GitHub login, Jira accountId) resolves to
+/// `handle` wherever members are compared.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct MemberProfile {
+    /// Canonical handle stored on tasks (e.g. `jdoe`).
+    pub handle: String,
+    /// Display name (e.g. `Jane Doe`).
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        alias = "display_name"
+    )]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub emails: Vec<String>,
+    /// Git author names or emails used in commits.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub git: Vec<String>,
+    /// GitHub login (without `@`).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub github: Option<String>,
+    /// Jira Cloud accountId.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub jira: Option<String>,
+}
+
+/// Comment syntax for one file extension under `scan.comment_syntax`.
+///
+/// Fields left empty keep the built-in value for that extension (or the one named
+/// by `extends`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct ScanCommentSyntax {
+    /// Start from another extension's syntax (e.g. `extends: js`).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub extends: Option<String>,
+    /// Tokens that start a comment running to the end of the line.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub line: Vec<String>,
+    /// Block comment `[open, close]` pairs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub block: Vec<[String; 2]>,
+    /// Whether block comments nest.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub nested: Option<bool>,
+    /// `[open, close]` pairs that are comments only at the start of a line.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docstrings: Vec<[String; 2]>,
+    /// String literal delimiters whose contents are never treated as comments.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub strings: Vec<ScanStringSyntax>,
+}
+
+/// What `scan --reconcile` does to a task whose code TODO was removed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct ScanReconcileConfig {
+    /// Add a comment naming the removed reference.
+    #[serde(default = "default_true")]
+    pub comment: bool,
+    /// Tag added to the task.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub tag: Option<String>,
+    /// Status the task moves to (e.g. `Done`); unset leaves the status alone.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub status: Option<String>,
+}
+
+impl Default for ScanReconcileConfig {
+    fn default() -> Self {
+        Self {
+            comment: true,
+            tag: None,
+            status: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(untagged)]
+pub enum ScanStringSyntax {
+    /// A quote character that opens and closes the literal, with backslash escapes.
+    Quote(String),
+    Delimited(ScanStringDelimiter),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct ScanStringDelimiter {
+    pub open: String,
+    /// Defaults to `open`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub close: Option<String>,
+    /// Escape character; defaults to a backslash, `""` disables escapes.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub escape: Option<String>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub multiline: bool,
+}
+
+/// Limits applied to an agent runner or automation `run` command.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct SandboxConfig {
+    /// Parent environment variables passed through; a trailing `*` matches a prefix.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env: Vec<String>,
+    /// Refuse to run outside the job's worktree.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub worktree_only: bool,
+    /// CPU time limit per process (e.g. "10m").
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub cpu_time: Option<String>,
+    /// Address space limit per process (e.g. "4G").
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub memory: Option<String>,
+    /// Set to false to run without network access (Linux only).
+    #[serde(default = "default_true")]
+    pub network: bool,
+    /// Maximum combined stdout and stderr size (e.g. "10M").
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub max_output: Option<String>,
+}
+
+impl Default for SandboxConfig {
+    fn default() -> Self {
+        Self {
+            env: Vec::new(),
+            worktree_only: false,
+            cpu_time: None,
+            memory: None,
+            network: true,
+            max_output: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct AgentProfileDetail {
@@ -159,6 +353,21 @@ pub struct AgentProfileDetail {
     pub mcp: Option<AgentMcpConfig>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub instructions: Option<AgentInstructionsConfig>,
+    /// Maximum run time for a single attempt (e.g. "30m").
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub timeout: Option<String>,
+    /// Kill an attempt when the runner emits no events for this long (e.g. "10m").
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub idle_timeout: Option<String>,
+    /// Number of automatic retries after a failed, timed out, or stalled attempt.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub max_retries: Option<u32>,
+    /// Delay before the first retry (e.g. "30s"); doubles for each further retry.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub retry_backoff: Option<String>,
+    /// Optional limits on the runner's environment, resources, and output.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub sandbox: Option<SandboxConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -174,7 +383,7 @@ pub enum AgentInstructionsConfig {
 #[serde(untagged)]
 pub enum AgentProfileConfig {
     Runner(String),
-    Detailed(AgentProfileDetail),
+    Detailed(Box<AgentProfileDetail>),
 }
 
 impl AgentProfileConfig {
@@ -188,12 +397,114 @@ impl AgentProfileConfig {
                 tools: None,
                 mcp: None,
                 instructions: None,
+                timeout: None,
+                idle_timeout: None,
+                max_retries: None,
+                retry_backoff: None,
+                sandbox: None,
             },
-            AgentProfileConfig::Detailed(detail) => detail.clone(),
+            AgentProfileConfig::Detailed(detail) => detail.as_ref().clone(),
         }
     }
 }
 
+/// Ticket operation an agent approval rule guards.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AgentApprovalOperation {
+    /// Status changes (optionally limited to target statuses via `to`)
+    Status,
+    /// Assignee changes (optionally limited to new assignees via `to`)
+    Assign,
+    Comment,
+    /// Any field update, including status and assignee
+    Update,
+    Delete,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AgentApprovalAction {
+    /// Park the operation until a human approves or rejects it
+    #[default]
+    RequireApproval,
+    /// Refuse the operation outright
+    Deny,
+}
+
+/// Gate on ticket operations requested by agents (e.g. "moving to Merging requires approval").
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentApprovalRule {
+    pub operation: AgentApprovalOperation,
+    /// Target statuses or assignees; empty matches any value
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub to: Vec<String>,
+    /// Agent profiles the rule applies to; empty applies to every agent
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub agents: Vec<String>,
+    #[serde(default)]
+    pub action: AgentApprovalAction,
+}
+
+/// Ticket updates applied when a pipeline stage (or the whole pipeline) starts, succeeds, or fails.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentPipelineTransition {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub assignee: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub comment: Option<String>,
+    /// Stage to re-run after a failure. Only valid in a stage's `on_failure`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub goto: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentPipelineStage {
+    pub name: String,
+    /// Agent profile that runs this stage. Omit for approval stages.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub agent: Option<String>,
+    /// Prompt for the stage; defaults to the prompt the pipeline was started with.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub prompt: Option<String>,
+    /// Stages that must succeed before this one starts. Stages sharing the same
+    /// dependencies run in parallel.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub needs: Vec<String>,
+    /// Pause the pipeline until a human approves or rejects this stage.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub approval: bool,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub on_start: Option<AgentPipelineTransition>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub on_success: Option<AgentPipelineTransition>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub on_failure: Option<AgentPipelineTransition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct AgentPipelineConfig {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub stages: Vec<AgentPipelineStage>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub on_success: Option<AgentPipelineTransition>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub on_failure: Option<AgentPipelineTransition>,
+    /// How many times failure `goto` transitions may re-run earlier stages (default 3).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub max_rewinds: Option<u32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct AgentAutomationAction {
@@ -413,6 +724,8 @@ pub struct ProjectConfig {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub issue_priorities: Option<ConfigurableField<Priority>>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub issue_templates: Option<BTreeMap<String, IssueTemplate>>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub tags: Option<StringConfigField>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub default_assignee: Option<String>,
@@ -421,6 +734,8 @@ pub struct ProjectConfig {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub members: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub member_profiles: Option<Vec<MemberProfile>>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub strict_members: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub auto_populate_members: Option<bool>,
@@ -462,6 +777,12 @@ pub struct ProjectConfig {
     // Scan mutation policy (project-level override)
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub scan_strip_attributes: Option<bool>,
+    // Per-extension comment syntax entries; merged over the global entries
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub scan_comment_syntax: Option<BTreeMap<String, ScanCommentSyntax>>,
+    // Handling of tasks whose code TODO disappeared (replaces the global policy)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub scan_reconcile: Option<ScanReconcileConfig>,
 
     // Optional per-project branch alias maps
     #[serde(skip_serializing_if = "Option::is_none", default)]
@@ -470,6 +791,12 @@ pub struct ProjectConfig {
     pub branch_status_aliases: Option<HashMap<String, TaskStatus>>, // token -> TaskStatus
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub branch_priority_aliases: Option<HashMap<String, Priority>>, // token -> Priority
+    // Branch name template used by `lotar start`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub branch_template: Option<String>,
+    // Commit message keyword -> status transition (replaces the global map)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub commit_keywords: Option<BTreeMap<String, TaskStatus>>,
 
     // Attachments (project-level override)
     #[serde(skip_serializing_if = "Option::is_none", default)]
@@ -495,6 +822,10 @@ pub struct ProjectConfig {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub agents: Option<HashMap<String, AgentProfileConfig>>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub pipelines: Option<HashMap<String, AgentPipelineConfig>>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub agent_approvals: Option<Vec<AgentApprovalRule>>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub agent_automation: Option<AgentAutomationConfigOverride>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub agent_worktree: Option<AgentWorktreeConfigOverride>,
@@ -512,10 +843,12 @@ impl ProjectConfig {
             issue_states: None,
             issue_types: None,
             issue_priorities: None,
+            issue_templates: None,
             tags: None,
             default_assignee: None,
             default_reporter: None,
             members: None,
+            member_profiles: None,
             strict_members: None,
             auto_populate_members: None,
             default_tags: None,
@@ -536,9 +869,13 @@ impl ProjectConfig {
             scan_enable_ticket_words: None,
             scan_enable_mentions: None,
             scan_strip_attributes: None,
+            scan_comment_syntax: None,
+            scan_reconcile: None,
             branch_type_aliases: None,
             branch_status_aliases: None,
             branch_priority_aliases: None,
+            branch_template: None,
+            commit_keywords: None,
 
             attachments_dir: None,
             attachments_max_upload_mb: None,
@@ -549,6 +886,8 @@ impl ProjectConfig {
             agent_logs_dir: None,
             agent_instructions: None,
             agents: None,
+            pipelines: None,
+            agent_approvals: None,
             agent_automation: None,
             agent_worktree: None,
             remotes: HashMap::new(),
@@ -557,8 +896,14 @@ impl ProjectConfig {
     }
 }
 
+/// Current `schema_version` of config files; see `services::migration_service`.
+pub const CONFIG_SCHEMA_VERSION: u32 = 1;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GlobalConfig {
+    // Shared config layers this file inherits from (`extends:`), lowest precedence first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extends: Vec<String>,
     #[serde(default = "default_port")]
     pub server_port: u16,
     #[serde(default = "default_project_name", rename = "default_project")]
@@ -571,6 +916,8 @@ pub struct GlobalConfig {
     pub issue_types: ConfigurableField<TaskType>,
     #[serde(default = "default_issue_priorities")]
     pub issue_priorities: ConfigurableField<Priority>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub issue_templates: BTreeMap<String, IssueTemplate>,
     #[serde(default = "default_tags")]
     pub tags: StringConfigField,
 
@@ -582,6 +929,8 @@ pub struct GlobalConfig {
     pub default_tags: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub members: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub member_profiles: Vec<MemberProfile>,
     #[serde(default)]
     pub strict_members: bool,
     #[serde(default = "default_true")]
@@ -621,6 +970,12 @@ pub struct GlobalConfig {
     // Scan mutation policy
     #[serde(default = "default_true")]
     pub scan_strip_attributes: bool,
+    // Per-extension comment syntax added to or overriding the built-in table
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub scan_comment_syntax: BTreeMap<String, ScanCommentSyntax>,
+    // Handling of tasks whose code TODO disappeared (`scan --reconcile`)
+    #[serde(default)]
+    pub scan_reconcile: ScanReconcileConfig,
 
     // Branch alias maps (global-level)
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
@@ -629,6 +984,13 @@ pub struct GlobalConfig {
     pub branch_status_aliases: HashMap<String, TaskStatus>,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub branch_priority_aliases: HashMap<String, Priority>,
+    // Branch name template for `lotar start` ({type}, {id}, {key}, {number}, {slug})
+    #[serde(default = "default_branch_template")]
+    pub branch_template: String,
+
+    // Commit message keywords (`fixes PROJ-1`) and the status they move the ticket to
+    #[serde(default = "default_commit_keywords")]
+    pub commit_keywords: BTreeMap<String, TaskStatus>,
 
     // Automation toggles
     #[serde(default = "default_true")]
@@ -668,6 +1030,10 @@ pub struct GlobalConfig {
     pub agent_instructions: Option<AgentInstructionsConfig>,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub agents: HashMap<String, AgentProfileConfig>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub pipelines: HashMap<String, AgentPipelineConfig>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub agent_approvals: Vec<AgentApprovalRule>,
     #[serde(default)]
     pub agent_automation: AgentAutomationConfig,
     #[serde(default)]
@@ -692,11 +1058,13 @@ pub struct ResolvedConfig {
     pub issue_states: ConfigurableField<TaskStatus>,
     pub issue_types: ConfigurableField<TaskType>,
     pub issue_priorities: ConfigurableField<Priority>,
+    pub issue_templates: BTreeMap<String, IssueTemplate>,
     pub tags: StringConfigField,
     pub default_assignee: Option<String>,
     pub default_reporter: Option<String>,
     pub default_tags: Vec<String>,
     pub members: Vec<String>,
+    pub member_profiles: Vec<MemberProfile>,
     pub strict_members: bool,
     pub auto_populate_members: bool,
     pub auto_set_reporter: bool,
@@ -707,6 +1075,8 @@ pub struct ResolvedConfig {
     pub custom_fields: StringConfigField,
     pub scan_signal_words: Vec<String>,
     pub scan_strip_attributes: bool,
+    pub scan_comment_syntax: BTreeMap<String, ScanCommentSyntax>,
+    pub scan_reconcile: ScanReconcileConfig,
     // Effective scanner options
     pub scan_ticket_patterns: Option<Vec<String>>, // effective patterns if configured
     pub scan_enable_ticket_words: bool,
@@ -727,6 +1097,8 @@ pub struct ResolvedConfig {
     pub branch_type_aliases: HashMap<String, TaskType>,
     pub branch_status_aliases: HashMap<String, TaskStatus>,
     pub branch_priority_aliases: HashMap<String, Priority>,
+    pub branch_template: String,
+    pub commit_keywords: BTreeMap<String, TaskStatus>,
 
     // Attachments
     pub attachments_dir: String,
@@ -744,6 +1116,10 @@ pub struct ResolvedConfig {
     pub agent_instructions: Option<AgentInstructionsConfig>,
     #[serde(skip_serializing_if = "HashMap::is_empty", default)]
     pub agent_profiles: HashMap<String, AgentProfileDetail>,
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub agent_pipelines: HashMap<String, AgentPipelineConfig>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub agent_approvals: Vec<AgentApprovalRule>,
     pub agent_automation: AgentAutomationConfig,
     pub agent_worktree: AgentWorktreeConfig,
 
@@ -791,6 +1167,14 @@ impl ResolvedConfig {
 
         Some(self.issue_types.values[0].clone())
     }
+
+    /// Template configured for `task_type` (type names match case-insensitively).
+    pub fn issue_template_for(&self, task_type: &TaskType) -> Option<&IssueTemplate> {
+        self.issue_templates
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(task_type.as_str()))
+            .map(|(_, template)| template)
+    }
 }
 
 #[derive(Debug)]
@@ -911,16 +1295,19 @@ fn default_agent_context_extension() -> String {
 impl Default for GlobalConfig {
     fn default() -> Self {
         Self {
+            extends: Vec::new(),
             server_port: default_port(),
             default_project: default_project_name(),
             issue_states: default_issue_states(),
             issue_types: default_issue_types(),
             issue_priorities: default_issue_priorities(),
+            issue_templates: BTreeMap::new(),
             tags: default_tags(),
             default_assignee: None,
             default_reporter: None,
             default_tags: Vec::new(),
             members: Vec::new(),
+            member_profiles: Vec::new(),
             strict_members: false,
             auto_populate_members: true,
             auto_set_reporter: true,
@@ -940,9 +1327,13 @@ impl Default for GlobalConfig {
             sprints: SprintConfig::default(),
             // scan mutation policy
             scan_strip_attributes: true,
+            scan_comment_syntax: BTreeMap::new(),
+            scan_reconcile: ScanReconcileConfig::default(),
             branch_type_aliases: HashMap::new(),
             branch_status_aliases: HashMap::new(),
             branch_priority_aliases: HashMap::new(),
+            branch_template: default_branch_template(),
+            commit_keywords: default_commit_keywords(),
             auto_identity: true,
             auto_identity_git: true,
             attachments_dir: default_attachments_dir(),
@@ -954,6 +1345,8 @@ impl Default for GlobalConfig {
             agent_logs_dir: None,
             agent_instructions: None,
             agents: HashMap::new(),
+            pipelines: HashMap::new(),
+            agent_approvals: Vec::new(),
             agent_automation: AgentAutomationConfig::default(),
             agent_worktree: AgentWorktreeConfig::default(),
             web_ui_path: None,
@@ -963,10 +1356,27 @@ impl Default for GlobalConfig {
     }
 }
 
+fn default_branch_template() -> String {
+    "{type}/{id}-{slug}".to_string()
+}
+
+fn default_commit_keywords() -> BTreeMap<String, TaskStatus> {
+    [
+        "close", "closes", "closed", "fix", "fixes", "fixed", "resolve", "resolves", "resolved",
+    ]
+    .into_iter()
+    .map(|word| (word.to_string(), TaskStatus::from("Done")))
+    .collect()
+}
+
 fn default_true() -> bool {
     true
 }
 
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
 // Helper accessors for optional fields used by normalization without exposing internals
 pub fn maybe_scan_ticket_patterns(cfg: &GlobalConfig) -> Option<&Vec<String>> {
     cfg.scan_ticket_patterns.as_ref()
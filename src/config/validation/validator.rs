@@ -1,10 +1,12 @@
 use crate::config::types::{
-    GlobalConfig, ProjectConfig, ResolvedConfig, StringConfigField, SyncAuthProfile, SyncProvider,
-    SyncRemoteConfig,
+    GlobalConfig, IssueTemplate, MemberProfile, ProjectConfig, ResolvedConfig, ScanReconcileConfig,
+    StringConfigField, SyncAuthProfile, SyncProvider, SyncRemoteConfig,
 };
 use crate::config::validation::conflicts::PrefixConflictDetector;
 use crate::config::validation::errors::{ValidationError, ValidationResult};
-use std::collections::{HashMap, HashSet};
+use crate::types::{TaskStatus, TaskType};
+use crate::utils::member::member_for_comparison;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
 
 pub struct ConfigValidator {
@@ -105,6 +107,13 @@ impl ConfigValidator {
         if let Some(patterns) = &config.scan_ticket_patterns {
             self.validate_ticket_patterns(patterns, &mut result);
         }
+        if let (Some(reconcile), Some(states)) = (&config.scan_reconcile, &config.issue_states) {
+            self.validate_reconcile_status(reconcile, &states.values, &mut result);
+        }
+
+        if let Some(profiles) = &config.member_profiles {
+            self.warn_on_member_profile_conflicts(profiles, &mut result);
+        }
 
         if config.strict_members.unwrap_or(false)
             && let Some(members) = &config.members
@@ -141,6 +150,10 @@ impl ConfigValidator {
             );
         }
 
+        if let (Some(templates), Some(types)) = (&config.issue_templates, &config.issue_types) {
+            self.warn_on_unknown_template_types(templates, &types.values, &mut result);
+        }
+
         if let (Some(aliases), Some(types)) = (&config.branch_type_aliases, &config.issue_types) {
             self.validate_alias_targets(
                 "branch_type_aliases",
@@ -235,6 +248,13 @@ impl ConfigValidator {
             &mut result,
         );
 
+        self.warn_on_member_profile_conflicts(&config.member_profiles, &mut result);
+        self.warn_on_unknown_template_types(
+            &config.issue_templates,
+            &config.issue_types.values,
+            &mut result,
+        );
+
         if config.strict_members && !Self::members_list_has_entries(&config.members) {
             result.add_error(
                 ValidationError::error(
@@ -364,10 +384,39 @@ impl ConfigValidator {
             );
             self.validate_ticket_patterns(patterns, &mut result);
         }
+        self.validate_reconcile_status(
+            &config.scan_reconcile,
+            &config.issue_states.values,
+            &mut result,
+        );
 
         result
     }
 
+    /// Warn when `scan.reconcile.status` names a state that is not configured.
+    fn validate_reconcile_status(
+        &self,
+        reconcile: &ScanReconcileConfig,
+        states: &[TaskStatus],
+        result: &mut ValidationResult,
+    ) {
+        let Some(status) = reconcile.status.as_deref().map(str::trim) else {
+            return;
+        };
+        if !status.is_empty() && !states.iter().any(|state| state.eq_ignore_case(status)) {
+            result.add_error(
+                ValidationError::warning(
+                    Some("scan.reconcile.status".to_string()),
+                    format!(
+                        "Reconcile status '{}' not found in issue_states list",
+                        status
+                    ),
+                )
+                .with_fix("Add the status to issue_states or choose a different one".to_string()),
+            );
+        }
+    }
+
     pub fn validate_home_config(&self, config: &GlobalConfig) -> ValidationResult {
         let mut result = self.validate_global_config(config);
         result
@@ -832,6 +881,70 @@ impl ConfigValidator {
         }
     }
 
+    fn warn_on_unknown_template_types(
+        &self,
+        templates: &BTreeMap<String, IssueTemplate>,
+        types: &[TaskType],
+        result: &mut ValidationResult,
+    ) {
+        for task_type in templates.keys() {
+            if !types.iter().any(|known| known.eq_ignore_case(task_type)) {
+                result.add_error(
+                    ValidationError::warning(
+                        Some("issue_templates".to_string()),
+                        format!(
+                            "Template for '{}' does not match any configured issue type",
+                            task_type
+                        ),
+                    )
+                    .with_fix("Rename the template key to one of issue.types".to_string()),
+                );
+            }
+        }
+    }
+
+    /// Warn when two member profiles claim the same alias; the first profile wins at lookup.
+    fn warn_on_member_profile_conflicts(
+        &self,
+        profiles: &[MemberProfile],
+        result: &mut ValidationResult,
+    ) {
+        let mut owners: HashMap<String, &str> = HashMap::new();
+        for profile in profiles {
+            let aliases = std::iter::once(&profile.handle)
+                .chain(profile.name.iter())
+                .chain(profile.emails.iter())
+                .chain(profile.git.iter())
+                .chain(profile.github.iter())
+                .chain(profile.jira.iter());
+            let mut own = HashSet::new();
+            for alias in aliases {
+                let key = member_for_comparison(alias);
+                if key.is_empty() || !own.insert(key.clone()) {
+                    continue;
+                }
+                if let Some(owner) = owners.get(&key) {
+                    result.add_error(
+                        ValidationError::warning(
+                            Some("members".to_string()),
+                            format!(
+                                "Member alias '{}' is claimed by both '{}' and '{}'",
+                                alias.trim(),
+                                owner,
+                                profile.handle
+                            ),
+                        )
+                        .with_fix(
+                            "Keep each email, git name and account id on one member".to_string(),
+                        ),
+                    );
+                } else {
+                    owners.insert(key, profile.handle.as_str());
+                }
+            }
+        }
+    }
+
     fn members_list_has_entries(values: &[String]) -> bool {
         values.iter().any(|value| !value.trim().is_empty())
     }
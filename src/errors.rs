@@ -12,6 +12,10 @@ pub enum LoTaRError {
     ProjectNotFound(String),
     ValidationError(String),
     IndexError(String),
+    /// An agent operation was parked by an `agent.approvals` rule instead of being applied.
+    ApprovalPending(Box<crate::api_types::AgentApproval>),
+    /// An `agent.approvals` deny rule refused the operation.
+    PermissionDenied(String),
 }
 
 impl fmt::Display for LoTaRError {
@@ -25,6 +29,12 @@ impl fmt::Display for LoTaRError {
             LoTaRError::ProjectNotFound(name) => write!(f, "Project not found: {}", name),
             LoTaRError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
             LoTaRError::IndexError(msg) => write!(f, "Index error: {}", msg),
+            LoTaRError::ApprovalPending(approval) => write!(
+                f,
+                "Parked as approval #{} until a human approves it",
+                approval.id
+            ),
+            LoTaRError::PermissionDenied(msg) => write!(f, "Operation not permitted: {}", msg),
         }
     }
 }
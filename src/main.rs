@@ -8,10 +8,12 @@ use lotar::cli::handlers::duedate::{DueDateArgs, DueDateHandler};
 use lotar::cli::handlers::priority::{PriorityArgs, PriorityHandler};
 use lotar::cli::handlers::status::{StatusArgs, StatusHandler};
 use lotar::cli::handlers::{
-    AddHandler, AgentHandler, AutomationHandler, CommandHandler, CompletionsHandler, ConfigHandler,
-    GitHandler, ScanHandler, ServeHandler, SprintHandler, StatsHandler, SyncHandler, TaskHandler,
+    AddHandler, AgentHandler, AutomationHandler, CheckHandler, CommandHandler, CompletionsHandler,
+    ConfigHandler, DoctorHandler, ExportHandler, ForecastHandler, GitHandler, MergeDriverHandler,
+    MigrateHandler, RenumberHandler, ScanHandler, SchemaHandler, ServeHandler, SprintHandler,
+    StartHandler, StatsHandler, SyncHandler, TaskHandler,
 };
-use lotar::cli::preprocess::normalize_args;
+use lotar::cli::preprocess::{default_task_id, normalize_args};
 use lotar::cli::{Cli, Commands, ConfigAction, SyncCommandAction, TaskAction};
 use lotar::services::agent_job_service::{AgentJobService, AgentOrchestratorMode};
 use lotar::services::sync_service::SyncDirection;
@@ -42,16 +44,25 @@ fn is_valid_command(command: &str) -> bool {
             | "due-date"
             | "effort"
             | "comment"
+            | "start"
             | "task"
             | "tasks"
             | "config"
             | "scan"
+            | "check"
             | "pull"
             | "push"
             | "serve"
             | "whoami"
             | "stats"
             | "sprint"
+            | "forecast"
+            | "export"
+            | "migrate"
+            | "schema"
+            | "doctor"
+            | "merge-driver"
+            | "renumber"
             | "changelog"
             | "mcp"
             | "git"
@@ -119,6 +130,12 @@ fn main() {
         }
     };
 
+    // Quick commands run without a task ID default to the ticket in the branch name
+    let normalized_args = default_task_id(
+        &normalized_args,
+        lotar::utils::task_intel::task_id_from_current_branch,
+    );
+
     // Parse with Clap
     let cli = match Cli::try_parse_from(normalized_args) {
         Ok(cli) => cli,
@@ -466,6 +483,34 @@ fn main() {
                 }
             }
         }
+        Commands::Check(args) => {
+            renderer.log_info("BEGIN CHECK");
+            match CheckHandler::execute(args, cli.project.as_deref(), &resolver, &renderer) {
+                Ok(()) => {
+                    renderer.log_info("END CHECK status=ok");
+                    Ok(())
+                }
+                Err(e) => {
+                    renderer.emit_error(&e);
+                    renderer.log_info("END CHECK status=err");
+                    Err(e)
+                }
+            }
+        }
+        Commands::Start(args) => {
+            renderer.log_info("BEGIN START");
+            match StartHandler::execute(args, cli.project.as_deref(), &resolver, &renderer) {
+                Ok(()) => {
+                    renderer.log_info("END START status=ok");
+                    Ok(())
+                }
+                Err(e) => {
+                    renderer.emit_error(&e);
+                    renderer.log_info("END START status=err");
+                    Err(e)
+                }
+            }
+        }
         Commands::Pull(args) => {
             renderer.log_info("BEGIN PULL");
             match SyncHandler::execute(
@@ -552,6 +597,104 @@ fn main() {
                 }
             }
         }
+        Commands::Export(args) => {
+            renderer.log_info("BEGIN EXPORT");
+            match ExportHandler::execute(args, cli.project.as_deref(), &resolver, &renderer) {
+                Ok(()) => {
+                    renderer.log_info("END EXPORT status=ok");
+                    Ok(())
+                }
+                Err(e) => {
+                    renderer.emit_error(&e);
+                    renderer.log_info("END EXPORT status=err");
+                    Err(e)
+                }
+            }
+        }
+        Commands::Migrate(args) => {
+            renderer.log_info("BEGIN MIGRATE");
+            match MigrateHandler::execute(args, cli.project.as_deref(), &resolver, &renderer) {
+                Ok(()) => {
+                    renderer.log_info("END MIGRATE status=ok");
+                    Ok(())
+                }
+                Err(e) => {
+                    renderer.emit_error(&e);
+                    renderer.log_info("END MIGRATE status=err");
+                    Err(e)
+                }
+            }
+        }
+        Commands::Schema(args) => {
+            renderer.log_info("BEGIN SCHEMA");
+            match SchemaHandler::execute(args, cli.project.as_deref(), &resolver, &renderer) {
+                Ok(()) => {
+                    renderer.log_info("END SCHEMA status=ok");
+                    Ok(())
+                }
+                Err(e) => {
+                    renderer.emit_error(&e);
+                    renderer.log_info("END SCHEMA status=err");
+                    Err(e)
+                }
+            }
+        }
+        Commands::Doctor(args) => {
+            renderer.log_info("BEGIN DOCTOR");
+            match DoctorHandler::execute(args, cli.project.as_deref(), &resolver, &renderer) {
+                Ok(()) => {
+                    renderer.log_info("END DOCTOR status=ok");
+                    Ok(())
+                }
+                Err(e) => {
+                    renderer.emit_error(&e);
+                    renderer.log_info("END DOCTOR status=err");
+                    Err(e)
+                }
+            }
+        }
+        Commands::MergeDriver(args) => {
+            renderer.log_info("BEGIN MERGE_DRIVER");
+            match MergeDriverHandler::execute(args, cli.project.as_deref(), &resolver, &renderer) {
+                Ok(()) => {
+                    renderer.log_info("END MERGE_DRIVER status=ok");
+                    Ok(())
+                }
+                Err(e) => {
+                    renderer.emit_error(&e);
+                    renderer.log_info("END MERGE_DRIVER status=err");
+                    Err(e)
+                }
+            }
+        }
+        Commands::Renumber(args) => {
+            renderer.log_info("BEGIN RENUMBER");
+            match RenumberHandler::execute(args, cli.project.as_deref(), &resolver, &renderer) {
+                Ok(()) => {
+                    renderer.log_info("END RENUMBER status=ok");
+                    Ok(())
+                }
+                Err(e) => {
+                    renderer.emit_error(&e);
+                    renderer.log_info("END RENUMBER status=err");
+                    Err(e)
+                }
+            }
+        }
+        Commands::Forecast(args) => {
+            renderer.log_info("BEGIN FORECAST");
+            match ForecastHandler::execute(args, cli.project.as_deref(), &resolver, &renderer) {
+                Ok(()) => {
+                    renderer.log_info("END FORECAST status=ok");
+                    Ok(())
+                }
+                Err(e) => {
+                    renderer.emit_error(&e);
+                    renderer.log_info("END FORECAST status=err");
+                    Err(e)
+                }
+            }
+        }
         Commands::Sprint(args) => {
             renderer.log_info("BEGIN SPRINT");
             match SprintHandler::execute(args, cli.project.as_deref(), &resolver, &renderer) {
@@ -760,6 +903,7 @@ fn main() {
                                 let tags = get_vec_str("tags");
 
                                 Some(lotar::storage::task::Task {
+                                    schema_version: 0,
                                     title,
                                     status,
                                     priority,
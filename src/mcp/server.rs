@@ -14,16 +14,18 @@ mod watchers;
 mod mcp_server_tests;
 
 use handlers::{
-    handle_agent_cancel, handle_agent_list_jobs, handle_agent_run, handle_agent_send_message,
-    handle_agent_status, handle_config_set, handle_config_show, handle_project_list,
-    handle_project_stats, handle_sprint_add, handle_sprint_backlog, handle_sprint_burndown,
-    handle_sprint_create, handle_sprint_delete, handle_sprint_get, handle_sprint_list,
-    handle_sprint_remove, handle_sprint_summary, handle_sprint_update, handle_sprint_velocity,
-    handle_sync_pull, handle_sync_push, handle_task_bulk_comment_add,
-    handle_task_bulk_reference_add, handle_task_bulk_reference_remove, handle_task_bulk_update,
-    handle_task_comment_add, handle_task_comment_update, handle_task_create, handle_task_delete,
-    handle_task_get, handle_task_list, handle_task_reference_add, handle_task_reference_remove,
-    handle_task_update, handle_whoami,
+    handle_agent_cancel, handle_agent_list_jobs, handle_agent_pipeline_cancel,
+    handle_agent_pipeline_run, handle_agent_pipeline_status, handle_agent_run,
+    handle_agent_send_message, handle_agent_status, handle_config_set, handle_config_show,
+    handle_forecast, handle_project_list, handle_project_stats, handle_sprint_add,
+    handle_sprint_backlog, handle_sprint_burndown, handle_sprint_create, handle_sprint_delete,
+    handle_sprint_get, handle_sprint_list, handle_sprint_remove, handle_sprint_summary,
+    handle_sprint_update, handle_sprint_velocity, handle_sync_pull, handle_sync_push,
+    handle_task_bulk_comment_add, handle_task_bulk_reference_add,
+    handle_task_bulk_reference_remove, handle_task_bulk_update, handle_task_comment_add,
+    handle_task_comment_update, handle_task_copy, handle_task_create, handle_task_delete,
+    handle_task_get, handle_task_list, handle_task_move, handle_task_reference_add,
+    handle_task_reference_remove, handle_task_update, handle_whoami,
 };
 use hints::gather_enum_hints;
 use tools::build_tool_definitions;
@@ -534,6 +536,10 @@ fn dispatch(req: JsonRpcRequest) -> JsonRpcResponse {
         "task/reference_remove" => handle_task_reference_remove(req),
         // task/delete({ id, project? }) -> { deleted }
         "task/delete" => handle_task_delete(req),
+        // task/move({ id, to, project?, dry_run? }) -> { relocation }
+        "task/move" => handle_task_move(req),
+        // task/copy({ id, to, project?, dry_run? }) -> { relocation }
+        "task/copy" => handle_task_copy(req),
         // task/list(params: TaskListFilter) -> { tasks }
         "task/list" => handle_task_list(req),
         // whoami({ explain? }) -> { user }
@@ -569,6 +575,8 @@ fn dispatch(req: JsonRpcRequest) -> JsonRpcResponse {
         "sprint/remove" => handle_sprint_remove(req),
         "sprint/delete" => handle_sprint_delete(req),
         "sprint/backlog" => handle_sprint_backlog(req),
+        // forecast({ project?, q?, tags?, epic?, sprint?, by?, metric?, history?, iterations?, target?, seed? }) -> ForecastReport
+        "forecast" => handle_forecast(req),
         // project/list({}) -> { projects }
         "project/list" => handle_project_list(req),
         // project/stats({ name }) -> { stats }
@@ -577,12 +585,18 @@ fn dispatch(req: JsonRpcRequest) -> JsonRpcResponse {
         "agent/run" => handle_agent_run(req),
         // agent/status({ id }) -> { job }
         "agent/status" => handle_agent_status(req),
-        // agent/list_jobs({}) -> { jobs, queue_stats }
+        // agent/list_jobs({ticket_id?, status?, runner?, limit?, offset?}) -> { jobs, queue_stats, total, limit, offset }
         "agent/list_jobs" => handle_agent_list_jobs(req),
         // agent/cancel({ id }) -> { cancelled, job }
         "agent/cancel" => handle_agent_cancel(req),
         // agent/send_message({ id, message }) -> { job }
         "agent/send_message" => handle_agent_send_message(req),
+        // agent/pipeline_run({ ticket_id, pipeline, prompt? }) -> { run }
+        "agent/pipeline_run" => handle_agent_pipeline_run(req),
+        // agent/pipeline_status({ ticket_id }) -> { run }
+        "agent/pipeline_status" => handle_agent_pipeline_status(req),
+        // agent/pipeline_cancel({ ticket_id }) -> { cancelled, run }
+        "agent/pipeline_cancel" => handle_agent_pipeline_cancel(req),
         _ => err(req.id, -32601, "Method not found", None),
     }
 }
@@ -1,8 +1,11 @@
 use serde_json::json;
 
-use super::super::{JsonRpcRequest, JsonRpcResponse, err, ok};
-use crate::api_types::AgentJobCreateRequest;
-use crate::services::agent_job_service::AgentJobService;
+use super::super::{
+    JsonRpcRequest, JsonRpcResponse, err, ok, parse_cursor_value, parse_limit_value,
+};
+use crate::api_types::{AgentJobCreateRequest, AgentPipelineRunRequest};
+use crate::services::agent_job_service::{AgentJobListFilter, AgentJobService};
+use crate::services::agent_pipeline_service::AgentPipelineService;
 use crate::workspace::TasksDirectoryResolver;
 
 pub(crate) fn handle_agent_run(req: JsonRpcRequest) -> JsonRpcResponse {
@@ -84,20 +87,46 @@ pub(crate) fn handle_agent_status(req: JsonRpcRequest) -> JsonRpcResponse {
 }
 
 pub(crate) fn handle_agent_list_jobs(req: JsonRpcRequest) -> JsonRpcResponse {
-    let mut jobs = AgentJobService::list_jobs();
-    jobs.sort_by(|a, b| b.created_at.cmp(&a.created_at));
-    let queue_stats = AgentJobService::queue_stats();
-
-    let payload = json!({
-        "jobs": jobs,
-        "queue_stats": queue_stats,
-    });
-    ok(
-        req.id,
-        json!({
-            "content": [{"type": "text", "text": serde_json::to_string_pretty(&payload).unwrap_or_default()}]
-        }),
-    )
+    let limit = match parse_limit_value(req.params.get("limit"), 50) {
+        Ok(value) if (1..=500).contains(&value) => value,
+        Ok(_) => return err(req.id, -32602, "limit must be between 1 and 500", None),
+        Err(msg) => return err(req.id, -32602, msg, None),
+    };
+    let offset = match parse_cursor_value(req.params.get("offset")) {
+        Ok(value) => value,
+        Err(msg) => return err(req.id, -32602, msg, None),
+    };
+    let text_param = |key: &str| {
+        req.params
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    let filter = AgentJobListFilter {
+        ticket_id: text_param("ticket_id"),
+        status: text_param("status"),
+        runner: text_param("runner"),
+        limit,
+        offset,
+    };
+    let tasks_dir = match TasksDirectoryResolver::resolve(None, None) {
+        Ok(resolver) => resolver.path,
+        Err(e) => return err(req.id, -32603, &e, None),
+    };
+
+    match AgentJobService::list_job_history(&tasks_dir, &filter) {
+        Ok(list) => {
+            let payload = serde_json::to_value(&list).unwrap_or(json!({}));
+            ok(
+                req.id,
+                json!({
+                    "content": [{"type": "text", "text": serde_json::to_string_pretty(&payload).unwrap_or_default()}]
+                }),
+            )
+        }
+        Err(e) => err(req.id, -32603, &e.to_string(), None),
+    }
 }
 
 pub(crate) fn handle_agent_cancel(req: JsonRpcRequest) -> JsonRpcResponse {
@@ -144,3 +173,133 @@ pub(crate) fn handle_agent_send_message(req: JsonRpcRequest) -> JsonRpcResponse
         Err(e) => err(req.id, -32603, &e.to_string(), None),
     }
 }
+
+pub(crate) fn handle_agent_pipeline_run(req: JsonRpcRequest) -> JsonRpcResponse {
+    let ticket_id = match req.params.get("ticket_id").and_then(|v| v.as_str()) {
+        Some(id) => id.to_string(),
+        None => {
+            return err(
+                req.id,
+                -32602,
+                "Missing required parameter: ticket_id",
+                None,
+            );
+        }
+    };
+    let pipeline = match req.params.get("pipeline").and_then(|v| v.as_str()) {
+        Some(p) => p.to_string(),
+        None => return err(req.id, -32602, "Missing required parameter: pipeline", None),
+    };
+
+    let resolver = match TasksDirectoryResolver::resolve(None, None) {
+        Ok(r) => r,
+        Err(e) => {
+            return err(
+                req.id,
+                -32603,
+                &format!("Cannot resolve tasks directory: {e}"),
+                None,
+            );
+        }
+    };
+
+    let run_req = AgentPipelineRunRequest {
+        ticket_id,
+        pipeline,
+        prompt: req
+            .params
+            .get("prompt")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+    };
+
+    match AgentPipelineService::start(run_req, &resolver.path) {
+        Ok(run) => {
+            let payload = serde_json::to_value(&run).unwrap_or(json!({}));
+            ok(
+                req.id,
+                json!({
+                    "content": [{"type": "text", "text": serde_json::to_string_pretty(&payload).unwrap_or_default()}]
+                }),
+            )
+        }
+        Err(e) => err(req.id, -32603, &e.to_string(), None),
+    }
+}
+
+pub(crate) fn handle_agent_pipeline_status(req: JsonRpcRequest) -> JsonRpcResponse {
+    let ticket_id = match req.params.get("ticket_id").and_then(|v| v.as_str()) {
+        Some(id) => id.to_string(),
+        None => {
+            return err(
+                req.id,
+                -32602,
+                "Missing required parameter: ticket_id",
+                None,
+            );
+        }
+    };
+    let resolver = match TasksDirectoryResolver::resolve(None, None) {
+        Ok(r) => r,
+        Err(e) => {
+            return err(
+                req.id,
+                -32603,
+                &format!("Cannot resolve tasks directory: {e}"),
+                None,
+            );
+        }
+    };
+
+    match AgentPipelineService::status(&resolver.path, &ticket_id) {
+        Ok(Some(run)) => {
+            let payload = serde_json::to_value(&run).unwrap_or(json!({}));
+            ok(
+                req.id,
+                json!({
+                    "content": [{"type": "text", "text": serde_json::to_string_pretty(&payload).unwrap_or_default()}]
+                }),
+            )
+        }
+        Ok(None) => err(req.id, -32602, "Pipeline run not found", None),
+        Err(e) => err(req.id, -32603, &e.to_string(), None),
+    }
+}
+
+pub(crate) fn handle_agent_pipeline_cancel(req: JsonRpcRequest) -> JsonRpcResponse {
+    let ticket_id = match req.params.get("ticket_id").and_then(|v| v.as_str()) {
+        Some(id) => id.to_string(),
+        None => {
+            return err(
+                req.id,
+                -32602,
+                "Missing required parameter: ticket_id",
+                None,
+            );
+        }
+    };
+    let resolver = match TasksDirectoryResolver::resolve(None, None) {
+        Ok(r) => r,
+        Err(e) => {
+            return err(
+                req.id,
+                -32603,
+                &format!("Cannot resolve tasks directory: {e}"),
+                None,
+            );
+        }
+    };
+
+    match AgentPipelineService::cancel(&ticket_id, &resolver.path, Some("mcp")) {
+        Ok(outcome) => {
+            let payload = json!({ "cancelled": true, "run": outcome.run });
+            ok(
+                req.id,
+                json!({
+                    "content": [{"type": "text", "text": serde_json::to_string_pretty(&payload).unwrap_or_default()}]
+                }),
+            )
+        }
+        Err(e) => err(req.id, -32603, &e.to_string(), None),
+    }
+}
@@ -0,0 +1,53 @@
+use serde_json::json;
+
+use super::super::{JsonRpcRequest, JsonRpcResponse, err, ok};
+use crate::api_types::ForecastRequest;
+use crate::services::forecast_service;
+use crate::workspace::TasksDirectoryResolver;
+
+pub(crate) fn handle_forecast(req: JsonRpcRequest) -> JsonRpcResponse {
+    let request: ForecastRequest = match serde_json::from_value(req.params.clone()) {
+        Ok(request) => request,
+        Err(e) => {
+            return err(
+                req.id,
+                -32602,
+                "Invalid params",
+                Some(json!({"message": e.to_string()})),
+            );
+        }
+    };
+    let (scope, options) = match request.to_scope_and_options() {
+        Ok(parsed) => parsed,
+        Err(message) => return err(req.id, -32602, &message, None),
+    };
+    let resolver = match TasksDirectoryResolver::resolve(None, None) {
+        Ok(r) => r,
+        Err(e) => {
+            return err(
+                req.id,
+                -32603,
+                "Internal error",
+                Some(json!({"message": e})),
+            );
+        }
+    };
+    let repo_root = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| crate::utils::git::find_repo_root(&cwd));
+    match forecast_service::run(
+        &resolver,
+        repo_root.as_deref(),
+        &scope,
+        &options,
+        chrono::Utc::now(),
+    ) {
+        Ok(report) => ok(
+            req.id,
+            json!({
+                "content": [ { "type": "text", "text": serde_json::to_string_pretty(&report).unwrap_or_else(|_| "{}".into()) } ]
+            }),
+        ),
+        Err(message) => err(req.id, -32602, &message, None),
+    }
+}
@@ -1,5 +1,6 @@
 pub(super) mod agent;
 pub(super) mod config;
+pub(super) mod forecast;
 pub(super) mod projects;
 pub(super) mod sprints;
 pub(super) mod sync;
@@ -7,10 +8,12 @@ pub(super) mod tasks;
 pub(super) mod whoami;
 
 pub(super) use agent::{
-    handle_agent_cancel, handle_agent_list_jobs, handle_agent_run, handle_agent_send_message,
-    handle_agent_status,
+    handle_agent_cancel, handle_agent_list_jobs, handle_agent_pipeline_cancel,
+    handle_agent_pipeline_run, handle_agent_pipeline_status, handle_agent_run,
+    handle_agent_send_message, handle_agent_status,
 };
 pub(super) use config::{handle_config_set, handle_config_show};
+pub(super) use forecast::handle_forecast;
 pub(super) use projects::{handle_project_list, handle_project_stats};
 pub(super) use sprints::{
     handle_sprint_add, handle_sprint_backlog, handle_sprint_burndown, handle_sprint_create,
@@ -21,7 +24,8 @@ pub(super) use sync::{handle_sync_pull, handle_sync_push};
 pub(super) use tasks::{
     handle_task_bulk_comment_add, handle_task_bulk_reference_add,
     handle_task_bulk_reference_remove, handle_task_bulk_update, handle_task_comment_add,
-    handle_task_comment_update, handle_task_create, handle_task_delete, handle_task_get,
-    handle_task_list, handle_task_reference_add, handle_task_reference_remove, handle_task_update,
+    handle_task_comment_update, handle_task_copy, handle_task_create, handle_task_delete,
+    handle_task_get, handle_task_list, handle_task_move, handle_task_reference_add,
+    handle_task_reference_remove, handle_task_update,
 };
 pub(super) use whoami::handle_whoami;
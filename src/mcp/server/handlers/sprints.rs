@@ -190,6 +190,7 @@ fn sprint_from_create_request(payload: &SprintCreateRequest) -> Sprint {
         plan.capacity = Some(SprintCapacity {
             points: payload.capacity_points,
             hours: payload.capacity_hours,
+            ..SprintCapacity::default()
         });
     }
     if let Some(overdue_after) = clean_opt_string(payload.overdue_after.clone()) {
@@ -1037,6 +1038,18 @@ pub(crate) fn handle_sprint_add(req: JsonRpcRequest) -> JsonRpcResponse {
             ),
         );
     }
+    if !outcome.capacity_warnings.is_empty() {
+        payload.insert(
+            "capacity_warnings".to_string(),
+            Value::Array(
+                outcome
+                    .capacity_warnings
+                    .iter()
+                    .map(|msg| Value::String(msg.clone()))
+                    .collect(),
+            ),
+        );
+    }
     payload.insert(
         "missing_sprints".to_string(),
         Value::Array(
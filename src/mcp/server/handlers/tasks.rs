@@ -5,11 +5,14 @@ use super::super::{
     JsonRpcRequest, JsonRpcResponse, MCP_DEFAULT_TASK_LIST_LIMIT, MCP_MAX_TASK_LIST_LIMIT, err, ok,
     parse_cursor_value, parse_limit_value,
 };
-use crate::api_types::{TaskCreate, TaskDTO, TaskListFilter, TaskUpdate};
+use crate::api_types::{AgentApproval, TaskCreate, TaskDTO, TaskListFilter, TaskUpdate};
 use crate::cli::project::ProjectResolver;
 use crate::cli::validation::CliValidator;
 use crate::config::manager::ConfigManager;
+use crate::errors::LoTaRError;
+use crate::services::agent_approval_service::{AgentApprovalService, AgentOperation, ApprovalGate};
 use crate::services::reference_service::ReferenceService;
+use crate::services::task_relocation_service::TaskRelocationService;
 use crate::services::task_service::TaskService;
 use crate::storage::manager::Storage;
 use crate::types::{
@@ -24,6 +27,85 @@ fn now_rfc3339() -> String {
     chrono::Utc::now().to_rfc3339()
 }
 
+/// Outcome of checking an MCP task operation against `agent.approvals`.
+enum AgentGate {
+    Proceed,
+    /// The operation was parked; the payload describes the pending approval.
+    Parked(Value),
+    /// A deny rule matched (or the rules could not be evaluated).
+    Refused {
+        code: i64,
+        message: String,
+    },
+}
+
+/// Approval gates only apply when the MCP server runs inside an agent job.
+fn gate_agent_operation(
+    tasks_dir: &std::path::Path,
+    ticket_id: &str,
+    operation: &AgentOperation,
+) -> AgentGate {
+    let caller = AgentApprovalService::current_caller();
+    match AgentApprovalService::gate(tasks_dir, ticket_id, operation, caller.as_ref()) {
+        Ok(ApprovalGate::Allow) => AgentGate::Proceed,
+        Ok(ApprovalGate::Parked(approval)) => AgentGate::Parked(parked_payload(&approval)),
+        Ok(ApprovalGate::Denied(message)) => AgentGate::Refused {
+            code: -32007,
+            message,
+        },
+        Err(e) => AgentGate::Refused {
+            code: -32603,
+            message: e.to_string(),
+        },
+    }
+}
+
+fn parked_payload(approval: &AgentApproval) -> Value {
+    json!({
+        "status": "pending_approval",
+        "message": format!(
+            "Parked as approval #{} until a human approves it. Continue with other work; you will be told if it is rejected.",
+            approval.id
+        ),
+        "approval": approval,
+    })
+}
+
+/// The gate outcome behind a service error, when `TaskService` parked or refused the operation.
+fn agent_gate_from_error(error: &LoTaRError) -> Option<AgentGate> {
+    match error {
+        LoTaRError::ApprovalPending(approval) => Some(AgentGate::Parked(parked_payload(approval))),
+        LoTaRError::PermissionDenied(message) => Some(AgentGate::Refused {
+            code: -32007,
+            message: message.clone(),
+        }),
+        _ => None,
+    }
+}
+
+/// Map a gate outcome to an early response for single-ticket handlers.
+fn agent_gate_response(req_id: Option<Value>, gate: AgentGate) -> Option<JsonRpcResponse> {
+    match gate {
+        AgentGate::Proceed => None,
+        AgentGate::Parked(payload) => Some(ok(
+            req_id,
+            json!({
+                "content": [ { "type": "text", "text": serde_json::to_string_pretty(&payload).unwrap_or_else(|_| "{}".into()) } ]
+            }),
+        )),
+        AgentGate::Refused { code, message } => Some(err(
+            req_id,
+            code,
+            if code == -32007 {
+                "Operation not permitted"
+            } else {
+                "Internal error"
+            },
+            Some(json!({"message": message})),
+        )),
+    }
+}
+
 fn parse_sprint_ids(value: Option<&Value>) -> Result<Vec<u32>, &'static str> {
     fn parse_one(raw: &Value) -> Option<u32> {
         match raw {
@@ -676,20 +758,25 @@ pub(crate) fn handle_task_update(req: JsonRpcRequest) -> JsonRpcResponse {
         Ok(patch) => patch,
         Err(resp) => return resp,
     };
+    let id = id.unwrap();
     let mut storage = Storage::new(&resolver.path.clone());
-    match TaskService::update(&mut storage, &id.unwrap(), patch) {
+    match TaskService::update(&mut storage, &id, patch) {
         Ok(task) => ok(
             req.id,
             json!({
                 "content": [ { "type": "text", "text": serde_json::to_string_pretty(&task).unwrap_or_else(|_| "{}".into()) } ]
             }),
         ),
-        Err(e) => err(
-            req.id,
-            -32005,
-            "Task update failed",
-            Some(json!({"message": e.to_string()})),
-        ),
+        Err(e) => agent_gate_from_error(&e)
+            .and_then(|gate| agent_gate_response(req.id.clone(), gate))
+            .unwrap_or_else(|| {
+                err(
+                    req.id,
+                    -32005,
+                    "Task update failed",
+                    Some(json!({"message": e.to_string()})),
+                )
+            }),
     }
 }
 
@@ -720,6 +807,11 @@ pub(crate) fn handle_task_comment_add(req: JsonRpcRequest) -> JsonRpcResponse {
     let dto = match TaskService::add_comment(&mut storage, &id, &text) {
         Ok(dto) => dto,
         Err(error) => {
+            if let Some(resp) = agent_gate_from_error(&error)
+                .and_then(|gate| agent_gate_response(req.id.clone(), gate))
+            {
+                return resp;
+            }
             let msg = error.to_string();
             if msg.contains("not found") {
                 return err(
@@ -917,12 +1009,20 @@ pub(crate) fn handle_task_bulk_update(req: JsonRpcRequest) -> JsonRpcResponse {
     let mut storage = Storage::new(&resolver.path.clone());
     let mut updated: Vec<TaskDTO> = Vec::new();
     let mut failed: Vec<Value> = Vec::new();
+    let mut pending: Vec<Value> = Vec::new();
 
     for id in ids {
         match TaskService::update(&mut storage, &id, patch.clone()) {
             Ok(task) => updated.push(task),
+            Err(LoTaRError::ApprovalPending(approval)) => {
+                pending.push(json!(approval));
+            }
             Err(e) => {
-                failed.push(json!({"id": id, "error": e.to_string()}));
+                let message = match e {
+                    LoTaRError::PermissionDenied(message) => message,
+                    other => other.to_string(),
+                };
+                failed.push(json!({"id": id, "error": message}));
                 if stop_on_error {
                     break;
                 }
@@ -930,11 +1030,14 @@ pub(crate) fn handle_task_bulk_update(req: JsonRpcRequest) -> JsonRpcResponse {
         }
     }
 
-    let payload = json!({
+    let mut payload = json!({
         "status": "ok",
         "updated": updated,
         "failed": failed,
     });
+    if !pending.is_empty() {
+        payload["pending_approval"] = Value::Array(pending);
+    }
 
     ok(
         req.id,
@@ -983,8 +1086,24 @@ pub(crate) fn handle_task_bulk_comment_add(req: JsonRpcRequest) -> JsonRpcRespon
     let mut storage = Storage::new(&resolver.path.clone());
     let mut updated: Vec<TaskDTO> = Vec::new();
     let mut failed: Vec<Value> = Vec::new();
+    let mut pending: Vec<Value> = Vec::new();
+    let operation = AgentOperation::Comment(text.clone());
 
     for id in ids {
+        match gate_agent_operation(resolver.path.as_path(), &id, &operation) {
+            AgentGate::Proceed => {}
+            AgentGate::Parked(parked) => {
+                pending.push(parked["approval"].clone());
+                continue;
+            }
+            AgentGate::Refused { message, .. } => {
+                failed.push(json!({"id": id, "error": message}));
+                if stop_on_error {
+                    break;
+                }
+                continue;
+            }
+        }
         let project_prefix = id.split('-').next().unwrap_or("").to_string();
         let mut task = match storage.get(&id, &project_prefix) {
             Some(task) => task,
@@ -1032,11 +1151,14 @@ pub(crate) fn handle_task_bulk_comment_add(req: JsonRpcRequest) -> JsonRpcRespon
         }
     }
 
-    let payload = json!({
+    let mut payload = json!({
         "status": "ok",
         "updated": updated,
         "failed": failed,
     });
+    if !pending.is_empty() {
+        payload["pending_approval"] = Value::Array(pending);
+    }
 
     ok(
         req.id,
@@ -1416,18 +1538,83 @@ pub(crate) fn handle_task_delete(req: JsonRpcRequest) -> JsonRpcResponse {
             );
         }
     };
+    let id = id.unwrap();
     let mut storage = Storage::new(&resolver.path);
-    match TaskService::delete(&mut storage, id.unwrap(), project) {
+    match TaskService::delete(&mut storage, id, project) {
         Ok(deleted) => ok(
             req.id,
             json!({
                 "content": [ { "type": "text", "text": format!("deleted={}", deleted) } ]
             }),
         ),
+        Err(e) => agent_gate_from_error(&e)
+            .and_then(|gate| agent_gate_response(req.id.clone(), gate))
+            .unwrap_or_else(|| {
+                err(
+                    req.id,
+                    -32006,
+                    "Task delete failed",
+                    Some(json!({"message": e.to_string()})),
+                )
+            }),
+    }
+}
+
+pub(crate) fn handle_task_move(req: JsonRpcRequest) -> JsonRpcResponse {
+    handle_task_transfer(req, false)
+}
+
+pub(crate) fn handle_task_copy(req: JsonRpcRequest) -> JsonRpcResponse {
+    handle_task_transfer(req, true)
+}
+
+fn handle_task_transfer(req: JsonRpcRequest, copy: bool) -> JsonRpcResponse {
+    let Some(id) = req.params.get("id").and_then(|v| v.as_str()) else {
+        return err(req.id, -32602, "Missing id", None);
+    };
+    let Some(to) = req.params.get("to").and_then(|v| v.as_str()) else {
+        return err(req.id, -32602, "Missing to", None);
+    };
+    let dry_run = req
+        .params
+        .get("dry_run")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let resolver = match TasksDirectoryResolver::resolve(None, None) {
+        Ok(r) => r,
+        Err(e) => {
+            return err(
+                req.id,
+                -32603,
+                "Internal error",
+                Some(json!({"message": e})),
+            );
+        }
+    };
+    let id = match req.params.get("project").and_then(|v| v.as_str()) {
+        Some(project) if id.bytes().all(|b| b.is_ascii_digit()) => format!("{}-{}", project, id),
+        _ => id.to_string(),
+    };
+    let result = if copy {
+        TaskRelocationService::copy_task(&resolver, &id, to, dry_run)
+    } else {
+        TaskRelocationService::move_task(&resolver, &id, to, dry_run)
+    };
+    match result {
+        Ok(relocation) => ok(
+            req.id,
+            json!({
+                "content": [ { "type": "text", "text": serde_json::to_string_pretty(&relocation).unwrap_or_else(|_| "{}".into()) } ]
+            }),
+        ),
         Err(e) => err(
             req.id,
             -32006,
-            "Task delete failed",
+            if copy {
+                "Task copy failed"
+            } else {
+                "Task move failed"
+            },
             Some(json!({"message": e.to_string()})),
         ),
     }
@@ -1556,10 +1743,8 @@ pub(crate) fn handle_task_list(req: JsonRpcRequest) -> JsonRpcResponse {
         custom_fields,
     };
     let storage = Storage::new(&resolver.path.clone());
-    let mut tasks = TaskService::list(&storage, &filter)
-        .into_iter()
-        .map(|(_, t)| t)
-        .collect::<Vec<_>>();
+    let (tasks, warnings) = TaskService::list_with_warnings(&storage, &filter);
+    let mut tasks = tasks.into_iter().map(|(_, t)| t).collect::<Vec<_>>();
 
     if let Some(raw) = req.params.get("assignee").and_then(|v| v.as_str()) {
         let trimmed = raw.trim();
@@ -1571,11 +1756,14 @@ pub(crate) fn handle_task_list(req: JsonRpcRequest) -> JsonRpcResponse {
             };
             match target {
                 Some(user) => {
-                    let norm_user = crate::utils::member::member_for_comparison(&user);
+                    let directory = crate::utils::member::MemberDirectory::load(Some(
+                        storage.root_path.as_path(),
+                    ));
+                    let norm_user = directory.comparison_key(&user);
                     tasks.retain(|task| {
-                        task.assignee.as_deref().is_some_and(|a| {
-                            crate::utils::member::member_for_comparison(a) == norm_user
-                        })
+                        task.assignee
+                            .as_deref()
+                            .is_some_and(|a| directory.comparison_key(a) == norm_user)
                     });
                 }
                 None => tasks.clear(),
@@ -1653,6 +1841,10 @@ pub(crate) fn handle_task_list(req: JsonRpcRequest) -> JsonRpcResponse {
         "tasks".into(),
         serde_json::to_value(&page).unwrap_or_else(|_| Value::Array(Vec::new())),
     );
+    payload.insert(
+        "warnings".into(),
+        serde_json::to_value(&warnings).unwrap_or_else(|_| Value::Array(Vec::new())),
+    );
     if let Some(hints) = enum_hints {
         payload.insert("enumHints".into(), enum_hints_to_value(&hints));
     }
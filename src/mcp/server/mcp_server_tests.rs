@@ -451,6 +451,68 @@ fn tools_call_update_delete_list_and_invalid_enum() {
     clear_tasks_dir_env();
 }
 
+#[test]
+fn tools_call_task_move_leaves_redirect_and_task_copy_keeps_original() {
+    let _lock = lock_var("LOTAR_TASKS_DIR");
+    let tmp = tempfile::tempdir().unwrap();
+    let tasks_dir = tmp.path().join(".tasks");
+    seed_single_project_config(&tasks_dir);
+    std::fs::create_dir_all(tasks_dir.join("MCP")).unwrap();
+    std::fs::create_dir_all(tasks_dir.join("OPS")).unwrap();
+    std::fs::write(
+        tasks_dir.join("MCP/1.yml"),
+        "title: Movable\ncreated: 2024-01-01T00:00:00Z\n",
+    )
+    .unwrap();
+    std::fs::write(
+        tasks_dir.join("MCP/2.yml"),
+        "title: Dependent\ncreated: 2024-01-02T00:00:00Z\nrelationships:\n  depends_on: ['1']\n",
+    )
+    .unwrap();
+    set_tasks_dir_env(&tasks_dir);
+
+    let call = |id: i64, name: &str, arguments: Value| {
+        dispatch(JsonRpcRequest {
+            jsonrpc: "2.0".into(),
+            id: Some(json!(id)),
+            method: "tools/call".into(),
+            params: json!({ "name": name, "arguments": arguments }),
+        })
+    };
+
+    let moved = call(
+        70,
+        "task_move",
+        json!({ "id": "1", "project": "MCP", "to": "OPS" }),
+    );
+    assert!(moved.error.is_none(), "task_move failed: {:?}", moved.error);
+    let relocation = parse_tool_payload(&moved);
+    assert_eq!(relocation["from"], "MCP-1");
+    assert_eq!(relocation["to"], "OPS-1");
+    assert_eq!(relocation["reason"], "moved");
+
+    let dependent = std::fs::read_to_string(tasks_dir.join("MCP/2.yml")).unwrap();
+    assert!(dependent.contains("OPS-1"), "{dependent}");
+
+    let fetched = call(71, "task_get", json!({ "id": "MCP-1" }));
+    assert!(fetched.error.is_none(), "old id should resolve");
+    assert_eq!(parse_tool_payload(&fetched)["id"], "OPS-1");
+
+    let copied = call(72, "task_copy", json!({ "id": "MCP-2", "to": "OPS" }));
+    assert!(
+        copied.error.is_none(),
+        "task_copy failed: {:?}",
+        copied.error
+    );
+    assert_eq!(parse_tool_payload(&copied)["to"], "OPS-2");
+    assert!(tasks_dir.join("MCP/2.yml").exists());
+
+    let missing = call(73, "task_copy", json!({ "id": "MCP-2" }));
+    assert!(missing.error.is_some());
+
+    clear_tasks_dir_env();
+}
+
 #[test]
 fn project_list_is_paginated() {
     let _lock = lock_var("LOTAR_TASKS_DIR");
@@ -42,6 +42,16 @@ pub(super) fn build_tool_definitions(enum_hints: Option<&EnumHints>) -> Vec<Valu
         make_task_reference_add_tool(enum_hints),
         make_task_reference_remove_tool(enum_hints),
         make_task_delete_tool(enum_hints),
+        make_task_transfer_tool(
+            "task_move",
+            "Move a task to another project. Allocates the next free ID there, rewrites relationships, sprint memberships and ticket keys in source files that mention the old ID, and leaves a redirect so the old ID still resolves. Returns the relocation (from, to, rewrites).",
+            enum_hints,
+        ),
+        make_task_transfer_tool(
+            "task_copy",
+            "Copy a task into another project under the next free ID there. The copy starts a fresh history and sprint membership; references to the original are unchanged. Returns the relocation (from, to).",
+            enum_hints,
+        ),
         make_task_list_tool(enum_hints),
         make_sprint_list_tool(),
         make_sprint_get_tool(),
@@ -54,6 +64,7 @@ pub(super) fn build_tool_definitions(enum_hints: Option<&EnumHints>) -> Vec<Valu
         make_sprint_remove_tool(),
         make_sprint_delete_tool(),
         make_sprint_backlog_tool(enum_hints),
+        make_forecast_tool(),
         make_project_list_tool(enum_hints),
         make_project_stats_tool(enum_hints),
         make_config_show_tool(enum_hints),
@@ -66,6 +77,9 @@ pub(super) fn build_tool_definitions(enum_hints: Option<&EnumHints>) -> Vec<Valu
         make_agent_list_jobs_tool(),
         make_agent_cancel_tool(),
         make_agent_send_message_tool(),
+        make_agent_pipeline_run_tool(),
+        make_agent_pipeline_status_tool(),
+        make_agent_pipeline_cancel_tool(),
     ]
 }
 
@@ -408,6 +422,41 @@ fn make_task_delete_tool(enum_hints: Option<&EnumHints>) -> Value {
     tool
 }
 
+fn make_task_transfer_tool(name: &str, description: &str, enum_hints: Option<&EnumHints>) -> Value {
+    let mut tool = json!({
+        "name": name,
+        "description": description,
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "id": {"type": "string"},
+                "project": {"type": ["string", "null"]},
+                "to": {"type": "string", "description": "Target project (name or prefix)"},
+                "dry_run": {"type": ["boolean", "null"]}
+            },
+            "required": ["id", "to"],
+            "additionalProperties": false
+        }
+    });
+
+    let mut field_hints = JsonMap::new();
+    for field in ["project", "to"] {
+        insert_field_hint(
+            &mut field_hints,
+            field,
+            enum_hints.map(|h| h.projects.as_slice()),
+            false,
+        );
+    }
+    attach_field_hints(&mut tool, field_hints);
+
+    if let Some(hints) = enum_hints {
+        append_hint_descriptions(&mut tool, &[(hints.projects.as_slice(), "projects")]);
+    }
+
+    tool
+}
+
 fn make_task_list_tool(enum_hints: Option<&EnumHints>) -> Value {
     let description = "List tasks using optional filters. status/priority/type accept a single string, comma-separated string, or array and are validated via project config. assignee accepts '@me'. tags can be provided as tag (single) or tags (multi). search performs a text match across id/title/description/tags. custom_fields filters require string or array-of-string values. sprints filters by numeric sprint ids.\n\nPAGINATION: Results are paginated. Default page size is 50 (max 200). The response includes `total`, `count`, `cursor`, `limit`, `hasMore`, and `nextCursor`. When `hasMore` is true, call the tool again with `cursor: <nextCursor>` (or `offset`) to fetch the next page. The `message` field summarizes what is shown vs. total. Do NOT assume the first response contains every matching task — always check `hasMore`/`total` before reasoning about completeness.".to_string();
 
@@ -838,7 +887,7 @@ fn make_sprint_update_tool() -> Value {
 fn make_sprint_summary_tool() -> Value {
     json!({
         "name": "sprint_summary",
-        "description": "Compute sprint summary metrics for a sprint.",
+        "description": "Compute sprint summary metrics for a sprint, including planned versus available effort per member when capacity is configured.",
         "inputSchema": {
             "type": "object",
             "properties": {
@@ -881,10 +930,34 @@ fn make_sprint_velocity_tool() -> Value {
     })
 }
 
+fn make_forecast_tool() -> Value {
+    json!({
+        "name": "forecast",
+        "description": "Monte Carlo completion forecast for open tasks, sampling historical throughput per closed sprint or per week. Returns completion-date percentiles (50/70/85/95) and, when target is given, the probability of finishing by that date. Narrow the tasks with project, q, tags, epic (descendants of an epic) or sprint.",
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "project": {"type": ["string", "null"], "description": "Project prefix (default: all projects)."},
+                "q": {"type": ["string", "null"], "description": "Free-text query."},
+                "tags": {"type": "array", "items": {"type": "string"}},
+                "epic": {"type": ["string", "null"], "description": "Epic ID; forecasts its children and their descendants."},
+                "sprint": {"type": ["string", "null"], "description": "Sprint reference like '3' or keyword (next/previous/active)."},
+                "by": {"type": ["string", "null"], "description": "sprint (default) | week"},
+                "metric": {"type": ["string", "null"], "description": "tasks (default) | points | hours"},
+                "history": {"type": ["number", "null"], "description": "Past sprints (default 6) or weeks (default 12) to sample; at most 520."},
+                "iterations": {"type": ["number", "null"], "description": "Simulated runs (default 10000, at most 100000)."},
+                "target": {"type": ["string", "null"], "description": "Target date (YYYY-MM-DD or e.g. +6w)."},
+                "seed": {"type": ["number", "null"], "description": "Seed for reproducible results."}
+            },
+            "additionalProperties": false
+        }
+    })
+}
+
 fn make_sprint_add_tool() -> Value {
     json!({
         "name": "sprint_add",
-        "description": "Attach one or more tasks to a sprint. When sprint is omitted the active sprint is assumed when unambiguous. Set allow_closed=true to override closed sprint guardrails and cleanup_missing=true to drop references to deleted sprint files before assigning.",
+        "description": "Attach one or more tasks to a sprint. When sprint is omitted the active sprint is assumed when unambiguous. Set allow_closed=true to override closed sprint guardrails and cleanup_missing=true to drop references to deleted sprint files before assigning. Returns capacity_warnings when the assignment pushes a member over capacity.",
         "inputSchema": {
             "type": "object",
             "properties": {
@@ -1288,10 +1361,16 @@ fn make_agent_status_tool() -> Value {
 fn make_agent_list_jobs_tool() -> Value {
     json!({
         "name": "agent_list_jobs",
-        "description": "List all agent jobs with queue statistics. Jobs are sorted newest first. Returns running, queued, completed, failed, and cancelled jobs.",
+        "description": "List agent jobs with queue statistics, newest first. Includes running and queued jobs plus finished jobs from the job logs (when agent_logs_dir is configured). Returns total, limit and offset for paging.",
         "inputSchema": {
             "type": "object",
-            "properties": {},
+            "properties": {
+                "ticket_id": {"type": ["string", "null"], "description": "Only jobs for this ticket."},
+                "status": {"type": ["string", "null"], "description": "Only jobs with this status (queued, running, completed, failed, cancelled)."},
+                "runner": {"type": ["string", "null"], "description": "Only jobs using this runner."},
+                "limit": {"type": ["number", "null"], "description": "Maximum number of jobs to return (1-500). Defaults to 50."},
+                "offset": {"type": ["number", "null"], "description": "Number of jobs to skip (0-based)."}
+            },
             "additionalProperties": false
         }
     })
@@ -1312,6 +1391,53 @@ fn make_agent_cancel_tool() -> Value {
     })
 }
 
+fn make_agent_pipeline_run_tool() -> Value {
+    json!({
+        "name": "agent_pipeline_run",
+        "description": "Start a configured multi-stage agent pipeline for a ticket. Stages run as agent jobs in dependency order; approval stages wait for a human decision. Returns the pipeline run with per-stage status.",
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "ticket_id": {"type": "string", "description": "The ticket ID to run the pipeline on (e.g. PROJ-1)"},
+                "pipeline": {"type": "string", "description": "Pipeline name from config"},
+                "prompt": {"type": ["string", "null"], "description": "Instructions used by stages without their own prompt"}
+            },
+            "required": ["ticket_id", "pipeline"],
+            "additionalProperties": false
+        }
+    })
+}
+
+fn make_agent_pipeline_status_tool() -> Value {
+    json!({
+        "name": "agent_pipeline_status",
+        "description": "Get the latest pipeline run for a ticket, including each stage's status, job ID, and how many times it ran.",
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "ticket_id": {"type": "string", "description": "The ticket ID whose pipeline run to show"}
+            },
+            "required": ["ticket_id"],
+            "additionalProperties": false
+        }
+    })
+}
+
+fn make_agent_pipeline_cancel_tool() -> Value {
+    json!({
+        "name": "agent_pipeline_cancel",
+        "description": "Cancel a ticket's pipeline run and any stage jobs it is running.",
+        "inputSchema": {
+            "type": "object",
+            "properties": {
+                "ticket_id": {"type": "string", "description": "The ticket ID whose pipeline run to cancel"}
+            },
+            "required": ["ticket_id"],
+            "additionalProperties": false
+        }
+    })
+}
+
 fn make_agent_send_message_tool() -> Value {
     json!({
         "name": "agent_send_message",
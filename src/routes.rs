@@ -14,7 +14,8 @@ use crate::services::{
     attachment_service::AttachmentService, automation_service::AutomationService,
     config_service::ConfigService, project_service::ProjectService,
     reference_service::ReferenceService, scan_service::ScanService, sprint_service::SprintService,
-    sync_service::SyncService, task_service::TaskService,
+    sync_service::SyncService, task_relocation_service::TaskRelocationService,
+    task_service::TaskService,
 };
 use crate::storage::sprint::{Sprint, SprintActual, SprintCapacity, SprintPlan};
 use crate::workspace::TasksDirectoryResolver;
@@ -286,6 +287,46 @@ fn clean_opt_string(input: Option<String>) -> Option<String> {
     })
 }
 
+/// POST /api/tasks/move and /api/tasks/copy share a body `{ id, to, dry_run? }`.
+fn transfer_task(req: &HttpRequest, copy: bool) -> HttpResponse {
+    let resolver = match TasksDirectoryResolver::resolve(None, None) {
+        Ok(r) => r,
+        Err(e) => return internal(json!({"error": {"code": "INTERNAL", "message": e}})),
+    };
+    let body: serde_json::Value = serde_json::from_slice(&req.body).unwrap_or(json!({}));
+    let args: crate::cli::TaskTransferArgs = match serde_json::from_value(body) {
+        Ok(v) => v,
+        Err(e) => return bad_request(format!("Invalid body: {}", e)),
+    };
+    let id = match req.query.get("project") {
+        Some(project) if args.id.bytes().all(|b| b.is_ascii_digit()) => {
+            format!("{}-{}", project, args.id)
+        }
+        _ => args.id.clone(),
+    };
+    let result = if copy {
+        TaskRelocationService::copy_task(&resolver, &id, &args.to, args.dry_run)
+    } else {
+        TaskRelocationService::move_task(&resolver, &id, &args.to, args.dry_run)
+    };
+    let relocation = match result {
+        Ok(relocation) => relocation,
+        Err(e @ LoTaRError::TaskNotFound(_)) => return not_found(e.to_string()),
+        Err(e) => return bad_request(e.to_string()),
+    };
+    if !args.dry_run {
+        let storage = crate::storage::manager::Storage::new(&resolver.path);
+        let actor = crate::utils::identity::resolve_current_user(Some(resolver.path.as_path()));
+        if !copy {
+            crate::api_events::emit_task_deleted(&relocation.from, actor.as_deref());
+        }
+        if let Ok(task) = TaskService::get(&storage, &relocation.to, None) {
+            crate::api_events::emit_task_created(&task, actor.as_deref());
+        }
+    }
+    ok_json(200, json!({"data": relocation}))
+}
+
 pub fn initialize(api_server: &mut ApiServer) {
     // GET /api/whoami -> current user resolved by identity
     api_server.register_handler("GET", "/api/whoami", |_req: &HttpRequest| {
@@ -313,18 +354,43 @@ pub fn initialize(api_server: &mut ApiServer) {
 
     // GET /api/jobs -> list agent jobs
     api_server.register_handler("GET", "/api/jobs", |req: &HttpRequest| {
-        let mut jobs = crate::services::agent_job_service::AgentJobService::list_jobs();
-        if let Some(ticket_id) = req.query.get("ticket_id") {
-            jobs.retain(|job| job.ticket_id == *ticket_id);
-        }
-        if let Some(status) = req.query.get("status") {
-            jobs.retain(|job| job.status.eq_ignore_ascii_case(status));
+        let resolver = match TasksDirectoryResolver::resolve(None, None) {
+            Ok(r) => r,
+            Err(e) => return internal(json!({"error": {"code": "INTERNAL", "message": e}})),
+        };
+        let non_empty = |key: &str| {
+            req.query
+                .get(key)
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+        let limit: usize = req
+            .query
+            .get("limit")
+            .and_then(|s| s.parse::<usize>().ok())
+            .unwrap_or(50)
+            .clamp(1, 500);
+        let offset: usize = req
+            .query
+            .get("offset")
+            .and_then(|s| s.parse::<usize>().ok())
+            .unwrap_or(0);
+        let filter = crate::services::agent_job_service::AgentJobListFilter {
+            ticket_id: non_empty("ticket_id"),
+            status: non_empty("status"),
+            runner: non_empty("runner"),
+            limit,
+            offset,
+        };
+        match crate::services::agent_job_service::AgentJobService::list_job_history(
+            &resolver.path,
+            &filter,
+        ) {
+            Ok(list) => ok_json(200, json!({"data": list})),
+            Err(err) => {
+                internal(json!({"error": {"code": "INTERNAL", "message": err.to_string()}}))
+            }
         }
-        let queue_stats = crate::services::agent_job_service::AgentJobService::queue_stats();
-        ok_json(
-            200,
-            json!({"data": {"jobs": jobs, "queue_stats": queue_stats}}),
-        )
     });
 
     // GET /api/jobs/get -> fetch agent job status
@@ -392,6 +458,201 @@ pub fn initialize(api_server: &mut ApiServer) {
             Err(err) => bad_request(err.to_string()),
         }
     });
+    // GET /api/pipelines -> configured pipelines and runs owned by this server
+    api_server.register_handler("GET", "/api/pipelines", |req: &HttpRequest| {
+        let resolver = match TasksDirectoryResolver::resolve(None, None) {
+            Ok(r) => r,
+            Err(e) => return internal(json!({"error": {"code": "INTERNAL", "message": e}})),
+        };
+        let cfg_mgr =
+            match crate::config::manager::ConfigManager::new_manager_with_tasks_dir_readonly(
+                &resolver.path,
+            ) {
+                Ok(m) => m,
+                Err(e) => {
+                    return internal(
+                        json!({"error": {"code": "INTERNAL", "message": e.to_string()}}),
+                    );
+                }
+            };
+        let config = match req.query.get("project") {
+            Some(prefix) => cfg_mgr
+                .get_project_config(prefix)
+                .unwrap_or_else(|_| cfg_mgr.get_resolved_config().clone()),
+            None => cfg_mgr.get_resolved_config().clone(),
+        };
+        let pipelines =
+            crate::services::agent_pipeline_service::AgentPipelineService::list_definitions(
+                &config,
+            );
+        let runs = crate::services::agent_pipeline_service::AgentPipelineService::list_runs();
+        ok_json(200, json!({"data": {"pipelines": pipelines, "runs": runs}}))
+    });
+
+    // POST /api/pipelines/run -> start a pipeline run for a ticket
+    api_server.register_handler("POST", "/api/pipelines/run", |req: &HttpRequest| {
+        let resolver = match TasksDirectoryResolver::resolve(None, None) {
+            Ok(r) => r,
+            Err(e) => return internal(json!({"error": {"code": "INTERNAL", "message": e}})),
+        };
+        let payload: crate::api_types::AgentPipelineRunRequest =
+            match serde_json::from_slice(&req.body) {
+                Ok(value) => value,
+                Err(err) => return bad_request(format!("Invalid body: {}", err)),
+            };
+        match crate::services::agent_pipeline_service::AgentPipelineService::start(
+            payload,
+            &resolver.path,
+        ) {
+            Ok(run) => ok_json(201, json!({"data": {"run": run}})),
+            Err(err) => bad_request(err.to_string()),
+        }
+    });
+
+    // GET /api/pipelines/get -> latest pipeline run for a ticket
+    api_server.register_handler("GET", "/api/pipelines/get", |req: &HttpRequest| {
+        let resolver = match TasksDirectoryResolver::resolve(None, None) {
+            Ok(r) => r,
+            Err(e) => return internal(json!({"error": {"code": "INTERNAL", "message": e}})),
+        };
+        let ticket_id = match req.query.get("ticket_id") {
+            Some(v) if !v.trim().is_empty() => v.clone(),
+            _ => return bad_request("Missing ticket_id".into()),
+        };
+        match crate::services::agent_pipeline_service::AgentPipelineService::status(
+            &resolver.path,
+            &ticket_id,
+        ) {
+            Ok(Some(run)) => ok_json(200, json!({"data": {"run": run}})),
+            Ok(None) => not_found(format!("No pipeline run found for '{}'", ticket_id)),
+            Err(err) => bad_request(err.to_string()),
+        }
+    });
+
+    // POST /api/pipelines/approve|reject -> decide a stage waiting for approval
+    for (path, approve) in [
+        ("/api/pipelines/approve", true),
+        ("/api/pipelines/reject", false),
+    ] {
+        api_server.register_handler("POST", path, move |req: &HttpRequest| {
+            let resolver = match TasksDirectoryResolver::resolve(None, None) {
+                Ok(r) => r,
+                Err(e) => return internal(json!({"error": {"code": "INTERNAL", "message": e}})),
+            };
+            let payload: crate::api_types::AgentPipelineDecisionRequest =
+                match serde_json::from_slice(&req.body) {
+                    Ok(value) => value,
+                    Err(err) => return bad_request(format!("Invalid body: {}", err)),
+                };
+            let actor = crate::utils::identity::resolve_current_user(Some(resolver.path.as_path()));
+            let result = if approve {
+                crate::services::agent_pipeline_service::AgentPipelineService::approve(
+                    &payload,
+                    &resolver.path,
+                    actor.as_deref(),
+                )
+            } else {
+                crate::services::agent_pipeline_service::AgentPipelineService::reject(
+                    &payload,
+                    &resolver.path,
+                    actor.as_deref(),
+                )
+            };
+            match result {
+                Ok(outcome) => ok_json(200, json!({"data": {"run": outcome.run}})),
+                Err(err) => bad_request(err.to_string()),
+            }
+        });
+    }
+
+    // POST /api/pipelines/cancel -> cancel a pipeline run and its running jobs
+    api_server.register_handler("POST", "/api/pipelines/cancel", |req: &HttpRequest| {
+        let resolver = match TasksDirectoryResolver::resolve(None, None) {
+            Ok(r) => r,
+            Err(e) => return internal(json!({"error": {"code": "INTERNAL", "message": e}})),
+        };
+        let payload: crate::api_types::AgentPipelineCancelRequest =
+            match serde_json::from_slice(&req.body) {
+                Ok(value) => value,
+                Err(err) => return bad_request(format!("Invalid body: {}", err)),
+            };
+        let actor = crate::utils::identity::resolve_current_user(Some(resolver.path.as_path()));
+        match crate::services::agent_pipeline_service::AgentPipelineService::cancel(
+            &payload.ticket_id,
+            &resolver.path,
+            actor.as_deref(),
+        ) {
+            Ok(outcome) => ok_json(200, json!({"data": {"run": outcome.run}})),
+            Err(err) => bad_request(err.to_string()),
+        }
+    });
+
+    // GET /api/approvals -> ticket operations agents parked for approval
+    api_server.register_handler("GET", "/api/approvals", |req: &HttpRequest| {
+        let resolver = match TasksDirectoryResolver::resolve(None, None) {
+            Ok(r) => r,
+            Err(e) => return internal(json!({"error": {"code": "INTERNAL", "message": e}})),
+        };
+        let status = req
+            .query
+            .get("status")
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty() && !v.eq_ignore_ascii_case("all"));
+        let ticket_id = req
+            .query
+            .get("ticket_id")
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty());
+        match crate::services::agent_approval_service::AgentApprovalService::list(
+            &resolver.path,
+            status,
+            ticket_id,
+        ) {
+            Ok(approvals) => ok_json(200, json!({"data": {"approvals": approvals}})),
+            Err(err) => {
+                internal(json!({"error": {"code": "INTERNAL", "message": err.to_string()}}))
+            }
+        }
+    });
+
+    // POST /api/approvals/approve|reject -> apply or discard a parked operation
+    for (path, approve) in [
+        ("/api/approvals/approve", true),
+        ("/api/approvals/reject", false),
+    ] {
+        api_server.register_handler("POST", path, move |req: &HttpRequest| {
+            let resolver = match TasksDirectoryResolver::resolve(None, None) {
+                Ok(r) => r,
+                Err(e) => return internal(json!({"error": {"code": "INTERNAL", "message": e}})),
+            };
+            let payload: crate::api_types::AgentApprovalDecisionRequest =
+                match serde_json::from_slice(&req.body) {
+                    Ok(value) => value,
+                    Err(err) => return bad_request(format!("Invalid body: {}", err)),
+                };
+            let actor = crate::utils::identity::resolve_current_user(Some(resolver.path.as_path()));
+            let result = if approve {
+                crate::services::agent_approval_service::AgentApprovalService::approve(
+                    &resolver.path,
+                    payload.id,
+                    actor.as_deref(),
+                    payload.note.as_deref(),
+                )
+            } else {
+                crate::services::agent_approval_service::AgentApprovalService::reject(
+                    &resolver.path,
+                    payload.id,
+                    actor.as_deref(),
+                    payload.note.as_deref(),
+                )
+            };
+            match result {
+                Ok(outcome) => ok_json(200, json!({"data": outcome})),
+                Err(err) => bad_request(err.to_string()),
+            }
+        });
+    }
+
     // POST /api/tasks/add
     api_server.register_handler("POST", "/api/tasks/add", |req: &HttpRequest| {
         // Resolve tasks root via resolver
@@ -578,6 +839,8 @@ pub fn initialize(api_server: &mut ApiServer) {
             "recent",
             "needs",
         ];
+        // Member aliases (emails, git names, ...) collapse onto their canonical handle.
+        let members = crate::utils::member::MemberDirectory::from_config(cfg);
         // Assignee (supports @me; __none__ means unassigned)
         let mut wants_unassigned = false;
         if let Some(a) = req.query.get("assignee") {
@@ -594,7 +857,9 @@ pub fn initialize(api_server: &mut ApiServer) {
                 let v = crate::utils::member::normalize_member_value(&resolved, |name| {
                     cfg.agent_profiles.contains_key(name)
                 });
-                uf.entry("assignee".into()).or_default().insert(v);
+                uf.entry("assignee".into())
+                    .or_default()
+                    .insert(members.canonical(&v));
             }
         }
         // Other keys
@@ -612,6 +877,13 @@ pub fn initialize(api_server: &mut ApiServer) {
                         .entry(name)
                         .or_default()
                         .push(part.to_string());
+                } else if matches!(
+                    crate::utils::fields::is_reserved_field(k),
+                    Some("reporter")
+                ) {
+                    uf.entry(k.clone())
+                        .or_default()
+                        .insert(members.canonical(part));
                 } else {
                     uf.entry(k.clone()).or_default().insert(part.to_string());
                 }
@@ -619,11 +891,11 @@ pub fn initialize(api_server: &mut ApiServer) {
         }
 
         let qc_key = crate::utils::query_cache::cache_key(&req.query);
-        let tasks = if let Some(cached) = crate::utils::query_cache::get(qc_key) {
+        let (tasks, warnings) = if let Some(cached) = crate::utils::query_cache::get(qc_key) {
             cached
         } else {
 
-        let tasks = TaskService::list(&storage, &filter);
+        let (tasks, warnings) = TaskService::list_with_warnings(&storage, &filter);
 
         // Apply in-memory filters if any
         let mut tasks = tasks; // shadow mutable
@@ -639,11 +911,11 @@ pub fn initialize(api_server: &mut ApiServer) {
                     match canon {
                         "assignee" => {
                             let v = t.assignee.as_deref().unwrap_or("");
-                            return Some(vec![v.trim_start_matches('@').to_string()]);
+                            return Some(vec![members.canonical(v.trim_start_matches('@'))]);
                         }
                         "reporter" => {
                             let v = t.reporter.as_deref().unwrap_or("");
-                            return Some(vec![v.trim_start_matches('@').to_string()]);
+                            return Some(vec![members.canonical(v.trim_start_matches('@'))]);
                         }
                         "type" => return Some(vec![t.task_type.to_string()]),
                         "status" => return Some(vec![t.status.to_string()]),
@@ -804,8 +1076,8 @@ pub fn initialize(api_server: &mut ApiServer) {
             }
         });
 
-        crate::utils::query_cache::put(qc_key, tasks.clone());
-        tasks
+        crate::utils::query_cache::put(qc_key, tasks.clone(), warnings.clone());
+        (tasks, warnings)
         }; // end query cache miss block
 
         let total = tasks.len();
@@ -820,6 +1092,7 @@ pub fn initialize(api_server: &mut ApiServer) {
             limit: page.limit,
             offset: page.offset,
             tasks: page_tasks,
+            warnings,
         };
 
         ok_json(200, json!({"data": payload}))
@@ -1032,6 +1305,7 @@ pub fn initialize(api_server: &mut ApiServer) {
             unchanged: outcome.unchanged,
             replaced: replaced_payload,
             messages,
+            capacity_warnings: outcome.capacity_warnings,
             integrity: make_integrity_payload(
                 &baseline_report,
                 &integrity_report,
@@ -1122,6 +1396,7 @@ pub fn initialize(api_server: &mut ApiServer) {
             unchanged: outcome.unchanged,
             replaced: replaced_payload,
             messages,
+            capacity_warnings: outcome.capacity_warnings,
             integrity: make_integrity_payload(
                 &baseline_report,
                 &integrity_report,
@@ -1796,6 +2071,80 @@ pub fn initialize(api_server: &mut ApiServer) {
         HttpResponse { status: 200, headers, body: wtr.into_bytes() }
     });
 
+    // GET /api/calendar.ics -> iCalendar feed of task due dates and sprint windows
+    api_server.register_handler("GET", "/api/calendar.ics", |req: &HttpRequest| {
+        let resolver = match TasksDirectoryResolver::resolve(None, None) {
+            Ok(r) => r,
+            Err(e) => return internal(json!({"error": {"code": "INTERNAL", "message": e}})),
+        };
+        let storage = crate::storage::manager::Storage::new(&resolver.path);
+        let cfg_mgr = match crate::config::manager::ConfigManager::new_manager_with_tasks_dir_readonly(&resolver.path) {
+            Ok(m) => m,
+            Err(e) => return internal(json!({"error": {"code": "INTERNAL", "message": format!("Failed to load config: {}", e)}})),
+        };
+        let project = req.query.get("project").filter(|p| !p.trim().is_empty()).cloned();
+        let cfg = match project.as_deref() {
+            Some(name) => match cfg_mgr.get_project_config(name) {
+                Ok(cfg) => cfg,
+                Err(e) => return bad_request(format!("Failed to load config: {}", e)),
+            },
+            None => cfg_mgr.get_resolved_config().clone(),
+        };
+        let flag = |key: &str, default: bool| -> bool {
+            req.query
+                .get(key)
+                .map(|value| matches!(value.to_ascii_lowercase().as_str(), "1" | "true" | "yes"))
+                .unwrap_or(default)
+        };
+        let assignee = match req.query.get("assignee").filter(|a| !a.trim().is_empty()) {
+            Some(a) => match crate::utils::identity::resolve_me_alias(a, Some(resolver.path.as_path())) {
+                Some(resolved) => Some(resolved),
+                None => return bad_request("Could not resolve @me".into()),
+            },
+            None => None,
+        };
+        let options = crate::services::ical_service::CalendarOptions {
+            project,
+            assignee,
+            tags: req
+                .query
+                .get("tags")
+                .map(|s| s.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
+                .unwrap_or_default(),
+            include_tasks: flag("tasks", true),
+            include_sprints: flag("sprints", true),
+            include_done: flag("include_done", false),
+            todos: flag("todo", false),
+        };
+        let calendar =
+            crate::services::ical_service::export_calendar(&storage, &cfg, &options, Utc::now());
+        let headers = vec![
+            ("Content-Type".to_string(), "text/calendar; charset=utf-8".to_string()),
+            ("Content-Disposition".to_string(), "inline; filename=lotar.ics".to_string()),
+        ];
+        HttpResponse { status: 200, headers, body: calendar.body.into_bytes() }
+    });
+
+    // GET /api/schema/<kind> -> JSON Schema for task, sprint, project, global or automation files
+    api_server.register_prefix_handler("GET", "/api/schema", |req: &HttpRequest| {
+        let raw = req
+            .path
+            .trim_end_matches('/')
+            .get("/api/schema".len()..)
+            .unwrap_or("")
+            .trim_start_matches('/');
+        let Some(kind) = crate::services::schema_service::SchemaKind::parse(raw) else {
+            return not_found(format!(
+                "Unknown schema '{}'; expected task, sprint, project, global or automation",
+                raw
+            ));
+        };
+        match crate::services::schema_service::generate(kind) {
+            Ok(schema) => ok_json(200, schema),
+            Err(e) => internal(json!({"error": {"code": "INTERNAL", "message": e.to_string()}})),
+        }
+    });
+
     // GET /api/tasks/get?id=ID[&project=PREFIX]
     api_server.register_handler("GET", "/api/tasks/get", |req: &HttpRequest| {
         let id = match req.query.get("id") {
@@ -2425,8 +2774,8 @@ pub fn initialize(api_server: &mut ApiServer) {
         }
 
         let kind = payload.kind.trim().to_ascii_lowercase();
-        if kind != "jira" && kind != "github" {
-            return bad_request("Reference kind must be jira or github".into());
+        if kind != "jira" && kind != "github" && kind != "commit" {
+            return bad_request("Reference kind must be jira, github or commit".into());
         }
 
         let resolver = match TasksDirectoryResolver::resolve(None, None) {
@@ -2475,8 +2824,8 @@ pub fn initialize(api_server: &mut ApiServer) {
             }
 
             let kind = payload.kind.trim().to_ascii_lowercase();
-            if kind != "jira" && kind != "github" {
-                return bad_request("Reference kind must be jira or github".into());
+            if kind != "jira" && kind != "github" && kind != "commit" {
+                return bad_request("Reference kind must be jira, github or commit".into());
             }
 
             let resolver = match TasksDirectoryResolver::resolve(None, None) {
@@ -2614,7 +2963,7 @@ pub fn initialize(api_server: &mut ApiServer) {
                 crate::api_events::emit_task_updated(&task, actor.as_deref());
                 ok_json(200, json!({"data": task}))
             },
-            Err(e) => bad_request(e.to_string()),
+            Err(e) => approval_response(&e).unwrap_or_else(|| bad_request(e.to_string())),
         }
     });
 
@@ -2666,7 +3015,7 @@ pub fn initialize(api_server: &mut ApiServer) {
                 crate::api_events::emit_task_updated(&task, actor.as_deref());
                 ok_json(200, json!({"data": task}))
             }
-            Err(e) => bad_request(e.to_string()),
+            Err(e) => approval_response(&e).unwrap_or_else(|| bad_request(e.to_string())),
         }
     });
 
@@ -2689,6 +3038,9 @@ pub fn initialize(api_server: &mut ApiServer) {
         let dto = match TaskService::add_comment(&mut storage, &id, &text) {
             Ok(dto) => dto,
             Err(err) => {
+                if let Some(response) = approval_response(&err) {
+                    return response;
+                }
                 let msg = err.to_string();
                 if msg.contains("not found") {
                     return not_found(format!("Task '{}' not found", id));
@@ -2800,12 +3152,14 @@ pub fn initialize(api_server: &mut ApiServer) {
         ) {
             Ok(value) => value,
             Err(err) => {
-                return internal(json!({
-                    "error": {
-                        "code": "INTERNAL",
-                        "message": err.to_string(),
-                    }
-                }));
+                return approval_response(&err).unwrap_or_else(|| {
+                    internal(json!({
+                        "error": {
+                            "code": "INTERNAL",
+                            "message": err.to_string(),
+                        }
+                    }))
+                });
             }
         };
         if deleted {
@@ -2815,6 +3169,16 @@ pub fn initialize(api_server: &mut ApiServer) {
         ok_json(200, json!({"data": {"deleted": deleted}}))
     });
 
+    // POST /api/tasks/move { id, to, dry_run? }
+    api_server.register_handler("POST", "/api/tasks/move", |req: &HttpRequest| {
+        transfer_task(req, false)
+    });
+
+    // POST /api/tasks/copy { id, to, dry_run? }
+    api_server.register_handler("POST", "/api/tasks/copy", |req: &HttpRequest| {
+        transfer_task(req, true)
+    });
+
     // GET /api/config/show
     api_server.register_handler("GET", "/api/config/show", |req: &HttpRequest| {
         let resolver = match TasksDirectoryResolver::resolve(None, None) {
@@ -3520,13 +3884,136 @@ pub fn initialize(api_server: &mut ApiServer) {
             .unwrap_or(now);
         let project = req.query.get("project").map(|s| s.as_str());
         match crate::services::audit_service::AuditService::list_activity(
-            &repo_root, &tasks_rel, since, until, gb, project,
+            &repo_root,
+            &tasks_rel,
+            since,
+            until,
+            gb,
+            project,
+            &crate::utils::member::MemberDirectory::load(Some(resolver.path.as_path())),
         ) {
             Ok(items) => ok_json(200, json!({"data": items})),
             Err(e) => internal(json!({"error": {"code": "INTERNAL", "message": e}})),
         }
     });
 
+    // GET /api/stats/flow[?since=ISO|14d][&until=ISO][&project=PREFIX]
+    api_server.register_handler("GET", "/api/stats/flow", |req: &HttpRequest| {
+        let resolver = match TasksDirectoryResolver::resolve(None, None) {
+            Ok(r) => r,
+            Err(e) => return internal(json!({"error": {"code": "INTERNAL", "message": e}})),
+        };
+        let (since, until) = match crate::utils::time::parse_since_until(
+            req.query.get("since").map(|s| s.as_str()),
+            req.query.get("until").map(|s| s.as_str()),
+        ) {
+            Ok(window) => window,
+            Err(e) => return bad_request(e),
+        };
+        let project = req.query.get("project").map(|s| s.as_str());
+        let storage = crate::storage::manager::Storage::new(&resolver.path.clone());
+        let cwd = std::env::current_dir().unwrap_or_else(|_| resolver.path.clone());
+        let repo_root = crate::utils::git::find_repo_root(&cwd);
+        let report = crate::services::flow_metrics::flow_report(
+            &storage,
+            repo_root.as_deref(),
+            project,
+            since,
+            until,
+        );
+        ok_json(200, json!({"data": report}))
+    });
+
+    // GET /api/stats/cycle-time[?since=ISO|14d][&until=ISO][&project=PREFIX]
+    api_server.register_handler("GET", "/api/stats/cycle-time", |req: &HttpRequest| {
+        let resolver = match TasksDirectoryResolver::resolve(None, None) {
+            Ok(r) => r,
+            Err(e) => return internal(json!({"error": {"code": "INTERNAL", "message": e}})),
+        };
+        let (since, until) = match crate::utils::time::parse_since_until(
+            req.query.get("since").map(|s| s.as_str()),
+            req.query.get("until").map(|s| s.as_str()),
+        ) {
+            Ok(window) => window,
+            Err(e) => return bad_request(e),
+        };
+        let project = req.query.get("project").map(|s| s.as_str());
+        let storage = crate::storage::manager::Storage::new(&resolver.path.clone());
+        let cwd = std::env::current_dir().unwrap_or_else(|_| resolver.path.clone());
+        let repo_root = crate::utils::git::find_repo_root(&cwd);
+        let report = crate::services::flow_metrics::cycle_time_report(
+            &storage,
+            repo_root.as_deref(),
+            project,
+            since,
+            until,
+        );
+        ok_json(200, json!({"data": report}))
+    });
+
+    // GET /api/forecast[?project=PREFIX][&q=TEXT][&tags=a,b][&epic=ID][&sprint=REF][&by=sprint|week]
+    //     [&metric=tasks|points|hours][&history=N][&iterations=N][&target=DATE][&seed=N]
+    api_server.register_handler("GET", "/api/forecast", |req: &HttpRequest| {
+        let resolver = match TasksDirectoryResolver::resolve(None, None) {
+            Ok(r) => r,
+            Err(e) => return internal(json!({"error": {"code": "INTERNAL", "message": e}})),
+        };
+        let text = |key: &str| req.query.get(key).cloned();
+        let number = |key: &str| -> Result<Option<u64>, String> {
+            req.query
+                .get(key)
+                .map(|raw| {
+                    raw.trim()
+                        .parse::<u64>()
+                        .map_err(|_| format!("Invalid {} '{}'", key, raw))
+                })
+                .transpose()
+        };
+        let (history, iterations, seed) =
+            match (number("history"), number("iterations"), number("seed")) {
+                (Ok(history), Ok(iterations), Ok(seed)) => (history, iterations, seed),
+                (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => return bad_request(e),
+            };
+        let request = crate::api_types::ForecastRequest {
+            project: text("project"),
+            q: text("q"),
+            tags: req
+                .query
+                .get("tags")
+                .map(|s| {
+                    s.split(',')
+                        .map(|p| p.trim().to_string())
+                        .filter(|p| !p.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            epic: text("epic"),
+            sprint: text("sprint"),
+            by: text("by"),
+            metric: text("metric"),
+            history: history.map(|v| usize::try_from(v).unwrap_or(usize::MAX)),
+            iterations: iterations.map(|v| usize::try_from(v).unwrap_or(usize::MAX)),
+            target: text("target"),
+            seed,
+        };
+        let (scope, options) = match request.to_scope_and_options() {
+            Ok(parsed) => parsed,
+            Err(e) => return bad_request(e),
+        };
+        let cwd = std::env::current_dir().unwrap_or_else(|_| resolver.path.clone());
+        let repo_root = crate::utils::git::find_repo_root(&cwd);
+        match crate::services::forecast_service::run(
+            &resolver,
+            repo_root.as_deref(),
+            &scope,
+            &options,
+            chrono::Utc::now(),
+        ) {
+            Ok(report) => ok_json(200, json!({"data": report})),
+            Err(e) => bad_request(e),
+        }
+    });
+
     // GET /api/activity/authors[?since=ISO][&until=ISO][&project=PREFIX]
     api_server.register_handler("GET", "/api/activity/authors", |req: &HttpRequest| {
         let resolver = match TasksDirectoryResolver::resolve(None, None) {
@@ -3559,7 +4046,12 @@ pub fn initialize(api_server: &mut ApiServer) {
             .unwrap_or(now);
         let project = req.query.get("project").map(|s| s.as_str());
         match crate::services::audit_service::AuditService::list_authors_activity(
-            &repo_root, &tasks_rel, since, until, project,
+            &repo_root,
+            &tasks_rel,
+            since,
+            until,
+            project,
+            &crate::utils::member::MemberDirectory::load(Some(resolver.path.as_path())),
         ) {
             Ok(items) => ok_json(200, json!({"data": items})),
             Err(e) => internal(json!({"error": {"code": "INTERNAL", "message": e}})),
@@ -3661,6 +4153,21 @@ fn bad_request(msg: String) -> HttpResponse {
     )
 }
 
+/// 202 for an agent operation parked by `agent.approvals`, 403 for one a deny rule refused.
+fn approval_response(err: &LoTaRError) -> Option<HttpResponse> {
+    match err {
+        LoTaRError::ApprovalPending(approval) => Some(ok_json(
+            202,
+            json!({"data": {"status": "pending_approval", "approval": approval}}),
+        )),
+        LoTaRError::PermissionDenied(msg) => Some(ok_json(
+            403,
+            json!({"error": {"code": "FORBIDDEN", "message": msg}}),
+        )),
+        _ => None,
+    }
+}
+
 fn internal(v: serde_json::Value) -> HttpResponse {
     ok_json(500, v)
 }
@@ -1,71 +1,19 @@
 //! Simple source scanner for TODO-like references in comments across many languages.
 
-// Supported extensions mapped to their single-line comment tokens
-const FILE_TYPES: &[(&str, &str)] = &[
-    // Single-line comment languages with //
-    ("rs", "//"),
-    ("rust", "//"),
-    ("go", "//"),
-    ("java", "//"),
-    ("js", "//"),
-    ("ts", "//"),
-    ("jsx", "//"),
-    ("tsx", "//"),
-    ("cpp", "//"),
-    ("cc", "//"),
-    ("cxx", "//"),
-    ("c", "//"),
-    ("h", "//"),
-    ("hpp", "//"),
-    ("cs", "//"),
-    ("scala", "//"),
-    ("groovy", "//"),
-    ("swift", "//"),
-    ("php", "//"),
-    ("kotlin", "//"),
-    ("dart", "//"),
-    ("fsharp", "//"),
-    ("lua", "--"),
-    // Hash-based comment languages
-    ("py", "#"),
-    ("rb", "#"),
-    ("sh", "#"),
-    ("bash", "#"),
-    ("perl", "#"),
-    ("r", "#"),
-    ("elixir", "#"),
-    ("powershell", "#"),
-    ("ps1", "#"),
-    ("nim", "#"),
-    ("yaml", "#"),
-    ("yml", "#"),
-    ("toml", "#"),
-    ("hcl", "#"),
-    ("tf", "#"),
-    // Double-dash comment languages
-    ("hs", "--"),
-    ("haskell", "--"),
-    ("elm", "--"),
-    ("pascal", "--"),
-    ("sql", "--"),
-    // Semicolon comment languages
-    ("clojure", ";"),
-    ("scheme", ";"),
-    ("commonlisp", ";"),
-    ("racket", ";"),
-    ("ini", ";"),
-    // Percent comment languages
-    ("erlang", "%"),
-    ("matlab", "%"),
-    ("tex", "%"),
-];
+mod lexer;
+pub mod syntax;
 
 use ignore::WalkBuilder;
+use lexer::CommentLexer;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use regex::Regex;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use syntax::SyntaxRegistry;
+
+use crate::config::types::ScanCommentSyntax;
 
 #[derive(Debug, Clone)]
 pub struct Reference {
@@ -99,6 +47,7 @@ pub struct Scanner {
     custom_ticket_key_regexes: Option<Vec<Regex>>, // configured ticket key patterns
     enable_ticket_words: bool,                     // treat ticket keys as triggers
     modified_only: bool,                           // limit scan to git-modified files
+    syntax: SyntaxRegistry,                        // comment syntax per extension
 }
 
 impl Scanner {
@@ -129,6 +78,7 @@ impl Scanner {
             custom_ticket_key_regexes: None,
             enable_ticket_words: false,
             modified_only: false,
+            syntax: SyntaxRegistry::builtin(),
         }
     }
 
@@ -185,6 +135,14 @@ impl Scanner {
         self
     }
 
+    /// Add or override comment syntax entries from `scan.comment_syntax`.
+    pub fn with_comment_syntax(mut self, entries: &BTreeMap<String, ScanCommentSyntax>) -> Self {
+        if !entries.is_empty() {
+            self.syntax = self.syntax.with_overrides(entries);
+        }
+        self
+    }
+
     pub fn with_modified_only(mut self, on: bool) -> Self {
         self.modified_only = on;
         self
@@ -262,12 +220,13 @@ impl Scanner {
         references
     }
 
-    fn is_supported_ext(ext: &str) -> bool {
-        if Self::get_comment_token(ext).is_some() {
-            return true;
-        }
-        let (open, close) = Self::block_tokens_for(ext);
-        open.is_some() && close.is_some()
+    /// Source files a scan of this root would read (honours ignore files and extension filters).
+    pub fn candidate_files(&self) -> Vec<PathBuf> {
+        self.collect_candidate_files(self.path.as_path())
+    }
+
+    fn is_supported_ext(&self, ext: &str) -> bool {
+        self.syntax.supports(ext)
     }
 
     fn collect_candidate_files(&self, dir_path: &Path) -> Vec<PathBuf> {
@@ -285,7 +244,7 @@ impl Scanner {
                     && let Some(ext) = abs.extension().and_then(|e| e.to_str())
                 {
                     let ext_lc = ext.to_ascii_lowercase();
-                    if Self::is_supported_ext(&ext_lc) {
+                    if self.is_supported_ext(&ext_lc) {
                         files.push(abs);
                     }
                 }
@@ -330,7 +289,7 @@ impl Scanner {
                 let path = entry.path().to_path_buf();
                 if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
                     let ext_lc = ext.to_ascii_lowercase();
-                    if !Self::is_supported_ext(&ext_lc) {
+                    if !self.is_supported_ext(&ext_lc) {
                         continue;
                     }
                     if let Some(ref excludes) = self.exclude_ext
@@ -390,67 +349,24 @@ impl Scanner {
         files
     }
 
-    fn get_comment_token(extension: &str) -> Option<&'static str> {
-        FILE_TYPES
-            .iter()
-            .find(|(file_type, _)| file_type == &extension)
-            .map(|(_, token)| *token)
-    }
-
     fn scan_file(&self, file_path: &Path, references: &mut Vec<Reference>) {
-        if let Ok(file_contents) = fs::read_to_string(file_path)
-            && let Some(extension) = file_path.extension()
-            && let Some(ext_str) = extension.to_str()
-        {
-            let ext_str = ext_str.to_ascii_lowercase();
-
-            // Determine comment syntaxes
-            let single_line = Self::get_comment_token(&ext_str);
-            let (block_open, block_close) = Self::block_tokens_for(&ext_str);
-
-            // Process each line to find TODOs in comments
-            let mut in_block = false;
-            let mut block_start_line: usize = 0;
-
-            for (line_number, raw_line) in file_contents.lines().enumerate() {
-                let mut line = raw_line;
-
-                // Handle block comment state transitions if supported
-                if let (Some(open), Some(close)) = (&block_open, &block_close) {
-                    if !in_block && let Some(open_idx) = line.find(open) {
-                        in_block = true;
-                        block_start_line = line_number + 1; // 1-based
-                        // Consider the remainder after the opener for same-line checks
-                        line = &line[open_idx + open.len()..];
-                    }
-
-                    if in_block {
-                        // If the closer appears on this line, truncate to the part before closer
-                        if let Some(close_idx) = line.find(close) {
-                            let before = &line[..close_idx];
-                            // Process the content within the block on this line
-                            self.process_comment_line(
-                                file_path,
-                                references,
-                                block_start_line, // report first line for block start
-                                before,
-                            );
-                            in_block = false;
-                            continue; // move to next line
-                        } else {
-                            // Entire line is within block; process as-is
-                            self.process_comment_line(file_path, references, line_number + 1, line);
-                            continue;
-                        }
-                    }
-                }
+        let Some(syntax) = file_path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| self.syntax.get(&ext.to_ascii_lowercase()))
+        else {
+            return;
+        };
+        let Ok(file_contents) = fs::read_to_string(file_path) else {
+            return;
+        };
 
-                // Single-line comments (if defined for this extension)
-                if let Some(start_comment) = single_line
-                    && raw_line.contains(start_comment)
-                {
-                    self.process_comment_line(file_path, references, line_number + 1, raw_line);
-                }
+        // The lexer tracks string and comment state across lines, so only real
+        // comment text reaches the signal matcher.
+        let mut lexer = CommentLexer::new(syntax);
+        for (line_number, line) in file_contents.lines().enumerate() {
+            for comment in lexer.next_line(line) {
+                self.process_comment_line(file_path, references, line_number + 1, comment);
             }
         }
     }
@@ -520,22 +436,6 @@ impl Scanner {
         references.push(reference);
     }
 
-    fn block_tokens_for(ext: &str) -> (Option<&'static str>, Option<&'static str>) {
-        // Provide block comment tokens per language family
-        match ext {
-            // C-style block comments
-            "rs" | "rust" | "c" | "h" | "hpp" | "cpp" | "cc" | "cxx" | "js" | "ts" | "jsx"
-            | "tsx" | "java" | "cs" | "scala" | "kotlin" | "go" | "dart" | "swift" | "groovy"
-            | "css" | "scss" | "less" => (Some("/*"), Some("*/")),
-            // HTML/XML/Markdown style block comments
-            "html" | "htm" | "xml" | "vue" | "svelte" | "md" | "markdown" => {
-                (Some("<!--"), Some("-->"))
-            }
-            // Others: no block comments
-            _ => (None, None),
-        }
-    }
-
     /// Suggest an idempotent insertion of ` (KEY)` right after the first signal word on the line.
     /// Returns Some(edited_line) when an insertion is proposed, or None if not applicable
     /// (no signal word found or the key already exists on the line).
@@ -0,0 +1,307 @@
+//! Line-by-line lexer that finds comments while skipping string literals.
+//!
+//! State carries across lines, so block comments, docstrings and multi-line strings
+//! are tracked through a whole file.
+
+use super::syntax::CommentSyntax;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Code,
+    /// Inside a block comment (`doc` selects the docstring pairs).
+    Block {
+        index: usize,
+        depth: usize,
+        doc: bool,
+    },
+    Str {
+        index: usize,
+    },
+}
+
+enum Token {
+    Line,
+    Block(usize),
+    Doc(usize),
+    Str(usize),
+}
+
+pub struct CommentLexer<'a> {
+    syntax: &'a CommentSyntax,
+    state: State,
+}
+
+impl<'a> CommentLexer<'a> {
+    pub fn new(syntax: &'a CommentSyntax) -> Self {
+        Self {
+            syntax,
+            state: State::Code,
+        }
+    }
+
+    /// Lex the next line and return the comment text found on it.
+    ///
+    /// Line comments include their token (`// TODO`); block comments and docstrings
+    /// return the text between their delimiters, one piece per line they cover.
+    pub fn next_line<'l>(&mut self, line: &'l str) -> Vec<&'l str> {
+        let mut comments = Vec::new();
+        let mut comment_start = 0;
+        let mut i = 0;
+        while i < line.len() {
+            let rest = &line[i..];
+            match self.state {
+                State::Code => match self.token_at(line, i) {
+                    Some((Token::Line, _)) => {
+                        comments.push(rest);
+                        return comments;
+                    }
+                    Some((Token::Block(index), len)) => {
+                        self.state = State::Block {
+                            index,
+                            depth: 1,
+                            doc: false,
+                        };
+                        i += len;
+                        comment_start = i;
+                    }
+                    Some((Token::Doc(index), len)) => {
+                        self.state = State::Block {
+                            index,
+                            depth: 1,
+                            doc: true,
+                        };
+                        i += len;
+                        comment_start = i;
+                    }
+                    Some((Token::Str(index), len)) => {
+                        self.state = State::Str { index };
+                        i += len;
+                    }
+                    None => match self.char_literal_len(rest) {
+                        Some(len) => i += len,
+                        None => i += char_len(rest),
+                    },
+                },
+                State::Block { index, depth, doc } => {
+                    let (open, close) = if doc {
+                        &self.syntax.docstrings[index]
+                    } else {
+                        &self.syntax.block[index]
+                    };
+                    if !doc && self.syntax.nested && rest.starts_with(open.as_str()) {
+                        self.state = State::Block {
+                            index,
+                            depth: depth + 1,
+                            doc,
+                        };
+                        i += open.len();
+                    } else if rest.starts_with(close.as_str()) {
+                        if depth == 1 {
+                            comments.push(&line[comment_start..i]);
+                            self.state = State::Code;
+                        } else {
+                            self.state = State::Block {
+                                index,
+                                depth: depth - 1,
+                                doc,
+                            };
+                        }
+                        i += close.len();
+                    } else {
+                        i += char_len(rest);
+                    }
+                }
+                State::Str { index } => {
+                    let delimiter = &self.syntax.strings[index];
+                    if delimiter
+                        .escape
+                        .is_some_and(|escape| rest.starts_with(escape))
+                    {
+                        i += char_len(rest);
+                        if i < line.len() {
+                            i += char_len(&line[i..]);
+                        }
+                    } else if rest.starts_with(delimiter.close.as_str()) {
+                        self.state = State::Code;
+                        i += delimiter.close.len();
+                    } else {
+                        i += char_len(rest);
+                    }
+                }
+            }
+        }
+
+        match self.state {
+            State::Block { .. } => comments.push(&line[comment_start..]),
+            State::Str { index } if !self.syntax.strings[index].multiline => {
+                self.state = State::Code;
+            }
+            _ => {}
+        }
+        comments
+    }
+
+    /// Find the longest comment or string opener at byte offset `i`.
+    fn token_at(&self, line: &str, i: usize) -> Option<(Token, usize)> {
+        let rest = &line[i..];
+        let mut best: Option<(Token, usize)> = None;
+        let mut consider = |token: Token, opener: &str| {
+            if !opener.is_empty()
+                && rest.starts_with(opener)
+                && best.as_ref().is_none_or(|(_, len)| opener.len() > *len)
+            {
+                best = Some((token, opener.len()));
+            }
+        };
+        // Docstrings win ties with identical string openers, so check them first.
+        if line[..i].trim().is_empty() {
+            for (index, (open, _)) in self.syntax.docstrings.iter().enumerate() {
+                consider(Token::Doc(index), open);
+            }
+        }
+        for (index, (open, _)) in self.syntax.block.iter().enumerate() {
+            consider(Token::Block(index), open);
+        }
+        for token in &self.syntax.line {
+            consider(Token::Line, token);
+        }
+        for (index, delimiter) in self.syntax.strings.iter().enumerate() {
+            consider(Token::Str(index), &delimiter.open);
+        }
+        best
+    }
+
+    /// Length of a char literal (`'x'`, `'\''`, `'\u{1F600}'`) starting at `rest`.
+    ///
+    /// Anything else starting with `'` (a lifetime or label) is not a literal.
+    fn char_literal_len(&self, rest: &str) -> Option<usize> {
+        if !self.syntax.char_literals {
+            return None;
+        }
+        let mut chars = rest.strip_prefix('\'')?.char_indices();
+        match chars.next()? {
+            (_, '\'') => None,
+            (_, '\\') => {
+                // Skip the escaped character; `{10FFFF}'` is the longest tail.
+                chars.next()?;
+                chars
+                    .take(9)
+                    .find(|&(_, c)| c == '\'')
+                    .map(|(offset, _)| offset + 2)
+            }
+            _ => {
+                let (offset, close) = chars.next()?;
+                (close == '\'').then_some(offset + 2)
+            }
+        }
+    }
+}
+
+fn char_len(rest: &str) -> usize {
+    rest.chars().next().map_or(1, char::len_utf8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::syntax::SyntaxRegistry;
+
+    fn lex(ext: &str, source: &str) -> Vec<(usize, String)> {
+        let registry = SyntaxRegistry::builtin();
+        let mut lexer = CommentLexer::new(registry.get(ext).expect("syntax"));
+        source
+            .lines()
+            .enumerate()
+            .flat_map(|(n, line)| {
+                lexer
+                    .next_line(line)
+                    .into_iter()
+                    .map(move |text| (n + 1, text.to_string()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn comment_tokens_inside_strings_are_ignored() {
+        let found = lex(
+            "js",
+            "const url = \"http://example.com\"; // TODO: real\nconst s = '// TODO: not a comment';",
+        );
+        assert_eq!(found, vec![(1, "// TODO: real".to_string())]);
+    }
+
+    #[test]
+    fn escaped_quotes_do_not_end_strings() {
+        let found = lex("c", "char *s = \"a \\\" // no\"; // yes");
+        assert_eq!(found, vec![(1, "// yes".to_string())]);
+    }
+
+    #[test]
+    fn nested_block_comments() {
+        let found = lex(
+            "rs",
+            "/* outer /* inner */ still TODO */ fn f() {}\n// after",
+        );
+        assert_eq!(
+            found,
+            vec![
+                (1, " outer /* inner */ still TODO ".to_string()),
+                (2, "// after".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn multiline_strings_hide_comment_tokens() {
+        let found = lex(
+            "rs",
+            "let s = \"line one\n// TODO inside string\n\"; // real",
+        );
+        assert_eq!(found, vec![(3, "// real".to_string())]);
+    }
+
+    #[test]
+    fn rust_char_literals_do_not_open_strings() {
+        let found = lex(
+            "rs",
+            "if c == '\"' || b == b'\"' || c == '\\'' {}\nfn f<'a>(s: &'a str) {} // TODO: real",
+        );
+        assert_eq!(found, vec![(2, "// TODO: real".to_string())]);
+        assert_eq!(
+            lex("rs", "let q = '\\u{22}'; // one\nlet s = \"'\"; // two"),
+            vec![(1, "// one".to_string()), (2, "// two".to_string())]
+        );
+    }
+
+    #[test]
+    fn python_docstrings_are_comments_but_strings_are_not() {
+        let source = "def f():\n    \"\"\"Do things.\n\n    TODO: handle errors\n    \"\"\"\n    x = \"\"\"TODO: data\"\"\"\n    # TODO: comment";
+        let found: Vec<_> = lex("py", source)
+            .into_iter()
+            .filter(|(_, text)| text.contains("TODO"))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (4, "    TODO: handle errors".to_string()),
+                (7, "# TODO: comment".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn lua_and_sql_block_comments() {
+        assert_eq!(
+            lex("lua", "--[[ TODO: block\nmore ]] x = 1 -- TODO: line"),
+            vec![
+                (1, " TODO: block".to_string()),
+                (2, "more ".to_string()),
+                (2, "-- TODO: line".to_string())
+            ]
+        );
+        assert_eq!(
+            lex("sql", "SELECT '--' /* TODO: index */ FROM t;"),
+            vec![(1, " TODO: index ".to_string())]
+        );
+    }
+}
@@ -0,0 +1,333 @@
+//! Comment and string-literal syntax per file extension.
+//!
+//! The built-in table covers every language the scanner supports. Projects can add
+//! extensions or replace built-in entries through `scan.comment_syntax` in config.
+
+use crate::config::types::{ScanCommentSyntax, ScanStringSyntax};
+use std::collections::{BTreeMap, HashMap};
+
+/// A string literal the lexer skips so comment tokens inside it are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringDelimiter {
+    pub open: String,
+    pub close: String,
+    /// Character that escapes the next character (`None` for raw strings).
+    pub escape: Option<char>,
+    /// Whether the literal may continue on the next line.
+    pub multiline: bool,
+}
+
+/// How comments and string literals are written in one language.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CommentSyntax {
+    /// Tokens that start a comment running to the end of the line.
+    pub line: Vec<String>,
+    /// Block comment open/close pairs.
+    pub block: Vec<(String, String)>,
+    /// Whether block comments nest (`/* /* */ */` in Rust).
+    pub nested: bool,
+    /// Pairs that count as comments only when they start a line, such as Python
+    /// docstrings or Ruby `=begin`/`=end`.
+    pub docstrings: Vec<(String, String)>,
+    pub strings: Vec<StringDelimiter>,
+    /// Whether `'x'` is a one-character literal rather than a string (Rust, where a
+    /// lone `'` also starts lifetimes).
+    pub char_literals: bool,
+}
+
+impl CommentSyntax {
+    fn apply(&mut self, entry: &ScanCommentSyntax) {
+        if !entry.line.is_empty() {
+            self.line = entry.line.clone();
+        }
+        if !entry.block.is_empty() {
+            self.block = pairs(&entry.block);
+        }
+        if let Some(nested) = entry.nested {
+            self.nested = nested;
+        }
+        if !entry.docstrings.is_empty() {
+            self.docstrings = pairs(&entry.docstrings);
+        }
+        if !entry.strings.is_empty() {
+            self.strings = entry.strings.iter().map(string_delimiter).collect();
+        }
+    }
+}
+
+/// Comment syntax for every supported extension (lowercase, without the dot).
+#[derive(Debug, Clone)]
+pub struct SyntaxRegistry {
+    by_ext: HashMap<String, CommentSyntax>,
+}
+
+impl Default for SyntaxRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl SyntaxRegistry {
+    pub fn builtin() -> Self {
+        let mut by_ext = HashMap::new();
+        for (exts, syntax) in builtin_syntaxes() {
+            for ext in exts {
+                by_ext.insert((*ext).to_string(), syntax.clone());
+            }
+        }
+        Self { by_ext }
+    }
+
+    /// Layer configured entries over the registry.
+    ///
+    /// An entry for a known extension only replaces the fields it sets; `extends`
+    /// starts from another extension's syntax instead.
+    pub fn with_overrides(mut self, overrides: &BTreeMap<String, ScanCommentSyntax>) -> Self {
+        let mut entries: Vec<_> = overrides.iter().collect();
+        // Entries that extend others go last so they see the configured base.
+        entries.sort_by_key(|(ext, entry)| (entry.extends.is_some(), ext.to_ascii_lowercase()));
+        for (ext, entry) in entries {
+            let ext = ext.trim().trim_start_matches('.').to_ascii_lowercase();
+            let mut syntax = match entry.extends.as_deref() {
+                Some(base) => self
+                    .by_ext
+                    .get(&base.trim().trim_start_matches('.').to_ascii_lowercase())
+                    .cloned()
+                    .unwrap_or_default(),
+                None => self.by_ext.get(&ext).cloned().unwrap_or_default(),
+            };
+            syntax.apply(entry);
+            self.by_ext.insert(ext, syntax);
+        }
+        self
+    }
+
+    pub fn get(&self, ext: &str) -> Option<&CommentSyntax> {
+        self.by_ext
+            .get(ext)
+            .filter(|syntax| !syntax.line.is_empty() || !syntax.block.is_empty())
+    }
+
+    pub fn supports(&self, ext: &str) -> bool {
+        self.get(ext).is_some()
+    }
+}
+
+fn pairs(values: &[[String; 2]]) -> Vec<(String, String)> {
+    values
+        .iter()
+        .map(|[open, close]| (open.clone(), close.clone()))
+        .collect()
+}
+
+fn string_delimiter(entry: &ScanStringSyntax) -> StringDelimiter {
+    match entry {
+        ScanStringSyntax::Quote(quote) => quoted(quote),
+        ScanStringSyntax::Delimited(detail) => StringDelimiter {
+            open: detail.open.clone(),
+            close: detail.close.clone().unwrap_or_else(|| detail.open.clone()),
+            escape: match detail.escape.as_deref() {
+                None => Some('\\'),
+                Some(escape) => escape.chars().next(),
+            },
+            multiline: detail.multiline,
+        },
+    }
+}
+
+fn quoted(quote: &str) -> StringDelimiter {
+    StringDelimiter {
+        open: quote.to_string(),
+        close: quote.to_string(),
+        escape: Some('\\'),
+        multiline: false,
+    }
+}
+
+fn multiline(open: &str, close: &str, escape: Option<char>) -> StringDelimiter {
+    StringDelimiter {
+        open: open.to_string(),
+        close: close.to_string(),
+        escape,
+        multiline: true,
+    }
+}
+
+fn raw(quote: &str) -> StringDelimiter {
+    StringDelimiter {
+        escape: None,
+        ..quoted(quote)
+    }
+}
+
+fn syntax(
+    line: &[&str],
+    block: &[(&str, &str)],
+    nested: bool,
+    strings: Vec<StringDelimiter>,
+) -> CommentSyntax {
+    CommentSyntax {
+        line: line.iter().map(|token| (*token).to_string()).collect(),
+        block: block
+            .iter()
+            .map(|(open, close)| ((*open).to_string(), (*close).to_string()))
+            .collect(),
+        nested,
+        docstrings: Vec::new(),
+        strings,
+        char_literals: false,
+    }
+}
+
+fn builtin_syntaxes() -> Vec<(&'static [&'static str], CommentSyntax)> {
+    let c_block = [("/*", "*/")];
+    let c_strings = || vec![quoted("\""), quoted("'")];
+    let python = CommentSyntax {
+        docstrings: vec![
+            ("\"\"\"".to_string(), "\"\"\"".to_string()),
+            ("'''".to_string(), "'''".to_string()),
+        ],
+        ..syntax(
+            &["#"],
+            &[],
+            false,
+            vec![
+                multiline("\"\"\"", "\"\"\"", Some('\\')),
+                multiline("'''", "'''", Some('\\')),
+                quoted("\""),
+                quoted("'"),
+            ],
+        )
+    };
+    let ruby = CommentSyntax {
+        docstrings: vec![("=begin".to_string(), "=end".to_string())],
+        ..syntax(&["#"], &[], false, c_strings())
+    };
+
+    vec![
+        (
+            &[
+                "c", "h", "cpp", "cc", "cxx", "hpp", "cs", "java", "groovy", "php",
+            ][..],
+            syntax(&["//"], &c_block, false, c_strings()),
+        ),
+        (
+            &["js", "ts", "jsx", "tsx"][..],
+            syntax(
+                &["//"],
+                &c_block,
+                false,
+                vec![quoted("\""), quoted("'"), multiline("`", "`", Some('\\'))],
+            ),
+        ),
+        (
+            &["go"][..],
+            syntax(
+                &["//"],
+                &c_block,
+                false,
+                vec![quoted("\""), quoted("'"), multiline("`", "`", None)],
+            ),
+        ),
+        (
+            // `'` also starts lifetimes in Rust, so it is only lexed as a char literal.
+            &["rs", "rust"][..],
+            CommentSyntax {
+                char_literals: true,
+                ..syntax(
+                    &["//"],
+                    &c_block,
+                    true,
+                    vec![
+                        multiline("r#\"", "\"#", None),
+                        multiline("r\"", "\"", None),
+                        multiline("\"", "\"", Some('\\')),
+                    ],
+                )
+            },
+        ),
+        (
+            &["scala", "kotlin", "swift", "dart"][..],
+            syntax(
+                &["//"],
+                &c_block,
+                true,
+                vec![
+                    multiline("\"\"\"", "\"\"\"", None),
+                    quoted("\""),
+                    quoted("'"),
+                ],
+            ),
+        ),
+        (
+            &["fsharp"][..],
+            syntax(&["//"], &[("(*", "*)")], true, vec![quoted("\"")]),
+        ),
+        (&["css"][..], syntax(&[], &c_block, false, c_strings())),
+        (
+            &["scss", "less"][..],
+            syntax(&["//"], &c_block, false, c_strings()),
+        ),
+        (
+            &["html", "htm", "xml", "vue", "svelte", "md", "markdown"][..],
+            syntax(&[], &[("<!--", "-->")], false, Vec::new()),
+        ),
+        (&["py"][..], python),
+        (&["rb"][..], ruby),
+        (
+            &["sh", "bash"][..],
+            syntax(&["#"], &[], false, vec![quoted("\""), raw("'")]),
+        ),
+        (
+            &["perl", "r", "elixir", "yaml", "yml", "toml"][..],
+            syntax(&["#"], &[], false, c_strings()),
+        ),
+        (
+            &["powershell", "ps1"][..],
+            syntax(&["#"], &[("<#", "#>")], false, vec![quoted("\""), raw("'")]),
+        ),
+        (
+            &["nim"][..],
+            syntax(&["#"], &[("#[", "]#")], true, vec![quoted("\"")]),
+        ),
+        (
+            &["hcl", "tf"][..],
+            syntax(&["#", "//"], &c_block, false, vec![quoted("\"")]),
+        ),
+        (
+            &["lua"][..],
+            syntax(
+                &["--"],
+                &[("--[[", "]]")],
+                false,
+                vec![quoted("\""), quoted("'"), multiline("[[", "]]", None)],
+            ),
+        ),
+        (
+            &["hs", "haskell", "elm"][..],
+            syntax(&["--"], &[("{-", "-}")], true, vec![quoted("\"")]),
+        ),
+        (
+            &["pascal"][..],
+            syntax(&["//"], &[("{", "}"), ("(*", "*)")], false, vec![raw("'")]),
+        ),
+        (
+            &["sql"][..],
+            syntax(&["--"], &c_block, false, vec![raw("'"), raw("\"")]),
+        ),
+        (
+            &["clojure", "commonlisp"][..],
+            syntax(&[";"], &[], false, vec![quoted("\"")]),
+        ),
+        (
+            &["scheme", "racket"][..],
+            syntax(&[";"], &[("#|", "|#")], true, vec![quoted("\"")]),
+        ),
+        (&["ini"][..], syntax(&[";", "#"], &[], false, Vec::new())),
+        (
+            &["erlang", "matlab"][..],
+            syntax(&["%"], &[], false, vec![quoted("\"")]),
+        ),
+        (&["tex"][..], syntax(&["%"], &[], false, Vec::new())),
+    ]
+}
@@ -0,0 +1,532 @@
+use crate::api_types::{AgentApproval, AgentApprovalDecisionResponse, TaskUpdate};
+use crate::config::manager::ConfigManager;
+use crate::config::types::{
+    AgentApprovalAction, AgentApprovalOperation, AgentApprovalRule, ResolvedConfig,
+};
+use crate::errors::{LoTaRError, LoTaRResult};
+use crate::services::agent_job_service::AgentJobService;
+use crate::services::task_service::{TaskService, TaskUpdateContext};
+use crate::storage::manager::Storage;
+use chrono::Utc;
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+const APPROVALS_DIR: &str = "@approvals";
+const SUMMARY_COMMENT_CHARS: usize = 60;
+
+/// Ticket operation an agent asked for, checked against `agent.approvals` rules.
+#[derive(Debug, Clone)]
+pub enum AgentOperation {
+    Update(Box<TaskUpdate>),
+    Comment(String),
+    Delete,
+}
+
+/// Agent job on whose behalf an operation is requested.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentCaller {
+    pub job_id: String,
+    pub agent: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ApprovalGate {
+    /// No rule matched; apply the operation now.
+    Allow,
+    /// The operation was parked as a pending approval record.
+    Parked(Box<AgentApproval>),
+    /// A `deny` rule matched; the message explains why.
+    Denied(String),
+}
+
+pub struct AgentApprovalService;
+
+impl AgentApprovalService {
+    /// The agent job running this process, taken from the `LOTAR_AGENT_*` variables that
+    /// agent runners (and the tools they spawn) inherit. `None` for human callers.
+    pub fn current_caller() -> Option<AgentCaller> {
+        let job_id = std::env::var("LOTAR_AGENT_JOB_ID").ok()?;
+        let job_id = job_id.trim();
+        if job_id.is_empty() {
+            return None;
+        }
+        let agent = std::env::var("LOTAR_AGENT_PROFILE")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty());
+        Some(AgentCaller {
+            job_id: job_id.to_string(),
+            agent,
+        })
+    }
+
+    /// Check an operation against the configured approval rules, parking it when required.
+    pub fn gate(
+        tasks_dir: &Path,
+        ticket_id: &str,
+        operation: &AgentOperation,
+        caller: Option<&AgentCaller>,
+    ) -> LoTaRResult<ApprovalGate> {
+        let Some(caller) = caller else {
+            return Ok(ApprovalGate::Allow);
+        };
+        let config = resolve_config(tasks_dir, ticket_id)?;
+        let matched: Vec<&AgentApprovalRule> = config
+            .agent_approvals
+            .iter()
+            .filter(|rule| rule_matches(rule, operation, caller))
+            .collect();
+        if matched.is_empty() {
+            return Ok(ApprovalGate::Allow);
+        }
+
+        let summary = summarize(operation);
+        if matched
+            .iter()
+            .any(|rule| rule.action == AgentApprovalAction::Deny)
+        {
+            return Ok(ApprovalGate::Denied(format!(
+                "Agents are not allowed to {} on {}",
+                summary, ticket_id
+            )));
+        }
+
+        let mut approval = AgentApproval {
+            id: 0,
+            ticket_id: ticket_id.to_string(),
+            operation: operation_name(operation).to_string(),
+            summary,
+            status: "pending".to_string(),
+            job_id: Some(caller.job_id.clone()),
+            agent: caller.agent.clone(),
+            requested_at: Utc::now().to_rfc3339(),
+            patch: None,
+            comment: None,
+            decided_at: None,
+            decided_by: None,
+            note: None,
+            error: None,
+        };
+        match operation {
+            AgentOperation::Update(patch) => approval.patch = Some(patch.as_ref().clone()),
+            AgentOperation::Comment(text) => approval.comment = Some(text.clone()),
+            AgentOperation::Delete => {}
+        }
+        approval.id = create_record(tasks_dir, &approval)?;
+        Ok(ApprovalGate::Parked(Box::new(approval)))
+    }
+
+    /// Approval records, oldest first, optionally filtered by status and ticket.
+    pub fn list(
+        tasks_dir: &Path,
+        status: Option<&str>,
+        ticket_id: Option<&str>,
+    ) -> LoTaRResult<Vec<AgentApproval>> {
+        let dir = approvals_dir(tasks_dir);
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut approvals = Vec::new();
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if record_id(&path).is_none() {
+                continue;
+            }
+            let Ok(approval) = read_record(&path) else {
+                continue;
+            };
+            if status.is_some_and(|status| !approval.status.eq_ignore_ascii_case(status)) {
+                continue;
+            }
+            if ticket_id.is_some_and(|ticket| !approval.ticket_id.eq_ignore_ascii_case(ticket)) {
+                continue;
+            }
+            approvals.push(approval);
+        }
+        approvals.sort_by_key(|approval| approval.id);
+        Ok(approvals)
+    }
+
+    pub fn get(tasks_dir: &Path, id: u32) -> LoTaRResult<AgentApproval> {
+        let path = record_path(tasks_dir, id);
+        if !path.exists() {
+            return Err(LoTaRError::ValidationError(format!(
+                "Approval #{} not found",
+                id
+            )));
+        }
+        read_record(&path)
+    }
+
+    /// Load the record behind a changed file, if the path is an approval record.
+    pub fn from_changed_path(tasks_dir: &Path, path: &Path) -> Option<AgentApproval> {
+        if path.parent()? != approvals_dir(tasks_dir) {
+            return None;
+        }
+        record_id(path)?;
+        read_record(path).ok()
+    }
+
+    /// Apply a parked operation and mark it approved.
+    pub fn approve(
+        tasks_dir: &Path,
+        id: u32,
+        actor: Option<&str>,
+        note: Option<&str>,
+    ) -> LoTaRResult<AgentApprovalDecisionResponse> {
+        let mut approval = Self::pending(tasks_dir, id)?;
+        let mut storage = Storage::new(tasks_dir);
+        // The human decision is the approval, so the rules are not checked a second time.
+        let applied = match approval.operation.as_str() {
+            "update" => TaskService::update_with_context(
+                &mut storage,
+                &approval.ticket_id,
+                approval.patch.clone().unwrap_or_default(),
+                TaskUpdateContext {
+                    enforce_agent_approvals: false,
+                    ..TaskUpdateContext::default()
+                },
+            )
+            .map(Some),
+            "comment" => TaskService::add_comment_with_approvals(
+                &mut storage,
+                &approval.ticket_id,
+                approval.comment.as_deref().unwrap_or_default(),
+                false,
+            )
+            .map(Some),
+            "delete" => {
+                TaskService::delete_with_approvals(&mut storage, &approval.ticket_id, None, false)
+                    .map(|_| None)
+            }
+            other => Err(LoTaRError::ValidationError(format!(
+                "Unknown operation '{}'",
+                other
+            ))),
+        };
+
+        record_decision(&mut approval, "approved", actor, note);
+        match applied {
+            Ok(task) => {
+                write_record(tasks_dir, &approval)?;
+                Ok(AgentApprovalDecisionResponse {
+                    approval,
+                    task,
+                    notified: false,
+                })
+            }
+            Err(err) => {
+                approval.status = "failed".to_string();
+                approval.error = Some(err.to_string());
+                write_record(tasks_dir, &approval)?;
+                Err(LoTaRError::ValidationError(format!(
+                    "Approval #{} could not be applied: {}",
+                    id, err
+                )))
+            }
+        }
+    }
+
+    /// Discard a parked operation and tell the requesting agent job, if it is still running.
+    pub fn reject(
+        tasks_dir: &Path,
+        id: u32,
+        actor: Option<&str>,
+        note: Option<&str>,
+    ) -> LoTaRResult<AgentApprovalDecisionResponse> {
+        let mut approval = Self::pending(tasks_dir, id)?;
+        record_decision(&mut approval, "rejected", actor, note);
+        write_record(tasks_dir, &approval)?;
+
+        let notified = approval.job_id.as_deref().is_some_and(|job_id| {
+            let mut message = format!(
+                "Your request to {} on {} was rejected by {}",
+                approval.summary,
+                approval.ticket_id,
+                approval.decided_by.as_deref().unwrap_or("a reviewer")
+            );
+            if let Some(note) = approval.note.as_deref() {
+                message.push_str(": ");
+                message.push_str(note);
+            }
+            message.push_str(". Do not retry it; continue without this change.");
+            AgentJobService::send_message(job_id, &message).is_ok()
+        });
+        Ok(AgentApprovalDecisionResponse {
+            approval,
+            task: None,
+            notified,
+        })
+    }
+
+    fn pending(tasks_dir: &Path, id: u32) -> LoTaRResult<AgentApproval> {
+        let approval = Self::get(tasks_dir, id)?;
+        if approval.status != "pending" {
+            return Err(LoTaRError::ValidationError(format!(
+                "Approval #{} is already {}",
+                id, approval.status
+            )));
+        }
+        Ok(approval)
+    }
+}
+
+fn rule_matches(
+    rule: &AgentApprovalRule,
+    operation: &AgentOperation,
+    caller: &AgentCaller,
+) -> bool {
+    if !rule.agents.is_empty() {
+        let Some(agent) = caller.agent.as_deref() else {
+            return false;
+        };
+        if !rule
+            .agents
+            .iter()
+            .any(|candidate| candidate.eq_ignore_ascii_case(agent))
+        {
+            return false;
+        }
+    }
+
+    let targets = |value: &str| {
+        rule.to.is_empty()
+            || rule
+                .to
+                .iter()
+                .any(|target| normalize_target(target) == normalize_target(value))
+    };
+    match (rule.operation, operation) {
+        (AgentApprovalOperation::Status, AgentOperation::Update(patch)) => patch
+            .status
+            .as_ref()
+            .is_some_and(|status| targets(status.as_str())),
+        (AgentApprovalOperation::Assign, AgentOperation::Update(patch)) => {
+            patch.assignee.as_deref().is_some_and(targets)
+        }
+        (AgentApprovalOperation::Update, AgentOperation::Update(_))
+        | (AgentApprovalOperation::Comment, AgentOperation::Comment(_))
+        | (AgentApprovalOperation::Delete, AgentOperation::Delete) => true,
+        _ => false,
+    }
+}
+
+fn normalize_target(value: &str) -> String {
+    value.trim().trim_start_matches('@').to_lowercase()
+}
+
+fn operation_name(operation: &AgentOperation) -> &'static str {
+    match operation {
+        AgentOperation::Update(_) => "update",
+        AgentOperation::Comment(_) => "comment",
+        AgentOperation::Delete => "delete",
+    }
+}
+
+fn summarize(operation: &AgentOperation) -> String {
+    match operation {
+        AgentOperation::Update(patch) => {
+            let mut parts = Vec::new();
+            if let Some(status) = patch.status.as_ref() {
+                parts.push(format!("set status to {}", status.as_str()));
+            }
+            if let Some(assignee) = patch.assignee.as_deref() {
+                parts.push(format!("assign to {}", assignee));
+            }
+            let others: Vec<&str> = [
+                ("title", patch.title.is_some()),
+                ("priority", patch.priority.is_some()),
+                ("type", patch.task_type.is_some()),
+                ("reporter", patch.reporter.is_some()),
+                ("due date", patch.due_date.is_some()),
+                ("effort", patch.effort.is_some()),
+                ("description", patch.description.is_some()),
+                ("tags", patch.tags.is_some()),
+                ("relationships", patch.relationships.is_some()),
+                ("custom fields", patch.custom_fields.is_some()),
+                ("sprints", patch.sprints.is_some()),
+            ]
+            .into_iter()
+            .filter_map(|(name, changed)| changed.then_some(name))
+            .collect();
+            if !others.is_empty() {
+                parts.push(format!("update {}", others.join(", ")));
+            }
+            if parts.is_empty() {
+                "update the ticket".to_string()
+            } else {
+                parts.join(" and ")
+            }
+        }
+        AgentOperation::Comment(text) => {
+            let mut preview: String = text.chars().take(SUMMARY_COMMENT_CHARS).collect();
+            if text.chars().count() > SUMMARY_COMMENT_CHARS {
+                preview.push('…');
+            }
+            format!("comment \"{}\"", preview)
+        }
+        AgentOperation::Delete => "delete the ticket".to_string(),
+    }
+}
+
+fn record_decision(
+    approval: &mut AgentApproval,
+    status: &str,
+    actor: Option<&str>,
+    note: Option<&str>,
+) {
+    approval.status = status.to_string();
+    approval.decided_at = Some(Utc::now().to_rfc3339());
+    approval.decided_by = actor.map(str::to_string);
+    approval.note = note
+        .map(str::trim)
+        .filter(|note| !note.is_empty())
+        .map(str::to_string);
+}
+
+/// Approval records live in `.tasks/@approvals/<id>.yml`, next to `@sprints`.
+fn approvals_dir(tasks_dir: &Path) -> PathBuf {
+    tasks_dir.join(APPROVALS_DIR)
+}
+
+fn record_path(tasks_dir: &Path, id: u32) -> PathBuf {
+    approvals_dir(tasks_dir).join(format!("{}.yml", id))
+}
+
+fn record_id(path: &Path) -> Option<u32> {
+    if path.extension().and_then(|ext| ext.to_str()) != Some("yml") {
+        return None;
+    }
+    path.file_stem()?.to_str()?.parse().ok()
+}
+
+fn read_record(path: &Path) -> LoTaRResult<AgentApproval> {
+    let content = fs::read_to_string(path)?;
+    serde_yaml::from_str(&content).map_err(|err| {
+        LoTaRError::SerializationError(format!(
+            "Invalid approval record {}: {}",
+            path.display(),
+            err
+        ))
+    })
+}
+
+fn serialize_record(approval: &AgentApproval) -> LoTaRResult<String> {
+    serde_yaml::to_string(approval).map_err(|err| {
+        LoTaRError::SerializationError(format!("Failed to serialize approval: {}", err))
+    })
+}
+
+fn write_record(tasks_dir: &Path, approval: &AgentApproval) -> LoTaRResult<()> {
+    fs::write(
+        record_path(tasks_dir, approval.id),
+        serialize_record(approval)?,
+    )?;
+    Ok(())
+}
+
+/// Allocate the next id and write the record, retrying if another process claims it first.
+fn create_record(tasks_dir: &Path, approval: &AgentApproval) -> LoTaRResult<u32> {
+    let dir = approvals_dir(tasks_dir);
+    fs::create_dir_all(&dir)?;
+    loop {
+        let next = fs::read_dir(&dir)?
+            .filter_map(|entry| entry.ok().and_then(|entry| record_id(&entry.path())))
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let mut record = approval.clone();
+        record.id = next;
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(record_path(tasks_dir, next))
+        {
+            Ok(mut file) => {
+                file.write_all(serialize_record(&record)?.as_bytes())?;
+                return Ok(next);
+            }
+            Err(err) if err.kind() == ErrorKind::AlreadyExists => continue,
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
+
+fn resolve_config(tasks_dir: &Path, ticket_id: &str) -> LoTaRResult<ResolvedConfig> {
+    let cfg_mgr = ConfigManager::new_manager_with_tasks_dir_readonly(tasks_dir)
+        .map_err(|e| LoTaRError::ValidationError(e.to_string()))?;
+    let project_prefix = ticket_id.split('-').next().unwrap_or("");
+    Ok(if project_prefix.is_empty() {
+        cfg_mgr.get_resolved_config().clone()
+    } else {
+        cfg_mgr
+            .get_project_config(project_prefix)
+            .unwrap_or_else(|_| cfg_mgr.get_resolved_config().clone())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TaskStatus;
+
+    fn caller(agent: &str) -> AgentCaller {
+        AgentCaller {
+            job_id: "job-1".to_string(),
+            agent: Some(agent.to_string()),
+        }
+    }
+
+    fn status_update(status: &str) -> AgentOperation {
+        AgentOperation::Update(Box::new(TaskUpdate {
+            status: Some(TaskStatus::from(status)),
+            ..TaskUpdate::default()
+        }))
+    }
+
+    #[test]
+    fn status_rules_match_target_statuses_and_agents() {
+        let rule = AgentApprovalRule {
+            operation: AgentApprovalOperation::Status,
+            to: vec!["Merging".to_string()],
+            agents: vec!["claude-dev".to_string()],
+            action: AgentApprovalAction::RequireApproval,
+        };
+        assert!(rule_matches(
+            &rule,
+            &status_update("merging"),
+            &caller("claude-dev")
+        ));
+        assert!(!rule_matches(
+            &rule,
+            &status_update("Review"),
+            &caller("claude-dev")
+        ));
+        assert!(!rule_matches(
+            &rule,
+            &status_update("Merging"),
+            &caller("codex")
+        ));
+        assert!(!rule_matches(
+            &rule,
+            &AgentOperation::Comment("hi".to_string()),
+            &caller("claude-dev")
+        ));
+    }
+
+    #[test]
+    fn summaries_describe_the_requested_change() {
+        let patch = TaskUpdate {
+            status: Some(TaskStatus::from("Merging")),
+            assignee: Some("@lead".to_string()),
+            tags: Some(vec!["x".to_string()]),
+            ..TaskUpdate::default()
+        };
+        assert_eq!(
+            summarize(&AgentOperation::Update(Box::new(patch))),
+            "set status to Merging and assign to @lead and update tags"
+        );
+        assert_eq!(summarize(&AgentOperation::Delete), "delete the ticket");
+    }
+}
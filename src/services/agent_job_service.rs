@@ -1,4 +1,4 @@
-use crate::api_types::{AgentJob, AgentJobCreateRequest};
+use crate::api_types::{AgentJob, AgentJobCreateRequest, AgentJobListResponse};
 use crate::config::manager::ConfigManager;
 use crate::config::resolution::load_and_merge_configs;
 use crate::config::types::{AgentInstructionsConfig, AgentProfileDetail, ResolvedConfig};
@@ -6,14 +6,18 @@ use crate::errors::{LoTaRError, LoTaRResult};
 use crate::services::agent_context_service::{
     AgentContextService, build_assistant_message, build_user_message,
 };
-use crate::services::agent_log_service::AgentLogService;
+use crate::services::agent_log_service::{ActiveJobEntry, AgentLogService};
+use crate::services::agent_pipeline_service::{
+    AgentPipelineJobRef, AgentPipelineService, PipelineJobOutcome,
+};
 use crate::services::agent_runner::{
     AgentRunnerKind, RunnerEventKind, build_runner_command, format_stdin_message,
-    parse_runner_line, supports_stdin, validate_runner_command,
+    parse_runner_line, supports_resume, supports_stdin, validate_runner_command,
 };
 use crate::services::automation_service::{
     AutomationEvent, AutomationJobContext, AutomationService, build_lotar_env,
 };
+use crate::services::sandbox::{OutputBudget, SandboxPolicy};
 use crate::services::sprint_metrics::determine_done_statuses_from_config;
 use crate::services::task_service::TaskService;
 use crate::storage::manager::Storage;
@@ -25,17 +29,26 @@ use std::collections::{HashMap, VecDeque};
 use std::fs;
 use std::io::{BufRead, BufReader, Write};
 use std::process::{Child, ChildStdin, Command, Stdio};
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, LazyLock, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, ProcessStatus, ProcessesToUpdate, System};
 
 const EVENT_LOG_LIMIT: usize = 200;
+const WATCHDOG_POLL_INTERVAL: Duration = Duration::from_millis(250);
+const REATTACH_POLL_INTERVAL: Duration = Duration::from_secs(1);
 const DEFAULT_AGENT_INSTRUCTIONS: &str = include_str!("../../docs/help/agent-instructions.md");
 
 static JOB_COUNTER: AtomicU64 = AtomicU64::new(1);
 static ORCHESTRATOR_MODE: LazyLock<Mutex<AgentOrchestratorMode>> =
     LazyLock::new(|| Mutex::new(AgentOrchestratorMode::Standalone));
+static WORKTREE_SETUP_LOCK: Mutex<()> = Mutex::new(());
+/// This process and its start time, recorded as the owner of persisted jobs.
+static REGISTRY_OWNER: LazyLock<(u32, Option<u64>)> = LazyLock::new(|| {
+    let pid = std::process::id();
+    (pid, process_start_time(pid))
+});
 static JOB_REGISTRY: LazyLock<Mutex<JobRegistry>> = LazyLock::new(|| {
     Mutex::new(JobRegistry {
         jobs: HashMap::new(),
@@ -90,6 +103,14 @@ struct AgentJobRecord {
     worktree_path: Option<String>,
     worktree_branch: Option<String>,
     is_merge_job: bool,
+    /// 1-based attempt number; 0 until the runner first starts.
+    attempt: u32,
+    /// Pipeline stage this job executes, if it was started by a pipeline run.
+    pipeline: Option<AgentPipelineJobRef>,
+    /// Prompt the job was requested with (before instructions and ticket context).
+    prompt: String,
+    /// Runner process id while an attempt is running.
+    pid: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -125,7 +146,8 @@ struct PendingJob {
 
 struct JobRegistry {
     jobs: HashMap<String, AgentJobState>,
-    active_by_ticket: HashMap<String, String>,
+    /// Active job ids per ticket. Only pipeline fan-out runs more than one job per ticket.
+    active_by_ticket: HashMap<String, Vec<String>>,
     pending_queue: VecDeque<PendingJob>,
     running_count: usize,
     max_parallel_jobs: Option<usize>,
@@ -146,6 +168,27 @@ pub enum JobStartMode {
     Immediate,
 }
 
+/// Filters and paging for [`AgentJobService::list_job_history`].
+#[derive(Debug, Clone, Default)]
+pub struct AgentJobListFilter {
+    pub ticket_id: Option<String>,
+    pub status: Option<String>,
+    pub runner: Option<String>,
+    pub limit: usize,
+    pub offset: usize,
+}
+
+/// What [`AgentJobService::restore_jobs`] did with the persisted registry.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RestoredJobs {
+    /// Jobs whose runner process is still alive and is now tracked again
+    pub reattached: Vec<String>,
+    /// Queued jobs submitted again (old id, new id)
+    pub requeued: Vec<(String, String)>,
+    /// Jobs whose process died while no lotar process owned them
+    pub failed: Vec<String>,
+}
+
 impl AgentJobService {
     pub fn set_orchestrator_mode(mode: AgentOrchestratorMode) {
         if let Ok(mut guard) = ORCHESTRATOR_MODE.lock() {
@@ -179,190 +222,157 @@ impl AgentJobService {
         tasks_dir: &std::path::Path,
         mode: JobStartMode,
     ) -> LoTaRResult<AgentJob> {
-        let ticket_id = req.ticket_id.trim().to_string();
-        if ticket_id.is_empty() {
-            return Err(LoTaRError::ValidationError(
-                "Ticket id is required".to_string(),
-            ));
-        }
-
-        let cfg_mgr = ConfigManager::new_manager_with_tasks_dir_readonly(tasks_dir)
-            .map_err(|e| LoTaRError::ValidationError(e.to_string()))?;
-
-        let project_prefix = ticket_id.split('-').next().unwrap_or("");
-        let config = if project_prefix.is_empty() {
-            cfg_mgr.get_resolved_config().clone()
-        } else {
-            cfg_mgr
-                .get_project_config(project_prefix)
-                .unwrap_or_else(|_| cfg_mgr.get_resolved_config().clone())
-        };
-
-        let profile = resolve_profile(&config, &req)?;
-        let runner_kind = profile.runner.parse::<AgentRunnerKind>().map_err(|_| {
-            LoTaRError::ValidationError(format!(
-                "Unsupported runner '{}'. Expected copilot, claude, codex, gemini, or command",
-                profile.runner
-            ))
-        })?;
+        start_job_internal(req, tasks_dir, mode, None)
+    }
 
-        let storage = Storage::new(tasks_dir);
-        let task = TaskService::get(&storage, &ticket_id, None)?;
-        let is_merge_job = is_merge_job_candidate(&task, req.agent.as_deref());
+    /// Start a job for a pipeline stage. Jobs from the same pipeline run may share a ticket,
+    /// and the pipeline is notified when the job finishes.
+    pub(crate) fn start_pipeline_job(
+        req: AgentJobCreateRequest,
+        tasks_dir: &std::path::Path,
+        pipeline: AgentPipelineJobRef,
+    ) -> LoTaRResult<AgentJob> {
+        start_job_internal(req, tasks_dir, JobStartMode::RespectLimits, Some(pipeline))
+    }
 
-        if is_merge_job && !config.agent_worktree.enabled {
-            return Err(LoTaRError::ValidationError(
-                "Merge agent jobs require agent.worktree.enabled=true so merges run on isolated branches."
-                    .to_string(),
-            ));
+    pub fn list_jobs() -> Vec<AgentJob> {
+        let registry = JOB_REGISTRY.lock();
+        if let Ok(registry) = registry {
+            return registry
+                .jobs
+                .values()
+                .map(|state| state.record.to_dto())
+                .collect();
         }
+        Vec::new()
+    }
 
-        let context = AgentContextService::load(tasks_dir, &config, &ticket_id)?;
-        let instructions = resolve_agent_instructions(tasks_dir, &config, &profile)?;
-        let user_prompt = req.prompt.trim().to_string();
-        if user_prompt.is_empty() {
-            return Err(LoTaRError::ValidationError(
-                "Prompt cannot be empty".to_string(),
-            ));
+    /// Jobs from this process plus finished jobs from the log directory, newest first.
+    /// Historic jobs are only available when `agent_logs_dir` is configured.
+    pub fn list_job_history(
+        tasks_dir: &std::path::Path,
+        filter: &AgentJobListFilter,
+    ) -> LoTaRResult<AgentJobListResponse> {
+        let mut jobs = Self::list_jobs();
+        let config = load_and_merge_configs(Some(tasks_dir))
+            .map_err(|e| LoTaRError::ValidationError(e.to_string()))?;
+        if let Some(logs_dir) = config.agent_logs_dir.as_deref() {
+            let workspace_root = workspace_root_for(tasks_dir);
+            let active: HashMap<String, ActiveJobEntry> =
+                AgentLogService::load_active(&workspace_root, logs_dir)?
+                    .into_iter()
+                    .map(|entry| (entry.job_id.clone(), entry))
+                    .collect();
+            let known: std::collections::HashSet<String> =
+                jobs.iter().map(|job| job.id.clone()).collect();
+            for job_id in AgentLogService::list_logs(&workspace_root, logs_dir)? {
+                if known.contains(&job_id) {
+                    continue;
+                }
+                if let Some(job) =
+                    historic_job(&workspace_root, logs_dir, &job_id, active.get(&job_id))
+                {
+                    jobs.push(job);
+                }
+            }
         }
-        let prompt = build_prompt(
-            &task,
-            &user_prompt,
-            context.as_ref(),
-            instructions.as_deref(),
-        );
 
-        let job_id = make_job_id();
-        let created_at = Utc::now().to_rfc3339();
-        let tasks_dir_buf = tasks_dir.to_path_buf();
+        jobs.retain(|job| {
+            filter
+                .ticket_id
+                .as_deref()
+                .is_none_or(|ticket| job.ticket_id.eq_ignore_ascii_case(ticket))
+                && filter
+                    .status
+                    .as_deref()
+                    .is_none_or(|status| job.status.eq_ignore_ascii_case(status))
+                && filter
+                    .runner
+                    .as_deref()
+                    .is_none_or(|runner| job.runner.eq_ignore_ascii_case(runner))
+        });
+        jobs.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.id.cmp(&a.id)));
+
+        let total = jobs.len();
+        let jobs = jobs
+            .into_iter()
+            .skip(filter.offset)
+            .take(filter.limit)
+            .collect();
+        Ok(AgentJobListResponse {
+            jobs,
+            queue_stats: Some(Self::queue_stats()),
+            total: Some(total),
+            limit: Some(filter.limit),
+            offset: Some(filter.offset),
+        })
+    }
 
-        let should_queue: bool;
-        {
-            let mut registry = JOB_REGISTRY
-                .lock()
-                .map_err(|_| LoTaRError::ValidationError("Job registry unavailable".to_string()))?;
+    /// Pick up jobs persisted by a lotar process that is no longer running.
+    ///
+    /// Runner processes that are still alive are tracked again (their output was lost with
+    /// the old process, so only their exit is observed), queued jobs are submitted again,
+    /// and running jobs whose process has died are marked failed.
+    pub fn restore_jobs(tasks_dir: &std::path::Path) -> LoTaRResult<RestoredJobs> {
+        let mut restored = RestoredJobs::default();
+        let config = load_and_merge_configs(Some(tasks_dir))
+            .map_err(|e| LoTaRError::ValidationError(e.to_string()))?;
+        let Some(logs_dir) = config.agent_logs_dir.clone() else {
+            return Ok(restored);
+        };
+        let workspace_root = workspace_root_for(tasks_dir);
+        let (owner_pid, _) = *REGISTRY_OWNER;
 
-            if registry.active_by_ticket.contains_key(&ticket_id) {
-                return Err(LoTaRError::ValidationError(format!(
-                    "Ticket '{}' already has an active job",
-                    ticket_id
-                )));
+        for entry in AgentLogService::load_active(&workspace_root, &logs_dir)? {
+            if get_job_dto(&entry.job_id).is_some() {
+                continue;
+            }
+            // Another lotar process still owns this job.
+            if entry.owner_pid != owner_pid
+                && process_alive(entry.owner_pid, entry.owner_started_at)
+            {
+                continue;
             }
 
-            // Update max_parallel_jobs from config (allows runtime changes)
-            registry.max_parallel_jobs = config.agent_worktree.max_parallel_jobs;
-
-            let parallel_limit_reached = registry
-                .max_parallel_jobs
-                .is_some_and(|max| registry.running_count >= max);
-            let merge_slot_busy = is_merge_job && has_active_merge_job(&registry);
-
-            should_queue = matches!(mode, JobStartMode::RespectLimits)
-                && (parallel_limit_reached || merge_slot_busy);
-
-            // Compute workspace root (parent of tasks_dir)
-            let workspace_root = tasks_dir_buf
-                .parent()
-                .map(|p| p.to_path_buf())
-                .unwrap_or_else(|| tasks_dir_buf.clone());
-
-            registry
-                .active_by_ticket
-                .insert(ticket_id.clone(), job_id.clone());
-            registry.jobs.insert(
-                job_id.clone(),
-                AgentJobState {
-                    record: AgentJobRecord {
-                        id: job_id.clone(),
-                        ticket_id: ticket_id.clone(),
-                        tasks_dir: tasks_dir_buf.clone(),
-                        workspace_root,
-                        agent_logs_dir: config.agent_logs_dir.clone(),
-                        runner: runner_kind.as_str().to_string(),
-                        runner_kind,
-                        agent_profile: req.agent.clone(),
-                        status: AgentJobStatus::Queued,
-                        created_at: created_at.clone(),
-                        started_at: None,
-                        finished_at: None,
-                        exit_code: None,
-                        last_message: None,
-                        summary: None,
-                        session_id: None,
-                        worktree_path: None,
-                        worktree_branch: None,
-                        is_merge_job,
-                    },
-                    events: Vec::new(),
-                    runtime: None,
-                },
-            );
+            let runner_alive = entry.status == AgentJobStatus::Running.as_str()
+                && entry
+                    .pid
+                    .is_some_and(|pid| process_alive(pid, entry.pid_started_at));
+            if runner_alive {
+                restored.reattached.push(entry.job_id.clone());
+                reattach_job(entry, &workspace_root, &logs_dir);
+                continue;
+            }
 
-            if should_queue {
-                // Add to pending queue - will be started when a slot opens
-                registry.pending_queue.push_back(PendingJob {
-                    job_id: job_id.clone(),
-                    runner_kind,
-                    profile: profile.clone(),
-                    tasks_dir: tasks_dir_buf.clone(),
-                    ticket_id: ticket_id.clone(),
-                    prompt: prompt.clone(),
-                    user_prompt: user_prompt.clone(),
-                    config: config.clone(),
-                    is_merge_job,
-                });
-            } else {
-                // Slot available - increment running count
-                registry.running_count += 1;
+            let _ = AgentLogService::remove_active(&workspace_root, Some(&logs_dir), &entry.job_id);
+            if entry.status == AgentJobStatus::Queued.as_str() && !entry.pipeline {
+                let req = AgentJobCreateRequest {
+                    ticket_id: entry.ticket_id.clone(),
+                    prompt: entry.prompt.clone(),
+                    runner: Some(entry.runner.clone()),
+                    agent: entry.agent.clone(),
+                };
+                match start_job_internal(req, &entry.tasks_dir, JobStartMode::RespectLimits, None) {
+                    Ok(job) => restored.requeued.push((entry.job_id, job.id)),
+                    Err(err) => {
+                        let message = format!("Could not requeue job after restart: {}", err);
+                        fail_orphaned_job(&entry, &workspace_root, &logs_dir, &message);
+                        restored.failed.push(entry.job_id);
+                    }
+                }
+                continue;
             }
-        }
 
-        if !should_queue {
-            // Start job immediately
-            let job_id_clone = job_id.clone();
-            let user_prompt_clone = user_prompt.clone();
-            thread::spawn(move || {
-                run_job(
-                    job_id_clone,
-                    runner_kind,
-                    profile,
-                    tasks_dir_buf,
-                    ticket_id,
-                    prompt,
-                    user_prompt_clone,
-                    config,
-                );
-            });
+            let message = if entry.status == AgentJobStatus::Queued.as_str() {
+                "Queued pipeline stage was lost when lotar stopped"
+            } else {
+                "Agent process exited while lotar was not running"
+            };
+            fail_orphaned_job(&entry, &workspace_root, &logs_dir, message);
+            restored.failed.push(entry.job_id);
         }
 
-        Ok(get_job_dto(&job_id).unwrap_or_else(|| AgentJob {
-            id: job_id,
-            ticket_id: req.ticket_id,
-            runner: runner_kind.as_str().to_string(),
-            agent: req.agent,
-            status: AgentJobStatus::Queued.as_str().to_string(),
-            created_at: created_at.clone(),
-            started_at: None,
-            finished_at: None,
-            exit_code: None,
-            last_message: None,
-            summary: None,
-            session_id: None,
-            worktree_path: None,
-            worktree_branch: None,
-        }))
-    }
-
-    pub fn list_jobs() -> Vec<AgentJob> {
-        let registry = JOB_REGISTRY.lock();
-        if let Ok(registry) = registry {
-            return registry
-                .jobs
-                .values()
-                .map(|state| state.record.to_dto())
-                .collect();
-        }
-        Vec::new()
+        Ok(restored)
     }
 
     /// Get queue statistics
@@ -404,10 +414,15 @@ impl AgentJobService {
             };
             let ticket_id = state.record.ticket_id.clone();
             let tasks_dir = state.record.tasks_dir.clone();
-            if let Some(runtime) = state.runtime.as_ref()
-                && let Ok(mut child) = runtime.child.lock()
+            if let Some(runtime) = state.runtime.as_ref() {
+                if let Ok(mut child) = runtime.child.lock() {
+                    terminate_child(&mut child);
+                }
+            } else if state.record.status == AgentJobStatus::Running
+                && let Some(pid) = state.record.pid
             {
-                terminate_child(&mut child);
+                // Reattached after a restart: there is no child handle, only the pid.
+                terminate_pid(pid);
             }
             let mut cancelled = false;
             if state.record.status != AgentJobStatus::Cancelled {
@@ -415,13 +430,14 @@ impl AgentJobService {
                 state.record.finished_at = Some(Utc::now().to_rfc3339());
                 push_event(state, "agent_job_cancelled", None);
                 emit_job_event("agent_job_cancelled", &state.record, None);
+                forget_active(&state.record);
                 cancelled = true;
             }
             let job = state.record.to_dto_with_cancelled(cancelled);
             (job, ticket_id, cancelled, tasks_dir, was_pending)
         };
         if cancelled {
-            registry.active_by_ticket.remove(&ticket_id);
+            release_ticket(&mut registry, &ticket_id, id);
             // Remove from pending queue if it was queued
             registry.pending_queue.retain(|p| p.job_id != id);
             // Decrement running count if the job was occupying a running slot
@@ -448,6 +464,7 @@ impl AgentJobService {
                     JobOutcome::Cancelled,
                 );
             }
+            notify_pipeline(id, PipelineJobOutcome::Cancelled, None);
             process_pending_queue();
         }
         Ok(Some(job))
@@ -562,6 +579,205 @@ impl AgentJobService {
     }
 }
 
+fn start_job_internal(
+    req: AgentJobCreateRequest,
+    tasks_dir: &std::path::Path,
+    mode: JobStartMode,
+    pipeline: Option<AgentPipelineJobRef>,
+) -> LoTaRResult<AgentJob> {
+    let ticket_id = req.ticket_id.trim().to_string();
+    if ticket_id.is_empty() {
+        return Err(LoTaRError::ValidationError(
+            "Ticket id is required".to_string(),
+        ));
+    }
+
+    let config = config_for_ticket(tasks_dir, &ticket_id)?;
+
+    let profile = resolve_profile(&config, &req)?;
+    RetryPolicy::from_profile(&profile)?;
+    if let Some(sandbox) = profile.sandbox.as_ref() {
+        SandboxPolicy::from_config(sandbox)?;
+    }
+    let runner_kind = profile.runner.parse::<AgentRunnerKind>().map_err(|_| {
+        LoTaRError::ValidationError(format!(
+            "Unsupported runner '{}'. Expected copilot, claude, codex, gemini, or command",
+            profile.runner
+        ))
+    })?;
+
+    let storage = Storage::new(tasks_dir);
+    let task = TaskService::get(&storage, &ticket_id, None)?;
+    let is_merge_job = is_merge_job_candidate(&task, req.agent.as_deref());
+
+    if is_merge_job && !config.agent_worktree.enabled {
+        return Err(LoTaRError::ValidationError(
+            "Merge agent jobs require agent.worktree.enabled=true so merges run on isolated branches."
+                .to_string(),
+        ));
+    }
+
+    let context = AgentContextService::load(tasks_dir, &config, &ticket_id)?;
+    let instructions = resolve_agent_instructions(tasks_dir, &config, &profile)?;
+    let user_prompt = req.prompt.trim().to_string();
+    if user_prompt.is_empty() {
+        return Err(LoTaRError::ValidationError(
+            "Prompt cannot be empty".to_string(),
+        ));
+    }
+    let prompt = build_prompt(
+        &task,
+        &user_prompt,
+        context.as_ref(),
+        instructions.as_deref(),
+    );
+
+    let job_id = make_job_id();
+    let created_at = Utc::now().to_rfc3339();
+    let tasks_dir_buf = tasks_dir.to_path_buf();
+
+    let should_queue: bool;
+    {
+        let mut registry = JOB_REGISTRY
+            .lock()
+            .map_err(|_| LoTaRError::ValidationError("Job registry unavailable".to_string()))?;
+
+        let shares_pipeline_run = |job_id: &String| {
+            pipeline.as_ref().is_some_and(|stage| {
+                registry.jobs.get(job_id).is_some_and(|state| {
+                    state
+                        .record
+                        .pipeline
+                        .as_ref()
+                        .is_some_and(|other| other.run_id == stage.run_id)
+                })
+            })
+        };
+        if registry
+            .active_by_ticket
+            .get(&ticket_id)
+            .is_some_and(|active| !active.iter().all(shares_pipeline_run))
+        {
+            return Err(LoTaRError::ValidationError(format!(
+                "Ticket '{}' already has an active job",
+                ticket_id
+            )));
+        }
+
+        // Update max_parallel_jobs from config (allows runtime changes)
+        registry.max_parallel_jobs = config.agent_worktree.max_parallel_jobs;
+
+        let parallel_limit_reached = registry
+            .max_parallel_jobs
+            .is_some_and(|max| registry.running_count >= max);
+        let merge_slot_busy = is_merge_job && has_active_merge_job(&registry);
+
+        should_queue = matches!(mode, JobStartMode::RespectLimits)
+            && (parallel_limit_reached || merge_slot_busy);
+
+        // Compute workspace root (parent of tasks_dir)
+        let workspace_root = tasks_dir_buf
+            .parent()
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| tasks_dir_buf.clone());
+
+        registry
+            .active_by_ticket
+            .entry(ticket_id.clone())
+            .or_default()
+            .push(job_id.clone());
+        registry.jobs.insert(
+            job_id.clone(),
+            AgentJobState {
+                record: AgentJobRecord {
+                    id: job_id.clone(),
+                    ticket_id: ticket_id.clone(),
+                    tasks_dir: tasks_dir_buf.clone(),
+                    workspace_root,
+                    agent_logs_dir: config.agent_logs_dir.clone(),
+                    runner: runner_kind.as_str().to_string(),
+                    runner_kind,
+                    agent_profile: req.agent.clone(),
+                    status: AgentJobStatus::Queued,
+                    created_at: created_at.clone(),
+                    started_at: None,
+                    finished_at: None,
+                    exit_code: None,
+                    last_message: None,
+                    summary: None,
+                    session_id: None,
+                    worktree_path: None,
+                    worktree_branch: None,
+                    is_merge_job,
+                    attempt: 0,
+                    pipeline,
+                    prompt: user_prompt.clone(),
+                    pid: None,
+                },
+                events: Vec::new(),
+                runtime: None,
+            },
+        );
+        if let Some(state) = registry.jobs.get(&job_id) {
+            persist_active(&state.record);
+        }
+
+        if should_queue {
+            // Add to pending queue - will be started when a slot opens
+            registry.pending_queue.push_back(PendingJob {
+                job_id: job_id.clone(),
+                runner_kind,
+                profile: profile.clone(),
+                tasks_dir: tasks_dir_buf.clone(),
+                ticket_id: ticket_id.clone(),
+                prompt: prompt.clone(),
+                user_prompt: user_prompt.clone(),
+                config: config.clone(),
+                is_merge_job,
+            });
+        } else {
+            // Slot available - increment running count
+            registry.running_count += 1;
+        }
+    }
+
+    if !should_queue {
+        // Start job immediately
+        let job_id_clone = job_id.clone();
+        let user_prompt_clone = user_prompt.clone();
+        thread::spawn(move || {
+            run_job(
+                job_id_clone,
+                runner_kind,
+                profile,
+                tasks_dir_buf,
+                ticket_id,
+                prompt,
+                user_prompt_clone,
+                config,
+            );
+        });
+    }
+
+    Ok(get_job_dto(&job_id).unwrap_or_else(|| AgentJob {
+        id: job_id,
+        ticket_id: req.ticket_id,
+        runner: runner_kind.as_str().to_string(),
+        agent: req.agent,
+        status: AgentJobStatus::Queued.as_str().to_string(),
+        created_at: created_at.clone(),
+        started_at: None,
+        finished_at: None,
+        exit_code: None,
+        last_message: None,
+        summary: None,
+        session_id: None,
+        worktree_path: None,
+        worktree_branch: None,
+        attempt: None,
+    }))
+}
+
 impl AgentJobRecord {
     fn to_dto(&self) -> AgentJob {
         AgentJob {
@@ -579,6 +795,7 @@ impl AgentJobRecord {
             session_id: self.session_id.clone(),
             worktree_path: self.worktree_path.clone(),
             worktree_branch: self.worktree_branch.clone(),
+            attempt: (self.attempt > 0).then_some(self.attempt),
         }
     }
 
@@ -722,6 +939,11 @@ fn resolve_profile(
             tools: None,
             mcp: None,
             instructions: None,
+            timeout: None,
+            idle_timeout: None,
+            max_retries: None,
+            retry_backoff: None,
+            sandbox: None,
         });
     }
 
@@ -1117,6 +1339,131 @@ fn maybe_cleanup_worktree(
     }
 }
 
+/// Timeout and retry settings resolved from an agent profile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct RetryPolicy {
+    timeout: Option<Duration>,
+    idle_timeout: Option<Duration>,
+    max_retries: u32,
+    backoff: Duration,
+}
+
+impl RetryPolicy {
+    fn from_profile(profile: &AgentProfileDetail) -> LoTaRResult<Self> {
+        Ok(Self {
+            timeout: parse_profile_interval("timeout", profile.timeout.as_deref())?,
+            idle_timeout: parse_profile_interval("idle_timeout", profile.idle_timeout.as_deref())?,
+            max_retries: profile.max_retries.unwrap_or(0),
+            backoff: parse_profile_interval("retry_backoff", profile.retry_backoff.as_deref())?
+                .unwrap_or(Duration::ZERO),
+        })
+    }
+
+    /// Delay before the given retry (1-based); doubles per retry, capped at one hour.
+    fn backoff_for(&self, retry: u32) -> Duration {
+        let factor = 1u32 << retry.saturating_sub(1).min(16);
+        self.backoff
+            .saturating_mul(factor)
+            .min(Duration::from_secs(3600))
+    }
+
+    fn has_watchdog(&self) -> bool {
+        self.timeout.is_some() || self.idle_timeout.is_some()
+    }
+}
+
+fn parse_profile_interval(field: &str, raw: Option<&str>) -> LoTaRResult<Option<Duration>> {
+    let Some(raw) = raw.map(str::trim).filter(|value| !value.is_empty()) else {
+        return Ok(None);
+    };
+    crate::utils::time::parse_interval(raw)
+        .map(Some)
+        .ok_or_else(|| {
+            LoTaRError::ValidationError(format!(
+                "Invalid agent {} '{}'. Use a duration like 30s, 10m, or 2h",
+                field, raw
+            ))
+        })
+}
+
+/// Why the watchdog terminated a runner attempt.
+#[derive(Debug, Clone, Copy)]
+enum WatchdogTrip {
+    Timeout(Duration),
+    Idle(Duration),
+}
+
+/// How a single runner attempt ended.
+#[derive(Debug, Clone)]
+enum AttemptOutcome {
+    Exited(Option<i32>),
+    Killed(WatchdogTrip),
+    /// The runner broke a sandbox limit; these attempts are not retried.
+    SandboxViolation(String),
+    Cancelled,
+}
+
+impl AttemptOutcome {
+    fn exit_code(&self) -> Option<i32> {
+        match self {
+            Self::Exited(code) => *code,
+            _ => None,
+        }
+    }
+
+    fn failure_reason(&self) -> Option<String> {
+        match self {
+            Self::Exited(Some(0)) | Self::Cancelled => None,
+            Self::Exited(Some(code)) => Some(format!("runner exited with code {}", code)),
+            Self::Exited(None) => Some("runner was terminated by a signal".to_string()),
+            Self::Killed(WatchdogTrip::Timeout(limit)) => Some(format!(
+                "runner timed out after {}",
+                format_interval(*limit)
+            )),
+            Self::Killed(WatchdogTrip::Idle(limit)) => Some(format!(
+                "runner produced no events for {}",
+                format_interval(*limit)
+            )),
+            Self::SandboxViolation(reason) => Some(format!("Sandbox: {}", reason)),
+        }
+    }
+}
+
+fn format_interval(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 3600 && secs.is_multiple_of(3600) {
+        format!("{}h", secs / 3600)
+    } else if secs >= 60 && secs.is_multiple_of(60) {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// Result of launching one attempt of a job.
+enum AttemptResult {
+    /// The runner could not be started; these errors are not retried.
+    SetupFailed(String),
+    Finished {
+        outcome: AttemptOutcome,
+        messages: Vec<crate::services::agent_context_service::AgentContextMessage>,
+    },
+}
+
+/// Everything needed to launch one runner attempt.
+struct AttemptSpec<'a> {
+    job_id: &'a str,
+    runner_kind: AgentRunnerKind,
+    profile: &'a AgentProfileDetail,
+    tasks_dir: &'a std::path::Path,
+    ticket_id: &'a str,
+    config: &'a ResolvedConfig,
+    working_dir: &'a std::path::Path,
+    worktree_path: Option<&'a std::path::Path>,
+    policy: RetryPolicy,
+    sandbox: Option<&'a SandboxPolicy>,
+}
+
 #[allow(clippy::too_many_arguments, clippy::needless_pass_by_value)]
 fn run_job(
     job_id: String,
@@ -1128,7 +1475,12 @@ fn run_job(
     user_prompt: String,
     config: ResolvedConfig,
 ) {
-    let worktree_context = match prepare_worktree(&job_id, &tasks_dir, &ticket_id, &config) {
+    // Parallel pipeline stages share the ticket's worktree; create it only once.
+    let setup = {
+        let _guard = WORKTREE_SETUP_LOCK.lock();
+        prepare_worktree(&job_id, &tasks_dir, &ticket_id, &config)
+    };
+    let worktree_context = match setup {
         Ok(context) => {
             update_job(&job_id, |state| {
                 state.record.worktree_path = context
@@ -1192,6 +1544,7 @@ fn run_job(
         }
         state.record.status = AgentJobStatus::Running;
         state.record.started_at = Some(started_at.clone());
+        persist_active(&state.record);
         push_event(state, "agent_job_started", None);
         emit_job_event("agent_job_started", &state.record, None);
     });
@@ -1207,45 +1560,185 @@ fn run_job(
         job_context_for(&job_id),
     );
 
-    let command = match build_runner_command(runner_kind, &profile, &prompt) {
-        Ok(cmd) => cmd,
-        Err(err) => {
-            update_job_failure(&job_id, &ticket_id, err.to_string(), &tasks_dir);
-            let _ = AutomationService::apply_job_event(
-                tasks_dir.as_path(),
+    // Durations were validated when the job was created.
+    let policy = RetryPolicy::from_profile(&profile).unwrap_or_default();
+    let sandbox = profile
+        .sandbox
+        .as_ref()
+        .and_then(|sandbox| SandboxPolicy::from_config(sandbox).ok());
+    let spec = AttemptSpec {
+        job_id: &job_id,
+        runner_kind,
+        profile: &profile,
+        tasks_dir: &tasks_dir,
+        ticket_id: &ticket_id,
+        config: &config,
+        working_dir: &worktree_context.working_dir,
+        worktree_path: worktree_context.worktree_path.as_deref(),
+        policy,
+        sandbox: sandbox.as_ref(),
+    };
+
+    let mut attempt: u32 = 1;
+    let mut attempt_prompt = prompt;
+    let mut resume_session: Option<String> = None;
+    loop {
+        update_job(&job_id, |state| {
+            state.record.attempt = attempt;
+        });
+
+        let seed_messages = if attempt == 1 {
+            vec![build_user_message(&user_prompt)]
+        } else {
+            Vec::new()
+        };
+        let result = run_attempt(
+            &spec,
+            attempt,
+            &attempt_prompt,
+            resume_session.as_deref(),
+            seed_messages,
+        );
+
+        let (outcome, messages) = match result {
+            AttemptResult::SetupFailed(message) => {
+                update_job_failure(&job_id, &ticket_id, message, &tasks_dir);
+                let _ = AutomationService::apply_job_event(
+                    tasks_dir.as_path(),
+                    &ticket_id,
+                    AutomationEvent::JobFailed,
+                    job_context_for(&job_id),
+                );
+                return;
+            }
+            AttemptResult::Finished { outcome, messages } => (outcome, messages),
+        };
+
+        if matches!(outcome, AttemptOutcome::Cancelled) || should_stop_job(&job_id) {
+            return;
+        }
+
+        let failure_reason = outcome.failure_reason();
+        let retryable = !matches!(outcome, AttemptOutcome::SandboxViolation(_));
+        let Some(reason) = failure_reason
+            .clone()
+            .filter(|_| retryable && attempt <= policy.max_retries)
+        else {
+            finalize_job(
+                &job_id,
                 &ticket_id,
-                AutomationEvent::JobFailed,
-                job_context_for(&job_id),
+                outcome.exit_code(),
+                failure_reason,
+                &messages,
+                &tasks_dir,
+                &config,
+            );
+            return;
+        };
+
+        // Persist what this attempt said so the next one can pick up from it.
+        let _ =
+            AgentContextService::append_messages(&tasks_dir, &config, &ticket_id, messages, None);
+
+        let delay = policy.backoff_for(attempt);
+        let retry_message = format!(
+            "Attempt {} of {} failed: {}; retrying in {}",
+            attempt,
+            policy.max_retries + 1,
+            reason,
+            format_interval(delay)
+        );
+        update_job(&job_id, |state| {
+            state.record.last_message = Some(retry_message.clone());
+            push_event(state, "agent_job_retry", Some(retry_message.clone()));
+            emit_job_event(
+                "agent_job_retry",
+                &state.record,
+                Some(retry_message.clone()),
             );
+        });
+
+        if !sleep_unless_cancelled(&job_id, delay) {
             return;
         }
+
+        attempt += 1;
+        let session_id = get_job_dto(&job_id).and_then(|job| job.session_id);
+        match session_id.filter(|_| supports_resume(runner_kind)) {
+            Some(session_id) => {
+                attempt_prompt = build_resume_prompt(&reason);
+                let msg = format!("Resuming session {}", session_id);
+                update_job(&job_id, |state| {
+                    push_event(state, "agent_job_progress", Some(msg.clone()));
+                });
+                resume_session = Some(session_id);
+            }
+            None => {
+                attempt_prompt = rebuild_prompt_for_retry(
+                    &tasks_dir,
+                    &config,
+                    &profile,
+                    &ticket_id,
+                    &user_prompt,
+                    &reason,
+                )
+                .unwrap_or(attempt_prompt);
+                resume_session = None;
+            }
+        }
+    }
+}
+
+/// Launch the runner once and block until it exits, is cancelled, or is killed by the watchdog.
+fn run_attempt(
+    spec: &AttemptSpec<'_>,
+    attempt: u32,
+    prompt: &str,
+    resume_session: Option<&str>,
+    mut context_messages: Vec<crate::services::agent_context_service::AgentContextMessage>,
+) -> AttemptResult {
+    let job_id = spec.job_id;
+    let runner_kind = spec.runner_kind;
+
+    let command = match build_runner_command(runner_kind, spec.profile, prompt, resume_session) {
+        Ok(cmd) => cmd,
+        Err(err) => return AttemptResult::SetupFailed(err.to_string()),
     };
 
-    let command = wrap_runner_command(command, &job_id, &ticket_id, runner_kind.as_str());
+    let command = wrap_runner_command(command, job_id, spec.ticket_id, runner_kind.as_str());
 
     if let Err(err) = validate_runner_command(&command) {
-        update_job_failure(&job_id, &ticket_id, err.to_string(), &tasks_dir);
-        let _ = AutomationService::apply_job_event(
-            tasks_dir.as_path(),
-            &ticket_id,
-            AutomationEvent::JobFailed,
-            job_context_for(&job_id),
-        );
-        return;
+        return AttemptResult::SetupFailed(err.to_string());
     }
 
     // Inject LOTAR_* environment variables so the agent runner process has
     // full context about the task it is working on.
     let mut command = command;
-    if let Some(storage) = Storage::try_open(&tasks_dir.clone())
-        && let Ok(task) = TaskService::get(&storage, &ticket_id, None)
+    if let Some(storage) = Storage::try_open(spec.tasks_dir)
+        && let Ok(task) = TaskService::get(&storage, spec.ticket_id, None)
     {
-        let job_ctx = job_context_for(&job_id);
-        let lotar_env = build_lotar_env(&task, &config, &tasks_dir, None, job_ctx.as_ref());
+        let job_ctx = job_context_for(job_id);
+        let lotar_env = build_lotar_env(&task, spec.config, spec.tasks_dir, None, job_ctx.as_ref());
         command.env.extend(lotar_env);
     }
+    command
+        .env
+        .insert("LOTAR_AGENT_ATTEMPT".to_string(), attempt.to_string());
+    if let Some(session_id) = resume_session {
+        command.env.insert(
+            "LOTAR_AGENT_RESUME_SESSION".to_string(),
+            session_id.to_string(),
+        );
+    }
 
     let mut cmd = Command::new(&command.program);
+    if let Some(sandbox) = spec.sandbox
+        && let Err(reason) = sandbox
+            .check_working_dir(spec.working_dir, spec.worktree_path)
+            .and_then(|()| sandbox.apply(&mut cmd))
+    {
+        return AttemptResult::SetupFailed(format!("Sandbox: {}", reason));
+    }
     cmd.args(&command.args)
         .envs(command.env)
         // Use null stdin - Claude CLI waits indefinitely when stdin is a pipe
@@ -1258,7 +1751,7 @@ fn run_job(
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
 
-    cmd.current_dir(&worktree_context.working_dir);
+    cmd.current_dir(spec.working_dir);
 
     #[cfg(unix)]
     {
@@ -1276,19 +1769,11 @@ fn run_job(
     let child = match cmd.spawn() {
         Ok(child) => child,
         Err(err) => {
-            update_job_failure(
-                &job_id,
-                &ticket_id,
-                format!("Failed to spawn runner: {}", err),
-                &tasks_dir,
-            );
-            let _ = AutomationService::apply_job_event(
-                tasks_dir.as_path(),
-                &ticket_id,
-                AutomationEvent::JobFailed,
-                job_context_for(&job_id),
-            );
-            return;
+            let message = match spec.sandbox.and_then(|s| s.describe_spawn_error(&err)) {
+                Some(reason) => format!("Sandbox: {}", reason),
+                None => format!("Failed to spawn runner: {}", err),
+            };
+            return AttemptResult::SetupFailed(message);
         }
     };
 
@@ -1298,17 +1783,36 @@ fn run_job(
     let stdin = child.lock().ok().and_then(|mut c| c.stdin.take());
     let stdin = stdin.map(|handle| Arc::new(Mutex::new(handle)));
 
-    update_job(&job_id, |state| {
+    let pid = child.lock().ok().map(|c| c.id());
+    update_job(job_id, |state| {
         state.runtime = Some(AgentJobRuntime {
             child: Arc::clone(&child),
             stdin: stdin.clone(),
         });
+        state.record.pid = pid;
+        persist_active(&state.record);
     });
 
-    let mut context_messages = vec![build_user_message(&user_prompt)];
+    let activity = Arc::new(Mutex::new(Instant::now()));
+    let finished = Arc::new(AtomicBool::new(false));
+    let budget = spec
+        .sandbox
+        .and_then(SandboxPolicy::max_output)
+        .map(|limit| Arc::new(OutputBudget::new(limit)));
+    let over_budget = Arc::new(AtomicBool::new(false));
+    let trip = spawn_watchdog(
+        spec.policy,
+        Arc::clone(&child),
+        Arc::clone(&activity),
+        Arc::clone(&finished),
+    );
 
     if let Some(stderr) = stderr {
-        let job_id_clone = job_id.clone();
+        let job_id_clone = job_id.to_string();
+        let activity = Arc::clone(&activity);
+        let budget = budget.clone();
+        let over_budget = Arc::clone(&over_budget);
+        let child_clone = Arc::clone(&child);
         thread::spawn(move || {
             let mut reader = BufReader::new(stderr);
             let mut line = String::new();
@@ -1316,7 +1820,15 @@ fn run_job(
                 line.clear();
                 match reader.read_line(&mut line) {
                     Ok(0) => break,
-                    Ok(_) => {
+                    Ok(read) => {
+                        touch_activity(&activity);
+                        if budget.as_ref().is_some_and(|b| !b.consume(read)) {
+                            over_budget.store(true, Ordering::Relaxed);
+                            if let Ok(mut c) = child_clone.lock() {
+                                terminate_child(&mut c);
+                            }
+                            break;
+                        }
                         let msg = line.trim().to_string();
                         if !msg.is_empty() {
                             update_job(&job_id_clone, |state| {
@@ -1337,7 +1849,6 @@ fn run_job(
     }
 
     if let Some(stdout) = stdout {
-        let job_id_clone = job_id.clone();
         let child_clone = Arc::clone(&child);
         let mut reader = BufReader::new(stdout);
         let mut line = String::new();
@@ -1345,12 +1856,20 @@ fn run_job(
             line.clear();
             match reader.read_line(&mut line) {
                 Ok(0) => break,
-                Ok(_) => {
-                    handle_runner_line(&job_id_clone, runner_kind, &line, &mut context_messages);
+                Ok(read) => {
+                    touch_activity(&activity);
+                    if budget.as_ref().is_some_and(|b| !b.consume(read)) {
+                        over_budget.store(true, Ordering::Relaxed);
+                        if let Ok(mut c) = child_clone.lock() {
+                            terminate_child(&mut c);
+                        }
+                        break;
+                    }
+                    handle_runner_line(job_id, runner_kind, &line, &mut context_messages);
                 }
                 Err(_) => break,
             }
-            if should_stop_job(&job_id_clone) {
+            if should_stop_job(job_id) {
                 if let Ok(mut c) = child_clone.lock() {
                     terminate_child(&mut c);
                 }
@@ -1359,33 +1878,140 @@ fn run_job(
         }
     }
 
-    loop {
+    let outcome = loop {
         let status = child
             .lock()
             .ok()
             .and_then(|mut c| c.try_wait().ok().flatten());
         if let Some(status) = status {
-            let code = status.code();
-            finalize_job(
-                &job_id,
-                &ticket_id,
-                code,
-                &context_messages,
-                &tasks_dir,
-                &config,
-            );
-            break;
+            let tripped = trip
+                .as_ref()
+                .and_then(|trip| trip.lock().ok().and_then(|t| *t));
+            let violation = match budget.as_ref() {
+                Some(budget) if over_budget.load(Ordering::Relaxed) => Some(budget.violation()),
+                _ => spec.sandbox.and_then(|s| s.describe_exit(&status)),
+            };
+            break match (tripped, violation) {
+                (Some(reason), _) => AttemptOutcome::Killed(reason),
+                (None, Some(reason)) => AttemptOutcome::SandboxViolation(reason),
+                (None, None) => AttemptOutcome::Exited(status.code()),
+            };
         }
-        if should_stop_job(&job_id) {
+        if should_stop_job(job_id) {
             if let Ok(mut c) = child.lock() {
                 terminate_child(&mut c);
             }
-            break;
+            break AttemptOutcome::Cancelled;
         }
         thread::sleep(Duration::from_millis(200));
+    };
+    finished.store(true, Ordering::Relaxed);
+
+    update_job(job_id, |state| {
+        state.runtime = None;
+        state.record.pid = None;
+    });
+
+    AttemptResult::Finished {
+        outcome,
+        messages: context_messages,
+    }
+}
+
+fn touch_activity(activity: &Mutex<Instant>) {
+    if let Ok(mut last) = activity.lock() {
+        *last = Instant::now();
     }
 }
 
+/// Watch a running attempt and terminate it when it exceeds the profile's
+/// `timeout` or stays silent for longer than `idle_timeout`.
+fn spawn_watchdog(
+    policy: RetryPolicy,
+    child: Arc<Mutex<Child>>,
+    activity: Arc<Mutex<Instant>>,
+    finished: Arc<AtomicBool>,
+) -> Option<Arc<Mutex<Option<WatchdogTrip>>>> {
+    if !policy.has_watchdog() {
+        return None;
+    }
+    let trip = Arc::new(Mutex::new(None));
+    let trip_clone = Arc::clone(&trip);
+    let started = Instant::now();
+    thread::spawn(move || {
+        while !finished.load(Ordering::Relaxed) {
+            let idle_for = activity
+                .lock()
+                .map(|last| last.elapsed())
+                .unwrap_or_default();
+            let reason = match (policy.timeout, policy.idle_timeout) {
+                (Some(limit), _) if started.elapsed() >= limit => {
+                    Some(WatchdogTrip::Timeout(limit))
+                }
+                (_, Some(limit)) if idle_for >= limit => Some(WatchdogTrip::Idle(limit)),
+                _ => None,
+            };
+            if let Some(reason) = reason {
+                if let Ok(mut slot) = trip_clone.lock() {
+                    *slot = Some(reason);
+                }
+                if let Ok(mut c) = child.lock() {
+                    terminate_child(&mut c);
+                }
+                break;
+            }
+            thread::sleep(WATCHDOG_POLL_INTERVAL);
+        }
+    });
+    Some(trip)
+}
+
+/// Sleep for `delay`, waking early when the job gets cancelled.
+/// Returns `false` when the job was cancelled while waiting.
+fn sleep_unless_cancelled(job_id: &str, delay: Duration) -> bool {
+    let deadline = Instant::now() + delay;
+    loop {
+        if should_stop_job(job_id) {
+            return false;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return true;
+        }
+        thread::sleep((deadline - now).min(Duration::from_millis(200)));
+    }
+}
+
+fn build_resume_prompt(reason: &str) -> String {
+    format!(
+        "The previous attempt ended early ({}). Continue the task from where you left off.",
+        reason
+    )
+}
+
+/// Rebuild the full prompt for a retry when the runner cannot resume its session.
+/// The stored context conversation carries over what earlier attempts said.
+fn rebuild_prompt_for_retry(
+    tasks_dir: &std::path::Path,
+    config: &ResolvedConfig,
+    profile: &AgentProfileDetail,
+    ticket_id: &str,
+    user_prompt: &str,
+    reason: &str,
+) -> Option<String> {
+    let storage = Storage::try_open(tasks_dir)?;
+    let task = TaskService::get(&storage, ticket_id, None).ok()?;
+    let context = AgentContextService::load(tasks_dir, config, ticket_id).ok()?;
+    let instructions = resolve_agent_instructions(tasks_dir, config, profile).ok()?;
+    let request = format!("{}\n\n{}", user_prompt.trim(), build_resume_prompt(reason));
+    Some(build_prompt(
+        &task,
+        &request,
+        context.as_ref(),
+        instructions.as_deref(),
+    ))
+}
+
 #[allow(clippy::ptr_arg)]
 fn handle_runner_line(
     job_id: &str,
@@ -1448,16 +2074,18 @@ fn handle_runner_line(
     }
 }
 
+#[allow(clippy::too_many_arguments, clippy::needless_pass_by_value)]
 fn finalize_job(
     job_id: &str,
     ticket_id: &str,
     exit_code: Option<i32>,
+    failure_reason: Option<String>,
     context_messages: &[crate::services::agent_context_service::AgentContextMessage],
     tasks_dir: &std::path::Path,
     config: &ResolvedConfig,
 ) {
     let now = Utc::now().to_rfc3339();
-    let success = exit_code.unwrap_or(1) == 0;
+    let success = failure_reason.is_none() && exit_code.unwrap_or(1) == 0;
     update_job(job_id, |state| {
         if state.record.status == AgentJobStatus::Cancelled {
             return;
@@ -1469,14 +2097,19 @@ fn finalize_job(
         } else {
             AgentJobStatus::Failed
         };
-        state.record.summary = state.record.last_message.clone();
+        // Watchdog kills leave no useful runner output, so report the reason instead.
+        state.record.summary = match exit_code {
+            None if !success => failure_reason.clone(),
+            _ => state.record.last_message.clone(),
+        };
         let event_kind = if success {
             "agent_job_completed"
         } else {
             "agent_job_failed"
         };
-        push_event(state, event_kind, None);
-        emit_job_event(event_kind, &state.record, None);
+        push_event(state, event_kind, failure_reason.clone());
+        emit_job_event(event_kind, &state.record, failure_reason.clone());
+        forget_active(&state.record);
     });
 
     // Write final status to persistent log (if logging is enabled)
@@ -1501,7 +2134,7 @@ fn finalize_job(
         status_str,
         &Utc::now().to_rfc3339(),
         exit_code,
-        summary,
+        summary.clone(),
     );
 
     let mut job_context = None;
@@ -1516,7 +2149,7 @@ fn finalize_job(
                 worktree_branch: state.record.worktree_branch.clone(),
             });
         }
-        registry.active_by_ticket.remove(ticket_id);
+        release_ticket(registry, ticket_id, job_id);
         if let Some(state) = registry.jobs.get_mut(job_id) {
             state.runtime = None;
         }
@@ -1555,6 +2188,15 @@ fn finalize_job(
         },
     );
 
+    if !should_stop_job(job_id) {
+        let outcome = if success {
+            PipelineJobOutcome::Succeeded
+        } else {
+            PipelineJobOutcome::Failed
+        };
+        notify_pipeline(job_id, outcome, summary);
+    }
+
     // Try to start the next queued job
     process_pending_queue();
 }
@@ -1568,6 +2210,7 @@ fn update_job_failure(job_id: &str, ticket_id: &str, message: String, tasks_dir:
         state.record.summary = Some(message.clone());
         push_event(state, "agent_job_failed", Some(message.clone()));
         emit_job_event("agent_job_failed", &state.record, Some(message.clone()));
+        forget_active(&state.record);
     });
 
     // Write final status to persistent log (if logging is enabled)
@@ -1590,12 +2233,12 @@ fn update_job_failure(job_id: &str, ticket_id: &str, message: String, tasks_dir:
         "failed",
         &now,
         None,
-        Some(message),
+        Some(message.clone()),
     );
 
     let mut registry = JOB_REGISTRY.lock().ok();
     if let Some(ref mut registry) = registry {
-        registry.active_by_ticket.remove(ticket_id);
+        release_ticket(registry, ticket_id, job_id);
         // Decrement running count
         if registry.running_count > 0 {
             registry.running_count -= 1;
@@ -1603,10 +2246,34 @@ fn update_job_failure(job_id: &str, ticket_id: &str, message: String, tasks_dir:
     }
     drop(registry);
 
+    notify_pipeline(job_id, PipelineJobOutcome::Failed, Some(message));
+
     // Try to start the next queued job
     process_pending_queue();
 }
 
+/// Report a finished job to the pipeline run that started it, if any.
+fn notify_pipeline(job_id: &str, outcome: PipelineJobOutcome, message: Option<String>) {
+    let pipeline = JOB_REGISTRY.lock().ok().and_then(|registry| {
+        registry
+            .jobs
+            .get(job_id)
+            .and_then(|state| state.record.pipeline.clone())
+    });
+    if let Some(pipeline) = pipeline {
+        AgentPipelineService::handle_job_finished(job_id, &pipeline, outcome, message);
+    }
+}
+
+fn release_ticket(registry: &mut JobRegistry, ticket_id: &str, job_id: &str) {
+    if let Some(active) = registry.active_by_ticket.get_mut(ticket_id) {
+        active.retain(|id| id != job_id);
+        if active.is_empty() {
+            registry.active_by_ticket.remove(ticket_id);
+        }
+    }
+}
+
 fn update_job<F>(job_id: &str, mut updater: F)
 where
     F: FnMut(&mut AgentJobState),
@@ -1703,6 +2370,297 @@ fn should_stop_job(job_id: &str) -> bool {
     false
 }
 
+fn workspace_root_for(tasks_dir: &std::path::Path) -> std::path::PathBuf {
+    tasks_dir
+        .parent()
+        .map(|p| p.to_path_buf())
+        .unwrap_or_else(|| tasks_dir.to_path_buf())
+}
+
+fn config_for_ticket(tasks_dir: &std::path::Path, ticket_id: &str) -> LoTaRResult<ResolvedConfig> {
+    let cfg_mgr = ConfigManager::new_manager_with_tasks_dir_readonly(tasks_dir)
+        .map_err(|e| LoTaRError::ValidationError(e.to_string()))?;
+
+    let project_prefix = ticket_id.split('-').next().unwrap_or("");
+    Ok(if project_prefix.is_empty() {
+        cfg_mgr.get_resolved_config().clone()
+    } else {
+        cfg_mgr
+            .get_project_config(project_prefix)
+            .unwrap_or_else(|_| cfg_mgr.get_resolved_config().clone())
+    })
+}
+
+/// Write the job's registry entry so another lotar process can pick it up after a restart.
+fn persist_active(record: &AgentJobRecord) {
+    let (owner_pid, owner_started_at) = *REGISTRY_OWNER;
+    let entry = ActiveJobEntry {
+        job_id: record.id.clone(),
+        ticket_id: record.ticket_id.clone(),
+        runner: record.runner.clone(),
+        agent: record.agent_profile.clone(),
+        status: record.status.as_str().to_string(),
+        created_at: record.created_at.clone(),
+        started_at: record.started_at.clone(),
+        prompt: record.prompt.clone(),
+        tasks_dir: record.tasks_dir.clone(),
+        owner_pid,
+        owner_started_at,
+        pid: record.pid,
+        pid_started_at: record.pid.and_then(process_start_time),
+        attempt: record.attempt,
+        worktree_path: record.worktree_path.clone(),
+        worktree_branch: record.worktree_branch.clone(),
+        pipeline: record.pipeline.is_some(),
+    };
+    let _ = AgentLogService::save_active(
+        &record.workspace_root,
+        record.agent_logs_dir.as_deref(),
+        &entry,
+    );
+}
+
+fn forget_active(record: &AgentJobRecord) {
+    let _ = AgentLogService::remove_active(
+        &record.workspace_root,
+        record.agent_logs_dir.as_deref(),
+        &record.id,
+    );
+}
+
+fn process_start_time(pid: u32) -> Option<u64> {
+    let pid = Pid::from_u32(pid);
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    system.process(pid).map(|process| process.start_time())
+}
+
+/// Whether `pid` is alive and, when known, still the process that started at `started_at`.
+fn process_alive(pid: u32, started_at: Option<u64>) -> bool {
+    let pid = Pid::from_u32(pid);
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    system.process(pid).is_some_and(|process| {
+        !matches!(process.status(), ProcessStatus::Zombie)
+            && started_at.is_none_or(|at| process.start_time() == at)
+    })
+}
+
+fn terminate_pid(pid: u32) {
+    #[cfg(unix)]
+    unsafe {
+        // Runners lead their own process group, so signal the whole group.
+        let _ = libc::kill(-(pid as i32), libc::SIGTERM);
+        let _ = libc::kill(pid as i32, libc::SIGTERM);
+    }
+    #[cfg(not(unix))]
+    {
+        let pid = Pid::from_u32(pid);
+        let mut system = System::new();
+        system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+        if let Some(process) = system.process(pid) {
+            process.kill();
+        }
+    }
+}
+
+/// Rebuild a job from its log file for job listings.
+fn historic_job(
+    workspace_root: &std::path::Path,
+    logs_dir: &str,
+    job_id: &str,
+    active: Option<&ActiveJobEntry>,
+) -> Option<AgentJob> {
+    let header = AgentLogService::load_header(workspace_root, logs_dir, job_id)
+        .ok()
+        .flatten()?;
+    let status = AgentLogService::load_status(workspace_root, logs_dir, job_id)
+        .ok()
+        .flatten();
+    let status_name = match (status.as_ref(), active) {
+        (Some(line), _) => line.status.clone(),
+        // Still running in another lotar process.
+        (None, Some(entry)) => entry.status.clone(),
+        (None, None) => "unknown".to_string(),
+    };
+    Some(AgentJob {
+        id: header.job_id,
+        ticket_id: header.ticket_id,
+        runner: header.runner,
+        agent: active.and_then(|entry| entry.agent.clone()),
+        status: status_name,
+        created_at: header.created_at.clone(),
+        started_at: Some(header.created_at),
+        finished_at: status.as_ref().map(|line| line.at.clone()),
+        exit_code: status.as_ref().and_then(|line| line.exit_code),
+        last_message: None,
+        summary: status.and_then(|line| line.summary),
+        session_id: None,
+        worktree_path: header.worktree_path,
+        worktree_branch: header.worktree_branch,
+        attempt: active
+            .map(|entry| entry.attempt)
+            .filter(|attempt| *attempt > 0),
+    })
+}
+
+/// Track a runner that outlived the lotar process that started it, and finalize the job
+/// once the runner exits.
+fn reattach_job(entry: ActiveJobEntry, workspace_root: &std::path::Path, logs_dir: &str) {
+    let Some(pid) = entry.pid else {
+        return;
+    };
+    let runner_kind = entry
+        .runner
+        .parse::<AgentRunnerKind>()
+        .unwrap_or(AgentRunnerKind::Command);
+    let storage = Storage::new(&entry.tasks_dir);
+    let is_merge_job = TaskService::get(&storage, &entry.ticket_id, None)
+        .is_ok_and(|task| is_merge_job_candidate(&task, entry.agent.as_deref()));
+    let mut events: Vec<AgentJobEvent> =
+        AgentLogService::load_events(workspace_root, logs_dir, &entry.job_id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|event| AgentJobEvent {
+                kind: event.kind,
+                at: event.at,
+                message: event.message,
+            })
+            .collect();
+    let keep_from = events.len().saturating_sub(EVENT_LOG_LIMIT);
+    events.drain(..keep_from);
+
+    let job_id = entry.job_id.clone();
+    let ticket_id = entry.ticket_id.clone();
+    let tasks_dir = entry.tasks_dir.clone();
+    let pid_started_at = entry.pid_started_at;
+    let message = format!(
+        "Reattached to runner process {} after restart; earlier output is in the job log",
+        pid
+    );
+    if let Ok(mut registry) = JOB_REGISTRY.lock() {
+        let mut state = AgentJobState {
+            record: AgentJobRecord {
+                id: entry.job_id,
+                ticket_id: entry.ticket_id,
+                tasks_dir: entry.tasks_dir,
+                workspace_root: workspace_root.to_path_buf(),
+                agent_logs_dir: Some(logs_dir.to_string()),
+                runner: entry.runner,
+                runner_kind,
+                agent_profile: entry.agent,
+                status: AgentJobStatus::Running,
+                created_at: entry.created_at,
+                started_at: entry.started_at,
+                finished_at: None,
+                exit_code: None,
+                last_message: Some(message.clone()),
+                summary: None,
+                session_id: None,
+                worktree_path: entry.worktree_path,
+                worktree_branch: entry.worktree_branch,
+                is_merge_job,
+                attempt: entry.attempt,
+                pipeline: None,
+                prompt: entry.prompt,
+                pid: Some(pid),
+            },
+            events,
+            runtime: None,
+        };
+        push_event(&mut state, "agent_job_progress", Some(message.clone()));
+        emit_job_event("agent_job_progress", &state.record, Some(message));
+        persist_active(&state.record);
+        registry
+            .active_by_ticket
+            .entry(ticket_id.clone())
+            .or_default()
+            .push(job_id.clone());
+        registry.jobs.insert(job_id.clone(), state);
+        registry.running_count += 1;
+    }
+
+    thread::spawn(move || {
+        while process_alive(pid, pid_started_at) {
+            if should_stop_job(&job_id) {
+                return;
+            }
+            thread::sleep(REATTACH_POLL_INTERVAL);
+        }
+        if should_stop_job(&job_id) {
+            return;
+        }
+        let config = config_for_ticket(&tasks_dir, &ticket_id)
+            .unwrap_or_else(|_| ResolvedConfig::from_global(Default::default()));
+        finalize_job(
+            &job_id,
+            &ticket_id,
+            None,
+            Some(
+                "Runner exited after lotar restarted; its exit status was not captured".to_string(),
+            ),
+            &[],
+            &tasks_dir,
+            &config,
+        );
+    });
+}
+
+/// Mark a persisted job failed when no process is left to finish it.
+fn fail_orphaned_job(
+    entry: &ActiveJobEntry,
+    workspace_root: &std::path::Path,
+    logs_dir: &str,
+    message: &str,
+) {
+    let now = Utc::now().to_rfc3339();
+    let _ = AgentLogService::append_event(
+        workspace_root,
+        Some(logs_dir),
+        &entry.job_id,
+        "agent_job_failed",
+        &now,
+        Some(message.to_string()),
+    );
+    let _ = AgentLogService::write_status(
+        workspace_root,
+        Some(logs_dir),
+        &entry.job_id,
+        AgentJobStatus::Failed.as_str(),
+        &now,
+        None,
+        Some(message.to_string()),
+    );
+    crate::api_events::emit(&crate::api_events::ApiEvent {
+        kind: "agent_job_failed".to_string(),
+        data: json!({
+            "id": entry.job_id,
+            "ticket_id": entry.ticket_id,
+            "runner": entry.runner,
+            "agent": entry.agent,
+            "status": AgentJobStatus::Failed.as_str(),
+            "created_at": entry.created_at,
+            "started_at": entry.started_at,
+            "finished_at": now,
+            "message": message,
+            "worktree_path": entry.worktree_path,
+            "worktree_branch": entry.worktree_branch,
+        }),
+    });
+    let _ = AutomationService::apply_job_event(
+        entry.tasks_dir.as_path(),
+        &entry.ticket_id,
+        AutomationEvent::JobFailed,
+        Some(AutomationJobContext {
+            job_id: entry.job_id.clone(),
+            runner: entry.runner.clone(),
+            agent: entry.agent.clone(),
+            worktree_path: entry.worktree_path.clone(),
+            worktree_branch: entry.worktree_branch.clone(),
+        }),
+    );
+}
+
 fn push_event(state: &mut AgentJobState, kind: &str, message: Option<String>) {
     let at = Utc::now().to_rfc3339();
     state.events.push(AgentJobEvent {
@@ -1772,6 +2730,7 @@ mod tests {
             subtitle: None,
             description: Some("Do the thing".to_string()),
             tags: vec!["infra".to_string()],
+            acceptance_criteria: vec![],
             relationships: Default::default(),
             comments: vec![],
             references: vec![],
@@ -1841,6 +2800,16 @@ mod tests {
         assert_eq!(resolved.as_deref(), Some("profile instructions"));
     }
 
+    #[test]
+    fn profile_interval_rejects_overflowing_values() {
+        assert_eq!(
+            parse_profile_interval("timeout", Some("2h")).unwrap(),
+            Some(Duration::from_secs(7200))
+        );
+        let err = parse_profile_interval("timeout", Some("18446744073709551615d")).unwrap_err();
+        assert!(matches!(err, LoTaRError::ValidationError(_)), "{err}");
+    }
+
     #[test]
     fn worktree_helpers_sanitize_branch_names() {
         let suffix = sanitize_worktree_token("TEST-1 / scratch");
@@ -1879,6 +2848,36 @@ mod tests {
         assert!(loaded.messages.last().unwrap().content.contains("msg-29"));
     }
 
+    #[test]
+    fn retry_policy_parses_profile_durations() {
+        let mut profile = AgentProfileConfig::Runner("claude".to_string()).to_detail();
+        profile.timeout = Some("30m".to_string());
+        profile.idle_timeout = Some("90s".to_string());
+        profile.max_retries = Some(2);
+        profile.retry_backoff = Some("10s".to_string());
+
+        let policy = RetryPolicy::from_profile(&profile).unwrap();
+        assert_eq!(policy.timeout, Some(Duration::from_secs(1800)));
+        assert_eq!(policy.idle_timeout, Some(Duration::from_secs(90)));
+        assert_eq!(policy.max_retries, 2);
+        assert_eq!(policy.backoff_for(1), Duration::from_secs(10));
+        assert_eq!(policy.backoff_for(3), Duration::from_secs(40));
+
+        profile.timeout = Some("soon".to_string());
+        let err = RetryPolicy::from_profile(&profile).unwrap_err();
+        assert!(err.to_string().contains("timeout"));
+    }
+
+    #[test]
+    fn attempt_outcome_describes_watchdog_kills() {
+        let idle = AttemptOutcome::Killed(WatchdogTrip::Idle(Duration::from_secs(600)));
+        assert_eq!(
+            idle.failure_reason().as_deref(),
+            Some("runner produced no events for 10m")
+        );
+        assert!(AttemptOutcome::Exited(Some(0)).failure_reason().is_none());
+    }
+
     #[test]
     fn resolve_profile_uses_agent_map() {
         let mut config = ResolvedConfig::from_global(GlobalConfig::default());
@@ -5,6 +5,9 @@
 //!
 //! Logging is only enabled when `agent_logs_dir` is configured. The path can be
 //! relative (resolved against workspace root) or absolute.
+//!
+//! Jobs that are queued or running also keep an entry in `<logs_dir>/active/<JOB_ID>.json`
+//! so a restarted server can reattach to them (see `AgentJobService::restore_jobs`).
 
 use crate::errors::{LoTaRError, LoTaRResult};
 use serde::{Deserialize, Serialize};
@@ -12,6 +15,8 @@ use std::fs::{self, File, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Component, Path, PathBuf};
 
+const ACTIVE_DIR: &str = "active";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JobLogEntry {
     pub kind: String,
@@ -53,6 +58,44 @@ pub struct JobStatusLine {
     pub summary: Option<String>,
 }
 
+/// Registry entry for a job that has not finished yet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveJobEntry {
+    pub job_id: String,
+    pub ticket_id: String,
+    pub runner: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub agent: Option<String>,
+    /// queued or running
+    pub status: String,
+    pub created_at: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub started_at: Option<String>,
+    /// Prompt the job was created with, used to requeue it after a restart
+    pub prompt: String,
+    pub tasks_dir: PathBuf,
+    /// Process that owns the job; entries are only restored once it has exited
+    pub owner_pid: u32,
+    /// Start time of `owner_pid` in seconds since the epoch, to detect reused pids
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub owner_started_at: Option<u64>,
+    /// Runner (or wrapper) process id while the job is running
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub pid: Option<u32>,
+    /// Start time of `pid` in seconds since the epoch, to detect reused pids
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub pid_started_at: Option<u64>,
+    #[serde(default)]
+    pub attempt: u32,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub worktree_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub worktree_branch: Option<String>,
+    /// Pipeline stage jobs are not requeued; their run cannot resume in another process
+    #[serde(default)]
+    pub pipeline: bool,
+}
+
 pub struct AgentLogService;
 
 impl AgentLogService {
@@ -246,6 +289,72 @@ impl AgentLogService {
         Ok(last_status)
     }
 
+    /// Record a queued or running job in the active registry.
+    /// Only writes if logs_dir is provided.
+    pub fn save_active(
+        workspace_root: &Path,
+        logs_dir: Option<&str>,
+        entry: &ActiveJobEntry,
+    ) -> LoTaRResult<()> {
+        let Some(logs_dir) = logs_dir else {
+            return Ok(());
+        };
+
+        let path = resolve_active_path(workspace_root, logs_dir, &entry.job_id)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let body = serde_json::to_string_pretty(entry)
+            .map_err(|e| LoTaRError::SerializationError(e.to_string()))?;
+        // Write through a temp file so a crash never leaves a truncated entry behind.
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, body)?;
+        fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+
+    /// Drop a finished job from the active registry.
+    pub fn remove_active(
+        workspace_root: &Path,
+        logs_dir: Option<&str>,
+        job_id: &str,
+    ) -> LoTaRResult<()> {
+        let Some(logs_dir) = logs_dir else {
+            return Ok(());
+        };
+
+        let path = resolve_active_path(workspace_root, logs_dir, job_id)?;
+        match fs::remove_file(&path) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Load every entry in the active registry. Unreadable entries are skipped.
+    pub fn load_active(workspace_root: &Path, logs_dir: &str) -> LoTaRResult<Vec<ActiveJobEntry>> {
+        let dir = resolve_logs_dir(workspace_root, logs_dir).join(ACTIVE_DIR);
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let Ok(raw) = fs::read_to_string(&path) else {
+                continue;
+            };
+            if let Ok(entry) = serde_json::from_str::<ActiveJobEntry>(&raw) {
+                entries.push(entry);
+            }
+        }
+        entries.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Ok(entries)
+    }
+
     /// List all job log files in the logs directory.
     pub fn list_logs(workspace_root: &Path, logs_dir: &str) -> LoTaRResult<Vec<String>> {
         let resolved_dir = resolve_logs_dir(workspace_root, logs_dir);
@@ -280,6 +389,19 @@ fn resolve_logs_dir(workspace_root: &Path, logs_dir: &str) -> PathBuf {
     }
 }
 
+fn resolve_active_path(
+    workspace_root: &Path,
+    logs_dir: &str,
+    job_id: &str,
+) -> LoTaRResult<PathBuf> {
+    let log_path = resolve_log_path(workspace_root, logs_dir, job_id)?;
+    let file_name = format!("{}.json", job_id.trim());
+    Ok(log_path
+        .parent()
+        .map(|dir| dir.join(ACTIVE_DIR).join(&file_name))
+        .unwrap_or_else(|| PathBuf::from(ACTIVE_DIR).join(&file_name)))
+}
+
 fn resolve_log_path(workspace_root: &Path, logs_dir: &str, job_id: &str) -> LoTaRResult<PathBuf> {
     let trimmed = job_id.trim();
     if trimmed.is_empty() {
@@ -382,6 +504,53 @@ mod tests {
         assert_eq!(logs, vec!["job-test-1".to_string()]);
     }
 
+    #[test]
+    fn test_active_registry_round_trip() {
+        let dir = tempdir().unwrap();
+        let workspace_root = dir.path();
+        let logs_dir = ".agent-logs";
+        let entry = ActiveJobEntry {
+            job_id: "job-test-3".to_string(),
+            ticket_id: "DICE-3".to_string(),
+            runner: "claude".to_string(),
+            agent: Some("implement".to_string()),
+            status: "running".to_string(),
+            created_at: "2026-02-03T12:00:00Z".to_string(),
+            started_at: None,
+            prompt: "Roll the dice".to_string(),
+            tasks_dir: workspace_root.join(".tasks"),
+            owner_pid: 1,
+            owner_started_at: None,
+            pid: Some(4242),
+            pid_started_at: None,
+            attempt: 1,
+            worktree_path: None,
+            worktree_branch: None,
+            pipeline: false,
+        };
+
+        AgentLogService::save_active(workspace_root, Some(logs_dir), &entry).unwrap();
+        let loaded = AgentLogService::load_active(workspace_root, logs_dir).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].pid, Some(4242));
+        assert_eq!(loaded[0].agent.as_deref(), Some("implement"));
+
+        // Active entries are not job logs
+        assert!(
+            AgentLogService::list_logs(workspace_root, logs_dir)
+                .unwrap()
+                .is_empty()
+        );
+
+        AgentLogService::remove_active(workspace_root, Some(logs_dir), "job-test-3").unwrap();
+        AgentLogService::remove_active(workspace_root, Some(logs_dir), "job-test-3").unwrap();
+        assert!(
+            AgentLogService::load_active(workspace_root, logs_dir)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_no_logs_when_disabled() {
         let dir = tempdir().unwrap();
@@ -0,0 +1,1238 @@
+use crate::api_types::{
+    AgentJobCreateRequest, AgentPipelineDecisionRequest, AgentPipelineInfo, AgentPipelineRun,
+    AgentPipelineRunRequest, AgentPipelineStageRun,
+};
+use crate::config::manager::ConfigManager;
+use crate::config::types::{AgentPipelineConfig, AgentPipelineTransition, ResolvedConfig};
+use crate::errors::{LoTaRError, LoTaRResult};
+use crate::services::agent_job_service::AgentJobService;
+use crate::services::automation_service::{AutomationEvent, AutomationService};
+use crate::services::task_service::TaskService;
+use crate::storage::manager::Storage;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::thread;
+use std::time::Duration;
+
+const DEFAULT_MAX_REWINDS: u32 = 3;
+const DEFAULT_STAGE_PROMPT: &str = "Complete this stage of the pipeline for the ticket. Make concrete changes where needed, verify them, and summarize what you did. Exit non-zero if the stage cannot be completed.";
+const RUN_FILE_SUFFIX: &str = ".pipeline.json";
+const REQUEST_FILE_SUFFIX: &str = ".pipeline-request.json";
+const REQUEST_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+static RUN_COUNTER: AtomicU64 = AtomicU64::new(1);
+/// Pipeline runs owned by this process, keyed by ticket id.
+static PIPELINE_RUNS: LazyLock<Mutex<HashMap<String, PipelineRunState>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Identifies the pipeline stage an agent job was started for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentPipelineJobRef {
+    pub run_id: String,
+    pub stage: String,
+    /// Which start of the stage the job belongs to; jobs from before a rewind are ignored.
+    pub visit: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipelineJobOutcome {
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+/// Result of an approve/reject/cancel request.
+pub struct PipelineControlOutcome {
+    pub run: AgentPipelineRun,
+    /// True when the run belongs to another process (e.g. `lotar serve`) and the request
+    /// was handed to it instead of being applied here.
+    pub forwarded: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RunStatus {
+    Running,
+    AwaitingApproval,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl RunStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::AwaitingApproval => "awaiting_approval",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Cancelled)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StageStatus {
+    Pending,
+    Running,
+    AwaitingApproval,
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+impl StageStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::AwaitingApproval => "awaiting_approval",
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct StageState {
+    status: StageStatus,
+    job_id: Option<String>,
+    runs: u32,
+    started_at: Option<String>,
+    finished_at: Option<String>,
+    message: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct PipelineRunState {
+    id: String,
+    pipeline: String,
+    ticket_id: String,
+    prompt: Option<String>,
+    tasks_dir: PathBuf,
+    definition: AgentPipelineConfig,
+    status: RunStatus,
+    created_at: String,
+    updated_at: String,
+    finished_at: Option<String>,
+    message: Option<String>,
+    rewinds: u32,
+    stages: Vec<StageState>,
+}
+
+/// Work collected while the run registry is locked and performed after it is released,
+/// because starting or cancelling jobs calls back into this service.
+enum Effect {
+    StartStage {
+        tasks_dir: PathBuf,
+        ticket_id: String,
+        agent: String,
+        prompt: String,
+        job: AgentPipelineJobRef,
+    },
+    CancelJob(String),
+    Transition {
+        tasks_dir: PathBuf,
+        ticket_id: String,
+        transition: AgentPipelineTransition,
+        event: AutomationEvent,
+    },
+}
+
+/// Approve/reject/cancel request handed from another process to the run's owner.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PipelineControlRequest {
+    run_id: String,
+    action: ControlAction,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stage: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    actor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    requested_at: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ControlAction {
+    Approve,
+    Reject,
+    Cancel,
+}
+
+pub struct AgentPipelineService;
+
+impl AgentPipelineService {
+    /// Summaries of the pipelines defined in config, sorted by name.
+    pub fn list_definitions(config: &ResolvedConfig) -> Vec<AgentPipelineInfo> {
+        let mut pipelines: Vec<AgentPipelineInfo> = config
+            .agent_pipelines
+            .iter()
+            .map(|(name, definition)| AgentPipelineInfo {
+                name: name.clone(),
+                description: definition.description.clone(),
+                stages: definition
+                    .stages
+                    .iter()
+                    .map(|stage| stage.name.clone())
+                    .collect(),
+            })
+            .collect();
+        pipelines.sort_by(|a, b| a.name.cmp(&b.name));
+        pipelines
+    }
+
+    /// Check that a pipeline definition forms a valid DAG and references known agents.
+    pub fn validate(
+        name: &str,
+        definition: &AgentPipelineConfig,
+        config: &ResolvedConfig,
+    ) -> LoTaRResult<()> {
+        let invalid = |message: String| {
+            Err(LoTaRError::ValidationError(format!(
+                "Pipeline '{}': {}",
+                name, message
+            )))
+        };
+        if definition.stages.is_empty() {
+            return invalid("at least one stage is required".to_string());
+        }
+
+        let mut seen = HashSet::new();
+        for stage in &definition.stages {
+            let stage_name = stage.name.trim();
+            if stage_name.is_empty() {
+                return invalid("every stage needs a name".to_string());
+            }
+            if !seen.insert(stage_name) {
+                return invalid(format!("duplicate stage '{}'", stage_name));
+            }
+        }
+
+        for stage in &definition.stages {
+            match (stage.approval, stage.agent.as_deref()) {
+                (true, Some(_)) => {
+                    return invalid(format!(
+                        "approval stage '{}' cannot also run an agent",
+                        stage.name
+                    ));
+                }
+                (false, None) => {
+                    return invalid(format!(
+                        "stage '{}' needs an agent (or approval: true)",
+                        stage.name
+                    ));
+                }
+                (false, Some(agent)) if !config.agent_profiles.contains_key(agent) => {
+                    return invalid(format!(
+                        "stage '{}' uses unknown agent '{}'",
+                        stage.name, agent
+                    ));
+                }
+                _ => {}
+            }
+            for need in &stage.needs {
+                if need == &stage.name {
+                    return invalid(format!("stage '{}' cannot need itself", stage.name));
+                }
+                if !seen.contains(need.as_str()) {
+                    return invalid(format!(
+                        "stage '{}' needs unknown stage '{}'",
+                        stage.name, need
+                    ));
+                }
+            }
+            let goto_outside_failure = [stage.on_start.as_ref(), stage.on_success.as_ref()]
+                .into_iter()
+                .flatten()
+                .any(|transition| transition.goto.is_some());
+            if goto_outside_failure {
+                return invalid(format!(
+                    "stage '{}' can only use goto in on_failure",
+                    stage.name
+                ));
+            }
+        }
+
+        if topological_order(definition).is_none() {
+            return invalid("stage dependencies contain a cycle".to_string());
+        }
+
+        for (index, stage) in definition.stages.iter().enumerate() {
+            let Some(target) = stage
+                .on_failure
+                .as_ref()
+                .and_then(|transition| transition.goto.as_deref())
+            else {
+                continue;
+            };
+            let Some(target_index) = stage_index(definition, target) else {
+                return invalid(format!(
+                    "stage '{}' has goto to unknown stage '{}'",
+                    stage.name, target
+                ));
+            };
+            if target_index != index && !ancestors(definition, index).contains(&target_index) {
+                return invalid(format!(
+                    "stage '{}' can only goto itself or a stage it depends on, not '{}'",
+                    stage.name, target
+                ));
+            }
+        }
+
+        let goto_at_pipeline_level = [
+            definition.on_success.as_ref(),
+            definition.on_failure.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|transition| transition.goto.is_some());
+        if goto_at_pipeline_level {
+            return invalid("goto is only supported in stage on_failure transitions".to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Start a pipeline run for a ticket. Stages run as agent jobs inside this process.
+    pub fn start(req: AgentPipelineRunRequest, tasks_dir: &Path) -> LoTaRResult<AgentPipelineRun> {
+        let ticket_id = req.ticket_id.trim().to_string();
+        if ticket_id.is_empty() {
+            return Err(LoTaRError::ValidationError(
+                "Ticket id is required".to_string(),
+            ));
+        }
+        let pipeline = req.pipeline.trim().to_string();
+        let config = resolve_config(tasks_dir, &ticket_id)?;
+        let Some(definition) = config.agent_pipelines.get(&pipeline).cloned() else {
+            let mut available: Vec<&str> =
+                config.agent_pipelines.keys().map(String::as_str).collect();
+            available.sort_unstable();
+            let hint = if available.is_empty() {
+                "no pipelines are configured".to_string()
+            } else {
+                format!("available: {}", available.join(", "))
+            };
+            return Err(LoTaRError::ValidationError(format!(
+                "Unknown pipeline '{}' ({})",
+                pipeline, hint
+            )));
+        };
+        Self::validate(&pipeline, &definition, &config)?;
+
+        let storage = Storage::new(tasks_dir);
+        TaskService::get(&storage, &ticket_id, None)?;
+        let run_path = run_file_path(tasks_dir, &ticket_id)?;
+
+        if AgentJobService::has_active_job(&ticket_id) {
+            return Err(LoTaRError::ValidationError(format!(
+                "Ticket '{}' already has an active agent job",
+                ticket_id
+            )));
+        }
+
+        let mut effects = Vec::new();
+        let (run_id, snapshot) = {
+            let mut runs = PIPELINE_RUNS.lock().map_err(|_| registry_unavailable())?;
+            if let Some(existing) = runs.get(&ticket_id)
+                && !existing.status.is_terminal()
+            {
+                return Err(LoTaRError::ValidationError(format!(
+                    "Ticket '{}' already has an active pipeline run ({})",
+                    ticket_id, existing.pipeline
+                )));
+            }
+
+            let now = Utc::now().to_rfc3339();
+            let prompt = req
+                .prompt
+                .map(|prompt| prompt.trim().to_string())
+                .filter(|prompt| !prompt.is_empty());
+            let mut state = PipelineRunState {
+                id: make_run_id(),
+                pipeline,
+                ticket_id: ticket_id.clone(),
+                prompt,
+                tasks_dir: tasks_dir.to_path_buf(),
+                stages: definition
+                    .stages
+                    .iter()
+                    .map(|_| StageState {
+                        status: StageStatus::Pending,
+                        job_id: None,
+                        runs: 0,
+                        started_at: None,
+                        finished_at: None,
+                        message: None,
+                    })
+                    .collect(),
+                definition,
+                status: RunStatus::Running,
+                created_at: now.clone(),
+                updated_at: now,
+                finished_at: None,
+                message: None,
+                rewinds: 0,
+            };
+            advance(&mut state, &mut effects);
+            publish(&mut state);
+            let run_id = state.id.clone();
+            let snapshot = state.to_dto();
+            runs.insert(ticket_id.clone(), state);
+            (run_id, snapshot)
+        };
+
+        // Drop any request left over from an earlier run of this ticket.
+        let _ = fs::remove_file(request_path_for(&run_path));
+        spawn_request_watcher(tasks_dir.to_path_buf(), ticket_id.clone(), run_id);
+        execute_effects(effects);
+
+        Ok(Self::get_run(&ticket_id).unwrap_or(snapshot))
+    }
+
+    /// Pipeline runs owned by this process.
+    pub fn list_runs() -> Vec<AgentPipelineRun> {
+        PIPELINE_RUNS
+            .lock()
+            .map(|runs| runs.values().map(PipelineRunState::to_dto).collect())
+            .unwrap_or_default()
+    }
+
+    /// Latest pipeline run for a ticket, from this process or from the run file on disk.
+    pub fn status(tasks_dir: &Path, ticket_id: &str) -> LoTaRResult<Option<AgentPipelineRun>> {
+        if let Some(run) = Self::get_run(ticket_id) {
+            return Ok(Some(run));
+        }
+        load_run_file(tasks_dir, ticket_id)
+    }
+
+    pub fn approve(
+        req: &AgentPipelineDecisionRequest,
+        tasks_dir: &Path,
+        actor: Option<&str>,
+    ) -> LoTaRResult<PipelineControlOutcome> {
+        control(ControlAction::Approve, req, tasks_dir, actor)
+    }
+
+    pub fn reject(
+        req: &AgentPipelineDecisionRequest,
+        tasks_dir: &Path,
+        actor: Option<&str>,
+    ) -> LoTaRResult<PipelineControlOutcome> {
+        control(ControlAction::Reject, req, tasks_dir, actor)
+    }
+
+    pub fn cancel(
+        ticket_id: &str,
+        tasks_dir: &Path,
+        actor: Option<&str>,
+    ) -> LoTaRResult<PipelineControlOutcome> {
+        let req = AgentPipelineDecisionRequest {
+            ticket_id: ticket_id.to_string(),
+            stage: None,
+            note: None,
+        };
+        control(ControlAction::Cancel, &req, tasks_dir, actor)
+    }
+
+    /// Called by the job service when a job started for a pipeline stage finishes.
+    pub(crate) fn handle_job_finished(
+        job_id: &str,
+        job: &AgentPipelineJobRef,
+        outcome: PipelineJobOutcome,
+        message: Option<String>,
+    ) {
+        let mut effects = Vec::new();
+        if let Ok(mut runs) = PIPELINE_RUNS.lock()
+            && let Some(state) = runs.values_mut().find(|state| state.id == job.run_id)
+            && let Some(index) = state.current_stage(job)
+        {
+            state.stages[index].job_id = Some(job_id.to_string());
+            match outcome {
+                PipelineJobOutcome::Succeeded => {
+                    succeed_stage(state, index, message, &mut effects);
+                }
+                PipelineJobOutcome::Failed => {
+                    let reason = message.unwrap_or_else(|| "agent job failed".to_string());
+                    fail_stage(state, index, &reason, &mut effects);
+                }
+                PipelineJobOutcome::Cancelled => {
+                    let stage = &mut state.stages[index];
+                    stage.status = StageStatus::Cancelled;
+                    stage.finished_at = Some(Utc::now().to_rfc3339());
+                    let reason = format!("Stage '{}' job was cancelled", job.stage);
+                    cancel_run(state, reason, &mut effects);
+                }
+            }
+            publish(state);
+        }
+        execute_effects(effects);
+    }
+
+    fn get_run(ticket_id: &str) -> Option<AgentPipelineRun> {
+        let runs = PIPELINE_RUNS.lock().ok()?;
+        runs.get(ticket_id).map(PipelineRunState::to_dto)
+    }
+}
+
+impl PipelineRunState {
+    fn to_dto(&self) -> AgentPipelineRun {
+        AgentPipelineRun {
+            id: self.id.clone(),
+            pipeline: self.pipeline.clone(),
+            ticket_id: self.ticket_id.clone(),
+            status: self.status.as_str().to_string(),
+            created_at: self.created_at.clone(),
+            updated_at: self.updated_at.clone(),
+            finished_at: self.finished_at.clone(),
+            prompt: self.prompt.clone(),
+            message: self.message.clone(),
+            rewinds: self.rewinds,
+            stages: self
+                .definition
+                .stages
+                .iter()
+                .zip(&self.stages)
+                .map(|(stage, state)| AgentPipelineStageRun {
+                    name: stage.name.clone(),
+                    agent: stage.agent.clone(),
+                    status: state.status.as_str().to_string(),
+                    needs: stage.needs.clone(),
+                    approval: stage.approval,
+                    job_id: state.job_id.clone(),
+                    runs: state.runs,
+                    started_at: state.started_at.clone(),
+                    finished_at: state.finished_at.clone(),
+                    message: state.message.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Index of the running stage a job belongs to, unless the job is stale.
+    fn current_stage(&self, job: &AgentPipelineJobRef) -> Option<usize> {
+        let index = stage_index(&self.definition, &job.stage)?;
+        let stage = &self.stages[index];
+        (stage.status == StageStatus::Running && stage.runs == job.visit).then_some(index)
+    }
+
+    fn awaiting_stage(&self, requested: Option<&str>) -> LoTaRResult<usize> {
+        let waiting: Vec<usize> = self
+            .stages
+            .iter()
+            .enumerate()
+            .filter(|(_, stage)| stage.status == StageStatus::AwaitingApproval)
+            .map(|(index, _)| index)
+            .collect();
+        if let Some(name) = requested.map(str::trim).filter(|name| !name.is_empty()) {
+            return waiting
+                .into_iter()
+                .find(|index| self.definition.stages[*index].name == name)
+                .ok_or_else(|| {
+                    LoTaRError::ValidationError(format!(
+                        "Stage '{}' is not waiting for approval",
+                        name
+                    ))
+                });
+        }
+        match waiting.as_slice() {
+            [index] => Ok(*index),
+            [] => Err(LoTaRError::ValidationError(format!(
+                "Pipeline run for '{}' is not waiting for approval",
+                self.ticket_id
+            ))),
+            _ => Err(LoTaRError::ValidationError(
+                "Several stages are waiting for approval; pass the stage name".to_string(),
+            )),
+        }
+    }
+
+    fn transition(
+        &self,
+        transition: Option<&AgentPipelineTransition>,
+        event: AutomationEvent,
+        effects: &mut Vec<Effect>,
+    ) {
+        if let Some(transition) = transition {
+            effects.push(Effect::Transition {
+                tasks_dir: self.tasks_dir.clone(),
+                ticket_id: self.ticket_id.clone(),
+                transition: transition.clone(),
+                event,
+            });
+        }
+    }
+}
+
+/// Start every pending stage whose dependencies have succeeded and refresh the run status.
+fn advance(state: &mut PipelineRunState, effects: &mut Vec<Effect>) {
+    if state.status.is_terminal() {
+        return;
+    }
+
+    for index in 0..state.stages.len() {
+        if state.stages[index].status != StageStatus::Pending {
+            continue;
+        }
+        let ready = state.definition.stages[index].needs.iter().all(|need| {
+            stage_index(&state.definition, need)
+                .is_some_and(|need| state.stages[need].status == StageStatus::Succeeded)
+        });
+        if ready {
+            start_stage(state, index, effects);
+        }
+    }
+
+    if state
+        .stages
+        .iter()
+        .all(|stage| stage.status == StageStatus::Succeeded)
+    {
+        state.status = RunStatus::Completed;
+        state.finished_at = Some(Utc::now().to_rfc3339());
+        state.message = Some("All stages succeeded".to_string());
+        state.transition(
+            state.definition.on_success.as_ref(),
+            AutomationEvent::JobCompleted,
+            effects,
+        );
+        return;
+    }
+
+    if state
+        .stages
+        .iter()
+        .any(|stage| stage.status == StageStatus::Running)
+    {
+        state.status = RunStatus::Running;
+    } else if state
+        .stages
+        .iter()
+        .any(|stage| stage.status == StageStatus::AwaitingApproval)
+    {
+        state.status = RunStatus::AwaitingApproval;
+    }
+}
+
+fn start_stage(state: &mut PipelineRunState, index: usize, effects: &mut Vec<Effect>) {
+    let definition = state.definition.stages[index].clone();
+    let stage = &mut state.stages[index];
+    stage.runs += 1;
+    stage.job_id = None;
+    stage.started_at = Some(Utc::now().to_rfc3339());
+    stage.finished_at = None;
+
+    if definition.approval {
+        stage.status = StageStatus::AwaitingApproval;
+        stage.message = Some("Waiting for approval".to_string());
+    } else {
+        stage.status = StageStatus::Running;
+        stage.message = None;
+        let base = definition
+            .prompt
+            .clone()
+            .or_else(|| state.prompt.clone())
+            .unwrap_or_else(|| DEFAULT_STAGE_PROMPT.to_string());
+        effects.push(Effect::StartStage {
+            tasks_dir: state.tasks_dir.clone(),
+            ticket_id: state.ticket_id.clone(),
+            agent: definition.agent.clone().unwrap_or_default(),
+            prompt: format!(
+                "Pipeline '{}', stage '{}'.\n\n{}",
+                state.pipeline, definition.name, base
+            ),
+            job: AgentPipelineJobRef {
+                run_id: state.id.clone(),
+                stage: definition.name.clone(),
+                visit: stage.runs,
+            },
+        });
+    }
+    state.transition(
+        definition.on_start.as_ref(),
+        AutomationEvent::JobStarted,
+        effects,
+    );
+}
+
+fn succeed_stage(
+    state: &mut PipelineRunState,
+    index: usize,
+    message: Option<String>,
+    effects: &mut Vec<Effect>,
+) {
+    let stage = &mut state.stages[index];
+    stage.status = StageStatus::Succeeded;
+    stage.finished_at = Some(Utc::now().to_rfc3339());
+    stage.message = message;
+    let on_success = state.definition.stages[index].on_success.clone();
+    state.transition(on_success.as_ref(), AutomationEvent::JobCompleted, effects);
+    advance(state, effects);
+}
+
+fn fail_stage(
+    state: &mut PipelineRunState,
+    index: usize,
+    message: &str,
+    effects: &mut Vec<Effect>,
+) {
+    let stage = &mut state.stages[index];
+    stage.status = StageStatus::Failed;
+    stage.finished_at = Some(Utc::now().to_rfc3339());
+    stage.message = Some(message.to_string());
+
+    let definition = state.definition.stages[index].clone();
+    state.transition(
+        definition.on_failure.as_ref(),
+        AutomationEvent::JobFailed,
+        effects,
+    );
+
+    let goto = definition
+        .on_failure
+        .as_ref()
+        .and_then(|transition| transition.goto.as_deref())
+        .and_then(|target| stage_index(&state.definition, target));
+    let max_rewinds = state.definition.max_rewinds.unwrap_or(DEFAULT_MAX_REWINDS);
+    if let Some(target) = goto {
+        if state.rewinds < max_rewinds {
+            state.rewinds += 1;
+            for affected in descendants(&state.definition, target) {
+                let stage = &mut state.stages[affected];
+                if stage.status == StageStatus::Running
+                    && let Some(job_id) = stage.job_id.clone()
+                {
+                    effects.push(Effect::CancelJob(job_id));
+                }
+                if affected != index {
+                    stage.message = None;
+                }
+                stage.status = StageStatus::Pending;
+                stage.job_id = None;
+            }
+            state.message = Some(format!(
+                "Stage '{}' failed; re-running from '{}'",
+                definition.name, state.definition.stages[target].name
+            ));
+            advance(state, effects);
+            return;
+        }
+        fail_run(
+            state,
+            format!(
+                "Stage '{}' failed after {} rewinds: {}",
+                definition.name, state.rewinds, message
+            ),
+            effects,
+        );
+        return;
+    }
+
+    fail_run(
+        state,
+        format!("Stage '{}' failed: {}", definition.name, message),
+        effects,
+    );
+}
+
+fn fail_run(state: &mut PipelineRunState, message: String, effects: &mut Vec<Effect>) {
+    stop_open_stages(state, effects);
+    state.status = RunStatus::Failed;
+    state.finished_at = Some(Utc::now().to_rfc3339());
+    state.message = Some(message);
+    let on_failure = state.definition.on_failure.clone();
+    state.transition(on_failure.as_ref(), AutomationEvent::JobFailed, effects);
+}
+
+fn cancel_run(state: &mut PipelineRunState, message: String, effects: &mut Vec<Effect>) {
+    stop_open_stages(state, effects);
+    state.status = RunStatus::Cancelled;
+    state.finished_at = Some(Utc::now().to_rfc3339());
+    state.message = Some(message);
+}
+
+fn stop_open_stages(state: &mut PipelineRunState, effects: &mut Vec<Effect>) {
+    let now = Utc::now().to_rfc3339();
+    for stage in &mut state.stages {
+        if !matches!(
+            stage.status,
+            StageStatus::Running | StageStatus::AwaitingApproval
+        ) {
+            continue;
+        }
+        if let Some(job_id) = stage.job_id.clone() {
+            effects.push(Effect::CancelJob(job_id));
+        }
+        stage.status = StageStatus::Cancelled;
+        stage.finished_at = Some(now.clone());
+    }
+}
+
+fn control(
+    action: ControlAction,
+    req: &AgentPipelineDecisionRequest,
+    tasks_dir: &Path,
+    actor: Option<&str>,
+) -> LoTaRResult<PipelineControlOutcome> {
+    let ticket_id = req.ticket_id.trim();
+    let mut effects = Vec::new();
+    let local = {
+        let mut runs = PIPELINE_RUNS.lock().map_err(|_| registry_unavailable())?;
+        match runs.get_mut(ticket_id) {
+            Some(state) if !state.status.is_terminal() => {
+                apply_control(
+                    state,
+                    action,
+                    req.stage.as_deref(),
+                    actor,
+                    req.note.as_deref(),
+                    &mut effects,
+                )?;
+                publish(state);
+                Some(state.to_dto())
+            }
+            _ => None,
+        }
+    };
+    if let Some(run) = local {
+        execute_effects(effects);
+        let run = AgentPipelineService::get_run(ticket_id).unwrap_or(run);
+        return Ok(PipelineControlOutcome {
+            run,
+            forwarded: false,
+        });
+    }
+
+    // The run belongs to another process: validate against its run file and leave a request.
+    let run = load_run_file(tasks_dir, ticket_id)?.ok_or_else(|| {
+        LoTaRError::ValidationError(format!("No pipeline run found for '{}'", ticket_id))
+    })?;
+    if matches!(run.status.as_str(), "completed" | "failed" | "cancelled") {
+        return Err(LoTaRError::ValidationError(format!(
+            "Pipeline run for '{}' already {}",
+            ticket_id, run.status
+        )));
+    }
+    if action != ControlAction::Cancel {
+        let waiting: Vec<&AgentPipelineStageRun> = run
+            .stages
+            .iter()
+            .filter(|stage| stage.status == StageStatus::AwaitingApproval.as_str())
+            .collect();
+        let requested = req.stage.as_deref().map(str::trim);
+        let matched = match requested {
+            Some(name) => waiting.iter().any(|stage| stage.name == name),
+            None => waiting.len() == 1,
+        };
+        if !matched {
+            return Err(LoTaRError::ValidationError(match requested {
+                Some(name) => format!("Stage '{}' is not waiting for approval", name),
+                None if waiting.is_empty() => format!(
+                    "Pipeline run for '{}' is not waiting for approval",
+                    ticket_id
+                ),
+                None => "Several stages are waiting for approval; pass the stage name".to_string(),
+            }));
+        }
+    }
+
+    let request = PipelineControlRequest {
+        run_id: run.id.clone(),
+        action,
+        stage: req.stage.clone(),
+        actor: actor.map(str::to_string),
+        note: req.note.clone(),
+        requested_at: Utc::now().to_rfc3339(),
+    };
+    let path = request_path_for(&run_file_path(tasks_dir, ticket_id)?);
+    let payload = serde_json::to_string_pretty(&request).map_err(|err| {
+        LoTaRError::SerializationError(format!("Failed to serialize pipeline request: {}", err))
+    })?;
+    let staging = path.with_extension("tmp");
+    fs::write(&staging, payload)?;
+    fs::rename(&staging, &path)?;
+    Ok(PipelineControlOutcome {
+        run,
+        forwarded: true,
+    })
+}
+
+fn apply_control(
+    state: &mut PipelineRunState,
+    action: ControlAction,
+    stage: Option<&str>,
+    actor: Option<&str>,
+    note: Option<&str>,
+    effects: &mut Vec<Effect>,
+) -> LoTaRResult<()> {
+    let actor = actor.unwrap_or("unknown");
+    let note = note.map(str::trim).filter(|note| !note.is_empty());
+    match action {
+        ControlAction::Approve => {
+            let index = state.awaiting_stage(stage)?;
+            let message = match note {
+                Some(note) => format!("Approved by {}: {}", actor, note),
+                None => format!("Approved by {}", actor),
+            };
+            succeed_stage(state, index, Some(message), effects);
+        }
+        ControlAction::Reject => {
+            let index = state.awaiting_stage(stage)?;
+            let message = match note {
+                Some(note) => format!("Rejected by {}: {}", actor, note),
+                None => format!("Rejected by {}", actor),
+            };
+            fail_stage(state, index, &message, effects);
+        }
+        ControlAction::Cancel => {
+            cancel_run(state, format!("Cancelled by {}", actor), effects);
+        }
+    }
+    Ok(())
+}
+
+/// Poll for approve/reject/cancel requests left by other processes until the run finishes.
+fn spawn_request_watcher(tasks_dir: PathBuf, ticket_id: String, run_id: String) {
+    thread::spawn(move || {
+        let Ok(path) = run_file_path(&tasks_dir, &ticket_id).map(|path| request_path_for(&path))
+        else {
+            return;
+        };
+        loop {
+            thread::sleep(REQUEST_POLL_INTERVAL);
+            let active = PIPELINE_RUNS.lock().is_ok_and(|runs| {
+                runs.get(&ticket_id)
+                    .is_some_and(|state| state.id == run_id && !state.status.is_terminal())
+            });
+            if !active {
+                return;
+            }
+            let Ok(payload) = fs::read_to_string(&path) else {
+                continue;
+            };
+            let _ = fs::remove_file(&path);
+            let Ok(request) = serde_json::from_str::<PipelineControlRequest>(&payload) else {
+                continue;
+            };
+            if request.run_id != run_id {
+                continue;
+            }
+            let mut effects = Vec::new();
+            if let Ok(mut runs) = PIPELINE_RUNS.lock()
+                && let Some(state) = runs.get_mut(&ticket_id)
+                && apply_control(
+                    state,
+                    request.action,
+                    request.stage.as_deref(),
+                    request.actor.as_deref(),
+                    request.note.as_deref(),
+                    &mut effects,
+                )
+                .is_ok()
+            {
+                publish(state);
+            }
+            execute_effects(effects);
+        }
+    });
+}
+
+fn execute_effects(effects: Vec<Effect>) {
+    let mut queue: VecDeque<Effect> = effects.into();
+    while let Some(effect) = queue.pop_front() {
+        match effect {
+            Effect::StartStage {
+                tasks_dir,
+                ticket_id,
+                agent,
+                prompt,
+                job,
+            } => {
+                let req = AgentJobCreateRequest {
+                    ticket_id,
+                    prompt,
+                    runner: None,
+                    agent: Some(agent),
+                };
+                match AgentJobService::start_pipeline_job(req, &tasks_dir, job.clone()) {
+                    Ok(started) => record_stage_job(&job, &started.id),
+                    Err(err) => {
+                        let mut follow_up = Vec::new();
+                        if let Ok(mut runs) = PIPELINE_RUNS.lock()
+                            && let Some(state) =
+                                runs.values_mut().find(|state| state.id == job.run_id)
+                            && let Some(index) = state.current_stage(&job)
+                        {
+                            let reason = format!("could not start agent job: {}", err);
+                            fail_stage(state, index, &reason, &mut follow_up);
+                            publish(state);
+                        }
+                        queue.extend(follow_up);
+                    }
+                }
+            }
+            Effect::CancelJob(job_id) => {
+                let _ = AgentJobService::cancel_job(&job_id);
+            }
+            Effect::Transition {
+                tasks_dir,
+                ticket_id,
+                transition,
+                event,
+            } => {
+                let _ = AutomationService::apply_pipeline_transition(
+                    &tasks_dir,
+                    &ticket_id,
+                    &transition,
+                    event,
+                );
+            }
+        }
+    }
+}
+
+fn record_stage_job(job: &AgentPipelineJobRef, job_id: &str) {
+    if let Ok(mut runs) = PIPELINE_RUNS.lock()
+        && let Some(state) = runs.values_mut().find(|state| state.id == job.run_id)
+        && let Some(index) = state.current_stage(job)
+        && state.stages[index].job_id.is_none()
+    {
+        state.stages[index].job_id = Some(job_id.to_string());
+        publish(state);
+    }
+}
+
+/// Persist the run file and emit an SSE update.
+fn publish(state: &mut PipelineRunState) {
+    state.updated_at = Utc::now().to_rfc3339();
+    let run = state.to_dto();
+    if let Ok(path) = run_file_path(&state.tasks_dir, &state.ticket_id)
+        && let Ok(payload) = serde_json::to_string_pretty(&run)
+    {
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let _ = fs::write(&path, payload);
+    }
+    crate::api_events::emit(&crate::api_events::ApiEvent {
+        kind: "agent_pipeline_updated".to_string(),
+        data: serde_json::to_value(&run).unwrap_or_default(),
+    });
+}
+
+fn load_run_file(tasks_dir: &Path, ticket_id: &str) -> LoTaRResult<Option<AgentPipelineRun>> {
+    let path = run_file_path(tasks_dir, ticket_id)?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let payload = fs::read_to_string(&path)?;
+    let run = serde_json::from_str::<AgentPipelineRun>(&payload).map_err(|err| {
+        LoTaRError::SerializationError(format!("Invalid pipeline run file: {}", err))
+    })?;
+    Ok(Some(run))
+}
+
+/// Run files live next to the ticket's agent context: `<tasks_dir>/<PROJECT>/<ID>.pipeline.json`.
+fn run_file_path(tasks_dir: &Path, ticket_id: &str) -> LoTaRResult<PathBuf> {
+    let trimmed = ticket_id.trim();
+    if trimmed.is_empty()
+        || trimmed
+            .chars()
+            .any(|c| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+    {
+        return Err(LoTaRError::ValidationError(format!(
+            "Invalid ticket id '{}'",
+            ticket_id
+        )));
+    }
+    let project = trimmed.split('-').next().unwrap_or(trimmed).to_uppercase();
+    Ok(tasks_dir
+        .join(project)
+        .join(format!("{}{}", trimmed, RUN_FILE_SUFFIX)))
+}
+
+fn request_path_for(run_path: &Path) -> PathBuf {
+    let name = run_path
+        .file_name()
+        .map(|name| {
+            name.to_string_lossy()
+                .replace(RUN_FILE_SUFFIX, REQUEST_FILE_SUFFIX)
+        })
+        .unwrap_or_else(|| REQUEST_FILE_SUFFIX.to_string());
+    run_path.with_file_name(name)
+}
+
+fn resolve_config(tasks_dir: &Path, ticket_id: &str) -> LoTaRResult<ResolvedConfig> {
+    let cfg_mgr = ConfigManager::new_manager_with_tasks_dir_readonly(tasks_dir)
+        .map_err(|e| LoTaRError::ValidationError(e.to_string()))?;
+    let project_prefix = ticket_id.split('-').next().unwrap_or("");
+    Ok(if project_prefix.is_empty() {
+        cfg_mgr.get_resolved_config().clone()
+    } else {
+        cfg_mgr
+            .get_project_config(project_prefix)
+            .unwrap_or_else(|_| cfg_mgr.get_resolved_config().clone())
+    })
+}
+
+fn make_run_id() -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%S%.3fZ").to_string();
+    let counter = RUN_COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("pipeline-{}-{}", stamp, counter)
+}
+
+fn registry_unavailable() -> LoTaRError {
+    LoTaRError::ValidationError("Pipeline registry unavailable".to_string())
+}
+
+fn stage_index(definition: &AgentPipelineConfig, name: &str) -> Option<usize> {
+    definition
+        .stages
+        .iter()
+        .position(|stage| stage.name == name)
+}
+
+/// Stage indices in dependency order, or `None` when the dependencies contain a cycle.
+fn topological_order(definition: &AgentPipelineConfig) -> Option<Vec<usize>> {
+    let count = definition.stages.len();
+    let mut remaining: Vec<usize> = definition
+        .stages
+        .iter()
+        .map(|stage| stage.needs.len())
+        .collect();
+    let mut ready: VecDeque<usize> = (0..count).filter(|i| remaining[*i] == 0).collect();
+    let mut order = Vec::with_capacity(count);
+    while let Some(index) = ready.pop_front() {
+        order.push(index);
+        let name = &definition.stages[index].name;
+        for (other, stage) in definition.stages.iter().enumerate() {
+            let edges = stage.needs.iter().filter(|need| *need == name).count();
+            if edges > 0 {
+                remaining[other] -= edges;
+                if remaining[other] == 0 {
+                    ready.push_back(other);
+                }
+            }
+        }
+    }
+    (order.len() == count).then_some(order)
+}
+
+/// Every stage the given stage depends on, directly or transitively.
+fn ancestors(definition: &AgentPipelineConfig, index: usize) -> HashSet<usize> {
+    let mut found = HashSet::new();
+    let mut pending = vec![index];
+    while let Some(current) = pending.pop() {
+        for need in &definition.stages[current].needs {
+            if let Some(need) = stage_index(definition, need)
+                && found.insert(need)
+            {
+                pending.push(need);
+            }
+        }
+    }
+    found
+}
+
+/// The given stage plus every stage that depends on it, directly or transitively.
+fn descendants(definition: &AgentPipelineConfig, index: usize) -> Vec<usize> {
+    let mut found = vec![index];
+    let mut cursor = 0;
+    while cursor < found.len() {
+        let name = &definition.stages[found[cursor]].name;
+        for (other, stage) in definition.stages.iter().enumerate() {
+            if stage.needs.contains(name) && !found.contains(&other) {
+                found.push(other);
+            }
+        }
+        cursor += 1;
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::types::{AgentPipelineStage, AgentProfileConfig, GlobalConfig};
+
+    fn config_with_agents(names: &[&str]) -> ResolvedConfig {
+        let mut config = ResolvedConfig::from_global(GlobalConfig::default());
+        for name in names {
+            config.agent_profiles.insert(
+                (*name).to_string(),
+                AgentProfileConfig::Runner("command".to_string()).to_detail(),
+            );
+        }
+        config
+    }
+
+    fn stage(name: &str, agent: Option<&str>, needs: &[&str]) -> AgentPipelineStage {
+        AgentPipelineStage {
+            name: name.to_string(),
+            agent: agent.map(str::to_string),
+            needs: needs.iter().map(|need| need.to_string()).collect(),
+            ..AgentPipelineStage::default()
+        }
+    }
+
+    fn fan_out() -> AgentPipelineConfig {
+        let mut test_b = stage("test-b", Some("test"), &["implement"]);
+        test_b.on_failure = Some(AgentPipelineTransition {
+            goto: Some("implement".to_string()),
+            ..AgentPipelineTransition::default()
+        });
+        let mut approve = stage("approve", None, &["test-a", "test-b"]);
+        approve.approval = true;
+        AgentPipelineConfig {
+            stages: vec![
+                stage("implement", Some("implement"), &[]),
+                stage("test-a", Some("test"), &["implement"]),
+                test_b,
+                approve,
+                stage("merge", Some("merge"), &["approve"]),
+            ],
+            ..AgentPipelineConfig::default()
+        }
+    }
+
+    #[test]
+    fn validate_accepts_fan_out_with_approval_and_goto() {
+        let config = config_with_agents(&["implement", "test", "merge"]);
+        AgentPipelineService::validate("deliver", &fan_out(), &config).expect("valid pipeline");
+        assert_eq!(descendants(&fan_out(), 0), vec![0, 1, 2, 3, 4]);
+        assert_eq!(ancestors(&fan_out(), 4), HashSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn validate_rejects_cycles_unknown_agents_and_forward_goto() {
+        let config = config_with_agents(&["implement", "test", "merge"]);
+
+        let mut cyclic = fan_out();
+        cyclic.stages[0].needs = vec!["merge".to_string()];
+        let err = AgentPipelineService::validate("deliver", &cyclic, &config).unwrap_err();
+        assert!(err.to_string().contains("cycle"), "{err}");
+
+        let mut unknown = fan_out();
+        unknown.stages[4].agent = Some("deploy".to_string());
+        let err = AgentPipelineService::validate("deliver", &unknown, &config).unwrap_err();
+        assert!(err.to_string().contains("unknown agent 'deploy'"), "{err}");
+
+        let mut forward = fan_out();
+        forward.stages[0].on_failure = Some(AgentPipelineTransition {
+            goto: Some("merge".to_string()),
+            ..AgentPipelineTransition::default()
+        });
+        let err = AgentPipelineService::validate("deliver", &forward, &config).unwrap_err();
+        assert!(err.to_string().contains("can only goto"), "{err}");
+    }
+}
@@ -67,6 +67,7 @@ pub fn build_runner_command(
     kind: AgentRunnerKind,
     profile: &AgentProfileDetail,
     prompt: &str,
+    resume_session: Option<&str>,
 ) -> LoTaRResult<RunnerCommand> {
     if kind == AgentRunnerKind::Command {
         let program = profile.command.clone().ok_or_else(|| {
@@ -113,6 +114,12 @@ pub fn build_runner_command(
         args.extend(profile.args.iter().cloned());
     }
 
+    if let Some(session_id) = resume_session
+        && let Some(resume) = resume_args(kind, session_id)
+    {
+        args.extend(resume);
+    }
+
     args.push(prompt.to_string());
 
     let env = expand_env_map(&profile.env);
@@ -386,6 +393,30 @@ pub fn validate_runner_command(cmd: &RunnerCommand) -> LoTaRResult<()> {
     Ok(())
 }
 
+/// Returns whether the runner can continue a previous session by id.
+pub fn supports_resume(kind: AgentRunnerKind) -> bool {
+    match kind {
+        AgentRunnerKind::Claude | AgentRunnerKind::Copilot | AgentRunnerKind::Codex => true,
+        AgentRunnerKind::Gemini | AgentRunnerKind::Command => false,
+    }
+}
+
+/// Arguments that make the runner continue an existing session.
+/// They are inserted right before the prompt argument.
+fn resume_args(kind: AgentRunnerKind, session_id: &str) -> Option<Vec<String>> {
+    let session_id = session_id.trim();
+    if session_id.is_empty() {
+        return None;
+    }
+    match kind {
+        AgentRunnerKind::Claude | AgentRunnerKind::Copilot => {
+            Some(vec!["--resume".to_string(), session_id.to_string()])
+        }
+        AgentRunnerKind::Codex => Some(vec!["resume".to_string(), session_id.to_string()]),
+        AgentRunnerKind::Gemini | AgentRunnerKind::Command => None,
+    }
+}
+
 /// Returns whether stdin should be piped for this runner kind.
 /// When true, the runner accepts user messages via stdin.
 pub fn supports_stdin(kind: AgentRunnerKind) -> bool {
@@ -520,6 +551,23 @@ mod tests {
         assert_eq!(parsed["message"]["content"], "hello world");
     }
 
+    #[test]
+    fn build_runner_command_inserts_resume_before_prompt() {
+        let profile =
+            crate::config::types::AgentProfileConfig::Runner("claude".to_string()).to_detail();
+        let cmd = build_runner_command(AgentRunnerKind::Claude, &profile, "go on", Some("abc-1"))
+            .unwrap();
+        let len = cmd.args.len();
+        assert_eq!(cmd.args[len - 3], "--resume");
+        assert_eq!(cmd.args[len - 2], "abc-1");
+        assert_eq!(cmd.args[len - 1], "go on");
+
+        let gemini =
+            build_runner_command(AgentRunnerKind::Gemini, &profile, "go on", Some("abc-1"))
+                .unwrap();
+        assert!(!gemini.args.iter().any(|arg| arg == "abc-1"));
+    }
+
     #[test]
     fn format_stdin_message_unsupported() {
         assert!(format_stdin_message(AgentRunnerKind::Codex, "hello").is_none());
@@ -0,0 +1,193 @@
+//! Discovery of git worktrees created for agent jobs.
+//!
+//! Shared by `lotar agent worktree` and `lotar doctor`.
+
+use crate::config::types::ResolvedConfig;
+use crate::services::agent_job_service::AgentJobService;
+use crate::storage::manager::Storage;
+use serde::Serialize;
+use std::path::Path;
+
+/// Information about an agent worktree
+#[derive(Debug, Clone, Serialize)]
+pub struct WorktreeInfo {
+    pub path: String,
+    pub branch: String,
+    pub ticket_id: String,
+    /// Whether the ticket is in a "done" state
+    pub is_done: bool,
+    /// Whether there's an active job for this ticket
+    pub has_active_job: bool,
+}
+
+/// List all agent worktrees
+pub fn list_agent_worktrees(
+    tasks_dir: &Path,
+    config: &ResolvedConfig,
+) -> Result<Vec<WorktreeInfo>, String> {
+    let repo_root = match crate::utils::git::find_repo_root(tasks_dir) {
+        Some(root) => root,
+        None => return Ok(Vec::new()),
+    };
+
+    let branch_prefix = &config.agent_worktree.branch_prefix;
+
+    // Get list of worktrees from git
+    let output = std::process::Command::new("git")
+        .args(["worktree", "list", "--porcelain"])
+        .current_dir(&repo_root)
+        .output()
+        .map_err(|e| format!("Failed to run git worktree list: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("git worktree list failed: {}", stderr.trim()));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let worktrees = parse_git_worktree_list(&stdout, branch_prefix);
+
+    // Enrich with task status information
+    let storage = Storage::new(tasks_dir);
+
+    let mut results = Vec::new();
+    for (path, branch) in worktrees {
+        let ticket_id = extract_ticket_from_branch(&branch, branch_prefix);
+        let is_done = check_ticket_done(&storage, &ticket_id);
+        let has_active_job = AgentJobService::has_active_job(&ticket_id);
+
+        results.push(WorktreeInfo {
+            path,
+            branch,
+            ticket_id,
+            is_done,
+            has_active_job,
+        });
+    }
+
+    Ok(results)
+}
+
+/// Parse git worktree list --porcelain output
+pub fn parse_git_worktree_list(output: &str, branch_prefix: &str) -> Vec<(String, String)> {
+    let mut results = Vec::new();
+    let mut current_path: Option<String> = None;
+    let mut current_branch: Option<String> = None;
+
+    for line in output.lines() {
+        if let Some(path) = line.strip_prefix("worktree ") {
+            // Flush previous worktree if any
+            if let (Some(path), Some(branch)) = (current_path.take(), current_branch.take())
+                && branch.starts_with(branch_prefix)
+            {
+                results.push((path, branch));
+            }
+            current_path = Some(path.to_string());
+        } else if let Some(branch) = line.strip_prefix("branch refs/heads/") {
+            current_branch = Some(branch.to_string());
+        } else if line.is_empty() {
+            // End of worktree block - flush
+            if let (Some(path), Some(branch)) = (current_path.take(), current_branch.take())
+                && branch.starts_with(branch_prefix)
+            {
+                results.push((path, branch));
+            }
+        }
+    }
+
+    // Flush last entry
+    if let (Some(path), Some(branch)) = (current_path, current_branch)
+        && branch.starts_with(branch_prefix)
+    {
+        results.push((path, branch));
+    }
+
+    results
+}
+
+/// Extract ticket ID from branch name
+pub fn extract_ticket_from_branch(branch: &str, prefix: &str) -> String {
+    branch
+        .strip_prefix(prefix)
+        .unwrap_or(branch)
+        .trim_start_matches('/')
+        .to_string()
+}
+
+/// Check if a ticket is in a "done" state
+pub fn check_ticket_done(storage: &Storage, ticket_id: &str) -> bool {
+    // Derive project prefix from ID (e.g., ABCD-1 -> ABCD)
+    let derived = ticket_id.split('-').next().unwrap_or("");
+    match storage.get(ticket_id, derived) {
+        Some(task) => {
+            let status_lower = task.status.as_str().to_lowercase();
+            status_lower == "done" || status_lower == "closed" || status_lower == "completed"
+        }
+        None => {
+            // Task not found - consider it "done" for cleanup purposes
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract_ticket_from_branch, parse_git_worktree_list};
+
+    #[test]
+    fn parses_git_worktree_list_output() {
+        let output = r#"worktree /Users/test/repo
+HEAD abc123
+branch refs/heads/main
+
+worktree /Users/test/.lotar-worktrees/repo/TEST-1
+HEAD def456
+branch refs/heads/agent/TEST-1
+
+worktree /Users/test/.lotar-worktrees/repo/TEST-2
+HEAD ghi789
+branch refs/heads/agent/TEST-2
+
+"#;
+        let results = parse_git_worktree_list(output, "agent/");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "/Users/test/.lotar-worktrees/repo/TEST-1");
+        assert_eq!(results[0].1, "agent/TEST-1");
+        assert_eq!(results[1].0, "/Users/test/.lotar-worktrees/repo/TEST-2");
+        assert_eq!(results[1].1, "agent/TEST-2");
+    }
+
+    #[test]
+    fn parses_git_worktree_list_with_custom_prefix() {
+        let output = r#"worktree /Users/test/repo
+HEAD abc123
+branch refs/heads/main
+
+worktree /Users/test/.lotar-worktrees/repo/TEST-1
+HEAD def456
+branch refs/heads/my-agent/TEST-1
+
+"#;
+        let results = parse_git_worktree_list(output, "my-agent/");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].1, "my-agent/TEST-1");
+    }
+
+    #[test]
+    fn extracts_ticket_from_branch() {
+        assert_eq!(
+            extract_ticket_from_branch("agent/TEST-1", "agent/"),
+            "TEST-1"
+        );
+        assert_eq!(
+            extract_ticket_from_branch("agent/PROJ-42", "agent/"),
+            "PROJ-42"
+        );
+        assert_eq!(
+            extract_ticket_from_branch("custom-prefix/ABC-1", "custom-prefix/"),
+            "ABC-1"
+        );
+        // Edge case: no match falls back to full branch name
+        assert_eq!(extract_ticket_from_branch("main", "agent/"), "main");
+    }
+}
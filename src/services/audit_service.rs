@@ -8,6 +8,7 @@ use std::process::Command;
 
 use crate::storage::task::Task;
 use crate::types::{TaskChange, TaskChangeLogEntry};
+use crate::utils::member::MemberDirectory;
 
 #[derive(Debug, Serialize, Clone)]
 pub struct FileCommitEvent {
@@ -75,6 +76,9 @@ struct ParsedCommit {
     files: Vec<PathBuf>,
 }
 
+/// Status values a task held, oldest first, with the commit time of each change.
+pub type StatusTransitions = Vec<(DateTime<Utc>, String)>;
+
 pub struct AuditService;
 
 fn classify_change(field: &str) -> &'static str {
@@ -848,6 +852,74 @@ impl AuditService {
         Ok(String::from_utf8_lossy(&output.stdout).to_string())
     }
 
+    /// Status values each task file under `dir_rel` held over time, oldest first, keyed by
+    /// repo-relative path. One `git log -p` covers the whole directory; only commits that
+    /// change a top-level `status:` line produce an entry.
+    pub fn list_status_transitions_under(
+        repo_root: &Path,
+        dir_rel: &Path,
+    ) -> Result<HashMap<String, StatusTransitions>, String> {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(repo_root)
+            .args([
+                "log",
+                "--no-merges",
+                "--no-renames",
+                "--no-color",
+                "--no-ext-diff",
+                "--unified=0",
+                "--format=%x1e%cI",
+                "-p",
+                "--",
+            ])
+            .arg(Self::git_path_arg(dir_rel))
+            .output()
+            .map_err(|e| format!("Failed to run git: {}", e))?;
+        if !output.status.success() {
+            return Err(format!(
+                "git log failed (status {}): {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut changes: HashMap<String, StatusTransitions> = HashMap::new();
+        for record in stdout.split('\x1e') {
+            let mut lines = record.lines();
+            let Some(date) = lines
+                .next()
+                .and_then(|raw| chrono::DateTime::parse_from_rfc3339(raw.trim()).ok())
+                .map(|dt| dt.with_timezone(&Utc))
+            else {
+                continue;
+            };
+            let mut file: Option<&str> = None;
+            for line in lines {
+                if let Some(paths) = line.strip_prefix("diff --git ") {
+                    file = paths.split_once(" b/").map(|(_, b)| b);
+                } else if let Some(value) = line.strip_prefix("+status:")
+                    && let Some(file) = file
+                    && let Some(status) = serde_yaml::from_str::<serde_yaml::Value>(value)
+                        .ok()
+                        .and_then(|v| v.as_str().map(str::to_string))
+                {
+                    changes
+                        .entry(file.to_string())
+                        .or_default()
+                        .push((date, status));
+                }
+            }
+        }
+
+        for transitions in changes.values_mut() {
+            transitions.sort_by_key(|(at, _)| *at);
+            transitions.dedup_by(|(_, next), (_, last)| last.eq_ignore_ascii_case(next));
+        }
+        Ok(changes)
+    }
+
     /// Get raw patch for a specific commit restricted to file
     pub fn show_file_diff(
         repo_root: &Path,
@@ -1018,6 +1090,7 @@ impl AuditService {
         since: DateTime<Utc>,
         until: DateTime<Utc>,
         project_filter: Option<&str>,
+        members: &MemberDirectory,
     ) -> Result<Vec<AuthorActivity>, String> {
         let mut cmd = Command::new("git");
         cmd.arg("-C").arg(repo_root);
@@ -1063,7 +1136,17 @@ impl AuditService {
                         Err(_) => continue,
                     };
 
-                    let key = (author.clone(), email.clone());
+                    // Known members are grouped under their handle regardless of git identity.
+                    let (key, author) = match members.find_git(&author, &email) {
+                        Some(profile) => (
+                            (profile.handle.clone(), String::new()),
+                            profile
+                                .name
+                                .clone()
+                                .unwrap_or_else(|| profile.handle.clone()),
+                        ),
+                        None => ((author.clone(), email.clone()), author),
+                    };
                     let entry = map.entry(key).or_insert_with(|| AuthorActivity {
                         author: author.clone(),
                         email: email.clone(),
@@ -1095,6 +1178,7 @@ impl AuditService {
         until: DateTime<Utc>,
         group_by: GroupBy,
         project_filter: Option<&str>,
+        members: &MemberDirectory,
     ) -> Result<Vec<ActivityItem>, String> {
         let mut cmd = Command::new("git");
         cmd.arg("-C").arg(repo_root);
@@ -1171,8 +1255,11 @@ impl AuditService {
                     // For non-project groups, bump immediately per commit
                     match group_by {
                         GroupBy::Author => {
-                            let (_, author, _, date) = current.as_ref().unwrap();
-                            let key = author.clone();
+                            let (_, author, email, date) = current.as_ref().unwrap();
+                            let key = match members.find_git(author, email) {
+                                Some(profile) => members.display_name(&profile.handle),
+                                None => author.clone(),
+                            };
                             let entry = map.entry(key.clone()).or_insert_with(|| ActivityItem {
                                 key: key.clone(),
                                 count: 0,
@@ -6,13 +6,15 @@ use crate::automation::types::{
     AutomationTagAction, StringOrVec,
 };
 use crate::config::manager::ConfigManager;
-use crate::config::types::ResolvedConfig;
+use crate::config::types::{AgentPipelineTransition, ResolvedConfig, SandboxConfig};
 use crate::config::validation::errors::ValidationResult;
 use crate::errors::{LoTaRError, LoTaRResult};
 use crate::services::agent_job_service::{AgentJobService, AgentOrchestratorMode};
+use crate::services::agent_log_service::AgentLogService;
 use crate::services::agent_queue_service::AgentQueueService;
 use crate::services::automation_matching::{ChangeSet, MatchMode, matches_rule};
 use crate::services::automation_validation::validate_rules;
+use crate::services::sandbox::{OutputBudget, SandboxPolicy, run_with_output_limit};
 use crate::services::sprint_metrics::determine_done_statuses_from_config;
 use crate::services::sprint_service::SprintService;
 use crate::services::sprint_status;
@@ -25,7 +27,7 @@ use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::process::Command;
 use std::sync::Mutex;
-use std::time::{Duration, Instant};
+use std::time::Instant;
 
 const DEFAULT_AUTO_PROMPT: &str = "Work on this ticket using the provided context and agent instructions. Make concrete changes in the repo (code/config/tests), run or update relevant tests, and summarize what changed and how you verified it. If you are blocked or missing information, say what you need and exit non-zero so automation can request help.";
 const DEFAULT_MAX_ITERATIONS: u32 = 10;
@@ -39,23 +41,6 @@ type CooldownKey = (String, String);
 static COOLDOWN_STATE: std::sync::LazyLock<Mutex<HashMap<CooldownKey, Instant>>> =
     std::sync::LazyLock::new(|| Mutex::new(HashMap::new()));
 
-/// Parse a human-friendly duration string ("30s", "5m", "2h", "1d") into `Duration`.
-fn parse_duration(s: &str) -> Option<Duration> {
-    let s = s.trim();
-    if s.is_empty() {
-        return None;
-    }
-    let (digits, suffix) = s.split_at(s.len() - 1);
-    let value: u64 = digits.parse().ok()?;
-    match suffix {
-        "s" => Some(Duration::from_secs(value)),
-        "m" => Some(Duration::from_secs(value * 60)),
-        "h" => Some(Duration::from_secs(value * 3600)),
-        "d" => Some(Duration::from_secs(value * 86400)),
-        _ => None,
-    }
-}
-
 /// Return a stable identity string for a rule (name if present, else index).
 fn rule_identity(rule: &AutomationRule, index: usize) -> String {
     rule.name
@@ -71,7 +56,7 @@ fn cooldown_allows(rule: &AutomationRule, rule_key: &str, ticket_id: &str) -> bo
         Some(s) => s,
         None => return true,
     };
-    let duration = match parse_duration(cooldown_str) {
+    let duration = match crate::utils::time::parse_interval(cooldown_str) {
         Some(d) => d,
         None => return true, // unparseable → don't block
     };
@@ -167,6 +152,7 @@ struct AutomationActionContext {
     job: Option<AutomationJobContext>,
     previous: Option<TaskDTO>,
     comment_text: Option<String>,
+    sandbox: Option<SandboxConfig>,
 }
 
 #[derive(Debug, Clone)]
@@ -331,6 +317,7 @@ impl AutomationService {
                         job: None,
                         previous: previous.cloned(),
                         comment_text: None,
+                        sandbox: rule.sandbox.clone(),
                     };
                     apply_action(storage, current, config, action, &action_context)?;
                     fired = true;
@@ -345,6 +332,7 @@ impl AutomationService {
                     job: None,
                     previous: previous.cloned(),
                     comment_text: None,
+                    sandbox: rule.sandbox.clone(),
                 };
                 apply_action(storage, current, config, action, &action_context)?;
                 fired = true;
@@ -400,6 +388,7 @@ impl AutomationService {
                     job: None,
                     previous: None,
                     comment_text: Some(comment_text.to_string()),
+                    sandbox: rule.sandbox.clone(),
                 };
                 apply_action(storage, task, config, action, &action_context)?;
                 cooldown_record(rule, &rk, &task.id);
@@ -424,11 +413,12 @@ impl AutomationService {
             Some(&config),
         )?;
 
-        let action_context = AutomationActionContext {
+        let job_action_context = AutomationActionContext {
             event,
             job: job_context,
             previous: None,
             comment_text: None,
+            sandbox: None,
         };
 
         for (rule_idx, rule) in automation.automation.rules().iter().enumerate() {
@@ -447,6 +437,10 @@ impl AutomationService {
                 continue;
             }
             if let Some(action) = resolve_action_for_event(&rule.on, event) {
+                let action_context = AutomationActionContext {
+                    sandbox: rule.sandbox.clone(),
+                    ..job_action_context.clone()
+                };
                 apply_action(&mut storage, &task, &config, action, &action_context)?;
                 cooldown_record(rule, &rk, ticket_id);
             }
@@ -454,6 +448,45 @@ impl AutomationService {
         Ok(())
     }
 
+    /// Apply a pipeline stage transition (status, assignee, comment) to a ticket.
+    ///
+    /// Values expand template variables and resolve assignee aliases exactly like the
+    /// equivalent automation `set`/`comment` actions.
+    pub fn apply_pipeline_transition(
+        tasks_dir: &Path,
+        ticket_id: &str,
+        transition: &AgentPipelineTransition,
+        event: AutomationEvent,
+    ) -> LoTaRResult<()> {
+        let set = (transition.status.is_some() || transition.assignee.is_some()).then(|| {
+            AutomationActionSet {
+                status: transition.status.clone(),
+                assignee: transition.assignee.clone(),
+                ..AutomationActionSet::default()
+            }
+        });
+        if set.is_none() && transition.comment.is_none() {
+            return Ok(());
+        }
+        let action = AutomationAction {
+            set,
+            comment: transition.comment.clone(),
+            ..AutomationAction::default()
+        };
+        let config =
+            resolve_config_for_project(tasks_dir, Some(ticket_id.split('-').next().unwrap_or("")))?;
+        let mut storage = Storage::new(tasks_dir);
+        let task = TaskService::get(&storage, ticket_id, None)?;
+        let action_context = AutomationActionContext {
+            event,
+            job: None,
+            previous: None,
+            comment_text: None,
+            sandbox: None,
+        };
+        apply_action(&mut storage, &task, &config, &action, &action_context)
+    }
+
     /// Simulate automation rules without applying changes
     pub fn simulate(
         tasks_dir: &Path,
@@ -843,6 +876,10 @@ fn load_effective_automation(
         return Ok((file, AutomationScope::Global));
     }
 
+    if let Some(file) = automation_persistence::load_inherited_automation(tasks_dir)? {
+        return Ok((file, AutomationScope::Global));
+    }
+
     // No automation file found - return empty ruleset
     Ok((AutomationFile::default(), AutomationScope::BuiltIn))
 }
@@ -1012,8 +1049,29 @@ fn execute_run_action(
         }
     };
 
-    let cwd = resolve_run_cwd(tasks_dir, cwd.as_deref())?;
+    let sandbox = context
+        .sandbox
+        .as_ref()
+        .map(SandboxPolicy::from_config)
+        .transpose()?;
+    let worktree = context
+        .job
+        .as_ref()
+        .and_then(|job| job.worktree_path.as_deref())
+        .map(Path::new);
+    // Sandboxed job hooks run in the job's worktree unless a cwd is given.
+    let cwd = match (cwd.as_deref(), worktree, sandbox.as_ref()) {
+        (None, Some(worktree), Some(_)) => worktree.to_path_buf(),
+        (cwd, _, _) => resolve_run_cwd(tasks_dir, cwd)?,
+    };
     let mut cmd = Command::new(command);
+    if let Some(sandbox) = sandbox.as_ref()
+        && let Err(reason) = sandbox
+            .check_working_dir(&cwd, worktree)
+            .and_then(|()| sandbox.apply(&mut cmd))
+    {
+        return Err(sandbox_violation(task, config, tasks_dir, context, &reason));
+    }
     cmd.args(args).current_dir(cwd);
 
     let mut merged_env = build_automation_env(task, config, tasks_dir, context);
@@ -1022,8 +1080,61 @@ fn execute_run_action(
     }
     cmd.envs(merged_env);
 
+    // Output limits need piped output, so these runs take a separate path.
+    if let Some((sandbox, limit)) = sandbox
+        .as_ref()
+        .and_then(|sandbox| sandbox.max_output().map(|limit| (sandbox.clone(), limit)))
+    {
+        let budget = OutputBudget::new(limit);
+        if wait {
+            let run = run_with_output_limit(&mut cmd, budget, true)?;
+            if let Some(reason) = sandbox_exit_reason(&run, &sandbox) {
+                return Err(sandbox_violation(task, config, tasks_dir, context, &reason));
+            }
+            if !run.status.success() && !ignore_failure {
+                return Err(LoTaRError::ValidationError(format!(
+                    "Automation run command failed with status {}",
+                    run.status
+                )));
+            }
+        } else {
+            let task = task.clone();
+            let config = config.clone();
+            let tasks_dir = tasks_dir.to_path_buf();
+            let context = context.clone();
+            std::thread::spawn(
+                move || match run_with_output_limit(&mut cmd, budget, false) {
+                    Ok(run) => {
+                        if let Some(reason) = sandbox_exit_reason(&run, &sandbox) {
+                            let err =
+                                sandbox_violation(&task, &config, &tasks_dir, &context, &reason);
+                            eprintln!("[lotar][warn] {}", err);
+                        } else if !run.status.success() {
+                            eprintln!(
+                                "[lotar][warn] Async automation run for task {} exited with {}: {}",
+                                task.id,
+                                run.status,
+                                run.stderr.trim()
+                            );
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!(
+                            "[lotar][warn] Async automation run for task {} failed to complete: {}",
+                            task.id, e
+                        );
+                    }
+                },
+            );
+        }
+        return Ok(());
+    }
+
     if wait {
         let status = cmd.status()?;
+        if let Some(reason) = sandbox.as_ref().and_then(|s| s.describe_exit(&status)) {
+            return Err(sandbox_violation(task, config, tasks_dir, context, &reason));
+        }
         if !status.success() && !ignore_failure {
             return Err(LoTaRError::ValidationError(format!(
                 "Automation run command failed with status {}",
@@ -1073,6 +1184,43 @@ fn execute_run_action(
     Ok(())
 }
 
+/// Pick the sandbox violation, if any, that ended a budgeted run.
+fn sandbox_exit_reason(
+    run: &crate::services::sandbox::LimitedRun,
+    sandbox: &SandboxPolicy,
+) -> Option<String> {
+    run.violation
+        .clone()
+        .or_else(|| sandbox.describe_exit(&run.status))
+}
+
+/// Build the error for a sandboxed `run` command that broke its limits.
+/// Job hooks also record the violation in the job's agent log.
+fn sandbox_violation(
+    task: &TaskDTO,
+    config: &ResolvedConfig,
+    tasks_dir: &Path,
+    context: &AutomationActionContext,
+    reason: &str,
+) -> LoTaRError {
+    let message = format!(
+        "Sandbox: automation run command for {} stopped: {}",
+        task.id, reason
+    );
+    if let Some(job) = context.job.as_ref() {
+        let workspace_root = tasks_dir.parent().unwrap_or(tasks_dir);
+        let _ = AgentLogService::append_event(
+            workspace_root,
+            config.agent_logs_dir.as_deref(),
+            &job.job_id,
+            "automation_sandbox_violation",
+            &chrono::Utc::now().to_rfc3339(),
+            Some(message.clone()),
+        );
+    }
+    LoTaRError::ValidationError(message)
+}
+
 fn apply_action(
     storage: &mut Storage,
     current: &TaskDTO,
@@ -4,6 +4,8 @@ use crate::automation::types::{
 };
 use crate::config::types::ResolvedConfig;
 use crate::config::validation::errors::{ValidationError, ValidationResult};
+use crate::errors::LoTaRError;
+use crate::services::sandbox::SandboxPolicy;
 use crate::types::{Priority, TaskStatus, TaskType};
 
 pub(crate) fn validate_rules(file: &AutomationFile, config: &ResolvedConfig) -> ValidationResult {
@@ -12,6 +14,14 @@ pub(crate) fn validate_rules(file: &AutomationFile, config: &ResolvedConfig) ->
         if let Some(group) = rule.when.as_ref() {
             validate_condition_group(group, config, &mut result);
         }
+        if let Some(sandbox) = rule.sandbox.as_ref()
+            && let Err(LoTaRError::ValidationError(message)) = SandboxPolicy::from_config(sandbox)
+        {
+            result.add_error(ValidationError::warning(
+                Some("automation".to_string()),
+                message,
+            ));
+        }
         // Legacy catch-all
         validate_action(rule.on.start.as_ref(), config, &mut result);
         // Ticket events
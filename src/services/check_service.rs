@@ -0,0 +1,342 @@
+use crate::config::manager::ConfigManager;
+use crate::config::types::{GlobalConfig, ResolvedConfig};
+use crate::errors::{LoTaRError, LoTaRResult};
+use crate::services::scan_service::{read_line, resolve_scan_config};
+use crate::services::sprint_metrics::determine_done_statuses_from_config;
+use crate::storage::manager::Storage;
+use crate::utils::paths::repo_relative_display;
+use crate::workspace::TasksDirectoryResolver;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Policy rules enforced by `lotar check`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckRule {
+    /// A TODO comment without a ticket key
+    Unlinked,
+    /// A ticket key that does not resolve to an existing task
+    Unknown,
+    /// A ticket key whose task is already in a done status
+    Done,
+}
+
+impl CheckRule {
+    pub const ALL: [CheckRule; 3] = [CheckRule::Unlinked, CheckRule::Unknown, CheckRule::Done];
+
+    /// Stable rule identifier used in SARIF output and annotations.
+    pub fn id(self) -> &'static str {
+        match self {
+            CheckRule::Unlinked => "lotar/unlinked-todo",
+            CheckRule::Unknown => "lotar/unknown-ticket",
+            CheckRule::Done => "lotar/done-ticket",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            CheckRule::Unlinked => "TODO comment is not linked to a ticket",
+            CheckRule::Unknown => "TODO comment references a ticket that does not exist",
+            CheckRule::Done => "TODO comment references a ticket that is already done",
+        }
+    }
+}
+
+/// Which lines of the working tree are subject to the check.
+#[derive(Debug, Clone)]
+pub enum CheckDiff {
+    /// Lines added in the index (`git diff --cached`)
+    Staged,
+    /// Lines added since the merge base with the given ref
+    Base(String),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CheckRequest {
+    pub paths: Vec<String>,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub modified_only: bool,
+    pub diff: Option<CheckDiff>,
+    pub project: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckFinding {
+    pub rule: CheckRule,
+    pub file: String,
+    pub line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    pub title: String,
+    pub message: String,
+}
+
+pub struct CheckService;
+
+impl CheckService {
+    /// Scan the requested paths and report TODO comments that violate the ticket policy.
+    pub fn run(
+        resolver: &TasksDirectoryResolver,
+        request: &CheckRequest,
+    ) -> LoTaRResult<Vec<CheckFinding>> {
+        let roots: Vec<PathBuf> = if request.paths.is_empty() {
+            vec![crate::project::get_project_path().unwrap_or_else(|| PathBuf::from("."))]
+        } else {
+            request.paths.iter().map(PathBuf::from).collect()
+        };
+        let mut canonical_roots = Vec::with_capacity(roots.len());
+        for root in roots {
+            let canonical = root.canonicalize().map_err(|_| {
+                LoTaRError::ValidationError(format!("Path '{}' does not exist", root.display()))
+            })?;
+            canonical_roots.push(canonical);
+        }
+
+        let added_lines = match &request.diff {
+            Some(diff) => {
+                let start = canonical_roots
+                    .first()
+                    .map(PathBuf::as_path)
+                    .unwrap_or(Path::new("."));
+                let repo_root = crate::utils::git::find_repo_root(start).ok_or_else(|| {
+                    LoTaRError::ValidationError("Diff mode requires a git repository".to_string())
+                })?;
+                Some(added_lines(&repo_root, diff)?)
+            }
+            None => None,
+        };
+
+        let scan_config = resolve_scan_config(resolver, request.project.as_deref());
+        // Staged changes are always part of `git status`, so limit the walk to those files.
+        let modified_only =
+            request.modified_only || matches!(request.diff, Some(CheckDiff::Staged));
+        let mut references = Vec::new();
+        for root in canonical_roots {
+            let mut scan = scan_config
+                .scanner(root)
+                .with_include_ext(&request.include)
+                .with_exclude_ext(&request.exclude)
+                .with_modified_only(modified_only);
+            references.extend(scan.scan());
+        }
+        references.sort_by(|a, b| {
+            a.file_path
+                .cmp(&b.file_path)
+                .then(a.line_number.cmp(&b.line_number))
+        });
+        references.dedup_by(|a, b| a.file_path == b.file_path && a.line_number == b.line_number);
+
+        let line_scanner = scan_config.scanner(PathBuf::from("."));
+        let storage = Storage::try_open(&resolver.path);
+        let manager =
+            ConfigManager::new_manager_with_tasks_dir_readonly(resolver.path.as_path()).ok();
+        let mut done_statuses: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut findings = Vec::new();
+
+        for reference in references {
+            let file = repo_relative_display(&reference.file_path);
+            if let Some(added) = &added_lines
+                && !added
+                    .get(&file)
+                    .is_some_and(|lines| lines.contains(&reference.line_number))
+            {
+                continue;
+            }
+
+            let Ok(line) = read_line(&reference.file_path, reference.line_number) else {
+                continue;
+            };
+            let title = reference.title.trim().to_string();
+            let Some(key) = line_scanner.extract_ticket_key_from_line(&line) else {
+                findings.push(CheckFinding {
+                    rule: CheckRule::Unlinked,
+                    file,
+                    line: reference.line_number,
+                    ticket: None,
+                    status: None,
+                    message: format!("TODO is not linked to a ticket: {}", title),
+                    title,
+                });
+                continue;
+            };
+
+            let prefix = key.split('-').next().unwrap_or("").to_string();
+            let task = storage.as_ref().and_then(|s| s.get(&key, &prefix));
+            let Some(task) = task else {
+                findings.push(CheckFinding {
+                    rule: CheckRule::Unknown,
+                    file,
+                    line: reference.line_number,
+                    message: format!("TODO references unknown ticket {}", key),
+                    ticket: Some(key),
+                    status: None,
+                    title,
+                });
+                continue;
+            };
+
+            let done = done_statuses.entry(prefix.clone()).or_insert_with(|| {
+                let config = manager
+                    .as_ref()
+                    .map(|m| {
+                        m.get_project_config(&prefix)
+                            .unwrap_or_else(|_| m.get_resolved_config().clone())
+                    })
+                    .unwrap_or_else(|| ResolvedConfig::from_global(GlobalConfig::default()));
+                determine_done_statuses_from_config(&config)
+            });
+            let status = task.status.as_str().to_string();
+            if done.contains(&status.to_ascii_lowercase()) {
+                findings.push(CheckFinding {
+                    rule: CheckRule::Done,
+                    file,
+                    line: reference.line_number,
+                    message: format!("TODO references {} which is already {}", key, status),
+                    ticket: Some(key),
+                    status: Some(status),
+                    title,
+                });
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+/// Collect added line numbers per repo-relative file from `git diff -U0`.
+fn added_lines(repo_root: &Path, diff: &CheckDiff) -> LoTaRResult<HashMap<String, HashSet<usize>>> {
+    let mut cmd = Command::new("git");
+    cmd.arg("-C")
+        .arg(repo_root)
+        .args(["diff", "--no-color", "--no-ext-diff", "--unified=0"]);
+    match diff {
+        CheckDiff::Staged => {
+            cmd.arg("--cached");
+        }
+        CheckDiff::Base(base) => {
+            cmd.arg(merge_base(repo_root, base)?);
+        }
+    }
+    cmd.arg("--");
+
+    let output = cmd
+        .output()
+        .map_err(|e| LoTaRError::ValidationError(format!("Failed to run git: {}", e)))?;
+    if !output.status.success() {
+        return Err(LoTaRError::ValidationError(format!(
+            "git diff failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(parse_unified_diff(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Resolve the merge base between `base` and HEAD so that unrelated upstream changes are ignored.
+fn merge_base(repo_root: &Path, base: &str) -> LoTaRResult<String> {
+    // A leading `-` would be read as a git option rather than a revision.
+    if base.starts_with('-') {
+        return Err(LoTaRError::ValidationError(format!(
+            "Invalid base ref '{}': must not start with '-'",
+            base
+        )));
+    }
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(["merge-base", base, "HEAD"])
+        .output()
+        .map_err(|e| LoTaRError::ValidationError(format!("Failed to run git: {}", e)))?;
+    if output.status.success() {
+        let sha = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if !sha.is_empty() {
+            return Ok(sha);
+        }
+    }
+
+    let verify = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(["rev-parse", "--verify", "--quiet"])
+        .arg(format!("{}^{{commit}}", base))
+        .output()
+        .map_err(|e| LoTaRError::ValidationError(format!("Failed to run git: {}", e)))?;
+    if verify.status.success() {
+        Ok(base.to_string())
+    } else {
+        Err(LoTaRError::ValidationError(format!(
+            "Unknown git ref '{}'",
+            base
+        )))
+    }
+}
+
+fn parse_unified_diff(diff: &str) -> HashMap<String, HashSet<usize>> {
+    let mut added: HashMap<String, HashSet<usize>> = HashMap::new();
+    let mut current: Option<String> = None;
+    for line in diff.lines() {
+        if let Some(path) = line.strip_prefix("+++ ") {
+            current = path.strip_prefix("b/").map(str::to_string);
+            continue;
+        }
+        let (Some(file), Some(hunk)) = (current.as_ref(), line.strip_prefix("@@ ")) else {
+            continue;
+        };
+        let Some(range) = hunk
+            .split_whitespace()
+            .find_map(|part| part.strip_prefix('+'))
+        else {
+            continue;
+        };
+        let (start, count) = match range.split_once(',') {
+            Some((start, count)) => (start.parse::<usize>(), count.parse::<usize>()),
+            None => (range.parse::<usize>(), Ok(1)),
+        };
+        if let (Ok(start), Ok(count)) = (start, count) {
+            added
+                .entry(file.clone())
+                .or_default()
+                .extend(start..start + count);
+        }
+    }
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unified_diff_collects_added_lines_per_file() {
+        let diff = "diff --git a/src/a.rs b/src/a.rs\n\
+--- a/src/a.rs\n\
++++ b/src/a.rs\n\
+@@ -1,0 +2,2 @@ fn main() {\n\
++// TODO: one\n\
++// TODO: two\n\
+@@ -10 +12 @@\n\
+-old\n\
++new\n\
+@@ -20,3 +22,0 @@\n\
+diff --git a/gone.rs b/gone.rs\n\
+--- a/gone.rs\n\
++++ /dev/null\n\
+@@ -1 +0,0 @@\n";
+        let added = parse_unified_diff(diff);
+        let lines = added.get("src/a.rs").expect("file entry");
+        let mut sorted: Vec<_> = lines.iter().copied().collect();
+        sorted.sort_unstable();
+        assert_eq!(sorted, vec![2, 3, 12]);
+        assert!(!added.contains_key("gone.rs"));
+    }
+
+    #[test]
+    fn base_refs_that_look_like_options_are_rejected() {
+        let err = merge_base(Path::new("."), "--output=/tmp/x").unwrap_err();
+        assert!(err.to_string().contains("must not start with '-'"), "{err}");
+    }
+}
@@ -0,0 +1,419 @@
+use crate::api_types::TaskUpdate;
+use crate::config::manager::ConfigManager;
+use crate::config::types::{GlobalConfig, ResolvedConfig};
+use crate::errors::{LoTaRError, LoTaRResult};
+use crate::services::reference_service::ReferenceService;
+use crate::services::task_service::TaskService;
+use crate::storage::manager::Storage;
+use crate::types::TaskStatus;
+use crate::workspace::TasksDirectoryResolver;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+use std::sync::LazyLock;
+
+const OUTCOME_LINKED: &str = "linked";
+const OUTCOME_ALREADY_LINKED: &str = "already_linked";
+const OUTCOME_UNKNOWN_TASK: &str = "unknown_task";
+
+static TICKET_KEY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[A-Z][A-Z0-9_]*-\d+$").expect("valid ticket key regex"));
+
+/// A ticket key found in a commit message with the words that introduced it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitMention {
+    pub key: String,
+    /// Lowercase keywords directly preceding the key (e.g. `fixes` in "fixes PROJ-12").
+    pub keywords: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CommitInfo {
+    pub sha: String,
+    pub message: String,
+}
+
+/// Which commits `lotar git ingest` reads.
+#[derive(Debug, Clone)]
+pub enum CommitRange {
+    /// Commits reachable from HEAD but not from the ref
+    Since(String),
+    /// Explicit revisions (e.g. `HEAD` from a post-commit hook)
+    Revisions(Vec<String>),
+    /// Full history of HEAD
+    All,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CommitIngestEntry {
+    pub commit: String,
+    pub task_id: String,
+    /// `linked`, `already_linked` or `unknown_task`
+    pub outcome: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
+    /// Status applied (or planned on dry run) because of a configured keyword.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+pub struct CommitIngestService;
+
+impl CommitIngestService {
+    /// Read commits (oldest first) from the repository at `repo_root`.
+    pub fn list_commits(repo_root: &Path, range: &CommitRange) -> LoTaRResult<Vec<CommitInfo>> {
+        let mut cmd = Command::new("git");
+        cmd.arg("-C")
+            .arg(repo_root)
+            .args(["log", "--reverse", "--format=%H%x1f%B%x1e"]);
+        let revisions: &[String] = match range {
+            CommitRange::Since(since) => std::slice::from_ref(since),
+            CommitRange::Revisions(revs) => revs,
+            CommitRange::All => &[],
+        };
+        // A leading `-` would be read as a git option rather than a revision.
+        if let Some(bad) = revisions
+            .iter()
+            .find(|rev| rev.trim_start().starts_with('-'))
+        {
+            return Err(LoTaRError::ValidationError(format!(
+                "Invalid revision '{}': must not start with '-'",
+                bad
+            )));
+        }
+        match range {
+            CommitRange::Since(since) => {
+                cmd.arg(format!("{}..HEAD", since));
+            }
+            CommitRange::Revisions(revs) => {
+                cmd.arg("--no-walk=unsorted").args(revs);
+            }
+            CommitRange::All => {
+                cmd.arg("HEAD");
+            }
+        }
+        cmd.arg("--");
+
+        let output = cmd
+            .output()
+            .map_err(|e| LoTaRError::ValidationError(format!("Failed to run git: {}", e)))?;
+        if !output.status.success() {
+            return Err(LoTaRError::ValidationError(format!(
+                "git log failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .split('\x1e')
+            .filter_map(|record| {
+                let (sha, message) = record.trim_start_matches('\n').split_once('\x1f')?;
+                Some(CommitInfo {
+                    sha: sha.trim().to_string(),
+                    message: message.trim().to_string(),
+                })
+            })
+            .filter(|commit| !commit.sha.is_empty())
+            .collect())
+    }
+
+    /// Link each commit to the tasks it mentions and apply keyword transitions.
+    ///
+    /// A task that already references the commit is left untouched, so re-running the
+    /// same range is a no-op.
+    pub fn ingest(
+        resolver: &TasksDirectoryResolver,
+        commits: &[CommitInfo],
+        dry_run: bool,
+    ) -> LoTaRResult<Vec<CommitIngestEntry>> {
+        let Some(mut storage) = Storage::try_open(&resolver.path) else {
+            return Ok(Vec::new());
+        };
+        let mut keywords = KeywordLookup::new(resolver);
+        let mut entries = Vec::new();
+
+        for commit in commits {
+            for mention in parse_commit_message(&commit.message) {
+                let prefix = mention.key.split('-').next().unwrap_or("").to_string();
+                let Some(task) = storage.get(&mention.key, &prefix) else {
+                    entries.push(CommitIngestEntry {
+                        commit: commit.sha.clone(),
+                        task_id: mention.key,
+                        outcome: OUTCOME_UNKNOWN_TASK.to_string(),
+                        keyword: None,
+                        status: None,
+                        message: None,
+                    });
+                    continue;
+                };
+
+                let already = task
+                    .references
+                    .iter()
+                    .any(|r| r.commit.as_deref() == Some(commit.sha.as_str()));
+                if already {
+                    entries.push(CommitIngestEntry {
+                        commit: commit.sha.clone(),
+                        task_id: mention.key,
+                        outcome: OUTCOME_ALREADY_LINKED.to_string(),
+                        keyword: None,
+                        status: None,
+                        message: None,
+                    });
+                    continue;
+                }
+
+                let transition = keywords.transition(&prefix, &mention.keywords);
+                let status = transition
+                    .as_ref()
+                    .filter(|(_, status)| !task.status.eq_ignore_case(status.as_str()))
+                    .map(|(_, status)| status.clone());
+                let mut message = None;
+                if !dry_run {
+                    ReferenceService::attach_platform_reference(
+                        &mut storage,
+                        &mention.key,
+                        "commit",
+                        &commit.sha,
+                    )?;
+                    if let Some(status) = &status {
+                        let patch = TaskUpdate {
+                            status: Some(status.clone()),
+                            ..TaskUpdate::default()
+                        };
+                        if let Err(err) = TaskService::update(&mut storage, &mention.key, patch) {
+                            message = Some(format!("Status not changed: {}", err));
+                        }
+                    }
+                }
+
+                entries.push(CommitIngestEntry {
+                    commit: commit.sha.clone(),
+                    task_id: mention.key,
+                    outcome: OUTCOME_LINKED.to_string(),
+                    keyword: transition.map(|(keyword, _)| keyword),
+                    status: if message.is_none() {
+                        status.map(|s| s.as_str().to_string())
+                    } else {
+                        None
+                    },
+                    message,
+                });
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Preview what a commit message will do once committed (used by the `commit-msg` hook).
+    pub fn preview_message(
+        resolver: &TasksDirectoryResolver,
+        message: &str,
+    ) -> Vec<CommitIngestEntry> {
+        let storage = Storage::try_open(&resolver.path);
+        let mut keywords = KeywordLookup::new(resolver);
+        parse_commit_message(&strip_comment_lines(message))
+            .into_iter()
+            .map(|mention| {
+                let prefix = mention.key.split('-').next().unwrap_or("").to_string();
+                let exists = storage
+                    .as_ref()
+                    .is_some_and(|s| s.get(&mention.key, &prefix).is_some());
+                let transition = if exists {
+                    keywords.transition(&prefix, &mention.keywords)
+                } else {
+                    None
+                };
+                CommitIngestEntry {
+                    commit: String::new(),
+                    outcome: if exists {
+                        OUTCOME_LINKED
+                    } else {
+                        OUTCOME_UNKNOWN_TASK
+                    }
+                    .to_string(),
+                    task_id: mention.key,
+                    keyword: transition.as_ref().map(|(keyword, _)| keyword.clone()),
+                    status: transition.map(|(_, status)| status.as_str().to_string()),
+                    message: None,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Per-project keyword maps, resolved lazily from config.
+struct KeywordLookup {
+    manager: Option<ConfigManager>,
+    cache: HashMap<String, ResolvedConfig>,
+}
+
+impl KeywordLookup {
+    fn new(resolver: &TasksDirectoryResolver) -> Self {
+        Self {
+            manager: ConfigManager::new_manager_with_tasks_dir_readonly(resolver.path.as_path())
+                .ok(),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// First keyword that maps to a status in the project's `commits.keywords`.
+    fn transition(&mut self, prefix: &str, keywords: &[String]) -> Option<(String, TaskStatus)> {
+        let manager = &self.manager;
+        let config = self.cache.entry(prefix.to_string()).or_insert_with(|| {
+            manager
+                .as_ref()
+                .map(|m| {
+                    m.get_project_config(prefix)
+                        .unwrap_or_else(|_| m.get_resolved_config().clone())
+                })
+                .unwrap_or_else(|| ResolvedConfig::from_global(GlobalConfig::default()))
+        });
+        keywords.iter().find_map(|keyword| {
+            config
+                .commit_keywords
+                .get(keyword)
+                .map(|status| (keyword.clone(), status.clone()))
+        })
+    }
+}
+
+/// Words that cancel the keyword right after them ("don't fix PROJ-1").
+const NEGATIONS: &[&str] = &[
+    "not", "no", "never", "don't", "dont", "doesn't", "doesnt", "didn't", "didnt", "won't", "wont",
+    "cannot", "can't", "cant",
+];
+
+/// Extract ticket keys and the keywords introducing them from a commit message.
+///
+/// A keyword applies to every key in the list that follows it, so
+/// "fixes PROJ-1, PROJ-2 and PROJ-3" closes all three. Keys are reported once, in order
+/// of first appearance. Negated keywords ("don't fix") and revert commits only link.
+pub fn parse_commit_message(message: &str) -> Vec<CommitMention> {
+    let mut mentions: Vec<CommitMention> = Vec::new();
+    let mut keyword: Option<String> = None;
+    let mut negated = false;
+
+    for raw in message.split_whitespace() {
+        let raw = raw.replace('\u{2019}', "'");
+        let token = raw.trim_matches(|c: char| !c.is_ascii_alphanumeric() && c != '-' && c != '_');
+        let token = token.trim_end_matches('-');
+        if token.is_empty() || token.eq_ignore_ascii_case("and") {
+            continue;
+        }
+        if TICKET_KEY.is_match(token) {
+            match mentions.iter_mut().find(|m| m.key == token) {
+                Some(existing) => {
+                    if let Some(word) = &keyword
+                        && !existing.keywords.contains(word)
+                    {
+                        existing.keywords.push(word.clone());
+                    }
+                }
+                None => mentions.push(CommitMention {
+                    key: token.to_string(),
+                    keywords: keyword.iter().cloned().collect(),
+                }),
+            }
+            continue;
+        }
+        let word = token.to_ascii_lowercase();
+        keyword = (!negated).then(|| word.clone());
+        negated = NEGATIONS.contains(&word.as_str());
+    }
+
+    if is_revert(message) {
+        for mention in &mut mentions {
+            mention.keywords.clear();
+        }
+    }
+    mentions
+}
+
+/// Whether `message` is a `git revert` commit, whose keywords belong to the reverted one.
+fn is_revert(message: &str) -> bool {
+    message.trim_start().starts_with("Revert \"") || message.contains("This reverts commit ")
+}
+
+/// Drop git's `#` comment lines and anything below the scissors line.
+fn strip_comment_lines(message: &str) -> String {
+    message
+        .lines()
+        .take_while(|line| !line.starts_with("# ------------------------ >8"))
+        .filter(|line| !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mention(key: &str, keywords: &[&str]) -> CommitMention {
+        CommitMention {
+            key: key.to_string(),
+            keywords: keywords.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn keywords_apply_to_the_following_key_list() {
+        let parsed = parse_commit_message(
+            "Fixes PROJ-1, PROJ-2 and PROJ-3: tidy parser\n\nRefs: OPS-7 (see DOC-2).",
+        );
+        assert_eq!(
+            parsed,
+            vec![
+                mention("PROJ-1", &["fixes"]),
+                mention("PROJ-2", &["fixes"]),
+                mention("PROJ-3", &["fixes"]),
+                mention("OPS-7", &["refs"]),
+                mention("DOC-2", &["see"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn bare_and_repeated_keys_are_reported_once() {
+        let parsed = parse_commit_message("[PROJ-4] tweak copy\n\ncloses PROJ-4, not proj-5");
+        assert_eq!(parsed, vec![mention("PROJ-4", &["closes"])]);
+    }
+
+    #[test]
+    fn revisions_that_look_like_options_are_rejected() {
+        let root = Path::new(".");
+        let since = CommitRange::Since("--output=/tmp/x".to_string());
+        let err = CommitIngestService::list_commits(root, &since).unwrap_err();
+        assert!(err.to_string().contains("must not start with '-'"), "{err}");
+        let revs = CommitRange::Revisions(vec!["HEAD".to_string(), "-p".to_string()]);
+        assert!(CommitIngestService::list_commits(root, &revs).is_err());
+    }
+
+    #[test]
+    fn negated_keywords_only_link() {
+        let parsed = parse_commit_message("Don't fix PROJ-1 yet; doesn\u{2019}t close PROJ-2");
+        assert_eq!(parsed, vec![mention("PROJ-1", &[]), mention("PROJ-2", &[])]);
+    }
+
+    #[test]
+    fn revert_commits_only_link() {
+        let parsed = parse_commit_message(
+            "Revert \"fixes PROJ-1\"\n\nThis reverts commit 0123456789abcdef0123456789abcdef01234567.",
+        );
+        assert_eq!(parsed, vec![mention("PROJ-1", &[])]);
+        let parsed =
+            parse_commit_message("Back out parser\n\nThis reverts commit abc1234, closes PROJ-2");
+        assert_eq!(parsed, vec![mention("PROJ-2", &[])]);
+    }
+
+    #[test]
+    fn comment_lines_are_ignored_in_message_files() {
+        let text = "fix PROJ-1\n# Please enter the commit message\n# PROJ-2\n";
+        assert_eq!(strip_comment_lines(text), "fix PROJ-1");
+    }
+}
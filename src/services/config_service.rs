@@ -63,6 +63,9 @@ impl ConfigService {
         let global_cfg = persistence::load_global_config(Some(&resolver.path)).ok();
         let home_cfg = persistence::load_home_config().ok();
         let global_raw: GlobalConfig = global_cfg.clone().unwrap_or_default();
+        let inherited =
+            crate::config::inheritance::load_inherited_layers(&resolver.path, &global_raw)
+                .unwrap_or_default();
 
         let mut project_exists = false;
         let mut project_raw_val = serde_json::json!({});
@@ -90,17 +93,20 @@ impl ConfigService {
                 project_cfg.as_ref(),
                 &global_cfg,
                 &home_cfg,
+                &inherited,
             );
 
             (effective_val, sources)
         } else {
             let effective_val = serde_json::to_value(&resolved_global)
                 .map_err(|e| LoTaRError::SerializationError(e.to_string()))?;
-            let sources = build_global_source_labels(&resolved_global, &global_cfg, &home_cfg);
+            let sources =
+                build_global_source_labels(&resolved_global, &global_cfg, &home_cfg, &inherited);
             (effective_val, sources)
         };
 
         let mut sources = serde_json::Map::new();
+        let mut inherited_sources = serde_json::Map::new();
         let is_project_scope = project_prefix.is_some();
 
         for entry in CONFIG_SOURCE_ENTRIES {
@@ -118,6 +124,12 @@ impl ConfigService {
             }
 
             if let Some(label) = sources_by_path.get(entry.path) {
+                if label.starts_with("inherited") {
+                    inherited_sources.insert(
+                        entry.inspect_key.to_string(),
+                        serde_json::Value::String(label.clone()),
+                    );
+                }
                 let collapsed = collapse_label_to_scope(label);
                 sources.insert(
                     entry.inspect_key.to_string(),
@@ -155,6 +167,8 @@ impl ConfigService {
             "global_raw": global_raw_val,
             "auth_profiles": auth_profiles_val,
             "sources": serde_json::Value::Object(sources),
+            "inherited": inherited.iter().map(|layer| layer.source.clone()).collect::<Vec<_>>(),
+            "inherited_sources": serde_json::Value::Object(inherited_sources),
             "has_global_file": has_global_file,
             "project_exists": project_exists,
             "project_raw": project_raw_val,
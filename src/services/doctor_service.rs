@@ -0,0 +1,681 @@
+//! Whole-repository integrity check behind `lotar doctor`.
+//!
+//! The regular storage paths skip anything they cannot read, so broken files,
+//! stray IDs and dangling references go unnoticed. The doctor walks the store
+//! directly, reports every problem it finds and, with `fix`, applies the
+//! repairs that cannot lose data.
+
+use crate::config::manager::ConfigManager;
+use crate::config::types::ResolvedConfig;
+use crate::errors::{LoTaRError, LoTaRResult};
+use crate::services::agent_worktree_service::list_agent_worktrees;
+use crate::services::attachment_service::AttachmentService;
+use crate::services::sprint_integrity::cleanup_missing_sprint_refs;
+use crate::services::sprint_service::SprintService;
+use crate::storage::TaskRedirect;
+use crate::storage::manager::Storage;
+use crate::storage::sprint::Sprint;
+use crate::storage::task::Task;
+use crate::types::{TaskRelationships, TaskStatus, TaskType};
+use crate::utils::paths::repo_relative_display;
+use chrono::Utc;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Problem categories reported by `lotar doctor`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DoctorCheck {
+    /// A task or sprint file that is not valid YAML for its model
+    ParseError,
+    /// Two files in one project that resolve to the same numeric ID
+    DuplicateId,
+    /// A task file whose name is not a canonical, positive numeric ID
+    InvalidId,
+    /// A stored attachment that no task references
+    OrphanAttachment,
+    /// A relationship pointing at a task that does not exist
+    DanglingRelationship,
+    /// A sprint membership pointing at a sprint or task that does not exist
+    DanglingSprint,
+    /// A status that the project configuration does not allow
+    InvalidStatus,
+    /// A type that the project configuration does not allow
+    InvalidType,
+    /// An agent worktree whose ticket is done or whose checkout is gone
+    StaleWorktree,
+}
+
+impl DoctorCheck {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DoctorCheck::ParseError => "parse_error",
+            DoctorCheck::DuplicateId => "duplicate_id",
+            DoctorCheck::InvalidId => "invalid_id",
+            DoctorCheck::OrphanAttachment => "orphan_attachment",
+            DoctorCheck::DanglingRelationship => "dangling_relationship",
+            DoctorCheck::DanglingSprint => "dangling_sprint",
+            DoctorCheck::InvalidStatus => "invalid_status",
+            DoctorCheck::InvalidType => "invalid_type",
+            DoctorCheck::StaleWorktree => "stale_worktree",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorFinding {
+    pub check: DoctorCheck,
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+    pub message: String,
+    /// Whether `--fix` knows a safe repair for this finding
+    pub fixable: bool,
+    pub fixed: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DoctorReport {
+    pub tasks_scanned: usize,
+    pub findings: Vec<DoctorFinding>,
+}
+
+impl DoctorReport {
+    pub fn fixed(&self) -> usize {
+        self.findings.iter().filter(|f| f.fixed).count()
+    }
+
+    pub fn unresolved(&self) -> usize {
+        self.findings.iter().filter(|f| !f.fixed).count()
+    }
+
+    pub fn fixable(&self) -> usize {
+        self.findings
+            .iter()
+            .filter(|f| f.fixable && !f.fixed)
+            .count()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DoctorOptions {
+    pub fix: bool,
+}
+
+struct ScannedTask {
+    id: String,
+    project: String,
+    path: PathBuf,
+    task: Task,
+}
+
+pub struct DoctorService;
+
+impl DoctorService {
+    pub fn run(tasks_dir: &Path, options: &DoctorOptions) -> LoTaRResult<DoctorReport> {
+        let config_manager = ConfigManager::new_manager_with_tasks_dir_readonly(tasks_dir)
+            .map_err(|e| LoTaRError::ValidationError(e.to_string()))?;
+        let config = config_manager.get_resolved_config().clone();
+
+        let mut report = DoctorReport::default();
+        let (tasks, task_ids) = scan_tasks(tasks_dir, options, &mut report);
+        report.tasks_scanned = tasks.len();
+        let sprint_ids = scan_sprints(tasks_dir, &mut report);
+
+        check_config_values(&config_manager, &tasks, &mut report);
+        check_relationships(tasks_dir, &tasks, &task_ids, options, &mut report)?;
+        check_sprints(
+            tasks_dir,
+            &tasks,
+            &task_ids,
+            &sprint_ids,
+            options,
+            &mut report,
+        )?;
+        check_attachments(tasks_dir, &config_manager, &tasks, options, &mut report)?;
+        check_worktrees(tasks_dir, &config, options, &mut report);
+
+        Ok(report)
+    }
+}
+
+fn display(path: &Path) -> String {
+    repo_relative_display(path)
+}
+
+/// Read every numeric task file, reporting the ones that fail to parse or carry a bad ID.
+///
+/// Also returns the ID of every task file on disk, readable or not, so that a broken
+/// file never makes references to it look dangling.
+fn scan_tasks(
+    tasks_dir: &Path,
+    options: &DoctorOptions,
+    report: &mut DoctorReport,
+) -> (Vec<ScannedTask>, HashSet<String>) {
+    let mut projects = crate::utils::filesystem::list_visible_subdirs(tasks_dir);
+    projects.sort();
+
+    let mut tasks = Vec::new();
+    let mut task_ids = HashSet::new();
+    for (project, dir) in projects {
+        let mut files = crate::utils::filesystem::list_files_with_ext(&dir, "yml");
+        files.sort();
+
+        let mut by_number: BTreeMap<u64, Vec<PathBuf>> = BTreeMap::new();
+        for path in files {
+            let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            if stem.is_empty() || !stem.bytes().all(|b| b.is_ascii_digit()) {
+                continue;
+            }
+            match stem.parse::<u64>() {
+                Ok(0) => report.findings.push(DoctorFinding {
+                    check: DoctorCheck::InvalidId,
+                    path: display(&path),
+                    task_id: None,
+                    message: "Task IDs start at 1; this file is never loaded".to_string(),
+                    fixable: false,
+                    fixed: false,
+                }),
+                Ok(number) => by_number.entry(number).or_default().push(path),
+                Err(_) => report.findings.push(DoctorFinding {
+                    check: DoctorCheck::InvalidId,
+                    path: display(&path),
+                    task_id: None,
+                    message: "Task number is out of range; this file is never loaded".to_string(),
+                    fixable: false,
+                    fixed: false,
+                }),
+            }
+        }
+
+        for (number, paths) in by_number {
+            let id = format!("{}-{}", project, number);
+            let canonical = dir.join(format!("{}.yml", number));
+            task_ids.insert(id.to_ascii_uppercase());
+
+            if paths.len() > 1 {
+                let names: Vec<String> = paths.iter().map(|p| display(p)).collect();
+                report.findings.push(DoctorFinding {
+                    check: DoctorCheck::DuplicateId,
+                    path: display(&dir),
+                    task_id: Some(id.clone()),
+                    message: format!(
+                        "{} files resolve to {}: {}",
+                        paths.len(),
+                        id,
+                        names.join(", ")
+                    ),
+                    fixable: false,
+                    fixed: false,
+                });
+            }
+
+            for path in paths {
+                let parsed = fs::read_to_string(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|content| {
+                        serde_yaml::from_str::<Task>(&content).map_err(|e| e.to_string())
+                    });
+                let task = match parsed {
+                    Ok(task) => task,
+                    // Stub left by `lotar task move`; the task is checked under its new ID
+                    Err(_)
+                        if fs::read_to_string(&path)
+                            .ok()
+                            .and_then(|content| TaskRedirect::parse(&content))
+                            .is_some() =>
+                    {
+                        continue;
+                    }
+                    Err(message) => {
+                        report.findings.push(DoctorFinding {
+                            check: DoctorCheck::ParseError,
+                            path: display(&path),
+                            task_id: Some(id.clone()),
+                            message,
+                            fixable: false,
+                            fixed: false,
+                        });
+                        continue;
+                    }
+                };
+
+                let mut path = path;
+                if path != canonical {
+                    // Leading zeros: storage only ever looks up `<n>.yml`.
+                    let fixable = !canonical.exists();
+                    let mut finding = DoctorFinding {
+                        check: DoctorCheck::InvalidId,
+                        path: display(&path),
+                        task_id: Some(id.clone()),
+                        message: format!(
+                            "File name is not canonical; {} is expected at {}",
+                            id,
+                            display(&canonical)
+                        ),
+                        fixable,
+                        fixed: false,
+                    };
+                    if fixable && options.fix && fs::rename(&path, &canonical).is_ok() {
+                        finding.fixed = true;
+                        path = canonical.clone();
+                    }
+                    let loaded = finding.fixed;
+                    report.findings.push(finding);
+                    if !loaded {
+                        continue;
+                    }
+                }
+
+                tasks.push(ScannedTask {
+                    id: id.clone(),
+                    project: project.clone(),
+                    path,
+                    task,
+                });
+            }
+        }
+    }
+    (tasks, task_ids)
+}
+
+/// Collect the IDs of all sprint files, reporting the ones that fail to parse.
+fn scan_sprints(tasks_dir: &Path, report: &mut DoctorReport) -> HashSet<u32> {
+    let mut ids = HashSet::new();
+    let dir = tasks_dir.join("@sprints");
+    let mut files = crate::utils::filesystem::list_files_with_ext(&dir, "yml");
+    files.sort();
+    for path in files {
+        let Some(id) = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .and_then(|s| s.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        let parsed = fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                serde_yaml::from_str::<Sprint>(&content).map_err(|e| e.to_string())
+            });
+        ids.insert(id);
+        if let Err(message) = parsed {
+            report.findings.push(DoctorFinding {
+                check: DoctorCheck::ParseError,
+                path: display(&path),
+                task_id: None,
+                message,
+                fixable: false,
+                fixed: false,
+            });
+        }
+    }
+    ids
+}
+
+fn check_config_values(
+    config_manager: &ConfigManager,
+    tasks: &[ScannedTask],
+    report: &mut DoctorReport,
+) {
+    let mut configs: HashMap<String, Option<ResolvedConfig>> = HashMap::new();
+    for scanned in tasks {
+        let config = configs
+            .entry(scanned.project.clone())
+            .or_insert_with(|| config_manager.get_project_config(&scanned.project).ok());
+        let Some(config) = config else {
+            continue;
+        };
+
+        if let Err(message) = TaskStatus::parse_with_config(scanned.task.status.as_str(), config) {
+            report.findings.push(DoctorFinding {
+                check: DoctorCheck::InvalidStatus,
+                path: display(&scanned.path),
+                task_id: Some(scanned.id.clone()),
+                message,
+                fixable: false,
+                fixed: false,
+            });
+        }
+        if let Err(message) = TaskType::parse_with_config(scanned.task.task_type.as_str(), config) {
+            report.findings.push(DoctorFinding {
+                check: DoctorCheck::InvalidType,
+                path: display(&scanned.path),
+                task_id: Some(scanned.id.clone()),
+                message,
+                fixable: false,
+                fixed: false,
+            });
+        }
+    }
+}
+
+/// Resolve a relationship value to a full task ID; bare numbers stay in the task's project.
+fn qualify_task_ref(value: &str, project: &str) -> String {
+    let trimmed = value.trim();
+    if !trimmed.is_empty() && trimmed.bytes().all(|b| b.is_ascii_digit()) {
+        format!("{}-{}", project, trimmed.trim_start_matches('0'))
+    } else {
+        trimmed.to_ascii_uppercase()
+    }
+}
+
+fn relationship_values(relationships: &TaskRelationships) -> Vec<(&'static str, &String)> {
+    let mut values = Vec::new();
+    for (kind, list) in [
+        ("depends_on", &relationships.depends_on),
+        ("blocks", &relationships.blocks),
+        ("related", &relationships.related),
+        ("children", &relationships.children),
+        ("fixes", &relationships.fixes),
+    ] {
+        values.extend(list.iter().map(|value| (kind, value)));
+    }
+    if let Some(parent) = &relationships.parent {
+        values.push(("parent", parent));
+    }
+    if let Some(duplicate_of) = &relationships.duplicate_of {
+        values.push(("duplicate_of", duplicate_of));
+    }
+    values
+}
+
+fn check_relationships(
+    tasks_dir: &Path,
+    tasks: &[ScannedTask],
+    known: &HashSet<String>,
+    options: &DoctorOptions,
+    report: &mut DoctorReport,
+) -> LoTaRResult<()> {
+    let mut storage = Storage::new(tasks_dir);
+
+    for scanned in tasks {
+        let dangling: BTreeSet<String> = relationship_values(&scanned.task.relationships)
+            .into_iter()
+            .filter(|(_, value)| !known.contains(&qualify_task_ref(value, &scanned.project)))
+            .map(|(_, value)| value.clone())
+            .collect();
+        if dangling.is_empty() {
+            continue;
+        }
+
+        let fixed = if options.fix {
+            let mut task = scanned.task.clone();
+            let keep = |value: &String| !dangling.contains(value);
+            let rel = &mut task.relationships;
+            rel.depends_on.retain(keep);
+            rel.blocks.retain(keep);
+            rel.related.retain(keep);
+            rel.children.retain(keep);
+            rel.fixes.retain(keep);
+            rel.parent = rel.parent.take().filter(keep);
+            rel.duplicate_of = rel.duplicate_of.take().filter(keep);
+            task.modified = Utc::now().to_rfc3339();
+            storage.edit(&scanned.id, &task)?;
+            true
+        } else {
+            false
+        };
+
+        for (kind, value) in relationship_values(&scanned.task.relationships) {
+            if !dangling.contains(value) {
+                continue;
+            }
+            report.findings.push(DoctorFinding {
+                check: DoctorCheck::DanglingRelationship,
+                path: display(&scanned.path),
+                task_id: Some(scanned.id.clone()),
+                message: format!("{} references missing task {}", kind, value),
+                fixable: true,
+                fixed,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn check_sprints(
+    tasks_dir: &Path,
+    tasks: &[ScannedTask],
+    task_ids: &HashSet<String>,
+    sprint_ids: &HashSet<u32>,
+    options: &DoctorOptions,
+    report: &mut DoctorReport,
+) -> LoTaRResult<()> {
+    // Legacy `sprints:` lists in task files
+    let first = report.findings.len();
+    for scanned in tasks {
+        for sprint_id in &scanned.task.sprints {
+            if sprint_ids.contains(sprint_id) {
+                continue;
+            }
+            report.findings.push(DoctorFinding {
+                check: DoctorCheck::DanglingSprint,
+                path: display(&scanned.path),
+                task_id: Some(scanned.id.clone()),
+                message: format!("Member of missing sprint #{}", sprint_id),
+                fixable: true,
+                fixed: false,
+            });
+        }
+    }
+
+    // Sprint cleanup rewrites sprint files, so leave it alone while any of them are unreadable.
+    let mut storage = Storage::new(tasks_dir);
+    let Ok(mut records) = SprintService::list(&storage) else {
+        return Ok(());
+    };
+    if options.fix && report.findings.len() > first {
+        let outcome = cleanup_missing_sprint_refs(&mut storage, &mut records, None)?;
+        if outcome.remaining_missing.is_empty() {
+            for finding in &mut report.findings[first..] {
+                finding.fixed = true;
+            }
+        }
+    }
+
+    // Membership entries in sprint files
+    let sprints_dir = Sprint::dir(tasks_dir);
+    for record in records {
+        let path = sprints_dir.join(format!("{}.yml", record.id));
+        let missing: Vec<String> = record
+            .sprint
+            .tasks
+            .iter()
+            .map(|entry| entry.id.trim().to_ascii_uppercase())
+            .filter(|id| !task_ids.contains(id))
+            .collect();
+        if missing.is_empty() {
+            continue;
+        }
+
+        let mut fixed = false;
+        if options.fix {
+            let mut sprint = record.sprint.clone();
+            sprint
+                .tasks
+                .retain(|entry| !missing.contains(&entry.id.trim().to_ascii_uppercase()));
+            fixed = SprintService::update(&mut storage, record.id, sprint).is_ok();
+        }
+        for id in missing {
+            report.findings.push(DoctorFinding {
+                check: DoctorCheck::DanglingSprint,
+                path: display(&path),
+                task_id: Some(id.clone()),
+                message: format!("Sprint #{} lists missing task {}", record.id, id),
+                fixable: true,
+                fixed,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn check_attachments(
+    tasks_dir: &Path,
+    config_manager: &ConfigManager,
+    tasks: &[ScannedTask],
+    options: &DoctorOptions,
+    report: &mut DoctorReport,
+) -> LoTaRResult<()> {
+    // Projects can point `attachments_dir` elsewhere, so check every distinct root.
+    let mut roots: BTreeSet<PathBuf> = BTreeSet::new();
+    if let Ok(root) =
+        AttachmentService::compute_attachments_root(tasks_dir, config_manager.get_resolved_config())
+    {
+        roots.insert(root);
+    }
+    for (project, _) in crate::utils::filesystem::list_visible_subdirs(tasks_dir) {
+        if let Ok(root) = config_manager
+            .get_project_config(&project)
+            .map_err(|e| LoTaRError::ValidationError(e.to_string()))
+            .and_then(|config| AttachmentService::compute_attachments_root(tasks_dir, &config))
+        {
+            roots.insert(root);
+        }
+    }
+
+    let referenced: HashSet<String> = tasks
+        .iter()
+        .flat_map(|t| t.task.references.iter())
+        .filter_map(|r| r.file.as_deref())
+        .filter_map(AttachmentService::extract_hash_tag)
+        .collect();
+
+    // A task file that was not loaded may still reference attachments; deleting
+    // "orphans" then would lose data, so only report them until it is repaired.
+    let incomplete = report.findings.iter().any(|f| {
+        !f.fixed
+            && match f.check {
+                DoctorCheck::InvalidId => true,
+                DoctorCheck::ParseError => f.task_id.is_some(),
+                _ => false,
+            }
+    });
+
+    let mut orphans: Vec<PathBuf> = roots
+        .iter()
+        .filter_map(|root| fs::read_dir(root).ok())
+        .flat_map(|entries| entries.flatten())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .and_then(AttachmentService::extract_hash_tag)
+                .is_some_and(|hash| !referenced.contains(&hash))
+        })
+        .collect();
+    orphans.sort();
+
+    for path in orphans {
+        let fixable = !incomplete;
+        let fixed = fixable && options.fix && fs::remove_file(&path).is_ok();
+        let message = if incomplete {
+            "Attachment is not referenced by any readable task; fix unreadable task files before removing it"
+        } else {
+            "Attachment is not referenced by any task"
+        };
+        report.findings.push(DoctorFinding {
+            check: DoctorCheck::OrphanAttachment,
+            path: display(&path),
+            task_id: None,
+            message: message.to_string(),
+            fixable,
+            fixed,
+        });
+    }
+    Ok(())
+}
+
+fn check_worktrees(
+    tasks_dir: &Path,
+    config: &ResolvedConfig,
+    options: &DoctorOptions,
+    report: &mut DoctorReport,
+) {
+    let Ok(worktrees) = list_agent_worktrees(tasks_dir, config) else {
+        return;
+    };
+    let repo_root = crate::utils::git::find_repo_root(tasks_dir);
+
+    for wt in worktrees {
+        if wt.has_active_job {
+            continue;
+        }
+        let missing = !Path::new(&wt.path).exists();
+        if !missing && !wt.is_done {
+            continue;
+        }
+
+        // Removing a checkout with uncommitted work would discard it; leave it for a human.
+        if !missing && worktree_is_dirty(&wt.path) {
+            report.findings.push(DoctorFinding {
+                check: DoctorCheck::StaleWorktree,
+                path: wt.path.clone(),
+                task_id: Some(wt.ticket_id.clone()),
+                message: format!(
+                    "Agent worktree on {} is stale: ticket is done, but it has uncommitted changes",
+                    wt.branch
+                ),
+                fixable: false,
+                fixed: false,
+            });
+            continue;
+        }
+
+        let fixed = match (&repo_root, options.fix) {
+            (Some(root), true) => {
+                let args: Vec<&str> = if missing {
+                    vec!["worktree", "prune"]
+                } else {
+                    vec!["worktree", "remove", &wt.path]
+                };
+                Command::new("git")
+                    .args(&args)
+                    .current_dir(root)
+                    .output()
+                    .is_ok_and(|out| out.status.success())
+            }
+            _ => false,
+        };
+        let reason = if missing {
+            "checkout no longer exists"
+        } else {
+            "ticket is done"
+        };
+        report.findings.push(DoctorFinding {
+            check: DoctorCheck::StaleWorktree,
+            path: wt.path.clone(),
+            task_id: Some(wt.ticket_id.clone()),
+            message: format!("Agent worktree on {} is stale: {}", wt.branch, reason),
+            fixable: true,
+            fixed,
+        });
+    }
+}
+
+/// Whether the checkout at `path` has modified, staged or untracked files (or cannot be checked).
+fn worktree_is_dirty(path: &str) -> bool {
+    Command::new("git")
+        .args(["-C", path, "status", "--porcelain"])
+        .output()
+        .map_or(true, |out| !out.status.success() || !out.stdout.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::qualify_task_ref;
+
+    #[test]
+    fn qualifies_bare_numbers_into_the_task_project() {
+        assert_eq!(qualify_task_ref("7", "AUTH"), "AUTH-7");
+        assert_eq!(qualify_task_ref("007", "AUTH"), "AUTH-7");
+        assert_eq!(qualify_task_ref(" api-3 ", "AUTH"), "API-3");
+    }
+}
@@ -0,0 +1,526 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::Serialize;
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+use crate::api_types::TaskListFilter;
+use crate::config::types::{GlobalConfig, ResolvedConfig};
+use crate::services::audit_service::{AuditService, StatusTransitions};
+use crate::services::sprint_metrics::determine_done_statuses_from_config;
+use crate::services::task_service::TaskService;
+use crate::storage::manager::Storage;
+
+/// Longest window a flow or cycle-time report covers; earlier `since` values are clamped.
+pub const MAX_WINDOW_DAYS: i64 = 3660;
+
+/// Status history of one task: the status it was created with followed by every change.
+#[derive(Debug, Clone)]
+pub struct StatusTimeline {
+    pub id: String,
+    pub task_type: String,
+    pub created: DateTime<Utc>,
+    pub transitions: Vec<(DateTime<Utc>, String)>,
+}
+
+impl StatusTimeline {
+    fn status_at(&self, at: DateTime<Utc>) -> Option<&str> {
+        if at < self.created {
+            return None;
+        }
+        self.transitions
+            .iter()
+            .take_while(|(when, _)| *when <= at)
+            .last()
+            .or(self.transitions.first())
+            .map(|(_, status)| status.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct FlowDay {
+    pub date: String,
+    pub counts: BTreeMap<String, usize>,
+    pub total: usize,
+}
+
+/// Daily status counts for a cumulative flow diagram.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct FlowReport {
+    pub since: String,
+    pub until: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub project: Option<String>,
+    /// Statuses in workflow order; every day carries a count for each of them.
+    pub statuses: Vec<String>,
+    pub days: Vec<FlowDay>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct DurationPercentiles {
+    pub p50: f64,
+    pub p85: f64,
+    pub p95: f64,
+    pub mean: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct CycleTimeGroup {
+    /// Task type, or `all` for the combined row.
+    pub task_type: String,
+    pub completed: usize,
+    /// Days from creation to done.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub lead_time_days: Option<DurationPercentiles>,
+    /// Days from first leaving the initial status to done.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub cycle_time_days: Option<DurationPercentiles>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct ThroughputWeek {
+    /// Monday of the ISO week (YYYY-MM-DD).
+    pub week_start: String,
+    pub completed: usize,
+}
+
+/// Lead/cycle time percentiles and weekly throughput for tasks finished in a window.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct CycleTimeReport {
+    pub since: String,
+    pub until: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub project: Option<String>,
+    pub overall: CycleTimeGroup,
+    pub by_type: Vec<CycleTimeGroup>,
+    pub throughput: Vec<ThroughputWeek>,
+}
+
+/// Cumulative flow for tasks in scope, using the project's workflow when one is given.
+pub fn flow_report(
+    storage: &Storage,
+    repo_root: Option<&Path>,
+    project: Option<&str>,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> FlowReport {
+    let config = resolve_config(storage, project);
+    let timelines = load_timelines(storage, repo_root, project);
+    compute_flow(&timelines, &config, since, until, project)
+}
+
+/// Lead/cycle time and throughput for tasks in scope.
+pub fn cycle_time_report(
+    storage: &Storage,
+    repo_root: Option<&Path>,
+    project: Option<&str>,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> CycleTimeReport {
+    let config = resolve_config(storage, project);
+    let timelines = load_timelines(storage, repo_root, project);
+    compute_cycle_time(&timelines, &config, since, until, project)
+}
+
+fn resolve_config(storage: &Storage, project: Option<&str>) -> ResolvedConfig {
+    let root = storage.root_path.as_path();
+    let base = crate::config::resolution::load_and_merge_configs(Some(root))
+        .unwrap_or_else(|_| ResolvedConfig::from_global(GlobalConfig::default()));
+    match project.filter(|prefix| !prefix.trim().is_empty()) {
+        Some(prefix) => {
+            crate::config::resolution::get_project_config(&base, prefix, root).unwrap_or(base)
+        }
+        None => base,
+    }
+}
+
+/// Build status timelines for tasks in scope.
+///
+/// Status changes come from each task's `history`. Tasks without recorded status changes
+/// (for example ones edited by hand) fall back to the git history of their file when
+/// `repo_root` is given.
+pub fn load_timelines(
+    storage: &Storage,
+    repo_root: Option<&Path>,
+    project: Option<&str>,
+) -> Vec<StatusTimeline> {
+    let filter = TaskListFilter {
+        project: project.map(str::to_string),
+        ..Default::default()
+    };
+    let mut git_history: Option<GitStatusHistory> = None;
+    let mut timelines = Vec::new();
+    for (id, task) in TaskService::list(storage, &filter) {
+        let Some(created) = parse_timestamp(&task.created) else {
+            continue;
+        };
+
+        let mut changes: Vec<(DateTime<Utc>, Option<String>, String)> = Vec::new();
+        for entry in &task.history {
+            let Some(at) = parse_timestamp(&entry.at) else {
+                continue;
+            };
+            for change in &entry.changes {
+                if change.field.eq_ignore_ascii_case("status")
+                    && let Some(new) = change.new.as_ref()
+                {
+                    changes.push((at, change.old.clone(), new.clone()));
+                }
+            }
+        }
+        changes.sort_by_key(|(at, _, _)| *at);
+
+        let transitions = if let Some((_, first_old, _)) = changes.first() {
+            let initial = first_old
+                .clone()
+                .unwrap_or_else(|| task.status.as_str().to_string());
+            let mut transitions = vec![(created, initial)];
+            transitions.extend(changes.into_iter().map(|(at, _, new)| (at, new)));
+            transitions
+        } else {
+            repo_root
+                .and_then(|root| {
+                    git_history
+                        .get_or_insert_with(|| GitStatusHistory::load(root, &storage.root_path))
+                        .transitions(&id)
+                })
+                .unwrap_or_else(|| vec![(created, task.status.as_str().to_string())])
+        };
+
+        timelines.push(StatusTimeline {
+            id,
+            task_type: task.task_type.as_str().to_string(),
+            created: created.min(transitions[0].0),
+            transitions,
+        });
+    }
+    timelines
+}
+
+/// Status changes of every task file under the tasks directory, read with one `git log`.
+struct GitStatusHistory {
+    tasks_rel: Option<PathBuf>,
+    by_file: HashMap<String, StatusTransitions>,
+}
+
+impl GitStatusHistory {
+    fn load(repo_root: &Path, tasks_root: &Path) -> Self {
+        let tasks_rel = std::fs::canonicalize(repo_root).ok().and_then(|repo_real| {
+            let tasks_real = std::fs::canonicalize(tasks_root).ok()?;
+            let rel = tasks_real.strip_prefix(&repo_real).ok()?.to_path_buf();
+            Some((repo_real, rel))
+        });
+        let by_file = tasks_rel
+            .as_ref()
+            .and_then(|(repo_real, rel)| {
+                AuditService::list_status_transitions_under(repo_real, rel).ok()
+            })
+            .unwrap_or_default();
+        Self {
+            tasks_rel: tasks_rel.map(|(_, rel)| rel),
+            by_file,
+        }
+    }
+
+    fn transitions(&self, id: &str) -> Option<StatusTransitions> {
+        let (prefix, number) = id.rsplit_once('-')?;
+        let file = self
+            .tasks_rel
+            .as_ref()?
+            .join(prefix)
+            .join(format!("{}.yml", number));
+        let key = file.to_string_lossy().replace('\\', "/");
+        self.by_file
+            .get(&key)
+            .filter(|transitions| !transitions.is_empty())
+            .cloned()
+    }
+}
+
+fn clamp_since(since: DateTime<Utc>, until: DateTime<Utc>) -> DateTime<Utc> {
+    since.max(until - Duration::days(MAX_WINDOW_DAYS))
+}
+
+/// Daily counts per status between `since` and `until`, sampled at the end of each day.
+pub fn compute_flow(
+    timelines: &[StatusTimeline],
+    config: &ResolvedConfig,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+    project: Option<&str>,
+) -> FlowReport {
+    let since = clamp_since(since, until);
+    let statuses = ordered_statuses(timelines, config);
+    let mut days = Vec::new();
+    let mut day = since.date_naive();
+    let last = until.date_naive();
+    while day <= last {
+        let sample = end_of_day(day).min(until);
+        let mut counts: BTreeMap<String, usize> =
+            statuses.iter().map(|status| (status.clone(), 0)).collect();
+        let mut total = 0;
+        for timeline in timelines {
+            if let Some(status) = timeline.status_at(sample) {
+                *counts
+                    .entry(canonical_status(status, &statuses))
+                    .or_insert(0) += 1;
+                total += 1;
+            }
+        }
+        days.push(FlowDay {
+            date: day.format("%Y-%m-%d").to_string(),
+            counts,
+            total,
+        });
+        day += Duration::days(1);
+    }
+
+    FlowReport {
+        since: since.to_rfc3339(),
+        until: until.to_rfc3339(),
+        project: project.map(str::to_string),
+        statuses,
+        days,
+    }
+}
+
+/// Lead and cycle time percentiles per task type, plus weekly throughput, for tasks that
+/// reached a done status between `since` and `until` and are still done.
+pub fn compute_cycle_time(
+    timelines: &[StatusTimeline],
+    config: &ResolvedConfig,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+    project: Option<&str>,
+) -> CycleTimeReport {
+    let since = clamp_since(since, until);
+    let done_statuses = determine_done_statuses_from_config(config);
+    let initial_status = config
+        .issue_states
+        .values
+        .first()
+        .map(|status| status.as_str().to_ascii_lowercase());
+
+    let mut samples: BTreeMap<String, (Vec<f64>, Vec<f64>)> = BTreeMap::new();
+    let mut all_lead = Vec::new();
+    let mut all_cycle = Vec::new();
+    let mut weekly: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+
+    for timeline in timelines {
+        let Some(done_at) = completion_time(timeline, &done_statuses) else {
+            continue;
+        };
+        if done_at < since || done_at > until {
+            continue;
+        }
+        let lead = to_days(done_at - timeline.created);
+        let started_at = timeline
+            .transitions
+            .iter()
+            .skip(1)
+            .find(|(_, status)| {
+                let lower = status.to_ascii_lowercase();
+                Some(&lower) != initial_status.as_ref() && !done_statuses.contains(&lower)
+            })
+            .map(|(at, _)| *at)
+            .filter(|at| *at <= done_at);
+
+        let entry = samples.entry(timeline.task_type.clone()).or_default();
+        entry.0.push(lead);
+        all_lead.push(lead);
+        if let Some(started) = started_at {
+            let cycle = to_days(done_at - started);
+            entry.1.push(cycle);
+            all_cycle.push(cycle);
+        }
+        *weekly.entry(week_start(done_at.date_naive())).or_insert(0) += 1;
+    }
+
+    let mut throughput = Vec::new();
+    let mut week = week_start(since.date_naive());
+    while week <= until.date_naive() {
+        throughput.push(ThroughputWeek {
+            week_start: week.format("%Y-%m-%d").to_string(),
+            completed: weekly.get(&week).copied().unwrap_or(0),
+        });
+        week += Duration::days(7);
+    }
+
+    CycleTimeReport {
+        since: since.to_rfc3339(),
+        until: until.to_rfc3339(),
+        project: project.map(str::to_string),
+        overall: cycle_group("all", all_lead, all_cycle),
+        by_type: samples
+            .into_iter()
+            .map(|(task_type, (lead, cycle))| cycle_group(&task_type, lead, cycle))
+            .collect(),
+        throughput,
+    }
+}
+
+fn cycle_group(task_type: &str, lead: Vec<f64>, cycle: Vec<f64>) -> CycleTimeGroup {
+    CycleTimeGroup {
+        task_type: task_type.to_string(),
+        completed: lead.len(),
+        lead_time_days: percentiles(lead),
+        cycle_time_days: percentiles(cycle),
+    }
+}
+
+/// Nearest-rank percentiles; `None` when there are no samples.
+pub fn percentiles(mut values: Vec<f64>) -> Option<DurationPercentiles> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+    let rank = |p: f64| {
+        let index = ((p / 100.0) * values.len() as f64).ceil() as usize;
+        values[index.clamp(1, values.len()) - 1]
+    };
+    Some(DurationPercentiles {
+        p50: rank(50.0),
+        p85: rank(85.0),
+        p95: rank(95.0),
+        mean: values.iter().sum::<f64>() / values.len() as f64,
+    })
+}
+
+/// When the task last entered a done status, if it is done at the end of its timeline.
+pub(crate) fn completion_time(
+    timeline: &StatusTimeline,
+    done: &HashSet<String>,
+) -> Option<DateTime<Utc>> {
+    let is_done = |status: &str| done.contains(&status.to_ascii_lowercase());
+    let (_, last) = timeline.transitions.last()?;
+    if !is_done(last) {
+        return None;
+    }
+    let mut at = None;
+    for (when, status) in timeline.transitions.iter().rev() {
+        if !is_done(status) {
+            break;
+        }
+        at = Some(*when);
+    }
+    at
+}
+
+fn ordered_statuses(timelines: &[StatusTimeline], config: &ResolvedConfig) -> Vec<String> {
+    let mut statuses: Vec<String> = config
+        .issue_states
+        .values
+        .iter()
+        .map(|status| status.as_str().to_string())
+        .collect();
+    let mut extra: Vec<String> = Vec::new();
+    for timeline in timelines {
+        for (_, status) in &timeline.transitions {
+            let known = statuses
+                .iter()
+                .chain(extra.iter())
+                .any(|existing| existing.eq_ignore_ascii_case(status));
+            if !known {
+                extra.push(status.clone());
+            }
+        }
+    }
+    extra.sort();
+    statuses.extend(extra);
+    statuses
+}
+
+fn canonical_status(status: &str, statuses: &[String]) -> String {
+    statuses
+        .iter()
+        .find(|candidate| candidate.eq_ignore_ascii_case(status))
+        .cloned()
+        .unwrap_or_else(|| status.to_string())
+}
+
+fn parse_timestamp(raw: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(raw.trim())
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn end_of_day(day: NaiveDate) -> DateTime<Utc> {
+    (day + Duration::days(1))
+        .and_hms_opt(0, 0, 0)
+        .map(|naive| naive.and_utc() - Duration::seconds(1))
+        .unwrap_or_else(Utc::now)
+}
+
+fn week_start(day: NaiveDate) -> NaiveDate {
+    day - Duration::days(day.weekday().num_days_from_monday() as i64)
+}
+
+fn to_days(duration: Duration) -> f64 {
+    (duration.num_seconds().max(0) as f64) / 86_400.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        let values: Vec<f64> = (1..=20).map(f64::from).collect();
+        let result = percentiles(values).expect("samples");
+        assert_eq!(result.p50, 10.0);
+        assert_eq!(result.p85, 17.0);
+        assert_eq!(result.p95, 19.0);
+        assert_eq!(result.mean, 10.5);
+        assert!(percentiles(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn completion_time_uses_last_entry_into_done() {
+        let at = |day: u32| {
+            NaiveDate::from_ymd_opt(2026, 1, day)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc()
+        };
+        let timeline = StatusTimeline {
+            id: "A-1".to_string(),
+            task_type: "Feature".to_string(),
+            created: at(1),
+            transitions: vec![
+                (at(1), "Todo".to_string()),
+                (at(2), "Done".to_string()),
+                (at(3), "InProgress".to_string()),
+                (at(5), "Done".to_string()),
+            ],
+        };
+        let done: HashSet<String> = ["done".to_string()].into_iter().collect();
+        assert_eq!(completion_time(&timeline, &done), Some(at(5)));
+        assert_eq!(timeline.status_at(at(4)), Some("InProgress"));
+    }
+
+    #[test]
+    fn long_windows_are_clamped() {
+        let until = NaiveDate::from_ymd_opt(2026, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc();
+        let since = until - Duration::days(1_000_000);
+        assert_eq!(
+            clamp_since(since, until),
+            until - Duration::days(MAX_WINDOW_DAYS)
+        );
+        let recent = until - Duration::days(7);
+        assert_eq!(clamp_since(recent, until), recent);
+    }
+}
@@ -0,0 +1,595 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+use crate::api_types::{ForecastRequest, TaskDTO, TaskListFilter, TaskSelection};
+use crate::config::types::ResolvedConfig;
+use crate::services::flow_metrics;
+use crate::services::sprint_assignment::{self, resolve_task_identifier};
+use crate::services::sprint_metrics::{
+    SprintBurndownMetric, determine_done_statuses_from_config, metric_label,
+};
+use crate::services::sprint_service::SprintService;
+use crate::services::sprint_velocity::{self, DEFAULT_VELOCITY_WINDOW, VelocityOptions};
+use crate::services::task_selection;
+use crate::services::task_service::TaskService;
+use crate::storage::manager::Storage;
+use crate::utils::effort::{self, EffortKind};
+use crate::workspace::TasksDirectoryResolver;
+
+pub const DEFAULT_ITERATIONS: usize = 10_000;
+pub const DEFAULT_WEEK_HISTORY: usize = 12;
+/// Upper bounds for request parameters; each run and each period costs memory.
+pub const MAX_ITERATIONS: usize = 100_000;
+pub const MAX_HISTORY: usize = 520;
+/// Iterations that still have work left after this many periods are reported as unfinished.
+const MAX_PERIODS: usize = 520;
+const PERCENTILES: [u8; 4] = [50, 70, 85, 95];
+
+/// What one historical sample covers.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum ForecastBasis {
+    /// Completed work per closed sprint
+    #[default]
+    Sprint,
+    /// Completed work per calendar week
+    Week,
+}
+
+impl ForecastBasis {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ForecastBasis::Sprint => "sprint",
+            ForecastBasis::Week => "week",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ForecastOptions {
+    pub basis: ForecastBasis,
+    pub metric: SprintBurndownMetric,
+    /// Number of past sprints or weeks to sample; defaults depend on the basis.
+    pub history: Option<usize>,
+    pub iterations: Option<usize>,
+    pub target: Option<NaiveDate>,
+    /// Fixed seed for reproducible runs.
+    pub seed: Option<u64>,
+}
+
+impl ForecastOptions {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(iterations) = self.iterations.filter(|n| *n > MAX_ITERATIONS) {
+            return Err(format!(
+                "iterations must be at most {} (got {})",
+                MAX_ITERATIONS, iterations
+            ));
+        }
+        if let Some(history) = self.history.filter(|n| *n > MAX_HISTORY) {
+            return Err(format!(
+                "history must be at most {} (got {})",
+                MAX_HISTORY, history
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct ForecastPercentile {
+    pub percentile: u8,
+    /// Sprints or weeks needed, counted from now.
+    pub periods: usize,
+    /// Projected completion date (YYYY-MM-DD).
+    pub date: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct ForecastTarget {
+    pub date: String,
+    /// Share of simulated runs finishing on or before `date` (0.0 - 1.0).
+    pub probability: f64,
+}
+
+/// Monte Carlo completion forecast for a set of tasks.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct ForecastReport {
+    pub basis: ForecastBasis,
+    pub metric: String,
+    /// Tasks matched by the selection, including finished ones.
+    pub selected: usize,
+    pub remaining_tasks: usize,
+    /// Remaining work in `metric` units.
+    pub remaining: f64,
+    /// Open tasks without an estimate in `metric` units (not counted in `remaining`).
+    pub unestimated: usize,
+    /// Completed work per historical sprint or week, oldest first.
+    pub history: Vec<f64>,
+    pub period_days: f64,
+    pub iterations: usize,
+    pub percentiles: Vec<ForecastPercentile>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub target: Option<ForecastTarget>,
+    pub warnings: Vec<String>,
+}
+
+/// Which tasks to forecast.
+#[derive(Debug, Clone, Default)]
+pub struct ForecastScope {
+    pub selection: TaskSelection,
+    /// Epic whose descendants to forecast.
+    pub epic: Option<String>,
+    /// Sprint reference (numeric id, next, previous, active).
+    pub sprint: Option<String>,
+}
+
+impl ForecastRequest {
+    /// Split API parameters into the tasks to forecast and the simulation options.
+    pub fn to_scope_and_options(&self) -> Result<(ForecastScope, ForecastOptions), String> {
+        let basis = match self.by.as_deref() {
+            Some(raw) => ForecastBasis::from_str(raw.trim(), true)
+                .map_err(|_| format!("Unsupported basis '{}'. Use sprint or week.", raw))?,
+            None => ForecastBasis::default(),
+        };
+        let metric = match self.metric.as_deref() {
+            Some(raw) => SprintBurndownMetric::from_str(raw.trim(), true).map_err(|_| {
+                format!("Unsupported metric '{}'. Use tasks, points, or hours.", raw)
+            })?,
+            None => SprintBurndownMetric::default(),
+        };
+        let selection = TaskSelection {
+            filter: TaskListFilter {
+                project: self.project.clone().filter(|p| !p.trim().is_empty()),
+                text_query: self.q.clone().filter(|q| !q.trim().is_empty()),
+                tags: self.tags.clone(),
+                ..Default::default()
+            },
+            r#where: Vec::new(),
+        };
+        let scope = ForecastScope {
+            selection,
+            epic: self.epic.clone().filter(|e| !e.trim().is_empty()),
+            sprint: self.sprint.clone().filter(|s| !s.trim().is_empty()),
+        };
+        let options = ForecastOptions {
+            basis,
+            metric,
+            history: self.history,
+            iterations: self.iterations,
+            target: self.target.as_deref().map(parse_target).transpose()?,
+            seed: self.seed,
+        };
+        Ok((scope, options))
+    }
+}
+
+/// Resolve `scope` against the workspace and forecast it.
+///
+/// Done statuses come from the selection's project when it names one.
+pub fn run(
+    resolver: &TasksDirectoryResolver,
+    repo_root: Option<&Path>,
+    scope: &ForecastScope,
+    options: &ForecastOptions,
+    now: DateTime<Utc>,
+) -> Result<ForecastReport, String> {
+    let storage = Storage::new(&resolver.path);
+    let base = crate::config::resolution::load_and_merge_configs(Some(resolver.path.as_path()))
+        .map_err(|err| err.to_string())?;
+    let project = scope.selection.filter.project.clone();
+    let config = match project.as_deref() {
+        Some(prefix) => {
+            crate::config::resolution::get_project_config(&base, prefix, &resolver.path)
+                .map_err(|err| err.to_string())?
+        }
+        None => base,
+    };
+
+    let mut selection = scope.selection.clone();
+    if let Some(reference) = scope.sprint.as_deref() {
+        let records = SprintService::list(&storage).map_err(|err| err.to_string())?;
+        let sprint_id = sprint_assignment::resolve_sprint_id(&records, Some(reference))?;
+        selection.filter.sprints = vec![sprint_id];
+    }
+    let tasks = select_tasks(
+        &storage,
+        resolver,
+        &config,
+        &selection,
+        scope.epic.as_deref(),
+    )?;
+    forecast(
+        &storage,
+        repo_root,
+        &config,
+        project.as_deref(),
+        &tasks,
+        options,
+        now,
+    )
+}
+
+/// Tasks a forecast covers: the selection, narrowed to the descendants of `epic` when given.
+pub fn select_tasks(
+    storage: &Storage,
+    resolver: &TasksDirectoryResolver,
+    config: &ResolvedConfig,
+    selection: &TaskSelection,
+    epic: Option<&str>,
+) -> Result<Vec<(String, TaskDTO)>, String> {
+    let ids: BTreeSet<String> =
+        task_selection::select_task_ids(storage, selection, resolver, config)?
+            .into_iter()
+            .collect();
+    let descendants = match epic {
+        Some(raw) => {
+            let epic_id = resolve_task_identifier(storage, raw)?;
+            Some(epic_descendants(storage, &epic_id))
+        }
+        None => None,
+    };
+    Ok(TaskService::list(storage, &selection.filter)
+        .into_iter()
+        .filter(|(id, _)| ids.contains(id))
+        .filter(|(id, _)| descendants.as_ref().is_none_or(|set| set.contains(id)))
+        .collect())
+}
+
+fn epic_descendants(storage: &Storage, epic_id: &str) -> BTreeSet<String> {
+    let tasks = TaskService::list(storage, &TaskListFilter::default());
+    let mut children: HashMap<String, BTreeSet<String>> = HashMap::new();
+    for (id, task) in &tasks {
+        if let Some(parent) = task.relationships.parent.as_ref() {
+            children
+                .entry(parent.clone())
+                .or_default()
+                .insert(id.clone());
+        }
+        for child in &task.relationships.children {
+            children
+                .entry(id.clone())
+                .or_default()
+                .insert(child.clone());
+        }
+    }
+
+    let mut seen = BTreeSet::new();
+    let mut queue = vec![epic_id.to_string()];
+    while let Some(current) = queue.pop() {
+        for child in children.get(&current).into_iter().flatten() {
+            if child != epic_id && seen.insert(child.clone()) {
+                queue.push(child.clone());
+            }
+        }
+    }
+    seen
+}
+
+/// Simulate completion of the open tasks in `tasks` by replaying historical throughput.
+pub fn forecast(
+    storage: &Storage,
+    repo_root: Option<&Path>,
+    config: &ResolvedConfig,
+    project: Option<&str>,
+    tasks: &[(String, TaskDTO)],
+    options: &ForecastOptions,
+    now: DateTime<Utc>,
+) -> Result<ForecastReport, String> {
+    options.validate()?;
+    let done_statuses = determine_done_statuses_from_config(config);
+    let mut warnings = Vec::new();
+    let mut remaining_tasks = 0;
+    let mut remaining = 0.0;
+    let mut unestimated = 0;
+    for (_, task) in tasks {
+        if done_statuses.contains(&task.status.as_str().to_ascii_lowercase()) {
+            continue;
+        }
+        remaining_tasks += 1;
+        match metric_value(task.effort.as_deref(), options.metric) {
+            Some(value) => remaining += value,
+            None => unestimated += 1,
+        }
+    }
+    if unestimated > 0 {
+        warnings.push(format!(
+            "{} open task(s) have no estimate in {} and are not counted.",
+            unestimated,
+            metric_label(options.metric)
+        ));
+    }
+
+    let (history, period_days) = match options.basis {
+        ForecastBasis::Sprint => sprint_history(storage, config, options, now)?,
+        ForecastBasis::Week => (
+            week_history(storage, repo_root, config, project, options, now),
+            7.0,
+        ),
+    };
+    if history.iter().all(|value| *value <= 0.0) {
+        return Err(format!(
+            "No completed {} in the last {} {}(s); nothing to base a forecast on.",
+            metric_label(options.metric),
+            history.len(),
+            options.basis.as_str()
+        ));
+    }
+    if history.len() < 3 {
+        warnings.push(format!(
+            "Only {} {}(s) of history; the forecast will be rough.",
+            history.len(),
+            options.basis.as_str()
+        ));
+    }
+
+    let iterations = options.iterations.unwrap_or(DEFAULT_ITERATIONS).max(1);
+    let seed = options
+        .seed
+        .unwrap_or_else(|| now.timestamp_nanos_opt().unwrap_or_default() as u64);
+    let mut outcomes = simulate(remaining, &history, iterations, seed);
+    outcomes.sort_unstable();
+
+    let unfinished = outcomes
+        .iter()
+        .filter(|periods| **periods > MAX_PERIODS)
+        .count();
+    if unfinished > 0 {
+        warnings.push(format!(
+            "{} of {} runs did not finish within {} {}s.",
+            unfinished,
+            iterations,
+            MAX_PERIODS,
+            options.basis.as_str()
+        ));
+    }
+
+    let date_after = |periods: usize| {
+        now + Duration::seconds((periods as f64 * period_days * 86_400.0).round() as i64)
+    };
+    let percentiles = PERCENTILES
+        .iter()
+        .map(|pct| {
+            let rank = ((*pct as f64 / 100.0) * outcomes.len() as f64).ceil() as usize;
+            let periods = outcomes[rank.clamp(1, outcomes.len()) - 1];
+            ForecastPercentile {
+                percentile: *pct,
+                periods,
+                date: date_after(periods).date_naive().to_string(),
+            }
+        })
+        .collect();
+    let target = options.target.map(|target| {
+        let hits = outcomes
+            .iter()
+            .filter(|periods| {
+                **periods <= MAX_PERIODS && date_after(**periods).date_naive() <= target
+            })
+            .count();
+        ForecastTarget {
+            date: target.to_string(),
+            probability: hits as f64 / iterations as f64,
+        }
+    });
+
+    Ok(ForecastReport {
+        basis: options.basis,
+        metric: metric_label(options.metric).to_string(),
+        selected: tasks.len(),
+        remaining_tasks,
+        remaining,
+        unestimated,
+        history,
+        period_days,
+        iterations,
+        percentiles,
+        target,
+        warnings,
+    })
+}
+
+/// Parse a `--target` value: a calendar date, or any expression accepted for due dates.
+pub fn parse_target(raw: &str) -> Result<NaiveDate, String> {
+    if let Ok(date) = NaiveDate::parse_from_str(raw.trim(), "%Y-%m-%d") {
+        return Ok(date);
+    }
+    crate::utils::time::parse_human_datetime_to_utc(raw)
+        .map(|at| at.with_timezone(&Local).date_naive())
+        .map_err(|err| format!("Invalid target: {}", err))
+}
+
+fn metric_value(effort: Option<&str>, metric: SprintBurndownMetric) -> Option<f64> {
+    if metric == SprintBurndownMetric::Tasks {
+        return Some(1.0);
+    }
+    let parsed = effort::parse_effort(effort?).ok()?;
+    match (parsed.kind, metric) {
+        (EffortKind::Points(points), SprintBurndownMetric::Points) => Some(points),
+        (EffortKind::TimeHours(hours), SprintBurndownMetric::Hours) => Some(hours),
+        _ => None,
+    }
+}
+
+fn sprint_history(
+    storage: &Storage,
+    config: &ResolvedConfig,
+    options: &ForecastOptions,
+    now: DateTime<Utc>,
+) -> Result<(Vec<f64>, f64), String> {
+    let records = SprintService::list(storage).map_err(|err| err.to_string())?;
+    let velocity = sprint_velocity::compute_velocity(
+        storage,
+        &records,
+        config,
+        &VelocityOptions {
+            limit: options.history.unwrap_or(DEFAULT_VELOCITY_WINDOW).max(1),
+            include_active: false,
+            metric: options.metric,
+        },
+        now,
+    );
+    if velocity.entries.is_empty() {
+        return Err(
+            "No closed sprints to sample; close a sprint first or forecast with --by week."
+                .to_string(),
+        );
+    }
+
+    let durations: Vec<f64> = velocity
+        .entries
+        .iter()
+        .filter_map(|entry| entry.duration)
+        .map(|duration| duration.num_seconds() as f64 / 86_400.0)
+        .collect();
+    let period_days = if durations.is_empty() {
+        config
+            .sprint_defaults
+            .length
+            .as_deref()
+            .and_then(crate::utils::time::parse_duration_like)
+            .map(|duration| duration.num_seconds() as f64 / 86_400.0)
+            .unwrap_or(14.0)
+    } else {
+        durations.iter().sum::<f64>() / durations.len() as f64
+    };
+
+    let mut history: Vec<f64> = velocity
+        .entries
+        .iter()
+        .map(|entry| entry.completed)
+        .collect();
+    history.reverse();
+    Ok((history, period_days))
+}
+
+fn week_history(
+    storage: &Storage,
+    repo_root: Option<&Path>,
+    config: &ResolvedConfig,
+    project: Option<&str>,
+    options: &ForecastOptions,
+    now: DateTime<Utc>,
+) -> Vec<f64> {
+    let weeks = options.history.unwrap_or(DEFAULT_WEEK_HISTORY).max(1);
+    let done_statuses = determine_done_statuses_from_config(config);
+    let filter = TaskListFilter {
+        project: project.map(str::to_string),
+        ..Default::default()
+    };
+    let efforts: BTreeMap<String, Option<String>> = TaskService::list(storage, &filter)
+        .into_iter()
+        .map(|(id, task)| (id, task.effort))
+        .collect();
+
+    let mut history = vec![0.0; weeks];
+    for timeline in flow_metrics::load_timelines(storage, repo_root, project) {
+        let Some(done_at) = flow_metrics::completion_time(&timeline, &done_statuses) else {
+            continue;
+        };
+        if done_at > now {
+            continue;
+        }
+        let weeks_ago = ((now - done_at).num_seconds() / (7 * 86_400)) as usize;
+        if weeks_ago >= weeks {
+            continue;
+        }
+        let effort = efforts.get(&timeline.id).cloned().flatten();
+        if let Some(value) = metric_value(effort.as_deref(), options.metric) {
+            history[weeks - 1 - weeks_ago] += value;
+        }
+    }
+    history
+}
+
+/// Periods needed to burn down `remaining` in each run, drawing a random historical sample
+/// per period. Runs that never finish report `MAX_PERIODS + 1`.
+fn simulate(remaining: f64, history: &[f64], iterations: usize, seed: u64) -> Vec<usize> {
+    let mut rng = SplitMix64(seed);
+    (0..iterations)
+        .map(|_| {
+            let mut left = remaining;
+            let mut periods = 0;
+            while left > f64::EPSILON {
+                if periods > MAX_PERIODS {
+                    break;
+                }
+                left -= history[rng.below(history.len())];
+                periods += 1;
+            }
+            periods
+        })
+        .collect()
+}
+
+/// Small deterministic PRNG so forecasts are reproducible with `--seed`.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simulate_with_constant_history_is_exact() {
+        let outcomes = simulate(10.0, &[3.0, 3.0], 100, 7);
+        assert!(outcomes.iter().all(|periods| *periods == 4));
+        assert!(simulate(0.0, &[3.0], 10, 7).iter().all(|p| *p == 0));
+    }
+
+    #[test]
+    fn oversized_iterations_and_history_are_rejected() {
+        let options = ForecastOptions {
+            iterations: Some(MAX_ITERATIONS + 1),
+            ..Default::default()
+        };
+        assert!(options.validate().unwrap_err().contains("iterations"));
+        let options = ForecastOptions {
+            history: Some(MAX_HISTORY + 1),
+            ..Default::default()
+        };
+        assert!(options.validate().unwrap_err().contains("history"));
+        let options = ForecastOptions {
+            iterations: Some(MAX_ITERATIONS),
+            history: Some(MAX_HISTORY),
+            ..Default::default()
+        };
+        assert!(options.validate().is_ok());
+    }
+
+    #[test]
+    fn simulate_is_reproducible_and_caps_stalled_runs() {
+        let history = [0.0, 1.0, 5.0];
+        assert_eq!(
+            simulate(20.0, &history, 50, 42),
+            simulate(20.0, &history, 50, 42)
+        );
+        assert!(
+            simulate(1.0, &[0.0], 3, 1)
+                .iter()
+                .all(|periods| *periods == MAX_PERIODS + 1)
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+
+use crate::api_types::{TaskDTO, TaskListFilter};
+use crate::config::types::ResolvedConfig;
+use crate::services::sprint_assignment::sprint_display_name;
+use crate::services::sprint_metrics::determine_done_statuses_from_config;
+use crate::services::sprint_service::SprintService;
+use crate::services::sprint_status;
+use crate::services::sprint_timing::{resolve_calendar_end, resolve_calendar_start};
+use crate::services::task_service::TaskService;
+use crate::storage::manager::Storage;
+
+/// Which entries go into an iCalendar export.
+#[derive(Debug, Clone)]
+pub struct CalendarOptions {
+    pub project: Option<String>,
+    /// Only tasks assigned to this user (already resolved from `@me`).
+    pub assignee: Option<String>,
+    pub tags: Vec<String>,
+    pub include_tasks: bool,
+    pub include_sprints: bool,
+    /// Keep tasks that are already in a done status.
+    pub include_done: bool,
+    /// Emit task due dates as VTODO instead of all-day VEVENT entries.
+    pub todos: bool,
+}
+
+impl Default for CalendarOptions {
+    fn default() -> Self {
+        Self {
+            project: None,
+            assignee: None,
+            tags: Vec::new(),
+            include_tasks: true,
+            include_sprints: true,
+            include_done: false,
+            todos: false,
+        }
+    }
+}
+
+/// Rendered calendar plus the number of entries it holds.
+#[derive(Debug, Clone)]
+pub struct CalendarExport {
+    pub body: String,
+    pub tasks: usize,
+    pub sprints: usize,
+}
+
+/// Build an RFC 5545 calendar with task due dates and sprint windows.
+pub fn export_calendar(
+    storage: &Storage,
+    config: &ResolvedConfig,
+    options: &CalendarOptions,
+    now: DateTime<Utc>,
+) -> CalendarExport {
+    let stamp = format_datetime(now);
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//LoTaR//Task Calendar//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!(
+            "X-WR-CALNAME:{}",
+            escape_text(&match options.project.as_deref() {
+                Some(project) => format!("LoTaR {}", project),
+                None => "LoTaR".to_string(),
+            })
+        ),
+    ];
+
+    let mut task_count = 0;
+    if options.include_tasks {
+        let done_statuses = determine_done_statuses_from_config(config);
+        for (id, task) in calendar_tasks(storage, options) {
+            let done = done_statuses.contains(&task.status.as_str().to_ascii_lowercase());
+            if done && !options.include_done {
+                continue;
+            }
+            let Some(due) = task.due_date.as_deref().and_then(parse_due_date) else {
+                continue;
+            };
+            let kind = if options.todos {
+                TaskEntry::Todo { done }
+            } else {
+                TaskEntry::Event
+            };
+            task_lines(&mut lines, &id, &task, due, &stamp, kind);
+            task_count += 1;
+        }
+    }
+
+    let mut sprint_count = 0;
+    if options.include_sprints {
+        for record in SprintService::list(storage).unwrap_or_default() {
+            let lifecycle = sprint_status::derive_status(&record.sprint, now);
+            let (Some(start), Some(end)) = (
+                resolve_calendar_start(&record, &lifecycle),
+                resolve_calendar_end(&record, &lifecycle),
+            ) else {
+                continue;
+            };
+            if end <= start {
+                continue;
+            }
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!("UID:sprint-{}@lotar", record.id));
+            lines.push(format!("DTSTAMP:{}", stamp));
+            lines.push(format!("DTSTART:{}", format_datetime(start)));
+            lines.push(format!("DTEND:{}", format_datetime(end)));
+            lines.push(format!(
+                "SUMMARY:{}",
+                escape_text(&sprint_display_name(&record))
+            ));
+            let goal = record
+                .sprint
+                .plan
+                .as_ref()
+                .and_then(|plan| plan.goal.as_deref())
+                .filter(|goal| !goal.trim().is_empty());
+            let description = match goal {
+                Some(goal) => format!("Sprint #{} ({})\n{}", record.id, lifecycle.label(), goal),
+                None => format!("Sprint #{} ({})", record.id, lifecycle.label()),
+            };
+            lines.push(format!("DESCRIPTION:{}", escape_text(&description)));
+            lines.push("CATEGORIES:Sprint".to_string());
+            lines.push("TRANSP:TRANSPARENT".to_string());
+            lines.push("END:VEVENT".to_string());
+            sprint_count += 1;
+        }
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+    let mut body = String::new();
+    for line in lines {
+        body.push_str(&fold_line(&line));
+        body.push_str("\r\n");
+    }
+    CalendarExport {
+        body,
+        tasks: task_count,
+        sprints: sprint_count,
+    }
+}
+
+#[derive(Clone, Copy)]
+enum TaskEntry {
+    Event,
+    Todo { done: bool },
+}
+
+fn calendar_tasks(storage: &Storage, options: &CalendarOptions) -> Vec<(String, TaskDTO)> {
+    let filter = TaskListFilter {
+        project: options.project.clone(),
+        tags: options.tags.clone(),
+        ..Default::default()
+    };
+    TaskService::list(storage, &filter)
+        .into_iter()
+        .filter(|(_, task)| match options.assignee.as_deref() {
+            Some(wanted) => task.assignee.as_deref().is_some_and(|assignee| {
+                assignee
+                    .trim_start_matches('@')
+                    .eq_ignore_ascii_case(wanted.trim_start_matches('@'))
+            }),
+            None => true,
+        })
+        .collect()
+}
+
+fn task_lines(
+    lines: &mut Vec<String>,
+    id: &str,
+    task: &TaskDTO,
+    due: NaiveDate,
+    stamp: &str,
+    kind: TaskEntry,
+) {
+    let component = match kind {
+        TaskEntry::Event => "VEVENT",
+        TaskEntry::Todo { .. } => "VTODO",
+    };
+    lines.push(format!("BEGIN:{}", component));
+    lines.push(format!("UID:{}@lotar", id));
+    lines.push(format!("DTSTAMP:{}", stamp));
+    match kind {
+        TaskEntry::Event => {
+            lines.push(format!("DTSTART;VALUE=DATE:{}", format_date(due)));
+            lines.push(format!(
+                "DTEND;VALUE=DATE:{}",
+                format_date(due + Duration::days(1))
+            ));
+            lines.push("TRANSP:TRANSPARENT".to_string());
+        }
+        TaskEntry::Todo { done } => {
+            lines.push(format!("DUE;VALUE=DATE:{}", format_date(due)));
+            let status = if done { "COMPLETED" } else { "NEEDS-ACTION" };
+            lines.push(format!("STATUS:{}", status));
+        }
+    }
+    lines.push(format!(
+        "SUMMARY:{}",
+        escape_text(&format!("{} {}", id, task.title))
+    ));
+    let mut description = format!(
+        "Status: {}\nPriority: {}\nType: {}",
+        task.status, task.priority, task.task_type
+    );
+    if let Some(assignee) = task.assignee.as_deref() {
+        description.push_str(&format!("\nAssignee: {}", assignee));
+    }
+    lines.push(format!("DESCRIPTION:{}", escape_text(&description)));
+    if !task.tags.is_empty() {
+        lines.push(format!(
+            "CATEGORIES:{}",
+            task.tags
+                .iter()
+                .map(|tag| escape_text(tag))
+                .collect::<Vec<_>>()
+                .join(",")
+        ));
+    }
+    lines.push(format!("END:{}", component));
+}
+
+fn parse_due_date(raw: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(raw.trim(), "%Y-%m-%d")
+        .ok()
+        .or_else(|| {
+            crate::cli::validation::parse_due_string_to_utc(raw.trim())
+                .map(|at| at.with_timezone(&Local).date_naive())
+        })
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+fn format_datetime(at: DateTime<Utc>) -> String {
+    at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape a TEXT value (RFC 5545 section 3.3.11).
+fn escape_text(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Fold content lines longer than 75 octets without splitting UTF-8 characters.
+fn fold_line(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + line.len() / 74 * 3);
+    let mut width = 0;
+    for ch in line.chars() {
+        let len = ch.len_utf8();
+        if width + len > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(ch);
+        width += len;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_text_and_folds_long_lines() {
+        assert_eq!(escape_text("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+        let folded = fold_line(&format!("SUMMARY:{}", "é".repeat(60)));
+        let parts: Vec<&str> = folded.split("\r\n").collect();
+        assert!(parts.len() > 1);
+        assert!(parts.iter().all(|part| part.len() <= 75));
+        assert!(parts[1..].iter().all(|part| part.starts_with(' ')));
+    }
+}
@@ -0,0 +1,592 @@
+//! Structured three-way merge of task and sprint YAML (`lotar merge-driver`).
+//!
+//! Git hands the driver the common ancestor, our version and their version of a file.
+//! Mappings are merged key by key, so edits to different fields never conflict. Lists are
+//! merged as a union that honours removals made on either side, and lists of timestamped
+//! entries (comments, history) are re-ordered by their `date`/`at` key. Only a field that
+//! both sides changed to different values is reported as a conflict; those top-level keys
+//! are written with the usual `<<<<<<<` / `=======` / `>>>>>>>` markers.
+
+use chrono::DateTime;
+use serde::Serialize;
+use serde_yaml::{Mapping, Value};
+use std::cmp::Ordering;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use crate::errors::{LoTaRError, LoTaRResult};
+use crate::storage::sprint::Sprint;
+use crate::storage::task::Task;
+use crate::utils::filesystem::{YAML_MODELINE_PREFIX, write_yaml_preserving_modeline};
+
+/// Keys that hold a last-touched timestamp; both sides touching them is not a conflict.
+const TIMESTAMP_KEYS: &[&str] = &["modified"];
+/// Keys that order entries of a list chronologically (comments use `date`, history uses `at`).
+const ENTRY_TIME_KEYS: &[&str] = &["date", "at"];
+
+/// Kind of document being merged; decides how the merged value is written back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeKind {
+    Task,
+    Sprint,
+    Yaml,
+}
+
+impl MergeKind {
+    /// Pick the kind from the repository path git passes as `%P`, if any.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let normalized = path.replace('\\', "/");
+        let file = Path::new(&normalized);
+        if file.extension().and_then(|ext| ext.to_str()) != Some("yml") {
+            return None;
+        }
+        let parent = file
+            .parent()
+            .and_then(|p| p.file_name())
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+        if parent == "@sprints" || parent == "sprints" {
+            return Some(MergeKind::Sprint);
+        }
+        let numeric = file
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .map(|stem| !stem.is_empty() && stem.chars().all(|c| c.is_ascii_digit()))
+            .unwrap_or(false);
+        numeric.then_some(MergeKind::Task)
+    }
+
+    fn detect(ours: &Value) -> Self {
+        if serde_yaml::from_value::<Task>(ours.clone()).is_ok() {
+            return MergeKind::Task;
+        }
+        let looks_like_sprint = ours.as_mapping().is_some_and(|map| {
+            ["plan", "actual", "tasks"]
+                .iter()
+                .any(|key| map.contains_key(Value::from(*key)))
+        });
+        if looks_like_sprint {
+            MergeKind::Sprint
+        } else {
+            MergeKind::Yaml
+        }
+    }
+}
+
+/// Result of merging one file.
+#[derive(Debug, Clone, Serialize)]
+pub struct MergeOutcome {
+    pub kind: MergeKind,
+    /// Merged file content (with conflict markers when `conflicts` is non-empty).
+    pub content: String,
+    /// Dotted paths of fields both sides changed differently.
+    pub conflicts: Vec<String>,
+    /// True when the inputs were not valid YAML and a line-based merge was used instead.
+    pub textual: bool,
+    /// True when both branches created a different task under the same ID; the file holds
+    /// both tasks between markers for `lotar renumber` to split.
+    pub id_collision: bool,
+}
+
+impl MergeOutcome {
+    pub fn is_clean(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+pub struct MergeService;
+
+impl MergeService {
+    /// Merge `base` and `theirs` into `ours` in place, as git expects from a merge driver.
+    ///
+    /// `path_hint` is the path of the file inside the repository (`%P`); without it the
+    /// kind is detected from the content of our version.
+    pub fn merge_files(
+        base: &Path,
+        ours: &Path,
+        theirs: &Path,
+        path_hint: Option<&str>,
+    ) -> LoTaRResult<MergeOutcome> {
+        let base_text = fs::read_to_string(base)?;
+        let ours_text = fs::read_to_string(ours)?;
+        let theirs_text = fs::read_to_string(theirs)?;
+        let kind = path_hint.and_then(MergeKind::from_path);
+
+        match Self::merge_documents(kind, &base_text, &ours_text, &theirs_text) {
+            Ok(outcome) => {
+                write_yaml_preserving_modeline(ours, &outcome.content)?;
+                Ok(outcome)
+            }
+            Err(_) => Self::merge_textual(base, ours, theirs, kind.unwrap_or(MergeKind::Yaml)),
+        }
+    }
+
+    /// Merge three YAML documents. Fails only when one of them is not valid YAML.
+    pub fn merge_documents(
+        kind: Option<MergeKind>,
+        base: &str,
+        ours: &str,
+        theirs: &str,
+    ) -> Result<MergeOutcome, serde_yaml::Error> {
+        let added_on_both = base.trim().is_empty();
+        let (ours_text, theirs_text) = (ours, theirs);
+        let base = parse_document(base)?;
+        let ours = parse_document(ours)?;
+        let theirs = parse_document(theirs)?;
+        let kind = kind.unwrap_or_else(|| MergeKind::detect(&ours));
+
+        if kind == MergeKind::Task && added_on_both && created_differs(&ours, &theirs) {
+            return Ok(MergeOutcome {
+                kind,
+                content: format!(
+                    "<<<<<<< ours\n{}=======\n{}>>>>>>> theirs\n",
+                    without_modeline(ours_text),
+                    without_modeline(theirs_text)
+                ),
+                conflicts: vec!["created".to_string()],
+                textual: false,
+                id_collision: true,
+            });
+        }
+
+        let mut conflicts = Vec::new();
+        let merged = merge_value(
+            Some(&base),
+            Some(&ours),
+            Some(&theirs),
+            "",
+            Side::Ours,
+            &mut conflicts,
+        );
+
+        let content = if conflicts.is_empty() {
+            render_clean(kind, merged.as_ref().unwrap_or(&Value::Null))?
+        } else {
+            let theirs_view = merge_value(
+                Some(&base),
+                Some(&ours),
+                Some(&theirs),
+                "",
+                Side::Theirs,
+                &mut Vec::new(),
+            );
+            render_conflicted(
+                merged.as_ref(),
+                theirs_view.as_ref(),
+                &top_level_keys(&conflicts),
+            )?
+        };
+
+        Ok(MergeOutcome {
+            kind,
+            content,
+            conflicts,
+            textual: false,
+            id_collision: false,
+        })
+    }
+
+    fn merge_textual(
+        base: &Path,
+        ours: &Path,
+        theirs: &Path,
+        kind: MergeKind,
+    ) -> LoTaRResult<MergeOutcome> {
+        let output = Command::new("git")
+            .args(["merge-file", "-L", "ours", "-L", "base", "-L", "theirs"])
+            .arg(ours)
+            .arg(base)
+            .arg(theirs)
+            .output()
+            .map_err(|err| LoTaRError::ValidationError(format!("Failed to execute git: {err}")))?;
+        // git merge-file exits with the number of conflicts (capped at 127); larger codes are errors.
+        let conflicts = match output.status.code() {
+            Some(0) => Vec::new(),
+            Some(code @ 1..=127) => (1..=code).map(|n| format!("hunk {n}")).collect(),
+            _ => {
+                return Err(LoTaRError::ValidationError(format!(
+                    "git merge-file failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                )));
+            }
+        };
+        Ok(MergeOutcome {
+            kind,
+            content: fs::read_to_string(ours)?,
+            conflicts,
+            textual: true,
+            id_collision: false,
+        })
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Side {
+    Ours,
+    Theirs,
+}
+
+fn parse_document(text: &str) -> Result<Value, serde_yaml::Error> {
+    if text.trim().is_empty() {
+        // Files added on both branches have an empty ancestor.
+        return Ok(Value::Mapping(Mapping::new()));
+    }
+    serde_yaml::from_str(text)
+}
+
+/// Two versions of a new task file describe different tasks when their creation times differ.
+fn created_differs(ours: &Value, theirs: &Value) -> bool {
+    let created = |value: &Value| value.get("created").cloned();
+    matches!((created(ours), created(theirs)), (Some(a), Some(b)) if a != b)
+}
+
+/// Raw document text without its schema modeline, terminated by a newline.
+fn without_modeline(text: &str) -> String {
+    let body = match text.split_once('\n') {
+        Some((first, rest)) if first.starts_with(YAML_MODELINE_PREFIX) => rest,
+        _ => text,
+    };
+    if body.ends_with('\n') || body.is_empty() {
+        body.to_string()
+    } else {
+        format!("{body}\n")
+    }
+}
+
+/// Three-way merge of optional values (`None` = key absent). Conflicts are recorded in
+/// `conflicts` and resolved towards `prefer` so the caller can render both views.
+fn merge_value(
+    base: Option<&Value>,
+    ours: Option<&Value>,
+    theirs: Option<&Value>,
+    path: &str,
+    prefer: Side,
+    conflicts: &mut Vec<String>,
+) -> Option<Value> {
+    if ours == theirs {
+        return ours.cloned();
+    }
+    if ours == base {
+        return theirs.cloned();
+    }
+    if theirs == base {
+        return ours.cloned();
+    }
+
+    match (ours, theirs) {
+        (Some(Value::Mapping(o)), Some(Value::Mapping(t))) => {
+            let empty = Mapping::new();
+            let b = base.and_then(Value::as_mapping).unwrap_or(&empty);
+            Some(Value::Mapping(merge_mapping(
+                b, o, t, path, prefer, conflicts,
+            )))
+        }
+        (Some(Value::Sequence(o)), Some(Value::Sequence(t))) => {
+            let b = base.and_then(Value::as_sequence).map(Vec::as_slice);
+            Some(Value::Sequence(merge_sequence(b.unwrap_or(&[]), o, t)))
+        }
+        (Some(o), Some(t)) if is_timestamp_key(path) => Some(latest(o, t).clone()),
+        _ => {
+            conflicts.push(if path.is_empty() {
+                "(document)".to_string()
+            } else {
+                path.to_string()
+            });
+            match prefer {
+                Side::Ours => ours.cloned(),
+                Side::Theirs => theirs.cloned(),
+            }
+        }
+    }
+}
+
+fn merge_mapping(
+    base: &Mapping,
+    ours: &Mapping,
+    theirs: &Mapping,
+    path: &str,
+    prefer: Side,
+    conflicts: &mut Vec<String>,
+) -> Mapping {
+    let mut keys: Vec<&Value> = ours.keys().collect();
+    keys.extend(theirs.keys().filter(|key| !ours.contains_key(*key)));
+
+    let mut merged = Mapping::new();
+    for key in keys {
+        let child_path = match key_label(key) {
+            label if path.is_empty() => label,
+            label => format!("{path}.{label}"),
+        };
+        if let Some(value) = merge_value(
+            base.get(key),
+            ours.get(key),
+            theirs.get(key),
+            &child_path,
+            prefer,
+            conflicts,
+        ) {
+            merged.insert(key.clone(), value);
+        }
+    }
+    merged
+}
+
+/// Three-way list merge: base entries that either side removed or edited are dropped, the
+/// entries both sides kept stay in our order, then our additions and theirs are appended.
+/// Entries are matched one for one, so duplicate values are counted rather than collapsed.
+fn merge_sequence(base: &[Value], ours: &[Value], theirs: &[Value]) -> Vec<Value> {
+    let ours_from_base = match_base(base, ours);
+    let theirs_from_base = match_base(base, theirs);
+    let mut kept_by_theirs: Vec<&Value> = theirs
+        .iter()
+        .zip(&theirs_from_base)
+        .filter_map(|(item, from_base)| from_base.then_some(item))
+        .collect();
+
+    let mut merged: Vec<Value> = Vec::new();
+    for (item, from_base) in ours.iter().zip(ours_from_base) {
+        if !from_base || take_match(&mut kept_by_theirs, item) {
+            merged.push(item.clone());
+        }
+    }
+    for (item, from_base) in theirs.iter().zip(theirs_from_base) {
+        if !from_base && !merged.contains(item) {
+            merged.push(item.clone());
+        }
+    }
+
+    let timestamped = !merged.is_empty() && merged.iter().all(|item| entry_time(item).is_some());
+    if timestamped {
+        merged.sort_by(|a, b| compare_timestamps(entry_time(a), entry_time(b)));
+    }
+    merged
+}
+
+/// For each entry of `side`, whether it is an unchanged base entry (as opposed to an addition).
+fn match_base(base: &[Value], side: &[Value]) -> Vec<bool> {
+    let mut pool: Vec<&Value> = base.iter().collect();
+    side.iter()
+        .map(|item| take_match(&mut pool, item))
+        .collect()
+}
+
+fn take_match(pool: &mut Vec<&Value>, item: &Value) -> bool {
+    match pool.iter().position(|candidate| *candidate == item) {
+        Some(index) => {
+            pool.remove(index);
+            true
+        }
+        None => false,
+    }
+}
+
+fn entry_time(item: &Value) -> Option<&str> {
+    let map = item.as_mapping()?;
+    ENTRY_TIME_KEYS
+        .iter()
+        .find_map(|key| map.get(Value::from(*key)).and_then(Value::as_str))
+}
+
+fn compare_timestamps(a: Option<&str>, b: Option<&str>) -> Ordering {
+    let (Some(a), Some(b)) = (a, b) else {
+        return Ordering::Equal;
+    };
+    match (
+        DateTime::parse_from_rfc3339(a),
+        DateTime::parse_from_rfc3339(b),
+    ) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+fn is_timestamp_key(path: &str) -> bool {
+    TIMESTAMP_KEYS.contains(&path)
+}
+
+fn latest<'a>(ours: &'a Value, theirs: &'a Value) -> &'a Value {
+    match compare_timestamps(ours.as_str(), theirs.as_str()) {
+        Ordering::Less => theirs,
+        _ => ours,
+    }
+}
+
+fn key_label(key: &Value) -> String {
+    match key {
+        Value::String(s) => s.clone(),
+        other => serde_yaml::to_string(other)
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+fn top_level_keys(conflicts: &[String]) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    for path in conflicts {
+        let key = path.split('.').next().unwrap_or(path).to_string();
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+    keys
+}
+
+/// Write a conflict-free merge in the canonical form lotar itself would write.
+fn render_clean(kind: MergeKind, merged: &Value) -> Result<String, serde_yaml::Error> {
+    match kind {
+        MergeKind::Task => match serde_yaml::from_value::<Task>(merged.clone()) {
+            Ok(task) => serde_yaml::to_string(&task),
+            Err(_) => serde_yaml::to_string(merged),
+        },
+        MergeKind::Sprint => match serde_yaml::from_value::<Sprint>(merged.clone()) {
+            Ok(mut sprint) => {
+                sprint.canonicalize();
+                sprint.to_yaml()
+            }
+            Err(_) => serde_yaml::to_string(merged),
+        },
+        MergeKind::Yaml => serde_yaml::to_string(merged),
+    }
+}
+
+/// Write the merged document key by key, wrapping conflicting top-level keys in markers.
+fn render_conflicted(
+    ours_view: Option<&Value>,
+    theirs_view: Option<&Value>,
+    conflicting: &[String],
+) -> Result<String, serde_yaml::Error> {
+    let (Some(Value::Mapping(ours)), Some(Value::Mapping(theirs))) = (ours_view, theirs_view)
+    else {
+        return Ok(format!(
+            "<<<<<<< ours\n{}=======\n{}>>>>>>> theirs\n",
+            render_optional(ours_view)?,
+            render_optional(theirs_view)?
+        ));
+    };
+
+    let mut keys: Vec<&Value> = ours.keys().collect();
+    keys.extend(theirs.keys().filter(|key| !ours.contains_key(*key)));
+
+    let mut out = String::new();
+    for key in keys {
+        let ours_entry = render_entry(key, ours.get(key))?;
+        if conflicting.contains(&key_label(key)) {
+            let theirs_entry = render_entry(key, theirs.get(key))?;
+            out.push_str("<<<<<<< ours\n");
+            out.push_str(&ours_entry);
+            out.push_str("=======\n");
+            out.push_str(&theirs_entry);
+            out.push_str(">>>>>>> theirs\n");
+        } else {
+            out.push_str(&ours_entry);
+        }
+    }
+    Ok(out)
+}
+
+fn render_entry(key: &Value, value: Option<&Value>) -> Result<String, serde_yaml::Error> {
+    let Some(value) = value else {
+        return Ok(String::new());
+    };
+    let mut single = Mapping::new();
+    single.insert(key.clone(), value.clone());
+    serde_yaml::to_string(&Value::Mapping(single))
+}
+
+fn render_optional(value: Option<&Value>) -> Result<String, serde_yaml::Error> {
+    value.map_or(Ok(String::new()), serde_yaml::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = "title: Login\nstatus: Todo\npriority: Medium\ncreated: 2024-01-01T00:00:00Z\nmodified: 2024-01-01T00:00:00Z\ntags: [auth]\n";
+
+    #[test]
+    fn different_fields_and_appended_comments_merge_cleanly() {
+        let ours = format!(
+            "{}comments:\n- date: 2024-01-03T00:00:00Z\n  text: second\n",
+            BASE.replace("status: Todo", "status: InProgress").replace(
+                "modified: 2024-01-01T00:00:00Z",
+                "modified: 2024-01-03T00:00:00Z"
+            )
+        );
+        let theirs = format!(
+            "{}comments:\n- date: 2024-01-02T00:00:00Z\n  text: first\n",
+            BASE.replace("priority: Medium", "priority: High")
+                .replace("tags: [auth]", "tags: [auth, security]")
+                .replace(
+                    "modified: 2024-01-01T00:00:00Z",
+                    "modified: 2024-01-02T00:00:00Z"
+                )
+        );
+
+        let outcome = MergeService::merge_documents(None, BASE, &ours, &theirs).unwrap();
+        assert_eq!(outcome.kind, MergeKind::Task);
+        assert!(outcome.is_clean(), "{:?}", outcome.conflicts);
+
+        let task: Task = serde_yaml::from_str(&outcome.content).unwrap();
+        assert_eq!(task.status.as_str(), "InProgress");
+        assert_eq!(task.priority.as_str(), "High");
+        assert_eq!(task.tags, vec!["auth", "security"]);
+        assert_eq!(task.modified, "2024-01-03T00:00:00Z");
+        let texts: Vec<&str> = task.comments.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["first", "second"]);
+    }
+
+    #[test]
+    fn same_field_changes_conflict_with_markers() {
+        let ours = BASE.replace("status: Todo", "status: Done");
+        let theirs = BASE
+            .replace("status: Todo", "status: Blocked")
+            .replace("tags: [auth]", "tags: []");
+
+        let outcome = MergeService::merge_documents(None, BASE, &ours, &theirs).unwrap();
+        assert_eq!(outcome.conflicts, vec!["status"]);
+        assert!(
+            outcome
+                .content
+                .contains("<<<<<<< ours\nstatus: Done\n=======\nstatus: Blocked\n>>>>>>> theirs\n")
+        );
+        assert!(outcome.content.contains("tags: []"));
+    }
+
+    #[test]
+    fn edited_list_entries_replace_the_base_entry() {
+        let base = format!("{BASE}comments:\n- date: 2024-01-02T00:00:00Z\n  text: first\n");
+        let ours = base.replace("text: first", "text: first (edited)");
+        let theirs = format!("{base}- date: 2024-01-03T00:00:00Z\n  text: second\n");
+
+        for (ours, theirs) in [(&ours, &theirs), (&theirs, &ours)] {
+            let outcome = MergeService::merge_documents(None, &base, ours, theirs).unwrap();
+            assert!(outcome.is_clean(), "{:?}", outcome.conflicts);
+            let task: Task = serde_yaml::from_str(&outcome.content).unwrap();
+            let texts: Vec<&str> = task.comments.iter().map(|c| c.text.as_str()).collect();
+            assert_eq!(texts, vec!["first (edited)", "second"]);
+        }
+    }
+
+    #[test]
+    fn duplicate_list_entries_are_counted() {
+        let base = [Value::from("a"), Value::from("a"), Value::from("b")];
+        let ours = [Value::from("a"), Value::from("b")];
+        let theirs = base.to_vec();
+        assert_eq!(merge_sequence(&base, &ours, &theirs), ours.to_vec());
+    }
+
+    #[test]
+    fn kind_is_detected_from_repository_path() {
+        assert_eq!(
+            MergeKind::from_path(".tasks/@sprints/3.yml"),
+            Some(MergeKind::Sprint)
+        );
+        assert_eq!(
+            MergeKind::from_path(".tasks/AUTH/12.yml"),
+            Some(MergeKind::Task)
+        );
+        assert_eq!(MergeKind::from_path(".tasks/AUTH/config.yml"), None);
+    }
+}
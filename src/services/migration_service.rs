@@ -0,0 +1,586 @@
+//! Versioned schema migrations for config and task files (`lotar migrate`).
+//!
+//! Files carry a top-level `schema_version`; files without one are version 0. Each
+//! [`MigrationStep`] upgrades one kind of file from `from_version` to the next version by
+//! rewriting its raw YAML, so keys the step does not know about are preserved.
+//! Breaking format changes ship as a new step plus a bump of the matching
+//! `*_SCHEMA_VERSION` constant instead of a permanent compatibility shim.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_yaml::{Mapping, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::types::CONFIG_SCHEMA_VERSION;
+use crate::errors::{LoTaRError, LoTaRResult};
+use crate::storage::TaskRedirect;
+use crate::storage::task::TASK_SCHEMA_VERSION;
+
+const VERSION_KEY: &str = "schema_version";
+
+/// Kind of file a migration step applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationTarget {
+    GlobalConfig,
+    ProjectConfig,
+    Task,
+}
+
+impl MigrationTarget {
+    pub fn current_version(self) -> u32 {
+        match self {
+            MigrationTarget::GlobalConfig | MigrationTarget::ProjectConfig => CONFIG_SCHEMA_VERSION,
+            MigrationTarget::Task => TASK_SCHEMA_VERSION,
+        }
+    }
+}
+
+/// One registered upgrade from `from_version` to `from_version + 1`.
+pub struct MigrationStep {
+    pub name: &'static str,
+    pub summary: &'static str,
+    pub targets: &'static [MigrationTarget],
+    pub from_version: u32,
+    /// Rewrites the document in place; returns whether anything besides the version changed.
+    apply: fn(&mut Mapping, MigrationTarget) -> bool,
+}
+
+/// Registry of all migration steps, in version order.
+pub const MIGRATIONS: &[MigrationStep] = &[
+    MigrationStep {
+        name: "config-legacy-keys",
+        summary: "Move taxonomy.tags to issue.tags, drop taxonomy.categories and rename legacy project name keys to project.name",
+        targets: &[
+            MigrationTarget::GlobalConfig,
+            MigrationTarget::ProjectConfig,
+        ],
+        from_version: 0,
+        apply: migrate_config_legacy_keys,
+    },
+    MigrationStep {
+        name: "task-legacy-fields",
+        summary: "Rename task_type to type and fold subtitle into the description",
+        targets: &[MigrationTarget::Task],
+        from_version: 0,
+        apply: migrate_task_legacy_fields,
+    },
+];
+
+/// A document upgraded to the current schema version.
+#[derive(Debug, Clone)]
+pub struct MigratedDocument {
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Steps that changed content (the version bump itself is implied).
+    pub steps: Vec<&'static str>,
+    pub content: String,
+}
+
+/// Upgrade a single YAML document; `None` when it is already current (or empty).
+pub fn migrate_document(
+    target: MigrationTarget,
+    content: &str,
+) -> LoTaRResult<Option<MigratedDocument>> {
+    // Stubs left by `lotar task move` are not tasks and reject unknown keys.
+    if target == MigrationTarget::Task && TaskRedirect::parse(content).is_some() {
+        return Ok(None);
+    }
+    let value: Value = serde_yaml::from_str(content)?;
+    let mut map = match value {
+        Value::Null => return Ok(None),
+        Value::Mapping(map) => map,
+        _ => {
+            return Err(LoTaRError::ValidationError(
+                "expected a YAML mapping at the top level".to_string(),
+            ));
+        }
+    };
+
+    let current = target.current_version();
+    let from_version = match map.get(VERSION_KEY) {
+        None => 0,
+        Some(raw) => raw
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| LoTaRError::ValidationError(format!("invalid {} value", VERSION_KEY)))?,
+    };
+    if from_version > current {
+        return Err(LoTaRError::ValidationError(format!(
+            "{} {} is newer than this version of lotar supports ({})",
+            VERSION_KEY, from_version, current
+        )));
+    }
+    if from_version == current {
+        return Ok(None);
+    }
+
+    let mut steps = Vec::new();
+    for version in from_version..current {
+        for step in MIGRATIONS
+            .iter()
+            .filter(|step| step.from_version == version && step.targets.contains(&target))
+        {
+            if (step.apply)(&mut map, target) {
+                steps.push(step.name);
+            }
+        }
+    }
+
+    // Only a real step rewrites the document; a bare version bump keeps comments and layout.
+    let content = if steps.is_empty() {
+        stamp_version(content, current)
+    } else {
+        let mut upgraded = Mapping::new();
+        upgraded.insert(VERSION_KEY.into(), Value::from(current));
+        for (key, value) in map {
+            if key.as_str() != Some(VERSION_KEY) {
+                upgraded.insert(key, value);
+            }
+        }
+        serde_yaml::to_string(&Value::Mapping(upgraded))?
+    };
+
+    Ok(Some(MigratedDocument {
+        from_version,
+        to_version: current,
+        steps,
+        content,
+    }))
+}
+
+/// Set the top-level version key in the raw text, replacing an existing line or inserting
+/// one after any leading comments and document marker.
+fn stamp_version(content: &str, version: u32) -> String {
+    let stamp = format!("{}: {}\n", VERSION_KEY, version);
+    let prefix = format!("{}:", VERSION_KEY);
+    let mut out = String::with_capacity(content.len() + stamp.len());
+    let mut stamped = false;
+    let mut in_header = true;
+    for line in content.split_inclusive('\n') {
+        if line.starts_with(&prefix) && !stamped {
+            out.push_str(&stamp);
+            stamped = true;
+            continue;
+        }
+        if in_header && !line.starts_with('#') && line.trim_end() != "---" {
+            in_header = false;
+            if !stamped {
+                out.push_str(&stamp);
+                stamped = true;
+            }
+        }
+        out.push_str(line);
+    }
+    if !stamped {
+        if !out.is_empty() && !out.ends_with('\n') {
+            out.push('\n');
+        }
+        out.push_str(&stamp);
+    }
+    out
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MigrationOptions {
+    pub dry_run: bool,
+    /// Copy originals to `.tasks/.backups/migrate-<timestamp>/` before rewriting them.
+    pub backup: bool,
+    /// Limit the run to one project (its config and tasks); the global config is skipped.
+    pub project: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MigratedFile {
+    /// Path relative to the tasks directory.
+    pub path: String,
+    pub target: MigrationTarget,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub steps: Vec<String>,
+    /// Line diff of the rewrite (dry runs only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationFailure {
+    pub path: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationReport {
+    pub dry_run: bool,
+    pub scanned: usize,
+    pub files: Vec<MigratedFile>,
+    pub failures: Vec<MigrationFailure>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backup_dir: Option<String>,
+}
+
+/// Upgrade every config and task file under `tasks_dir` to the current schema versions.
+pub fn run(
+    tasks_dir: &Path,
+    options: &MigrationOptions,
+    now: DateTime<Utc>,
+) -> LoTaRResult<MigrationReport> {
+    let targets = collect_targets(tasks_dir, options.project.as_deref());
+    let mut report = MigrationReport {
+        dry_run: options.dry_run,
+        scanned: targets.len(),
+        files: Vec::new(),
+        failures: Vec::new(),
+        backup_dir: None,
+    };
+
+    let mut pending: Vec<(PathBuf, String)> = Vec::new();
+    for (target, path) in targets {
+        let relative = relative_display(tasks_dir, &path);
+        let outcome = fs::read_to_string(&path)
+            .map_err(LoTaRError::from)
+            .and_then(|content| {
+                migrate_document(target, &content).map(|migrated| (content, migrated))
+            });
+        match outcome {
+            Ok((_, None)) => {}
+            Ok((original, Some(migrated))) => {
+                report.files.push(MigratedFile {
+                    path: relative,
+                    target,
+                    from_version: migrated.from_version,
+                    to_version: migrated.to_version,
+                    steps: migrated.steps.iter().map(|s| s.to_string()).collect(),
+                    diff: options
+                        .dry_run
+                        .then(|| line_diff(&original, &migrated.content)),
+                });
+                pending.push((path, migrated.content));
+            }
+            Err(err) => report.failures.push(MigrationFailure {
+                path: relative,
+                error: err.to_string(),
+            }),
+        }
+    }
+
+    if options.dry_run || pending.is_empty() {
+        return Ok(report);
+    }
+
+    if options.backup {
+        let backup_dir = tasks_dir
+            .join(".backups")
+            .join(format!("migrate-{}", now.format("%Y%m%dT%H%M%SZ")));
+        for (path, _) in &pending {
+            let relative = path.strip_prefix(tasks_dir).unwrap_or(path);
+            let destination = backup_dir.join(relative);
+            if let Some(parent) = destination.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(path, &destination)?;
+        }
+        report.backup_dir = Some(backup_dir.display().to_string());
+    }
+
+    for (path, content) in &pending {
+        crate::utils::filesystem::write_yaml_preserving_modeline(path, content)?;
+    }
+    crate::config::resolution::invalidate_config_cache_for(Some(tasks_dir));
+    Ok(report)
+}
+
+fn collect_targets(tasks_dir: &Path, project: Option<&str>) -> Vec<(MigrationTarget, PathBuf)> {
+    let mut targets = Vec::new();
+    if project.is_none() {
+        let global = crate::utils::paths::global_config_path(tasks_dir);
+        if global.exists() {
+            targets.push((MigrationTarget::GlobalConfig, global));
+        }
+    }
+
+    let mut projects = crate::utils::filesystem::list_visible_subdirs(tasks_dir);
+    projects.sort();
+    for (prefix, dir) in projects {
+        if project.is_some_and(|wanted| wanted != prefix) {
+            continue;
+        }
+        let config = crate::utils::paths::project_config_path(tasks_dir, &prefix);
+        if config.exists() {
+            targets.push((MigrationTarget::ProjectConfig, config));
+        }
+        let mut tasks: Vec<(u64, PathBuf)> =
+            crate::utils::filesystem::list_files_with_ext(&dir, "yml")
+                .into_iter()
+                .filter_map(|path| {
+                    let number = path.file_stem()?.to_str()?.parse::<u64>().ok()?;
+                    Some((number, path))
+                })
+                .collect();
+        tasks.sort();
+        targets.extend(
+            tasks
+                .into_iter()
+                .map(|(_, path)| (MigrationTarget::Task, path)),
+        );
+    }
+    targets
+}
+
+fn relative_display(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn migrate_config_legacy_keys(map: &mut Mapping, target: MigrationTarget) -> bool {
+    let mut changed = false;
+    if let Some(tags) = take_key(map, &["taxonomy", "tags"]) {
+        if !has_key(map, &["issue", "tags"]) {
+            put_key(map, &["issue", "tags"], tags);
+        }
+        changed = true;
+    }
+    if take_key(map, &["taxonomy", "categories"]).is_some() {
+        changed = true;
+    }
+
+    if target == MigrationTarget::ProjectConfig {
+        let legacy_names: [&[&str]; 3] = [
+            &["project", "id"],
+            &["config", "project_name"],
+            &["project_name"],
+        ];
+        for legacy in legacy_names {
+            if let Some(name) = take_key(map, legacy) {
+                if !has_key(map, &["project", "name"]) {
+                    put_key(map, &["project", "name"], name);
+                }
+                changed = true;
+            }
+        }
+    }
+    map.retain(|key, value| {
+        !(matches!(key.as_str(), Some("taxonomy" | "project" | "config"))
+            && matches!(value, Value::Mapping(section) if section.is_empty()))
+    });
+    changed
+}
+
+fn migrate_task_legacy_fields(map: &mut Mapping, _target: MigrationTarget) -> bool {
+    let mut changed = false;
+    if map.contains_key("task_type") {
+        if map.contains_key("type") {
+            map.remove("task_type");
+        } else {
+            rename_key(map, "task_type", "type");
+        }
+        changed = true;
+    }
+
+    if let Some(subtitle) = map.remove("subtitle") {
+        changed = true;
+        if let Some(subtitle) = subtitle.as_str().map(str::trim).filter(|s| !s.is_empty()) {
+            let description = match map
+                .get("description")
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|d| !d.is_empty())
+            {
+                Some(existing) => format!("{}\n\n{}", subtitle, existing),
+                None => subtitle.to_string(),
+            };
+            map.insert("description".into(), Value::String(description));
+        }
+    }
+    changed
+}
+
+/// Remove a key given either as a dotted key (`a.b`) or as nested sections.
+/// Emptied sections are left in place so a replacement key can be nested there.
+fn take_key(map: &mut Mapping, path: &[&str]) -> Option<Value> {
+    if let Some(value) = map.remove(path.join(".").as_str()) {
+        return Some(value);
+    }
+    let (first, rest) = path.split_first()?;
+    if rest.is_empty() {
+        return None;
+    }
+    let Some(Value::Mapping(section)) = map.get_mut(*first) else {
+        return None;
+    };
+    take_key(section, rest)
+}
+
+fn has_key(map: &Mapping, path: &[&str]) -> bool {
+    if map.contains_key(path.join(".").as_str()) {
+        return true;
+    }
+    match path.split_first() {
+        Some((first, rest)) if !rest.is_empty() => match map.get(*first) {
+            Some(Value::Mapping(section)) => has_key(section, rest),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Insert into an existing nested section when the file has one, otherwise as a dotted key.
+fn put_key(map: &mut Mapping, path: &[&str], value: Value) {
+    if let Some((first, rest)) = path.split_first()
+        && !rest.is_empty()
+        && let Some(Value::Mapping(section)) = map.get_mut(*first)
+    {
+        put_key(section, rest, value);
+        return;
+    }
+    map.insert(path.join(".").into(), value);
+}
+
+fn rename_key(map: &mut Mapping, from: &str, to: &str) {
+    let entries = std::mem::take(map);
+    for (key, value) in entries {
+        if key.as_str() == Some(from) {
+            map.insert(to.into(), value);
+        } else {
+            map.insert(key, value);
+        }
+    }
+}
+
+/// Line diff with two lines of context around each change.
+fn line_diff(old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Longest common subsequence table, filled from the end.
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines: Vec<(char, &str)> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push((' ', old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(('-', old[i]));
+            i += 1;
+        } else {
+            lines.push(('+', new[j]));
+            j += 1;
+        }
+    }
+
+    const CONTEXT: usize = 2;
+    let near_change = |index: usize| {
+        let start = index.saturating_sub(CONTEXT);
+        let end = (index + CONTEXT + 1).min(lines.len());
+        lines[start..end].iter().any(|(mark, _)| *mark != ' ')
+    };
+    let mut out = String::new();
+    let mut skipped = false;
+    for (index, (mark, line)) in lines.iter().enumerate() {
+        if near_change(index) {
+            if skipped && !out.is_empty() {
+                out.push_str("...\n");
+            }
+            skipped = false;
+            out.push(*mark);
+            out.push(' ');
+            out.push_str(line);
+            out.push('\n');
+        } else {
+            skipped = true;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrates_legacy_task_and_project_config() {
+        let task = "title: Old\ntask_type: Bug\nsubtitle: Short\ndescription: Body\ncreated: 2024-01-01T00:00:00Z\n";
+        let migrated = migrate_document(MigrationTarget::Task, task)
+            .unwrap()
+            .expect("task upgraded");
+        assert_eq!(migrated.from_version, 0);
+        assert_eq!(migrated.steps, vec!["task-legacy-fields"]);
+        assert!(
+            migrated
+                .content
+                .starts_with("schema_version: 1\ntitle: Old\ntype: Bug\n")
+        );
+        assert!(
+            migrated.content.contains("Short\n\n  Body"),
+            "{}",
+            migrated.content
+        );
+        assert!(!migrated.content.contains("subtitle"));
+        assert!(
+            migrate_document(MigrationTarget::Task, &migrated.content)
+                .unwrap()
+                .is_none()
+        );
+
+        let config =
+            "project:\n  id: Legacy\ntaxonomy.tags: [ui]\nissue:\n  states: [Todo, Done]\n";
+        let migrated = migrate_document(MigrationTarget::ProjectConfig, config)
+            .unwrap()
+            .expect("config upgraded");
+        let value: Value = serde_yaml::from_str(&migrated.content).unwrap();
+        assert_eq!(value["project"]["name"], Value::from("Legacy"));
+        assert_eq!(value["issue"]["tags"][0], Value::from("ui"));
+        assert!(value.get("taxonomy.tags").is_none());
+
+        assert!(migrate_document(MigrationTarget::Task, "schema_version: 99\ntitle: x\n").is_err());
+    }
+
+    #[test]
+    fn version_only_upgrades_keep_comments_and_layout() {
+        let config = "# Team defaults\n# keep in sync with CI\ndefault:\n  project: AUTH  # main project\n\nissue:\n  states: [Todo, Done]\n";
+        let migrated = migrate_document(MigrationTarget::ProjectConfig, config)
+            .unwrap()
+            .expect("version stamped");
+        assert!(migrated.steps.is_empty());
+        assert_eq!(
+            migrated.content,
+            format!(
+                "# Team defaults\n# keep in sync with CI\nschema_version: {}\ndefault:\n  project: AUTH  # main project\n\nissue:\n  states: [Todo, Done]\n",
+                CONFIG_SCHEMA_VERSION
+            )
+        );
+
+        let task = "schema_version: 0\ntitle: Keep # not a comment\n";
+        let migrated = migrate_document(MigrationTarget::Task, task)
+            .unwrap()
+            .expect("version stamped");
+        assert_eq!(
+            migrated.content,
+            format!(
+                "schema_version: {}\ntitle: Keep # not a comment\n",
+                TASK_SCHEMA_VERSION
+            )
+        );
+    }
+
+    #[test]
+    fn line_diff_marks_changes_with_context() {
+        let diff = line_diff("a\nb\nc\nd\ne\nf\ng\n", "a\nb\nc\nD\ne\nf\ng\n");
+        assert_eq!(diff, "  b\n  c\n- d\n+ D\n  e\n  f\n");
+    }
+}
@@ -1,28 +1,44 @@
+pub mod agent_approval_service;
 pub mod agent_context_service;
 pub mod agent_job_service;
 pub mod agent_log_service;
+pub mod agent_pipeline_service;
 pub mod agent_queue_service;
 pub mod agent_runner;
+pub mod agent_worktree_service;
 pub mod attachment_service;
 pub mod audit_service;
 pub mod automation_matching;
 pub mod automation_service;
 pub mod automation_validation;
+pub mod check_service;
+pub mod commit_ingest_service;
 pub mod config_service;
+pub mod doctor_service;
+pub mod flow_metrics;
+pub mod forecast_service;
+pub mod ical_service;
+pub mod merge_service;
+pub mod migration_service;
 pub mod project_service;
 pub mod reference_service;
+pub mod sandbox;
 pub mod scan_service;
+pub mod schema_service;
 pub mod sprint_analytics;
 pub mod sprint_assignment;
+pub mod sprint_capacity;
 pub mod sprint_integrity;
 pub mod sprint_metrics;
 pub mod sprint_reports;
+pub mod sprint_rollover;
 pub mod sprint_service;
 pub mod sprint_status;
 pub mod sprint_timing;
 pub mod sprint_velocity;
 pub mod sync_report_service;
 pub mod sync_service;
+pub mod task_relocation_service;
 pub mod task_service;
 
 pub mod task_selection;
@@ -309,6 +309,7 @@ impl ReferenceService {
         let normalized = match kind.trim().to_ascii_lowercase().as_str() {
             "jira" => normalize_jira_reference(trimmed),
             "github" => normalize_github_reference(trimmed),
+            "commit" => normalize_commit_reference(trimmed)?,
             other => {
                 return Err(LoTaRError::ValidationError(format!(
                     "Unsupported reference kind: {}",
@@ -331,6 +332,10 @@ impl ReferenceService {
                 .references
                 .iter()
                 .any(|r| r.github.as_deref() == Some(normalized.as_str())),
+            "commit" => task
+                .references
+                .iter()
+                .any(|r| r.commit.as_deref() == Some(normalized.as_str())),
             _ => false,
         };
 
@@ -345,6 +350,10 @@ impl ReferenceService {
                     github: Some(normalized),
                     ..Default::default()
                 },
+                "commit" => ReferenceEntry {
+                    commit: Some(normalized),
+                    ..Default::default()
+                },
                 _ => {
                     return Err(LoTaRError::ValidationError(
                         "Unsupported reference kind".to_string(),
@@ -381,6 +390,7 @@ impl ReferenceService {
         let normalized = match kind.trim().to_ascii_lowercase().as_str() {
             "jira" => normalize_jira_reference(trimmed),
             "github" => normalize_github_reference(trimmed),
+            "commit" => normalize_commit_reference(trimmed)?,
             other => {
                 return Err(LoTaRError::ValidationError(format!(
                     "Unsupported reference kind: {}",
@@ -402,6 +412,9 @@ impl ReferenceService {
             "github" => task
                 .references
                 .retain(|r| r.github.as_deref() != Some(normalized.as_str())),
+            "commit" => task
+                .references
+                .retain(|r| r.commit.as_deref() != Some(normalized.as_str())),
             _ => {}
         }
 
@@ -696,6 +709,18 @@ fn normalize_github_reference(value: &str) -> String {
     trimmed
 }
 
+/// Commit references are stored as lowercase hex object names.
+fn normalize_commit_reference(value: &str) -> LoTaRResult<String> {
+    let sha = trim_platform_prefix(value, "commit").to_ascii_lowercase();
+    if sha.len() < 7 || sha.len() > 64 || !sha.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(LoTaRError::ValidationError(format!(
+            "Invalid commit reference: {}",
+            value.trim()
+        )));
+    }
+    Ok(sha)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1075,4 +1100,14 @@ mod tests {
         assert_eq!(normalize_github_reference("ORG/Repo#42"), "org/repo#42");
         assert_eq!(normalize_github_reference("repo#7"), "repo#7");
     }
+
+    #[test]
+    fn normalize_commit_reference_requires_hex_sha() {
+        assert_eq!(
+            normalize_commit_reference("commit:ABCDEF1234").unwrap(),
+            "abcdef1234"
+        );
+        assert!(normalize_commit_reference("abc").is_err());
+        assert!(normalize_commit_reference("not-a-sha").is_err());
+    }
 }
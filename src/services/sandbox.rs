@@ -0,0 +1,452 @@
+//! Sandbox limits for agent runners and automation `run` commands.
+//!
+//! A [`SandboxPolicy`] is resolved from a `sandbox:` block on an agent profile or
+//! automation rule. It trims the inherited environment, keeps the working directory
+//! inside the job worktree, applies CPU and memory rlimits, optionally cuts the
+//! process off from the network, and caps how much output it may produce.
+
+use crate::config::types::SandboxConfig;
+use crate::errors::{LoTaRError, LoTaRResult};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Sandbox limits resolved from a [`SandboxConfig`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SandboxPolicy {
+    env: Vec<String>,
+    worktree_only: bool,
+    cpu_seconds: Option<u64>,
+    memory_bytes: Option<u64>,
+    network: bool,
+    max_output: Option<u64>,
+}
+
+impl SandboxPolicy {
+    /// Validate a sandbox block and resolve its durations and sizes.
+    pub fn from_config(config: &SandboxConfig) -> LoTaRResult<Self> {
+        let cpu_seconds = match config.cpu_time.as_deref().map(str::trim) {
+            Some(raw) if !raw.is_empty() => {
+                let duration = crate::utils::time::parse_interval(raw).ok_or_else(|| {
+                    LoTaRError::ValidationError(format!(
+                        "Invalid sandbox cpu_time '{}'. Use a duration like 30s, 10m, or 2h",
+                        raw
+                    ))
+                })?;
+                Some(duration.as_secs().max(1))
+            }
+            _ => None,
+        };
+        Ok(Self {
+            env: config
+                .env
+                .iter()
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+                .collect(),
+            worktree_only: config.worktree_only,
+            cpu_seconds,
+            memory_bytes: parse_size_field("memory", config.memory.as_deref())?,
+            network: config.network,
+            max_output: parse_size_field("max_output", config.max_output.as_deref())?,
+        })
+    }
+
+    /// Maximum number of output bytes (stdout and stderr combined), if capped.
+    pub fn max_output(&self) -> Option<u64> {
+        self.max_output
+    }
+
+    /// Check that `cwd` is allowed to run under this sandbox.
+    ///
+    /// With `worktree_only`, the process must run inside `worktree`; a job without a
+    /// worktree cannot satisfy the restriction.
+    pub fn check_working_dir(&self, cwd: &Path, worktree: Option<&Path>) -> Result<(), String> {
+        if !self.worktree_only {
+            return Ok(());
+        }
+        let Some(worktree) = worktree else {
+            return Err(
+                "worktree_only is set but the job has no worktree (enable agent.worktree)"
+                    .to_string(),
+            );
+        };
+        let root = canonical_or_self(worktree);
+        if canonical_or_self(cwd).starts_with(&root) {
+            Ok(())
+        } else {
+            Err(format!(
+                "working directory {} is outside the worktree {}",
+                cwd.display(),
+                root.display()
+            ))
+        }
+    }
+
+    /// Apply the environment allowlist, rlimits, and network isolation to `command`.
+    ///
+    /// Call this before adding the command's own environment: the inherited
+    /// environment is cleared and only allowlisted variables are copied back.
+    pub fn apply(&self, command: &mut Command) -> Result<(), String> {
+        command.env_clear();
+        for (key, value) in std::env::vars_os() {
+            if self.allows_env(&key.to_string_lossy()) {
+                command.env(key, value);
+            }
+        }
+        self.apply_limits(command)
+    }
+
+    fn allows_env(&self, key: &str) -> bool {
+        self.env
+            .iter()
+            .any(|pattern| match pattern.strip_suffix('*') {
+                Some(prefix) => key.starts_with(prefix),
+                None => pattern == key,
+            })
+    }
+
+    #[cfg(unix)]
+    fn apply_limits(&self, command: &mut Command) -> Result<(), String> {
+        use std::os::unix::process::CommandExt;
+
+        if !self.network && !cfg!(target_os = "linux") {
+            return Err("network isolation is only available on Linux".to_string());
+        }
+        if self.cpu_seconds.is_none() && self.memory_bytes.is_none() && self.network {
+            return Ok(());
+        }
+
+        let cpu_seconds = self.cpu_seconds;
+        let memory_bytes = self.memory_bytes;
+        let isolate_network = !self.network;
+        // Identity maps are formatted here; only raw syscalls run after fork.
+        // SAFETY: getuid/getgid cannot fail.
+        let (uid, gid) = unsafe { (libc::getuid(), libc::getgid()) };
+        let uid_map = format!("{uid} {uid} 1\n").into_bytes();
+        let gid_map = format!("{gid} {gid} 1\n").into_bytes();
+
+        // SAFETY: the closure only calls async-signal-safe libc functions.
+        unsafe {
+            command.pre_exec(move || {
+                if let Some(seconds) = cpu_seconds {
+                    // The soft limit sends SIGXCPU; the hard limit a second later kills.
+                    set_rlimit(libc::RLIMIT_CPU, seconds, seconds.saturating_add(1))?;
+                }
+                if let Some(bytes) = memory_bytes {
+                    set_rlimit(libc::RLIMIT_AS, bytes, bytes)?;
+                }
+                if isolate_network {
+                    isolate_network_namespace(&uid_map, &gid_map)?;
+                }
+                Ok(())
+            });
+        }
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    fn apply_limits(&self, _command: &mut Command) -> Result<(), String> {
+        if self.cpu_seconds.is_some() || self.memory_bytes.is_some() {
+            return Err("cpu_time and memory limits are only available on Unix".to_string());
+        }
+        if !self.network {
+            return Err("network isolation is only available on Linux".to_string());
+        }
+        Ok(())
+    }
+
+    /// Explain a spawn failure caused by the sandbox setup, if it could be one.
+    pub fn describe_spawn_error(&self, err: &std::io::Error) -> Option<String> {
+        if err.kind() == std::io::ErrorKind::NotFound {
+            return None;
+        }
+        if !self.network {
+            return Some(format!(
+                "could not isolate the network (unprivileged user namespaces may be disabled): {}",
+                err
+            ));
+        }
+        if self.cpu_seconds.is_some() || self.memory_bytes.is_some() {
+            return Some(format!("could not apply resource limits: {}", err));
+        }
+        None
+    }
+
+    /// Explain an exit caused by a sandbox resource limit, if any.
+    pub fn describe_exit(&self, status: &ExitStatus) -> Option<String> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            let seconds = self.cpu_seconds?;
+            (status.signal() == Some(libc::SIGXCPU))
+                .then(|| format!("process exceeded the CPU time limit of {}s", seconds))
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = status;
+            None
+        }
+    }
+}
+
+/// Counts output bytes against a sandbox `max_output` limit.
+#[derive(Debug)]
+pub struct OutputBudget {
+    limit: u64,
+    used: AtomicU64,
+}
+
+impl OutputBudget {
+    pub fn new(limit: u64) -> Self {
+        Self {
+            limit,
+            used: AtomicU64::new(0),
+        }
+    }
+
+    /// Record `bytes` of output. Returns `false` once the limit is exceeded.
+    pub fn consume(&self, bytes: usize) -> bool {
+        let used = self.used.fetch_add(bytes as u64, Ordering::Relaxed) + bytes as u64;
+        used <= self.limit
+    }
+
+    pub fn violation(&self) -> String {
+        format!("output exceeded the limit of {}", format_size(self.limit))
+    }
+}
+
+/// Outcome of [`run_with_output_limit`].
+#[derive(Debug)]
+pub struct LimitedRun {
+    pub status: ExitStatus,
+    /// Set when the process was killed for exceeding the output budget.
+    pub violation: Option<String>,
+    /// Captured stderr when output was not forwarded.
+    pub stderr: String,
+}
+
+/// Run `command` to completion while counting its output against `budget`.
+///
+/// With `forward`, output is copied to this process's stdout and stderr; otherwise
+/// stdout is discarded and stderr is captured. The process is killed as soon as the
+/// budget runs out.
+pub fn run_with_output_limit(
+    command: &mut Command,
+    budget: OutputBudget,
+    forward: bool,
+) -> std::io::Result<LimitedRun> {
+    use std::io::{Read, Write};
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicBool;
+
+    command.stdout(Stdio::piped()).stderr(Stdio::piped());
+    let mut child = command.spawn()?;
+    let budget = Arc::new(budget);
+    let exceeded = Arc::new(AtomicBool::new(false));
+
+    let pump = |mut source: Box<dyn Read + Send>, is_stderr: bool| {
+        let budget = Arc::clone(&budget);
+        let exceeded = Arc::clone(&exceeded);
+        std::thread::spawn(move || {
+            let mut captured = Vec::new();
+            let mut buf = [0u8; 8192];
+            loop {
+                let read = match source.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(read) => read,
+                };
+                if !budget.consume(read) {
+                    exceeded.store(true, Ordering::Relaxed);
+                    break;
+                }
+                let chunk = &buf[..read];
+                if forward && is_stderr {
+                    let _ = std::io::stderr().write_all(chunk);
+                } else if forward {
+                    let _ = std::io::stdout().write_all(chunk);
+                } else if is_stderr {
+                    captured.extend_from_slice(chunk);
+                }
+            }
+            String::from_utf8_lossy(&captured).into_owned()
+        })
+    };
+    let stdout = child.stdout.take().map(|out| pump(Box::new(out), false));
+    let stderr = child.stderr.take().map(|err| pump(Box::new(err), true));
+
+    let status = loop {
+        if exceeded.load(Ordering::Relaxed) {
+            let _ = child.kill();
+        }
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    };
+    if let Some(handle) = stdout {
+        let _ = handle.join();
+    }
+    let stderr = stderr
+        .and_then(|handle| handle.join().ok())
+        .unwrap_or_default();
+    Ok(LimitedRun {
+        status,
+        violation: exceeded.load(Ordering::Relaxed).then(|| budget.violation()),
+        stderr,
+    })
+}
+
+/// Parse a byte size such as `512K`, `64M`, `2G`, or a plain byte count.
+pub fn parse_size(raw: &str) -> Option<u64> {
+    let trimmed = raw.trim();
+    let upper = trimmed.to_ascii_uppercase();
+    let upper = upper
+        .strip_suffix("IB")
+        .or_else(|| upper.strip_suffix('B'))
+        .unwrap_or(&upper);
+    let (digits, multiplier) = match upper.chars().last()? {
+        'K' => (&upper[..upper.len() - 1], 1u64 << 10),
+        'M' => (&upper[..upper.len() - 1], 1 << 20),
+        'G' => (&upper[..upper.len() - 1], 1 << 30),
+        'T' => (&upper[..upper.len() - 1], 1 << 40),
+        _ => (upper, 1),
+    };
+    let value: u64 = digits.trim().parse().ok()?;
+    value.checked_mul(multiplier).filter(|bytes| *bytes > 0)
+}
+
+fn parse_size_field(field: &str, raw: Option<&str>) -> LoTaRResult<Option<u64>> {
+    let Some(raw) = raw.map(str::trim).filter(|value| !value.is_empty()) else {
+        return Ok(None);
+    };
+    parse_size(raw).map(Some).ok_or_else(|| {
+        LoTaRError::ValidationError(format!(
+            "Invalid sandbox {} '{}'. Use a size like 512K, 64M, or 2G",
+            field, raw
+        ))
+    })
+}
+
+fn format_size(bytes: u64) -> String {
+    for (suffix, unit) in [("G", 1u64 << 30), ("M", 1 << 20), ("K", 1 << 10)] {
+        if bytes >= unit && bytes.is_multiple_of(unit) {
+            return format!("{}{}", bytes / unit, suffix);
+        }
+    }
+    format!("{} bytes", bytes)
+}
+
+fn canonical_or_self(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+type RlimitResource = libc::__rlimit_resource_t;
+#[cfg(all(unix, not(all(target_os = "linux", target_env = "gnu"))))]
+type RlimitResource = libc::c_int;
+
+#[cfg(unix)]
+fn set_rlimit(resource: RlimitResource, soft: u64, hard: u64) -> std::io::Result<()> {
+    let limit = libc::rlimit {
+        rlim_cur: soft as libc::rlim_t,
+        rlim_max: hard as libc::rlim_t,
+    };
+    // SAFETY: `limit` is a valid rlimit for the duration of the call.
+    if unsafe { libc::setrlimit(resource, &limit) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Move the calling process into fresh user and network namespaces.
+///
+/// The new network namespace only has a loopback device, which starts down.
+/// The user namespace maps the caller's own uid and gid so file ownership looks
+/// unchanged to the sandboxed process.
+#[cfg(target_os = "linux")]
+fn isolate_network_namespace(uid_map: &[u8], gid_map: &[u8]) -> std::io::Result<()> {
+    // SAFETY: unshare only affects the calling (child) process.
+    if unsafe { libc::unshare(libc::CLONE_NEWUSER | libc::CLONE_NEWNET) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    write_proc_file(c"/proc/self/uid_map", uid_map)?;
+    write_proc_file(c"/proc/self/setgroups", b"deny")?;
+    write_proc_file(c"/proc/self/gid_map", gid_map)
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+fn isolate_network_namespace(_uid_map: &[u8], _gid_map: &[u8]) -> std::io::Result<()> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+#[cfg(target_os = "linux")]
+fn write_proc_file(path: &std::ffi::CStr, contents: &[u8]) -> std::io::Result<()> {
+    // SAFETY: `path` is NUL-terminated and `contents` outlives the write.
+    unsafe {
+        let fd = libc::open(path.as_ptr(), libc::O_WRONLY | libc::O_CLOEXEC);
+        if fd < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        let written = libc::write(fd, contents.as_ptr().cast(), contents.len());
+        let result = if written < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        };
+        libc::close(fd);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!(parse_size("512"), Some(512));
+        assert_eq!(parse_size("4K"), Some(4096));
+        assert_eq!(parse_size("64mb"), Some(64 << 20));
+        assert_eq!(parse_size("2GiB"), Some(2 << 30));
+        assert_eq!(parse_size("0"), None);
+        assert_eq!(parse_size("lots"), None);
+        assert_eq!(format_size(64 << 20), "64M");
+    }
+
+    #[test]
+    fn env_allowlist_supports_prefixes() {
+        let policy = SandboxPolicy::from_config(&SandboxConfig {
+            env: vec!["PATH".to_string(), "LC_*".to_string()],
+            ..SandboxConfig::default()
+        })
+        .unwrap();
+        assert!(policy.allows_env("PATH"));
+        assert!(policy.allows_env("LC_ALL"));
+        assert!(!policy.allows_env("HOME"));
+        assert!(!policy.allows_env("PATHEXT"));
+    }
+
+    #[test]
+    fn worktree_restriction() {
+        let dir = tempfile::tempdir().unwrap();
+        let worktree = dir.path().join("wt");
+        std::fs::create_dir_all(worktree.join("src")).unwrap();
+        let policy = SandboxPolicy::from_config(&SandboxConfig {
+            worktree_only: true,
+            ..SandboxConfig::default()
+        })
+        .unwrap();
+        assert!(
+            policy
+                .check_working_dir(&worktree.join("src"), Some(&worktree))
+                .is_ok()
+        );
+        assert!(
+            policy
+                .check_working_dir(dir.path(), Some(&worktree))
+                .is_err()
+        );
+        assert!(policy.check_working_dir(&worktree, None).is_err());
+    }
+}
@@ -1,15 +1,25 @@
-use crate::api_types::{ScanEntry, ScanRequest, ScanResponse, ScanSummary, TaskCreate};
+use crate::api_types::{
+    ScanEntry, ScanReconcileEntry, ScanRequest, ScanResponse, ScanSummary, TaskCreate, TaskUpdate,
+};
 use crate::config::manager::ConfigManager;
+use crate::config::types::{GlobalConfig, ResolvedConfig, ScanCommentSyntax, ScanReconcileConfig};
 use crate::errors::{LoTaRError, LoTaRResult};
 use crate::scanner;
+use crate::services::sprint_metrics::determine_done_statuses_from_config;
 use crate::services::task_service::TaskService;
+use crate::storage::TaskFilter;
 use crate::storage::manager::Storage;
-use crate::types::{CustomFields, Priority, ReferenceEntry, TaskType, custom_value_string};
+use crate::storage::task::Task;
+use crate::types::{
+    CustomFields, Priority, ReferenceEntry, TaskStatus, TaskType, custom_value_string,
+};
 use crate::utils::paths::repo_relative_display;
-use crate::utils::scan::{parse_inline_attributes, strip_bracket_attributes};
+use crate::utils::scan::{
+    AnchorLocation, AnchorLocator, parse_inline_attributes, strip_bracket_attributes,
+};
 use crate::workspace::TasksDirectoryResolver;
 use chrono::Utc;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 const STATUS_CREATED: &str = "created";
@@ -21,6 +31,13 @@ const ACTION_CREATE: &str = "create";
 const ACTION_REFRESH: &str = "refresh";
 const ACTION_SKIP: &str = "skip";
 
+const OUTCOME_MOVED: &str = "moved";
+const OUTCOME_REMOVED: &str = "removed";
+const OUTCOME_SKIPPED: &str = "skipped";
+
+/// Lines searched around a stale anchor before falling back to the whole file.
+const REANCHOR_WINDOW: usize = 7;
+
 pub struct ScanService;
 
 impl ScanService {
@@ -73,31 +90,13 @@ impl ScanService {
             .strip_attributes
             .unwrap_or(scan_config.strip_attributes);
 
-        let mut final_words = scan_config.signal_words.clone();
-        for word in &scan_config.issue_type_words {
-            if !final_words
-                .iter()
-                .any(|existing| existing.eq_ignore_ascii_case(word))
-            {
-                final_words.push(word.clone());
-            }
-        }
-
         let mut all_results = Vec::new();
         for root in roots {
-            let mut scan = scanner::Scanner::new(root)
+            let mut scan = scan_config
+                .scanner(root)
                 .with_include_ext(&request.include)
                 .with_exclude_ext(&request.exclude)
                 .with_modified_only(request.modified_only);
-
-            if !final_words.is_empty() {
-                scan = scan.with_signal_words(&final_words);
-            }
-
-            scan = scan.with_ticket_detection(
-                scan_config.ticket_patterns.as_deref(),
-                scan_config.enable_ticket_words,
-            );
             all_results.extend(scan.scan());
         }
 
@@ -107,13 +106,7 @@ impl ScanService {
                 .then(a.line_number.cmp(&b.line_number))
         });
 
-        let mut line_scanner = scanner::Scanner::new(PathBuf::from(".")).with_ticket_detection(
-            scan_config.ticket_patterns.as_deref(),
-            scan_config.enable_ticket_words,
-        );
-        if !final_words.is_empty() {
-            line_scanner = line_scanner.with_signal_words(&final_words);
-        }
+        let line_scanner = scan_config.scanner(PathBuf::from("."));
 
         let target_keys = build_target_keys(&request.targets);
 
@@ -364,7 +357,18 @@ impl ScanService {
             entries.push(scan_entry);
         }
 
-        let status = if summary.failed > 0 { "partial" } else { "ok" };
+        let reconciled = if request.reconcile {
+            Self::reconcile(resolver, project_hint.as_deref(), dry_run)?
+        } else {
+            Vec::new()
+        };
+        let reconcile_failed = reconciled.iter().any(|entry| !entry.applied(dry_run));
+
+        let status = if summary.failed > 0 || reconcile_failed {
+            "partial"
+        } else {
+            "ok"
+        };
 
         Ok(ScanResponse {
             status: status.to_string(),
@@ -374,8 +378,247 @@ impl ScanService {
             warnings,
             info,
             entries,
+            reconciled,
         })
     }
+
+    /// Check tasks whose only code reference may be stale.
+    ///
+    /// Anchors whose key moved to another line (or a renamed file) are re-pointed. When the
+    /// key is gone the TODO was removed: the dead reference is dropped and the project's
+    /// `scan.reconcile` policy adds a comment, a tag and/or a status change. Done tasks are
+    /// left alone.
+    pub fn reconcile(
+        resolver: &TasksDirectoryResolver,
+        project: Option<&str>,
+        dry_run: bool,
+    ) -> LoTaRResult<Vec<ScanReconcileEntry>> {
+        let mut storage = Storage::new(&resolver.path);
+        let filter = TaskFilter {
+            project: project.map(str::to_string),
+            ..TaskFilter::default()
+        };
+        let tasks = storage.search(&filter);
+
+        // Anchors are relative to the repository holding the tasks, not to wherever
+        // lotar happens to run (the server's cwd may be elsewhere).
+        let repo_root = crate::utils::git::find_repo_root(&resolver.path);
+        let base = repo_root
+            .clone()
+            .or_else(|| resolver.path.parent().map(Path::to_path_buf))
+            .unwrap_or_else(|| PathBuf::from("."));
+        let locator = AnchorLocator::new(&base, REANCHOR_WINDOW);
+        let manager =
+            ConfigManager::new_manager_with_tasks_dir_readonly(resolver.path.as_path()).ok();
+        let mut configs: HashMap<String, ResolvedConfig> = HashMap::new();
+        let mut entries = Vec::new();
+
+        for (task_id, task) in tasks {
+            let mut code_refs = task.references.iter().filter_map(|r| r.code.as_deref());
+            let (Some(code_ref), None) = (code_refs.next(), code_refs.next()) else {
+                continue;
+            };
+            let code_ref = code_ref.to_string();
+
+            let prefix = task_id.split('-').next().unwrap_or("").to_string();
+            let config = configs.entry(prefix.clone()).or_insert_with(|| {
+                manager
+                    .as_ref()
+                    .map(|m| {
+                        m.get_project_config(&prefix)
+                            .unwrap_or_else(|_| m.get_resolved_config().clone())
+                    })
+                    .unwrap_or_else(|| ResolvedConfig::from_global(GlobalConfig::default()))
+            });
+            if determine_done_statuses_from_config(config)
+                .contains(&task.status.as_str().to_ascii_lowercase())
+            {
+                continue;
+            }
+
+            match locator.locate(&task_id, &code_ref) {
+                None => {}
+                Some(AnchorLocation::Found { path, line }) => {
+                    let new_ref = format!("{}#{}", repo_relative_display(&path), line);
+                    if new_ref == code_ref {
+                        continue;
+                    }
+                    let message = if dry_run {
+                        Some("Dry run: would re-anchor reference".to_string())
+                    } else {
+                        replace_code_reference(&mut storage, &task_id, &code_ref, Some(&new_ref))
+                            .err()
+                            .map(|err| err.to_string())
+                    };
+                    entries.push(ScanReconcileEntry {
+                        task_id,
+                        code_reference: code_ref,
+                        outcome: OUTCOME_MOVED.to_string(),
+                        new_reference: Some(new_ref),
+                        actions: vec!["reanchor".to_string()],
+                        message,
+                    });
+                }
+                Some(AnchorLocation::Unreadable { path, error }) => {
+                    entries.push(ScanReconcileEntry {
+                        task_id,
+                        code_reference: code_ref,
+                        outcome: OUTCOME_SKIPPED.to_string(),
+                        new_reference: None,
+                        actions: Vec::new(),
+                        message: Some(format!(
+                            "Could not read {}: {}",
+                            repo_relative_display(&path),
+                            error
+                        )),
+                    });
+                }
+                Some(AnchorLocation::KeyMissing { .. } | AnchorLocation::FileMissing)
+                    if repo_root.is_none() =>
+                {
+                    // Without the repository root a missing file may just be a wrong base.
+                    entries.push(ScanReconcileEntry {
+                        task_id,
+                        code_reference: code_ref,
+                        outcome: OUTCOME_SKIPPED.to_string(),
+                        new_reference: None,
+                        actions: Vec::new(),
+                        message: Some(format!(
+                            "No git repository contains {}; removed TODOs are not reconciled",
+                            resolver.path.display()
+                        )),
+                    });
+                }
+                Some(AnchorLocation::KeyMissing { .. } | AnchorLocation::FileMissing) => {
+                    let plan = RemovalPlan::new(&config.scan_reconcile, &task);
+                    let message = if dry_run {
+                        Some("Dry run: code TODO was removed".to_string())
+                    } else {
+                        plan.apply(&mut storage, &task_id, &code_ref)
+                            .err()
+                            .map(|err| err.to_string())
+                    };
+                    entries.push(ScanReconcileEntry {
+                        task_id,
+                        code_reference: code_ref,
+                        outcome: OUTCOME_REMOVED.to_string(),
+                        new_reference: None,
+                        actions: plan.actions(),
+                        message,
+                    });
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+}
+
+impl ScanReconcileEntry {
+    /// Whether the entry was applied (dry-run entries always carry an informational message).
+    fn applied(&self, dry_run: bool) -> bool {
+        dry_run || self.message.is_none()
+    }
+}
+
+/// Changes applied to a task whose code TODO was removed.
+struct RemovalPlan {
+    comment: bool,
+    tags: Option<Vec<String>>,
+    status: Option<String>,
+}
+
+impl RemovalPlan {
+    fn new(policy: &ScanReconcileConfig, task: &Task) -> Self {
+        let tag = policy
+            .tag
+            .as_deref()
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .filter(|tag| !task.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)));
+        let tags = tag.map(|tag| {
+            let mut next = task.tags.clone();
+            next.push(tag.to_string());
+            next
+        });
+        let status = policy
+            .status
+            .as_deref()
+            .map(str::trim)
+            .filter(|status| !status.is_empty() && !task.status.eq_ignore_case(status))
+            .map(str::to_string);
+        Self {
+            comment: policy.comment,
+            tags,
+            status,
+        }
+    }
+
+    fn actions(&self) -> Vec<String> {
+        let mut actions = vec!["unlink".to_string()];
+        if let Some(tag) = self.tags.as_ref().and_then(|tags| tags.last()) {
+            actions.push(format!("tag:{}", tag));
+        }
+        if let Some(status) = &self.status {
+            actions.push(format!("status:{}", status));
+        }
+        if self.comment {
+            actions.push("comment".to_string());
+        }
+        actions
+    }
+
+    fn apply(&self, storage: &mut Storage, task_id: &str, code_ref: &str) -> LoTaRResult<()> {
+        replace_code_reference(storage, task_id, code_ref, None)?;
+        if self.tags.is_some() || self.status.is_some() {
+            let patch = TaskUpdate {
+                tags: self.tags.clone(),
+                status: self.status.as_deref().map(TaskStatus::from),
+                ..TaskUpdate::default()
+            };
+            TaskService::update(storage, task_id, patch)?;
+        }
+        if self.comment {
+            TaskService::add_comment(
+                storage,
+                task_id,
+                &format!(
+                    "Code TODO removed: {} no longer mentions this task.",
+                    code_ref
+                ),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Point a task's code reference elsewhere, or drop it when `next` is `None`.
+fn replace_code_reference(
+    storage: &mut Storage,
+    task_id: &str,
+    code_ref: &str,
+    next: Option<&str>,
+) -> LoTaRResult<()> {
+    let derived = task_id.split('-').next().unwrap_or("");
+    let mut task = storage
+        .get(task_id, derived)
+        .ok_or_else(|| LoTaRError::TaskNotFound(task_id.to_string()))?;
+    for reference in task.references.iter_mut() {
+        if reference.code.as_deref() == Some(code_ref) {
+            reference.code = next.map(str::to_string);
+        }
+    }
+    task.references.retain(|r| {
+        r.code.is_some()
+            || r.link.is_some()
+            || r.file.is_some()
+            || r.jira.is_some()
+            || r.github.is_some()
+            || r.commit.is_some()
+    });
+    task.modified = Utc::now().to_rfc3339();
+    storage.edit(task_id, &task)?;
+    Ok(())
 }
 
 fn update_summary(summary: &mut ScanSummary, status: &str) {
@@ -388,16 +631,39 @@ fn update_summary(summary: &mut ScanSummary, status: &str) {
     }
 }
 
-struct ScanConfigSnapshot {
+pub(crate) struct ScanConfigSnapshot {
     signal_words: Vec<String>,
     ticket_patterns: Option<Vec<String>>,
     enable_ticket_words: bool,
     enable_mentions: bool,
     strip_attributes: bool,
     issue_type_words: Vec<String>,
+    comment_syntax: BTreeMap<String, ScanCommentSyntax>,
+}
+
+impl ScanConfigSnapshot {
+    /// Build a scanner rooted at `root` with the configured signal words, ticket detection
+    /// and comment syntax applied.
+    pub(crate) fn scanner(&self, root: PathBuf) -> scanner::Scanner {
+        let mut words = self.signal_words.clone();
+        for word in &self.issue_type_words {
+            if !words
+                .iter()
+                .any(|existing| existing.eq_ignore_ascii_case(word))
+            {
+                words.push(word.clone());
+            }
+        }
+
+        let mut scan = scanner::Scanner::new(root).with_comment_syntax(&self.comment_syntax);
+        if !words.is_empty() {
+            scan = scan.with_signal_words(&words);
+        }
+        scan.with_ticket_detection(self.ticket_patterns.as_deref(), self.enable_ticket_words)
+    }
 }
 
-fn resolve_scan_config(
+pub(crate) fn resolve_scan_config(
     resolver: &TasksDirectoryResolver,
     project: Option<&str>,
 ) -> ScanConfigSnapshot {
@@ -426,6 +692,7 @@ fn resolve_scan_config(
             enable_mentions: resolved.scan_enable_mentions,
             strip_attributes: resolved.scan_strip_attributes,
             issue_type_words,
+            comment_syntax: resolved.scan_comment_syntax,
         }
     } else {
         ScanConfigSnapshot {
@@ -435,6 +702,7 @@ fn resolve_scan_config(
             enable_mentions: true,
             strip_attributes: true,
             issue_type_words: Vec::new(),
+            comment_syntax: BTreeMap::new(),
         }
     }
 }
@@ -450,7 +718,7 @@ fn build_custom_fields(fields: Vec<(String, String)>) -> Option<CustomFields> {
     Some(custom)
 }
 
-fn read_line(path: &Path, line_number: usize) -> Result<String, String> {
+pub(crate) fn read_line(path: &Path, line_number: usize) -> Result<String, String> {
     let contents = std::fs::read_to_string(path)
         .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
     let lines: Vec<&str> = contents.lines().collect();
@@ -608,6 +876,7 @@ mod scan_service_tests {
             reanchor: false,
             modified_only: false,
             targets: Vec::<ScanTarget>::new(),
+            reconcile: false,
         };
 
         let response = ScanService::run(&resolver, request).expect("scan run");
@@ -0,0 +1,874 @@
+//! JSON Schemas for the YAML files lotar keeps under `.tasks/`, for editor completion and
+//! validation (`lotar schema`, `GET /api/schema/<kind>`).
+//!
+//! Schemas come from the `JsonSchema` derives behind the `schema` cargo feature. Config files
+//! are read by tolerant parsers rather than serde, so their schema is assembled from the
+//! canonical nested key layout instead of the flat `GlobalConfig`/`ProjectConfig` structs.
+
+use crate::errors::{LoTaRError, LoTaRResult};
+use crate::utils::filesystem::YAML_MODELINE_PREFIX;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// A family of YAML files with its own schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaKind {
+    /// Task files (`.tasks/<PROJECT>/<N>.yml`)
+    Task,
+    /// Sprint files (`.tasks/@sprints/<N>.yml`)
+    Sprint,
+    /// Project config (`.tasks/<PROJECT>/config.yml`)
+    Project,
+    /// Global config (`.tasks/config.yml`)
+    Global,
+    /// Automation rules (`automation.yml`)
+    Automation,
+}
+
+impl SchemaKind {
+    pub const ALL: [SchemaKind; 5] = [
+        SchemaKind::Task,
+        SchemaKind::Sprint,
+        SchemaKind::Project,
+        SchemaKind::Global,
+        SchemaKind::Automation,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SchemaKind::Task => "task",
+            SchemaKind::Sprint => "sprint",
+            SchemaKind::Project => "project",
+            SchemaKind::Global => "global",
+            SchemaKind::Automation => "automation",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        let raw = raw.trim().trim_end_matches(".json");
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(raw))
+    }
+
+    /// File name used by `lotar schema export`.
+    pub fn file_name(self) -> String {
+        format!("{}.schema.json", self.as_str())
+    }
+
+    /// Globs for the files of this kind, given the tasks directory relative to the workspace.
+    pub fn file_globs(self, tasks_dir: &str) -> Vec<String> {
+        match self {
+            SchemaKind::Task => vec![format!("{}/*/[0-9]*.yml", tasks_dir)],
+            SchemaKind::Sprint => vec![format!("{}/@sprints/*.yml", tasks_dir)],
+            SchemaKind::Project => vec![format!("{}/*/config.yml", tasks_dir)],
+            SchemaKind::Global => vec![format!("{}/config.yml", tasks_dir)],
+            SchemaKind::Automation => vec![
+                format!("{}/automation.yml", tasks_dir),
+                format!("{}/*/automation.yml", tasks_dir),
+            ],
+        }
+    }
+
+    #[cfg_attr(not(feature = "schema"), allow(dead_code))]
+    fn title(self) -> &'static str {
+        match self {
+            SchemaKind::Task => "lotar task file",
+            SchemaKind::Sprint => "lotar sprint file",
+            SchemaKind::Project => "lotar project config",
+            SchemaKind::Global => "lotar global config",
+            SchemaKind::Automation => "lotar automation rules",
+        }
+    }
+}
+
+/// Generate the JSON Schema (draft-07) for one kind of file.
+pub fn generate(kind: SchemaKind) -> LoTaRResult<Value> {
+    #[cfg(feature = "schema")]
+    {
+        Ok(build::schema_for(kind))
+    }
+    #[cfg(not(feature = "schema"))]
+    {
+        let _ = kind;
+        Err(LoTaRError::ValidationError(
+            "JSON Schemas are unavailable: this build of lotar was compiled without the `schema` feature"
+                .to_string(),
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SchemaExportOptions {
+    /// Kinds to export; empty means all of them.
+    pub kinds: Vec<SchemaKind>,
+    /// Output directory; defaults to `.tasks/.schemas`.
+    pub out_dir: Option<PathBuf>,
+    /// Point every matching file at its schema with a `# yaml-language-server:` first line.
+    pub modelines: bool,
+    /// Map the schemas to their files in `.vscode/settings.json` (`yaml.schemas`).
+    pub vscode: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedSchema {
+    pub kind: SchemaKind,
+    pub path: String,
+    pub globs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemaExportReport {
+    pub schemas: Vec<ExportedSchema>,
+    /// Files whose modeline was added or updated.
+    pub modelines: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vscode_settings: Option<String>,
+}
+
+/// Write schema files and, optionally, the editor wiring that points YAML files at them.
+pub fn export(tasks_dir: &Path, options: &SchemaExportOptions) -> LoTaRResult<SchemaExportReport> {
+    let kinds: Vec<SchemaKind> = if options.kinds.is_empty() {
+        SchemaKind::ALL.to_vec()
+    } else {
+        options.kinds.clone()
+    };
+    let workspace = tasks_dir.parent().unwrap_or(tasks_dir);
+    let tasks_dir_name = tasks_dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| ".tasks".to_string());
+    let out_dir = options
+        .out_dir
+        .clone()
+        .unwrap_or_else(|| tasks_dir.join(".schemas"));
+    fs::create_dir_all(&out_dir)?;
+
+    let mut report = SchemaExportReport {
+        schemas: Vec::new(),
+        modelines: Vec::new(),
+        vscode_settings: None,
+    };
+    for kind in &kinds {
+        let schema = generate(*kind)?;
+        let path = out_dir.join(kind.file_name());
+        let body = serde_json::to_string_pretty(&schema)
+            .map_err(|e| LoTaRError::SerializationError(e.to_string()))?;
+        fs::write(&path, format!("{}\n", body))?;
+
+        if options.modelines {
+            for file in files_of_kind(tasks_dir, *kind) {
+                let Some(dir) = file.parent() else {
+                    continue;
+                };
+                let modeline = format!(
+                    "{} $schema={}",
+                    YAML_MODELINE_PREFIX,
+                    relative_path(dir, &path)
+                );
+                let content = fs::read_to_string(&file)?;
+                if let Some(updated) = set_modeline(&content, &modeline) {
+                    fs::write(&file, updated)?;
+                    report.modelines.push(relative_path(workspace, &file));
+                }
+            }
+        }
+
+        report.schemas.push(ExportedSchema {
+            kind: *kind,
+            path: path.display().to_string(),
+            globs: kind.file_globs(&tasks_dir_name),
+        });
+    }
+
+    if options.vscode {
+        let settings = workspace.join(".vscode").join("settings.json");
+        write_vscode_mapping(&settings, workspace, &report.schemas)?;
+        report.vscode_settings = Some(settings.display().to_string());
+    }
+    Ok(report)
+}
+
+/// Existing files of one kind under the tasks directory.
+fn files_of_kind(tasks_dir: &Path, kind: SchemaKind) -> Vec<PathBuf> {
+    use crate::utils::filesystem::{file_numeric_stem, list_files_with_ext, list_visible_subdirs};
+    use crate::utils::paths;
+
+    let mut projects = list_visible_subdirs(tasks_dir);
+    projects.sort();
+    let mut files = match kind {
+        SchemaKind::Global => vec![paths::global_config_path(tasks_dir)],
+        SchemaKind::Project => projects
+            .iter()
+            .map(|(prefix, _)| paths::project_config_path(tasks_dir, prefix))
+            .collect(),
+        SchemaKind::Automation => std::iter::once(paths::global_automation_path(tasks_dir))
+            .chain(
+                projects
+                    .iter()
+                    .map(|(prefix, _)| paths::project_automation_path(tasks_dir, prefix)),
+            )
+            .collect(),
+        SchemaKind::Task => projects
+            .iter()
+            .flat_map(|(_, dir)| list_files_with_ext(dir, "yml"))
+            .filter(|path| file_numeric_stem(path).is_some())
+            .collect(),
+        SchemaKind::Sprint => {
+            list_files_with_ext(&crate::storage::sprint::Sprint::dir(tasks_dir), "yml")
+                .into_iter()
+                .filter(|path| file_numeric_stem(path).is_some())
+                .collect()
+        }
+    };
+    files.retain(|path| path.is_file());
+    files.sort();
+    files
+}
+
+/// Put `modeline` on the first line of `content`; `None` when it is already there.
+fn set_modeline(content: &str, modeline: &str) -> Option<String> {
+    let (first, rest) = content.split_once('\n').unwrap_or((content, ""));
+    if first.starts_with(YAML_MODELINE_PREFIX) {
+        if first == modeline {
+            return None;
+        }
+        return Some(format!("{}\n{}", modeline, rest));
+    }
+    Some(format!("{}\n{}", modeline, content))
+}
+
+fn write_vscode_mapping(
+    settings_path: &Path,
+    workspace: &Path,
+    schemas: &[ExportedSchema],
+) -> LoTaRResult<()> {
+    let mut settings: Map<String, Value> = match fs::read_to_string(settings_path) {
+        Ok(content) if !content.trim().is_empty() => serde_json::from_str(&content).map_err(|e| {
+            LoTaRError::ValidationError(format!(
+                "Cannot update {}: {} (comments and trailing commas are not supported; add the yaml.schemas entries by hand)",
+                settings_path.display(),
+                e
+            ))
+        })?,
+        _ => Map::new(),
+    };
+    let mapping = settings
+        .entry("yaml.schemas")
+        .or_insert_with(|| Value::Object(Map::new()));
+    let Value::Object(mapping) = mapping else {
+        return Err(LoTaRError::ValidationError(format!(
+            "Cannot update {}: yaml.schemas is not an object",
+            settings_path.display()
+        )));
+    };
+    for schema in schemas {
+        let relative = relative_path(workspace, Path::new(&schema.path));
+        let key = if relative.starts_with("..") || Path::new(&relative).is_absolute() {
+            relative
+        } else {
+            format!("./{}", relative)
+        };
+        mapping.insert(key, Value::from(schema.globs.clone()));
+    }
+
+    if let Some(parent) = settings_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let body = serde_json::to_string_pretty(&Value::Object(settings))
+        .map_err(|e| LoTaRError::SerializationError(e.to_string()))?;
+    fs::write(settings_path, format!("{}\n", body))?;
+    Ok(())
+}
+
+/// `to` relative to the directory `from`, with `/` separators; absolute when they share no root.
+fn relative_path(from: &Path, to: &Path) -> String {
+    let parts = |path: &Path| -> Vec<String> {
+        std::path::absolute(path)
+            .unwrap_or_else(|_| path.to_path_buf())
+            .components()
+            .filter(|c| !matches!(c, Component::CurDir))
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect()
+    };
+    let (from, to_parts) = (parts(from), parts(to));
+    let common = from
+        .iter()
+        .zip(&to_parts)
+        .take_while(|(a, b)| a == b)
+        .count();
+    if common == 0 {
+        return to.to_string_lossy().replace('\\', "/");
+    }
+    let mut relative = vec!["..".to_string(); from.len() - common];
+    relative.extend(to_parts[common..].iter().cloned());
+    relative.join("/")
+}
+
+#[cfg(feature = "schema")]
+mod build {
+    use super::SchemaKind;
+    use crate::automation::types::AutomationFile;
+    use crate::config::types::{
+        AgentApprovalRule, AgentAutomationConfig, AgentAutomationConfigOverride,
+        AgentInstructionsConfig, AgentPipelineConfig, AgentProfileConfig, AgentWorktreeConfig,
+        AgentWorktreeConfigOverride, IssueTemplate, MemberProfile, ScanCommentSyntax,
+        ScanReconcileConfig, SprintRolloverMode, SyncAuthProfile, SyncRemoteConfig,
+    };
+    use crate::storage::sprint::Sprint;
+    use crate::storage::task::Task;
+    use crate::types::{Priority, TaskStatus, TaskType};
+    use schemars::generate::SchemaSettings;
+    use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+    use serde_json::{Map, Value};
+    use std::collections::{BTreeMap, HashMap};
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum Scope {
+        Both,
+        Global,
+        Project,
+    }
+
+    /// One key of the canonical nested config layout.
+    struct ConfigKey {
+        path: &'static str,
+        scope: Scope,
+        schema: fn(&mut SchemaGenerator) -> Schema,
+        description: &'static str,
+    }
+
+    const fn key(
+        path: &'static str,
+        scope: Scope,
+        schema: fn(&mut SchemaGenerator) -> Schema,
+        description: &'static str,
+    ) -> ConfigKey {
+        ConfigKey {
+            path,
+            scope,
+            schema,
+            description,
+        }
+    }
+
+    fn of<T: JsonSchema>(generator: &mut SchemaGenerator) -> Schema {
+        generator.subschema_for::<T>()
+    }
+
+    fn string_or_list(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "anyOf": [
+                { "type": "string" },
+                { "type": "array", "items": { "type": "string" } }
+            ]
+        })
+    }
+
+    fn members(generator: &mut SchemaGenerator) -> Schema {
+        let profile = generator.subschema_for::<MemberProfile>();
+        json_schema!({
+            "type": "array",
+            "items": { "anyOf": [{ "type": "string" }, profile] }
+        })
+    }
+
+    use Scope::{Both, Global, Project};
+
+    const CONFIG_KEYS: &[ConfigKey] = &[
+        key(
+            "schema_version",
+            Both,
+            of::<u32>,
+            "File format version; older files are upgraded with `lotar migrate`.",
+        ),
+        key(
+            "extends",
+            Global,
+            string_or_list,
+            "Shared configs merged below this file: paths relative to the tasks directory or `<repo>#<ref>:<path>`.",
+        ),
+        key(
+            "server.port",
+            Global,
+            of::<u16>,
+            "Port used by `lotar serve`.",
+        ),
+        key(
+            "project.name",
+            Project,
+            of::<String>,
+            "Display name of the project.",
+        ),
+        key(
+            "default.project",
+            Global,
+            of::<String>,
+            "Project prefix used when a command does not name one.",
+        ),
+        key(
+            "default.assignee",
+            Both,
+            of::<String>,
+            "Assignee for new tasks.",
+        ),
+        key(
+            "default.reporter",
+            Both,
+            of::<String>,
+            "Reporter for new tasks.",
+        ),
+        key(
+            "default.priority",
+            Both,
+            of::<Priority>,
+            "Priority for new tasks.",
+        ),
+        key(
+            "default.status",
+            Both,
+            of::<TaskStatus>,
+            "Status for new tasks.",
+        ),
+        key(
+            "default.tags",
+            Both,
+            of::<Vec<String>>,
+            "Tags added to new tasks.",
+        ),
+        key(
+            "default.strict_members",
+            Both,
+            of::<bool>,
+            "Reject assignees and reporters that are not listed in `members`.",
+        ),
+        key(
+            "members",
+            Both,
+            members,
+            "Team members: plain handles or profiles with their aliases.",
+        ),
+        key(
+            "issue.states",
+            Both,
+            of::<Vec<TaskStatus>>,
+            "Allowed statuses.",
+        ),
+        key(
+            "issue.types",
+            Both,
+            of::<Vec<TaskType>>,
+            "Allowed task types.",
+        ),
+        key(
+            "issue.priorities",
+            Both,
+            of::<Vec<Priority>>,
+            "Allowed priorities.",
+        ),
+        key(
+            "issue.tags",
+            Both,
+            of::<Vec<String>>,
+            "Allowed tags; `*` allows any.",
+        ),
+        key(
+            "issue.templates",
+            Both,
+            of::<BTreeMap<String, IssueTemplate>>,
+            "Defaults and required fields for new tasks, keyed by task type.",
+        ),
+        key(
+            "custom.fields",
+            Both,
+            of::<Vec<String>>,
+            "Allowed custom field names; `*` allows any.",
+        ),
+        key(
+            "scan.signal_words",
+            Both,
+            of::<Vec<String>>,
+            "Comment words that `lotar scan` turns into tasks.",
+        ),
+        key(
+            "scan.ticket_patterns",
+            Both,
+            of::<Vec<String>>,
+            "Regex patterns that detect ticket keys in comments.",
+        ),
+        key(
+            "scan.enable_ticket_words",
+            Both,
+            of::<bool>,
+            "Treat ticket keys as signal words.",
+        ),
+        key(
+            "scan.enable_mentions",
+            Both,
+            of::<bool>,
+            "Add code references for existing ticket keys found in source.",
+        ),
+        key(
+            "scan.strip_attributes",
+            Both,
+            of::<bool>,
+            "Remove inline `[key=value]` attribute blocks from source after inserting the ticket key.",
+        ),
+        key(
+            "scan.comment_syntax",
+            Both,
+            of::<BTreeMap<String, ScanCommentSyntax>>,
+            "Comment syntax per file extension.",
+        ),
+        key(
+            "scan.reconcile",
+            Both,
+            of::<ScanReconcileConfig>,
+            "What `lotar scan --reconcile` does to tasks whose code TODO was removed.",
+        ),
+        key(
+            "auto.populate_members",
+            Both,
+            of::<bool>,
+            "Add new assignees and reporters to `members`.",
+        ),
+        key(
+            "auto.set_reporter",
+            Both,
+            of::<bool>,
+            "Set the reporter of new tasks to the current user.",
+        ),
+        key(
+            "auto.assign_on_status",
+            Both,
+            of::<bool>,
+            "Assign the current user when a task changes status without an assignee.",
+        ),
+        key(
+            "auto.codeowners_assign",
+            Both,
+            of::<bool>,
+            "Assign scanned tasks from CODEOWNERS.",
+        ),
+        key(
+            "auto.tags_from_path",
+            Both,
+            of::<bool>,
+            "Tag scanned tasks from their source path.",
+        ),
+        key(
+            "auto.branch_infer_type",
+            Both,
+            of::<bool>,
+            "Infer the task type from the branch name.",
+        ),
+        key(
+            "auto.branch_infer_status",
+            Both,
+            of::<bool>,
+            "Infer the status from the branch name.",
+        ),
+        key(
+            "auto.branch_infer_priority",
+            Both,
+            of::<bool>,
+            "Infer the priority from the branch name.",
+        ),
+        key(
+            "auto.identity",
+            Both,
+            of::<bool>,
+            "Detect the current user automatically.",
+        ),
+        key(
+            "auto.identity_git",
+            Both,
+            of::<bool>,
+            "Use the git author as a source for the current user.",
+        ),
+        key(
+            "branch.template",
+            Both,
+            of::<String>,
+            "Branch name template used by `lotar start`.",
+        ),
+        key(
+            "branch.type_aliases",
+            Both,
+            of::<HashMap<String, TaskType>>,
+            "Branch tokens mapped to task types.",
+        ),
+        key(
+            "branch.status_aliases",
+            Both,
+            of::<HashMap<String, TaskStatus>>,
+            "Branch tokens mapped to statuses.",
+        ),
+        key(
+            "branch.priority_aliases",
+            Both,
+            of::<HashMap<String, Priority>>,
+            "Branch tokens mapped to priorities.",
+        ),
+        key(
+            "commits.keywords",
+            Both,
+            of::<BTreeMap<String, TaskStatus>>,
+            "Commit message keywords mapped to the status they apply.",
+        ),
+        key(
+            "sprints.defaults.capacity_points",
+            Global,
+            of::<u32>,
+            "Default sprint capacity in points.",
+        ),
+        key(
+            "sprints.defaults.capacity_hours",
+            Global,
+            of::<u32>,
+            "Default sprint capacity in hours.",
+        ),
+        key(
+            "sprints.defaults.length",
+            Global,
+            of::<String>,
+            "Default sprint length (e.g. `2w`).",
+        ),
+        key(
+            "sprints.defaults.overdue_after",
+            Global,
+            of::<String>,
+            "Grace period before an active sprint counts as overdue.",
+        ),
+        key(
+            "sprints.defaults.weekly_hours",
+            Global,
+            of::<u32>,
+            "Weekly hours each member is available for sprint work.",
+        ),
+        key(
+            "sprints.defaults.member_weekly_hours",
+            Global,
+            of::<BTreeMap<String, u32>>,
+            "Per-member weekly hours, overriding `weekly_hours`.",
+        ),
+        key(
+            "sprints.defaults.rollover",
+            Global,
+            of::<SprintRolloverMode>,
+            "Where `sprint close` moves unfinished tasks.",
+        ),
+        key(
+            "sprints.notifications.enabled",
+            Global,
+            of::<bool>,
+            "Show sprint lifecycle notifications.",
+        ),
+        key(
+            "attachments.dir",
+            Both,
+            of::<String>,
+            "Directory for task attachments.",
+        ),
+        key(
+            "attachments.max_upload_mb",
+            Both,
+            of::<i64>,
+            "Upload size limit in MiB; `0` disables uploads, `-1` is unlimited.",
+        ),
+        key(
+            "sync.reports_dir",
+            Both,
+            of::<String>,
+            "Directory for sync reports.",
+        ),
+        key(
+            "sync.write_reports",
+            Both,
+            of::<bool>,
+            "Write a report for every sync run.",
+        ),
+        key(
+            "remotes",
+            Both,
+            of::<HashMap<String, SyncRemoteConfig>>,
+            "Jira and GitHub remotes for `lotar sync`.",
+        ),
+        key(
+            "auth_profiles",
+            Both,
+            of::<HashMap<String, SyncAuthProfile>>,
+            "Credentials used by sync remotes.",
+        ),
+        key(
+            "agent.context_enabled",
+            Both,
+            of::<bool>,
+            "Store per-ticket `.context` files so agents can resume.",
+        ),
+        key(
+            "agent.context_extension",
+            Both,
+            of::<String>,
+            "File extension of agent context files.",
+        ),
+        key(
+            "agent.logs_dir",
+            Both,
+            of::<String>,
+            "Directory for agent job logs.",
+        ),
+        key(
+            "agent.instructions",
+            Both,
+            of::<AgentInstructionsConfig>,
+            "Instructions passed to every agent job.",
+        ),
+        key(
+            "agent.approvals",
+            Both,
+            of::<Vec<AgentApprovalRule>>,
+            "Ticket operations by agents that need approval.",
+        ),
+        key(
+            "agent.automation",
+            Global,
+            of::<AgentAutomationConfig>,
+            "Agent automation defaults.",
+        ),
+        key(
+            "agent.automation",
+            Project,
+            of::<AgentAutomationConfigOverride>,
+            "Agent automation overrides for this project.",
+        ),
+        key(
+            "agent.worktree",
+            Global,
+            of::<AgentWorktreeConfig>,
+            "Git worktree settings for agent jobs.",
+        ),
+        key(
+            "agent.worktree",
+            Project,
+            of::<AgentWorktreeConfigOverride>,
+            "Git worktree overrides for this project.",
+        ),
+        key(
+            "agents",
+            Both,
+            of::<HashMap<String, AgentProfileConfig>>,
+            "Agent profiles by name.",
+        ),
+        key(
+            "pipelines",
+            Both,
+            of::<HashMap<String, AgentPipelineConfig>>,
+            "Multi-stage agent pipelines by name.",
+        ),
+    ];
+
+    fn generator() -> SchemaGenerator {
+        SchemaSettings::draft07().into_generator()
+    }
+
+    pub(super) fn schema_for(kind: SchemaKind) -> Value {
+        let mut schema = match kind {
+            SchemaKind::Task => generator().into_root_schema_for::<Task>(),
+            SchemaKind::Sprint => generator().into_root_schema_for::<Sprint>(),
+            SchemaKind::Automation => generator().into_root_schema_for::<AutomationFile>(),
+            SchemaKind::Global => config_schema(Global),
+            SchemaKind::Project => config_schema(Project),
+        };
+        schema.insert("title".to_string(), Value::from(kind.title()));
+        schema.to_value()
+    }
+
+    fn config_schema(scope: Scope) -> Schema {
+        let mut generator = generator();
+        let mut root = Map::new();
+        for entry in CONFIG_KEYS
+            .iter()
+            .filter(|entry| entry.scope == Both || entry.scope == scope)
+        {
+            let mut leaf = (entry.schema)(&mut generator).to_value();
+            if let Some(target) = leaf.get("$ref").cloned() {
+                leaf = serde_json::json!({ "allOf": [{ "$ref": target }] });
+            }
+            if let Value::Object(fields) = &mut leaf {
+                fields.insert("description".to_string(), Value::from(entry.description));
+            }
+            insert_nested(&mut root, entry.path, leaf);
+        }
+
+        let mut schema = json_schema!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "properties": root,
+        });
+        let definitions = generator.take_definitions(true);
+        if !definitions.is_empty() {
+            schema.insert("definitions".to_string(), Value::Object(definitions));
+        }
+        schema
+    }
+
+    fn insert_nested(properties: &mut Map<String, Value>, path: &str, leaf: Value) {
+        match path.split_once('.') {
+            None => {
+                properties.insert(path.to_string(), leaf);
+            }
+            Some((section, rest)) => {
+                let node = properties
+                    .entry(section.to_string())
+                    .or_insert_with(|| serde_json::json!({ "type": "object", "properties": {} }));
+                if let Some(Value::Object(children)) = node.get_mut("properties") {
+                    insert_nested(children, rest, leaf);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modelines_are_added_once_and_replaced() {
+        let line = "# yaml-language-server: $schema=../.schemas/task.schema.json";
+        let added = set_modeline("title: A\n", line).unwrap();
+        assert_eq!(added, format!("{}\ntitle: A\n", line));
+        assert!(set_modeline(&added, line).is_none());
+        let moved = set_modeline(&added, "# yaml-language-server: $schema=x.json").unwrap();
+        assert_eq!(moved, "# yaml-language-server: $schema=x.json\ntitle: A\n");
+
+        assert_eq!(
+            relative_path(
+                Path::new("/work/.tasks/AUTH"),
+                Path::new("/work/.tasks/.schemas/task.schema.json")
+            ),
+            "../.schemas/task.schema.json"
+        );
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn config_schema_uses_nested_layout() {
+        let global = generate(SchemaKind::Global).unwrap();
+        let properties = &global["properties"];
+        assert_eq!(
+            properties["server"]["properties"]["port"]["type"],
+            "integer"
+        );
+        assert!(properties["issue"]["properties"]["states"].is_object());
+        assert!(properties["project"].is_null());
+
+        let project = generate(SchemaKind::Project).unwrap();
+        assert!(project["properties"]["project"]["properties"]["name"].is_object());
+        assert!(project["properties"]["extends"].is_null());
+    }
+}
@@ -3,6 +3,9 @@ use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use chrono::Utc;
 use serde::Serialize;
 
+use crate::config::manager::ConfigManager;
+use crate::services::sprint_capacity::compute_member_capacity;
+use crate::services::sprint_metrics::determine_done_statuses_from_config;
 use crate::services::sprint_service::{SprintRecord, SprintService};
 use crate::services::sprint_status::{self, SprintLifecycleState};
 use crate::services::task_service::TaskService;
@@ -36,6 +39,8 @@ pub struct SprintAssignmentOutcome {
     pub modified: Vec<String>,
     pub unchanged: Vec<String>,
     pub replaced: Vec<SprintReassignmentInfo>,
+    /// Members pushed over their sprint capacity by this assignment.
+    pub capacity_warnings: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -268,6 +273,17 @@ fn apply_assignment(
         }
     }
 
+    let capacity_warnings = match (action, sprint_map.get(&sprint_id)) {
+        (SprintAssignmentAction::Add, Some(sprint)) if !modified.is_empty() => {
+            let updated = SprintRecord {
+                id: sprint_id,
+                sprint: sprint.clone(),
+            };
+            capacity_warnings(storage, target_record, &updated, &modified)
+        }
+        _ => Vec::new(),
+    };
+
     Ok(SprintAssignmentOutcome {
         action,
         sprint_id,
@@ -280,9 +296,56 @@ fn apply_assignment(
         modified,
         unchanged,
         replaced,
+        capacity_warnings,
     })
 }
 
+/// Over-capacity messages for members that the newly added tasks push over capacity.
+/// Members who were already over before the assignment are not reported again.
+fn capacity_warnings(
+    storage: &Storage,
+    before: &SprintRecord,
+    after: &SprintRecord,
+    added: &[String],
+) -> Vec<String> {
+    let Ok(manager) = ConfigManager::new_manager_with_tasks_dir_readonly(&storage.root_path) else {
+        return Vec::new();
+    };
+    let config = manager.get_resolved_config();
+    let tasks = SprintService::load_tasks_for_record(storage, after);
+    let assignees: HashSet<&str> = tasks
+        .iter()
+        .filter(|(id, _)| added.contains(id))
+        .filter_map(|(_, task)| task.assignee.as_deref().map(str::trim))
+        .collect();
+    if assignees.is_empty() {
+        return Vec::new();
+    }
+
+    let done_statuses = determine_done_statuses_from_config(config);
+    let now = Utc::now();
+    let previous_tasks = SprintService::load_tasks_for_record(storage, before);
+    let already_over: HashSet<String> = compute_member_capacity(
+        before,
+        &sprint_status::derive_status(&before.sprint, now),
+        &previous_tasks,
+        config,
+        &done_statuses,
+    )
+    .into_iter()
+    .filter(|member| member.over_capacity)
+    .map(|member| member.member)
+    .collect();
+
+    let lifecycle = sprint_status::derive_status(&after.sprint, now);
+    compute_member_capacity(after, &lifecycle, &tasks, config, &done_statuses)
+        .iter()
+        .filter(|member| assignees.contains(member.member.as_str()))
+        .filter(|member| !already_over.contains(&member.member))
+        .filter_map(|member| member.describe_overload())
+        .collect()
+}
+
 fn build_membership_index(sprint_map: &BTreeMap<u32, Sprint>) -> HashMap<String, BTreeSet<u32>> {
     let mut map: HashMap<String, BTreeSet<u32>> = HashMap::new();
     for (sprint_id, sprint) in sprint_map.iter() {
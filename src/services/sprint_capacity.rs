@@ -0,0 +1,266 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
+use serde::Serialize;
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+use crate::config::types::ResolvedConfig;
+use crate::services::sprint_metrics::ratio;
+use crate::services::sprint_service::SprintRecord;
+use crate::services::sprint_status::SprintLifecycleStatus;
+use crate::services::sprint_timing::{resolve_calendar_end, resolve_calendar_start};
+use crate::storage::sprint::SprintMemberCapacity;
+use crate::storage::task::Task as StoredTask;
+use crate::utils::effort::{self, EffortKind};
+
+const WORKDAYS_PER_WEEK: f64 = 5.0;
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
+/// Planned versus available effort for one person in a sprint.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct SprintMemberCapacityPayload {
+    pub member: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub weekly_hours: Option<f64>,
+    /// Hours available across the sprint's working days, after time off.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub available_hours: Option<f64>,
+    pub planned_hours: f64,
+    pub remaining_hours: f64,
+    pub planned_points: f64,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub load_ratio: Option<f64>,
+    #[serde(skip_serializing_if = "is_false", default)]
+    pub over_capacity: bool,
+    #[serde(skip_serializing_if = "is_zero", default)]
+    pub time_off_days: usize,
+}
+
+impl SprintMemberCapacityPayload {
+    pub fn describe_overload(&self) -> Option<String> {
+        if !self.over_capacity {
+            return None;
+        }
+        Some(format!(
+            "{} is over capacity: {:.1}h planned, {:.1}h available",
+            self.member,
+            self.planned_hours,
+            self.available_hours.unwrap_or(0.0)
+        ))
+    }
+}
+
+#[derive(Default)]
+struct MemberLoad {
+    planned_hours: f64,
+    remaining_hours: f64,
+    planned_points: f64,
+}
+
+/// Per-member capacity for a sprint.
+///
+/// Returns an empty list unless capacity is configured somewhere, either through
+/// `sprints.defaults.weekly_hours` / `member_weekly_hours` or the sprint's
+/// `plan.capacity.members`. Members come from config `members`, the sprint's capacity
+/// entries and the assignees of sprint tasks.
+pub fn compute_member_capacity(
+    record: &SprintRecord,
+    lifecycle: &SprintLifecycleStatus,
+    tasks: &[(String, StoredTask)],
+    config: &ResolvedConfig,
+    done_statuses: &HashSet<String>,
+) -> Vec<SprintMemberCapacityPayload> {
+    let defaults = &config.sprint_defaults;
+    let empty = BTreeMap::new();
+    let overrides = record
+        .sprint
+        .plan
+        .as_ref()
+        .and_then(|plan| plan.capacity.as_ref())
+        .map(|capacity| &capacity.members)
+        .unwrap_or(&empty);
+
+    if defaults.weekly_hours.is_none()
+        && defaults.member_weekly_hours.is_empty()
+        && overrides.is_empty()
+    {
+        return Vec::new();
+    }
+
+    let mut loads: BTreeMap<String, MemberLoad> = BTreeMap::new();
+    for (_, task) in tasks {
+        let Some(assignee) = task
+            .assignee
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        else {
+            continue;
+        };
+        let load = loads.entry(assignee.to_string()).or_default();
+        let Some(parsed) = task
+            .effort
+            .as_deref()
+            .and_then(|raw| effort::parse_effort(raw).ok())
+        else {
+            continue;
+        };
+        match parsed.kind {
+            EffortKind::TimeHours(hours) => {
+                load.planned_hours += hours;
+                if !done_statuses.contains(&task.status.as_str().to_ascii_lowercase()) {
+                    load.remaining_hours += hours;
+                }
+            }
+            EffortKind::Points(points) => load.planned_points += points,
+        }
+    }
+
+    let mut members: BTreeSet<String> = config
+        .members
+        .iter()
+        .map(|member| member.trim().to_string())
+        .filter(|member| !member.is_empty())
+        .collect();
+    members.extend(defaults.member_weekly_hours.keys().cloned());
+    members.extend(overrides.keys().cloned());
+    members.extend(loads.keys().cloned());
+
+    let workdays = sprint_workdays(record, lifecycle);
+
+    members
+        .into_iter()
+        .map(|member| {
+            let load = loads.remove(&member).unwrap_or_default();
+            let sprint_entry = overrides.get(&member);
+            let in_directory = config.members.iter().any(|m| m.trim() == member);
+            let weekly_hours = sprint_entry
+                .and_then(|entry| entry.weekly_hours)
+                .or_else(|| defaults.member_weekly_hours.get(&member).copied())
+                .or(if in_directory {
+                    defaults.weekly_hours
+                } else {
+                    None
+                })
+                .map(f64::from);
+
+            let time_off_days = match (&workdays, sprint_entry) {
+                (Some(days), Some(entry)) => count_time_off(days, entry),
+                _ => 0,
+            };
+            let available_hours = match (weekly_hours, &workdays) {
+                (Some(weekly), Some(days)) => {
+                    let working = days.len().saturating_sub(time_off_days) as f64;
+                    Some(weekly / WORKDAYS_PER_WEEK * working)
+                }
+                _ => None,
+            };
+
+            SprintMemberCapacityPayload {
+                member,
+                weekly_hours,
+                available_hours,
+                planned_hours: load.planned_hours,
+                remaining_hours: load.remaining_hours,
+                planned_points: load.planned_points,
+                load_ratio: available_hours.and_then(|cap| ratio(load.planned_hours, cap)),
+                over_capacity: available_hours.is_some_and(|cap| load.planned_hours > cap),
+                time_off_days,
+            }
+        })
+        .collect()
+}
+
+/// Weekdays covered by the sprint window. The end date only counts when the sprint ends
+/// later in the day than it started, so a `1w` sprint covers five weekdays.
+fn sprint_workdays(
+    record: &SprintRecord,
+    lifecycle: &SprintLifecycleStatus,
+) -> Option<BTreeSet<NaiveDate>> {
+    let start = resolve_calendar_start(record, lifecycle)?;
+    let end = resolve_calendar_end(record, lifecycle)?;
+    Some(weekdays_between(start, end))
+}
+
+fn weekdays_between(start: DateTime<Utc>, end: DateTime<Utc>) -> BTreeSet<NaiveDate> {
+    let mut days = BTreeSet::new();
+    let last = if end.time() > start.time() {
+        end.date_naive()
+    } else {
+        end.date_naive() - Duration::days(1)
+    };
+    let mut day = start.date_naive();
+    while day <= last {
+        if !matches!(day.weekday(), Weekday::Sat | Weekday::Sun) {
+            days.insert(day);
+        }
+        day += Duration::days(1);
+    }
+    days
+}
+
+fn count_time_off(workdays: &BTreeSet<NaiveDate>, entry: &SprintMemberCapacity) -> usize {
+    let mut off: BTreeSet<NaiveDate> = BTreeSet::new();
+    for range in &entry.time_off {
+        let Ok(start) = NaiveDate::parse_from_str(range.start.trim(), "%Y-%m-%d") else {
+            continue;
+        };
+        let end = range
+            .end
+            .as_deref()
+            .and_then(|raw| NaiveDate::parse_from_str(raw.trim(), "%Y-%m-%d").ok())
+            .unwrap_or(start);
+        off.extend(workdays.range(start..=end.max(start)).copied());
+    }
+    off.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::sprint::SprintTimeOff;
+    use chrono::TimeZone;
+
+    #[test]
+    fn weekdays_skip_weekends_and_the_boundary_day() {
+        let start = Utc.with_ymd_and_hms(2026, 1, 5, 9, 0, 0).unwrap();
+        let two_weeks = Utc.with_ymd_and_hms(2026, 1, 19, 9, 0, 0).unwrap();
+        assert_eq!(weekdays_between(start, two_weeks).len(), 10);
+        let friday_evening = Utc.with_ymd_and_hms(2026, 1, 9, 17, 0, 0).unwrap();
+        assert_eq!(weekdays_between(start, friday_evening).len(), 5);
+    }
+
+    #[test]
+    fn time_off_counts_only_sprint_workdays() {
+        let start = Utc.with_ymd_and_hms(2026, 1, 5, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 1, 19, 0, 0, 0).unwrap();
+        let days = weekdays_between(start, end);
+        let entry = SprintMemberCapacity {
+            weekly_hours: None,
+            time_off: vec![
+                SprintTimeOff {
+                    start: "2026-01-08".to_string(),
+                    end: Some("2026-01-12".to_string()),
+                    note: None,
+                },
+                SprintTimeOff {
+                    start: "2026-01-12".to_string(),
+                    end: None,
+                    note: Some("dentist".to_string()),
+                },
+            ],
+        };
+        // Thu, Fri, Mon; the weekend and the overlapping Monday don't count twice
+        assert_eq!(count_time_off(&days, &entry), 3);
+    }
+}
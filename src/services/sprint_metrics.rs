@@ -4,6 +4,7 @@ use std::collections::HashSet;
 
 use crate::config::types::ResolvedConfig;
 use crate::storage::task::Task as StoredTask;
+use crate::types::TaskStatus;
 use crate::utils::effort::{self, EffortKind};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum, Default, Serialize, Deserialize)]
@@ -71,6 +72,42 @@ pub fn determine_done_statuses_from_config(config: &ResolvedConfig) -> HashSet<S
     done
 }
 
+/// Status that marks work as started (`InProgress`, `Doing`, ...).
+///
+/// Falls back to the first state after the initial one when no state has a recognizable
+/// name, since workflows are ordered from new to done.
+pub fn determine_in_progress_status_from_config(config: &ResolvedConfig) -> Option<TaskStatus> {
+    let states = &config.issue_states.values;
+    let normalized = |status: &TaskStatus| {
+        status
+            .as_str()
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase()
+    };
+    if let Some(status) = states.iter().find(|status| {
+        matches!(
+            normalized(status).as_str(),
+            "inprogress" | "doing" | "started" | "active" | "wip"
+        )
+    }) {
+        return Some(status.clone());
+    }
+    for (alias, status) in &config.branch_status_aliases {
+        if matches!(
+            alias.to_ascii_lowercase().as_str(),
+            "wip" | "doing" | "in-progress" | "inprogress"
+        ) {
+            return Some(status.clone());
+        }
+    }
+    if states.len() > 2 {
+        return states.get(1).cloned();
+    }
+    None
+}
+
 pub fn determine_blocked_statuses_from_config(config: &ResolvedConfig) -> HashSet<String> {
     let mut blocked = HashSet::new();
 
@@ -11,6 +11,7 @@ use crate::services::sprint_analytics::{
     SprintDetail, SprintReviewLifecyclePayload, SprintStatusWarningPayload, SprintSummary,
     to_status_warning_payloads,
 };
+use crate::services::sprint_capacity::{SprintMemberCapacityPayload, compute_member_capacity};
 use crate::services::sprint_metrics::{
     determine_blocked_statuses_from_config, determine_done_statuses_from_config, ratio, ratio_usize,
 };
@@ -130,6 +131,8 @@ pub struct SprintStatsMetricsPayload {
     pub points: Option<SprintStatsEffortPayload>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub status_breakdown: Vec<SprintReviewStatusMetric>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub members: Vec<SprintMemberCapacityPayload>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -177,6 +180,8 @@ pub struct SprintSummaryReportMetrics {
     pub points: Option<SprintStatsEffortPayload>,
     #[serde(skip_serializing_if = "is_zero", default)]
     pub blocked: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub members: Vec<SprintMemberCapacityPayload>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -489,6 +494,7 @@ pub fn compute_sprint_stats(
         hours: hours_payload,
         points: points_payload,
         status_breakdown: metrics.status_breakdown.clone(),
+        members: compute_member_capacity(record, &lifecycle, &tasks, config, &done_statuses),
     };
 
     let timeline_payload = SprintStatsTimelinePayload {
@@ -611,6 +617,7 @@ pub fn compute_sprint_summary(
         hours: hours_payload,
         points: points_payload,
         blocked: metrics.blocked_tasks.len(),
+        members: compute_member_capacity(record, &lifecycle, &tasks, config, &done_statuses),
     };
 
     let timeline_payload = SprintStatsTimelinePayload {
@@ -0,0 +1,205 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::config::types::{ResolvedConfig, SprintRolloverMode};
+use crate::errors::LoTaRResult;
+use crate::services::sprint_metrics::determine_done_statuses_from_config;
+use crate::services::sprint_service::{SprintRecord, SprintService};
+use crate::services::sprint_status::{self, SprintLifecycleState};
+use crate::storage::manager::Storage;
+use crate::storage::sprint::{
+    Sprint, SprintActual, SprintHistoryEntry, SprintPlan, SprintTaskEntry,
+};
+use crate::types::TaskChange;
+use crate::utils::identity::resolve_current_user;
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+/// Where the unfinished tasks of a closed sprint ended up.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct SprintRolloverOutcome {
+    pub mode: SprintRolloverMode,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub target_sprint: Option<u32>,
+    #[serde(skip_serializing_if = "is_false", default)]
+    pub created_sprint: bool,
+    pub tasks: Vec<String>,
+}
+
+/// Detach unfinished tasks from `closed` and carry them over according to `mode`.
+///
+/// `closed` is the in-memory sprint being closed; the caller persists it. Target sprints
+/// and task files are written here. Every move is recorded in the history of the closed
+/// sprint, the target sprint (if any) and the task itself, and the closed sprint keeps
+/// the carried-over ids in `actual.spillover` for velocity reporting.
+pub fn rollover_incomplete_tasks(
+    storage: &mut Storage,
+    closed_id: u32,
+    closed: &mut Sprint,
+    records: &[SprintRecord],
+    mode: SprintRolloverMode,
+    config: &ResolvedConfig,
+    at: DateTime<Utc>,
+) -> LoTaRResult<SprintRolloverOutcome> {
+    let done_statuses = determine_done_statuses_from_config(config);
+    let closed_record = SprintRecord {
+        id: closed_id,
+        sprint: closed.clone(),
+    };
+    let incomplete: Vec<String> = SprintService::load_tasks_for_record(storage, &closed_record)
+        .into_iter()
+        .filter(|(_, task)| !done_statuses.contains(&task.status.as_str().to_ascii_lowercase()))
+        .map(|(id, _)| id)
+        .collect();
+
+    if incomplete.is_empty() {
+        return Ok(SprintRolloverOutcome {
+            mode,
+            target_sprint: None,
+            created_sprint: false,
+            tasks: Vec::new(),
+        });
+    }
+
+    let mut created_sprint = false;
+    let target = match mode {
+        SprintRolloverMode::Backlog => None,
+        SprintRolloverMode::Next => match next_pending_sprint(records, closed_id, at) {
+            Some(record) => Some(record),
+            None => {
+                created_sprint = true;
+                Some(create_followup_sprint(storage, config, at)?)
+            }
+        },
+        SprintRolloverMode::New => {
+            created_sprint = true;
+            Some(create_followup_sprint(storage, config, at)?)
+        }
+    };
+
+    let now = at.to_rfc3339();
+    let actor = resolve_current_user(Some(storage.root_path.as_path()));
+    let closed_ref = format!("#{}", closed_id);
+    let destination = target
+        .as_ref()
+        .map(|record| format!("#{}", record.id))
+        .unwrap_or_else(|| "backlog".to_string());
+
+    let moving: HashSet<&str> = incomplete.iter().map(String::as_str).collect();
+    closed
+        .tasks
+        .retain(|entry| !moving.contains(entry.id.as_str()));
+    let actual = closed.actual.get_or_insert_with(SprintActual::default);
+    actual.spillover.extend(incomplete.iter().cloned());
+    closed.history.push(SprintHistoryEntry {
+        at: now.clone(),
+        actor: actor.clone(),
+        changes: incomplete
+            .iter()
+            .map(|id| TaskChange {
+                field: "spillover".into(),
+                old: Some(id.clone()),
+                new: Some(destination.clone()),
+            })
+            .collect(),
+    });
+
+    if let Some(mut record) = target.clone() {
+        let mut changes = Vec::new();
+        for id in &incomplete {
+            if record.sprint.tasks.iter().any(|entry| &entry.id == id) {
+                continue;
+            }
+            record.sprint.tasks.push(SprintTaskEntry {
+                id: id.clone(),
+                order: None,
+            });
+            changes.push(TaskChange {
+                field: "carried_over".into(),
+                old: Some(closed_ref.clone()),
+                new: Some(id.clone()),
+            });
+        }
+        if !changes.is_empty() {
+            record.sprint.history.push(SprintHistoryEntry {
+                at: now.clone(),
+                actor: actor.clone(),
+                changes,
+            });
+            SprintService::update(storage, record.id, record.sprint)?;
+        }
+    }
+
+    for id in &incomplete {
+        let project = id.split('-').next().unwrap_or_default();
+        let Some(mut task) = storage.get(id, project) else {
+            continue;
+        };
+        task.history.push(SprintHistoryEntry {
+            at: now.clone(),
+            actor: actor.clone(),
+            changes: vec![TaskChange {
+                field: "sprint".into(),
+                old: Some(closed_ref.clone()),
+                new: target.as_ref().map(|record| format!("#{}", record.id)),
+            }],
+        });
+        task.modified = now.clone();
+        storage.edit(id, &task)?;
+    }
+
+    Ok(SprintRolloverOutcome {
+        mode,
+        target_sprint: target.map(|record| record.id),
+        created_sprint,
+        tasks: incomplete,
+    })
+}
+
+/// The earliest pending sprint other than the one being closed, ordered by planned start
+/// and then by id.
+fn next_pending_sprint(
+    records: &[SprintRecord],
+    closed_id: u32,
+    at: DateTime<Utc>,
+) -> Option<SprintRecord> {
+    records
+        .iter()
+        .filter(|record| record.id != closed_id)
+        .filter_map(|record| {
+            let lifecycle = sprint_status::derive_status(&record.sprint, at);
+            (lifecycle.state == SprintLifecycleState::Pending)
+                .then_some((lifecycle.planned_start, record))
+        })
+        .min_by(|a, b| match (a.0, b.0) {
+            (Some(left), Some(right)) => left.cmp(&right).then(a.1.id.cmp(&b.1.id)),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => a.1.id.cmp(&b.1.id),
+        })
+        .map(|(_, record)| record.clone())
+}
+
+fn create_followup_sprint(
+    storage: &mut Storage,
+    config: &ResolvedConfig,
+    at: DateTime<Utc>,
+) -> LoTaRResult<SprintRecord> {
+    let sprint = Sprint {
+        plan: Some(SprintPlan {
+            starts_at: Some(at.to_rfc3339()),
+            ..SprintPlan::default()
+        }),
+        ..Sprint::default()
+    };
+    let outcome = SprintService::create(storage, sprint, Some(&config.sprint_defaults))?;
+    Ok(outcome.record)
+}
@@ -170,7 +170,7 @@ impl SprintService {
         fs::create_dir_all(dir)?;
         let serialized = sprint.to_yaml()?;
         let path = dir.join(format!("{}.yml", id));
-        fs::write(path, serialized)?;
+        crate::utils::filesystem::write_yaml_preserving_modeline(&path, &serialized)?;
         Ok(())
     }
 }
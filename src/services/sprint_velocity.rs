@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use chrono::{DateTime, Duration, Utc};
 use serde::Serialize;
 
@@ -17,6 +19,7 @@ use crate::services::sprint_timing::{
     resolve_calendar_start,
 };
 use crate::storage::manager::Storage;
+use crate::storage::task::Task as StoredTask;
 
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
@@ -43,6 +46,7 @@ pub struct VelocityEntry {
     pub relative: String,
     pub committed: f64,
     pub completed: f64,
+    pub spillover: f64,
     pub capacity: Option<f64>,
     pub completion_ratio: Option<f64>,
     pub warnings: Vec<SprintStatusWarningPayload>,
@@ -79,6 +83,9 @@ pub struct SprintVelocityEntryPayload {
     pub window: Option<String>,
     pub committed: f64,
     pub completed: f64,
+    /// Work rolled over to another sprint or the backlog when this sprint closed.
+    #[serde(skip_serializing_if = "is_zero", default)]
+    pub spillover: f64,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub completion_ratio: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
@@ -115,6 +122,10 @@ fn is_false(value: &bool) -> bool {
     !*value
 }
 
+fn is_zero(value: &f64) -> bool {
+    *value == 0.0
+}
+
 impl VelocityComputation {
     pub fn to_payload(&self, include_active: bool) -> SprintVelocityResponse {
         SprintVelocityResponse {
@@ -147,6 +158,7 @@ impl VelocityComputation {
                     )),
                     committed: entry.committed,
                     completed: entry.completed,
+                    spillover: entry.spillover,
                     completion_ratio: entry.completion_ratio,
                     capacity: entry.capacity,
                     capacity_commitment_ratio: entry
@@ -220,11 +232,18 @@ pub fn compute_velocity(
 
         let tasks = SprintService::load_tasks_for_record(storage, record);
         let totals = compute_velocity_totals(&tasks, &done_statuses);
+        // Rolled-over tasks were committed to this sprint but never finished in it, whatever
+        // their status is now.
+        let spilled =
+            compute_velocity_totals(&load_spillover_tasks(storage, record), &HashSet::new());
 
-        let (committed, completed, capacity) = match options.metric {
-            SprintBurndownMetric::Tasks => {
-                (totals.total_tasks as f64, totals.done_tasks as f64, None)
-            }
+        let (committed, completed, spillover, capacity) = match options.metric {
+            SprintBurndownMetric::Tasks => (
+                totals.total_tasks as f64,
+                totals.done_tasks as f64,
+                spilled.total_tasks as f64,
+                None,
+            ),
             SprintBurndownMetric::Points => {
                 let capacity = record
                     .sprint
@@ -233,7 +252,12 @@ pub fn compute_velocity(
                     .and_then(|plan| plan.capacity.as_ref())
                     .and_then(|cap| cap.points)
                     .map(|value| value as f64);
-                (totals.total_points, totals.done_points, capacity)
+                (
+                    totals.total_points,
+                    totals.done_points,
+                    spilled.total_points,
+                    capacity,
+                )
             }
             SprintBurndownMetric::Hours => {
                 let capacity = record
@@ -243,10 +267,16 @@ pub fn compute_velocity(
                     .and_then(|plan| plan.capacity.as_ref())
                     .and_then(|cap| cap.hours)
                     .map(|value| value as f64);
-                (totals.total_hours, totals.done_hours, capacity)
+                (
+                    totals.total_hours,
+                    totals.done_hours,
+                    spilled.total_hours,
+                    capacity,
+                )
             }
         };
 
+        let committed = committed + spillover;
         let completion_ratio = ratio(completed, committed);
         let warnings = to_status_warning_payloads(&lifecycle.warnings);
         let actual_start = lifecycle.actual_start;
@@ -270,6 +300,7 @@ pub fn compute_velocity(
             relative,
             committed,
             completed,
+            spillover,
             capacity,
             completion_ratio,
             warnings,
@@ -332,3 +363,17 @@ pub fn compute_velocity(
         average_completion_ratio,
     }
 }
+
+fn load_spillover_tasks(storage: &Storage, record: &SprintRecord) -> Vec<(String, StoredTask)> {
+    let Some(actual) = record.sprint.actual.as_ref() else {
+        return Vec::new();
+    };
+    actual
+        .spillover
+        .iter()
+        .filter_map(|id| {
+            let project = id.split('-').next().unwrap_or_default();
+            storage.get(id, project).map(|task| (id.clone(), task))
+        })
+        .collect()
+}
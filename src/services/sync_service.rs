@@ -21,6 +21,7 @@ use crate::services::sync_report_service::SyncReportService;
 use crate::services::task_service::TaskService;
 use crate::storage::manager::Storage;
 use crate::types::{CustomFieldValue, CustomFields, Priority, TaskStatus, TaskType};
+use crate::utils::member::MemberDirectory;
 use crate::workspace::TasksDirectoryResolver;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -523,6 +524,7 @@ impl SyncService {
         );
 
         let mut recorder = SyncReportRecorder::new(context);
+        let members = MemberDirectory::from_config(&resolved);
 
         let outcome = match direction {
             SyncDirection::Push => perform_push(
@@ -532,6 +534,7 @@ impl SyncService {
                 task_id,
                 dry_run,
                 client.as_ref(),
+                &members,
                 &mut recorder,
                 &mut warnings,
             ),
@@ -544,6 +547,7 @@ impl SyncService {
                 task_id,
                 dry_run,
                 client.as_ref().expect("sync client required"),
+                &members,
                 &mut recorder,
                 &mut warnings,
             ),
@@ -856,6 +860,7 @@ impl JiraIssuePayload {
 struct JiraLookupCache {
     issue_types: Option<Vec<String>>,
     user_cache: HashMap<String, String>,
+    members: MemberDirectory,
 }
 
 #[derive(Debug)]
@@ -873,6 +878,7 @@ fn perform_push(
     task_id: Option<&str>,
     dry_run: bool,
     client: Option<&SyncClient>,
+    members: &MemberDirectory,
     recorder: &mut SyncReportRecorder,
     warnings: &mut Vec<String>,
 ) -> LoTaRResult<()> {
@@ -903,7 +909,10 @@ fn perform_push(
     };
 
     let mut failures = Vec::new();
-    let mut jira_lookup = JiraLookupCache::default();
+    let mut jira_lookup = JiraLookupCache {
+        members: members.clone(),
+        ..JiraLookupCache::default()
+    };
 
     for (_id, task) in tasks {
         match determine_reference_state(remote, &task) {
@@ -1015,7 +1024,7 @@ fn perform_push(
                     }
                     SyncProvider::Github => {
                         let mut payload =
-                            build_github_payload(remote, &task, SyncOperation::Update);
+                            build_github_payload(remote, &task, members, SyncOperation::Update);
                         let mut changed_fields = github_payload_field_names(&payload);
                         if payload.is_empty(true) {
                             recorder.record(
@@ -1147,7 +1156,7 @@ fn perform_push(
                         }
                         SyncProvider::Github => {
                             let payload =
-                                build_github_payload(remote, &task, SyncOperation::Create);
+                                build_github_payload(remote, &task, members, SyncOperation::Create);
                             format_field_list(&github_payload_field_names(&payload))
                                 .map(|fields| format!("Dry run: would create {}", fields))
                         }
@@ -1250,7 +1259,8 @@ fn perform_push(
                         );
                     }
                     SyncProvider::Github => {
-                        let payload = build_github_payload(remote, &task, SyncOperation::Create);
+                        let payload =
+                            build_github_payload(remote, &task, members, SyncOperation::Create);
                         let repo = remote.repo.as_deref().ok_or_else(|| {
                             LoTaRError::ValidationError(
                                 "GitHub remote must define repo".to_string(),
@@ -1342,6 +1352,7 @@ fn perform_pull(
     task_id: Option<&str>,
     dry_run: bool,
     client: &SyncClient,
+    members: &MemberDirectory,
     recorder: &mut SyncReportRecorder,
     warnings: &mut Vec<String>,
 ) -> LoTaRResult<()> {
@@ -1394,7 +1405,8 @@ fn perform_pull(
             }
         };
 
-        let update = build_task_update_from_issue(remote.provider, remote, &issue, Some(&existing));
+        let update =
+            build_task_update_from_issue(remote.provider, remote, &issue, Some(&existing), members);
         if task_update_is_empty(&update) {
             recorder.record(
                 SyncEntryStatus::Skipped,
@@ -1500,8 +1512,13 @@ fn perform_pull(
 
         if let Some(task_id) = reference_index.get(&reference) {
             let existing = tasks_by_id.get(task_id).cloned();
-            let update =
-                build_task_update_from_issue(remote.provider, remote, &issue, existing.as_ref());
+            let update = build_task_update_from_issue(
+                remote.provider,
+                remote,
+                &issue,
+                existing.as_ref(),
+                members,
+            );
             if task_update_is_empty(&update) {
                 recorder.record(
                     SyncEntryStatus::Skipped,
@@ -1557,7 +1574,7 @@ fn perform_pull(
             );
         } else {
             let (create, status) =
-                build_task_create_from_issue(remote.provider, remote, &issue, project)?;
+                build_task_create_from_issue(remote.provider, remote, &issue, project, members)?;
             if dry_run {
                 recorder.record(
                     SyncEntryStatus::Created,
@@ -2406,8 +2423,10 @@ fn build_task_update_from_issue(
     remote: &SyncRemoteConfig,
     issue: &JsonValue,
     existing: Option<&TaskDTO>,
+    members: &MemberDirectory,
 ) -> TaskUpdate {
-    let local = map_issue_to_local_fields(provider, remote, issue, existing);
+    let mut local = map_issue_to_local_fields(provider, remote, issue, existing);
+    canonicalize_local_members(&mut local, members);
     let custom_fields = local
         .custom_fields
         .map(|fields| merge_custom_fields(existing.map(|task| &task.custom_fields), fields));
@@ -2443,10 +2462,18 @@ fn build_task_update_from_issue(
         if update.task_type.as_ref() == Some(&existing.task_type) {
             update.task_type = None;
         }
-        if update.assignee.as_deref() == existing.assignee.as_deref() {
+        if same_optional_member(
+            members,
+            update.assignee.as_deref(),
+            existing.assignee.as_deref(),
+        ) {
             update.assignee = None;
         }
-        if update.reporter.as_deref() == existing.reporter.as_deref() {
+        if same_optional_member(
+            members,
+            update.reporter.as_deref(),
+            existing.reporter.as_deref(),
+        ) {
             update.reporter = None;
         }
         if let Some(tags) = update.tags.as_ref()
@@ -2467,8 +2494,10 @@ fn build_task_create_from_issue(
     remote: &SyncRemoteConfig,
     issue: &JsonValue,
     project: &str,
+    members: &MemberDirectory,
 ) -> LoTaRResult<(TaskCreate, Option<TaskStatus>)> {
-    let local = map_issue_to_local_fields(provider, remote, issue, None);
+    let mut local = map_issue_to_local_fields(provider, remote, issue, None);
+    canonicalize_local_members(&mut local, members);
     let title = local
         .title
         .or_else(|| default_title_from_issue(provider, issue));
@@ -2495,6 +2524,24 @@ fn build_task_create_from_issue(
     Ok((create, local.status))
 }
 
+/// Map remote logins/accountIds onto member handles so pulls store one identity per person.
+fn canonicalize_local_members(local: &mut LocalFieldValues, members: &MemberDirectory) {
+    for value in [&mut local.assignee, &mut local.reporter] {
+        if let Some(member) = value.as_mut()
+            && !member.trim().is_empty()
+        {
+            *member = members.canonical(member);
+        }
+    }
+}
+
+fn same_optional_member(members: &MemberDirectory, a: Option<&str>, b: Option<&str>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => members.same_member(a, b),
+        (a, b) => a == b,
+    }
+}
+
 fn map_issue_to_local_fields(
     provider: SyncProvider,
     remote: &SyncRemoteConfig,
@@ -2815,6 +2862,7 @@ fn build_jira_payload(
 fn build_github_payload(
     remote: &SyncRemoteConfig,
     task: &TaskDTO,
+    members: &MemberDirectory,
     op: SyncOperation,
 ) -> GithubIssuePayload {
     let mut payload = GithubIssuePayload::default();
@@ -2824,7 +2872,13 @@ fn build_github_payload(
             .field
             .clone()
             .unwrap_or_else(|| local_key.to_string());
-        let value = local_value_for_field(task, local_key);
+        let mut value = local_value_for_field(task, local_key);
+        if matches!(local_key.as_str(), "assignee" | "reporter")
+            && let Some(FieldValue::String(member)) = value.as_ref()
+            && let Some(login) = members.github_login(member)
+        {
+            value = Some(FieldValue::String(login.to_string()));
+        }
         let mapped = apply_mapping_for_push(remote.provider, &remote_field, value, &detail);
         let Some(value) = mapped else {
             continue;
@@ -3470,6 +3524,10 @@ fn lookup_jira_account_id(
         return None;
     }
 
+    if let Some(account_id) = lookup.members.jira_account_id(trimmed) {
+        return Some(account_id.to_string());
+    }
+
     let key = trimmed.to_ascii_lowercase();
     if let Some(account_id) = lookup.user_cache.get(&key) {
         return Some(account_id.clone());
@@ -3849,6 +3907,7 @@ mod sync_mapping_tests {
             subtitle: None,
             description: Some("Body".to_string()),
             tags: vec!["alpha".to_string()],
+            acceptance_criteria: vec![],
             relationships: Default::default(),
             comments: vec![],
             references: vec![],
@@ -3932,7 +3991,13 @@ mod sync_mapping_tests {
             ]
         });
 
-        let update = build_task_update_from_issue(SyncProvider::Github, &remote, &issue, None);
+        let update = build_task_update_from_issue(
+            SyncProvider::Github,
+            &remote,
+            &issue,
+            None,
+            &MemberDirectory::default(),
+        );
         assert_eq!(update.task_type.unwrap().to_string(), "Feature");
         assert_eq!(update.priority.unwrap().to_string(), "Medium");
     }
@@ -3963,8 +4028,13 @@ mod sync_mapping_tests {
         let mut existing = sample_task();
         existing.status = TaskStatus::from("Todo");
 
-        let update =
-            build_task_update_from_issue(SyncProvider::Github, &remote, &issue, Some(&existing));
+        let update = build_task_update_from_issue(
+            SyncProvider::Github,
+            &remote,
+            &issue,
+            Some(&existing),
+            &MemberDirectory::default(),
+        );
         assert!(update.status.is_none());
     }
 
@@ -4012,6 +4082,7 @@ mod tests {
             subtitle: None,
             description: Some("Body".to_string()),
             tags: vec!["alpha".to_string()],
+            acceptance_criteria: vec![],
             relationships: Default::default(),
             comments: vec![],
             references: vec![],
@@ -4048,7 +4119,12 @@ mod tests {
             SyncFieldMapping::Simple("labels".to_string()),
         );
 
-        let payload = build_github_payload(&remote, &sample_task(), SyncOperation::Update);
+        let payload = build_github_payload(
+            &remote,
+            &sample_task(),
+            &MemberDirectory::default(),
+            SyncOperation::Update,
+        );
         assert_eq!(payload.state.as_deref(), Some("open"));
         assert_eq!(payload.labels, vec!["alpha".to_string()]);
     }
@@ -4074,7 +4150,13 @@ mod tests {
                 "status": {"name": "In Progress"}
             }
         });
-        let update = build_task_update_from_issue(SyncProvider::Jira, &remote, &issue, None);
+        let update = build_task_update_from_issue(
+            SyncProvider::Jira,
+            &remote,
+            &issue,
+            None,
+            &MemberDirectory::default(),
+        );
         assert_eq!(update.status.unwrap().to_string(), "In Progress");
     }
 
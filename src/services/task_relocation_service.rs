@@ -0,0 +1,839 @@
+//! Giving a task a new ID and rewriting what points at it (`lotar renumber`,
+//! `lotar task move`, `lotar task copy`).
+//!
+//! Numeric IDs are allocated by scanning the project folder, so two branches that create
+//! tasks concurrently pick the same number. Git then sees the same file added twice; the
+//! merge driver (and git's own textual merge) leaves both versions between conflict
+//! markers. [`TaskRelocationService::resolve_collisions`] splits such files and gives the
+//! incoming task the next free number. [`TaskRelocationService::renumber`] moves a single
+//! task and rewrites relationships in other tasks, sprint membership lists and ticket keys
+//! in source files. [`TaskRelocationService::move_task`] does the same across projects and
+//! leaves a [`TaskRedirect`] stub at the old path so the old ID keeps resolving.
+
+use regex::Regex;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::resolution;
+use crate::config::types::ResolvedConfig;
+use crate::errors::{LoTaRError, LoTaRResult};
+use crate::services::attachment_service::AttachmentService;
+use crate::services::scan_service::resolve_scan_config;
+use crate::services::sprint_service::SprintService;
+use crate::storage::TaskFilter;
+use crate::storage::TaskRedirect;
+use crate::storage::manager::Storage;
+use crate::storage::operations::StorageOperations;
+use crate::storage::task::Task;
+use crate::types::{TaskChange, TaskChangeLogEntry, TaskRelationships, TaskStatus, TaskType};
+use crate::utils::filesystem::{YAML_MODELINE_PREFIX, file_numeric_stem, list_files_with_ext};
+use crate::utils::identity::resolve_current_user;
+use crate::utils::project::resolve_project_input;
+use crate::workspace::TasksDirectoryResolver;
+
+/// Why a task was given a new number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelocationReason {
+    /// Explicitly requested (`lotar renumber <ID>`)
+    Requested,
+    /// Two branches created different tasks under the same number
+    MergeCollision,
+    /// Two files in one project resolved to the same number (e.g. `3.yml` and `03.yml`)
+    DuplicateFile,
+    /// Moved to another project (`lotar task move`)
+    Moved,
+    /// Copied to another project (`lotar task copy`); the original is untouched
+    Copied,
+}
+
+/// Kind of file a reference was rewritten in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RewriteTarget {
+    Task,
+    Sprint,
+    Source,
+    /// Attachment copied into the target project's attachments directory
+    Attachment,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReferenceRewrite {
+    pub target: RewriteTarget,
+    /// Task or sprint file relative to the tasks root, or source file relative to the repository
+    pub path: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Relocation {
+    pub from: String,
+    pub to: String,
+    /// Task file the relocated task was read from, relative to the tasks root
+    pub file: String,
+    pub reason: RelocationReason,
+    pub rewrites: Vec<ReferenceRewrite>,
+    /// Status and type values the target project does not allow, replaced by its defaults
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mapped: Vec<TaskChange>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RenumberReport {
+    pub dry_run: bool,
+    pub relocations: Vec<Relocation>,
+    /// Files that need a human (e.g. conflicting edits to one task rather than two tasks)
+    pub skipped: Vec<String>,
+}
+
+pub struct TaskRelocationService;
+
+impl TaskRelocationService {
+    /// Move `id` to number `to` (or the next free number in its project) and rewrite every
+    /// reference to it.
+    pub fn renumber(
+        resolver: &TasksDirectoryResolver,
+        id: &str,
+        to: Option<u64>,
+        dry_run: bool,
+    ) -> LoTaRResult<Relocation> {
+        let (project, _) = split_task_id(id)?;
+        let old_id = id.trim().to_ascii_uppercase();
+        let project_dir = resolver.path.join(&project);
+        let source = StorageOperations::get_file_path_for_id(&project_dir, &old_id)
+            .ok_or_else(|| LoTaRError::TaskNotFound(old_id.clone()))?;
+        let raw = fs::read_to_string(&source)?;
+        let mut task: Task = serde_yaml::from_str(&raw)?;
+
+        let number = match to {
+            Some(0) => {
+                return Err(LoTaRError::ValidationError(
+                    "Task numbers start at 1".to_string(),
+                ));
+            }
+            Some(number) => {
+                let target = format!("{}-{}", project, number);
+                if target == old_id {
+                    return Err(LoTaRError::ValidationError(format!(
+                        "{} already has number {}",
+                        old_id, number
+                    )));
+                }
+                if StorageOperations::get_file_path_for_id(&project_dir, &target).is_some() {
+                    return Err(LoTaRError::ValidationError(format!(
+                        "{} already exists",
+                        target
+                    )));
+                }
+                number
+            }
+            None => StorageOperations::get_current_id(&project_dir) + 1,
+        };
+        let new_id = format!("{}-{}", project, number);
+
+        // Write the task under its new number first, so rewritten references never point
+        // at a file that failed to be written.
+        if !dry_run {
+            record_id_change(&mut task, &old_id, &new_id, &resolver.path);
+            let target = StorageOperations::get_file_path(&project, number, &resolver.path);
+            write_task_file(&target, &task, modeline(&raw))?;
+        }
+        let rewrites = Self::rewrite_references(resolver, &old_id, &new_id, dry_run)?;
+        if !dry_run {
+            fs::remove_file(&source)?;
+        }
+
+        Ok(Relocation {
+            from: old_id,
+            to: new_id,
+            file: relative_to(&source, &resolver.path),
+            reason: RelocationReason::Requested,
+            rewrites,
+            mapped: Vec::new(),
+        })
+    }
+
+    /// Move `id` into project `to_project` (name or prefix) under the next free number there, rewrite every
+    /// reference to it and leave a redirect stub at the old path.
+    pub fn move_task(
+        resolver: &TasksDirectoryResolver,
+        id: &str,
+        to_project: &str,
+        dry_run: bool,
+    ) -> LoTaRResult<Relocation> {
+        let old_id = StorageOperations::resolve_id(&resolver.path, &id.trim().to_ascii_uppercase());
+        let (source, raw, mut task, new_id) =
+            Self::prepare_transfer(resolver, &old_id, to_project)?;
+        let (project, _) = split_task_id(&old_id)?;
+        let (target_project, number) = split_task_id(&new_id)?;
+
+        let mapped = fit_to_project(&mut task, resolver, &target_project);
+        let attachments = copy_attachments(resolver, &task, &project, &target_project, dry_run)?;
+        if !dry_run {
+            qualify_relationships(&mut task.relationships, &project);
+            record_mapped_fields(&mut task, &mapped, &resolver.path);
+            record_id_change(&mut task, &old_id, &new_id, &resolver.path);
+            let target = StorageOperations::get_file_path(&target_project, number, &resolver.path);
+            write_task_file(&target, &task, modeline(&raw))?;
+        }
+        let mut rewrites = Self::rewrite_references(resolver, &old_id, &new_id, dry_run)?;
+        rewrites.extend(attachments);
+        if !dry_run {
+            fs::write(&source, serde_yaml::to_string(&TaskRedirect::new(&new_id))?)?;
+        }
+
+        Ok(Relocation {
+            from: old_id,
+            to: new_id,
+            file: relative_to(&source, &resolver.path),
+            reason: RelocationReason::Moved,
+            rewrites,
+            mapped,
+        })
+    }
+
+    /// Create a copy of `id` in project `to_project`. The copy starts a fresh history and
+    /// sprint membership; references to the original are left alone.
+    pub fn copy_task(
+        resolver: &TasksDirectoryResolver,
+        id: &str,
+        to_project: &str,
+        dry_run: bool,
+    ) -> LoTaRResult<Relocation> {
+        let old_id = StorageOperations::resolve_id(&resolver.path, &id.trim().to_ascii_uppercase());
+        let (source, raw, original, new_id) =
+            Self::prepare_transfer(resolver, &old_id, to_project)?;
+        let (project, _) = split_task_id(&old_id)?;
+        let (target_project, number) = split_task_id(&new_id)?;
+
+        let mut copy = original.clone();
+        let mapped = fit_to_project(&mut copy, resolver, &target_project);
+        let rewrites = copy_attachments(resolver, &original, &project, &target_project, dry_run)?;
+        if !dry_run {
+            let now = chrono::Utc::now().to_rfc3339();
+            let actor = resolve_current_user(Some(&resolver.path));
+            qualify_relationships(&mut copy.relationships, &project);
+            // Code references point at lines that mention the original's key
+            copy.references.retain(|reference| reference.code.is_none());
+            copy.sprints.clear();
+            copy.created = now.clone();
+            copy.modified = String::new();
+            copy.history = vec![TaskChangeLogEntry {
+                at: now.clone(),
+                actor: actor.clone(),
+                changes: std::iter::once(TaskChange {
+                    field: "copied_from".into(),
+                    old: None,
+                    new: Some(old_id.clone()),
+                })
+                .chain(mapped.iter().cloned())
+                .collect(),
+            }];
+            let target = StorageOperations::get_file_path(&target_project, number, &resolver.path);
+            write_task_file(&target, &copy, modeline(&raw))?;
+
+            let mut original = original;
+            original.history.push(TaskChangeLogEntry {
+                at: now.clone(),
+                actor,
+                changes: vec![TaskChange {
+                    field: "copied_to".into(),
+                    old: None,
+                    new: Some(new_id.clone()),
+                }],
+            });
+            original.modified = now;
+            write_task_file(&source, &original, modeline(&raw))?;
+        }
+
+        Ok(Relocation {
+            from: old_id,
+            to: new_id,
+            file: relative_to(&source, &resolver.path),
+            reason: RelocationReason::Copied,
+            rewrites,
+            mapped,
+        })
+    }
+
+    /// Load `old_id` for a move or copy and allocate its ID in `to_project`.
+    fn prepare_transfer(
+        resolver: &TasksDirectoryResolver,
+        old_id: &str,
+        to_project: &str,
+    ) -> LoTaRResult<(PathBuf, String, Task, String)> {
+        let (project, _) = split_task_id(old_id)?;
+        if to_project.trim().is_empty() {
+            return Err(LoTaRError::ValidationError(
+                "Target project is required".to_string(),
+            ));
+        }
+        let target_project = resolve_project_input(to_project.trim(), &resolver.path);
+        if target_project == project {
+            return Err(LoTaRError::ValidationError(format!(
+                "{} is already in project {}",
+                old_id, project
+            )));
+        }
+        let target_dir = resolver.path.join(&target_project);
+        if !target_dir.is_dir() {
+            return Err(LoTaRError::ProjectNotFound(target_project));
+        }
+
+        let source = StorageOperations::get_file_path_for_id(&resolver.path.join(&project), old_id)
+            .ok_or_else(|| LoTaRError::TaskNotFound(old_id.to_string()))?;
+        let raw = fs::read_to_string(&source)?;
+        let task: Task = serde_yaml::from_str(&raw)?;
+        let number = StorageOperations::get_current_id(&target_dir) + 1;
+        Ok((source, raw, task, format!("{}-{}", target_project, number)))
+    }
+
+    /// Split task files that hold two different tasks between conflict markers and move
+    /// duplicate files to free numbers. The task on our side (or the canonically named file)
+    /// keeps its number; references are left alone because they cannot be told apart.
+    pub fn resolve_collisions(
+        resolver: &TasksDirectoryResolver,
+        dry_run: bool,
+    ) -> LoTaRResult<RenumberReport> {
+        let mut report = RenumberReport {
+            dry_run,
+            ..RenumberReport::default()
+        };
+
+        for project_dir in project_dirs(&resolver.path)? {
+            let project = project_dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default()
+                .to_string();
+            let mut next = StorageOperations::get_current_id(&project_dir) + 1;
+
+            let mut by_number: BTreeMap<u64, Vec<PathBuf>> = BTreeMap::new();
+            for path in list_files_with_ext(&project_dir, "yml") {
+                if let Some(number) = file_numeric_stem(&path).filter(|n| *n > 0) {
+                    // Redirect stubs left by `task move` hold no task to relocate.
+                    let is_redirect = fs::read_to_string(&path)
+                        .is_ok_and(|raw| TaskRedirect::parse(&raw).is_some());
+                    if !is_redirect {
+                        by_number.entry(number).or_default().push(path);
+                    }
+                }
+            }
+
+            for (number, mut paths) in by_number {
+                paths.sort();
+                let id = format!("{}-{}", project, number);
+                let canonical = project_dir.join(format!("{}.yml", number));
+                let keep = if paths.contains(&canonical) {
+                    canonical
+                } else {
+                    paths[0].clone()
+                };
+
+                for path in &paths {
+                    let raw = fs::read_to_string(path)?;
+                    let file = relative_to(path, &resolver.path);
+
+                    if has_conflict_markers(&raw) {
+                        let Some((ours, theirs)) = split_collision(&raw) else {
+                            report.skipped.push(format!(
+                                "{}: conflicting edits to one task; resolve the markers by hand",
+                                file
+                            ));
+                            continue;
+                        };
+                        let new_id = format!("{}-{}", project, next);
+                        if !dry_run {
+                            let (ours_text, mut incoming) = (ours.0, theirs.1);
+                            record_id_change(&mut incoming, &id, &new_id, &resolver.path);
+                            fs::write(path, ours_text)?;
+                            let target =
+                                StorageOperations::get_file_path(&project, next, &resolver.path);
+                            write_task_file(&target, &incoming, modeline(&theirs.0))?;
+                        }
+                        next += 1;
+                        report.relocations.push(Relocation {
+                            from: id.clone(),
+                            to: new_id,
+                            file,
+                            reason: RelocationReason::MergeCollision,
+                            rewrites: Vec::new(),
+                            mapped: Vec::new(),
+                        });
+                    } else if *path != keep {
+                        let Ok(mut task) = serde_yaml::from_str::<Task>(&raw) else {
+                            report.skipped.push(format!(
+                                "{}: duplicate of {} that cannot be parsed",
+                                file, id
+                            ));
+                            continue;
+                        };
+                        let new_id = format!("{}-{}", project, next);
+                        if !dry_run {
+                            record_id_change(&mut task, &id, &new_id, &resolver.path);
+                            let target =
+                                StorageOperations::get_file_path(&project, next, &resolver.path);
+                            write_task_file(&target, &task, modeline(&raw))?;
+                            fs::remove_file(path)?;
+                        }
+                        next += 1;
+                        report.relocations.push(Relocation {
+                            from: id.clone(),
+                            to: new_id,
+                            file,
+                            reason: RelocationReason::DuplicateFile,
+                            rewrites: Vec::new(),
+                            mapped: Vec::new(),
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Point relationships, sprint membership lists and ticket keys in source files that
+    /// mention `old_id` at `new_id`. Returns what was (or, with `dry_run`, would be) changed.
+    pub fn rewrite_references(
+        resolver: &TasksDirectoryResolver,
+        old_id: &str,
+        new_id: &str,
+        dry_run: bool,
+    ) -> LoTaRResult<Vec<ReferenceRewrite>> {
+        let mut storage = Storage::new(&resolver.path);
+        let mut rewrites = Vec::new();
+
+        // Sprints first: an unreadable sprint file aborts before anything was written.
+        let mut sprint_updates = Vec::new();
+        for mut record in SprintService::list(&storage)? {
+            let mut count = 0;
+            for entry in record.sprint.tasks.iter_mut() {
+                if entry.id.eq_ignore_ascii_case(old_id) {
+                    entry.id = new_id.to_string();
+                    count += 1;
+                }
+            }
+            if count > 0 {
+                rewrites.push(ReferenceRewrite {
+                    target: RewriteTarget::Sprint,
+                    path: format!("@sprints/{}.yml", record.id),
+                    count,
+                });
+                sprint_updates.push(record);
+            }
+        }
+
+        let mut task_updates = Vec::new();
+        for (task_id, mut task) in storage.search(&TaskFilter::default()) {
+            let project = task_id.split('-').next().unwrap_or_default().to_string();
+            let count = rewrite_relationships(&mut task.relationships, &project, old_id, new_id);
+            if count > 0 {
+                rewrites.push(ReferenceRewrite {
+                    target: RewriteTarget::Task,
+                    path: task_file_display(&task_id),
+                    count,
+                });
+                task_updates.push((task_id, task));
+            }
+        }
+
+        let source_updates = source_mentions(resolver, old_id)?;
+        for (path, count, _) in &source_updates {
+            rewrites.push(ReferenceRewrite {
+                target: RewriteTarget::Source,
+                path: crate::utils::paths::repo_relative_display(path),
+                count: *count,
+            });
+        }
+
+        if !dry_run {
+            for record in sprint_updates {
+                SprintService::update(&mut storage, record.id, record.sprint)?;
+            }
+            for (task_id, task) in task_updates {
+                storage.edit(&task_id, &task)?;
+            }
+            for (path, _, pattern) in source_updates {
+                let text = fs::read_to_string(&path)?;
+                fs::write(&path, pattern.replace_all(&text, new_id).as_ref())?;
+            }
+        }
+
+        Ok(rewrites)
+    }
+}
+
+/// Split a conflicted file into its two sides when they are two different tasks (they
+/// were created at different times). Returns `(raw text, task)` for ours and theirs.
+fn split_collision(raw: &str) -> Option<((String, Task), (String, Task))> {
+    let (ours, theirs) = split_conflict(raw)?;
+    let ours_task: Task = serde_yaml::from_str(&ours).ok()?;
+    let theirs_task: Task = serde_yaml::from_str(&theirs).ok()?;
+    (ours_task.created != theirs_task.created).then_some(((ours, ours_task), (theirs, theirs_task)))
+}
+
+#[derive(PartialEq)]
+enum Section {
+    Both,
+    Ours,
+    Base,
+    Theirs,
+}
+
+/// Rebuild both sides of a file containing `<<<<<<<` / `|||||||` / `=======` / `>>>>>>>`
+/// blocks. Lines outside the blocks belong to both sides.
+fn split_conflict(raw: &str) -> Option<(String, String)> {
+    let mut section = Section::Both;
+    let (mut ours, mut theirs) = (String::new(), String::new());
+    for line in raw.split_inclusive('\n') {
+        if line.starts_with("<<<<<<<") && section == Section::Both {
+            section = Section::Ours;
+        } else if line.starts_with("|||||||") && section == Section::Ours {
+            section = Section::Base;
+        } else if line.starts_with("=======")
+            && (section == Section::Ours || section == Section::Base)
+        {
+            section = Section::Theirs;
+        } else if line.starts_with(">>>>>>>") && section == Section::Theirs {
+            section = Section::Both;
+        } else {
+            match section {
+                Section::Both => {
+                    ours.push_str(line);
+                    theirs.push_str(line);
+                }
+                Section::Ours => ours.push_str(line),
+                Section::Base => {}
+                Section::Theirs => theirs.push_str(line),
+            }
+        }
+    }
+    (section == Section::Both).then_some((ours, theirs))
+}
+
+fn has_conflict_markers(raw: &str) -> bool {
+    raw.lines().any(|line| line.starts_with("<<<<<<<"))
+}
+
+fn rewrite_relationships(
+    relationships: &mut TaskRelationships,
+    project: &str,
+    old_id: &str,
+    new_id: &str,
+) -> usize {
+    let mut count = 0;
+    let mut rewrite = |value: &mut String| {
+        if qualify_task_ref(value, project).eq_ignore_ascii_case(old_id) {
+            *value = new_id.to_string();
+            count += 1;
+        }
+    };
+    for list in [
+        &mut relationships.depends_on,
+        &mut relationships.blocks,
+        &mut relationships.related,
+        &mut relationships.children,
+        &mut relationships.fixes,
+    ] {
+        list.iter_mut().for_each(&mut rewrite);
+    }
+    for value in [&mut relationships.parent, &mut relationships.duplicate_of]
+        .into_iter()
+        .flatten()
+    {
+        rewrite(value);
+    }
+    count
+}
+
+/// Spell out bare numbers before a task leaves `project`, where they would change meaning.
+fn qualify_relationships(relationships: &mut TaskRelationships, project: &str) {
+    let qualify = |value: &mut String| *value = qualify_task_ref(value, project);
+    for list in [
+        &mut relationships.depends_on,
+        &mut relationships.blocks,
+        &mut relationships.related,
+        &mut relationships.children,
+        &mut relationships.fixes,
+    ] {
+        list.iter_mut().for_each(qualify);
+    }
+    for value in [&mut relationships.parent, &mut relationships.duplicate_of]
+        .into_iter()
+        .flatten()
+    {
+        qualify(value);
+    }
+}
+
+/// Copy the task's attachments into `to_project`'s attachments directory when it differs
+/// from `from_project`'s. Originals stay put: attachments are shared by content hash.
+fn copy_attachments(
+    resolver: &TasksDirectoryResolver,
+    task: &Task,
+    from_project: &str,
+    to_project: &str,
+    dry_run: bool,
+) -> LoTaRResult<Vec<ReferenceRewrite>> {
+    let files: Vec<&str> = task
+        .references
+        .iter()
+        .filter_map(|reference| reference.file.as_deref())
+        .collect();
+    if files.is_empty() {
+        return Ok(Vec::new());
+    }
+    let (Some(from_root), Some(to_root)) = (
+        attachments_root(resolver, from_project),
+        attachments_root(resolver, to_project),
+    ) else {
+        return Ok(Vec::new());
+    };
+    if from_root == to_root {
+        return Ok(Vec::new());
+    }
+
+    let mut copied = Vec::new();
+    for file in files {
+        let Ok(source) = AttachmentService::resolve_attachment_path(&from_root, file) else {
+            continue;
+        };
+        let target = to_root.join(file);
+        if target.exists() {
+            continue;
+        }
+        if !dry_run {
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(&source, &target)?;
+        }
+        copied.push(ReferenceRewrite {
+            target: RewriteTarget::Attachment,
+            path: crate::utils::paths::repo_relative_display(&target),
+            count: 1,
+        });
+    }
+    Ok(copied)
+}
+
+fn attachments_root(resolver: &TasksDirectoryResolver, project: &str) -> Option<PathBuf> {
+    let config = project_config(resolver, project)?;
+    AttachmentService::compute_attachments_root(&resolver.path, &config).ok()
+}
+
+fn project_config(resolver: &TasksDirectoryResolver, project: &str) -> Option<ResolvedConfig> {
+    let base = resolution::load_and_merge_configs(Some(&resolver.path)).ok()?;
+    Some(resolution::get_project_config(&base, project, &resolver.path).unwrap_or(base))
+}
+
+/// Bring status and type in line with `project`'s workflow, the way `create` fills them:
+/// values the project allows are kept (in its spelling), others fall back to its defaults.
+fn fit_to_project(
+    task: &mut Task,
+    resolver: &TasksDirectoryResolver,
+    project: &str,
+) -> Vec<TaskChange> {
+    let Some(config) = project_config(resolver, project) else {
+        return Vec::new();
+    };
+    let mut mapped = Vec::new();
+    let status = TaskStatus::parse_with_config(task.status.as_str(), &config)
+        .ok()
+        .or_else(|| config.effective_default_status());
+    if let Some(status) = status.filter(|status| *status != task.status) {
+        if !status.eq_ignore_case(task.status.as_str()) {
+            mapped.push(TaskChange {
+                field: "status".into(),
+                old: Some(task.status.to_string()),
+                new: Some(status.to_string()),
+            });
+        }
+        task.status = status;
+    }
+    let task_type = TaskType::parse_with_config(task.task_type.as_str(), &config)
+        .ok()
+        .or_else(|| config.effective_default_task_type());
+    if let Some(task_type) = task_type.filter(|task_type| *task_type != task.task_type) {
+        if !task_type.eq_ignore_case(task.task_type.as_str()) {
+            mapped.push(TaskChange {
+                field: "type".into(),
+                old: Some(task.task_type.to_string()),
+                new: Some(task_type.to_string()),
+            });
+        }
+        task.task_type = task_type;
+    }
+    mapped
+}
+
+fn record_mapped_fields(task: &mut Task, mapped: &[TaskChange], tasks_root: &Path) {
+    if mapped.is_empty() {
+        return;
+    }
+    task.history.push(TaskChangeLogEntry {
+        at: chrono::Utc::now().to_rfc3339(),
+        actor: resolve_current_user(Some(tasks_root)),
+        changes: mapped.to_vec(),
+    });
+}
+
+/// Bare numbers in relationships refer to a task in the same project.
+fn qualify_task_ref(value: &str, project: &str) -> String {
+    let trimmed = value.trim();
+    if !trimmed.is_empty() && trimmed.bytes().all(|b| b.is_ascii_digit()) {
+        format!("{}-{}", project, trimmed.trim_start_matches('0'))
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Source files (as a scan would read them) that mention `id` as a whole word.
+fn source_mentions(
+    resolver: &TasksDirectoryResolver,
+    id: &str,
+) -> LoTaRResult<Vec<(PathBuf, usize, Regex)>> {
+    let Some(root) = resolver.path.parent() else {
+        return Ok(Vec::new());
+    };
+    let project = id.split('-').next();
+    let scanner = resolve_scan_config(resolver, project).scanner(root.to_path_buf());
+    let pattern = Regex::new(&format!(r"\b{}\b", regex::escape(id)))
+        .map_err(|e| LoTaRError::ValidationError(e.to_string()))?;
+
+    let mut mentions = Vec::new();
+    for path in scanner.candidate_files() {
+        if path.starts_with(&resolver.path) {
+            continue;
+        }
+        let Ok(text) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let count = pattern.find_iter(&text).count();
+        if count > 0 {
+            mentions.push((path, count, pattern.clone()));
+        }
+    }
+    Ok(mentions)
+}
+
+fn record_id_change(task: &mut Task, old_id: &str, new_id: &str, tasks_root: &Path) {
+    let now = chrono::Utc::now().to_rfc3339();
+    task.history.push(TaskChangeLogEntry {
+        at: now.clone(),
+        actor: resolve_current_user(Some(tasks_root)),
+        changes: vec![TaskChange {
+            field: "id".into(),
+            old: Some(old_id.to_string()),
+            new: Some(new_id.to_string()),
+        }],
+    });
+    task.modified = now;
+}
+
+fn write_task_file(path: &Path, task: &Task, modeline: Option<&str>) -> LoTaRResult<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let yaml = serde_yaml::to_string(task)?;
+    match modeline {
+        Some(line) => fs::write(path, format!("{}\n{}", line, yaml))?,
+        None => fs::write(path, yaml)?,
+    }
+    Ok(())
+}
+
+fn modeline(raw: &str) -> Option<&str> {
+    raw.lines()
+        .next()
+        .filter(|line| line.starts_with(YAML_MODELINE_PREFIX))
+}
+
+fn project_dirs(tasks_root: &Path) -> LoTaRResult<Vec<PathBuf>> {
+    let mut dirs = Vec::new();
+    if !tasks_root.is_dir() {
+        return Ok(dirs);
+    }
+    for entry in fs::read_dir(tasks_root)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        if path.is_dir() && !name.starts_with('@') && !name.starts_with('.') {
+            dirs.push(path);
+        }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
+
+fn split_task_id(id: &str) -> LoTaRResult<(String, u64)> {
+    let trimmed = id.trim().to_ascii_uppercase();
+    match trimmed.split_once('-') {
+        Some((project, number)) if !project.is_empty() => number
+            .parse::<u64>()
+            .map(|number| (project.to_string(), number))
+            .map_err(|_| LoTaRError::InvalidTaskId(id.to_string())),
+        _ => Err(LoTaRError::InvalidTaskId(id.to_string())),
+    }
+}
+
+fn task_file_display(task_id: &str) -> String {
+    match split_task_id(task_id) {
+        Ok((project, number)) => format!("{}/{}.yml", project, number),
+        Err(_) => task_id.to_string(),
+    }
+}
+
+fn relative_to(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_conflict_rebuilds_both_sides_of_partial_hunks() {
+        let raw = "title: Shared\n<<<<<<< HEAD\ncreated: 2024-01-01T00:00:00Z\n||||||| base\n=======\ncreated: 2024-02-01T00:00:00Z\n>>>>>>> feature\nstatus: Todo\n";
+        let (ours, theirs) = split_conflict(raw).unwrap();
+        assert_eq!(
+            ours,
+            "title: Shared\ncreated: 2024-01-01T00:00:00Z\nstatus: Todo\n"
+        );
+        assert_eq!(
+            theirs,
+            "title: Shared\ncreated: 2024-02-01T00:00:00Z\nstatus: Todo\n"
+        );
+        assert!(split_collision(raw).is_some());
+    }
+
+    #[test]
+    fn same_task_edit_conflicts_are_not_collisions() {
+        let raw = "title: One\ncreated: 2024-01-01T00:00:00Z\n<<<<<<< HEAD\nstatus: Done\n=======\nstatus: Todo\n>>>>>>> feature\n";
+        assert!(split_collision(raw).is_none());
+    }
+
+    #[test]
+    fn relationships_match_bare_and_qualified_references() {
+        let mut relationships = TaskRelationships {
+            depends_on: vec!["7".into(), "AUTH-70".into()],
+            parent: Some("AUTH-7".into()),
+            ..TaskRelationships::default()
+        };
+        assert_eq!(
+            rewrite_relationships(&mut relationships, "AUTH", "AUTH-7", "AUTH-12"),
+            2
+        );
+        assert_eq!(relationships.depends_on, vec!["AUTH-12", "AUTH-70"]);
+        assert_eq!(relationships.parent.as_deref(), Some("AUTH-12"));
+    }
+}
@@ -1,14 +1,17 @@
 use crate::api_types::{TaskCreate, TaskDTO, TaskListFilter, TaskUpdate};
 use crate::config::types::{GlobalConfig, ResolvedConfig};
 use crate::errors::{LoTaRError, LoTaRResult};
+use crate::services::agent_approval_service::{AgentApprovalService, AgentOperation, ApprovalGate};
 use crate::services::agent_job_service::AgentJobService;
 use crate::services::automation_service::AutomationService;
 use crate::services::sprint_service::{SprintRecord, SprintService};
 use crate::storage::manager::Storage;
 use crate::storage::sprint::SprintTaskEntry;
 use crate::storage::task::Task;
+use crate::storage::warnings::TaskParseWarning;
 use crate::types::{Priority, TaskChange, TaskChangeLogEntry, TaskStatus, TaskType};
 use crate::utils::identity::{resolve_current_user, resolve_me_alias};
+use crate::utils::member::MemberDirectory;
 use crate::utils::project::generate_project_prefix;
 use crate::utils::tags::normalize_tags;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
@@ -26,6 +29,9 @@ pub struct TaskUpdateContext {
     pub enforce_review_owner: bool,
     /// Internal automation updates can emit API task events for live UI refresh.
     pub emit_api_event: bool,
+    /// Check agent callers against `agent.approvals`; off for automation and for
+    /// applying an approval that was already granted.
+    pub enforce_agent_approvals: bool,
 }
 
 impl TaskUpdateContext {
@@ -35,6 +41,7 @@ impl TaskUpdateContext {
             bypass_active_job_lock: true,
             enforce_review_owner: false,
             emit_api_event: true,
+            enforce_agent_approvals: false,
         }
     }
 }
@@ -46,6 +53,7 @@ impl Default for TaskUpdateContext {
             bypass_active_job_lock: false,
             enforce_review_owner: true,
             emit_api_event: false,
+            enforce_agent_approvals: true,
         }
     }
 }
@@ -175,6 +183,7 @@ impl TaskService {
         });
         t.description = description;
         let mut normalized_tags = normalize_tags(tags);
+        let explicit_tags = !normalized_tags.is_empty();
         if normalized_tags.is_empty() {
             if !config.default_tags.is_empty() {
                 normalized_tags.extend(config.default_tags.clone());
@@ -196,6 +205,9 @@ impl TaskService {
         }
 
         Self::ensure_task_defaults(&mut t, &config);
+        crate::utils::issue_templates::apply_issue_template(&mut t, &config, explicit_tags);
+        t.tags = normalize_tags(std::mem::take(&mut t.tags));
+        crate::utils::issue_templates::enforce_required_fields(&t, &config)?;
         config =
             Self::maybe_auto_populate_members(storage.root_path.as_path(), &project, &t, config)?;
         Self::enforce_membership(&t, &config, &project)?;
@@ -218,23 +230,44 @@ impl TaskService {
         // If project not provided, derive prefix from ID (e.g., ABCD-1 -> ABCD)
         let derived = id.split('-').next().unwrap_or("");
         let p = project.unwrap_or(derived).to_string();
-        match storage.get(id, &p) {
-            Some(mut t) => {
-                let config = Self::resolve_config_for_project(storage.root_path.as_path(), &p);
+        match storage.get_checked(id, &p) {
+            Ok(Some(mut t)) => {
+                // A moved task is reported under its new ID and configured by its new project
+                let current_id = storage.resolve_id(id);
+                let current_project = if current_id == id {
+                    p.as_str()
+                } else {
+                    current_id.split('-').next().unwrap_or(&p)
+                };
+                let config =
+                    Self::resolve_config_for_project(storage.root_path.as_path(), current_project);
                 Self::ensure_task_defaults(&mut t, &config);
                 let sprint_lookup = Self::load_sprint_lookup(storage);
-                Ok(Self::to_dto(id, t, Some(&sprint_lookup)))
+                Ok(Self::to_dto(&current_id, t, Some(&sprint_lookup)))
             }
-            None => Err(LoTaRError::TaskNotFound(id.to_string())),
+            Ok(None) => Err(LoTaRError::TaskNotFound(id.to_string())),
+            Err(warning) => Err(LoTaRError::SerializationError(warning.to_string())),
         }
     }
 
     /// Add a comment to a task and fire `on.commented` automation rules.
     pub fn add_comment(storage: &mut Storage, id: &str, text: &str) -> LoTaRResult<TaskDTO> {
+        Self::add_comment_with_approvals(storage, id, text, true)
+    }
+
+    pub(crate) fn add_comment_with_approvals(
+        storage: &mut Storage,
+        id: &str,
+        text: &str,
+        enforce_agent_approvals: bool,
+    ) -> LoTaRResult<TaskDTO> {
         let derived = id.split('-').next().unwrap_or("");
         let mut task = storage
             .get(id, derived)
             .ok_or_else(|| LoTaRError::TaskNotFound(id.to_string()))?;
+        if enforce_agent_approvals {
+            Self::check_agent_approvals(storage, id, &AgentOperation::Comment(text.to_string()))?;
+        }
 
         let now = chrono::Utc::now().to_rfc3339();
         let actor = resolve_current_user(Some(storage.root_path.as_path()));
@@ -280,6 +313,13 @@ impl TaskService {
         if context.enforce_review_owner {
             Self::enforce_review_owner_transition(storage, id, &existing, &patch)?;
         }
+        if context.enforce_agent_approvals {
+            Self::check_agent_approvals(
+                storage,
+                id,
+                &AgentOperation::Update(Box::new(patch.clone())),
+            )?;
+        }
 
         let current_job_can_mutate = Self::current_job_matches_ticket(id);
 
@@ -596,12 +636,47 @@ impl TaskService {
     }
 
     pub fn delete(storage: &mut Storage, id: &str, project: Option<&str>) -> LoTaRResult<bool> {
+        Self::delete_with_approvals(storage, id, project, true)
+    }
+
+    pub(crate) fn delete_with_approvals(
+        storage: &mut Storage,
+        id: &str,
+        project: Option<&str>,
+        enforce_agent_approvals: bool,
+    ) -> LoTaRResult<bool> {
         let derived = id.split('-').next().unwrap_or("");
         let p = project.unwrap_or(derived);
+        if enforce_agent_approvals && storage.get(id, p).is_some() {
+            Self::check_agent_approvals(storage, id, &AgentOperation::Delete)?;
+        }
         storage.delete(id, p)
     }
 
+    /// Agent jobs (and the tools they spawn) are held to `agent.approvals` whichever way
+    /// they reach the service: CLI, REST or MCP. Human callers pass straight through.
+    fn check_agent_approvals(
+        storage: &Storage,
+        id: &str,
+        operation: &AgentOperation,
+    ) -> LoTaRResult<()> {
+        let caller = AgentApprovalService::current_caller();
+        match AgentApprovalService::gate(&storage.root_path, id, operation, caller.as_ref())? {
+            ApprovalGate::Allow => Ok(()),
+            ApprovalGate::Parked(approval) => Err(LoTaRError::ApprovalPending(approval)),
+            ApprovalGate::Denied(message) => Err(LoTaRError::PermissionDenied(message)),
+        }
+    }
+
     pub fn list(storage: &Storage, filter: &TaskListFilter) -> Vec<(String, TaskDTO)> {
+        Self::list_with_warnings(storage, filter).0
+    }
+
+    /// Like `list`, but also returns the task files that could not be read or parsed
+    pub fn list_with_warnings(
+        storage: &Storage,
+        filter: &TaskListFilter,
+    ) -> (Vec<(String, TaskDTO)>, Vec<TaskParseWarning>) {
         // Map API filter to storage filter
         let storage_filter = crate::storage::TaskFilter {
             status: filter.status.clone(),
@@ -619,8 +694,8 @@ impl TaskService {
         let sprint_lookup = Self::load_sprint_lookup(storage);
         let requested_sprints: HashSet<u32> = filter.sprints.iter().copied().collect();
 
-        storage
-            .search(&storage_filter)
+        let (found, warnings) = storage.search_with_warnings(&storage_filter);
+        let tasks = found
             .into_iter()
             .filter(|(id, _)| {
                 if requested_sprints.is_empty() {
@@ -648,7 +723,8 @@ impl TaskService {
                 Self::ensure_task_defaults(&mut t, config);
                 (id.clone(), Self::to_dto(&id, t, Some(&sprint_lookup)))
             })
-            .collect()
+            .collect();
+        (tasks, warnings)
     }
 
     fn to_dto(
@@ -681,6 +757,7 @@ impl TaskService {
             subtitle: task.subtitle,
             description: task.description,
             tags: task.tags,
+            acceptance_criteria: task.acceptance_criteria,
             relationships: task.relationships,
             comments: task.comments,
             references: task.references,
@@ -844,8 +921,11 @@ impl TaskService {
 
         let mut missing: Vec<String> = Vec::new();
         let mut seen: HashSet<String> = HashSet::new();
+        let directory = MemberDirectory::from_config(config);
 
         for candidate in candidates {
+            // Aliases of a known profile are recorded under its handle.
+            let candidate = directory.canonical(&candidate);
             let lower = candidate.to_ascii_lowercase();
             if !seen.insert(lower.clone()) {
                 continue;
@@ -853,7 +933,7 @@ impl TaskService {
             let already_present = config
                 .members
                 .iter()
-                .any(|existing| existing.eq_ignore_ascii_case(&candidate));
+                .any(|existing| directory.same_member(existing, &candidate));
             if !already_present {
                 missing.push(candidate);
             }
@@ -912,8 +992,19 @@ impl TaskService {
             default_type.ensure_leading_uppercase();
             task.task_type = default_type;
         }
-        if task.tags.is_empty() && !config.default_tags.is_empty() {
+        if task.tags.is_empty() {
             task.tags = config.default_tags.clone();
+            if let Some(template) = config.issue_template_for(&task.task_type) {
+                for tag in &template.tags {
+                    if !task
+                        .tags
+                        .iter()
+                        .any(|existing| existing.eq_ignore_ascii_case(tag))
+                    {
+                        task.tags.push(tag.clone());
+                    }
+                }
+            }
         }
         // Normalize legacy @-prefixed member values for display consistency.
         let is_agent = |name: &str| config.agent_profiles.contains_key(name);
@@ -954,12 +1045,13 @@ impl TaskService {
             )));
         }
 
+        let directory = MemberDirectory::from_config(config);
         if let Some(reporter) = task.reporter.as_deref() {
-            Self::enforce_member_value("Reporter", reporter, &allowed, project)?;
+            Self::enforce_member_value("Reporter", reporter, &allowed, &directory, project)?;
         }
 
         if let Some(assignee) = task.assignee.as_deref() {
-            Self::enforce_member_value("Assignee", assignee, &allowed, project)?;
+            Self::enforce_member_value("Assignee", assignee, &allowed, &directory, project)?;
         }
 
         Ok(())
@@ -969,6 +1061,7 @@ impl TaskService {
         field_label: &str,
         value: &str,
         allowed: &[String],
+        directory: &MemberDirectory,
         project: &str,
     ) -> LoTaRResult<()> {
         let trimmed = value.trim();
@@ -976,10 +1069,10 @@ impl TaskService {
             return Ok(());
         }
 
-        let norm_val = crate::utils::member::member_for_comparison(trimmed);
+        let norm_val = directory.comparison_key(trimmed);
         let permitted = allowed
             .iter()
-            .any(|candidate| crate::utils::member::member_for_comparison(candidate) == norm_val);
+            .any(|candidate| directory.comparison_key(candidate) == norm_val);
 
         if permitted {
             return Ok(());
@@ -1,6 +1,7 @@
 use std::path::Path;
 
 use super::task::Task;
+use super::warnings::TaskParseWarning;
 
 pub trait StorageBackend {
     fn add(
@@ -11,6 +12,15 @@ pub trait StorageBackend {
         original_project: Option<&str>,
     ) -> Result<String, Box<dyn std::error::Error>>;
     fn get(&self, root: &Path, id: &str, project: &str) -> Option<Task>;
+    /// Like `get`, but reports a task file that exists and cannot be parsed
+    fn get_checked(
+        &self,
+        root: &Path,
+        id: &str,
+        project: &str,
+    ) -> Result<Option<Task>, TaskParseWarning> {
+        Ok(self.get(root, id, project))
+    }
     fn edit(&self, root: &Path, id: &str, task: &Task) -> Result<(), Box<dyn std::error::Error>>;
     fn delete(
         &self,
@@ -37,6 +47,15 @@ impl StorageBackend for FsBackend {
         super::operations::StorageOperations::get(root, id, project)
     }
 
+    fn get_checked(
+        &self,
+        root: &Path,
+        id: &str,
+        project: &str,
+    ) -> Result<Option<Task>, TaskParseWarning> {
+        super::operations::StorageOperations::get_checked(root, id, project)
+    }
+
     fn edit(&self, root: &Path, id: &str, task: &Task) -> Result<(), Box<dyn std::error::Error>> {
         super::operations::StorageOperations::edit(root, id, task)
     }
@@ -1,5 +1,6 @@
 use crate::errors::{LoTaRError, LoTaRResult};
 use crate::storage::TaskFilter;
+use crate::storage::TaskParseWarning;
 use crate::storage::backend::{FsBackend, StorageBackend};
 use crate::storage::locator::StorageLocator;
 use crate::storage::search::StorageSearch;
@@ -69,6 +70,16 @@ impl Storage {
         self.backend.get(&self.root_path, id, project)
     }
 
+    /// Like `get`, but reports a task file that exists and cannot be parsed
+    pub fn get_checked(&self, id: &str, project: &str) -> Result<Option<Task>, TaskParseWarning> {
+        self.backend.get_checked(&self.root_path, id, project)
+    }
+
+    /// ID a task lives under now, following redirect stubs left by `lotar task move`
+    pub fn resolve_id(&self, id: &str) -> String {
+        crate::storage::operations::StorageOperations::resolve_id(&self.root_path, id)
+    }
+
     pub fn find_task_by_numeric_id(&self, numeric_id: &str) -> Option<(String, Task)> {
         if !numeric_id.chars().all(|c| c.is_ascii_digit()) {
             return None;
@@ -154,6 +165,14 @@ impl Storage {
     pub fn search(&self, filter: &TaskFilter) -> Vec<(String, Task)> {
         StorageSearch::search(&self.root_path, filter)
     }
+
+    /// Like `search`, but also returns the task files that could not be read or parsed
+    pub fn search_with_warnings(
+        &self,
+        filter: &TaskFilter,
+    ) -> (Vec<(String, Task)>, Vec<TaskParseWarning>) {
+        StorageSearch::search_with_warnings(&self.root_path, filter)
+    }
 }
 
 fn map_storage_error(err: Box<dyn std::error::Error>) -> LoTaRError {
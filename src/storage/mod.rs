@@ -6,16 +6,22 @@
 // - search: Search and filtering functionality
 // - manager: High-level storage coordination and project management
 // - filter: Task filtering utilities
+// - redirect: Stubs left at a task's old ID after a move
 // - sprint: Sprint entity definition and helpers
+// - warnings: Parse failures reported alongside search results
 
 pub mod backend;
 pub mod filter;
 pub mod locator;
 pub mod manager;
 pub mod operations;
+pub mod redirect;
 pub mod search;
 pub mod sprint;
 pub mod task;
+pub mod warnings;
 
 // Re-export commonly used types and operations for convenience
 pub use filter::TaskFilter;
+pub use redirect::TaskRedirect;
+pub use warnings::TaskParseWarning;
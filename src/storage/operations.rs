@@ -1,6 +1,8 @@
 use crate::config::{ConfigManager, types::ProjectConfig};
 use crate::output::{LogLevel, OutputFormat, OutputRenderer};
+use crate::storage::redirect::{MAX_REDIRECT_HOPS, TaskRedirect};
 use crate::storage::task::Task;
+use crate::storage::warnings::{TaskFile, TaskParseWarning, read_task_file};
 #[cfg(test)]
 use crate::utils::project::generate_project_prefix;
 use std::fs;
@@ -95,46 +97,85 @@ impl StorageOperations {
 
     /// Get a task by ID
     pub fn get(root_path: &Path, id: &str, project: &str) -> Option<Task> {
+        Self::get_checked(root_path, id, project).ok().flatten()
+    }
+
+    /// Get a task by ID, reporting a task file that exists but cannot be parsed
+    pub fn get_checked(
+        root_path: &Path,
+        id: &str,
+        project: &str,
+    ) -> Result<Option<Task>, TaskParseWarning> {
+        let project_name: &str = if project.trim().is_empty() {
+            "default"
+        } else {
+            project
+        };
+
         // Extract project folder from the task ID if provided
         if let Some(folder_from_id) = Self::get_project_for_task(id) {
             // SECURITY: Enforce project isolation - verify the project folder from ID matches the provided project
-            let project_name: &str = if project.trim().is_empty() {
-                "default"
-            } else {
-                project
-            };
-
             // If the project folder extracted from ID doesn't match the provided project, deny access
             if folder_from_id != project_name {
-                return None;
+                return Ok(None);
             }
 
             // Use filesystem-based file path resolution
             let project_path = root_path.join(&folder_from_id);
-            if let Some(file_path) = Self::get_file_path_for_id(&project_path, id)
-                && let Ok(file_string) = fs::read_to_string(&file_path)
-                && let Ok(task) = serde_yaml::from_str::<Task>(&file_string)
-            {
-                return Some(task);
+            if let Some(file_path) = Self::get_file_path_for_id(&project_path, id) {
+                return Self::load_following_redirects(root_path, id, file_path);
             }
         }
 
         // Fallback: try the provided project name (for backward compatibility)
-        let project_name: &str = if project.trim().is_empty() {
-            "default"
-        } else {
-            project
-        };
-
         let project_path = root_path.join(project_name);
-        if let Some(file_path) = Self::get_file_path_for_id(&project_path, id)
-            && let Ok(file_string) = fs::read_to_string(&file_path)
-            && let Ok(task) = serde_yaml::from_str::<Task>(&file_string)
-        {
-            return Some(task);
+        match Self::get_file_path_for_id(&project_path, id) {
+            Some(file_path) => Self::load_following_redirects(root_path, id, file_path),
+            None => Ok(None),
         }
+    }
 
-        None
+    /// Load a task file; when it is a redirect stub, load the task it points at instead.
+    fn load_following_redirects(
+        root_path: &Path,
+        id: &str,
+        file_path: PathBuf,
+    ) -> Result<Option<Task>, TaskParseWarning> {
+        let (mut current, mut path) = (id.to_string(), file_path);
+        for _ in 0..MAX_REDIRECT_HOPS {
+            match read_task_file(root_path, &current, &path)? {
+                TaskFile::Task(task) => return Ok(Some(*task)),
+                TaskFile::Redirect(redirect) => {
+                    current = redirect.moved_to;
+                    let Some(folder) = Self::get_project_for_task(&current) else {
+                        return Ok(None);
+                    };
+                    match Self::get_file_path_for_id(&root_path.join(folder), &current) {
+                        Some(next) => path = next,
+                        None => return Ok(None),
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Follow redirect stubs from `id` to the ID the task lives under now.
+    /// Returns `id` unchanged when it is not a stub (or does not exist).
+    pub fn resolve_id(root_path: &Path, id: &str) -> String {
+        let mut current = id.to_string();
+        for _ in 0..MAX_REDIRECT_HOPS {
+            let path = Self::get_project_for_task(&current)
+                .and_then(|folder| Self::get_file_path_for_id(&root_path.join(folder), &current));
+            let Some(redirect) = path
+                .and_then(|path| fs::read_to_string(path).ok())
+                .and_then(|content| TaskRedirect::parse(&content))
+            else {
+                break;
+            };
+            current = redirect.moved_to;
+        }
+        current
     }
 
     /// Edit an existing task
@@ -143,6 +184,10 @@ impl StorageOperations {
         id: &str,
         new_task: &Task,
     ) -> Result<(), Box<dyn std::error::Error>> {
+        // Writes through an old ID land in the moved task, not over its redirect stub
+        let resolved = Self::resolve_id(root_path, id);
+        let id = resolved.as_str();
+
         // Extract the project folder from the task ID
         let project_folder = match Self::get_project_for_task(id) {
             Some(folder) => folder,
@@ -162,7 +207,7 @@ impl StorageOperations {
 
         // Save the task
         let file_string = serde_yaml::to_string(new_task)?;
-        fs::write(&file_path, file_string)?;
+        crate::utils::filesystem::write_yaml_preserving_modeline(&file_path, &file_string)?;
 
         // No longer need to update index - simplified architecture
 
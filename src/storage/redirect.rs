@@ -0,0 +1,34 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Redirects followed before a lookup gives up (guards against `A → B → A` loops).
+pub const MAX_REDIRECT_HOPS: usize = 8;
+
+/// Stub left at a task's old path by `lotar task move`.
+///
+/// The stub keeps the old number allocated and lets `Storage::get` resolve the old ID to the
+/// task's new home. Searches skip stubs, so a moved task is listed once under its new ID.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct TaskRedirect {
+    /// ID the task was moved to, e.g. `OPS-12`
+    pub moved_to: String,
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub moved_at: String,
+}
+
+impl TaskRedirect {
+    pub fn new(moved_to: &str) -> Self {
+        Self {
+            moved_to: moved_to.to_string(),
+            moved_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Parse file content as a redirect stub; `None` for anything else (including tasks).
+    pub fn parse(content: &str) -> Option<Self> {
+        serde_yaml::from_str(content).ok()
+    }
+}
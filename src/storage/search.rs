@@ -1,10 +1,10 @@
 use crate::storage::TaskFilter;
 use crate::storage::locator::StorageLocator;
 use crate::storage::task::Task;
+use crate::storage::warnings::{TaskFile, TaskParseWarning, read_task_file};
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
-use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Search and filtering functionality for task storage
 pub struct StorageSearch;
@@ -12,26 +12,22 @@ pub struct StorageSearch;
 impl StorageSearch {
     /// Search for tasks based on filter criteria
     pub fn search(root_path: &Path, filter: &TaskFilter) -> Vec<(String, Task)> {
-        let mut results: Vec<(String, Task)> = Vec::new();
-        {
-            use std::fs::OpenOptions;
-            use std::io::Write;
-            if std::env::var("LOTAR_DEBUG").is_ok()
-                && let Ok(mut f) = OpenOptions::new()
-                    .create(true)
-                    .append(true)
-                    .open("/tmp/lotar_search_debug.log")
-            {
-                let _ = writeln!(
-                    f,
-                    "[SEARCH] root={} project={:?}",
-                    root_path.display(),
-                    filter.project
-                );
-            }
-        }
+        Self::search_with_warnings(root_path, filter).0
+    }
 
-        // No longer use index for tag pre-filtering - do all filtering during file scan
+    /// Search for tasks, also returning the task files that could not be read or parsed
+    pub fn search_with_warnings(
+        root_path: &Path,
+        filter: &TaskFilter,
+    ) -> (Vec<(String, Task)>, Vec<TaskParseWarning>) {
+        Self::debug_log(format_args!(
+            "[SEARCH] root={} project={:?}",
+            root_path.display(),
+            filter.project
+        ));
+
+        // (tasks root, project folder, task file)
+        let mut candidates: Vec<(PathBuf, String, PathBuf)> = Vec::new();
 
         // If we have a specific project filter, search only that project
         if let Some(project) = &filter.project {
@@ -40,165 +36,83 @@ impl StorageSearch {
                     StorageLocator::project_folders_for_name(&candidate_root, project);
                 for project_folder in project_folders {
                     let project_path = candidate_root.join(&project_folder);
-                    let files = crate::utils::filesystem::list_files_with_ext(&project_path, "yml");
-
-                    #[cfg(feature = "parallel")]
-                    let mut partial: Vec<(String, Task)> = files
-                        .par_iter()
-                        .filter_map(|path| {
-                            let numeric_id = crate::utils::filesystem::file_numeric_stem(path)?;
-                            let task_id = format!("{}-{}", project_folder, numeric_id);
-                            let content = fs::read_to_string(path).ok()?;
-                            let task: Task = serde_yaml::from_str(&content).ok()?;
-                            if Self::task_matches_filter(&task_id, &task, filter) {
-                                Some((task_id, task))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect();
-
-                    #[cfg(not(feature = "parallel"))]
-                    let mut partial: Vec<(String, Task)> = files
-                        .iter()
-                        .filter_map(|path| {
-                            let numeric_id = crate::utils::filesystem::file_numeric_stem(path)?;
-                            let task_id = format!("{}-{}", project_folder, numeric_id);
-                            let content = fs::read_to_string(path).ok()?;
-                            let task: Task = serde_yaml::from_str(&content).ok()?;
-                            if Self::task_matches_filter(&task_id, &task, filter) {
-                                Some((task_id, task))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect();
-
-                    results.append(&mut partial);
+                    for path in crate::utils::filesystem::list_files_with_ext(&project_path, "yml")
+                    {
+                        candidates.push((candidate_root.clone(), project_folder.clone(), path));
+                    }
                 }
             }
         } else {
             // Search across all projects
             let subdirs = crate::utils::filesystem::list_visible_subdirs(root_path);
-            {
-                use std::fs::OpenOptions;
-                use std::io::Write;
-                if std::env::var("LOTAR_DEBUG").is_ok()
-                    && let Ok(mut f) = OpenOptions::new()
-                        .create(true)
-                        .append(true)
-                        .open("/tmp/lotar_search_debug.log")
-                {
-                    let names: Vec<String> = subdirs.iter().map(|(n, _)| n.clone()).collect();
-                    let _ = writeln!(f, "[SUBDIRS] {:?}", names);
+            Self::debug_log(format_args!(
+                "[SUBDIRS] {:?}",
+                subdirs.iter().map(|(n, _)| n.as_str()).collect::<Vec<_>>()
+            ));
+            for (project_folder, dir_path) in subdirs {
+                let files = crate::utils::filesystem::list_files_with_ext(&dir_path, "yml");
+                Self::debug_log(format_args!(
+                    "[FILES] project={} count={} dir={}",
+                    project_folder,
+                    files.len(),
+                    dir_path.display()
+                ));
+                for path in files {
+                    candidates.push((root_path.to_path_buf(), project_folder.clone(), path));
                 }
             }
-            let all_files: Vec<(String, std::path::PathBuf)> = subdirs
-                .into_iter()
-                .flat_map(|(project_folder, dir_path)| {
-                    let files = crate::utils::filesystem::list_files_with_ext(&dir_path, "yml");
-                    {
-                        use std::fs::OpenOptions;
-                        use std::io::Write;
-                        if std::env::var("LOTAR_DEBUG").is_ok()
-                            && let Ok(mut f) = OpenOptions::new()
-                                .create(true)
-                                .append(true)
-                                .open("/tmp/lotar_search_debug.log")
-                        {
-                            let _ = writeln!(
-                                f,
-                                "[FILES] project={} count={} dir={}",
-                                project_folder,
-                                files.len(),
-                                dir_path.display()
-                            );
-                        }
-                    }
-                    files.into_iter().map(move |p| (project_folder.clone(), p))
-                })
-                .collect();
+        }
 
-            #[cfg(feature = "parallel")]
-            {
-                results = all_files
-                    .par_iter()
-                    .filter_map(|(project_folder, task_path)| {
-                        let numeric_id = crate::utils::filesystem::file_numeric_stem(task_path)?;
-                        let task_id = format!("{}-{}", project_folder, numeric_id);
-                        let content = fs::read_to_string(task_path).ok()?;
-                        let task: Task = serde_yaml::from_str(&content).ok()?;
-                        if Self::task_matches_filter(&task_id, &task, filter) {
-                            Some((task_id, task))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-            }
-            #[cfg(not(feature = "parallel"))]
-            {
-                results = all_files
-                    .iter()
-                    .filter_map(|(project_folder, task_path)| {
-                        let numeric_id =
-                            match crate::utils::filesystem::file_numeric_stem(task_path) {
-                                Some(n) => n,
-                                None => return None,
-                            };
-                        let task_id = format!("{}-{}", project_folder, numeric_id);
-                        let content = match fs::read_to_string(task_path) {
-                            Ok(c) => c,
-                            Err(_) => return None,
-                        };
-                        match serde_yaml::from_str::<Task>(&content) {
-                            Ok(task) => {
-                                if Self::task_matches_filter(&task_id, &task, filter) {
-                                    Some((task_id, task))
-                                } else {
-                                    None
-                                }
-                            }
-                            Err(e) => {
-                                use std::fs::OpenOptions;
-                                use std::io::Write;
-                                if std::env::var("LOTAR_DEBUG").is_ok() {
-                                    if let Ok(mut f) = OpenOptions::new()
-                                        .create(true)
-                                        .append(true)
-                                        .open("/tmp/lotar_search_debug.log")
-                                    {
-                                        let _ = writeln!(
-                                            f,
-                                            "[PARSE_ERR] file={} err={}",
-                                            task_path.display(),
-                                            e
-                                        );
-                                    }
-                                }
-                                None
-                            }
-                        }
-                    })
-                    .collect();
-                {
-                    use std::fs::OpenOptions;
-                    use std::io::Write;
-                    if std::env::var("LOTAR_DEBUG").is_ok() {
-                        if let Ok(mut f) = OpenOptions::new()
-                            .create(true)
-                            .append(true)
-                            .open("/tmp/lotar_search_debug.log")
-                        {
-                            let _ = writeln!(f, "[RESULTS] {}", results.len());
-                        }
-                    }
+        let load = |(root, project_folder, path): &(PathBuf, String, PathBuf)| {
+            let numeric_id = crate::utils::filesystem::file_numeric_stem(path)?;
+            let task_id = format!("{}-{}", project_folder, numeric_id);
+            match read_task_file(root, &task_id, path) {
+                Ok(TaskFile::Task(task)) if Self::task_matches_filter(&task_id, &task, filter) => {
+                    Some(Ok((task_id, *task)))
+                }
+                // Moved tasks are listed under their new ID
+                Ok(_) => None,
+                Err(warning) => {
+                    Self::debug_log(format_args!("[PARSE_ERR] {}", warning));
+                    Some(Err(warning))
                 }
             }
+        };
+
+        #[cfg(feature = "parallel")]
+        let loaded: Vec<Result<(String, Task), TaskParseWarning>> =
+            candidates.par_iter().filter_map(load).collect();
+        #[cfg(not(feature = "parallel"))]
+        let loaded: Vec<Result<(String, Task), TaskParseWarning>> =
+            candidates.iter().filter_map(load).collect();
+
+        let mut results = Vec::new();
+        let mut warnings = Vec::new();
+        for entry in loaded {
+            match entry {
+                Ok(found) => results.push(found),
+                Err(warning) => warnings.push(warning),
+            }
         }
+        Self::debug_log(format_args!("[RESULTS] {}", results.len()));
+
         // Deterministic order
         results.sort_by(|a, b| a.0.cmp(&b.0));
-        results
+        warnings.sort_by(|a, b| a.file.cmp(&b.file));
+        (results, warnings)
+    }
+
+    fn debug_log(message: std::fmt::Arguments<'_>) {
+        use std::fs::OpenOptions;
+        use std::io::Write;
+        if std::env::var("LOTAR_DEBUG").is_ok()
+            && let Ok(mut f) = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open("/tmp/lotar_search_debug.log")
+        {
+            let _ = writeln!(f, "{}", message);
+        }
     }
 
     /// Helper method to check if a task matches all filter criteria
@@ -1,7 +1,7 @@
 use serde::de::{self, Deserializer, IgnoredAny, MapAccess, Visitor};
 use serde::ser::{SerializeMap, Serializer};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -154,12 +154,35 @@ impl Sprint {
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
-#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct SprintTaskEntry {
     pub id: String,
     pub order: Option<u32>,
 }
 
+// Entries are written as a bare task id unless they carry an order.
+#[cfg(feature = "schema")]
+impl JsonSchema for SprintTaskEntry {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "SprintTaskEntry".into()
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "anyOf": [
+                { "type": "string" },
+                {
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "string" },
+                        "order": { "type": "integer", "format": "uint32", "minimum": 0 }
+                    },
+                    "required": ["id"]
+                }
+            ]
+        })
+    }
+}
+
 impl Serialize for SprintTaskEntry {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -330,6 +353,9 @@ pub struct SprintCapacity {
     pub points: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub hours: Option<u32>,
+    /// Per-member availability for this sprint, keyed by member name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub members: BTreeMap<String, SprintMemberCapacity>,
 }
 
 impl SprintCapacity {
@@ -340,10 +366,66 @@ impl SprintCapacity {
         if self.hours == Some(0) {
             self.hours = None;
         }
+        let members = std::mem::take(&mut self.members);
+        for (name, mut member) in members {
+            let name = name.trim();
+            member.canonicalize();
+            if !name.is_empty() && !member.is_empty() {
+                self.members.insert(name.to_string(), member);
+            }
+        }
     }
 
     fn is_empty(&self) -> bool {
-        self.points.is_none() && self.hours.is_none()
+        self.points.is_none() && self.hours.is_none() && self.members.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct SprintMemberCapacity {
+    /// Weekly hours for this sprint, overriding `sprints.defaults` in config.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub weekly_hours: Option<u32>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub time_off: Vec<SprintTimeOff>,
+}
+
+impl SprintMemberCapacity {
+    fn canonicalize(&mut self) {
+        for entry in &mut self.time_off {
+            entry.canonicalize();
+        }
+        self.time_off.retain(|entry| !entry.start.is_empty());
+    }
+
+    fn is_empty(&self) -> bool {
+        self.weekly_hours.is_none() && self.time_off.is_empty()
+    }
+}
+
+/// Days off within a sprint (`YYYY-MM-DD`, both ends inclusive; `end` defaults to `start`).
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct SprintTimeOff {
+    pub start: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub end: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub note: Option<String>,
+}
+
+impl SprintTimeOff {
+    fn canonicalize(&mut self) {
+        self.start = self.start.trim().to_string();
+        for target in [&mut self.end, &mut self.note] {
+            if let Some(value) = target.take() {
+                let trimmed = value.trim();
+                if !trimmed.is_empty() {
+                    *target = Some(trimmed.to_string());
+                }
+            }
+        }
     }
 }
 
@@ -354,16 +436,24 @@ pub struct SprintActual {
     pub started_at: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub closed_at: Option<String>,
+    /// Unfinished tasks rolled over to another sprint (or the backlog) on close.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub spillover: Vec<String>,
 }
 
 impl SprintActual {
     fn canonicalize(&mut self) {
         Self::normalize_text(&mut self.started_at);
         Self::normalize_text(&mut self.closed_at);
+        let mut seen = HashSet::new();
+        self.spillover.retain_mut(|id| {
+            *id = id.trim().to_string();
+            !id.is_empty() && seen.insert(id.clone())
+        });
     }
 
     fn is_empty(&self) -> bool {
-        self.started_at.is_none() && self.closed_at.is_none()
+        self.started_at.is_none() && self.closed_at.is_none() && self.spillover.is_empty()
     }
 
     fn normalize_text(target: &mut Option<String>) {
@@ -1,13 +1,22 @@
 use crate::types::{
     CustomFields, Priority, ReferenceEntry, TaskComment, TaskRelationships, TaskStatus, TaskType,
 };
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
 use std::path::PathBuf;
 
+/// Current `schema_version` of task files; see `services::migration_service`.
+pub const TASK_SCHEMA_VERSION: u32 = 1;
+
 #[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct Task {
+    // File format version (0 = written before versioning; upgrade with `lotar migrate`)
+    #[serde(skip_serializing_if = "is_unversioned", default)]
+    pub schema_version: u32,
     // Built-in standard fields (special handling in UI)
     // Note: ID is no longer stored in file - it's derived from folder+filename
     pub title: String,
@@ -65,11 +74,16 @@ pub struct Task {
     pub history: Vec<crate::types::TaskChangeLogEntry>,
 }
 
+fn is_unversioned(version: &u32) -> bool {
+    *version == 0
+}
+
 impl Task {
     pub fn new(_root_path: PathBuf, title: String, priority: Priority) -> Self {
         let now = chrono::Utc::now().to_rfc3339();
 
         Self {
+            schema_version: TASK_SCHEMA_VERSION,
             title,
             status: TaskStatus::default(),
             priority,
@@ -0,0 +1,83 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+use super::redirect::TaskRedirect;
+use super::task::Task;
+
+/// A task file that exists on disk but could not be read or parsed.
+///
+/// Storage reads used to drop such files silently; list responses now carry them as
+/// `warnings` so a merge conflict marker or typo does not make a task vanish unnoticed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct TaskParseWarning {
+    /// Task ID derived from the folder and file name
+    pub id: String,
+    /// File path relative to the tasks directory, e.g. `AUTH/2.yml`
+    pub file: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl TaskParseWarning {
+    fn new(root: &Path, id: &str, path: &Path, message: String) -> Self {
+        let file = path
+            .strip_prefix(root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        Self {
+            id: id.to_string(),
+            file,
+            line: None,
+            column: None,
+            message,
+        }
+    }
+
+    pub fn from_yaml_error(root: &Path, id: &str, path: &Path, err: &serde_yaml::Error) -> Self {
+        let mut warning = Self::new(root, id, path, err.to_string());
+        if let Some(location) = err.location() {
+            warning.line = Some(location.line());
+            warning.column = Some(location.column());
+        }
+        warning
+    }
+
+    pub fn from_io_error(root: &Path, id: &str, path: &Path, err: &std::io::Error) -> Self {
+        Self::new(root, id, path, format!("failed to read file: {}", err))
+    }
+}
+
+impl fmt::Display for TaskParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}: {}", self.file, line, self.message),
+            None => write!(f, "{}: {}", self.file, self.message),
+        }
+    }
+}
+
+/// Contents of a file in a project folder: a task, or the stub left behind by a move.
+pub enum TaskFile {
+    Task(Box<Task>),
+    Redirect(TaskRedirect),
+}
+
+/// Read and parse one task file, reporting failures as a [`TaskParseWarning`].
+pub fn read_task_file(root: &Path, id: &str, path: &Path) -> Result<TaskFile, TaskParseWarning> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| TaskParseWarning::from_io_error(root, id, path, &e))?;
+    match serde_yaml::from_str::<Task>(&content) {
+        Ok(task) => Ok(TaskFile::Task(Box::new(task))),
+        Err(err) => TaskRedirect::parse(&content)
+            .map(TaskFile::Redirect)
+            .ok_or_else(|| TaskParseWarning::from_yaml_error(root, id, path, &err)),
+    }
+}
@@ -429,6 +429,8 @@ pub struct ReferenceEntry {
     pub jira: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub github: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub commit: Option<String>,
 }
 
 // Type alias for custom fields - can hold any YAML-serializable value
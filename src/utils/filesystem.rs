@@ -66,3 +66,23 @@ pub fn file_numeric_stem(path: &Path) -> Option<u64> {
         .and_then(|s| s.to_str())
         .and_then(parse_numeric_stem)
 }
+
+/// Leading comment that points YAML editors at a JSON Schema (see `lotar schema export`).
+pub const YAML_MODELINE_PREFIX: &str = "# yaml-language-server:";
+
+/// Write YAML to `path`, keeping the schema modeline the existing file starts with.
+pub fn write_yaml_preserving_modeline(path: &Path, content: &str) -> std::io::Result<()> {
+    let modeline = fs::read_to_string(path).ok().and_then(|existing| {
+        existing
+            .lines()
+            .next()
+            .filter(|line| line.starts_with(YAML_MODELINE_PREFIX))
+            .map(str::to_string)
+    });
+    match modeline {
+        Some(line) if !content.starts_with(YAML_MODELINE_PREFIX) => {
+            fs::write(path, format!("{}\n{}", line, content))
+        }
+        _ => fs::write(path, content),
+    }
+}
@@ -79,7 +79,8 @@ fn explain_cache_key(tasks_root: Option<&Path>) -> String {
 }
 
 /// Resolve current user identity used for reporter/assignee detection.
-/// Order: config.default_reporter -> git config user.name/email -> system username
+/// Order: config.default_reporter -> git config user.name/email -> system username.
+/// Identities listed in a `members` profile resolve to that profile's handle.
 pub fn resolve_current_user(tasks_root: Option<&Path>) -> Option<String> {
     // Fast path: check cache first
     let key = id_cache_key(tasks_root);
@@ -89,30 +90,22 @@ pub fn resolve_current_user(tasks_root: Option<&Path>) -> Option<String> {
         return cached.clone();
     }
 
+    let resolved = detect_current_user(tasks_root)
+        .map(|raw| crate::utils::member::MemberDirectory::load(tasks_root).canonical(&raw));
+    if let Ok(mut guard) = identity_cache().write() {
+        guard.insert(key, resolved.clone());
+    }
+    resolved
+}
+
+fn detect_current_user(tasks_root: Option<&Path>) -> Option<String> {
     // Config default_reporter (global/project merged)
-    if let Some(root) = tasks_root {
-        if let Ok(cfg) = crate::config::resolution::load_and_merge_configs(Some(root))
-            && let Some(rep_raw) = cfg.default_reporter
-        {
-            let trimmed = rep_raw.trim();
-            if !trimmed.is_empty() && !trimmed.eq_ignore_ascii_case("@me") {
-                let rep = trimmed.to_string();
-                if let Ok(mut guard) = identity_cache().write() {
-                    guard.insert(key.clone(), Some(rep.clone()));
-                }
-                return Some(rep);
-            }
-        }
-    } else if let Ok(cfg) = crate::config::resolution::load_and_merge_configs(None)
+    if let Ok(cfg) = crate::config::resolution::load_and_merge_configs(tasks_root)
         && let Some(rep_raw) = cfg.default_reporter
     {
         let trimmed = rep_raw.trim();
         if !trimmed.is_empty() && !trimmed.eq_ignore_ascii_case("@me") {
-            let rep = trimmed.to_string();
-            if let Ok(mut guard) = identity_cache().write() {
-                guard.insert(key.clone(), Some(rep.clone()));
-            }
-            return Some(rep);
+            return Some(trimmed.to_string());
         }
     }
 
@@ -126,31 +119,15 @@ pub fn resolve_current_user(tasks_root: Option<&Path>) -> Option<String> {
         if git_config.exists()
             && let Ok(contents) = std::fs::read_to_string(&git_config)
         {
-            // Find user.name first
-            for line in contents.lines() {
-                let line = line.trim();
-                if line.starts_with("name = ") {
-                    let name = line.trim_start_matches("name = ").trim();
-                    if !name.is_empty() {
-                        let v = Some(name.to_string());
-                        if let Ok(mut guard) = identity_cache().write() {
-                            guard.insert(key.clone(), v.clone());
+            // Find user.name first, then user.email
+            for prefix in ["name = ", "email = "] {
+                for line in contents.lines() {
+                    let line = line.trim();
+                    if line.starts_with(prefix) {
+                        let value = line.trim_start_matches(prefix).trim();
+                        if !value.is_empty() {
+                            return Some(value.to_string());
                         }
-                        return v;
-                    }
-                }
-            }
-            // Then user.email
-            for line in contents.lines() {
-                let line = line.trim();
-                if line.starts_with("email = ") {
-                    let email = line.trim_start_matches("email = ").trim();
-                    if !email.is_empty() {
-                        let v = Some(email.to_string());
-                        if let Ok(mut guard) = identity_cache().write() {
-                            guard.insert(key.clone(), v.clone());
-                        }
-                        return v;
                     }
                 }
             }
@@ -158,30 +135,15 @@ pub fn resolve_current_user(tasks_root: Option<&Path>) -> Option<String> {
     }
 
     // System username via env
-    if let Ok(user) = std::env::var("USER") {
-        let t = user.trim();
-        if !t.is_empty() {
-            let v = Some(t.to_string());
-            if let Ok(mut guard) = identity_cache().write() {
-                guard.insert(key.clone(), v.clone());
+    for var in ["USER", "USERNAME"] {
+        if let Ok(user) = std::env::var(var) {
+            let t = user.trim();
+            if !t.is_empty() {
+                return Some(t.to_string());
             }
-            return v;
-        }
-    }
-    if let Ok(user) = std::env::var("USERNAME") {
-        let t = user.trim();
-        if !t.is_empty() {
-            let v = Some(t.to_string());
-            if let Ok(mut guard) = identity_cache().write() {
-                guard.insert(key.clone(), v.clone());
-            }
-            return v;
         }
     }
 
-    if let Ok(mut guard) = identity_cache().write() {
-        guard.insert(key, None);
-    }
     None
 }
 
@@ -196,7 +158,19 @@ pub fn resolve_current_user_explain(tasks_root: Option<&Path>) -> Option<Identit
     }
 
     let ctx = DetectContext { tasks_root };
-    let found = detectors_mod::detect_identity(&ctx);
+    let found = detectors_mod::detect_identity(&ctx).map(|mut detection| {
+        let handle =
+            crate::utils::member::MemberDirectory::load(tasks_root).canonical(&detection.user);
+        if handle != detection.user {
+            let mapped = format!("members profile maps '{}' to '{}'", detection.user, handle);
+            detection.details = Some(match detection.details.take() {
+                Some(details) => format!("{}; {}", details, mapped),
+                None => mapped,
+            });
+            detection.user = handle;
+        }
+        detection
+    });
     if let Ok(mut guard) = identity_explain_cache().write() {
         guard.insert(key, found.clone());
     }
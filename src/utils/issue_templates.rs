@@ -0,0 +1,151 @@
+//! Per-type issue templates (`issue.templates.<Type>`).
+//!
+//! A template pre-fills new tasks of its type (description, tags, custom fields,
+//! acceptance criteria) and lists fields that must be set before the task is created.
+
+use crate::config::types::{IssueTemplate, ResolvedConfig};
+use crate::errors::{LoTaRError, LoTaRResult};
+use crate::storage::task::Task;
+
+/// Fill unset values on `task` from the template configured for its type.
+///
+/// Template tags are only added when the caller gave no tags (`explicit_tags == false`).
+pub fn apply_issue_template(task: &mut Task, config: &ResolvedConfig, explicit_tags: bool) {
+    let Some(template) = config.issue_template_for(&task.task_type) else {
+        return;
+    };
+
+    if is_blank(task.description.as_deref())
+        && let Some(description) = template.description.as_ref()
+    {
+        task.description = Some(description.clone());
+    }
+    if !explicit_tags {
+        for tag in &template.tags {
+            if !task
+                .tags
+                .iter()
+                .any(|existing| existing.eq_ignore_ascii_case(tag))
+            {
+                task.tags.push(tag.clone());
+            }
+        }
+    }
+    for (name, value) in &template.custom_fields {
+        if find_custom_field(task, name).is_none() {
+            task.custom_fields.insert(
+                name.clone(),
+                crate::types::custom_value_string(value.clone()),
+            );
+        }
+    }
+    if task.acceptance_criteria.is_empty() {
+        task.acceptance_criteria = template.acceptance_criteria.clone();
+    }
+}
+
+/// Required fields from `template` that have no value on `task`, in template order.
+pub fn missing_required_fields(task: &Task, template: &IssueTemplate) -> Vec<String> {
+    template
+        .required
+        .iter()
+        .map(|raw| raw.trim())
+        .filter(|name| !name.is_empty() && !has_value(task, name))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Reject a new task that lacks fields its type's template requires.
+pub fn enforce_required_fields(task: &Task, config: &ResolvedConfig) -> LoTaRResult<()> {
+    let Some(template) = config.issue_template_for(&task.task_type) else {
+        return Ok(());
+    };
+    let missing = missing_required_fields(task, template);
+    if missing.is_empty() {
+        return Ok(());
+    }
+    Err(LoTaRError::ValidationError(format!(
+        "{} tasks require {}; missing: {}",
+        task.task_type,
+        template
+            .required
+            .iter()
+            .map(|name| name.trim())
+            .filter(|name| !name.is_empty())
+            .collect::<Vec<_>>()
+            .join(", "),
+        missing.join(", ")
+    )))
+}
+
+fn has_value(task: &Task, name: &str) -> bool {
+    let name = name.strip_prefix("field:").unwrap_or(name).trim();
+    let normalized = name.to_ascii_lowercase().replace(['_', '-'], "");
+    if normalized == "acceptancecriteria" {
+        return task
+            .acceptance_criteria
+            .iter()
+            .any(|item| !item.trim().is_empty());
+    }
+    match crate::utils::fields::is_reserved_field(name) {
+        Some("title") => !task.title.trim().is_empty(),
+        Some("description") => !is_blank(task.description.as_deref()),
+        Some("assignee") => !is_blank(task.assignee.as_deref()),
+        Some("reporter") => !is_blank(task.reporter.as_deref()),
+        Some("due_date") => !is_blank(task.due_date.as_deref()),
+        Some("effort") => !is_blank(task.effort.as_deref()),
+        Some("tags") => task.tags.iter().any(|tag| !tag.trim().is_empty()),
+        Some("priority") => !task.priority.is_empty(),
+        Some("status") => !task.status.is_empty(),
+        Some("type") => !task.task_type.is_empty(),
+        _ => find_custom_field(task, name).is_some_and(|value| {
+            !crate::types::custom_value_to_string(value)
+                .trim()
+                .is_empty()
+        }),
+    }
+}
+
+fn find_custom_field<'a>(task: &'a Task, name: &str) -> Option<&'a crate::types::CustomFieldValue> {
+    task.custom_fields
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value)
+}
+
+fn is_blank(value: Option<&str>) -> bool {
+    value.is_none_or(|v| v.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Priority, TaskType};
+    use std::path::PathBuf;
+
+    #[test]
+    fn reports_missing_builtin_and_custom_fields() {
+        let template = IssueTemplate {
+            required: vec![
+                "severity".to_string(),
+                "assignee".to_string(),
+                "acceptance_criteria".to_string(),
+            ],
+            ..IssueTemplate::default()
+        };
+        let mut task = Task::new(PathBuf::new(), "Crash".to_string(), Priority::from("High"));
+        task.task_type = TaskType::from("Bug");
+        assert_eq!(
+            missing_required_fields(&task, &template),
+            vec!["severity", "assignee", "acceptance_criteria"]
+        );
+
+        task.assignee = Some("alice".to_string());
+        task.custom_fields.insert(
+            "Severity".to_string(),
+            crate::types::custom_value_string("major"),
+        );
+        task.acceptance_criteria = vec!["No crash".to_string()];
+        assert!(missing_required_fields(&task, &template).is_empty());
+    }
+}
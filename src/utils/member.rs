@@ -11,6 +11,11 @@
 //! like `@alice` (where `alice` is *not* a directive) is silently normalized
 //! to `alice`.
 
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::config::types::{MemberProfile, ResolvedConfig};
+
 /// Built-in directives that are always preserved with their `@` prefix.
 const BUILTIN_DIRECTIVES: &[&str] = &["@me"];
 
@@ -84,6 +89,123 @@ pub fn is_email_like(s: &str) -> bool {
         && !s.ends_with('@')
 }
 
+/// Lookup over the structured `members` profiles.
+///
+/// Maps every known identity of a person (handle, display name, emails, git author
+/// names, GitHub login, Jira accountId) to the profile's canonical handle, so values
+/// like `jane@corp.com` and `jdoe` compare as the same member.
+#[derive(Debug, Clone, Default)]
+pub struct MemberDirectory {
+    profiles: Vec<MemberProfile>,
+    index: HashMap<String, usize>,
+}
+
+impl MemberDirectory {
+    pub fn new(profiles: &[MemberProfile]) -> Self {
+        let mut directory = MemberDirectory {
+            profiles: profiles.to_vec(),
+            index: HashMap::new(),
+        };
+        // Handles win over aliases, then earlier profiles win over later ones.
+        for (idx, profile) in profiles.iter().enumerate() {
+            directory.insert_alias(&profile.handle, idx);
+        }
+        for (idx, profile) in profiles.iter().enumerate() {
+            let aliases = profile
+                .name
+                .iter()
+                .chain(profile.emails.iter())
+                .chain(profile.git.iter())
+                .chain(profile.github.iter())
+                .chain(profile.jira.iter());
+            for alias in aliases {
+                directory.insert_alias(alias, idx);
+            }
+        }
+        directory
+    }
+
+    pub fn from_config(config: &ResolvedConfig) -> Self {
+        Self::new(&config.member_profiles)
+    }
+
+    /// Best-effort load from the merged configuration; empty when it cannot be read.
+    pub fn load(tasks_root: Option<&Path>) -> Self {
+        crate::config::resolution::load_and_merge_configs(tasks_root)
+            .map(|config| Self::from_config(&config))
+            .unwrap_or_default()
+    }
+
+    fn insert_alias(&mut self, alias: &str, idx: usize) {
+        let key = member_for_comparison(alias);
+        if !key.is_empty() {
+            self.index.entry(key).or_insert(idx);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty()
+    }
+
+    pub fn profiles(&self) -> &[MemberProfile] {
+        &self.profiles
+    }
+
+    /// Find the profile owning `value` (any alias, case-insensitive, `@` ignored).
+    pub fn find(&self, value: &str) -> Option<&MemberProfile> {
+        let idx = self.index.get(&member_for_comparison(value))?;
+        self.profiles.get(*idx)
+    }
+
+    /// Find the profile for a git author, trying the email before the name.
+    pub fn find_git(&self, name: &str, email: &str) -> Option<&MemberProfile> {
+        self.find(email).or_else(|| self.find(name))
+    }
+
+    /// Canonical handle for `value`; unknown values and directives come back trimmed.
+    pub fn canonical(&self, value: &str) -> String {
+        let trimmed = value.trim();
+        if is_builtin_directive(trimmed) {
+            return trimmed.to_string();
+        }
+        match self.find(trimmed) {
+            Some(profile) => profile.handle.clone(),
+            None => trimmed.to_string(),
+        }
+    }
+
+    /// Like [`member_for_comparison`], but aliases collapse onto their handle.
+    pub fn comparison_key(&self, value: &str) -> String {
+        match self.find(value) {
+            Some(profile) => member_for_comparison(&profile.handle),
+            None => member_for_comparison(value),
+        }
+    }
+
+    pub fn same_member(&self, a: &str, b: &str) -> bool {
+        self.comparison_key(a) == self.comparison_key(b)
+    }
+
+    /// Display label: the profile name when known, else the canonical handle.
+    pub fn display_name(&self, value: &str) -> String {
+        match self.find(value) {
+            Some(profile) => profile
+                .name
+                .clone()
+                .unwrap_or_else(|| profile.handle.clone()),
+            None => value.trim().to_string(),
+        }
+    }
+
+    pub fn jira_account_id(&self, value: &str) -> Option<&str> {
+        self.find(value)?.jira.as_deref()
+    }
+
+    pub fn github_login(&self, value: &str) -> Option<&str> {
+        self.find(value)?.github.as_deref()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -159,4 +281,34 @@ mod tests {
         assert!(!is_email_like("john@"));
         assert!(!is_email_like("@"));
     }
+
+    #[test]
+    fn directory_collapses_aliases_onto_handle() {
+        let directory = MemberDirectory::new(&[MemberProfile {
+            handle: "jdoe".to_string(),
+            name: Some("Jane Doe".to_string()),
+            emails: vec!["jane@corp.com".to_string()],
+            git: vec!["Jane D".to_string()],
+            github: Some("janedoe".to_string()),
+            jira: Some("5b10ac8d82e05b22cc7d4ef5".to_string()),
+        }]);
+        assert_eq!(directory.canonical("Jane@Corp.com"), "jdoe");
+        assert_eq!(directory.canonical("@janedoe"), "jdoe");
+        assert_eq!(directory.canonical("5b10ac8d82e05b22cc7d4ef5"), "jdoe");
+        assert_eq!(directory.canonical("someone"), "someone");
+        assert_eq!(directory.canonical("@me"), "@me");
+        assert!(directory.same_member("Jane Doe", "JDOE"));
+        assert_eq!(
+            directory
+                .find_git("Jane D", "jane@home.example")
+                .map(|p| p.handle.as_str()),
+            Some("jdoe")
+        );
+        assert_eq!(
+            directory.jira_account_id("jane@corp.com"),
+            Some("5b10ac8d82e05b22cc7d4ef5")
+        );
+        assert_eq!(directory.github_login("jdoe"), Some("janedoe"));
+        assert_eq!(directory.display_name("janedoe"), "Jane Doe");
+    }
 }
@@ -8,6 +8,7 @@ pub mod fuzzy_match;
 pub mod git;
 pub mod identity;
 pub mod identity_detectors;
+pub mod issue_templates;
 pub mod member;
 pub mod pagination;
 pub mod paths;
@@ -4,10 +4,15 @@ use std::sync::{LazyLock, Mutex};
 use std::time::{Duration, Instant};
 
 use crate::api_types::TaskDTO;
+use crate::storage::TaskParseWarning;
+
+/// Sorted `(id, task)` pairs plus the parse warnings collected while loading them.
+pub type QueryResult = (Vec<(String, TaskDTO)>, Vec<TaskParseWarning>);
 
 /// Cached result of a fully-filtered, sorted task query.
 struct CachedQuery {
     tasks: Vec<(String, TaskDTO)>,
+    warnings: Vec<TaskParseWarning>,
     created: Instant,
 }
 
@@ -38,19 +43,19 @@ pub fn cache_key(params: &HashMap<String, String>) -> u64 {
     hasher.finish()
 }
 
-/// Try to get a cached query result.  Returns `None` if expired or absent.
-pub fn get(key: u64) -> Option<Vec<(String, TaskDTO)>> {
+/// Try to get a cached query result (tasks plus parse warnings).  Returns `None` if expired or absent.
+pub fn get(key: u64) -> Option<QueryResult> {
     let mut cache = CACHE.lock().ok()?;
     let entry = cache.get(&key)?;
     if entry.created.elapsed() > DEFAULT_TTL {
         cache.remove(&key);
         return None;
     }
-    Some(entry.tasks.clone())
+    Some((entry.tasks.clone(), entry.warnings.clone()))
 }
 
 /// Store a query result in the cache.
-pub fn put(key: u64, tasks: Vec<(String, TaskDTO)>) {
+pub fn put(key: u64, tasks: Vec<(String, TaskDTO)>, warnings: Vec<TaskParseWarning>) {
     let Ok(mut cache) = CACHE.lock() else { return };
     // Simple eviction: if too many entries, remove the oldest.
     if cache.len() >= MAX_ENTRIES {
@@ -63,6 +68,7 @@ pub fn put(key: u64, tasks: Vec<(String, TaskDTO)>) {
         key,
         CachedQuery {
             tasks,
+            warnings,
             created: Instant::now(),
         },
     );
@@ -123,7 +129,7 @@ mod tests {
     fn put_and_get_roundtrip() {
         let key = 0xDEAD;
         let tasks: Vec<(String, TaskDTO)> = vec![];
-        put(key, tasks);
+        put(key, tasks, vec![]);
         let result = get(key);
         assert!(result.is_some());
         invalidate_all();
@@ -132,7 +138,7 @@ mod tests {
     #[test]
     fn invalidate_all_clears_cache() {
         let key = 0xBEEF;
-        put(key, vec![]);
+        put(key, vec![], vec![]);
         invalidate_all();
         assert!(get(key).is_none());
     }
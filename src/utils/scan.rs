@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
 /// Scan-related helpers shared by CLI and API surfaces.
 
 #[derive(Default, Debug, Clone)]
@@ -154,9 +157,186 @@ pub fn parse_inline_attributes(line: &str) -> InlineAttrs {
     out
 }
 
+/// Where a task's code anchor points after the source may have changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnchorLocation {
+    /// The key marker was found on `line` (the anchor may have moved or the file been renamed).
+    Found { path: PathBuf, line: usize },
+    /// The file still exists but no longer mentions the key.
+    KeyMissing { path: PathBuf },
+    /// Neither the file nor a git rename of it exists.
+    FileMissing,
+    /// The file exists but could not be read (permissions, non-UTF-8 content).
+    Unreadable { path: PathBuf, error: String },
+}
+
+/// Locates task keys referenced by `path#line` code anchors, following nearby line moves
+/// and git renames. Shared by scan re-anchoring and reconciliation.
+pub struct AnchorLocator {
+    repo_root: Option<PathBuf>,
+    base: PathBuf,
+    rename_map: HashMap<String, String>,
+    window: usize,
+}
+
+impl AnchorLocator {
+    /// Build a locator for anchors relative to the repository containing `base`
+    /// (or `base` itself outside a repository). `window` is the number of lines
+    /// searched around the previous anchor before falling back to the whole file.
+    pub fn new(base: &Path, window: usize) -> Self {
+        let repo_root = crate::utils::git::find_repo_root(base);
+        let rename_map = repo_root.as_deref().map(git_rename_map).unwrap_or_default();
+        Self {
+            repo_root,
+            base: base.to_path_buf(),
+            rename_map,
+            window,
+        }
+    }
+
+    /// Find where `key` now lives for a `path#line` anchor; `None` when the anchor has no path.
+    pub fn locate(&self, key: &str, code_ref: &str) -> Option<AnchorLocation> {
+        let (path_str, orig_line) = parse_code_ref(code_ref);
+        if path_str.is_empty() {
+            return None;
+        }
+        let root = self.repo_root.as_deref().unwrap_or(&self.base);
+        let mut abs_path = root.join(&path_str);
+        let exists = match abs_path.try_exists() {
+            Ok(exists) => exists,
+            Err(err) => {
+                return Some(AnchorLocation::Unreadable {
+                    path: abs_path,
+                    error: err.to_string(),
+                });
+            }
+        };
+        if !exists {
+            // File may have been renamed; try git rename map using repo-relative key
+            match self
+                .rename_map
+                .get(&path_str)
+                .map(|new_rel| root.join(new_rel))
+            {
+                Some(candidate) if candidate.exists() => abs_path = candidate,
+                _ => return Some(AnchorLocation::FileMissing),
+            }
+        }
+
+        let content = match std::fs::read_to_string(&abs_path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Some(AnchorLocation::FileMissing);
+            }
+            Err(err) => {
+                return Some(AnchorLocation::Unreadable {
+                    path: abs_path,
+                    error: err.to_string(),
+                });
+            }
+        };
+        let lines: Vec<&str> = content.lines().collect();
+
+        // Original line first, then a proximity window, then the closest match in the file
+        let mut best_line = orig_line.filter(|ln| {
+            lines
+                .get(ln.saturating_sub(1))
+                .is_some_and(|text| line_contains_key(text, key))
+        });
+        if best_line.is_none()
+            && let Some(orig) = orig_line
+        {
+            let start = orig.saturating_sub(self.window).max(1);
+            let end = (orig + self.window).min(lines.len());
+            best_line = (start..=end).find(|ln| line_contains_key(lines[ln - 1], key));
+        }
+        if best_line.is_none() {
+            let mut candidates: Vec<usize> = lines
+                .iter()
+                .enumerate()
+                .filter(|(_, text)| line_contains_key(text, key))
+                .map(|(idx, _)| idx + 1)
+                .collect();
+            if let Some(orig) = orig_line {
+                candidates.sort_by_key(|&ln| ln.abs_diff(orig));
+            }
+            best_line = candidates.first().copied();
+        }
+
+        Some(match best_line {
+            Some(line) => AnchorLocation::Found {
+                path: abs_path,
+                line,
+            },
+            None => AnchorLocation::KeyMissing { path: abs_path },
+        })
+    }
+}
+
+/// Split a `path#L12` style code reference into its path and line number.
+pub fn parse_code_ref(code: &str) -> (String, Option<usize>) {
+    if let Some((path, anchor)) = code.split_once('#') {
+        let digits: String = anchor
+            .chars()
+            .skip_while(|ch| !ch.is_ascii_digit())
+            .take_while(|ch| ch.is_ascii_digit())
+            .collect();
+        (path.to_string(), digits.parse::<usize>().ok())
+    } else {
+        (code.to_string(), None)
+    }
+}
+
+/// Whether a source line carries the key marker inserted by scan: `(KEY)` or `[ticket=KEY]`.
+pub fn line_contains_key(line: &str, key: &str) -> bool {
+    if line.contains(&format!("({})", key)) {
+        return true;
+    }
+    // Accept [ticket=KEY] with optional spaces and case-insensitive ticket
+    let pattern = format!(r"(?i)\[\s*ticket\s*=\s*{}\s*\]", regex::escape(key));
+    regex::Regex::new(&pattern)
+        .map(|re| re.is_match(line))
+        .unwrap_or(false)
+}
+
+/// Map of renamed paths (old -> new) from `git status --porcelain`.
+fn git_rename_map(repo_root: &Path) -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    let Ok(o) = std::process::Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .arg("status")
+        .arg("--porcelain")
+        .output()
+    else {
+        return map;
+    };
+    if !o.status.success() {
+        return map;
+    }
+    let s = String::from_utf8_lossy(&o.stdout);
+    for line in s.lines() {
+        if line.len() < 4 {
+            continue;
+        }
+        let status = &line[..2];
+        if status.contains('R')
+            && let Some(pos) = line.find(" -> ")
+        {
+            let old = line[3..pos].trim().to_string();
+            let newp = line[pos + 4..].trim().to_string();
+            map.insert(old, newp);
+        }
+    }
+    map
+}
+
 #[cfg(test)]
 mod scan_utils_tests {
-    use super::{parse_inline_attributes, strip_bracket_attributes};
+    use super::{
+        AnchorLocation, AnchorLocator, parse_code_ref, parse_inline_attributes,
+        strip_bracket_attributes,
+    };
 
     #[test]
     fn strip_preserves_leading_indentation() {
@@ -193,4 +373,74 @@ mod scan_utils_tests {
             vec![("product".to_string(), "Platform".to_string())]
         );
     }
+
+    #[test]
+    fn parse_code_ref_extracts_line() {
+        assert_eq!(
+            parse_code_ref("src/a.rs#L12"),
+            ("src/a.rs".to_string(), Some(12))
+        );
+        assert_eq!(
+            parse_code_ref("src/a.rs#3"),
+            ("src/a.rs".to_string(), Some(3))
+        );
+        assert_eq!(parse_code_ref("src/a.rs"), ("src/a.rs".to_string(), None));
+    }
+
+    #[test]
+    fn locator_distinguishes_moved_and_removed_keys() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let file = temp.path().join("a.rs");
+        std::fs::write(&file, "fn a() {}\n\n// TODO (DEMO-1): moved\n").unwrap();
+        let locator = AnchorLocator::new(temp.path(), 7);
+
+        assert_eq!(
+            locator.locate("DEMO-1", "a.rs#1"),
+            Some(AnchorLocation::Found {
+                path: file.clone(),
+                line: 3
+            })
+        );
+        assert_eq!(
+            locator.locate("DEMO-2", "a.rs#1"),
+            Some(AnchorLocation::KeyMissing { path: file })
+        );
+        assert_eq!(
+            locator.locate("DEMO-1", "gone.rs#1"),
+            Some(AnchorLocation::FileMissing)
+        );
+    }
+
+    #[test]
+    fn locator_reports_non_utf8_files_as_unreadable() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let file = temp.path().join("blob.rs");
+        std::fs::write(&file, [0xff, 0xfe, b'\n', 0xc3]).unwrap();
+        let locator = AnchorLocator::new(temp.path(), 7);
+
+        assert!(matches!(
+            locator.locate("DEMO-1", "blob.rs#1"),
+            Some(AnchorLocation::Unreadable { path, .. }) if path == file
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn locator_reports_unreadable_files_instead_of_missing() {
+        use std::os::unix::fs::PermissionsExt;
+        let temp = tempfile::tempdir().expect("tempdir");
+        let file = temp.path().join("secret.rs");
+        std::fs::write(&file, "// TODO (DEMO-1): hidden\n").unwrap();
+        std::fs::set_permissions(&file, std::fs::Permissions::from_mode(0o000)).unwrap();
+        if std::fs::read(&file).is_ok() {
+            // Running as root: permissions are not enforced.
+            return;
+        }
+        let locator = AnchorLocator::new(temp.path(), 7);
+
+        assert!(matches!(
+            locator.locate("DEMO-1", "secret.rs#1"),
+            Some(AnchorLocation::Unreadable { path, .. }) if path == file
+        ));
+    }
 }
@@ -1,6 +1,15 @@
 use crate::config::types::ResolvedConfig;
 use crate::types::{Priority, TaskStatus, TaskType};
+use regex::Regex;
 use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Longest title slug rendered into a branch name.
+const MAX_BRANCH_SLUG_LEN: usize = 40;
+
+static BRANCH_TICKET: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|[/_.-])([A-Z][A-Z0-9_]*-\d+)(?:$|[/_.-])").expect("valid branch ticket regex")
+});
 
 struct BranchContext {
     branch_lower: String,
@@ -152,3 +161,123 @@ pub fn auto_tag_from_path(config: &ResolvedConfig) -> Option<String> {
     }
     crate::utils::workspace_labels::derive_label_from_cwd()
 }
+
+/// Ticket key embedded in a branch name (e.g. `feat/PROJ-12-login` → `PROJ-12`).
+///
+/// Keys must keep their upper-case project prefix, as written by `lotar start`, so
+/// words like `fix-2fa` are not mistaken for tickets.
+pub fn task_id_from_branch_name(branch: &str) -> Option<String> {
+    BRANCH_TICKET
+        .captures(branch)
+        .and_then(|caps| caps.get(1))
+        .map(|m| m.as_str().to_string())
+}
+
+/// Ticket key embedded in the branch checked out in the current directory.
+pub fn task_id_from_current_branch() -> Option<String> {
+    let cwd = std::env::current_dir().ok()?;
+    let repo_root = crate::utils::git::find_repo_root(&cwd)?;
+    let branch = crate::utils::git::read_current_branch(&repo_root)?;
+    task_id_from_branch_name(&branch)
+}
+
+/// Render `branch.template` for a task.
+///
+/// Placeholders: `{id}` (PROJ-12), `{project}` (PROJ), `{number}` (12), `{type}` and
+/// `{slug}` (lower-case, dash-separated title). Empty placeholders collapse cleanly.
+pub fn render_branch_name(template: &str, task_id: &str, task_type: &str, title: &str) -> String {
+    let (project, number) = task_id.split_once('-').unwrap_or(("", task_id));
+    let rendered = template
+        .replace("{id}", task_id)
+        .replace("{project}", project)
+        .replace("{number}", number)
+        .replace("{type}", &slugify(task_type, MAX_BRANCH_SLUG_LEN))
+        .replace("{slug}", &slugify(title, MAX_BRANCH_SLUG_LEN));
+
+    let mut branch = String::with_capacity(rendered.len());
+    for ch in rendered.chars() {
+        let ch = if ch.is_ascii_alphanumeric() || matches!(ch, '/' | '-' | '_' | '.') {
+            ch
+        } else {
+            '-'
+        };
+        let last = branch.chars().last();
+        if (ch == '-' && matches!(last, Some('-' | '/') | None))
+            || (ch == '/' && matches!(last, Some('/') | None))
+        {
+            continue;
+        }
+        if ch == '/' && last == Some('-') {
+            branch.pop();
+        }
+        branch.push(ch);
+    }
+    let branch = branch.trim_end_matches(['-', '/', '.']).replace("..", ".");
+    if branch.is_empty() {
+        task_id.to_string()
+    } else {
+        branch
+    }
+}
+
+fn slugify(input: &str, max_len: usize) -> String {
+    let mut slug = String::new();
+    for word in input
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        let word = word.to_ascii_lowercase();
+        let needed = if slug.is_empty() {
+            word.len()
+        } else {
+            word.len() + 1
+        };
+        if slug.len() + needed > max_len {
+            if slug.is_empty() {
+                slug.push_str(&word[..max_len]);
+            }
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(&word);
+    }
+    slug
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn branch_names_render_and_parse_back() {
+        let branch = render_branch_name(
+            "{type}/{id}-{slug}",
+            "AUTH-12",
+            "Feature",
+            "Refresh tokens on 401 (again)!",
+        );
+        assert_eq!(branch, "feature/AUTH-12-refresh-tokens-on-401-again");
+        assert_eq!(
+            task_id_from_branch_name(&branch).as_deref(),
+            Some("AUTH-12")
+        );
+
+        assert_eq!(
+            render_branch_name("{type}/{project}/{number}-{slug}", "AUTH-3", "", "..."),
+            "AUTH/3"
+        );
+    }
+
+    #[test]
+    fn branch_parsing_requires_an_uppercase_key() {
+        assert_eq!(task_id_from_branch_name("fix-2fa-login"), None);
+        assert_eq!(task_id_from_branch_name("main"), None);
+        assert_eq!(
+            task_id_from_branch_name("MY_APP-7_hotfix").as_deref(),
+            Some("MY_APP-7")
+        );
+        assert_eq!(task_id_from_branch_name("feat/AUTH-12x"), None);
+    }
+}
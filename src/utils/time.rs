@@ -173,6 +173,25 @@ pub fn parse_duration_like(input: &str) -> Option<Duration> {
     parse_unsigned_days_or_weeks(&raw.to_lowercase())
 }
 
+/// Parse a short interval like `30s`, `5m`, `2h`, or `1d` into a `std::time::Duration`.
+/// Returns `None` when the input is empty, uses an unknown unit, or overflows.
+pub fn parse_interval(input: &str) -> Option<std::time::Duration> {
+    let s = input.trim();
+    if s.is_empty() || !s.is_ascii() {
+        return None;
+    }
+    let (digits, suffix) = s.split_at(s.len() - 1);
+    let value: u64 = digits.trim().parse().ok()?;
+    let unit: u64 = match suffix {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86400,
+        _ => return None,
+    };
+    value.checked_mul(unit).map(std::time::Duration::from_secs)
+}
+
 fn is_bare_offset(s: &str) -> bool {
     // e.g., "14d", "2w", "7 days", "3 weeks"
     if let Some(num) = s.strip_suffix('d').or_else(|| s.strip_suffix('w')) {
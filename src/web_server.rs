@@ -619,6 +619,15 @@ fn start_tasks_watcher() {
                     if let Some(paths) = (!event.paths.is_empty()).then_some(event.paths) {
                         let mut emitted: HashSet<String> = HashSet::new();
                         for p in paths {
+                            if !matches!(event.kind, EventKind::Remove(_))
+                                && let Some(approval) = crate::services::agent_approval_service::AgentApprovalService::from_changed_path(&tasks_dir, &p)
+                            {
+                                crate::api_events::emit(&crate::api_events::ApiEvent {
+                                    kind: "agent_approval_updated".to_string(),
+                                    data: serde_json::json!({ "approval": approval }),
+                                });
+                                continue;
+                            }
                             let mut proj: Option<String> = None;
                             let mut task_id: Option<String> = None;
 
@@ -665,10 +674,9 @@ fn start_tasks_watcher() {
                                             let storage = crate::storage::manager::Storage::new(
                                                 &resolver.path,
                                             );
-                                            match crate::services::task_service::TaskService::get(
-                                                &storage, &task_id, None,
-                                            ) {
-                                                Ok(_) => {
+                                            let project = task_id.split('-').next().unwrap_or("");
+                                            match storage.get_checked(&task_id, project) {
+                                                Ok(Some(_)) => {
                                                     crate::api_events::emit(
                                                         &crate::api_events::ApiEvent {
                                                             kind: "task_updated".to_string(),
@@ -676,11 +684,30 @@ fn start_tasks_watcher() {
                                                         },
                                                     );
                                                 }
-                                                Err(e) => {
-                                                    // Emit both: task_error for the notification, task_updated so UIs can try to refresh
+                                                Ok(None) => {
+                                                    crate::api_events::emit_task_error(
+                                                        &task_id,
+                                                        &crate::errors::LoTaRError::TaskNotFound(
+                                                            task_id.clone(),
+                                                        )
+                                                        .to_string(),
+                                                    );
+                                                    crate::api_events::emit(
+                                                        &crate::api_events::ApiEvent {
+                                                            kind: "task_updated".to_string(),
+                                                            data: serde_json::json!({ "id": task_id }),
+                                                        },
+                                                    );
+                                                }
+                                                Err(warning) => {
+                                                    // Emit task_parse_error (file/line details) and task_error for the
+                                                    // notification, plus task_updated so UIs can try to refresh
+                                                    crate::api_events::emit_task_parse_error(
+                                                        &warning,
+                                                    );
                                                     crate::api_events::emit_task_error(
                                                         &task_id,
-                                                        &e.to_string(),
+                                                        &warning.to_string(),
                                                     );
                                                     crate::api_events::emit(
                                                         &crate::api_events::ApiEvent {
@@ -4,8 +4,12 @@
 mod common;
 
 use common::TestFixtures;
-use lotar::api_types::{AgentJobCreateRequest, TaskCreate, TaskUpdate};
+use lotar::api_types::{
+    AgentJobCreateRequest, AgentPipelineDecisionRequest, AgentPipelineRunRequest, TaskCreate,
+    TaskUpdate,
+};
 use lotar::services::agent_job_service::{AgentJobService, AgentOrchestratorMode};
+use lotar::services::agent_pipeline_service::AgentPipelineService;
 use lotar::services::automation_service::{
     AutomationEvent, AutomationJobContext, AutomationService,
 };
@@ -2202,6 +2206,7 @@ automation:
         actual: Some(SprintActual {
             started_at: Some(chrono::Utc::now().to_rfc3339()),
             closed_at: None,
+            ..SprintActual::default()
         }),
         ..Default::default()
     };
@@ -2768,3 +2773,726 @@ exit 0\n",
     assert!(wait_for_job_status(&first_job.id, "completed", 4000));
     assert!(wait_for_job_status(&second_job.id, "completed", 5000));
 }
+
+/// Failed attempts are retried and resume the runner session captured from the first attempt.
+#[cfg(unix)]
+#[test]
+fn failed_attempt_retries_and_resumes_session() {
+    let _guard = lock_agent_tests();
+    enable_server_mode();
+    let fixtures = TestFixtures::new();
+
+    let agent_name = "retry-agent";
+    let args_log = fixtures.get_temp_path().join("retry-args.log");
+    let script = write_stub_agent_script(
+        fixtures.get_temp_path(),
+        "retry-agent.sh",
+        &format!(
+            "#!/bin/sh\n\
+resume=none\n\
+prev=\n\
+for arg in \"$@\"; do\n\
+  if [ \"$prev\" = \"--resume\" ]; then resume=\"$arg\"; fi\n\
+  prev=\"$arg\"\n\
+done\n\
+echo \"$LOTAR_AGENT_ATTEMPT $resume\" >> \"{}\"\n\
+echo '{{\"type\":\"system\",\"subtype\":\"init\",\"session_id\":\"retry-session\"}}'\n\
+if [ \"$LOTAR_AGENT_ATTEMPT\" = \"1\" ]; then exit 1; fi\n\
+exit 0\n",
+            args_log.to_string_lossy()
+        ),
+    );
+
+    fixtures.create_config_in_dir(
+        &fixtures.tasks_root,
+        &format!(
+            "agents:\n  {agent_name}:\n    runner: claude\n    command: \"{}\"\n    max_retries: 2\n    retry_backoff: 0s\n",
+            script.to_string_lossy()
+        ),
+    );
+    AutomationService::set(&fixtures.tasks_root, None, "automation:\n  rules: []\n")
+        .expect("set automation");
+
+    let mut storage = fixtures.create_storage();
+    let created = TaskService::create(
+        &mut storage,
+        TaskCreate {
+            title: "Retry test".to_string(),
+            project: Some("RETRY".to_string()),
+            ..Default::default()
+        },
+    )
+    .expect("create task");
+
+    let job = AgentJobService::start_job_with_tasks_dir(
+        AgentJobCreateRequest {
+            ticket_id: created.id.clone(),
+            prompt: "try twice".to_string(),
+            runner: None,
+            agent: Some(agent_name.to_string()),
+        },
+        fixtures.tasks_root.as_path(),
+    )
+    .expect("start job");
+
+    assert!(
+        wait_for_job_status(&job.id, "completed", 5000),
+        "retried job did not complete in time"
+    );
+    let finished = AgentJobService::get_job(&job.id).expect("job exists");
+    assert_eq!(finished.attempt, Some(2));
+
+    let events = AgentJobService::events_for(&job.id);
+    let retry = events
+        .iter()
+        .find(|event| event.kind == "agent_job_retry")
+        .expect("retry event recorded");
+    assert!(
+        retry
+            .message
+            .as_deref()
+            .is_some_and(|msg| msg.contains("exited with code 1")),
+        "retry reason missing: {:?}",
+        retry.message
+    );
+
+    let log = fs::read_to_string(&args_log).expect("read args log");
+    let lines: Vec<&str> = log.lines().collect();
+    assert_eq!(lines.len(), 2, "expected two attempts: {log}");
+    assert_eq!(lines[0], "1 none");
+    assert_eq!(
+        lines[1], "2 retry-session",
+        "second attempt should resume the session"
+    );
+}
+
+/// The idle watchdog kills runners that stop emitting events and fails the job with a reason.
+#[cfg(unix)]
+#[test]
+fn idle_watchdog_kills_silent_runner() {
+    let _guard = lock_agent_tests();
+    enable_server_mode();
+    let fixtures = TestFixtures::new();
+
+    let agent_name = "stuck-cmd";
+    let script = write_stub_agent_script(
+        fixtures.get_temp_path(),
+        "stuck-cmd.sh",
+        "#!/bin/sh\nsleep 30\n",
+    );
+
+    fixtures.create_config_in_dir(
+        &fixtures.tasks_root,
+        &format!(
+            "agents:\n  {agent_name}:\n    runner: command\n    command: \"{}\"\n    idle_timeout: 1s\n",
+            script.to_string_lossy()
+        ),
+    );
+    AutomationService::set(&fixtures.tasks_root, None, "automation:\n  rules: []\n")
+        .expect("set automation");
+
+    let mut storage = fixtures.create_storage();
+    let created = TaskService::create(
+        &mut storage,
+        TaskCreate {
+            title: "Watchdog test".to_string(),
+            project: Some("DOG".to_string()),
+            ..Default::default()
+        },
+    )
+    .expect("create task");
+
+    let job = AgentJobService::start_job_with_tasks_dir(
+        AgentJobCreateRequest {
+            ticket_id: created.id.clone(),
+            prompt: "hang".to_string(),
+            runner: None,
+            agent: Some(agent_name.to_string()),
+        },
+        fixtures.tasks_root.as_path(),
+    )
+    .expect("start job");
+
+    assert!(
+        wait_for_job_status(&job.id, "failed", 6000),
+        "watchdog did not stop the silent runner"
+    );
+    let failed = AgentJobService::get_job(&job.id).expect("job exists");
+    assert!(
+        failed
+            .summary
+            .as_deref()
+            .is_some_and(|summary| summary.contains("no events for 1s")),
+        "unexpected summary: {:?}",
+        failed.summary
+    );
+}
+
+#[cfg(unix)]
+fn wait_for_pipeline_status(
+    tasks_dir: &Path,
+    ticket_id: &str,
+    status: &str,
+    timeout_ms: u64,
+) -> Option<lotar::api_types::AgentPipelineRun> {
+    let start = Instant::now();
+    let mut latest = None;
+    while start.elapsed() < Duration::from_millis(timeout_ms) {
+        latest = AgentPipelineService::status(tasks_dir, ticket_id).expect("pipeline status");
+        if latest.as_ref().is_some_and(|run| run.status == status) {
+            return latest;
+        }
+        sleep(Duration::from_millis(50));
+    }
+    panic!("pipeline did not reach {status}: {latest:?}");
+}
+
+/// Pipelines fan out parallel stages, stop at approval gates, and apply stage transitions.
+#[cfg(unix)]
+#[test]
+fn pipeline_runs_parallel_stages_and_waits_for_approval() {
+    let _guard = lock_agent_tests();
+    enable_server_mode();
+    let fixtures = TestFixtures::new();
+
+    let stage_log = fixtures.get_temp_path().join("stages.log");
+    let script = write_stub_agent_script(
+        fixtures.get_temp_path(),
+        "stage.sh",
+        &format!(
+            "#!/bin/sh\necho \"$LOTAR_AGENT_PROFILE\" >> \"{}\"\nexit 0\n",
+            stage_log.to_string_lossy()
+        ),
+    );
+    let command = script.to_string_lossy();
+    fixtures.create_config_in_dir(
+        &fixtures.tasks_root,
+        &format!(
+            "agents:\n  implement:\n    runner: command\n    command: \"{command}\"\n  test:\n    runner: command\n    command: \"{command}\"\n  publish:\n    runner: command\n    command: \"{command}\"\n\
+pipelines:\n  deliver:\n    stages:\n      - name: implement\n        agent: implement\n        on_start:\n          status: InProgress\n      - name: unit\n        agent: test\n        needs: [implement]\n      - name: lint\n        agent: test\n        needs: [implement]\n      - name: review\n        approval: true\n        needs: [unit, lint]\n        on_start:\n          assignee: lead\n      - name: publish\n        agent: publish\n        needs: [review]\n    on_success:\n      status: Done\n"
+        ),
+    );
+    AutomationService::set(&fixtures.tasks_root, None, "automation:\n  rules: []\n")
+        .expect("set automation");
+
+    let mut storage = fixtures.create_storage();
+    let created = TaskService::create(
+        &mut storage,
+        TaskCreate {
+            title: "Pipeline test".to_string(),
+            project: Some("PIPE".to_string()),
+            ..Default::default()
+        },
+    )
+    .expect("create task");
+
+    AgentPipelineService::start(
+        AgentPipelineRunRequest {
+            ticket_id: created.id.clone(),
+            pipeline: "deliver".to_string(),
+            prompt: None,
+        },
+        &fixtures.tasks_root,
+    )
+    .expect("start pipeline");
+
+    let waiting =
+        wait_for_pipeline_status(&fixtures.tasks_root, &created.id, "awaiting_approval", 5000)
+            .expect("run");
+    let statuses: Vec<(&str, &str)> = waiting
+        .stages
+        .iter()
+        .map(|stage| (stage.name.as_str(), stage.status.as_str()))
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![
+            ("implement", "succeeded"),
+            ("unit", "succeeded"),
+            ("lint", "succeeded"),
+            ("review", "awaiting_approval"),
+            ("publish", "pending"),
+        ]
+    );
+    // Stage transitions are applied after the run state is published
+    let task = wait_for_task_state(&storage, &created.id, "InProgress", Some("lead"), 3000)
+        .expect("review stage transition applied");
+    assert_eq!(task.assignee.as_deref(), Some("lead"));
+
+    let outcome = AgentPipelineService::approve(
+        &AgentPipelineDecisionRequest {
+            ticket_id: created.id.clone(),
+            stage: None,
+            note: Some("ship it".to_string()),
+        },
+        &fixtures.tasks_root,
+        Some("lead"),
+    )
+    .expect("approve");
+    assert!(!outcome.forwarded);
+
+    let finished = wait_for_pipeline_status(&fixtures.tasks_root, &created.id, "completed", 5000)
+        .expect("run");
+    assert!(finished.stages.iter().all(|stage| stage.runs == 1));
+    assert_eq!(
+        finished.stages[3].message.as_deref(),
+        Some("Approved by lead: ship it")
+    );
+    assert!(wait_for_task_status(&storage, &created.id, "Done", 3000));
+
+    let log = fs::read_to_string(&stage_log).expect("read stage log");
+    let mut profiles: Vec<&str> = log.lines().collect();
+    assert_eq!(profiles.first(), Some(&"implement"));
+    assert_eq!(profiles.last(), Some(&"publish"));
+    profiles.sort_unstable();
+    assert_eq!(profiles, vec!["implement", "publish", "test", "test"]);
+}
+
+/// A failing stage with `on_failure.goto` re-runs the target and its downstream stages.
+#[cfg(unix)]
+#[test]
+fn pipeline_failure_goto_rewinds_to_earlier_stage() {
+    let _guard = lock_agent_tests();
+    enable_server_mode();
+    let fixtures = TestFixtures::new();
+
+    let marker = fixtures.get_temp_path().join("tests-failed-once");
+    let implement = write_stub_agent_script(
+        fixtures.get_temp_path(),
+        "implement.sh",
+        "#!/bin/sh\nexit 0\n",
+    );
+    let flaky = write_stub_agent_script(
+        fixtures.get_temp_path(),
+        "flaky-tests.sh",
+        &format!(
+            "#!/bin/sh\nif [ -f \"{marker}\" ]; then exit 0; fi\ntouch \"{marker}\"\necho 'tests failed'\nexit 1\n",
+            marker = marker.to_string_lossy()
+        ),
+    );
+    fixtures.create_config_in_dir(
+        &fixtures.tasks_root,
+        &format!(
+            "agents:\n  implement:\n    runner: command\n    command: \"{}\"\n  test:\n    runner: command\n    command: \"{}\"\n\
+pipelines:\n  fix-loop:\n    max_rewinds: 1\n    stages:\n      - name: implement\n        agent: implement\n      - name: test\n        agent: test\n        needs: [implement]\n        on_failure:\n          goto: implement\n          comment: Tests failed, re-implementing\n",
+            implement.to_string_lossy(),
+            flaky.to_string_lossy()
+        ),
+    );
+    AutomationService::set(&fixtures.tasks_root, None, "automation:\n  rules: []\n")
+        .expect("set automation");
+
+    let mut storage = fixtures.create_storage();
+    let created = TaskService::create(
+        &mut storage,
+        TaskCreate {
+            title: "Rewind test".to_string(),
+            project: Some("LOOP".to_string()),
+            ..Default::default()
+        },
+    )
+    .expect("create task");
+
+    AgentPipelineService::start(
+        AgentPipelineRunRequest {
+            ticket_id: created.id.clone(),
+            pipeline: "fix-loop".to_string(),
+            prompt: Some("fix the bug".to_string()),
+        },
+        &fixtures.tasks_root,
+    )
+    .expect("start pipeline");
+
+    let finished = wait_for_pipeline_status(&fixtures.tasks_root, &created.id, "completed", 8000)
+        .expect("run");
+    assert_eq!(finished.rewinds, 1);
+    assert_eq!(finished.stages[0].runs, 2);
+    assert_eq!(finished.stages[1].runs, 2);
+
+    let task = TaskService::get(&storage, &created.id, None).expect("get task");
+    assert!(
+        task.comments
+            .iter()
+            .any(|comment| comment.text == "Tests failed, re-implementing"),
+        "rewind comment missing: {:?}",
+        task.comments
+    );
+    let run_file = fixtures
+        .tasks_root
+        .join("LOOP")
+        .join(format!("{}.pipeline.json", created.id));
+    assert!(run_file.exists(), "run state should be persisted");
+}
+
+#[cfg(unix)]
+fn exited_pid() -> u32 {
+    let mut child = Command::new("true").spawn().expect("spawn true");
+    let pid = child.id();
+    child.wait().expect("wait for true");
+    pid
+}
+
+#[cfg(unix)]
+fn active_entry(
+    job_id: &str,
+    ticket_id: &str,
+    tasks_dir: &Path,
+    owner_pid: u32,
+    pid: u32,
+) -> lotar::services::agent_log_service::ActiveJobEntry {
+    lotar::services::agent_log_service::ActiveJobEntry {
+        job_id: job_id.to_string(),
+        ticket_id: ticket_id.to_string(),
+        runner: "command".to_string(),
+        agent: None,
+        status: "running".to_string(),
+        created_at: "2026-10-01T10:00:00+00:00".to_string(),
+        started_at: Some("2026-10-01T10:00:01+00:00".to_string()),
+        prompt: "work on it".to_string(),
+        tasks_dir: tasks_dir.to_path_buf(),
+        owner_pid,
+        owner_started_at: None,
+        pid: Some(pid),
+        pid_started_at: None,
+        attempt: 1,
+        worktree_path: None,
+        worktree_branch: None,
+        pipeline: false,
+    }
+}
+
+/// A restarted server reattaches to live runners and fails jobs whose runner died.
+#[cfg(unix)]
+#[test]
+fn persisted_jobs_are_restored_after_restart() {
+    use lotar::services::agent_job_service::AgentJobListFilter;
+    use lotar::services::agent_log_service::AgentLogService;
+    use std::os::unix::process::CommandExt;
+
+    let _guard = lock_agent_tests();
+    enable_server_mode();
+    let fixtures = TestFixtures::new();
+    fixtures.create_config_in_dir(&fixtures.tasks_root, "agent:\n  logs_dir: .agent-logs\n");
+    let workspace_root = fixtures.tasks_root.parent().expect("workspace root");
+
+    let mut storage = fixtures.create_storage();
+    let created = TaskService::create(
+        &mut storage,
+        TaskCreate {
+            title: "Survive restarts".to_string(),
+            project: Some("RST".to_string()),
+            ..Default::default()
+        },
+    )
+    .expect("create task");
+
+    let mut runner = Command::new("sleep")
+        .arg("30")
+        .process_group(0)
+        .spawn()
+        .expect("spawn runner");
+    let dead_owner = exited_pid();
+    let dead_runner = exited_pid();
+
+    for (job_id, pid) in [
+        ("job-restore-alive", runner.id()),
+        ("job-restore-dead", dead_runner),
+    ] {
+        AgentLogService::init_log(
+            workspace_root,
+            Some(".agent-logs"),
+            job_id,
+            &created.id,
+            "command",
+            "2026-10-01T10:00:01+00:00",
+            None,
+            None,
+        )
+        .expect("init log");
+        AgentLogService::save_active(
+            workspace_root,
+            Some(".agent-logs"),
+            &active_entry(job_id, &created.id, &fixtures.tasks_root, dead_owner, pid),
+        )
+        .expect("save active entry");
+    }
+
+    let restored = AgentJobService::restore_jobs(&fixtures.tasks_root).expect("restore jobs");
+    assert_eq!(restored.reattached, vec!["job-restore-alive".to_string()]);
+    assert_eq!(restored.failed, vec!["job-restore-dead".to_string()]);
+
+    let alive = AgentJobService::get_job("job-restore-alive").expect("reattached job");
+    assert_eq!(alive.status, "running");
+
+    let all = AgentJobService::list_job_history(
+        &fixtures.tasks_root,
+        &AgentJobListFilter {
+            ticket_id: Some(created.id.clone()),
+            limit: 1,
+            ..Default::default()
+        },
+    )
+    .expect("list jobs");
+    assert_eq!(all.total, Some(2));
+    assert_eq!(all.jobs.len(), 1);
+
+    let failed = AgentJobService::list_job_history(
+        &fixtures.tasks_root,
+        &AgentJobListFilter {
+            ticket_id: Some(created.id.clone()),
+            status: Some("failed".to_string()),
+            limit: 10,
+            ..Default::default()
+        },
+    )
+    .expect("list failed jobs");
+    assert_eq!(failed.jobs.len(), 1);
+    assert_eq!(failed.jobs[0].id, "job-restore-dead");
+    assert!(
+        failed.jobs[0]
+            .summary
+            .as_deref()
+            .is_some_and(|summary| summary.contains("exited")),
+        "unexpected summary: {:?}",
+        failed.jobs[0].summary
+    );
+
+    AgentJobService::cancel_job("job-restore-alive").expect("cancel reattached job");
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        if runner.try_wait().expect("poll runner").is_some() {
+            break;
+        }
+        assert!(Instant::now() < deadline, "runner should be terminated");
+        sleep(Duration::from_millis(50));
+    }
+
+    let remaining =
+        AgentLogService::load_active(workspace_root, ".agent-logs").expect("load active");
+    assert!(remaining.is_empty(), "active entries left: {:?}", remaining);
+}
+
+/// A live process that reused the owner's pid does not keep its jobs from being restored.
+#[cfg(unix)]
+#[test]
+fn persisted_jobs_are_restored_when_owner_pid_is_reused() {
+    use lotar::services::agent_log_service::AgentLogService;
+    let _guard = lock_agent_tests();
+    enable_server_mode();
+    let fixtures = TestFixtures::new();
+    fixtures.create_config_in_dir(&fixtures.tasks_root, "agent:\n  logs_dir: .agent-logs\n");
+    let workspace_root = fixtures.tasks_root.parent().expect("workspace root");
+
+    let mut storage = fixtures.create_storage();
+    let created = TaskService::create(
+        &mut storage,
+        TaskCreate {
+            title: "Reused owner pid".to_string(),
+            project: Some("RPD".to_string()),
+            ..Default::default()
+        },
+    )
+    .expect("create task");
+
+    let mut impostor = Command::new("sleep")
+        .arg("30")
+        .spawn()
+        .expect("spawn impostor");
+    let mut entry = active_entry(
+        "job-reused-owner",
+        &created.id,
+        &fixtures.tasks_root,
+        impostor.id(),
+        exited_pid(),
+    );
+    // The original owner started long before the process now holding its pid.
+    entry.owner_started_at = Some(1);
+    AgentLogService::init_log(
+        workspace_root,
+        Some(".agent-logs"),
+        &entry.job_id,
+        &created.id,
+        "command",
+        "2026-10-01T10:00:01+00:00",
+        None,
+        None,
+    )
+    .expect("init log");
+    AgentLogService::save_active(workspace_root, Some(".agent-logs"), &entry)
+        .expect("save active entry");
+
+    let restored = AgentJobService::restore_jobs(&fixtures.tasks_root).expect("restore jobs");
+    let _ = impostor.kill();
+    let _ = impostor.wait();
+    assert_eq!(restored.failed, vec!["job-reused-owner".to_string()]);
+}
+
+/// Sandboxed runners only see allowlisted variables, and exceeding `max_output`
+/// fails the job without a retry.
+#[cfg(unix)]
+#[test]
+fn sandboxed_runner_output_limit_fails_job() {
+    let _guard = lock_agent_tests();
+    enable_server_mode();
+    let fixtures = TestFixtures::new();
+
+    let probe = fixtures.get_temp_path().join("sandbox-probe.log");
+    let agent_name = "boxed-cmd";
+    let script = write_stub_agent_script(
+        fixtures.get_temp_path(),
+        "boxed-cmd.sh",
+        &format!(
+            "#!/bin/sh\n\
+echo \"${{HOME:-unset}} ${{LOTAR_TICKET_ID:-missing}}\" >> \"{}\"\n\
+i=0\n\
+while [ $i -lt 2000 ]; do echo \"line $i of runner output\"; i=$((i+1)); done\n\
+sleep 30\n",
+            probe.to_string_lossy()
+        ),
+    );
+
+    fixtures.create_config_in_dir(
+        &fixtures.tasks_root,
+        &format!(
+            "agents:\n  {agent_name}:\n    runner: command\n    command: \"{}\"\n    max_retries: 1\n    retry_backoff: 0s\n    sandbox:\n      env: [PATH]\n      max_output: 4K\n",
+            script.to_string_lossy()
+        ),
+    );
+    AutomationService::set(&fixtures.tasks_root, None, "automation:\n  rules: []\n")
+        .expect("set automation");
+
+    let mut storage = fixtures.create_storage();
+    let created = TaskService::create(
+        &mut storage,
+        TaskCreate {
+            title: "Sandbox test".to_string(),
+            project: Some("BOX".to_string()),
+            ..Default::default()
+        },
+    )
+    .expect("create task");
+
+    let job = AgentJobService::start_job_with_tasks_dir(
+        AgentJobCreateRequest {
+            ticket_id: created.id.clone(),
+            prompt: "flood".to_string(),
+            runner: None,
+            agent: Some(agent_name.to_string()),
+        },
+        fixtures.tasks_root.as_path(),
+    )
+    .expect("start job");
+
+    assert!(
+        wait_for_job_status(&job.id, "failed", 6000),
+        "sandbox did not stop the noisy runner"
+    );
+    let failed = AgentJobService::get_job(&job.id).expect("job exists");
+    assert_eq!(
+        failed.attempt,
+        Some(1),
+        "sandbox violations are not retried"
+    );
+    assert!(
+        failed
+            .summary
+            .as_deref()
+            .is_some_and(|summary| summary.contains("Sandbox: output exceeded the limit of 4K")),
+        "unexpected summary: {:?}",
+        failed.summary
+    );
+
+    let probe_output = fs::read_to_string(&probe).expect("read sandbox probe");
+    assert_eq!(probe_output.trim(), format!("unset {}", created.id));
+}
+
+/// `worktree_only` refuses to start a runner for a job without a worktree.
+#[cfg(unix)]
+#[test]
+fn sandbox_worktree_only_requires_worktree() {
+    let _guard = lock_agent_tests();
+    enable_server_mode();
+    let fixtures = TestFixtures::new();
+
+    let agent_name = "boxed-tree";
+    let script = write_stub_agent_script(
+        fixtures.get_temp_path(),
+        "boxed-tree.sh",
+        "#!/bin/sh\nexit 0\n",
+    );
+    fixtures.create_config_in_dir(
+        &fixtures.tasks_root,
+        &format!(
+            "agents:\n  {agent_name}:\n    runner: command\n    command: \"{}\"\n    sandbox:\n      worktree_only: true\n",
+            script.to_string_lossy()
+        ),
+    );
+    AutomationService::set(&fixtures.tasks_root, None, "automation:\n  rules: []\n")
+        .expect("set automation");
+
+    let mut storage = fixtures.create_storage();
+    let created = TaskService::create(
+        &mut storage,
+        TaskCreate {
+            title: "Worktree sandbox".to_string(),
+            project: Some("BOX".to_string()),
+            ..Default::default()
+        },
+    )
+    .expect("create task");
+
+    let job = AgentJobService::start_job_with_tasks_dir(
+        AgentJobCreateRequest {
+            ticket_id: created.id.clone(),
+            prompt: "noop".to_string(),
+            runner: None,
+            agent: Some(agent_name.to_string()),
+        },
+        fixtures.tasks_root.as_path(),
+    )
+    .expect("start job");
+
+    assert!(
+        wait_for_job_status(&job.id, "failed", 5000),
+        "job without a worktree should fail"
+    );
+    let failed = AgentJobService::get_job(&job.id).expect("job exists");
+    assert!(
+        failed
+            .summary
+            .as_deref()
+            .is_some_and(|summary| summary.contains("Sandbox: worktree_only")),
+        "unexpected summary: {:?}",
+        failed.summary
+    );
+}
+
+/// Sandboxed automation `run` commands get the allowlisted environment plus LOTAR_* variables.
+#[cfg(unix)]
+#[test]
+fn sandboxed_run_action_filters_environment() {
+    let _guard = lock_agent_tests();
+    enable_server_mode();
+    let fixtures = TestFixtures::new();
+
+    let run_log = fixtures.get_temp_path().join("sandbox-run.log");
+    let automation_yaml = format!(
+        "automation:\n  rules:\n    - name: Sandboxed run\n      sandbox:\n        env: [PATH]\n      on:\n        created:\n          run:\n            command: sh\n            args:\n              - \"-c\"\n              - 'echo \"${{HOME:-unset}} $LOTAR_TICKET_ID\" > \"{}\"'\n",
+        run_log.to_string_lossy()
+    );
+    AutomationService::set(&fixtures.tasks_root, None, &automation_yaml).expect("set automation");
+
+    let mut storage = fixtures.create_storage();
+    let created = TaskService::create(
+        &mut storage,
+        TaskCreate {
+            title: "Sandboxed automation".to_string(),
+            project: Some("BOX".to_string()),
+            ..Default::default()
+        },
+    )
+    .expect("create task");
+
+    let run_output = fs::read_to_string(&run_log).expect("read sandboxed run log");
+    assert_eq!(run_output.trim(), format!("unset {}", created.id));
+}
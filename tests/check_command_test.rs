@@ -0,0 +1,151 @@
+use predicates::prelude::*;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::process::Command as ProcCommand;
+
+mod common;
+use common::TestFixtures;
+
+fn run_git(repo: &Path, args: &[&str]) {
+    let out = ProcCommand::new("git")
+        .current_dir(repo)
+        .args(args)
+        .output()
+        .expect("failed to run git");
+    assert!(
+        out.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+fn lotar(root: &Path, args: &[&str]) -> assert_cmd::assert::Assert {
+    let mut cmd = crate::common::lotar_cmd().unwrap();
+    cmd.current_dir(root).args(args).assert()
+}
+
+/// Workspace with DEMO-1 (done), DEMO-2 (open) and a file that exercises every rule.
+fn seeded_workspace() -> TestFixtures {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    lotar(root, &["add", "Finished work", "--project", "DEMO"]).success();
+    lotar(root, &["add", "Open work", "--project", "DEMO"]).success();
+    lotar(root, &["status", "DEMO-1", "Done"]).success();
+    fs::write(
+        root.join("main.rs"),
+        "// TODO: untracked\n// TODO(DEMO-1): already shipped\n// TODO(DEMO-9): typo\n// TODO(DEMO-2): still open\nfn main() {}\n",
+    )
+    .unwrap();
+    tf
+}
+
+#[test]
+fn check_reports_each_rule_and_fails() {
+    let tf = seeded_workspace();
+    let root = tf.temp_dir.path();
+
+    let output = lotar(root, &["--format", "json", "check"])
+        .failure()
+        .stderr(predicate::str::contains("3 TODO finding(s)"))
+        .get_output()
+        .stdout
+        .clone();
+    let payload: Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(payload["status"], "failed");
+    let findings: Vec<(String, u64)> = payload["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| {
+            (
+                f["rule"].as_str().unwrap().to_string(),
+                f["line"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        findings,
+        vec![
+            ("unlinked".to_string(), 1),
+            ("done".to_string(), 2),
+            ("unknown".to_string(), 3),
+        ]
+    );
+}
+
+#[test]
+fn check_fail_on_controls_exit_status_and_levels() {
+    let tf = seeded_workspace();
+    let root = tf.temp_dir.path();
+
+    lotar(root, &["check", "--fail-on", "none"]).success();
+
+    lotar(
+        root,
+        &["check", "--fail-on", "unknown", "--report", "github"],
+    )
+    .failure()
+    .stdout(predicate::str::contains(
+        "::error file=main.rs,line=3,title=lotar/unknown-ticket::",
+    ))
+    .stdout(predicate::str::contains(
+        "::warning file=main.rs,line=1,title=lotar/unlinked-todo::",
+    ));
+
+    let output = lotar(
+        root,
+        &["check", "--report", "sarif", "--fail-on", "done,unknown"],
+    )
+    .failure()
+    .get_output()
+    .stdout
+    .clone();
+    let sarif: Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(sarif["version"], "2.1.0");
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0]["ruleId"], "lotar/unlinked-todo");
+    assert_eq!(results[0]["level"], "warning");
+    assert_eq!(results[1]["level"], "error");
+    assert_eq!(
+        results[1]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "main.rs"
+    );
+}
+
+#[cfg(not(no_git_tests))]
+#[test]
+fn check_diff_modes_only_consider_added_lines() {
+    let tf = seeded_workspace();
+    let root = tf.temp_dir.path();
+    run_git(root, &["init", "-q"]);
+    run_git(root, &["config", "user.name", "Test User"]);
+    run_git(root, &["config", "user.email", "test@example.com"]);
+    run_git(root, &["config", "commit.gpgsign", "false"]);
+    run_git(root, &["add", "-A"]);
+    run_git(root, &["commit", "-q", "-m", "baseline"]);
+
+    lotar(root, &["check", "--base", "HEAD"])
+        .success()
+        .stdout(predicate::str::contains("No unlinked TODOs"));
+
+    let existing = fs::read_to_string(root.join("main.rs")).unwrap();
+    fs::write(
+        root.join("main.rs"),
+        format!("{existing}// TODO: fresh debt\n"),
+    )
+    .unwrap();
+
+    lotar(root, &["check", "--base", "HEAD"])
+        .failure()
+        .stdout(predicate::str::contains("main.rs:6 [lotar/unlinked-todo]"))
+        .stdout(predicate::str::contains("main.rs:1").not());
+
+    lotar(root, &["check", "--staged"]).success();
+    run_git(root, &["add", "main.rs"]);
+    lotar(root, &["check", "--staged"])
+        .failure()
+        .stdout(predicate::str::contains("main.rs:6"));
+}
@@ -0,0 +1,58 @@
+use predicates::prelude::*;
+use std::fs;
+
+mod common;
+use common::TestFixtures;
+
+#[test]
+fn cli_changes_from_agent_jobs_go_through_approval_gates() {
+    let fixtures = TestFixtures::new();
+    fs::write(
+        fixtures.tasks_root.join("config.yml"),
+        "default.project: GATE\nagent:\n  approvals:\n    - operation: status\n      to: [Done]\n    - operation: delete\n      action: deny\n",
+    )
+    .unwrap();
+
+    common::cargo_bin_in(&fixtures)
+        .args(["add", "Gated task"])
+        .assert()
+        .success();
+
+    let agent = || {
+        let mut cmd = common::cargo_bin_in(&fixtures);
+        cmd.env("LOTAR_AGENT_JOB_ID", "job-cli-gate")
+            .env("LOTAR_AGENT_PROFILE", "implement");
+        cmd
+    };
+
+    // Rules that do not match let the change through
+    agent()
+        .args(["status", "GATE-1", "InProgress"])
+        .assert()
+        .success();
+
+    agent()
+        .args(["status", "GATE-1", "Done"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Parked as approval #1 until a human approves it",
+        ));
+    let task = fs::read_to_string(fixtures.tasks_root.join("GATE/1.yml")).unwrap();
+    assert!(task.contains("status: InProgress"), "{task}");
+    let approval = fs::read_to_string(fixtures.tasks_root.join("@approvals/1.yml")).unwrap();
+    assert!(approval.contains("job_id: job-cli-gate"), "{approval}");
+
+    agent()
+        .args(["task", "delete", "GATE-1", "--yes"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Operation not permitted"));
+    assert!(fixtures.tasks_root.join("GATE/1.yml").exists());
+
+    // Humans are never gated
+    common::cargo_bin_in(&fixtures)
+        .args(["status", "GATE-1", "Done"])
+        .assert()
+        .success();
+}
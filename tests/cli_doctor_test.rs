@@ -0,0 +1,306 @@
+use lotar::utils::paths;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fs;
+
+mod common;
+
+const ORPHAN_ATTACHMENT: &str = "screenshot-0123456789abcdef0123456789abcdef.png";
+
+fn write_damaged_tree(fixtures: &common::TestFixtures) {
+    fs::write(
+        paths::global_config_path(&fixtures.tasks_root),
+        "default.project: AUTH\n",
+    )
+    .unwrap();
+    let project = fixtures.tasks_root.join("AUTH");
+    fs::create_dir_all(&project).unwrap();
+    fs::write(
+        project.join("1.yml"),
+        "title: Login fails\nstatus: Parked\ntype: Bug\ncreated: 2024-01-01T00:00:00Z\n\
+         relationships:\n  depends_on: [AUTH-9]\n  related: [AUTH-2]\nsprints: [4]\n",
+    )
+    .unwrap();
+    fs::write(
+        project.join("2.yml"),
+        "title: Broken\n<<<<<<< HEAD\nstatus: Todo\n=======\nstatus: Done\n>>>>>>> feature\n",
+    )
+    .unwrap();
+    fs::write(
+        project.join("03.yml"),
+        "title: Padded name\nstatus: Todo\ntype: Feature\ncreated: 2024-01-01T00:00:00Z\n",
+    )
+    .unwrap();
+    fs::write(project.join("0.yml"), "title: Zero\ncreated: 2024-01-01\n").unwrap();
+
+    let attachments = fixtures.tasks_root.join("@attachments");
+    fs::create_dir_all(&attachments).unwrap();
+    fs::write(attachments.join(ORPHAN_ATTACHMENT), b"png").unwrap();
+}
+
+fn finding_checks(report: &Value) -> HashSet<String> {
+    report["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["check"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn doctor_reports_integrity_problems_without_changing_files() {
+    let fixtures = common::TestFixtures::new();
+    write_damaged_tree(&fixtures);
+
+    let output = common::cargo_bin_in(&fixtures)
+        .args(["--format", "json", "doctor"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let report: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["status"], "failed");
+    assert_eq!(report["action"], "doctor");
+    assert_eq!(report["fixed"], 0);
+
+    let checks = finding_checks(&report);
+    for expected in [
+        "parse_error",
+        "invalid_id",
+        "invalid_status",
+        "dangling_relationship",
+        "dangling_sprint",
+        "orphan_attachment",
+    ] {
+        assert!(checks.contains(expected), "missing {expected} in {report}");
+    }
+
+    // A task that fails to parse still exists, so references to it are not dangling.
+    let dangling: Vec<&str> = report["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|f| f["check"] == "dangling_relationship")
+        .map(|f| f["message"].as_str().unwrap())
+        .collect();
+    assert_eq!(dangling, vec!["depends_on references missing task AUTH-9"]);
+
+    assert!(fixtures.tasks_root.join("AUTH/03.yml").exists());
+    assert!(
+        fixtures
+            .tasks_root
+            .join("@attachments")
+            .join(ORPHAN_ATTACHMENT)
+            .exists()
+    );
+}
+
+#[test]
+fn doctor_fix_applies_safe_repairs_only() {
+    let fixtures = common::TestFixtures::new();
+    write_damaged_tree(&fixtures);
+
+    common::cargo_bin_in(&fixtures)
+        .args(["doctor", "--fix"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("✅ [dangling_sprint] AUTH-1"))
+        .stdout(predicate::str::contains("❌ [parse_error] AUTH-2"))
+        .stdout(predicate::str::contains("Repaired"));
+
+    let task = fs::read_to_string(fixtures.tasks_root.join("AUTH/1.yml")).unwrap();
+    assert!(!task.contains("AUTH-9"), "{task}");
+    assert!(task.contains("AUTH-2"), "{task}");
+    assert!(!task.contains("sprints"), "{task}");
+    assert!(fixtures.tasks_root.join("AUTH/3.yml").exists());
+    assert!(!fixtures.tasks_root.join("AUTH/03.yml").exists());
+    // Unsafe problems are left for a human; attachments stay while a task is unreadable.
+    assert!(
+        fixtures
+            .tasks_root
+            .join("@attachments")
+            .join(ORPHAN_ATTACHMENT)
+            .exists()
+    );
+    assert!(fixtures.tasks_root.join("AUTH/2.yml").exists());
+    assert!(fixtures.tasks_root.join("AUTH/0.yml").exists());
+
+    let output = common::cargo_bin_in(&fixtures)
+        .args(["--format", "json", "doctor"])
+        .output()
+        .unwrap();
+    let report: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let checks = finding_checks(&report);
+    assert!(!checks.contains("dangling_relationship"), "{report}");
+    assert!(!checks.contains("dangling_sprint"), "{report}");
+    assert!(checks.contains("orphan_attachment"), "{report}");
+}
+
+#[test]
+fn doctor_fix_keeps_attachments_of_unreadable_tasks() {
+    let fixtures = common::TestFixtures::new();
+    let attachment = "log.0123456789abcdef0123456789abcdef.txt";
+    let project = fixtures.tasks_root.join("AUTH");
+    fs::create_dir_all(&project).unwrap();
+    fs::write(
+        project.join("1.yml"),
+        format!(
+            "<<<<<<< ours\ntitle: Crash log\nstatus: Todo\ncreated: 2024-01-01T00:00:00Z\n\
+             references:\n  - file: \"@attachments/{attachment}\"\n"
+        ),
+    )
+    .unwrap();
+    let attachments = fixtures.tasks_root.join("@attachments");
+    fs::create_dir_all(&attachments).unwrap();
+    fs::write(attachments.join(attachment), b"log").unwrap();
+
+    let output = common::cargo_bin_in(&fixtures)
+        .args(["--format", "json", "doctor", "--fix"])
+        .output()
+        .unwrap();
+    let report: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(finding_checks(&report).contains("parse_error"), "{report}");
+    assert!(attachments.join(attachment).exists(), "{report}");
+}
+
+#[test]
+fn doctor_fix_removes_orphans_from_project_attachment_dirs() {
+    let fixtures = common::TestFixtures::new();
+    common::cargo_bin_in(&fixtures)
+        .args(["add", "A healthy task", "--project", "AUTH"])
+        .assert()
+        .success();
+    fs::write(
+        paths::project_config_path(&fixtures.tasks_root, "AUTH"),
+        "project:\n  name: AUTH\nattachments:\n  dir: \"@auth-files\"\n",
+    )
+    .unwrap();
+    let attachments = fixtures.tasks_root.join("@auth-files");
+    fs::create_dir_all(&attachments).unwrap();
+    fs::write(attachments.join(ORPHAN_ATTACHMENT), b"png").unwrap();
+
+    common::cargo_bin_in(&fixtures)
+        .args(["doctor", "--fix"])
+        .assert()
+        .stdout(predicate::str::contains("[orphan_attachment]"));
+    assert!(!attachments.join(ORPHAN_ATTACHMENT).exists());
+}
+
+#[test]
+fn doctor_passes_on_a_clean_store() {
+    let fixtures = common::TestFixtures::new();
+
+    common::cargo_bin_in(&fixtures)
+        .args(["add", "A healthy task"])
+        .assert()
+        .success();
+
+    common::cargo_bin_in(&fixtures)
+        .arg("doctor")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "No integrity problems found (1 task(s) scanned)",
+        ));
+}
+
+#[test]
+fn doctor_prunes_sprint_entries_for_missing_tasks() {
+    let fixtures = common::TestFixtures::new();
+    common::cargo_bin_in(&fixtures)
+        .args(["add", "Kept", "--project", "AUTH"])
+        .assert()
+        .success();
+    let sprints = fixtures.tasks_root.join("@sprints");
+    fs::create_dir_all(&sprints).unwrap();
+    fs::write(
+        sprints.join("1.yml"),
+        "plan:\n  label: Sprint 1\ntasks:\n- AUTH-1\n- AUTH-42\n",
+    )
+    .unwrap();
+
+    common::cargo_bin_in(&fixtures)
+        .args(["doctor"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Sprint #1 lists missing task AUTH-42",
+        ));
+
+    common::cargo_bin_in(&fixtures)
+        .args(["doctor", "--fix"])
+        .assert()
+        .stdout(predicate::str::contains("✅ [dangling_sprint] AUTH-42"));
+    let sprint = fs::read_to_string(sprints.join("1.yml")).unwrap();
+    assert!(sprint.contains("AUTH-1"), "{sprint}");
+    assert!(!sprint.contains("AUTH-42"), "{sprint}");
+}
+
+fn git(dir: &std::path::Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .status()
+        .expect("run git");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+#[test]
+fn doctor_fix_keeps_dirty_worktrees_of_done_tickets() {
+    let fixtures = common::TestFixtures::new();
+    let root = fixtures.temp_dir.path();
+    git(root, &["init", "-q"]);
+    git(root, &["config", "user.name", "Doctor Tests"]);
+    git(root, &["config", "user.email", "doctor@example.com"]);
+    git(root, &["config", "commit.gpgsign", "false"]);
+    fs::write(root.join("README.md"), "repo\n").unwrap();
+    git(root, &["add", "README.md"]);
+    git(root, &["commit", "-q", "-m", "init"]);
+
+    for title in ["Dirty", "Clean"] {
+        common::cargo_bin_in(&fixtures)
+            .args(["add", title, "--project", "AUTH"])
+            .assert()
+            .success();
+    }
+    for id in ["AUTH-1", "AUTH-2"] {
+        common::cargo_bin_in(&fixtures)
+            .args(["status", id, "Done"])
+            .assert()
+            .success();
+    }
+
+    let trees = tempfile::tempdir().unwrap();
+    let dirty = trees.path().join("dirty");
+    let clean = trees.path().join("clean");
+    git(
+        root,
+        &[
+            "worktree",
+            "add",
+            "-q",
+            "-b",
+            "agent/AUTH-1",
+            dirty.to_str().unwrap(),
+        ],
+    );
+    git(
+        root,
+        &[
+            "worktree",
+            "add",
+            "-q",
+            "-b",
+            "agent/AUTH-2",
+            clean.to_str().unwrap(),
+        ],
+    );
+    fs::write(dirty.join("work.rs"), "// unfinished\n").unwrap();
+
+    common::cargo_bin_in(&fixtures)
+        .args(["doctor", "--fix"])
+        .assert()
+        .stdout(predicate::str::contains("uncommitted changes"));
+    assert!(dirty.join("work.rs").exists(), "dirty worktree was removed");
+    assert!(!clean.exists(), "clean stale worktree should be removed");
+}
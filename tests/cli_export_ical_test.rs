@@ -0,0 +1,192 @@
+use lotar::api_server::{ApiServer, HttpRequest};
+use lotar::api_types::{TaskCreate, TaskUpdate};
+use lotar::routes;
+use lotar::services::sprint_service::SprintService;
+use lotar::services::task_service::TaskService;
+use lotar::storage::manager::Storage;
+use lotar::storage::sprint::{Sprint, SprintPlan};
+use lotar::types::TaskStatus;
+use lotar::utils::paths;
+use serde_json::Value;
+use std::collections::HashMap;
+
+mod common;
+use crate::common::env_mutex::EnvVarGuard;
+
+fn create_task(
+    storage: &mut Storage,
+    title: &str,
+    assignee: Option<&str>,
+    due: Option<&str>,
+    tags: &[&str],
+) -> String {
+    TaskService::create(
+        storage,
+        TaskCreate {
+            title: title.to_string(),
+            project: Some("TEST".to_string()),
+            priority: None,
+            task_type: None,
+            reporter: None,
+            assignee: assignee.map(str::to_string),
+            due_date: due.map(str::to_string),
+            effort: None,
+            description: None,
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            relationships: None,
+            custom_fields: None,
+            sprints: Vec::new(),
+        },
+    )
+    .expect("create task")
+    .id
+}
+
+fn seed(tasks_root: &std::path::Path) -> (String, String, String) {
+    std::fs::write(
+        paths::global_config_path(tasks_root),
+        r#"
+default.project: TEST
+default.reporter: alice
+issue.states: [Todo, InProgress, Done]
+"#,
+    )
+    .expect("write config");
+    let mut storage = Storage::new(tasks_root);
+    let mine = create_task(
+        &mut storage,
+        "Ship release; notes, docs",
+        Some("alice"),
+        Some("2026-03-10"),
+        &["release"],
+    );
+    let other = create_task(
+        &mut storage,
+        "Review budget",
+        Some("bob"),
+        Some("2026-03-12"),
+        &[],
+    );
+    let done = create_task(
+        &mut storage,
+        "Old deadline",
+        Some("alice"),
+        Some("2026-02-01"),
+        &[],
+    );
+    TaskService::update(
+        &mut storage,
+        &done,
+        TaskUpdate {
+            status: Some(TaskStatus::from("Done")),
+            ..TaskUpdate::default()
+        },
+    )
+    .expect("close task");
+    create_task(&mut storage, "No deadline", Some("alice"), None, &[]);
+    SprintService::create(
+        &mut storage,
+        Sprint {
+            plan: Some(SprintPlan {
+                label: Some("Sprint Alpha".to_string()),
+                goal: Some("Release candidate".to_string()),
+                starts_at: Some("2026-03-02T09:00:00Z".to_string()),
+                ends_at: Some("2026-03-16T17:00:00Z".to_string()),
+                ..SprintPlan::default()
+            }),
+            ..Sprint::default()
+        },
+        None,
+    )
+    .expect("create sprint");
+    (mine, other, done)
+}
+
+#[test]
+fn export_ical_writes_due_dates_and_sprints() {
+    let fixtures = common::TestFixtures::new();
+    let (mine, other, done) = seed(&fixtures.tasks_root);
+
+    let output = common::cargo_bin_in(&fixtures)
+        .args(["export", "ical"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let ics = String::from_utf8(output).expect("utf8 calendar");
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"), "{ics}");
+    assert!(ics.ends_with("END:VCALENDAR\r\n"), "{ics}");
+    assert!(ics.contains(&format!("UID:{}@lotar", mine)), "{ics}");
+    assert!(ics.contains(&format!("UID:{}@lotar", other)), "{ics}");
+    assert!(!ics.contains(&format!("UID:{}@lotar", done)), "{ics}");
+    assert!(ics.contains("DTSTART;VALUE=DATE:20260310\r\nDTEND;VALUE=DATE:20260311"));
+    assert!(ics.contains(r"Ship release\; notes\, docs"), "{ics}");
+    assert!(ics.contains("CATEGORIES:release"), "{ics}");
+    assert!(ics.contains("SUMMARY:Sprint Alpha"), "{ics}");
+    assert!(ics.contains("DTSTART:20260302T090000Z\r\nDTEND:20260316T170000Z"));
+    assert!(ics.contains("Release candidate"), "{ics}");
+    assert!(!ics.contains("No deadline"), "{ics}");
+
+    let path = fixtures.temp_dir.path().join("mine.ics");
+    let output = common::cargo_bin_in(&fixtures)
+        .args([
+            "--format",
+            "json",
+            "export",
+            "ical",
+            "--assignee",
+            "@me",
+            "--todo",
+            "--no-sprints",
+            "--include-done",
+            "--output",
+            path.to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let payload: Value = serde_json::from_slice(&output).expect("parse export json");
+    assert_eq!(payload["action"], "export.ical");
+    assert_eq!(payload["tasks"].as_u64(), Some(2));
+    assert_eq!(payload["sprints"].as_u64(), Some(0));
+    let ics = std::fs::read_to_string(&path).expect("read calendar");
+    assert!(ics.contains("BEGIN:VTODO"), "{ics}");
+    assert!(ics.contains("DUE;VALUE=DATE:20260310"), "{ics}");
+    assert!(ics.contains("STATUS:COMPLETED"), "{ics}");
+    assert!(!ics.contains(&format!("UID:{}@lotar", other)), "{ics}");
+    assert!(!ics.contains("VEVENT"), "{ics}");
+}
+
+#[test]
+fn calendar_endpoint_serves_filtered_feed() {
+    let fixtures = common::TestFixtures::new();
+    let (mine, other, _) = seed(&fixtures.tasks_root);
+    let _guard_tasks = EnvVarGuard::set("LOTAR_TASKS_DIR", &fixtures.tasks_root.to_string_lossy());
+
+    let mut api = ApiServer::new();
+    routes::initialize(&mut api);
+    let mut query = HashMap::new();
+    query.insert("project".to_string(), "TEST".to_string());
+    query.insert("tags".to_string(), "release".to_string());
+    query.insert("sprints".to_string(), "false".to_string());
+    let resp = api.handle_request(&HttpRequest {
+        method: "GET".to_string(),
+        path: "/api/calendar.ics".to_string(),
+        query,
+        headers: HashMap::new(),
+        body: Vec::new(),
+    });
+    assert_eq!(resp.status, 200);
+    assert!(
+        resp.headers
+            .iter()
+            .any(|(k, v)| k == "Content-Type" && v.starts_with("text/calendar"))
+    );
+    let ics = String::from_utf8(resp.body).expect("utf8 calendar");
+    assert!(ics.contains(&format!("UID:{}@lotar", mine)), "{ics}");
+    assert!(!ics.contains(&format!("UID:{}@lotar", other)), "{ics}");
+    assert!(!ics.contains("Sprint Alpha"), "{ics}");
+}
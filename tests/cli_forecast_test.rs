@@ -0,0 +1,224 @@
+use lotar::api_types::{TaskCreate, TaskUpdate};
+use lotar::services::sprint_service::SprintService;
+use lotar::services::task_service::TaskService;
+use lotar::storage::manager::Storage;
+use lotar::storage::sprint::{Sprint, SprintActual, SprintPlan};
+use lotar::types::{TaskRelationships, TaskStatus, TaskType};
+use lotar::utils::paths;
+use serde_json::Value;
+
+mod common;
+
+fn create_task(
+    storage: &mut Storage,
+    title: &str,
+    task_type: &str,
+    status: &str,
+    parent: Option<&str>,
+    sprint_id: Option<u32>,
+) -> String {
+    let task = TaskService::create(
+        storage,
+        TaskCreate {
+            title: title.to_string(),
+            project: Some("TEST".to_string()),
+            priority: None,
+            task_type: Some(TaskType::from(task_type)),
+            reporter: None,
+            assignee: None,
+            due_date: None,
+            effort: None,
+            description: None,
+            tags: Vec::new(),
+            relationships: parent.map(|id| TaskRelationships {
+                parent: Some(id.to_string()),
+                ..TaskRelationships::default()
+            }),
+            custom_fields: None,
+            sprints: sprint_id.into_iter().collect(),
+        },
+    )
+    .expect("create task");
+
+    TaskService::update(
+        storage,
+        &task.id,
+        TaskUpdate {
+            status: Some(TaskStatus::from(status)),
+            ..TaskUpdate::default()
+        },
+    )
+    .expect("update task");
+
+    task.id
+}
+
+fn closed_sprint(storage: &mut Storage, label: &str, start: &str, end: &str) -> u32 {
+    let created = SprintService::create(
+        storage,
+        Sprint {
+            plan: Some(SprintPlan {
+                label: Some(label.to_string()),
+                starts_at: Some(start.to_string()),
+                ends_at: Some(end.to_string()),
+                ..SprintPlan::default()
+            }),
+            ..Sprint::default()
+        },
+        None,
+    )
+    .expect("create sprint");
+    let id = created.record.id;
+    let mut sprint = created.record.sprint;
+    sprint.actual = Some(SprintActual {
+        started_at: Some(start.to_string()),
+        closed_at: Some(end.to_string()),
+        ..SprintActual::default()
+    });
+    SprintService::update(storage, id, sprint).expect("close sprint");
+    id
+}
+
+#[test]
+fn forecast_epic_from_sprint_history_reports_percentiles_and_target() {
+    let fixtures = common::TestFixtures::new();
+    std::fs::write(
+        paths::global_config_path(&fixtures.tasks_root),
+        r#"
+default.project: TEST
+issue.states: [Todo, InProgress, Done]
+issue.types: [Epic, Feature, Bug]
+issue.priorities: [Low, Medium, High]
+"#,
+    )
+    .expect("write config");
+    let mut storage = Storage::new(&fixtures.tasks_root.clone());
+
+    // Two closed two-week sprints that each finished two tasks.
+    let first = closed_sprint(
+        &mut storage,
+        "Sprint 1",
+        "2025-09-01T00:00:00Z",
+        "2025-09-15T00:00:00Z",
+    );
+    let second = closed_sprint(
+        &mut storage,
+        "Sprint 2",
+        "2025-09-15T00:00:00Z",
+        "2025-09-29T00:00:00Z",
+    );
+    for sprint in [first, second] {
+        create_task(
+            &mut storage,
+            "Shipped",
+            "Feature",
+            "Done",
+            None,
+            Some(sprint),
+        );
+        create_task(&mut storage, "Shipped", "Bug", "Done", None, Some(sprint));
+    }
+
+    let epic = create_task(&mut storage, "Checkout", "Epic", "Todo", None, None);
+    let story = create_task(&mut storage, "Cart", "Feature", "Todo", Some(&epic), None);
+    create_task(
+        &mut storage,
+        "Cart bug",
+        "Bug",
+        "InProgress",
+        Some(&story),
+        None,
+    );
+    create_task(
+        &mut storage,
+        "Payments",
+        "Feature",
+        "Todo",
+        Some(&epic),
+        None,
+    );
+    create_task(&mut storage, "Paid", "Feature", "Done", Some(&epic), None);
+    create_task(&mut storage, "Unrelated", "Feature", "Todo", None, None);
+
+    let output = common::cargo_bin_in(&fixtures)
+        .args([
+            "--format", "json", "forecast", "--epic", &epic, "--target", "+52w", "--seed", "7",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let payload: Value = serde_json::from_slice(&output).expect("parse forecast json");
+
+    assert_eq!(payload["status"], "ok");
+    assert_eq!(payload["action"], "forecast");
+    assert_eq!(payload["basis"], "sprint");
+    assert_eq!(payload["metric"], "tasks");
+    // Descendants only: the nested bug counts, the epic itself and unrelated work do not.
+    assert_eq!(payload["selected"].as_u64(), Some(4));
+    assert_eq!(payload["remaining_tasks"].as_u64(), Some(3));
+    assert_eq!(payload["history"], serde_json::json!([2.0, 2.0]));
+    assert_eq!(payload["period_days"].as_f64(), Some(14.0));
+    let percentiles = payload["percentiles"].as_array().expect("percentiles");
+    assert_eq!(percentiles.len(), 4);
+    assert!(
+        percentiles
+            .iter()
+            .all(|entry| entry["periods"].as_u64() == Some(2))
+    );
+    assert_eq!(payload["target"]["probability"].as_f64(), Some(1.0));
+    assert!(
+        payload["warnings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|w| w.as_str().unwrap_or_default().contains("history"))
+    );
+
+    let output = common::cargo_bin_in(&fixtures)
+        .args(["forecast", "--target", "today"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let text = String::from_utf8_lossy(&output);
+    assert!(text.contains("Forecast for 5 open task(s)"), "{text}");
+    assert!(text.contains("Target"), "{text}");
+    assert!(text.contains(": 0% likely"), "{text}");
+}
+
+#[test]
+fn forecast_by_week_without_history_fails() {
+    let fixtures = common::TestFixtures::new();
+    let mut storage = Storage::new(&fixtures.tasks_root.clone());
+    create_task(&mut storage, "Open", "Feature", "Todo", None, None);
+
+    common::cargo_bin_in(&fixtures)
+        .args(["--project", "TEST", "forecast", "--by", "week"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("nothing to base a forecast on"));
+}
+
+#[test]
+fn forecast_rejects_oversized_iterations() {
+    let fixtures = common::TestFixtures::new();
+    let mut storage = Storage::new(&fixtures.tasks_root.clone());
+    create_task(&mut storage, "Open", "Feature", "Todo", None, None);
+
+    common::cargo_bin_in(&fixtures)
+        .args([
+            "--project",
+            "TEST",
+            "forecast",
+            "--iterations",
+            "1000000000000",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "iterations must be at most 100000",
+        ));
+}
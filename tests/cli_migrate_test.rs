@@ -0,0 +1,106 @@
+use lotar::storage::manager::Storage;
+use lotar::utils::paths;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::fs;
+
+mod common;
+
+const LEGACY_TASK: &str = r#"title: Login fails
+subtitle: Only on Safari
+status: Todo
+priority: High
+task_type: Bug
+created: 2024-01-01T00:00:00Z
+modified: 2024-01-02T00:00:00Z
+description: Steps to reproduce below.
+"#;
+
+const LEGACY_PROJECT_CONFIG: &str =
+    "project_name: Authentication\ntaxonomy:\n  tags: [auth, web]\n  categories: [backend]\n";
+
+fn write_legacy_tree(fixtures: &common::TestFixtures) {
+    fs::write(
+        paths::global_config_path(&fixtures.tasks_root),
+        "default.project: AUTH\n",
+    )
+    .unwrap();
+    let project = fixtures.tasks_root.join("AUTH");
+    fs::create_dir_all(&project).unwrap();
+    fs::write(project.join("config.yml"), LEGACY_PROJECT_CONFIG).unwrap();
+    fs::write(project.join("1.yml"), LEGACY_TASK).unwrap();
+}
+
+#[test]
+fn migrate_dry_run_shows_diff_without_writing() {
+    let fixtures = common::TestFixtures::new();
+    write_legacy_tree(&fixtures);
+
+    common::cargo_bin_in(&fixtures)
+        .args(["migrate", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Would migrate AUTH/1.yml (v0 -> v1): task-legacy-fields",
+        ))
+        .stdout(predicate::str::contains("- task_type: Bug"))
+        .stdout(predicate::str::contains("+ type: Bug"))
+        .stdout(predicate::str::contains(
+            "Would migrate AUTH/config.yml (v0 -> v1): config-legacy-keys",
+        ))
+        .stdout(predicate::str::contains("3 of 3 file(s) need migration"));
+
+    let task = fs::read_to_string(fixtures.tasks_root.join("AUTH/1.yml")).unwrap();
+    assert_eq!(task, LEGACY_TASK);
+    assert!(!fixtures.tasks_root.join(".backups").exists());
+}
+
+#[test]
+fn migrate_rewrites_files_and_keeps_backups() {
+    let fixtures = common::TestFixtures::new();
+    write_legacy_tree(&fixtures);
+
+    let output = common::cargo_bin_in(&fixtures)
+        .args(["--format", "json", "migrate"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let report: Value = serde_json::from_slice(&output).expect("parse migrate json");
+    assert_eq!(report["status"], "ok");
+    assert_eq!(report["action"], "migrate");
+    assert_eq!(report["scanned"], 3);
+    assert_eq!(report["files"].as_array().unwrap().len(), 3);
+    let backup_dir = report["backup_dir"].as_str().expect("backup dir");
+    let backup = fs::read_to_string(std::path::Path::new(backup_dir).join("AUTH/1.yml")).unwrap();
+    assert_eq!(backup, LEGACY_TASK);
+
+    let task = fs::read_to_string(fixtures.tasks_root.join("AUTH/1.yml")).unwrap();
+    assert!(task.starts_with("schema_version: 1\n"), "{task}");
+    assert!(
+        !task.contains("task_type") && !task.contains("subtitle"),
+        "{task}"
+    );
+    let storage = Storage::new(&fixtures.tasks_root);
+    let loaded = storage.get("AUTH-1", "AUTH").expect("task loads");
+    assert_eq!(loaded.task_type.to_string(), "Bug");
+    assert_eq!(
+        loaded.description.as_deref(),
+        Some("Only on Safari\n\nSteps to reproduce below.")
+    );
+
+    let config = fs::read_to_string(fixtures.tasks_root.join("AUTH/config.yml")).unwrap();
+    assert!(config.starts_with("schema_version: 1\n"), "{config}");
+    assert!(config.contains("project.name: Authentication"), "{config}");
+    assert!(config.contains("issue.tags:\n- auth\n- web"), "{config}");
+    assert!(!config.contains("taxonomy"), "{config}");
+
+    common::cargo_bin_in(&fixtures)
+        .arg("migrate")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "All 3 file(s) are already at the current schema version",
+        ));
+}
@@ -0,0 +1,151 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use std::process::{Command as ProcCommand, Output};
+
+mod common;
+use common::TestFixtures;
+
+fn git(repo: &Path, args: &[&str]) -> Output {
+    let bin_dir = Path::new(env!("CARGO_BIN_EXE_lotar")).parent().unwrap();
+    let path = std::env::join_paths(std::iter::once(bin_dir.to_path_buf()).chain(
+        std::env::split_paths(&std::env::var_os("PATH").unwrap_or_default()),
+    ))
+    .unwrap();
+    ProcCommand::new("git")
+        .current_dir(repo)
+        .env("PATH", path)
+        .env("LOTAR_IGNORE_HOME_CONFIG", "1")
+        .args(args)
+        .output()
+        .expect("failed to run git")
+}
+
+fn run_git(repo: &Path, args: &[&str]) -> String {
+    let out = git(repo, args);
+    assert!(
+        out.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).trim().to_string()
+}
+
+fn lotar(root: &Path, args: &[&str]) -> assert_cmd::assert::Assert {
+    let mut cmd = crate::common::lotar_cmd().unwrap();
+    cmd.current_dir(root).args(args).assert()
+}
+
+#[test]
+fn renumber_moves_task_and_rewrites_references() {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    let project = tf.tasks_root.join("AUTH");
+    fs::create_dir_all(&project).unwrap();
+    fs::write(
+        project.join("1.yml"),
+        "title: Login\ncreated: 2024-01-01T00:00:00Z\n",
+    )
+    .unwrap();
+    fs::write(
+        project.join("2.yml"),
+        "title: Session\ncreated: 2024-01-02T00:00:00Z\nrelationships:\n  depends_on: [AUTH-1]\n  parent: '1'\n",
+    )
+    .unwrap();
+    let sprints = tf.tasks_root.join("@sprints");
+    fs::create_dir_all(&sprints).unwrap();
+    fs::write(
+        sprints.join("1.yml"),
+        "plan:\n  label: Sprint 1\ntasks:\n- id: AUTH-1\n- id: AUTH-2\n",
+    )
+    .unwrap();
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(
+        root.join("src/login.rs"),
+        "// TODO(AUTH-1): handle expiry\n// see AUTH-10 as well\nfn login() {}\n",
+    )
+    .unwrap();
+
+    lotar(root, &["renumber", "AUTH-1", "--to", "5", "--dry-run"])
+        .success()
+        .stdout(predicate::str::contains("Would renumber AUTH-1 → AUTH-5"))
+        .stdout(predicate::str::contains("1 reference(s) in src/login.rs"));
+    assert!(project.join("1.yml").exists());
+
+    lotar(root, &["renumber", "AUTH-1", "--to", "5"])
+        .success()
+        .stdout(predicate::str::contains("Renumbered AUTH-1 → AUTH-5"));
+
+    assert!(!project.join("1.yml").exists());
+    let moved = fs::read_to_string(project.join("5.yml")).unwrap();
+    assert!(moved.contains("title: Login"), "{moved}");
+    assert!(moved.contains("field: id"), "{moved}");
+    assert!(moved.contains("new: AUTH-5"), "{moved}");
+
+    let dependent = fs::read_to_string(project.join("2.yml")).unwrap();
+    assert!(!dependent.contains("AUTH-1"), "{dependent}");
+    assert_eq!(dependent.matches("AUTH-5").count(), 2, "{dependent}");
+
+    let sprint = fs::read_to_string(sprints.join("1.yml")).unwrap();
+    assert!(sprint.contains("- AUTH-5"), "{sprint}");
+    assert!(sprint.contains("- AUTH-2"), "{sprint}");
+
+    let source = fs::read_to_string(root.join("src/login.rs")).unwrap();
+    assert!(source.contains("TODO(AUTH-5)"), "{source}");
+    assert!(source.contains("AUTH-10"), "{source}");
+
+    lotar(root, &["renumber", "AUTH-2", "--to", "5"])
+        .failure()
+        .stderr(predicate::str::contains("AUTH-5 already exists"));
+}
+
+#[cfg(not(no_git_tests))]
+#[test]
+fn renumber_splits_tasks_created_on_both_branches() {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    run_git(root, &["init", "-q"]);
+    run_git(root, &["config", "user.name", "Test User"]);
+    run_git(root, &["config", "user.email", "test@example.com"]);
+    run_git(root, &["config", "commit.gpgsign", "false"]);
+    lotar(root, &["git", "install-merge-driver"]).success();
+    lotar(root, &["add", "Baseline", "--project", "AUTH"]).success();
+    run_git(root, &["add", "-A"]);
+    run_git(root, &["commit", "-q", "-m", "baseline"]);
+    let main = run_git(root, &["rev-parse", "--abbrev-ref", "HEAD"]);
+
+    run_git(root, &["checkout", "-q", "-b", "feature"]);
+    lotar(root, &["add", "Feature task", "--project", "AUTH"]).success();
+    run_git(root, &["add", "-A"]);
+    run_git(root, &["commit", "-q", "-m", "feature task"]);
+
+    run_git(root, &["checkout", "-q", &main]);
+    // Distinct creation timestamps, as on two machines.
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    lotar(root, &["add", "Main task", "--project", "AUTH"]).success();
+    run_git(root, &["add", "-A"]);
+    run_git(root, &["commit", "-q", "-m", "main task"]);
+
+    let merge = git(root, &["merge", "--no-edit", "feature"]);
+    assert!(!merge.status.success(), "both branches added AUTH-2");
+    let combined = String::from_utf8_lossy(&merge.stdout).to_string()
+        + &String::from_utf8_lossy(&merge.stderr);
+    assert!(combined.contains("lotar renumber"), "{combined}");
+
+    lotar(root, &["renumber"])
+        .success()
+        .stdout(predicate::str::contains("Renumbered AUTH-2 → AUTH-3"));
+
+    let project = tf.tasks_root.join("AUTH");
+    let kept = fs::read_to_string(project.join("2.yml")).unwrap();
+    assert!(kept.contains("title: Main task"), "{kept}");
+    assert!(!kept.contains("<<<<<<<"), "{kept}");
+    let incoming = fs::read_to_string(project.join("3.yml")).unwrap();
+    assert!(incoming.contains("title: Feature task"), "{incoming}");
+    assert!(incoming.contains("old: AUTH-2"), "{incoming}");
+
+    lotar(root, &["renumber"])
+        .success()
+        .stdout(predicate::str::contains("No ID collisions found"));
+}
@@ -0,0 +1,127 @@
+#[cfg(feature = "schema")]
+use lotar::api_server::{ApiServer, HttpRequest};
+#[cfg(feature = "schema")]
+use lotar::routes;
+use predicates::prelude::*;
+#[cfg(feature = "schema")]
+use serde_json::Value;
+#[cfg(feature = "schema")]
+use std::collections::HashMap;
+use std::fs;
+
+mod common;
+#[cfg(feature = "schema")]
+use crate::common::env_mutex::EnvVarGuard;
+
+fn seed(fixtures: &common::TestFixtures) -> String {
+    fs::write(
+        lotar::utils::paths::global_config_path(&fixtures.tasks_root),
+        "default:\n  project: TEST\n",
+    )
+    .unwrap();
+    let output = common::cargo_bin_in(fixtures)
+        .args(["add", "Schema task"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    common::extract_task_id_from_bytes(&output).expect("task id")
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn schema_export_writes_schemas_modelines_and_vscode_mapping() {
+    let fixtures = common::TestFixtures::new();
+    let id = seed(&fixtures);
+    let workspace = fixtures.temp_dir.path();
+    fs::create_dir_all(workspace.join(".vscode")).unwrap();
+    fs::write(
+        workspace.join(".vscode/settings.json"),
+        r#"{ "editor.tabSize": 2 }"#,
+    )
+    .unwrap();
+
+    common::cargo_bin_in(&fixtures)
+        .args(["schema", "export", "--modelines", "--vscode"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Wrote task schema to"))
+        .stdout(predicate::str::contains("Mapped yaml.schemas in"));
+
+    let schemas = fixtures.tasks_root.join(".schemas");
+    let task_schema: Value =
+        serde_json::from_str(&fs::read_to_string(schemas.join("task.schema.json")).unwrap())
+            .unwrap();
+    assert_eq!(task_schema["title"], "lotar task file");
+    assert!(task_schema["properties"]["title"].is_object());
+    assert!(schemas.join("automation.schema.json").exists());
+
+    let settings: Value =
+        serde_json::from_str(&fs::read_to_string(workspace.join(".vscode/settings.json")).unwrap())
+            .unwrap();
+    assert_eq!(settings["editor.tabSize"], 2);
+    assert_eq!(
+        settings["yaml.schemas"]["./.tasks/.schemas/task.schema.json"][0],
+        ".tasks/*/[0-9]*.yml"
+    );
+
+    let task_path = fixtures.tasks_root.join("TEST/1.yml");
+    let modeline = "# yaml-language-server: $schema=../.schemas/task.schema.json\n";
+    assert!(
+        fs::read_to_string(&task_path)
+            .unwrap()
+            .starts_with(modeline)
+    );
+    let config = fs::read_to_string(fixtures.tasks_root.join("config.yml")).unwrap();
+    assert!(
+        config.starts_with("# yaml-language-server: $schema=.schemas/global.schema.json\n"),
+        "{config}"
+    );
+
+    // Rewrites by lotar keep the modeline.
+    common::cargo_bin_in(&fixtures)
+        .args(["status", &id, "in_progress"])
+        .assert()
+        .success();
+    let task = fs::read_to_string(&task_path).unwrap();
+    assert!(task.starts_with(modeline), "{task}");
+    assert!(task.contains("status: InProgress"), "{task}");
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn schema_endpoint_serves_each_kind() {
+    let fixtures = common::TestFixtures::new();
+    let _guard_tasks = EnvVarGuard::set("LOTAR_TASKS_DIR", &fixtures.tasks_root.to_string_lossy());
+    let mut api = ApiServer::new();
+    routes::initialize(&mut api);
+    let get = |path: &str| {
+        api.handle_request(&HttpRequest {
+            method: "GET".to_string(),
+            path: path.to_string(),
+            query: HashMap::new(),
+            headers: HashMap::new(),
+            body: Vec::new(),
+        })
+    };
+
+    let resp = get("/api/schema/project");
+    assert_eq!(resp.status, 200);
+    let schema: Value = serde_json::from_slice(&resp.body).unwrap();
+    assert!(schema["properties"]["project"]["properties"]["name"].is_object());
+    assert_eq!(get("/api/schema/sprint").status, 200);
+    assert_eq!(get("/api/schema/unknown").status, 404);
+}
+
+#[cfg(not(feature = "schema"))]
+#[test]
+fn schema_commands_need_the_schema_feature() {
+    let fixtures = common::TestFixtures::new();
+    seed(&fixtures);
+    common::cargo_bin_in(&fixtures)
+        .args(["schema", "show", "task"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("without the `schema` feature"));
+}
@@ -234,6 +234,7 @@ fn sprint_service_applies_defaults_for_direct_callers() {
         capacity_hours: None,
         length: Some("1w".to_string()),
         overdue_after: Some("8h".to_string()),
+        ..SprintDefaultsConfig::default()
     };
 
     let outcome = SprintService::create(&mut storage, Sprint::default(), Some(&defaults))
@@ -384,6 +385,143 @@ fn sprint_close_defaults_to_last_active_sprint() {
     assert!(contents.contains("closed_at: 2025-10-15T17:00:00+00:00"));
 }
 
+#[test]
+fn sprint_close_rolls_unfinished_tasks_over_to_next_sprint() {
+    let fixtures = common::TestFixtures::new();
+    let mut storage = Storage::new(&fixtures.tasks_root.clone());
+
+    for label in ["Sprint Delta", "Sprint Epsilon"] {
+        let sprint = Sprint {
+            plan: Some(SprintPlan {
+                label: Some(label.to_string()),
+                ..SprintPlan::default()
+            }),
+            ..Sprint::default()
+        };
+        SprintService::create(&mut storage, sprint, None).expect("create sprint");
+    }
+
+    let mut done = Task::new(
+        fixtures.tasks_root.clone(),
+        "Ship it".to_string(),
+        Priority::from("Medium"),
+    );
+    done.status = TaskStatus::from("Done");
+    let done_id = storage
+        .add(&done, "TEST", Some("Test Project"))
+        .expect("add done task");
+    let mut open = Task::new(
+        fixtures.tasks_root.clone(),
+        "Still going".to_string(),
+        Priority::from("Medium"),
+    );
+    open.status = TaskStatus::from("InProgress");
+    let open_id = storage
+        .add(&open, "TEST", Some("Test Project"))
+        .expect("add open task");
+    append_sprint_tasks(&mut storage, 1, &[done_id.clone(), open_id.clone()]);
+
+    common::cargo_bin_in(&fixtures)
+        .args(["sprint", "start", "1", "--at", "2025-10-01T09:00:00Z"])
+        .assert()
+        .success();
+
+    let output = common::cargo_bin_in(&fixtures)
+        .args([
+            "--format",
+            "json",
+            "sprint",
+            "close",
+            "1",
+            "--at",
+            "2025-10-15T17:00:00Z",
+            "--rollover",
+            "next",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let payload: Value = serde_json::from_slice(&output).expect("close json");
+    assert_eq!(payload["rollover"]["mode"], "next");
+    assert_eq!(payload["rollover"]["target_sprint"], 2);
+    assert_eq!(payload["rollover"]["tasks"], serde_json::json!([open_id]));
+
+    assert_eq!(sprint_task_ids(&storage, 1), vec![done_id.clone()]);
+    assert_eq!(task_sprint_ids(&storage, &open_id), vec![2]);
+
+    let closed = SprintService::get(&storage, 1).expect("closed sprint");
+    let actual = closed.sprint.actual.expect("actual");
+    assert_eq!(actual.spillover, vec![open_id.clone()]);
+    assert!(closed.sprint.history.iter().any(|entry| {
+        entry.changes.iter().any(|change| {
+            change.field == "spillover"
+                && change.old.as_deref() == Some(open_id.as_str())
+                && change.new.as_deref() == Some("#2")
+        })
+    }));
+    let next = SprintService::get(&storage, 2).expect("next sprint");
+    assert!(next.sprint.history.iter().any(|entry| {
+        entry
+            .changes
+            .iter()
+            .any(|change| change.field == "carried_over" && change.old.as_deref() == Some("#1"))
+    }));
+
+    let task = storage.get(&open_id, "TEST").expect("task exists");
+    assert!(task.history.iter().any(|entry| {
+        entry.changes.iter().any(|change| {
+            change.field == "sprint"
+                && change.old.as_deref() == Some("#1")
+                && change.new.as_deref() == Some("#2")
+        })
+    }));
+
+    let velocity = common::cargo_bin_in(&fixtures)
+        .args([
+            "--format", "json", "sprint", "velocity", "--metric", "tasks",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let velocity: Value = serde_json::from_slice(&velocity).expect("velocity json");
+    let entry = &velocity["entries"][0];
+    assert_eq!(entry["committed"], 2.0);
+    assert_eq!(entry["completed"], 1.0);
+    assert_eq!(entry["spillover"], 1.0);
+}
+
+#[test]
+fn sprint_close_rollover_backlog_detaches_unfinished_tasks() {
+    let fixtures = common::TestFixtures::new();
+    let mut storage = Storage::new(&fixtures.tasks_root.clone());
+
+    SprintService::create(&mut storage, Sprint::default(), None).expect("create sprint");
+    let task = Task::new(
+        fixtures.tasks_root.clone(),
+        "Unfinished".to_string(),
+        Priority::from("Medium"),
+    );
+    let task_id = storage
+        .add(&task, "TEST", Some("Test Project"))
+        .expect("add task");
+    append_sprint_tasks(&mut storage, 1, std::slice::from_ref(&task_id));
+
+    common::cargo_bin_in(&fixtures)
+        .args(["sprint", "close", "1", "--force", "--rollover", "backlog"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Rolled 1 unfinished task(s) over to the backlog",
+        ));
+
+    assert!(task_sprint_ids(&storage, &task_id).is_empty());
+    assert_eq!(SprintService::list(&storage).unwrap().len(), 1);
+}
+
 #[test]
 fn sprint_start_warns_about_parallel_active_sprints() {
     let fixtures = common::TestFixtures::new();
@@ -820,6 +958,7 @@ issue.priorities: [Medium]
             capacity: Some(SprintCapacity {
                 points: Some(30),
                 hours: Some(80),
+                ..SprintCapacity::default()
             }),
             ..SprintPlan::default()
         }),
@@ -39,6 +39,7 @@ issue.priorities: [Low, Medium, High]
         actual: Some(SprintActual {
             started_at: Some("2024-05-01T09:00:00Z".to_string()),
             closed_at: Some("2024-05-08T17:00:00Z".to_string()),
+            ..SprintActual::default()
         }),
         ..Sprint::default()
     };
@@ -164,6 +165,7 @@ issue.priorities: [Low, High]
         actual: Some(SprintActual {
             started_at: Some("2024-06-01T09:00:00Z".to_string()),
             closed_at: Some("2024-06-08T17:00:00Z".to_string()),
+            ..SprintActual::default()
         }),
         ..Sprint::default()
     };
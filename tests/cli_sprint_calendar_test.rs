@@ -84,6 +84,7 @@ issue.priorities: [Low, Medium, High]
     completed_doc.actual = Some(SprintActual {
         started_at: Some(completed_start.to_rfc3339()),
         closed_at: Some(completed_end.to_rfc3339()),
+        ..SprintActual::default()
     });
     SprintService::update(&mut storage, completed_id, completed_doc).expect("close sprint");
 
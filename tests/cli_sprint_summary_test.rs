@@ -36,6 +36,7 @@ issue.priorities: [Low, Medium, High]
             capacity: Some(SprintCapacity {
                 points: Some(20),
                 hours: Some(40),
+                ..SprintCapacity::default()
             }),
             ..SprintPlan::default()
         }),
@@ -161,3 +162,97 @@ issue.priorities: [Low, Medium, High]
     assert_eq!(blocked.len(), 1);
     assert_eq!(blocked[0]["id"].as_str(), Some(blocker_id.as_str()));
 }
+
+#[test]
+fn member_capacity_warns_on_overload_and_reports_per_person() {
+    let fixtures = common::TestFixtures::new();
+
+    let config_path = paths::global_config_path(&fixtures.tasks_root);
+    std::fs::write(
+        &config_path,
+        r#"
+default.project: TEST
+members: [alice, bob]
+sprints:
+  defaults:
+    weekly_hours: 10
+    member_weekly_hours:
+      bob: 20
+"#,
+    )
+    .expect("write config");
+
+    let run = |args: &[&str]| {
+        let mut cmd = common::cargo_bin_in(&fixtures);
+        cmd.args(args).assert().success()
+    };
+
+    run(&[
+        "sprint",
+        "create",
+        "--label",
+        "Capacity",
+        "--starts-at",
+        "2026-03-02T09:00:00Z",
+        "--length",
+        "1w",
+    ]);
+    run(&["add", "Design", "--assignee", "alice", "--effort", "8h"]);
+    run(&["add", "Build", "--assignee", "alice", "--effort", "6h"]);
+    run(&["add", "Review", "--assignee", "bob", "--effort", "3h"]);
+
+    run(&["sprint", "add", "--sprint", "1", "TEST-1", "TEST-3"])
+        .stderr(predicate::str::contains("over capacity").not());
+    run(&["sprint", "add", "--sprint", "1", "TEST-2"]).stderr(predicate::str::contains(
+        "alice is over capacity: 14.0h planned, 10.0h available",
+    ));
+
+    run(&[
+        "sprint",
+        "update",
+        "1",
+        "--time-off",
+        "bob=2026-03-05..2026-03-06",
+    ]);
+
+    let output = run(&["--format", "json", "sprint", "stats", "1"])
+        .get_output()
+        .stdout
+        .clone();
+    let payload: Value = serde_json::from_slice(&output).expect("parse stats json");
+    let members = payload["metrics"]["members"]
+        .as_array()
+        .expect("members array");
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0]["member"], "alice");
+    assert_eq!(members[0]["planned_hours"].as_f64(), Some(14.0));
+    assert_eq!(members[0]["available_hours"].as_f64(), Some(10.0));
+    assert_eq!(members[0]["over_capacity"], true);
+    assert_eq!(members[1]["member"], "bob");
+    assert_eq!(members[1]["available_hours"].as_f64(), Some(12.0));
+    assert_eq!(members[1]["time_off_days"].as_u64(), Some(2));
+    assert!(members[1].get("over_capacity").is_none());
+
+    run(&["sprint", "summary", "1"])
+        .stdout(predicate::str::contains("Capacity by member:"))
+        .stdout(predicate::str::contains("bob: 3h planned / 12h available"));
+
+    // Alice was already over capacity, so a further assignment does not warn again
+    run(&["add", "Polish", "--assignee", "alice", "--effort", "2h"]);
+    run(&["sprint", "add", "--sprint", "1", "TEST-4"])
+        .stderr(predicate::str::contains("over capacity").not());
+
+    // Member keys are trimmed, so this updates alice instead of adding " alice "
+    run(&["sprint", "update", "1", "--member-hours", " alice = 40"]);
+    let output = run(&["--format", "json", "sprint", "stats", "1"])
+        .get_output()
+        .stdout
+        .clone();
+    let payload: Value = serde_json::from_slice(&output).expect("parse stats json");
+    let members = payload["metrics"]["members"]
+        .as_array()
+        .expect("members array");
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0]["member"], "alice");
+    assert_eq!(members[0]["available_hours"].as_f64(), Some(40.0));
+}
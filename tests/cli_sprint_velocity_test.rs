@@ -79,6 +79,7 @@ issue.priorities: [Low, Medium, High]
             capacity: Some(SprintCapacity {
                 points: Some(30),
                 hours: None,
+                ..SprintCapacity::default()
             }),
             ..SprintPlan::default()
         }),
@@ -91,6 +92,7 @@ issue.priorities: [Low, Medium, High]
     alpha_doc.actual = Some(SprintActual {
         started_at: Some("2025-09-01T09:00:00Z".to_string()),
         closed_at: Some("2025-09-12T17:00:00Z".to_string()),
+        ..SprintActual::default()
     });
     SprintService::update(&mut storage, alpha_id, alpha_doc).expect("close sprint alpha");
 
@@ -118,6 +120,7 @@ issue.priorities: [Low, Medium, High]
             capacity: Some(SprintCapacity {
                 points: Some(24),
                 hours: Some(120),
+                ..SprintCapacity::default()
             }),
             ..SprintPlan::default()
         }),
@@ -130,6 +133,7 @@ issue.priorities: [Low, Medium, High]
     beta_doc.actual = Some(SprintActual {
         started_at: Some("2025-10-01T09:00:00Z".to_string()),
         closed_at: Some("2025-10-15T15:00:00Z".to_string()),
+        ..SprintActual::default()
     });
     SprintService::update(&mut storage, beta_id, beta_doc).expect("close sprint beta");
 
@@ -34,6 +34,8 @@ fn cfg() -> ResolvedConfig {
         default_reporter: None,
         default_tags: vec![],
         members: vec![],
+        member_profiles: vec![],
+        issue_templates: Default::default(),
         strict_members: false,
         auto_populate_members: true,
         auto_set_reporter: true,
@@ -50,6 +52,8 @@ fn cfg() -> ResolvedConfig {
             "NOTE".to_string(),
         ],
         scan_strip_attributes: true,
+        scan_comment_syntax: Default::default(),
+        scan_reconcile: Default::default(),
         scan_ticket_patterns: None,
         scan_enable_ticket_words: false,
         scan_enable_mentions: true,
@@ -62,6 +66,8 @@ fn cfg() -> ResolvedConfig {
         branch_type_aliases: std::collections::HashMap::new(),
         branch_status_aliases: std::collections::HashMap::new(),
         branch_priority_aliases: std::collections::HashMap::new(),
+        branch_template: "{type}/{id}-{slug}".to_string(),
+        commit_keywords: Default::default(),
         sprint_defaults: Default::default(),
         sprint_notifications: Default::default(),
         attachments_dir: "@attachments".to_string(),
@@ -73,6 +79,8 @@ fn cfg() -> ResolvedConfig {
         agent_logs_dir: None,
         agent_instructions: None,
         agent_profiles: Default::default(),
+        agent_pipelines: Default::default(),
+        agent_approvals: Default::default(),
         agent_automation: Default::default(),
         agent_worktree: Default::default(),
         remotes: Default::default(),
@@ -0,0 +1,137 @@
+use lotar::automation::persistence::load_inherited_automation;
+use lotar::config::manager::ConfigManager;
+use lotar::types::{Priority, TaskStatus};
+use lotar::utils::paths;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::process::Command as ProcCommand;
+
+mod common;
+
+const ORG_CONFIG: &str = r#"
+issue.states: [Backlog, Doing, Shipped]
+default.priority: High
+default.tags: [org]
+"#;
+
+fn run_git(repo: &Path, args: &[&str]) {
+    let out = ProcCommand::new("git")
+        .current_dir(repo)
+        .args(args)
+        .output()
+        .expect("failed to run git");
+    assert!(
+        out.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+#[test]
+fn extends_file_is_merged_below_global_and_labelled() {
+    let fixtures = common::TestFixtures::new();
+    let org = fixtures.temp_dir.path().join("org");
+    fs::create_dir_all(&org).unwrap();
+    fs::write(org.join("lotar.yml"), ORG_CONFIG).unwrap();
+    fs::write(
+        org.join("automation.yml"),
+        "automation:\n  max_iterations: 3\n  rules: []\n",
+    )
+    .unwrap();
+    fs::write(
+        paths::global_config_path(&fixtures.tasks_root),
+        "extends: ../org/lotar.yml\ndefault.project: TEST\ndefault.tags: [local]\n",
+    )
+    .unwrap();
+
+    let resolved = ConfigManager::new_manager_with_tasks_dir_readonly(&fixtures.tasks_root)
+        .expect("load config")
+        .get_resolved_config()
+        .clone();
+    assert_eq!(
+        resolved.issue_states.values,
+        vec![
+            TaskStatus::from("Backlog"),
+            TaskStatus::from("Doing"),
+            TaskStatus::from("Shipped"),
+        ]
+    );
+    assert_eq!(resolved.default_priority, Priority::from("High"));
+    assert_eq!(resolved.default_tags, vec!["local".to_string()]);
+    assert_eq!(resolved.default_project, "TEST");
+    let automation = load_inherited_automation(&fixtures.tasks_root)
+        .expect("load automation")
+        .expect("inherited automation file");
+    assert_eq!(automation.automation.max_iterations(), Some(3));
+
+    common::cargo_bin_in(&fixtures)
+        .args(["config", "show", "--explain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "(inherited from ../org/lotar.yml)",
+        ))
+        .stdout(predicate::str::contains("Inherits: ../org/lotar.yml"));
+
+    let output = common::cargo_bin_in(&fixtures)
+        .args(["--format", "json", "config", "show"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let payload: Value = serde_json::from_slice(&output).expect("parse config json");
+    assert_eq!(
+        payload["sources"]["issue.states"],
+        "inherited from ../org/lotar.yml"
+    );
+    assert_eq!(payload["sources"]["default.tags"], "global");
+}
+
+#[test]
+fn extends_reads_git_ref_and_detects_cycles() {
+    let fixtures = common::TestFixtures::new();
+    let repo = fixtures.temp_dir.path().join("org-config");
+    fs::create_dir_all(&repo).unwrap();
+    fs::write(repo.join("lotar.yml"), ORG_CONFIG).unwrap();
+    run_git(&repo, &["init", "-q"]);
+    run_git(&repo, &["config", "user.name", "Test User"]);
+    run_git(&repo, &["config", "user.email", "test@example.com"]);
+    run_git(&repo, &["config", "commit.gpgsign", "false"]);
+    run_git(&repo, &["add", "-A"]);
+    run_git(&repo, &["commit", "-q", "-m", "org config"]);
+    // Uncommitted edits must not leak into a ref-pinned layer.
+    fs::write(repo.join("lotar.yml"), "default.priority: Low\n").unwrap();
+
+    fs::write(
+        paths::global_config_path(&fixtures.tasks_root),
+        "extends: ../org-config#HEAD:lotar.yml\ndefault.project: TEST\n",
+    )
+    .unwrap();
+    let resolved = ConfigManager::new_manager_with_tasks_dir_readonly(&fixtures.tasks_root)
+        .expect("load config")
+        .get_resolved_config()
+        .clone();
+    assert_eq!(resolved.default_priority, Priority::from("High"));
+
+    let shared = fixtures.temp_dir.path().join("shared");
+    fs::create_dir_all(&shared).unwrap();
+    fs::write(shared.join("a.yml"), "extends: b.yml\n").unwrap();
+    fs::write(shared.join("b.yml"), "extends: [a.yml]\n").unwrap();
+    fs::write(
+        paths::global_config_path(&fixtures.tasks_root),
+        "extends: ../shared/a.yml\ndefault.project: TEST\n",
+    )
+    .unwrap();
+    let err = ConfigManager::new_manager_with_tasks_dir_readonly(&fixtures.tasks_root)
+        .err()
+        .expect("cycle is rejected");
+    assert!(
+        err.to_string()
+            .contains("../shared/a.yml -> b.yml -> a.yml"),
+        "{err}"
+    );
+}
@@ -39,6 +39,7 @@ fn test_global_config_validation_valid() {
     let validator = ConfigValidator::new(temp_dir.path());
 
     let config = GlobalConfig {
+        extends: Vec::new(),
         server_port: 8080,
         default_project: "TEST".to_string(),
         issue_states: ConfigurableField {
@@ -63,6 +64,8 @@ fn test_global_config_validation_valid() {
         default_reporter: None,
         default_tags: vec![],
         members: vec![],
+        member_profiles: vec![],
+        issue_templates: Default::default(),
         strict_members: false,
         auto_populate_members: true,
         auto_set_reporter: true,
@@ -81,6 +84,8 @@ fn test_global_config_validation_valid() {
         scan_enable_ticket_words: false,
         scan_enable_mentions: true,
         scan_strip_attributes: true,
+        scan_comment_syntax: Default::default(),
+        scan_reconcile: Default::default(),
         auto_identity: true,
         auto_identity_git: true,
         auto_codeowners_assign: true,
@@ -91,6 +96,8 @@ fn test_global_config_validation_valid() {
         branch_type_aliases: std::collections::HashMap::new(),
         branch_status_aliases: std::collections::HashMap::new(),
         branch_priority_aliases: std::collections::HashMap::new(),
+        branch_template: "{type}/{id}-{slug}".to_string(),
+        commit_keywords: Default::default(),
         sprints: Default::default(),
         attachments_dir: "@attachments".to_string(),
         attachments_max_upload_mb: 10,
@@ -101,6 +108,8 @@ fn test_global_config_validation_valid() {
         agent_logs_dir: None,
         agent_instructions: None,
         agents: Default::default(),
+        pipelines: Default::default(),
+        agent_approvals: Default::default(),
         agent_automation: Default::default(),
         agent_worktree: Default::default(),
         web_ui_path: None,
@@ -119,6 +128,7 @@ fn test_global_config_validation_privileged_port_warning() {
     let validator = ConfigValidator::new(temp_dir.path());
 
     let config = GlobalConfig {
+        extends: Vec::new(),
         server_port: 80, // Privileged port should trigger warning
         default_project: "TEST".to_string(),
         issue_states: ConfigurableField {
@@ -135,6 +145,8 @@ fn test_global_config_validation_privileged_port_warning() {
         default_reporter: None,
         default_tags: vec![],
         members: vec![],
+        member_profiles: vec![],
+        issue_templates: Default::default(),
         strict_members: false,
         auto_populate_members: true,
         auto_set_reporter: true,
@@ -153,6 +165,8 @@ fn test_global_config_validation_privileged_port_warning() {
         scan_enable_ticket_words: false,
         scan_enable_mentions: true,
         scan_strip_attributes: true,
+        scan_comment_syntax: Default::default(),
+        scan_reconcile: Default::default(),
         auto_identity: true,
         auto_identity_git: true,
         auto_codeowners_assign: true,
@@ -163,6 +177,8 @@ fn test_global_config_validation_privileged_port_warning() {
         branch_type_aliases: std::collections::HashMap::new(),
         branch_status_aliases: std::collections::HashMap::new(),
         branch_priority_aliases: std::collections::HashMap::new(),
+        branch_template: "{type}/{id}-{slug}".to_string(),
+        commit_keywords: Default::default(),
         sprints: Default::default(),
         attachments_dir: "@attachments".to_string(),
         attachments_max_upload_mb: 10,
@@ -173,6 +189,8 @@ fn test_global_config_validation_privileged_port_warning() {
         agent_logs_dir: None,
         agent_instructions: None,
         agents: Default::default(),
+        pipelines: Default::default(),
+        agent_approvals: Default::default(),
         agent_automation: Default::default(),
         agent_worktree: Default::default(),
         web_ui_path: None,
@@ -208,6 +226,7 @@ fn test_global_config_validation_empty_lists_error() {
     let validator = ConfigValidator::new(temp_dir.path());
 
     let config = GlobalConfig {
+        extends: Vec::new(),
         server_port: 8080,
         default_project: "TEST".to_string(),
         issue_states: ConfigurableField {
@@ -224,6 +243,8 @@ fn test_global_config_validation_empty_lists_error() {
         default_reporter: None,
         default_tags: vec![],
         members: vec![],
+        member_profiles: vec![],
+        issue_templates: Default::default(),
         strict_members: false,
         auto_populate_members: true,
         auto_set_reporter: true,
@@ -242,6 +263,8 @@ fn test_global_config_validation_empty_lists_error() {
         scan_enable_ticket_words: false,
         scan_enable_mentions: true,
         scan_strip_attributes: true,
+        scan_comment_syntax: Default::default(),
+        scan_reconcile: Default::default(),
         auto_identity: true,
         auto_identity_git: true,
         auto_codeowners_assign: true,
@@ -252,6 +275,8 @@ fn test_global_config_validation_empty_lists_error() {
         branch_type_aliases: std::collections::HashMap::new(),
         branch_status_aliases: std::collections::HashMap::new(),
         branch_priority_aliases: std::collections::HashMap::new(),
+        branch_template: "{type}/{id}-{slug}".to_string(),
+        commit_keywords: Default::default(),
         sprints: Default::default(),
         attachments_dir: "@attachments".to_string(),
         attachments_max_upload_mb: 10,
@@ -262,6 +287,8 @@ fn test_global_config_validation_empty_lists_error() {
         agent_logs_dir: None,
         agent_instructions: None,
         agents: Default::default(),
+        pipelines: Default::default(),
+        agent_approvals: Default::default(),
         agent_automation: Default::default(),
         agent_worktree: Default::default(),
         web_ui_path: None,
@@ -299,6 +326,7 @@ fn test_global_config_validation_invalid_defaults() {
     let validator = ConfigValidator::new(temp_dir.path());
 
     let config = GlobalConfig {
+        extends: Vec::new(),
         server_port: 8080,
         default_project: "TEST".to_string(),
         issue_states: ConfigurableField {
@@ -315,6 +343,8 @@ fn test_global_config_validation_invalid_defaults() {
         default_reporter: None,
         default_tags: vec![],
         members: vec![],
+        member_profiles: vec![],
+        issue_templates: Default::default(),
         strict_members: false,
         auto_populate_members: true,
         auto_set_reporter: true,
@@ -333,6 +363,8 @@ fn test_global_config_validation_invalid_defaults() {
         scan_enable_ticket_words: false,
         scan_enable_mentions: true,
         scan_strip_attributes: true,
+        scan_comment_syntax: Default::default(),
+        scan_reconcile: Default::default(),
         auto_identity: true,
         auto_identity_git: true,
         auto_codeowners_assign: true,
@@ -343,6 +375,8 @@ fn test_global_config_validation_invalid_defaults() {
         branch_type_aliases: std::collections::HashMap::new(),
         branch_status_aliases: std::collections::HashMap::new(),
         branch_priority_aliases: std::collections::HashMap::new(),
+        branch_template: "{type}/{id}-{slug}".to_string(),
+        commit_keywords: Default::default(),
         sprints: Default::default(),
         attachments_dir: "@attachments".to_string(),
         attachments_max_upload_mb: 10,
@@ -353,6 +387,8 @@ fn test_global_config_validation_invalid_defaults() {
         agent_logs_dir: None,
         agent_instructions: None,
         agents: Default::default(),
+        pipelines: Default::default(),
+        agent_approvals: Default::default(),
         agent_automation: Default::default(),
         agent_worktree: Default::default(),
         web_ui_path: None,
@@ -383,6 +419,7 @@ fn test_global_config_duplicate_entries_warning() {
     let validator = ConfigValidator::new(temp_dir.path());
 
     let config = GlobalConfig {
+        extends: Vec::new(),
         server_port: 8080,
         default_project: "TEST".to_string(),
         issue_states: ConfigurableField {
@@ -399,6 +436,8 @@ fn test_global_config_duplicate_entries_warning() {
         default_reporter: None,
         default_tags: vec!["tag".to_string(), "Tag".to_string()],
         members: vec![],
+        member_profiles: vec![],
+        issue_templates: Default::default(),
         strict_members: false,
         auto_populate_members: true,
         auto_set_reporter: true,
@@ -411,6 +450,8 @@ fn test_global_config_duplicate_entries_warning() {
         scan_enable_ticket_words: false,
         scan_enable_mentions: true,
         scan_strip_attributes: true,
+        scan_comment_syntax: Default::default(),
+        scan_reconcile: Default::default(),
         auto_identity: true,
         auto_identity_git: true,
         auto_codeowners_assign: true,
@@ -421,6 +462,8 @@ fn test_global_config_duplicate_entries_warning() {
         branch_type_aliases: std::collections::HashMap::new(),
         branch_status_aliases: std::collections::HashMap::new(),
         branch_priority_aliases: std::collections::HashMap::new(),
+        branch_template: "{type}/{id}-{slug}".to_string(),
+        commit_keywords: Default::default(),
         sprints: Default::default(),
         attachments_dir: "@attachments".to_string(),
         attachments_max_upload_mb: 10,
@@ -431,6 +474,8 @@ fn test_global_config_duplicate_entries_warning() {
         agent_logs_dir: None,
         agent_instructions: None,
         agents: Default::default(),
+        pipelines: Default::default(),
+        agent_approvals: Default::default(),
         agent_automation: Default::default(),
         agent_worktree: Default::default(),
         web_ui_path: None,
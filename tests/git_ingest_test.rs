@@ -0,0 +1,135 @@
+use predicates::prelude::*;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::process::Command as ProcCommand;
+
+mod common;
+use common::TestFixtures;
+
+fn run_git(repo: &Path, args: &[&str]) -> String {
+    let out = ProcCommand::new("git")
+        .current_dir(repo)
+        .args(args)
+        .output()
+        .expect("failed to run git");
+    assert!(
+        out.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).trim().to_string()
+}
+
+fn lotar(root: &Path, args: &[&str]) -> assert_cmd::assert::Assert {
+    let mut cmd = crate::common::lotar_cmd().unwrap();
+    cmd.current_dir(root).args(args).assert()
+}
+
+/// Repository with DEMO-1/DEMO-2 and a commit that fixes one and refers to the other.
+fn seeded_repo() -> (TestFixtures, String) {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    lotar(root, &["add", "Broken login", "--project", "DEMO"]).success();
+    lotar(root, &["add", "Login epic", "--project", "DEMO"]).success();
+    run_git(root, &["init", "-q"]);
+    run_git(root, &["config", "user.name", "Test User"]);
+    run_git(root, &["config", "user.email", "test@example.com"]);
+    run_git(root, &["config", "commit.gpgsign", "false"]);
+    run_git(root, &["add", "-A"]);
+    run_git(root, &["commit", "-q", "-m", "baseline"]);
+    fs::write(root.join("login.rs"), "fn login() {}\n").unwrap();
+    run_git(root, &["add", "login.rs"]);
+    run_git(
+        root,
+        &[
+            "commit",
+            "-q",
+            "-m",
+            "Fix login\n\nFixes DEMO-1, refs DEMO-2 and DEMO-99",
+        ],
+    );
+    let sha = run_git(root, &["rev-parse", "HEAD"]);
+    (tf, sha)
+}
+
+fn task_yaml(root: &Path, number: u32) -> String {
+    fs::read_to_string(root.join(".tasks/DEMO").join(format!("{number}.yml"))).unwrap()
+}
+
+#[cfg(not(no_git_tests))]
+#[test]
+fn ingest_links_commits_and_applies_keywords_once() {
+    let (tf, sha) = seeded_repo();
+    let root = tf.temp_dir.path();
+
+    lotar(root, &["git", "ingest", "--since", "HEAD~1", "--dry-run"])
+        .success()
+        .stdout(predicate::str::contains(
+            "DEMO-1 would link, would move to Done",
+        ))
+        .stdout(predicate::str::contains("DEMO-99 unknown ticket"));
+    assert!(!task_yaml(root, 1).contains(&sha));
+
+    lotar(root, &["git", "ingest", "--since", "HEAD~1"]).success();
+    let fixed = task_yaml(root, 1);
+    assert!(fixed.contains(&format!("commit: {sha}")), "{fixed}");
+    assert!(fixed.contains("status: Done"), "{fixed}");
+    let referenced = task_yaml(root, 2);
+    assert!(
+        referenced.contains(&format!("commit: {sha}")),
+        "{referenced}"
+    );
+    assert!(!referenced.contains("status: Done"), "{referenced}");
+
+    // Re-running the same range (or the post-commit hook's --commit HEAD) is a no-op
+    lotar(root, &["status", "DEMO-1", "InProgress"]).success();
+    let output = lotar(
+        root,
+        &["--format", "json", "git", "ingest", "--commit", "HEAD"],
+    )
+    .success()
+    .get_output()
+    .stdout
+    .clone();
+    let payload: Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(payload["linked"], 0);
+    let outcomes: Vec<&str> = payload["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["outcome"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        outcomes,
+        vec!["already_linked", "already_linked", "unknown_task"]
+    );
+    let yaml = task_yaml(root, 1);
+    assert!(yaml.contains("status: InProgress"), "{yaml}");
+    assert_eq!(yaml.matches("commit:").count(), 1, "{yaml}");
+}
+
+#[cfg(not(no_git_tests))]
+#[test]
+fn commit_msg_warns_and_strict_rejects_unknown_tickets() {
+    let (tf, _) = seeded_repo();
+    let root = tf.temp_dir.path();
+    let message = root.join("COMMIT_EDITMSG");
+    fs::write(
+        &message,
+        "closes DEMO-2 and DEMO-42\n# Please enter the commit message\n# DEMO-77\n",
+    )
+    .unwrap();
+    let file = message.to_str().unwrap();
+
+    lotar(root, &["git", "commit-msg", file])
+        .success()
+        .stdout(predicate::str::contains("DEMO-2 will move to Done"))
+        .stderr(predicate::str::contains("unknown ticket DEMO-42"))
+        .stderr(predicate::str::contains("DEMO-77").not());
+
+    lotar(root, &["git", "commit-msg", "--strict", file])
+        .failure()
+        .stderr(predicate::str::contains("unknown ticket(s): DEMO-42"));
+}
@@ -0,0 +1,144 @@
+#![cfg(not(no_git_tests))]
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use std::process::Command as ProcCommand;
+
+mod common;
+use common::TestFixtures;
+
+const BASE_TASK: &str = "title: Login fails\nstatus: Todo\npriority: Medium\ncreated: 2024-01-01T00:00:00Z\ntags:\n- auth\n";
+
+fn run_git(repo: &Path, args: &[&str]) -> String {
+    let bin_dir = Path::new(env!("CARGO_BIN_EXE_lotar")).parent().unwrap();
+    let path = std::env::join_paths(std::iter::once(bin_dir.to_path_buf()).chain(
+        std::env::split_paths(&std::env::var_os("PATH").unwrap_or_default()),
+    ))
+    .unwrap();
+    let out = ProcCommand::new("git")
+        .current_dir(repo)
+        .env("PATH", path)
+        .env("LOTAR_IGNORE_HOME_CONFIG", "1")
+        .args(args)
+        .output()
+        .expect("failed to run git");
+    assert!(
+        out.status.success(),
+        "git {:?} failed: {}{}",
+        args,
+        String::from_utf8_lossy(&out.stdout),
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).trim().to_string()
+}
+
+fn lotar(root: &Path, args: &[&str]) -> assert_cmd::assert::Assert {
+    let mut cmd = crate::common::lotar_cmd().unwrap();
+    cmd.current_dir(root).args(args).assert()
+}
+
+fn commit_task(root: &Path, content: &str, message: &str) {
+    fs::write(root.join(".tasks/DEMO/1.yml"), content).unwrap();
+    run_git(root, &["commit", "-q", "-am", message]);
+}
+
+#[test]
+fn git_merge_combines_field_edits_and_comments_from_both_branches() {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    fs::create_dir_all(root.join(".tasks/DEMO")).unwrap();
+    fs::write(root.join(".tasks/DEMO/1.yml"), BASE_TASK).unwrap();
+    run_git(root, &["init", "-q"]);
+    run_git(root, &["config", "user.name", "Test User"]);
+    run_git(root, &["config", "user.email", "test@example.com"]);
+    run_git(root, &["config", "commit.gpgsign", "false"]);
+
+    lotar(root, &["git", "install-merge-driver"])
+        .success()
+        .stdout(predicate::str::contains(".tasks/*/[0-9]*.yml merge=lotar"));
+    assert_eq!(
+        run_git(root, &["config", "--get", "merge.lotar.driver"]),
+        "lotar merge-driver %O %A %B %P"
+    );
+    lotar(root, &["git", "install-merge-driver"])
+        .success()
+        .stdout(predicate::str::contains("already configured"));
+    let attributes = fs::read_to_string(root.join(".gitattributes")).unwrap();
+    assert_eq!(attributes, ".tasks/*/[0-9]*.yml merge=lotar\n");
+
+    run_git(root, &["add", "-A"]);
+    run_git(root, &["commit", "-q", "-m", "baseline"]);
+    let main = run_git(root, &["rev-parse", "--abbrev-ref", "HEAD"]);
+
+    run_git(root, &["checkout", "-q", "-b", "feature"]);
+    commit_task(
+        root,
+        &format!(
+            "{}- security\ncomments:\n- date: 2024-01-02T00:00:00Z\n  text: from feature\n",
+            BASE_TASK.replace("priority: Medium", "priority: High")
+        ),
+        "feature edits",
+    );
+
+    run_git(root, &["checkout", "-q", &main]);
+    commit_task(
+        root,
+        &format!(
+            "{}comments:\n- date: 2024-01-03T00:00:00Z\n  text: from main\n",
+            BASE_TASK.replace("status: Todo", "status: InProgress")
+        ),
+        "main edits",
+    );
+
+    run_git(root, &["merge", "-q", "--no-edit", "feature"]);
+
+    let merged = fs::read_to_string(root.join(".tasks/DEMO/1.yml")).unwrap();
+    assert!(!merged.contains("<<<<<<<"), "{merged}");
+    assert!(merged.contains("status: InProgress"), "{merged}");
+    assert!(merged.contains("priority: High"), "{merged}");
+    assert!(merged.contains("- security"), "{merged}");
+    let feature = merged.find("from feature").expect("feature comment kept");
+    let main_comment = merged.find("from main").expect("main comment kept");
+    assert!(feature < main_comment, "{merged}");
+}
+
+#[test]
+fn merge_driver_marks_same_field_conflicts() {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    fs::write(root.join("base.yml"), BASE_TASK).unwrap();
+    fs::write(
+        root.join("ours.yml"),
+        BASE_TASK.replace("status: Todo", "status: Done"),
+    )
+    .unwrap();
+    fs::write(
+        root.join("theirs.yml"),
+        BASE_TASK
+            .replace("status: Todo", "status: Blocked")
+            .replace("priority: Medium", "priority: Low"),
+    )
+    .unwrap();
+
+    lotar(
+        root,
+        &[
+            "merge-driver",
+            "base.yml",
+            "ours.yml",
+            "theirs.yml",
+            ".tasks/DEMO/1.yml",
+        ],
+    )
+    .failure()
+    .stderr(predicate::str::contains(
+        "Merge conflict in .tasks/DEMO/1.yml: both sides changed status",
+    ));
+
+    let merged = fs::read_to_string(root.join("ours.yml")).unwrap();
+    assert!(
+        merged.contains("<<<<<<< ours\nstatus: Done\n=======\nstatus: Blocked\n>>>>>>> theirs\n"),
+        "{merged}"
+    );
+    assert!(merged.contains("priority: Low"), "{merged}");
+}
@@ -0,0 +1,133 @@
+use lotar::api_types::TaskCreate;
+use lotar::services::task_service::TaskService;
+use lotar::storage::manager::Storage;
+use lotar::types::TaskType;
+use lotar::utils::paths;
+use predicates::prelude::*;
+use serde_json::Value;
+
+mod common;
+
+const CONFIG: &str = r#"
+default.project: TEST
+issue.states: [Todo, InProgress, Done]
+issue.types: [Feature, Bug, Chore]
+issue.priorities: [Low, Medium, High]
+custom.fields: [severity, area]
+issue.templates:
+  Bug:
+    description: |
+      ## Steps to reproduce
+
+      ## Expected
+
+      ## Actual
+    tags: [triage]
+    custom_fields:
+      area: backend
+    required: [severity]
+  Feature:
+    acceptance_criteria:
+      - Documented in the changelog
+"#;
+
+#[test]
+fn add_applies_bug_template_and_lists_missing_fields() {
+    let fixtures = common::TestFixtures::new();
+    std::fs::write(paths::global_config_path(&fixtures.tasks_root), CONFIG).unwrap();
+
+    common::cargo_bin_in(&fixtures)
+        .args(["add", "Crash on save", "--bug"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("missing: severity"));
+
+    let output = common::cargo_bin_in(&fixtures)
+        .args([
+            "--format",
+            "json",
+            "add",
+            "Crash on save",
+            "--bug",
+            "--field",
+            "severity=major",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let payload: Value = serde_json::from_slice(&output).expect("parse add json");
+    let id = payload["task"]["id"].as_str().expect("task id").to_string();
+
+    let storage = Storage::new(&fixtures.tasks_root);
+    let task = TaskService::get(&storage, &id, None).expect("load task");
+    assert!(
+        task.description
+            .as_deref()
+            .unwrap_or_default()
+            .contains("## Steps to reproduce"),
+        "{task:?}"
+    );
+    assert_eq!(task.tags, vec!["triage".to_string()]);
+    let field = |name: &str| {
+        task.custom_fields
+            .get(name)
+            .map(lotar::types::custom_value_to_string)
+    };
+    assert_eq!(field("area").as_deref(), Some("backend"));
+    assert_eq!(field("severity").as_deref(), Some("major"));
+}
+
+#[test]
+fn task_service_create_seeds_feature_acceptance_criteria() {
+    let fixtures = common::TestFixtures::new();
+    std::fs::write(paths::global_config_path(&fixtures.tasks_root), CONFIG).unwrap();
+    let mut storage = Storage::new(&fixtures.tasks_root);
+
+    let task = TaskService::create(
+        &mut storage,
+        TaskCreate {
+            title: "Dark mode".to_string(),
+            project: Some("TEST".to_string()),
+            priority: None,
+            task_type: Some(TaskType::from("Feature")),
+            reporter: None,
+            assignee: None,
+            due_date: None,
+            effort: None,
+            description: Some("Explicit body".to_string()),
+            tags: vec![],
+            relationships: None,
+            custom_fields: None,
+            sprints: vec![],
+        },
+    )
+    .expect("create feature");
+    assert_eq!(task.description.as_deref(), Some("Explicit body"));
+    assert_eq!(
+        task.acceptance_criteria,
+        vec!["Documented in the changelog".to_string()]
+    );
+
+    let err = TaskService::create(
+        &mut storage,
+        TaskCreate {
+            title: "Broken export".to_string(),
+            project: Some("TEST".to_string()),
+            priority: None,
+            task_type: Some(TaskType::from("Bug")),
+            reporter: None,
+            assignee: None,
+            due_date: None,
+            effort: None,
+            description: None,
+            tags: vec![],
+            relationships: None,
+            custom_fields: None,
+            sprints: vec![],
+        },
+    )
+    .expect_err("bug without severity");
+    assert!(err.to_string().contains("missing: severity"), "{err}");
+}
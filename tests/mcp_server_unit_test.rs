@@ -1375,3 +1375,109 @@ fn mcp_task_update_resolves_me_in_patch() {
 
     // guard drops here
 }
+
+#[test]
+fn mcp_task_tools_respect_agent_approval_gates() {
+    let tmp = tempfile::tempdir().unwrap();
+    let tasks_dir = tmp.path().join(".tasks");
+    std::fs::create_dir_all(&tasks_dir).unwrap();
+    std::fs::write(
+        tasks_dir.join("config.yml"),
+        "agent:\n  approvals:\n    - operation: status\n      to: [Done]\n    - operation: delete\n      action: deny\n",
+    )
+    .unwrap();
+
+    let _guard_tasks = EnvVarGuard::set("LOTAR_TASKS_DIR", tasks_dir.to_string_lossy().as_ref());
+    let _guard_job = EnvVarGuard::set("LOTAR_AGENT_JOB_ID", "job-approval-test");
+    let _guard_profile = EnvVarGuard::set("LOTAR_AGENT_PROFILE", "implement");
+
+    let resolver = lotar::TasksDirectoryResolver::resolve(None, None).unwrap();
+    let mut storage = lotar::Storage::new(&resolver.path.clone());
+    let created = lotar::services::task_service::TaskService::create(
+        &mut storage,
+        lotar::api_types::TaskCreate {
+            title: "Gated task".into(),
+            project: Some("MCP".into()),
+            ..lotar::api_types::TaskCreate::default()
+        },
+    )
+    .expect("create task");
+    let call = |id: u64, name: &str, arguments: serde_json::Value| {
+        let req = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": "tools/call",
+            "params": {"name": name, "arguments": arguments}
+        });
+        let resp_line = lotar::mcp::server::handle_json_line(&serde_json::to_string(&req).unwrap());
+        serde_json::from_str::<serde_json::Value>(&resp_line).unwrap()
+    };
+
+    // Ungated changes go straight through
+    let resp = call(
+        1,
+        "task_update",
+        serde_json::json!({"id": created.id, "patch": {"priority": "High"}}),
+    );
+    assert!(resp.get("error").is_none(), "task_update failed: {resp}");
+
+    // Moving to Done is parked instead of applied
+    let resp = call(
+        2,
+        "task_update",
+        serde_json::json!({"id": created.id, "patch": {"status": "Done"}}),
+    );
+    assert!(resp.get("error").is_none(), "task_update failed: {resp}");
+    let payload = tool_payload_json(&resp).expect("payload");
+    assert_eq!(payload["status"], "pending_approval");
+    let approval_id = payload["approval"]["id"].as_u64().expect("approval id") as u32;
+    assert_eq!(payload["approval"]["agent"], "implement");
+    assert_eq!(payload["approval"]["job_id"], "job-approval-test");
+
+    let stored = lotar::services::task_service::TaskService::get(&storage, &created.id, None)
+        .expect("stored task");
+    assert_ne!(stored.status.as_str(), "Done");
+
+    // Deleting is refused outright
+    let resp = call(3, "task_delete", serde_json::json!({"id": created.id}));
+    assert!(
+        resp.get("error").is_some(),
+        "delete should be denied: {resp}"
+    );
+    assert!(
+        lotar::services::task_service::TaskService::get(&storage, &created.id, None).is_ok(),
+        "denied delete must leave the task in place"
+    );
+
+    let pending = lotar::services::agent_approval_service::AgentApprovalService::list(
+        &resolver.path,
+        Some("pending"),
+        Some(&created.id),
+    )
+    .unwrap();
+    assert_eq!(pending.len(), 1);
+
+    let outcome = lotar::services::agent_approval_service::AgentApprovalService::approve(
+        &resolver.path,
+        approval_id,
+        Some("reviewer"),
+        None,
+    )
+    .expect("approve");
+    assert_eq!(outcome.approval.status, "approved");
+    assert_eq!(outcome.approval.decided_by.as_deref(), Some("reviewer"));
+    let stored = lotar::services::task_service::TaskService::get(&storage, &created.id, None)
+        .expect("stored task");
+    assert_eq!(stored.status.as_str(), "Done");
+
+    // A decided approval cannot be decided again
+    assert!(
+        lotar::services::agent_approval_service::AgentApprovalService::reject(
+            &resolver.path,
+            approval_id,
+            None,
+            None,
+        )
+        .is_err()
+    );
+}
@@ -0,0 +1,79 @@
+use lotar::config::normalization::{parse_global_from_yaml_str, to_canonical_global_yaml};
+use lotar::utils::paths;
+use predicates::prelude::*;
+use serde_json::Value;
+
+mod common;
+use crate::common::env_mutex::EnvVarGuard;
+
+const CONFIG: &str = r#"
+default.project: TEST
+default.reporter: jane@corp.com
+default.strict_members: true
+auto.populate_members: false
+issue.states: [Todo, InProgress, Done]
+issue.types: [Feature, Bug, Chore]
+issue.priorities: [Low, Medium, High]
+members:
+  - alice
+  - handle: jdoe
+    name: Jane Doe
+    emails: [jane@corp.com]
+    git: [Jane D]
+    github: janedoe
+"#;
+
+#[test]
+fn member_aliases_resolve_to_profile_handle() {
+    let fixtures = common::TestFixtures::new();
+    std::fs::write(paths::global_config_path(&fixtures.tasks_root), CONFIG).unwrap();
+    let _reporter_guard = EnvVarGuard::clear("LOTAR_DEFAULT_REPORTER");
+
+    common::cargo_bin_in(&fixtures)
+        .args(["whoami"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("jdoe"));
+
+    // Strict members accept any alias of a configured profile.
+    common::cargo_bin_in(&fixtures)
+        .args(["add", "Alias assignee", "--assignee", "@janedoe"])
+        .assert()
+        .success();
+    common::cargo_bin_in(&fixtures)
+        .args(["add", "Someone else", "--assignee", "mallory"])
+        .assert()
+        .failure();
+
+    let output = common::cargo_bin_in(&fixtures)
+        .args(["--format", "json", "list", "--mine"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let payload: Value = serde_json::from_slice(&output).expect("parse list json");
+    let titles: Vec<&str> = payload["tasks"]
+        .as_array()
+        .expect("tasks array")
+        .iter()
+        .filter_map(|task| task["title"].as_str())
+        .collect();
+    assert_eq!(titles, vec!["Alias assignee"]);
+}
+
+#[test]
+fn member_profiles_round_trip_through_canonical_yaml() {
+    let parsed = parse_global_from_yaml_str(CONFIG).expect("parse config");
+    assert_eq!(
+        parsed.members,
+        vec!["alice".to_string(), "jdoe".to_string()]
+    );
+    assert_eq!(parsed.member_profiles.len(), 1);
+    assert_eq!(parsed.member_profiles[0].name.as_deref(), Some("Jane Doe"));
+
+    let written = to_canonical_global_yaml(&parsed);
+    let reparsed = parse_global_from_yaml_str(&written).expect("reparse config");
+    assert_eq!(reparsed.members, parsed.members);
+    assert_eq!(reparsed.member_profiles, parsed.member_profiles);
+}
@@ -0,0 +1,147 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+mod common;
+use common::TestFixtures;
+
+fn git_init(root: &Path) {
+    let status = std::process::Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(root)
+        .status()
+        .expect("git init");
+    assert!(status.success(), "git init should succeed");
+}
+
+fn run_scan(root: &Path, args: &[&str]) -> assert_cmd::assert::Assert {
+    let mut cmd = crate::common::lotar_cmd().unwrap();
+    cmd.current_dir(root)
+        .arg("scan")
+        .args(args)
+        .assert()
+        .success()
+}
+
+/// Path of the single task created by the first scan.
+fn created_task_file(tasks_dir: &Path) -> PathBuf {
+    let project = fs::read_dir(tasks_dir)
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .find(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .expect("project folder")
+        .file_name();
+    tasks_dir.join(project).join("1.yml")
+}
+
+#[test]
+fn reconcile_follows_moved_todo() {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    fs::write(root.join("main.rs"), "// TODO: keep me\nfn main() {}\n").unwrap();
+    run_scan(root, &[]);
+
+    let task_file = created_task_file(&root.join(".tasks"));
+    let annotated = fs::read_to_string(root.join("main.rs")).unwrap();
+    fs::write(root.join("main.rs"), format!("\n\n\n{annotated}")).unwrap();
+
+    run_scan(root, &["--reconcile", "--dry-run"])
+        .stdout(predicate::str::contains("moved to main.rs#4"));
+    assert!(
+        fs::read_to_string(&task_file)
+            .unwrap()
+            .contains("main.rs#1")
+    );
+}
+
+#[test]
+fn reconcile_applies_policy_to_removed_todo() {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    let tasks_dir = root.join(".tasks");
+    git_init(root);
+    fs::write(
+        tasks_dir.join("config.yml"),
+        "scan:\n  reconcile:\n    tag: todo-removed\n    status: Done\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("main.rs"),
+        "// TODO: remove me later\nfn main() {}\n",
+    )
+    .unwrap();
+    run_scan(root, &[]);
+
+    let task_file = created_task_file(&tasks_dir);
+    fs::write(root.join("main.rs"), "fn main() {}\n").unwrap();
+
+    run_scan(root, &["--reconcile", "--dry-run"]).stdout(predicate::str::contains(
+        "TODO removed (unlink, tag:todo-removed, status:Done, comment)",
+    ));
+    assert!(
+        fs::read_to_string(&task_file)
+            .unwrap()
+            .contains("main.rs#1")
+    );
+
+    run_scan(root, &["--reconcile"]).stdout(predicate::str::contains("Reconciled 1 task(s)"));
+    let yaml = fs::read_to_string(&task_file).unwrap();
+    assert!(yaml.contains("status: Done"), "{yaml}");
+    assert!(yaml.contains("todo-removed"), "{yaml}");
+    assert!(yaml.contains("Code TODO removed"), "{yaml}");
+    assert!(!yaml.contains("code: main.rs#1"), "{yaml}");
+
+    // The dead reference is gone, so a second pass has nothing to do
+    run_scan(root, &["--reconcile"])
+        .stdout(predicate::str::contains("all code references are current"));
+}
+
+#[test]
+fn reconcile_resolves_anchors_against_the_tasks_repo() {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    git_init(root);
+    fs::write(root.join("main.rs"), "// TODO: stay put\nfn main() {}\n").unwrap();
+    run_scan(root, &[]);
+    let task_file = created_task_file(&root.join(".tasks"));
+    let before = fs::read_to_string(&task_file).unwrap();
+
+    // Run from an unrelated directory: anchors must still resolve inside the repo
+    let elsewhere = tempfile::tempdir().unwrap();
+    let tasks_dir = root.join(".tasks");
+    let mut cmd = crate::common::lotar_cmd().unwrap();
+    cmd.current_dir(elsewhere.path())
+        .args([
+            "--tasks-dir",
+            tasks_dir.to_str().unwrap(),
+            "scan",
+            "--reconcile",
+        ])
+        .arg(elsewhere.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("all code references are current"));
+    assert_eq!(fs::read_to_string(&task_file).unwrap(), before);
+}
+
+#[test]
+fn reconcile_does_not_remove_outside_a_repository() {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    fs::write(root.join("main.rs"), "// TODO: no repo\nfn main() {}\n").unwrap();
+    run_scan(root, &[]);
+    let task_file = created_task_file(&root.join(".tasks"));
+    fs::write(root.join("main.rs"), "fn main() {}\n").unwrap();
+
+    let mut cmd = crate::common::lotar_cmd().unwrap();
+    cmd.current_dir(root)
+        .args(["scan", "--reconcile"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("No git repository contains"));
+    assert!(
+        fs::read_to_string(&task_file)
+            .unwrap()
+            .contains("main.rs#1")
+    );
+}
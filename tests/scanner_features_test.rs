@@ -574,3 +574,107 @@ mod more_languages {
             .stdout(predicate::str::contains("Found 1 TODO comment(s):"));
     }
 }
+
+mod comment_syntax {
+    use lotar::config::types::ScanCommentSyntax;
+    use lotar::scanner::Scanner;
+    use predicates::prelude::*;
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::{TestFixtures, cargo_bin_in};
+
+    fn scan_titles(root: &std::path::Path) -> Vec<(usize, String)> {
+        let mut refs = Scanner::new(PathBuf::from(root)).scan();
+        refs.sort_by_key(|r| r.line_number);
+        refs.into_iter().map(|r| (r.line_number, r.title)).collect()
+    }
+
+    #[test]
+    fn todo_inside_string_literal_is_ignored() {
+        let tmp = tempfile::tempdir().unwrap();
+        fs::write(
+            tmp.path().join("a.js"),
+            "const msg = \"// TODO: not a comment\";\nconst url = 'http://x'; // TODO: real one",
+        )
+        .unwrap();
+        let found = scan_titles(tmp.path());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, 2);
+        assert!(found[0].1.contains("real one"));
+    }
+
+    #[test]
+    fn python_docstrings_are_scanned_at_their_own_line() {
+        let tmp = tempfile::tempdir().unwrap();
+        fs::write(
+            tmp.path().join("a.py"),
+            "def f():\n    \"\"\"Summary.\n\n    TODO: handle errors\n    \"\"\"\n    return \"# TODO: data\"\n",
+        )
+        .unwrap();
+        let found = scan_titles(tmp.path());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, 4);
+    }
+
+    #[test]
+    fn lua_block_comments_are_scanned() {
+        let tmp = tempfile::tempdir().unwrap();
+        fs::write(
+            tmp.path().join("a.lua"),
+            "--[[\n  FIXME: leaking handle\n]]\nlocal s = \"-- TODO: no\"\n",
+        )
+        .unwrap();
+        let found = scan_titles(tmp.path());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, 2);
+    }
+
+    #[test]
+    fn configured_syntax_adds_extensions() {
+        let tmp = tempfile::tempdir().unwrap();
+        fs::write(tmp.path().join("rules.zz"), "!! TODO: custom comment\n").unwrap();
+        fs::write(tmp.path().join("app.jsonc"), "// TODO: jsonc comment\n").unwrap();
+
+        assert!(scan_titles(tmp.path()).is_empty());
+
+        let mut entries = BTreeMap::new();
+        entries.insert(
+            "zz".to_string(),
+            ScanCommentSyntax {
+                line: vec!["!!".to_string()],
+                ..Default::default()
+            },
+        );
+        entries.insert(
+            "jsonc".to_string(),
+            ScanCommentSyntax {
+                extends: Some("js".to_string()),
+                ..Default::default()
+            },
+        );
+        let refs = Scanner::new(PathBuf::from(tmp.path()))
+            .with_comment_syntax(&entries)
+            .scan();
+        assert_eq!(refs.len(), 2);
+    }
+
+    #[test]
+    fn comment_syntax_from_config_is_used_by_scan_command() {
+        let tf = TestFixtures::new();
+        let root = tf.temp_dir.path();
+        fs::write(
+            tf.tasks_root.join("config.yml"),
+            "scan:\n  comment_syntax:\n    zz:\n      line: ['!!']\n",
+        )
+        .unwrap();
+        fs::write(root.join("rules.zz"), "!! TODO: from config\n").unwrap();
+
+        cargo_bin_in(&tf)
+            .arg("scan")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Found 1 TODO comment(s):"));
+    }
+}
@@ -82,6 +82,7 @@ fn complete_when_closed() {
     let actual = SprintActual {
         started_at: Some("2025-09-01T09:00:00Z".to_string()),
         closed_at: Some("2025-09-07T18:00:00Z".to_string()),
+        ..SprintActual::default()
     };
     let sprint = Sprint {
         plan: Some(plan),
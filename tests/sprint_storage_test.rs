@@ -57,6 +57,7 @@ fn sprint_create_and_list_trims_empty_sections() {
             capacity: Some(SprintCapacity {
                 points: Some(0),
                 hours: None,
+                ..SprintCapacity::default()
             }),
             overdue_after: Some(String::new()),
             ..SprintPlan::default()
@@ -64,6 +65,7 @@ fn sprint_create_and_list_trims_empty_sections() {
         actual: Some(SprintActual {
             started_at: Some(String::new()),
             closed_at: None,
+            ..SprintActual::default()
         }),
         ..Sprint::default()
     };
@@ -0,0 +1,125 @@
+use predicates::prelude::*;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::process::Command as ProcCommand;
+
+mod common;
+use common::TestFixtures;
+
+fn run_git(repo: &Path, args: &[&str]) -> String {
+    let out = ProcCommand::new("git")
+        .current_dir(repo)
+        .args(args)
+        .output()
+        .expect("failed to run git");
+    assert!(
+        out.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).trim().to_string()
+}
+
+fn lotar(root: &Path, args: &[&str]) -> assert_cmd::assert::Assert {
+    let mut cmd = crate::common::lotar_cmd().unwrap();
+    cmd.current_dir(root)
+        .env("LOTAR_DEFAULT_REPORTER", "jane.doe")
+        .args(args)
+        .assert()
+}
+
+fn seeded_repo() -> TestFixtures {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    lotar(
+        root,
+        &[
+            "add",
+            "Refresh tokens on 401!",
+            "--type",
+            "bug",
+            "--project",
+            "AUTH",
+        ],
+    )
+    .success();
+    run_git(root, &["init", "-q", "-b", "main"]);
+    run_git(root, &["config", "user.name", "Test User"]);
+    run_git(root, &["config", "user.email", "test@example.com"]);
+    run_git(root, &["config", "commit.gpgsign", "false"]);
+    run_git(root, &["add", "-A"]);
+    run_git(root, &["commit", "-q", "-m", "baseline"]);
+    tf
+}
+
+#[cfg(not(no_git_tests))]
+#[test]
+fn start_creates_branch_and_marks_task_in_progress() {
+    let tf = seeded_repo();
+    let root = tf.temp_dir.path();
+
+    let output = lotar(root, &["--format", "json", "start", "AUTH-1", "--dry-run"])
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let preview: Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(preview["status"], "preview");
+    assert_eq!(preview["branch"], "bug/AUTH-1-refresh-tokens-on-401");
+    assert_eq!(preview["checkout"], "created");
+    assert_eq!(run_git(root, &["branch", "--show-current"]), "main");
+
+    lotar(root, &["start", "AUTH-1"])
+        .success()
+        .stdout(predicate::str::contains(
+            "Started AUTH-1 on branch 'bug/AUTH-1-refresh-tokens-on-401'",
+        ));
+    assert_eq!(
+        run_git(root, &["branch", "--show-current"]),
+        "bug/AUTH-1-refresh-tokens-on-401"
+    );
+    let yaml = fs::read_to_string(root.join(".tasks/AUTH/1.yml")).unwrap();
+    assert!(yaml.contains("status: InProgress"), "{yaml}");
+    assert!(yaml.contains("assignee: jane.doe"), "{yaml}");
+
+    // Running it again on the same branch is a no-op
+    let output = lotar(root, &["--format", "json", "start", "1"])
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let again: Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(again["checkout"], "current");
+    assert!(again["new_status"].is_null());
+    assert!(again["assignee"].is_null());
+}
+
+#[cfg(not(no_git_tests))]
+#[test]
+fn quick_commands_default_to_the_branch_ticket() {
+    let tf = seeded_repo();
+    let root = tf.temp_dir.path();
+    fs::write(
+        root.join(".tasks/AUTH/config.yml"),
+        "project:\n  name: AUTH\nauto:\n  assign_on_status: false\nbranch:\n  template: \"work/{id}\"\n",
+    )
+    .unwrap();
+    lotar(root, &["start", "AUTH-1", "--no-assign"]).success();
+    assert_eq!(run_git(root, &["branch", "--show-current"]), "work/AUTH-1");
+    let yaml = fs::read_to_string(root.join(".tasks/AUTH/1.yml")).unwrap();
+    assert!(!yaml.contains("assignee:"), "{yaml}");
+
+    lotar(root, &["status", "done"])
+        .success()
+        .stdout(predicate::str::contains("AUTH-1"));
+    lotar(root, &["comment", "-m", "shipped"]).success();
+    let yaml = fs::read_to_string(root.join(".tasks/AUTH/1.yml")).unwrap();
+    assert!(yaml.contains("status: Done"), "{yaml}");
+    assert!(yaml.contains("shipped"), "{yaml}");
+
+    // Off the branch the ID is required again
+    run_git(root, &["checkout", "-q", "main"]);
+    lotar(root, &["status", "done"]).failure();
+}
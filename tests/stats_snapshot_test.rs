@@ -268,6 +268,160 @@ fn stats_due_overdue_threshold_snapshot() {
 // Effort-related snapshots (consolidated)
 // =============================================================================
 
+#[test]
+fn stats_flow_and_cycle_time_from_task_history() {
+    let temp = crate::common::temp_dir();
+
+    for args in [
+        &["task", "add", "Ship login", "--type=feature"][..],
+        &["task", "add", "Fix crash", "--type=bug"][..],
+        &["task", "add", "Write docs", "--type=feature"][..],
+        &["status", "1", "InProgress"][..],
+        &["status", "1", "Done"][..],
+        &["status", "2", "Done"][..],
+        &["status", "3", "InProgress"][..],
+    ] {
+        let mut c = crate::common::lotar_cmd().unwrap();
+        run(&mut c, &temp, args).success();
+    }
+
+    let mut c = crate::common::lotar_cmd().unwrap();
+    let output = run(
+        &mut c,
+        &temp,
+        &["--format", "json", "stats", "flow", "--since", "1d"],
+    )
+    .success()
+    .get_output()
+    .stdout
+    .clone();
+    let flow: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(flow["action"], "stats.flow");
+    let statuses: Vec<&str> = flow["statuses"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|v| v.as_str())
+        .collect();
+    assert!(statuses.contains(&"Todo") && statuses.contains(&"Done"));
+    let today = flow["days"].as_array().unwrap().last().unwrap();
+    assert_eq!(today["total"], 3);
+    assert_eq!(today["counts"]["Done"], 2);
+    assert_eq!(today["counts"]["InProgress"], 1);
+    assert_eq!(today["counts"]["Todo"], 0);
+
+    let mut c = crate::common::lotar_cmd().unwrap();
+    let output = run(
+        &mut c,
+        &temp,
+        &["--format", "json", "stats", "cycle-time", "--since", "1d"],
+    )
+    .success()
+    .get_output()
+    .stdout
+    .clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(report["action"], "stats.cycle_time");
+    assert_eq!(report["overall"]["completed"], 2);
+    assert!(report["overall"]["lead_time_days"]["p95"].is_number());
+    let by_type = report["by_type"].as_array().unwrap();
+    assert_eq!(by_type.len(), 2);
+    // Only the feature passed through InProgress, so only it has a cycle time
+    let feature = by_type
+        .iter()
+        .find(|group| group["task_type"] == "Feature")
+        .unwrap();
+    assert!(feature["cycle_time_days"]["p50"].is_number());
+    let bug = by_type
+        .iter()
+        .find(|group| group["task_type"] == "Bug")
+        .unwrap();
+    assert!(bug.get("cycle_time_days").is_none());
+    let completed: u64 = report["throughput"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|week| week["completed"].as_u64().unwrap())
+        .sum();
+    assert_eq!(completed, 2);
+
+    let mut c = crate::common::lotar_cmd().unwrap();
+    run(&mut c, &temp, &["stats", "cycle-time"])
+        .success()
+        .stdout(predicate::str::contains("Throughput per week:"));
+}
+
+#[test]
+fn stats_flow_falls_back_to_git_history_of_hand_edited_tasks() {
+    let temp = crate::common::temp_dir();
+    let root = temp.path();
+    let git = |args: &[&str], date: &str| {
+        let status = std::process::Command::new("git")
+            .args(["-c", "user.name=t", "-c", "user.email=t@example.com"])
+            .args(args)
+            .current_dir(root)
+            .env("GIT_AUTHOR_DATE", date)
+            .env("GIT_COMMITTER_DATE", date)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {args:?}");
+    };
+    let write = |number: u32, status: &str| {
+        let dir = root.join(".tasks/AUTH");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join(format!("{number}.yml")),
+            format!(
+                "title: Task {number}\nstatus: {status}\npriority: Medium\ntype: Feature\ncreated: 2026-01-01T00:00:00Z\n"
+            ),
+        )
+        .unwrap();
+    };
+    let commit = |date: &str| {
+        git(&["add", "-A"], date);
+        git(&["commit", "-q", "-m", "edit"], date);
+    };
+
+    git(&["init", "-q"], "2026-01-01T08:00:00Z");
+    write(1, "Todo");
+    write(2, "Todo");
+    commit("2026-01-01T08:00:00Z");
+    write(1, "InProgress");
+    commit("2026-01-02T08:00:00Z");
+    write(1, "Done");
+    write(2, "InProgress");
+    commit("2026-01-03T08:00:00Z");
+
+    let mut c = crate::common::lotar_cmd().unwrap();
+    let output = run(
+        &mut c,
+        &temp,
+        &[
+            "--format",
+            "json",
+            "stats",
+            "flow",
+            "--global",
+            "--since",
+            "2026-01-01",
+            "--until",
+            "2026-01-03T12:00:00Z",
+        ],
+    )
+    .success()
+    .get_output()
+    .stdout
+    .clone();
+    let flow: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let days = flow["days"].as_array().unwrap();
+    assert_eq!(days.len(), 3);
+    assert_eq!(days[0]["counts"]["Todo"], 2);
+    assert_eq!(days[1]["counts"]["InProgress"], 1);
+    assert_eq!(days[1]["counts"]["Todo"], 1);
+    assert_eq!(days[2]["counts"]["Done"], 1);
+    assert_eq!(days[2]["counts"]["InProgress"], 1);
+}
+
 mod effort_unit {
     use serde_json::Value;
 
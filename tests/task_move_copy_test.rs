@@ -0,0 +1,284 @@
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+
+mod common;
+use common::TestFixtures;
+
+use lotar::services::task_service::TaskService;
+use lotar::storage::manager::Storage;
+
+fn lotar(root: &Path, args: &[&str]) -> assert_cmd::assert::Assert {
+    let mut cmd = crate::common::lotar_cmd().unwrap();
+    cmd.current_dir(root).args(args).assert()
+}
+
+const ATTACHMENT: &str = "notes.0123456789abcdef0123456789abcdef.txt";
+
+fn seed(tf: &TestFixtures) {
+    let root = tf.temp_dir.path();
+    let auth = tf.tasks_root.join("AUTH");
+    let ops = tf.tasks_root.join("OPS");
+    fs::create_dir_all(&auth).unwrap();
+    fs::create_dir_all(&ops).unwrap();
+    fs::write(
+        auth.join("1.yml"),
+        format!(
+            "title: Login\nstatus: Todo\ncreated: 2024-01-01T00:00:00Z\nrelationships:\n  blocks: ['2']\nreferences:\n- file: {ATTACHMENT}\n"
+        ),
+    )
+    .unwrap();
+    fs::write(
+        auth.join("2.yml"),
+        "title: Session\nstatus: Todo\ncreated: 2024-01-02T00:00:00Z\nrelationships:\n  depends_on: ['1']\n",
+    )
+    .unwrap();
+    fs::write(
+        ops.join("1.yml"),
+        "title: Deploy\nstatus: Todo\ncreated: 2024-01-03T00:00:00Z\nrelationships:\n  related: [AUTH-1]\n",
+    )
+    .unwrap();
+    fs::write(
+        ops.join("config.yml"),
+        "project:\n  name: Operations\nattachments:\n  dir: ops-files\n",
+    )
+    .unwrap();
+
+    let attachments = tf.tasks_root.join("@attachments");
+    fs::create_dir_all(&attachments).unwrap();
+    fs::write(attachments.join(ATTACHMENT), "remember the cookies").unwrap();
+
+    let sprints = tf.tasks_root.join("@sprints");
+    fs::create_dir_all(&sprints).unwrap();
+    fs::write(
+        sprints.join("1.yml"),
+        "plan:\n  label: Sprint 1\ntasks:\n- id: AUTH-1\n- id: AUTH-2\n",
+    )
+    .unwrap();
+
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(
+        root.join("src/login.rs"),
+        "// TODO(AUTH-1): handle expiry\n// AUTH-10 is unrelated\nfn login() {}\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn move_rewrites_references_and_leaves_a_redirect() {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    seed(&tf);
+
+    lotar(
+        root,
+        &["task", "move", "AUTH-1", "--to", "ops", "--dry-run"],
+    )
+    .success()
+    .stdout(predicate::str::contains("Would move AUTH-1 → OPS-2"))
+    .stdout(predicate::str::contains("1 reference(s) in src/login.rs"));
+    assert!(!tf.tasks_root.join("OPS/2.yml").exists());
+
+    lotar(root, &["task", "move", "AUTH-1", "--to", "OPS"])
+        .success()
+        .stdout(predicate::str::contains("Moved AUTH-1 → OPS-2"))
+        .stdout(predicate::str::contains("AUTH/2.yml"))
+        .stdout(predicate::str::contains("OPS/1.yml"));
+
+    let moved = fs::read_to_string(tf.tasks_root.join("OPS/2.yml")).unwrap();
+    assert!(moved.contains("title: Login"), "{moved}");
+    assert!(moved.contains("old: AUTH-1"), "{moved}");
+    assert!(moved.contains("new: OPS-2"), "{moved}");
+    // Bare numbers keep pointing into the old project
+    assert!(moved.contains("AUTH-2"), "{moved}");
+
+    let stub = fs::read_to_string(tf.tasks_root.join("AUTH/1.yml")).unwrap();
+    assert!(stub.contains("moved_to: OPS-2"), "{stub}");
+
+    let dependent = fs::read_to_string(tf.tasks_root.join("AUTH/2.yml")).unwrap();
+    assert!(dependent.contains("OPS-2"), "{dependent}");
+    let related = fs::read_to_string(tf.tasks_root.join("OPS/1.yml")).unwrap();
+    assert!(
+        related.contains("OPS-2") && !related.contains("AUTH-1"),
+        "{related}"
+    );
+    let sprint = fs::read_to_string(tf.tasks_root.join("@sprints/1.yml")).unwrap();
+    assert!(
+        sprint.contains("- OPS-2") && !sprint.contains("AUTH-1"),
+        "{sprint}"
+    );
+    let source = fs::read_to_string(root.join("src/login.rs")).unwrap();
+    assert!(
+        source.contains("TODO(OPS-2)") && source.contains("AUTH-10"),
+        "{source}"
+    );
+    assert!(
+        tf.tasks_root.join("ops-files").join(ATTACHMENT).exists(),
+        "attachment copied to the target project's directory"
+    );
+
+    // The old ID still resolves, to the moved task
+    let storage = Storage::new(&tf.tasks_root);
+    let task = storage.get("AUTH-1", "AUTH").expect("redirect resolves");
+    assert_eq!(task.title, "Login");
+    assert_eq!(
+        TaskService::get(&storage, "AUTH-1", None).unwrap().id,
+        "OPS-2"
+    );
+
+    // Listed once, under the new ID, and the stub is not a parse warning
+    lotar(root, &["list", "--format", "json"])
+        .success()
+        .stdout(predicate::str::contains("\"OPS-2\""))
+        .stdout(predicate::str::contains("\"AUTH-1\"").not())
+        .stderr(predicate::str::contains("AUTH/1.yml").not());
+
+    // Writes through the old ID land in the moved task
+    lotar(root, &["task", "edit", "AUTH-1", "--title", "Login v2"]).success();
+    let moved = fs::read_to_string(tf.tasks_root.join("OPS/2.yml")).unwrap();
+    assert!(moved.contains("title: Login v2"), "{moved}");
+    let stub = fs::read_to_string(tf.tasks_root.join("AUTH/1.yml")).unwrap();
+    assert!(stub.contains("moved_to: OPS-2"), "{stub}");
+
+    // The old number stays allocated
+    lotar(root, &["add", "Fresh", "--project", "AUTH"])
+        .success()
+        .stdout(predicate::str::contains("AUTH-3"));
+
+    lotar(root, &["task", "move", "OPS-2", "--to", "OPS"])
+        .failure()
+        .stderr(predicate::str::contains("already in project OPS"));
+}
+
+#[test]
+fn migrate_leaves_redirect_stubs_alone() {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    seed(&tf);
+
+    lotar(root, &["task", "move", "AUTH-1", "--to", "OPS"]).success();
+    let stub = fs::read_to_string(tf.tasks_root.join("AUTH/1.yml")).unwrap();
+
+    lotar(root, &["migrate"]).success();
+    assert_eq!(
+        fs::read_to_string(tf.tasks_root.join("AUTH/1.yml")).unwrap(),
+        stub
+    );
+
+    lotar(root, &["list", "--format", "json"])
+        .success()
+        .stdout(predicate::str::contains("\"OPS-2\""))
+        .stderr(predicate::str::contains("AUTH/1.yml").not());
+    let storage = Storage::new(&tf.tasks_root);
+    let task = storage.get("AUTH-1", "AUTH").expect("redirect resolves");
+    assert_eq!(task.title, "Login");
+}
+
+#[test]
+fn move_maps_status_and_type_to_the_target_workflow() {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    seed(&tf);
+    fs::write(
+        tf.tasks_root.join("OPS/config.yml"),
+        "project:\n  name: Operations\nissue:\n  states: [Open, Closed]\n  types: [Chore, Incident]\n",
+    )
+    .unwrap();
+    fs::write(
+        tf.tasks_root.join("AUTH/2.yml"),
+        "title: Session\nstatus: InProgress\ntype: Feature\ncreated: 2024-01-02T00:00:00Z\n",
+    )
+    .unwrap();
+
+    lotar(root, &["task", "move", "AUTH-2", "--to", "OPS"])
+        .success()
+        .stdout(predicate::str::contains("Moved AUTH-2 → OPS-2"))
+        .stderr(predicate::str::contains(
+            "status 'InProgress' is not used in the target project; set to 'Open'",
+        ))
+        .stderr(predicate::str::contains("set to 'Chore'"));
+
+    let moved = fs::read_to_string(tf.tasks_root.join("OPS/2.yml")).unwrap();
+    assert!(moved.contains("status: Open"), "{moved}");
+    assert!(moved.contains("type: Chore"), "{moved}");
+    assert!(moved.contains("old: InProgress"), "{moved}");
+
+    // A value the target allows is kept
+    fs::write(
+        tf.tasks_root.join("AUTH/3.yml"),
+        "title: Pager\nstatus: closed\ntype: Incident\ncreated: 2024-01-03T00:00:00Z\n",
+    )
+    .unwrap();
+    lotar(
+        root,
+        &["task", "copy", "AUTH-3", "--to", "OPS", "--format", "json"],
+    )
+    .success()
+    .stdout(predicate::str::contains("\"mapped\"").not());
+    let copy = fs::read_to_string(tf.tasks_root.join("OPS/3.yml")).unwrap();
+    assert!(copy.contains("status: Closed"), "{copy}");
+    assert!(copy.contains("type: Incident"), "{copy}");
+}
+
+#[test]
+fn renumber_skips_redirect_stubs() {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    seed(&tf);
+
+    lotar(root, &["task", "move", "AUTH-1", "--to", "OPS"]).success();
+    // A stub under a padded name next to a real task with the same number
+    let auth = tf.tasks_root.join("AUTH");
+    fs::rename(auth.join("1.yml"), auth.join("01.yml")).unwrap();
+    fs::write(
+        auth.join("1.yml"),
+        "title: Replacement\nstatus: Todo\ncreated: 2024-02-01T00:00:00Z\n",
+    )
+    .unwrap();
+
+    lotar(root, &["renumber"])
+        .success()
+        .stdout(predicate::str::contains("No ID collisions found"))
+        .stdout(predicate::str::contains("cannot be parsed").not())
+        .stderr(predicate::str::contains("cannot be parsed").not());
+    let stub = fs::read_to_string(auth.join("01.yml")).unwrap();
+    assert!(stub.contains("moved_to: OPS-2"), "{stub}");
+}
+
+#[test]
+fn copy_keeps_the_original_and_its_references() {
+    let tf = TestFixtures::new();
+    let root = tf.temp_dir.path();
+    seed(&tf);
+
+    lotar(
+        root,
+        &["task", "copy", "AUTH-2", "--to", "OPS", "--format", "json"],
+    )
+    .success()
+    .stdout(predicate::str::contains("\"action\":\"copy\""))
+    .stdout(predicate::str::contains("\"to\":\"OPS-2\""));
+
+    let copy = fs::read_to_string(tf.tasks_root.join("OPS/2.yml")).unwrap();
+    assert!(copy.contains("title: Session"), "{copy}");
+    assert!(copy.contains("field: copied_from"), "{copy}");
+    assert!(copy.contains("AUTH-1"), "{copy}");
+    assert!(
+        !copy.contains("2024-01-02"),
+        "copy gets a fresh created: {copy}"
+    );
+
+    let original = fs::read_to_string(tf.tasks_root.join("AUTH/2.yml")).unwrap();
+    assert!(original.contains("field: copied_to"), "{original}");
+    assert!(original.contains("new: OPS-2"), "{original}");
+
+    let sprint = fs::read_to_string(tf.tasks_root.join("@sprints/1.yml")).unwrap();
+    assert!(
+        sprint.contains("AUTH-2") && !sprint.contains("OPS-2"),
+        "{sprint}"
+    );
+
+    lotar(root, &["task", "copy", "AUTH-2", "--to", "NOPE"])
+        .failure()
+        .stderr(predicate::str::contains("Project not found: NOPE"));
+}
@@ -0,0 +1,108 @@
+use lotar::api_server::{ApiServer, HttpRequest};
+use lotar::routes;
+use lotar::services::task_service::TaskService;
+use lotar::storage::manager::Storage;
+use predicates::prelude::*;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+
+mod common;
+use crate::common::env_mutex::EnvVarGuard;
+
+const CONFLICTED_TASK: &str =
+    "title: Broken\n<<<<<<< HEAD\nstatus: Todo\n=======\nstatus: Done\n>>>>>>> feature\n";
+
+fn seed(fixtures: &common::TestFixtures) {
+    fs::write(
+        lotar::utils::paths::global_config_path(&fixtures.tasks_root),
+        "default:\n  project: AUTH\n",
+    )
+    .unwrap();
+    let project = fixtures.tasks_root.join("AUTH");
+    fs::create_dir_all(&project).unwrap();
+    fs::write(
+        project.join("1.yml"),
+        "title: Healthy\nstatus: Todo\ncreated: 2024-01-01T00:00:00Z\n",
+    )
+    .unwrap();
+    fs::write(project.join("2.yml"), CONFLICTED_TASK).unwrap();
+}
+
+#[test]
+fn list_warns_about_unparseable_task_files() {
+    let fixtures = common::TestFixtures::new();
+    seed(&fixtures);
+
+    common::cargo_bin_in(&fixtures)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("AUTH-1 - Healthy"))
+        .stderr(predicate::str::contains(
+            "Skipped AUTH-2 (could not parse AUTH/2.yml:3:",
+        ));
+
+    let output = common::cargo_bin_in(&fixtures)
+        .args(["--format", "json", "list"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["total"], 1);
+    let warnings = json["warnings"].as_array().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0]["id"], "AUTH-2");
+    assert_eq!(warnings[0]["file"], "AUTH/2.yml");
+    assert_eq!(warnings[0]["line"], 3);
+    assert!(warnings[0]["message"].as_str().unwrap().contains("line 3"));
+}
+
+#[test]
+fn list_strict_fails_on_unparseable_task_files() {
+    let fixtures = common::TestFixtures::new();
+    seed(&fixtures);
+
+    common::cargo_bin_in(&fixtures)
+        .args(["list", "--strict"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "1 task file(s) could not be parsed (--strict)",
+        ));
+}
+
+#[test]
+fn get_reports_parse_error_instead_of_not_found() {
+    let fixtures = common::TestFixtures::new();
+    seed(&fixtures);
+
+    let storage = Storage::new(&fixtures.tasks_root);
+    let err = TaskService::get(&storage, "AUTH-2", None).unwrap_err();
+    assert!(err.to_string().contains("AUTH/2.yml:3:"), "{err}");
+    assert!(TaskService::get(&storage, "AUTH-1", None).is_ok());
+}
+
+#[test]
+fn api_task_list_carries_warnings() {
+    let fixtures = common::TestFixtures::new();
+    seed(&fixtures);
+    let _guard_tasks = EnvVarGuard::set("LOTAR_TASKS_DIR", &fixtures.tasks_root.to_string_lossy());
+    lotar::utils::query_cache::invalidate_all();
+
+    let mut api = ApiServer::new();
+    routes::initialize(&mut api);
+    let resp = api.handle_request(&HttpRequest {
+        method: "GET".to_string(),
+        path: "/api/tasks/list".to_string(),
+        query: HashMap::from([("project".to_string(), "AUTH".to_string())]),
+        headers: HashMap::new(),
+        body: Vec::new(),
+    });
+    assert_eq!(resp.status, 200);
+    let body: Value = serde_json::from_slice(&resp.body).unwrap();
+    assert_eq!(body["data"]["total"], 1);
+    let warnings = body["data"]["warnings"].as_array().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0]["file"], "AUTH/2.yml");
+}
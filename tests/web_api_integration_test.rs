@@ -621,6 +621,64 @@ fn api_add_list_get_delete_roundtrip() {
     // Restored by guards
 }
 
+#[test]
+fn api_move_and_copy_tasks_between_projects() {
+    let _guard_fast = EnvVarGuard::set("LOTAR_TEST_FAST_IO", "1");
+    let tmp = tempfile::tempdir().unwrap();
+    let tasks_dir = tmp.path().join(".tasks");
+    std::fs::create_dir_all(tasks_dir.join("OPS")).unwrap();
+    let _guard_tasks = EnvVarGuard::set("LOTAR_TASKS_DIR", &tasks_dir.to_string_lossy());
+
+    let mut api = ApiServer::new();
+    routes::initialize(&mut api);
+
+    let resp = api.handle_request(&mk_req(
+        "POST",
+        "/api/tasks/add",
+        &[("project", "TEST")],
+        json!({"title": "Move me"}),
+    ));
+    assert_eq!(resp.status, 201, "add status");
+    let added: Value = serde_json::from_slice(&resp.body).unwrap();
+    let id = added["data"]["id"].as_str().unwrap().to_string();
+
+    let resp = api.handle_request(&mk_req(
+        "POST",
+        "/api/tasks/move",
+        &[],
+        json!({"id": id, "to": "OPS"}),
+    ));
+    assert_eq!(resp.status, 200, "move status");
+    let moved: Value = serde_json::from_slice(&resp.body).unwrap();
+    assert_eq!(moved["data"]["to"], "OPS-1");
+
+    // The old ID resolves to the moved task
+    let resp = api.handle_request(&mk_req("GET", "/api/tasks/get", &[("id", &id)], json!({})));
+    assert_eq!(resp.status, 200, "get via old id");
+    let got: Value = serde_json::from_slice(&resp.body).unwrap();
+    assert_eq!(got["data"]["id"], "OPS-1");
+    assert_eq!(got["data"]["title"], "Move me");
+
+    let resp = api.handle_request(&mk_req(
+        "POST",
+        "/api/tasks/copy",
+        &[],
+        json!({"id": "OPS-1", "to": "TEST", "dry_run": true}),
+    ));
+    assert_eq!(resp.status, 200, "copy preview status");
+    let preview: Value = serde_json::from_slice(&resp.body).unwrap();
+    assert_eq!(preview["data"]["reason"], "copied");
+    assert!(!tasks_dir.join("TEST/2.yml").exists());
+
+    let resp = api.handle_request(&mk_req(
+        "POST",
+        "/api/tasks/move",
+        &[],
+        json!({"id": "OPS-9", "to": "TEST"}),
+    ));
+    assert_eq!(resp.status, 404, "missing task");
+}
+
 #[test]
 fn api_comment_update_edits_existing_comment() {
     let _guard_fast = EnvVarGuard::set("LOTAR_TEST_FAST_IO", "1");
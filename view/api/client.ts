@@ -26,6 +26,10 @@ import type {
   CodeReferenceRemoveResponse,
   ConfigInspectResult,
   ConfigSetResponse,
+  CycleTimeReport,
+  FlowReport,
+  ForecastReport,
+  ForecastRequest,
   GenericReferenceAddRequest,
   GenericReferenceAddResponse,
   GenericReferenceRemoveRequest,
@@ -207,7 +211,7 @@ export const api = {
   scanRun(payload: ScanRequest): Promise<ScanResponse> { return post('/api/scan/run', payload) },
 
   // Agent jobs
-  listAgentJobs(params: { status?: string; ticket_id?: string } = {}): Promise<AgentJobListResponse> {
+  listAgentJobs(params: { status?: string; ticket_id?: string; runner?: string; limit?: number; offset?: number } = {}): Promise<AgentJobListResponse> {
     return get('/api/jobs', params as any)
   },
   getAgentJob(id: string): Promise<AgentJobStatusResponse> { return get('/api/jobs/get', { id }) },
@@ -248,6 +252,15 @@ export const api = {
   activityFeed(params: { since?: string; until?: string; project?: string; limit?: number } = {}): Promise<ActivityFeedItem[]> {
     return get('/api/activity/feed', params)
   },
+  statsFlow(params: { since?: string; until?: string; project?: string } = {}): Promise<FlowReport> {
+    return get('/api/stats/flow', params)
+  },
+  statsCycleTime(params: { since?: string; until?: string; project?: string } = {}): Promise<CycleTimeReport> {
+    return get('/api/stats/cycle-time', params)
+  },
+  forecast(params: ForecastRequest = {}): Promise<ForecastReport> {
+    return get('/api/forecast', params)
+  },
   whoami(): Promise<string> { return get('/api/whoami') },
   exportTasks(filter: TaskListFilter & { q?: string } = {} as any): Promise<Response> {
     const params: any = {
@@ -260,6 +273,9 @@ export const api = {
     }
     return fetch(`/api/tasks/export${qs(params)}`, { headers: { 'Accept': 'text/csv' } })
   },
+  calendarUrl(params: { project?: string; assignee?: string; tags?: string[]; sprints?: boolean; tasks?: boolean; include_done?: boolean; todo?: boolean } = {}): string {
+    return `/api/calendar.ics${qs(params)}`
+  },
 
   // Sprints
   sprintList(): Promise<SprintListResponse> { return get('/api/sprints/list') },
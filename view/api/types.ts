@@ -18,6 +18,7 @@ export interface TaskDTO {
   subtitle?: string | null
   description?: string | null
   tags: string[]
+  acceptance_criteria?: string[]
   relationships: TaskRelationships
   comments: any[]
   references: ReferenceEntry[]
@@ -33,6 +34,7 @@ export interface ReferenceEntry {
   file?: string | null
   jira?: string | null
   github?: string | null
+  commit?: string | null
 }
 
 export interface AttachmentUploadRequest {
@@ -78,7 +80,7 @@ export interface LinkReferenceRemoveResponse {
   removed: boolean
 }
 
-export type TaskReferenceKind = 'jira' | 'github'
+export type TaskReferenceKind = 'jira' | 'github' | 'commit'
 
 export interface GenericReferenceAddRequest {
   id: string
@@ -230,11 +232,20 @@ export interface TaskListFilter {
   [key: string]: any
 }
 
+export interface TaskParseWarning {
+  id: string
+  file: string
+  line?: number
+  column?: number
+  message: string
+}
+
 export interface TaskListResponse {
   total: number
   limit: number
   offset: number
   tasks: TaskDTO[]
+  warnings?: TaskParseWarning[]
 }
 
 export interface TaskSelection {
@@ -260,6 +271,7 @@ export interface SprintAssignmentResponse {
   unchanged: string[]
   replaced?: SprintReassignment[]
   messages?: string[]
+  capacity_warnings?: string[]
   integrity?: SprintIntegrityDiagnostics
 }
 
@@ -450,11 +462,24 @@ export interface SprintStatsEffortPayload {
   capacity_consumed_ratio?: number | null
 }
 
+export interface SprintMemberCapacityPayload {
+  member: string
+  weekly_hours?: number | null
+  available_hours?: number | null
+  planned_hours: number
+  remaining_hours: number
+  planned_points: number
+  load_ratio?: number | null
+  over_capacity?: boolean
+  time_off_days?: number
+}
+
 export interface SprintStatsMetricsPayload {
   tasks: SprintStatsCountsPayload
   hours?: SprintStatsEffortPayload | null
   points?: SprintStatsEffortPayload | null
   status_breakdown: SprintReviewStatusMetric[]
+  members?: SprintMemberCapacityPayload[]
 }
 
 export interface SprintStatsTimelinePayload {
@@ -475,6 +500,7 @@ export interface SprintSummaryReportMetrics {
   hours?: SprintStatsEffortPayload | null
   points?: SprintStatsEffortPayload | null
   blocked?: number
+  members?: SprintMemberCapacityPayload[]
 }
 
 export interface SprintSummaryReportResponse {
@@ -521,6 +547,7 @@ export interface SprintVelocityEntryPayload {
   window?: string | null
   committed: number
   completed: number
+  spillover?: number
   completion_ratio?: number | null
   capacity?: number | null
   capacity_commitment_ratio?: number | null
@@ -541,6 +568,88 @@ export interface SprintVelocityResponse {
   entries: SprintVelocityEntryPayload[]
 }
 
+export interface FlowDay {
+  date: string
+  counts: Record<string, number>
+  total: number
+}
+
+export interface FlowReport {
+  since: string
+  until: string
+  project?: string | null
+  statuses: string[]
+  days: FlowDay[]
+}
+
+export interface DurationPercentiles {
+  p50: number
+  p85: number
+  p95: number
+  mean: number
+}
+
+export interface CycleTimeGroup {
+  task_type: string
+  completed: number
+  lead_time_days?: DurationPercentiles | null
+  cycle_time_days?: DurationPercentiles | null
+}
+
+export interface ThroughputWeek {
+  week_start: string
+  completed: number
+}
+
+export interface CycleTimeReport {
+  since: string
+  until: string
+  project?: string | null
+  overall: CycleTimeGroup
+  by_type: CycleTimeGroup[]
+  throughput: ThroughputWeek[]
+}
+
+export interface ForecastRequest {
+  project?: string
+  q?: string
+  tags?: string[]
+  epic?: string
+  sprint?: string
+  by?: 'sprint' | 'week'
+  metric?: 'tasks' | 'points' | 'hours'
+  history?: number
+  iterations?: number
+  target?: string
+  seed?: number
+}
+
+export interface ForecastPercentile {
+  percentile: number
+  periods: number
+  date: string
+}
+
+export interface ForecastTarget {
+  date: string
+  probability: number
+}
+
+export interface ForecastReport {
+  basis: 'sprint' | 'week'
+  metric: string
+  selected: number
+  remaining_tasks: number
+  remaining: number
+  unestimated: number
+  history: number[]
+  period_days: number
+  iterations: number
+  percentiles: ForecastPercentile[]
+  target?: ForecastTarget | null
+  warnings: string[]
+}
+
 export interface SprintBacklogTask {
   id: string
   title: string
@@ -649,6 +758,7 @@ export interface ResolvedConfigDTO {
 }
 
 export interface GlobalConfigRaw {
+  extends?: string[]
   server_port: number
   default_project: string
   attachments_dir: string
@@ -725,6 +835,8 @@ export interface ConfigInspectResult {
   has_global_file: boolean
   project_exists: boolean
   sources: Record<string, ConfigSource>
+  inherited: string[]
+  inherited_sources: Record<string, string>
 }
 
 export interface ConfigSetResponse {
@@ -769,6 +881,7 @@ export interface ScanRequest {
   reanchor?: boolean
   modified_only?: boolean
   targets?: ScanTarget[]
+  reconcile?: boolean
 }
 
 export interface ScanSummary {
@@ -801,6 +914,16 @@ export interface ScanResponse {
   warnings: string[]
   info: string[]
   entries: ScanEntry[]
+  reconciled?: ScanReconcileEntry[]
+}
+
+export interface ScanReconcileEntry {
+  task_id: string
+  code_reference: string
+  outcome: 'moved' | 'removed' | string
+  new_reference?: string | null
+  actions?: string[]
+  message?: string | null
 }
 
 export interface SyncRequest {
@@ -920,6 +1043,7 @@ export interface AgentJob {
   session_id?: string | null
   worktree_path?: string | null
   worktree_branch?: string | null
+  attempt?: number | null
 }
 
 export interface AgentJobCreateRequest {
@@ -946,6 +1070,9 @@ export interface AgentQueueStats {
 export interface AgentJobListResponse {
   jobs: AgentJob[]
   queue_stats?: AgentQueueStats | null
+  total?: number | null
+  limit?: number | null
+  offset?: number | null
 }
 
 export interface AgentJobCancelRequest {
@@ -983,6 +1110,98 @@ export interface AgentJobMessageResponse {
   job: AgentJob
 }
 
+// Agent pipelines
+export interface AgentPipelineStageRun {
+  name: string
+  agent?: string | null
+  status: string
+  needs?: string[]
+  approval?: boolean
+  job_id?: string | null
+  runs?: number
+  started_at?: string | null
+  finished_at?: string | null
+  message?: string | null
+}
+
+export interface AgentPipelineRun {
+  id: string
+  pipeline: string
+  ticket_id: string
+  status: string
+  created_at: string
+  updated_at: string
+  finished_at?: string | null
+  prompt?: string | null
+  message?: string | null
+  rewinds?: number
+  stages: AgentPipelineStageRun[]
+}
+
+export interface AgentPipelineRunRequest {
+  ticket_id: string
+  pipeline: string
+  prompt?: string
+}
+
+export interface AgentPipelineDecisionRequest {
+  ticket_id: string
+  stage?: string
+  note?: string
+}
+
+export interface AgentPipelineCancelRequest {
+  ticket_id: string
+}
+
+export interface AgentPipelineRunResponse {
+  run: AgentPipelineRun
+}
+
+export interface AgentPipelineInfo {
+  name: string
+  description?: string | null
+  stages: string[]
+}
+
+export interface AgentPipelineListResponse {
+  pipelines: AgentPipelineInfo[]
+  runs: AgentPipelineRun[]
+}
+
+// Agent approval gates
+export interface AgentApproval {
+  id: number
+  ticket_id: string
+  operation: 'update' | 'comment' | 'delete' | string
+  summary: string
+  status: 'pending' | 'approved' | 'rejected' | 'failed' | string
+  job_id?: string | null
+  agent?: string | null
+  requested_at: string
+  patch?: TaskUpdate | null
+  comment?: string | null
+  decided_at?: string | null
+  decided_by?: string | null
+  note?: string | null
+  error?: string | null
+}
+
+export interface AgentApprovalListResponse {
+  approvals: AgentApproval[]
+}
+
+export interface AgentApprovalDecisionRequest {
+  id: number
+  note?: string
+}
+
+export interface AgentApprovalDecisionResponse {
+  approval: AgentApproval
+  task?: TaskDTO | null
+  notified: boolean
+}
+
 // Agent profiles
 export interface AgentProfileInfo {
   name: string